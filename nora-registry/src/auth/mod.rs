@@ -282,8 +282,9 @@ pub async fn auth_middleware(
     // Token management always requires auth, even with anonymous_read
     let is_token_management = path.starts_with("/ui/tokens") || path.starts_with("/api/ui/tokens");
 
-    // npm whoami always requires auth (otherwise it returns "anonymous" for every user)
-    let is_whoami = path.ends_with("/-/whoami");
+    // npm whoami and the Conan login endpoints always require auth (otherwise they
+    // answer "anonymous" for every user)
+    let is_whoami = path.ends_with("/-/whoami") || path.starts_with("/conan/v2/users/");
 
     // Admin control-plane paths always require an admin token — never anonymous,
     // even under anonymous_read, and method-independent (covers a future GET).
@@ -304,6 +305,12 @@ pub async fn auth_middleware(
         && (path == "/npm/-/npm/v1/security/advisories/bulk"
             || path == "/npm/-/npm/v1/security/audits/quick");
 
+    // `swift package-registry login` probes credentials with a POST to
+    // `/swift/login`. The handler mutates nothing, so a read-only token must
+    // pass the write-role check below — otherwise read-only CI tokens could
+    // never be registered with SwiftPM.
+    let is_swift_login = *request.method() == axum::http::Method::POST && path == "/swift/login";
+
     // A request that presents credentials is always validated below (honest
     // `docker login`, correct audit attribution) — never short-circuited to
     // anonymous. Anonymous Docker bypass applies only when no Authorization sent.
@@ -358,10 +365,18 @@ pub async fn auth_middleware(
         }
     }
 
-    // Extract Authorization header
+    // Extract Authorization header. `dotnet nuget push -k` sends its API key in
+    // `X-NuGet-ApiKey` instead; on NuGet paths an `nra_` key there stands in for
+    // a missing one.
+    let nuget_api_key = request
+        .headers()
+        .get("x-nuget-apikey")
+        .filter(|key| path.starts_with("/nuget/") && key.as_bytes().starts_with(b"nra_"));
+    let from_api_key = !request.headers().contains_key(header::AUTHORIZATION);
     let auth_header = request
         .headers()
         .get(header::AUTHORIZATION)
+        .or(nuget_api_key)
         .and_then(|h| h.to_str().ok());
 
     let auth_header = match auth_header {
@@ -369,8 +384,15 @@ pub async fn auth_middleware(
         None => return unauthorized_response("Authentication required", realm),
     };
 
-    // Try Bearer token first (opaque nra_ tokens, then OIDC JWT)
-    if let Some(token) = auth_header.strip_prefix("Bearer ") {
+    // Try Bearer token first (opaque nra_ tokens, then OIDC JWT). The NuGet API
+    // key is a bare token, and so is the `Authorization` value `gem push` and
+    // `gem yank` send, so on those endpoints a scheme-less `nra_…` is taken as a
+    // Bearer token too.
+    let is_gems_api = path == "/gems/api/v1/gems" || path.starts_with("/gems/api/v1/gems/");
+    let bearer = auth_header.strip_prefix("Bearer ").or_else(|| {
+        ((from_api_key || is_gems_api) && auth_header.starts_with("nra_")).then_some(auth_header)
+    });
+    if let Some(token) = bearer {
         // 1. Try opaque token (nra_ prefix)
         if let Some(ref token_store) = state.tokens {
             match token_store.verify_token(token) {
@@ -384,6 +406,7 @@ pub async fn auth_middleware(
                         || method == axum::http::Method::DELETE
                         || method == axum::http::Method::PATCH)
                         && !role.can_write()
+                        && !is_swift_login
                     {
                         return (StatusCode::FORBIDDEN, "Read-only token").into_response();
                     }
@@ -437,6 +460,7 @@ pub async fn auth_middleware(
                             || method == axum::http::Method::DELETE
                             || method == axum::http::Method::PATCH)
                             && !identity.role.can_write()
+                            && !is_swift_login
                         {
                             return (StatusCode::FORBIDDEN, "Read-only OIDC identity")
                                 .into_response();
@@ -531,6 +555,7 @@ pub async fn auth_middleware(
                 || method == axum::http::Method::DELETE
                 || method == axum::http::Method::PATCH)
                 && !role.can_write()
+                && !is_swift_login
             {
                 return (StatusCode::FORBIDDEN, "Read-only token").into_response();
             }
@@ -1188,6 +1213,53 @@ mod integration_tests {
         assert_eq!(response.status(), StatusCode::FORBIDDEN);
     }
 
+    /// RubyGems sends `Authorization: <api key>` with no scheme. That form is
+    /// only honoured on the gem push/yank API (tested in registry/gems.rs), not
+    /// on other routes.
+    #[tokio::test]
+    async fn test_bare_api_token_rejected_outside_gems_api() {
+        let ctx = create_test_context_with_auth(&[("admin", "secret")]);
+        let token = ctx
+            .state
+            .tokens
+            .as_ref()
+            .unwrap()
+            .create_token("admin", 30, None, crate::tokens::Role::Write)
+            .unwrap();
+        let response = send_with_headers(
+            &ctx.app,
+            Method::PUT,
+            "/raw/test.txt",
+            vec![("authorization", &token)],
+            b"data".to_vec(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    /// `X-NuGet-ApiKey` stands in for `Authorization` on NuGet paths only (the
+    /// push is tested in registry/nuget.rs).
+    #[tokio::test]
+    async fn test_nuget_api_key_header_rejected_outside_nuget() {
+        let ctx = create_test_context_with_auth(&[("admin", "secret")]);
+        let token = ctx
+            .state
+            .tokens
+            .as_ref()
+            .unwrap()
+            .create_token("admin", 30, None, crate::tokens::Role::Write)
+            .unwrap();
+        let response = send_with_headers(
+            &ctx.app,
+            Method::PUT,
+            "/raw/test.txt",
+            vec![("x-nuget-apikey", &token)],
+            b"data".to_vec(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
     /// Path of a stored token's on-disk file inside a test context.
     fn token_file_path(ctx: &crate::test_helpers::TestContext, token: &str) -> std::path::PathBuf {
         use sha2::Digest;
@@ -1255,6 +1327,43 @@ mod integration_tests {
         assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
     }
 
+    /// `swift package-registry login` POSTs to /swift/login; a read-only token
+    /// must pass (it is a credential probe), while other writes stay 403.
+    #[tokio::test]
+    async fn test_read_only_token_can_swift_login() {
+        let ctx = create_test_context_with_auth(&[("admin", "secret")]);
+        let token = ctx
+            .state
+            .tokens
+            .as_ref()
+            .unwrap()
+            .create_token("ci", 30, None, crate::tokens::Role::Read)
+            .unwrap();
+        let header_val = format!("Bearer {token}");
+        let response = send_with_headers(
+            &ctx.app,
+            Method::POST,
+            "/swift/login",
+            vec![("authorization", &header_val)],
+            Vec::new(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = send_with_headers(
+            &ctx.app,
+            Method::PUT,
+            "/swift/acme/lib/1.0.0",
+            vec![("authorization", &header_val)],
+            Vec::new(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = send(&ctx.app, Method::POST, "/swift/login", "").await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
     #[tokio::test]
     async fn test_auth_anonymous_read() {
         let ctx = create_test_context_with_anonymous_read(&[("admin", "secret")]);
@@ -1666,6 +1775,8 @@ Jd74nq6dNCjpWG4drIsyhqX+
                     crate::config::CurationConfig::default(),
                 ),
                 bypass_token: None,
+                vulndb: None,
+                cosign: Default::default(),
             })),
             auth_failures: Arc::new(crate::auth::AuthFailureTracker::new(5, 900)),
             oidc: Some(Arc::new(oidc_validator)),
@@ -1674,7 +1785,10 @@ Jd74nq6dNCjpWG4drIsyhqX+
             )),
             proxy_coalesce: crate::proxy_coalesce::InflightMap::new(),
             digest_store: ctx.state.digest_store.clone(),
+            cosign_children: ctx.state.cosign_children.clone(),
             signer: ctx.state.signer.clone(),
+            terraform_signer: ctx.state.terraform_signer.clone(),
+            apk_signer: ctx.state.apk_signer.clone(),
             leak_finders: ctx.state.leak_finders.clone(),
             cancel_token: tokio_util::sync::CancellationToken::new(),
         };
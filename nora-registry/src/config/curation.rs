@@ -5,6 +5,7 @@
 
 use crate::digest_quarantine::QuarantineMode;
 use crate::secrets::ProtectedString;
+use crate::vulndb::Severity;
 use serde::{Deserialize, Serialize};
 use std::env;
 
@@ -73,6 +74,15 @@ pub enum CurationOnFailure {
 /// - `NORA_CURATION_MIN_RELEASE_AGE` — minimum release age (e.g., "7d", "24h", "1w")
 /// - `NORA_CURATION_QUARANTINE` — quarantine mode: off/observe/enforce (default: off)
 /// - `NORA_CURATION_QUARANTINE_TTL` — quarantine hold duration (e.g., "14d", "24h")
+/// - `NORA_CURATION_VULNERABILITIES` — enable the vulnerability filter (default: false)
+/// - `NORA_CURATION_VULNDB_PATH` — vulnerability database path
+/// - `NORA_CURATION_VULN_MIN_SEVERITY` — low/medium/high/critical (default: high)
+/// - `NORA_CURATION_LICENSES` — enable the license filter (default: false)
+/// - `NORA_CURATION_LICENSE_ALLOW` — comma-separated accepted license patterns
+/// - `NORA_CURATION_LICENSE_DENY` — comma-separated rejected license patterns
+/// - `NORA_CURATION_TYPOSQUAT` — enable the typosquatting filter (default: false)
+/// - `NORA_CURATION_TYPOSQUAT_CORPUS` — name corpus for every registry
+/// - `NORA_CURATION_{REGISTRY}_TYPOSQUAT_CORPUS` — additional corpus for one registry
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurationConfig {
     #[serde(default)]
@@ -104,6 +114,15 @@ pub struct CurationConfig {
     /// How long new digests are held in quarantine (e.g., "14d", "24h", "1w").
     #[serde(default)]
     pub quarantine_ttl: Option<String>,
+    /// Vulnerability filter backed by the offline OSV database (`nora vulndb import`).
+    #[serde(default)]
+    pub vulnerabilities: VulnerabilityConfig,
+    /// License policy filter (npm, PyPI, Cargo, NuGet and Maven declare licenses).
+    #[serde(default)]
+    pub licenses: LicenseConfig,
+    /// Typosquatting filter: names that resemble, but are not, well-known or internal names.
+    #[serde(default)]
+    pub typosquat: TyposquatConfig,
     /// Per-registry curation overrides. Overrides `min_release_age` per registry.
     #[serde(default)]
     pub npm: RegistryCurationOverride,
@@ -133,6 +152,14 @@ pub struct CurationConfig {
     pub rpm: RegistryCurationOverride,
     #[serde(default)]
     pub deb: RegistryCurationOverride,
+    #[serde(default)]
+    pub helm: RegistryCurationOverride,
+    #[serde(default)]
+    pub apk: RegistryCurationOverride,
+    #[serde(default)]
+    pub conda: RegistryCurationOverride,
+    #[serde(default)]
+    pub composer: RegistryCurationOverride,
 }
 
 /// Per-registry curation override (used within `[curation.{registry}]`).
@@ -147,6 +174,174 @@ pub struct RegistryCurationOverride {
     /// Override quarantine TTL for this specific registry.
     #[serde(default)]
     pub quarantine_ttl: Option<String>,
+    /// Override `[curation.vulnerabilities].min_severity` for this specific registry.
+    #[serde(default)]
+    pub min_severity: Option<Severity>,
+    /// Replace `[curation.licenses].allow` for this specific registry.
+    #[serde(default)]
+    pub license_allow: Option<Vec<String>>,
+    /// Replace `[curation.licenses].deny` for this specific registry.
+    #[serde(default)]
+    pub license_deny: Option<Vec<String>>,
+    /// Name corpus for this registry, used in addition to `[curation.typosquat].corpus_path`.
+    #[serde(default)]
+    pub typosquat_corpus: Option<String>,
+}
+
+/// What the vulnerability, license or typosquat filter does with a match.
+///
+/// - `block` — a curation block (403 in enforce mode, logged in audit mode) (default)
+/// - `audit` — log and count the match, never block, whatever the curation mode
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FilterAction {
+    #[default]
+    Block,
+    Audit,
+}
+
+/// Vulnerability filter configuration (`[curation.vulnerabilities]`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VulnerabilityConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Database written by `nora vulndb import`. Default: `{storage.path}/vulndb/osv.json`.
+    #[serde(default)]
+    pub db_path: Option<String>,
+    #[serde(default)]
+    pub action: FilterAction,
+    /// Lowest severity that matches (per-registry override: `[curation.{registry}]`).
+    #[serde(default = "default_min_severity")]
+    pub min_severity: Severity,
+    /// Whether advisories without any severity rating match. Default true: an
+    /// unrated advisory is not evidence the vulnerability is harmless.
+    #[serde(default = "default_true")]
+    pub include_unrated: bool,
+    /// Accepted advisories that never match.
+    #[serde(default)]
+    pub waivers: Vec<VulnerabilityWaiver>,
+}
+
+/// An accepted advisory: by id or alias (`GHSA-…`, `CVE-…`), optionally scoped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VulnerabilityWaiver {
+    pub id: String,
+    /// Registry the waiver is limited to (e.g. "npm"). Default: all.
+    #[serde(default)]
+    pub registry: Option<String>,
+    /// Package glob the waiver is limited to. Default: all.
+    #[serde(default)]
+    pub package: Option<String>,
+    /// Last day the waiver applies (`YYYY-MM-DD`, UTC). Default: no expiry.
+    #[serde(default)]
+    pub expires: Option<String>,
+    /// Why the advisory is accepted — for the people who review the config.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// License filter configuration (`[curation.licenses]`).
+///
+/// Patterns are SPDX identifiers or globs (`GPL-*`, `*-only`), matched
+/// case-insensitively. A license passes when it matches `allow`, else fails when
+/// it matches `deny`, else passes only if `allow` is empty. `OR` needs one
+/// acceptable branch, `AND` needs all; `X WITH exception` is checked as a whole
+/// against `allow` first, then as `X`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub action: FilterAction,
+    /// Accepted licenses. Empty = anything not denied.
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// Rejected licenses.
+    #[serde(default)]
+    pub deny: Vec<String>,
+    /// Whether versions without a recognizable declared license pass. Default
+    /// true; set false to require one.
+    #[serde(default = "default_true")]
+    pub allow_unknown: bool,
+}
+
+impl Default for LicenseConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            action: FilterAction::Block,
+            allow: Vec::new(),
+            deny: Vec::new(),
+            allow_unknown: true,
+        }
+    }
+}
+
+/// Typosquatting filter configuration (`[curation.typosquat]`).
+///
+/// Corpus files list the names worth impersonating, one per line (`#` comments);
+/// `@scope/*`, `github.com/org/*` and `group:*` entries name namespaces.
+/// Internal namespaces ending in `/*` or `/**` are always part of the corpus.
+/// With `action = "block"` a lookalike is blocked in enforce mode and logged in
+/// audit mode, like any other curation block.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TyposquatConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub action: FilterAction,
+    /// Name corpus for every registry.
+    #[serde(default)]
+    pub corpus_path: Option<String>,
+    /// Most edits (insert, delete, substitute, swap) between a lookalike and the
+    /// name it imitates.
+    #[serde(default = "default_max_distance")]
+    pub max_distance: usize,
+    /// Names shorter than this are only compared after folding homoglyphs and
+    /// separators, never by edit distance.
+    #[serde(default = "default_min_length")]
+    pub min_length: usize,
+}
+
+impl Default for TyposquatConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            action: FilterAction::Block,
+            corpus_path: None,
+            max_distance: default_max_distance(),
+            min_length: default_min_length(),
+        }
+    }
+}
+
+fn default_max_distance() -> usize {
+    1
+}
+
+fn default_min_length() -> usize {
+    5
+}
+
+fn default_min_severity() -> Severity {
+    Severity::High
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for VulnerabilityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            db_path: None,
+            action: FilterAction::Block,
+            min_severity: default_min_severity(),
+            include_unrated: true,
+            waivers: Vec::new(),
+        }
+    }
 }
 
 impl Default for CurationConfig {
@@ -162,6 +357,9 @@ impl Default for CurationConfig {
             min_release_age: None,
             quarantine: None,
             quarantine_ttl: None,
+            vulnerabilities: VulnerabilityConfig::default(),
+            licenses: LicenseConfig::default(),
+            typosquat: TyposquatConfig::default(),
             npm: RegistryCurationOverride::default(),
             pypi: RegistryCurationOverride::default(),
             cargo: RegistryCurationOverride::default(),
@@ -176,6 +374,10 @@ impl Default for CurationConfig {
             conan: RegistryCurationOverride::default(),
             rpm: RegistryCurationOverride::default(),
             deb: RegistryCurationOverride::default(),
+            helm: RegistryCurationOverride::default(),
+            apk: RegistryCurationOverride::default(),
+            conda: RegistryCurationOverride::default(),
+            composer: RegistryCurationOverride::default(),
         }
     }
 }
@@ -234,6 +436,41 @@ impl CurationConfig {
             self.quarantine_ttl = if val.is_empty() { None } else { Some(val) };
         }
 
+        if let Ok(val) = env::var("NORA_CURATION_VULNERABILITIES") {
+            self.vulnerabilities.enabled = val.to_lowercase() == "true" || val == "1";
+        }
+        if let Ok(val) = env::var("NORA_CURATION_VULNDB_PATH") {
+            self.vulnerabilities.db_path = if val.is_empty() { None } else { Some(val) };
+        }
+        if let Ok(val) = env::var("NORA_CURATION_VULN_MIN_SEVERITY") {
+            self.vulnerabilities.min_severity = val
+                .parse::<Severity>()
+                .map_err(|e| format!("NORA_CURATION_VULN_MIN_SEVERITY={:?}: {}", val, e))?;
+        }
+
+        if let Ok(val) = env::var("NORA_CURATION_LICENSES") {
+            self.licenses.enabled = val.to_lowercase() == "true" || val == "1";
+        }
+        for (var, field) in [
+            ("NORA_CURATION_LICENSE_ALLOW", &mut self.licenses.allow),
+            ("NORA_CURATION_LICENSE_DENY", &mut self.licenses.deny),
+        ] {
+            if let Ok(val) = env::var(var) {
+                *field = val
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+            }
+        }
+
+        if let Ok(val) = env::var("NORA_CURATION_TYPOSQUAT") {
+            self.typosquat.enabled = val.to_lowercase() == "true" || val == "1";
+        }
+        if let Ok(val) = env::var("NORA_CURATION_TYPOSQUAT_CORPUS") {
+            self.typosquat.corpus_path = if val.is_empty() { None } else { Some(val) };
+        }
+
         // Per-registry curation overrides
         for (env_suffix, field) in [
             ("NPM", &mut self.npm),
@@ -250,6 +487,10 @@ impl CurationConfig {
             ("CONAN", &mut self.conan),
             ("RPM", &mut self.rpm),
             ("DEB", &mut self.deb),
+            ("HELM", &mut self.helm),
+            ("APK", &mut self.apk),
+            ("CONDA", &mut self.conda),
+            ("COMPOSER", &mut self.composer),
         ] {
             if let Ok(val) = env::var(format!("NORA_CURATION_{}_MIN_RELEASE_AGE", env_suffix)) {
                 field.min_release_age = if val.is_empty() { None } else { Some(val) };
@@ -266,6 +507,9 @@ impl CurationConfig {
             if let Ok(val) = env::var(format!("NORA_CURATION_{}_QUARANTINE_TTL", env_suffix)) {
                 field.quarantine_ttl = if val.is_empty() { None } else { Some(val) };
             }
+            if let Ok(val) = env::var(format!("NORA_CURATION_{}_TYPOSQUAT_CORPUS", env_suffix)) {
+                field.typosquat_corpus = if val.is_empty() { None } else { Some(val) };
+            }
         }
 
         Ok(())
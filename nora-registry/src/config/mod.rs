@@ -31,7 +31,8 @@ pub use self::auth::{
 #[allow(unused_imports)]
 pub use self::circuit_breaker::{CircuitBreakerConfig, CircuitBreakerOverride};
 pub use self::curation::{
-    CurationConfig, CurationMode, CurationOnFailure, RegistryCurationOverride,
+    CurationConfig, CurationMode, CurationOnFailure, FilterAction, LicenseConfig,
+    RegistryCurationOverride, TyposquatConfig, VulnerabilityConfig, VulnerabilityWaiver,
 };
 pub use self::gc::GcConfig;
 pub use self::rate_limit::RateLimitConfig;
@@ -148,6 +149,16 @@ pub struct Config {
     #[serde(default)]
     pub deb: DebConfig,
     #[serde(default)]
+    pub helm: HelmConfig,
+    #[serde(default)]
+    pub apk: ApkConfig,
+    #[serde(default)]
+    pub conda: CondaConfig,
+    #[serde(default)]
+    pub composer: ComposerConfig,
+    #[serde(default)]
+    pub swift: SwiftConfig,
+    #[serde(default)]
     pub auth: AuthConfig,
     #[serde(default)]
     pub rate_limit: RateLimitConfig,
@@ -256,6 +267,11 @@ impl Config {
                 RegistryType::Conan => self.conan.enabled = on,
                 RegistryType::Rpm => self.rpm.enabled = on,
                 RegistryType::Deb => self.deb.enabled = on,
+                RegistryType::Helm => self.helm.enabled = on,
+                RegistryType::Apk => self.apk.enabled = on,
+                RegistryType::Conda => self.conda.enabled = on,
+                RegistryType::Composer => self.composer.enabled = on,
+                RegistryType::Swift => self.swift.enabled = on,
             }
         }
     }
@@ -308,6 +324,21 @@ impl Config {
         if self.deb.enabled {
             set.insert(RegistryType::Deb);
         }
+        if self.helm.enabled {
+            set.insert(RegistryType::Helm);
+        }
+        if self.apk.enabled {
+            set.insert(RegistryType::Apk);
+        }
+        if self.conda.enabled {
+            set.insert(RegistryType::Conda);
+        }
+        if self.composer.enabled {
+            set.insert(RegistryType::Composer);
+        }
+        if self.swift.enabled {
+            set.insert(RegistryType::Swift);
+        }
         if set.is_empty() {
             tracing::warn!("No registries enabled! All registries are disabled.");
         }
@@ -340,6 +371,11 @@ impl Config {
             RegistryType::Conan => self.conan.enabled && self.conan.proxy.is_some(),
             RegistryType::Rpm => self.rpm.enabled && !self.rpm.proxies.is_empty(),
             RegistryType::Deb => self.deb.enabled && !self.deb.proxies.is_empty(),
+            RegistryType::Helm => self.helm.enabled && !self.helm.proxies.is_empty(),
+            RegistryType::Apk => self.apk.enabled && !self.apk.proxies.is_empty(),
+            RegistryType::Conda => self.conda.enabled && !self.conda.proxies.is_empty(),
+            RegistryType::Composer => self.composer.enabled && self.composer.proxy.is_some(),
+            RegistryType::Swift => false, // SE-0292 registry is hosted-only
         }
     }
 
@@ -351,9 +387,9 @@ impl Config {
     /// `registry/docker.rs::resolve_quarantine`), so config validation and the
     /// runtime DigestStore gate compute the SAME value and cannot diverge — that
     /// divergence was the #765 bug (docker dropped from the hand-rolled chains).
-    /// `Raw` has no curation override and no quarantine-enforcing path → always
-    /// `Off` (counting it would be a phantom control: `any_quarantine_active`
-    /// true while nothing enforces).
+    /// `Raw` and `Swift` have no curation override and no quarantine-enforcing
+    /// path → always `Off` (counting them would be a phantom control:
+    /// `any_quarantine_active` true while nothing enforces).
     fn quarantine_mode_for(&self, rt: RegistryType) -> crate::digest_quarantine::QuarantineMode {
         use crate::digest_quarantine::QuarantineMode;
         let global = self.curation.quarantine.as_ref();
@@ -372,9 +408,13 @@ impl Config {
             RegistryType::Conan => self.curation.conan.quarantine.as_ref(),
             RegistryType::Rpm => self.curation.rpm.quarantine.as_ref(),
             RegistryType::Deb => self.curation.deb.quarantine.as_ref(),
-            // Raw is hosted-only: no curation override, no quarantine gate in
-            // its handlers (quarantine gates proxy downloads).
-            RegistryType::Raw => return QuarantineMode::Off,
+            RegistryType::Helm => self.curation.helm.quarantine.as_ref(),
+            RegistryType::Apk => self.curation.apk.quarantine.as_ref(),
+            RegistryType::Conda => self.curation.conda.quarantine.as_ref(),
+            RegistryType::Composer => self.curation.composer.quarantine.as_ref(),
+            // Raw and Swift are hosted-only: no curation override, no quarantine
+            // gate in their handlers (quarantine gates proxy downloads).
+            RegistryType::Raw | RegistryType::Swift => return QuarantineMode::Off,
         };
         per.or(global).cloned().unwrap_or(QuarantineMode::Off)
     }
@@ -395,6 +435,18 @@ impl Config {
             .any(|&rt| self.quarantine_mode_for(rt) != QuarantineMode::Off)
     }
 
+    /// Where `nora vulndb import` writes and the vulnerability filter reads the
+    /// OSV database: `curation.vulnerabilities.db_path`, else under `storage.path`.
+    pub fn vulndb_path(&self) -> String {
+        match self.curation.vulnerabilities.db_path {
+            Some(ref path) => path.clone(),
+            None => std::path::Path::new(&self.storage.path)
+                .join("vulndb/osv.json")
+                .to_string_lossy()
+                .into_owned(),
+        }
+    }
+
     /// Warn if legacy NORA_*_ENABLED env vars are set while using the new
     /// `[registries].enable` or `NORA_REGISTRIES_ENABLE`.
     fn warn_legacy_env_vars_if_present() {
@@ -532,6 +584,7 @@ impl Config {
             ("nuget", self.nuget.proxy.as_deref()),
             ("pub", self.pub_dart.proxy.as_deref()),
             ("conan", self.conan.proxy.as_deref()),
+            ("composer", self.composer.proxy.as_deref()),
         ];
         for (name, url) in simple {
             if let Some(url) = url {
@@ -563,8 +616,16 @@ impl Config {
             }
         }
 
-        // RPM/DEB per-repo proxies: BTreeMap<repo, RepoProxyEntry>
-        for (name, proxies) in [("rpm", &self.rpm.proxies), ("deb", &self.deb.proxies)] {
+        // RPM/DEB/Helm/APK/Conda per-repo proxies and Go checksum databases:
+        // BTreeMap<name, RepoProxyEntry>
+        for (name, proxies) in [
+            ("rpm", &self.rpm.proxies),
+            ("deb", &self.deb.proxies),
+            ("helm", &self.helm.proxies),
+            ("apk", &self.apk.proxies),
+            ("conda", &self.conda.proxies),
+            ("go", &self.go.sumdbs),
+        ] {
             for entry in proxies.values() {
                 if let Some(host) = extract_host(entry.url()) {
                     result.push((name.to_string(), host));
@@ -797,10 +858,13 @@ impl Config {
                 || self.curation.min_release_age.is_some()
                 || quarantine_active
                 || !self.curation.internal_namespaces.is_empty()
-                || self.curation.require_integrity;
+                || self.curation.require_integrity
+                || self.curation.vulnerabilities.enabled
+                || self.curation.licenses.enabled
+                || self.curation.typosquat.enabled;
             if !any_control {
                 errors.push(
-                    "curation.mode=enforce but no active control is configured — enforce would block nothing. Set at least one of: allowlist_path, blocklist_path, min_release_age, quarantine, internal_namespaces, or require_integrity.".to_string(),
+                    "curation.mode=enforce but no active control is configured — enforce would block nothing. Set at least one of: allowlist_path, blocklist_path, min_release_age, quarantine, internal_namespaces, require_integrity, vulnerabilities.enabled, licenses.enabled, or typosquat.enabled.".to_string(),
                 );
             }
             if let Some(ref path) = self.curation.allowlist_path {
@@ -812,6 +876,113 @@ impl Config {
                 }
             }
         }
+        // 8a. Vulnerability filter: the database comes from `nora vulndb import`;
+        // waivers must name a real registry and a parseable expiry date.
+        let vulns = &self.curation.vulnerabilities;
+        if vulns.enabled && !std::path::Path::new(&self.vulndb_path()).exists() {
+            let msg = format!(
+                "curation.vulnerabilities is enabled but the database {} does not exist — run `nora vulndb import <osv-dump.zip>`",
+                self.vulndb_path()
+            );
+            if self.curation.mode == CurationMode::Enforce {
+                errors.push(msg);
+            } else {
+                warnings.push(msg);
+            }
+        }
+        for (i, waiver) in vulns.waivers.iter().enumerate() {
+            if waiver.id.is_empty() {
+                errors.push(format!(
+                    "curation.vulnerabilities.waivers[{}]: id must not be empty",
+                    i
+                ));
+            }
+            if let Some(ref registry) = waiver.registry {
+                if RegistryType::from_str_opt(registry).is_none() {
+                    errors.push(format!(
+                        "curation.vulnerabilities.waivers[{}]: unknown registry \"{}\"",
+                        i, registry
+                    ));
+                }
+            }
+            if let Some(ref expires) = waiver.expires {
+                if chrono::NaiveDate::parse_from_str(expires, "%Y-%m-%d").is_err() {
+                    errors.push(format!(
+                        "curation.vulnerabilities.waivers[{}]: expires \"{}\" is not a YYYY-MM-DD date",
+                        i, expires
+                    ));
+                }
+            }
+        }
+        // 8b. License filter: with no patterns and unknown licenses allowed it
+        // can never match; an empty pattern would never match either.
+        let licenses = &self.curation.licenses;
+        if licenses.enabled
+            && licenses.allow.is_empty()
+            && licenses.deny.is_empty()
+            && licenses.allow_unknown
+        {
+            warnings.push(
+                "curation.licenses is enabled but allow and deny are empty and allow_unknown=true — the license filter will never block".to_string(),
+            );
+        }
+        if licenses
+            .allow
+            .iter()
+            .chain(&licenses.deny)
+            .any(|p| p.trim().is_empty())
+        {
+            errors.push("curation.licenses: allow/deny patterns must not be empty".to_string());
+        }
+        // 8c. Typosquat filter: a missing corpus is fatal in enforce mode like a
+        // missing allowlist; without any corpus or scoped namespace it compares
+        // against nothing.
+        let typosquat = &self.curation.typosquat;
+        if typosquat.enabled {
+            let c = &self.curation;
+            let corpora = [
+                ("typosquat.corpus_path", &typosquat.corpus_path),
+                ("npm.typosquat_corpus", &c.npm.typosquat_corpus),
+                ("pypi.typosquat_corpus", &c.pypi.typosquat_corpus),
+                ("cargo.typosquat_corpus", &c.cargo.typosquat_corpus),
+                ("go.typosquat_corpus", &c.go.typosquat_corpus),
+                ("docker.typosquat_corpus", &c.docker.typosquat_corpus),
+                ("maven.typosquat_corpus", &c.maven.typosquat_corpus),
+                ("gems.typosquat_corpus", &c.gems.typosquat_corpus),
+                ("terraform.typosquat_corpus", &c.terraform.typosquat_corpus),
+                ("ansible.typosquat_corpus", &c.ansible.typosquat_corpus),
+                ("nuget.typosquat_corpus", &c.nuget.typosquat_corpus),
+                ("pub.typosquat_corpus", &c.pub_dart.typosquat_corpus),
+                ("conan.typosquat_corpus", &c.conan.typosquat_corpus),
+                ("helm.typosquat_corpus", &c.helm.typosquat_corpus),
+                ("apk.typosquat_corpus", &c.apk.typosquat_corpus),
+                ("conda.typosquat_corpus", &c.conda.typosquat_corpus),
+                ("composer.typosquat_corpus", &c.composer.typosquat_corpus),
+            ];
+            let mut configured = false;
+            for (key, path) in corpora {
+                let Some(path) = path else { continue };
+                configured = true;
+                if !std::path::Path::new(path).exists() {
+                    let msg = format!("curation.{}=\"{}\" does not exist", key, path);
+                    if c.mode == CurationMode::Enforce {
+                        errors.push(msg);
+                    } else {
+                        warnings.push(msg);
+                    }
+                }
+            }
+            let scoped_namespace = c
+                .internal_namespaces
+                .iter()
+                .any(|ns| ns.ends_with("/*") || ns.ends_with("/**"));
+            if !configured && !scoped_namespace {
+                warnings.push(
+                    "curation.typosquat is enabled but no corpus is configured — the typosquat filter will never block".to_string(),
+                );
+            }
+        }
+
         if self.curation.bypass_token.is_some() && env::var("NORA_CURATION_BYPASS_TOKEN").is_err() {
             warnings.push(
                 "curation.bypass_token is set in config file — consider using NORA_CURATION_BYPASS_TOKEN env var instead".to_string(),
@@ -901,6 +1072,74 @@ impl Config {
             );
         }
 
+        // 9c. Helm proxy repos share the `/helm/` namespace with the hosted repo's
+        // own paths — a repo named after one of them would be unreachable.
+        for repo in self.helm.proxies.keys() {
+            if matches!(repo.as_str(), "api" | "charts" | "index.yaml")
+                || repo.is_empty()
+                || repo.contains('/')
+                || repo.starts_with('.')
+            {
+                errors.push(format!(
+                    "helm.proxies: \"{}\" is not a valid repository name (reserved or not a single path segment)",
+                    repo
+                ));
+            }
+        }
+
+        // 9d. The apk key name is a file name on clients and a tar entry name
+        // in every signed index (`.SIGN.RSA256.{name}` within ustar's 100 bytes).
+        let key_name = &self.signing.apk_key_name;
+        if self.apk.enabled
+            && (key_name.is_empty()
+                || !key_name.is_ascii()
+                || key_name.contains(['/', '\\', ' '])
+                || key_name.starts_with('.')
+                || key_name.len() > 80)
+        {
+            errors.push(format!(
+                "signing.apk_key_name: \"{}\" is not a valid key file name",
+                key_name
+            ));
+        }
+
+        // 9e. A cosign policy that trusts nothing blocks every pull (enforce) or
+        // logs every pull (audit); keyless needs its roots of trust.
+        for (i, upstream) in self.docker.upstreams.iter().enumerate() {
+            let Some(ref policy) = upstream.cosign else {
+                continue;
+            };
+            if policy.keys.is_empty() && policy.identities.is_empty() {
+                errors.push(format!(
+                    "docker.upstreams[{}].cosign: set keys or identities to trust",
+                    i
+                ));
+            }
+            if !policy.identities.is_empty()
+                && (policy.fulcio_roots.is_empty() || policy.rekor_keys.is_empty())
+            {
+                errors.push(format!(
+                    "docker.upstreams[{}].cosign: identities require fulcio_roots and rekor_keys",
+                    i
+                ));
+            }
+            for entry in policy
+                .keys
+                .iter()
+                .chain(&policy.fulcio_roots)
+                .chain(&policy.rekor_keys)
+            {
+                if !entry.trim_start().starts_with("-----BEGIN")
+                    && !std::path::Path::new(entry).is_file()
+                {
+                    errors.push(format!(
+                        "docker.upstreams[{}].cosign: key file {} not found",
+                        i, entry
+                    ));
+                }
+            }
+        }
+
         // 10. [registries].enable validation
         if let Some(ref section) = self.registries {
             if let Some(ref spec) = section.enable {
@@ -1028,6 +1267,11 @@ impl Config {
         self.conan.apply_env_overrides();
         self.rpm.apply_env_overrides();
         self.deb.apply_env_overrides();
+        self.helm.apply_env_overrides();
+        self.apk.apply_env_overrides();
+        self.conda.apply_env_overrides();
+        self.composer.apply_env_overrides();
+        self.swift.apply_env_overrides();
 
         // Rate limit, GC, retention
         self.rate_limit.apply_env_overrides();
@@ -1651,6 +1895,11 @@ mod tests {
         assert_serde_default_eq_default::<ConanConfig>("conan");
         assert_serde_default_eq_default::<RpmConfig>("rpm");
         assert_serde_default_eq_default::<DebConfig>("deb");
+        assert_serde_default_eq_default::<HelmConfig>("helm");
+        assert_serde_default_eq_default::<ApkConfig>("apk");
+        assert_serde_default_eq_default::<CondaConfig>("conda");
+        assert_serde_default_eq_default::<ComposerConfig>("composer");
+        assert_serde_default_eq_default::<SwiftConfig>("swift");
         assert_serde_default_eq_default::<SigningConfig>("signing");
 
         // Whole-Config fallback agrees with deserializing an empty file.
@@ -2488,6 +2737,11 @@ mod tests {
             QuarantineMode::Off,
             "raw must never report an active quarantine"
         );
+        assert_eq!(
+            config.quarantine_mode_for(RegistryType::Swift),
+            QuarantineMode::Off,
+            "swift is hosted-only and never quarantines"
+        );
     }
 
     #[test]
@@ -2526,6 +2780,10 @@ mod tests {
             &mut config.curation.conan,
             &mut config.curation.rpm,
             &mut config.curation.deb,
+            &mut config.curation.helm,
+            &mut config.curation.apk,
+            &mut config.curation.conda,
+            &mut config.curation.composer,
         ] {
             o.quarantine = Some(QuarantineMode::Off);
         }
@@ -3255,6 +3513,145 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_helm_proxy_reserved_names() {
+        let mut config = Config::default();
+        for name in ["api", "charts", "index.yaml", "a/b", ".hidden"] {
+            config.helm.proxies.insert(
+                name.to_string(),
+                RepoProxyEntry::Simple("https://charts.example.com".to_string()),
+            );
+        }
+        config.helm.proxies.insert(
+            "bitnami".to_string(),
+            RepoProxyEntry::Simple("https://charts.bitnami.com/bitnami".to_string()),
+        );
+        let (_, errors) = config.validate();
+        assert_eq!(errors.len(), 5, "{:?}", errors);
+        assert!(errors.iter().all(|e| e.starts_with("helm.proxies")));
+    }
+
+    #[test]
+    fn test_validate_vulnerability_config() {
+        use crate::config::VulnerabilityWaiver;
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.curation.mode = CurationMode::Enforce;
+        config.curation.vulnerabilities.enabled = true;
+        config.curation.vulnerabilities.db_path =
+            Some(dir.path().join("osv.json").to_str().unwrap().to_string());
+        let (_, errors) = config.validate();
+        assert!(
+            errors.iter().any(|e| e.contains("nora vulndb import")),
+            "{errors:?}"
+        );
+        // The filter alone is a control that makes enforce meaningful.
+        assert!(!errors.iter().any(|e| e.contains("no active control")));
+
+        std::fs::write(dir.path().join("osv.json"), "{}").unwrap();
+        config.curation.vulnerabilities.waivers = vec![VulnerabilityWaiver {
+            id: "GHSA-1".to_string(),
+            registry: Some("nope".to_string()),
+            package: None,
+            expires: Some("31/12/2026".to_string()),
+            reason: None,
+        }];
+        let (_, errors) = config.validate();
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        assert!(errors
+            .iter()
+            .all(|e| e.starts_with("curation.vulnerabilities.waivers[0]")));
+    }
+
+    #[test]
+    fn test_validate_license_config() {
+        let mut config = Config::default();
+        config.curation.mode = CurationMode::Enforce;
+        config.curation.licenses.enabled = true;
+        let (warnings, errors) = config.validate();
+        assert!(!errors.iter().any(|e| e.contains("no active control")));
+        assert!(warnings.iter().any(|w| w.contains("never block")));
+
+        config.curation.licenses.deny = vec!["GPL-*".to_string(), " ".to_string()];
+        let (warnings, errors) = config.validate();
+        assert!(!warnings.iter().any(|w| w.contains("never block")));
+        assert!(errors.iter().any(|e| e.contains("must not be empty")));
+    }
+
+    #[test]
+    fn test_validate_typosquat_config() {
+        let mut config = Config::default();
+        config.curation.mode = CurationMode::Enforce;
+        config.curation.typosquat.enabled = true;
+        let (warnings, errors) = config.validate();
+        assert!(!errors.iter().any(|e| e.contains("no active control")));
+        assert!(warnings.iter().any(|w| w.contains("never block")));
+
+        config.curation.npm.typosquat_corpus = Some("/nonexistent/npm.txt".to_string());
+        let (warnings, errors) = config.validate();
+        assert!(!warnings.iter().any(|w| w.contains("never block")));
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("curation.npm.typosquat_corpus")));
+
+        config.curation.mode = CurationMode::Audit;
+        let (warnings, errors) = config.validate();
+        assert!(!errors.iter().any(|e| e.contains("typosquat_corpus")));
+        assert!(warnings
+            .iter()
+            .any(|w| w.contains("curation.npm.typosquat_corpus")));
+    }
+
+    #[test]
+    fn test_validate_docker_cosign_policy() {
+        use crate::config::registry::{CosignIdentity, CosignPolicy};
+        let with_policy = |policy: CosignPolicy| {
+            let mut config = Config::default();
+            config.docker.upstreams[0].cosign = Some(policy);
+            config.validate().1
+        };
+        let inline = "-----BEGIN PUBLIC KEY-----\n-----END PUBLIC KEY-----".to_string();
+
+        assert!(with_policy(CosignPolicy {
+            keys: vec![inline.clone()],
+            ..Default::default()
+        })
+        .is_empty());
+        let errors = with_policy(CosignPolicy::default());
+        assert!(errors.iter().any(|e| e.contains("set keys or identities")));
+        let errors = with_policy(CosignPolicy {
+            keys: vec!["/nonexistent/cosign.pub".to_string()],
+            ..Default::default()
+        });
+        assert!(errors.iter().any(|e| e.contains("not found")));
+        let errors = with_policy(CosignPolicy {
+            identities: vec![CosignIdentity {
+                issuer: "https://accounts.google.com".to_string(),
+                subject: "ci@example.com".to_string(),
+            }],
+            fulcio_roots: vec![inline],
+            ..Default::default()
+        });
+        assert!(errors.iter().any(|e| e.contains("require fulcio_roots")));
+    }
+
+    #[test]
+    fn test_validate_apk_key_name() {
+        let mut config = Config::default();
+        config.apk.enabled = true;
+        let (_, errors) = config.validate();
+        assert!(errors.is_empty(), "{:?}", errors);
+        for bad in ["", "../nora.rsa.pub", ".hidden", "my key.pub"] {
+            config.signing.apk_key_name = bad.to_string();
+            let (_, errors) = config.validate();
+            assert!(
+                errors.iter().any(|e| e.starts_with("signing.apk_key_name")),
+                "{bad}: {:?}",
+                errors
+            );
+        }
+    }
+
     #[test]
     fn test_validate_no_warn_on_normal_proxies() {
         let mut config = Config::default();
@@ -0,0 +1,90 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+use crate::secrets::ProtectedString;
+use serde::{Deserialize, Serialize};
+use std::env;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComposerConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Upstream Composer repository (Packagist v2 layout: `packages.json` and
+    /// `/p2/{vendor}/{package}.json`). `None` makes the registry hosted-only.
+    #[serde(default = "default_composer_proxy")]
+    pub proxy: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub proxy_auth: Option<ProtectedString>,
+    #[serde(default = "super::super::default_timeout")]
+    pub proxy_timeout: u64,
+    #[serde(default = "super::super::default_metadata_ttl")]
+    pub metadata_ttl: i64,
+    #[serde(default = "super::super::default_true")]
+    pub serve_stale: bool,
+    #[serde(default = "default_composer_max_file_size")]
+    pub max_file_size: u64,
+}
+
+fn default_composer_proxy() -> Option<String> {
+    Some("https://repo.packagist.org".to_string())
+}
+
+fn default_composer_max_file_size() -> u64 {
+    268_435_456 // 256MiB
+}
+
+impl Default for ComposerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            proxy: default_composer_proxy(),
+            proxy_auth: None,
+            proxy_timeout: 30,
+            metadata_ttl: 300,
+            serve_stale: true,
+            max_file_size: default_composer_max_file_size(),
+        }
+    }
+}
+
+impl ComposerConfig {
+    pub(in crate::config) fn apply_env_overrides(&mut self) {
+        if let Ok(val) = env::var("NORA_COMPOSER_ENABLED") {
+            self.enabled = val.to_lowercase() == "true" || val == "1";
+        }
+        if let Ok(val) = env::var("NORA_COMPOSER_PROXY") {
+            self.proxy = if val.is_empty() { None } else { Some(val) };
+        }
+        if let Ok(val) = env::var("NORA_COMPOSER_PROXY_AUTH") {
+            self.proxy_auth = if val.is_empty() {
+                None
+            } else {
+                Some(ProtectedString::new(val))
+            };
+        }
+        if let Ok(val) = env::var("NORA_COMPOSER_PROXY_TIMEOUT") {
+            super::super::parse_env_warn(
+                "NORA_COMPOSER_PROXY_TIMEOUT",
+                &val,
+                &mut self.proxy_timeout,
+            );
+        }
+        if let Ok(val) = env::var("NORA_COMPOSER_METADATA_TTL") {
+            super::super::parse_env_warn(
+                "NORA_COMPOSER_METADATA_TTL",
+                &val,
+                &mut self.metadata_ttl,
+            );
+        }
+        if let Ok(val) = env::var("NORA_COMPOSER_SERVE_STALE") {
+            self.serve_stale = !matches!(val.as_str(), "false" | "0");
+        }
+        if let Ok(val) = env::var("NORA_COMPOSER_MAX_FILE_SIZE") {
+            super::super::parse_env_warn(
+                "NORA_COMPOSER_MAX_FILE_SIZE",
+                &val,
+                &mut self.max_file_size,
+            );
+        }
+    }
+}
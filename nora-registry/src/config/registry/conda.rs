@@ -0,0 +1,64 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+use super::RepoProxyEntry;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CondaConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_conda_max_file_size")]
+    pub max_file_size: u64,
+    /// Pull-through channels: local channel name → upstream channel URL (the
+    /// directory holding `channeldata.json` and the subdirs, e.g.
+    /// `https://conda.anaconda.org/conda-forge`). A channel listed here is
+    /// read-only; its repodata is re-fetched after `metadata_ttl`, packages
+    /// are cached forever. Every other channel name is hosted.
+    #[serde(default)]
+    pub proxies: BTreeMap<String, RepoProxyEntry>,
+    #[serde(default = "super::super::default_timeout")]
+    pub proxy_timeout: u64,
+    /// Staleness window (seconds) for proxied `repodata.json`/`channeldata.json`;
+    /// a non-positive value revalidates every pull.
+    #[serde(default = "super::super::default_metadata_ttl")]
+    pub metadata_ttl: i64,
+}
+
+fn default_conda_max_file_size() -> u64 {
+    1_073_741_824 // 1GiB — CUDA/ML builds run to hundreds of MB
+}
+
+impl Default for CondaConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_file_size: default_conda_max_file_size(),
+            proxies: BTreeMap::new(),
+            proxy_timeout: 30,
+            metadata_ttl: 300,
+        }
+    }
+}
+
+impl CondaConfig {
+    pub(in crate::config) fn apply_env_overrides(&mut self) {
+        if let Ok(val) = env::var("NORA_CONDA_ENABLED") {
+            self.enabled = val.to_lowercase() == "true" || val == "1";
+        }
+        if let Ok(val) = env::var("NORA_CONDA_MAX_FILE_SIZE") {
+            super::super::parse_env_warn("NORA_CONDA_MAX_FILE_SIZE", &val, &mut self.max_file_size);
+        }
+        if let Ok(val) = env::var("NORA_CONDA_PROXIES") {
+            self.proxies = super::parse_repo_proxies_env(&val);
+        }
+        if let Ok(val) = env::var("NORA_CONDA_PROXY_TIMEOUT") {
+            super::super::parse_env_warn("NORA_CONDA_PROXY_TIMEOUT", &val, &mut self.proxy_timeout);
+        }
+        if let Ok(val) = env::var("NORA_CONDA_METADATA_TTL") {
+            super::super::parse_env_warn("NORA_CONDA_METADATA_TTL", &val, &mut self.metadata_ttl);
+        }
+    }
+}
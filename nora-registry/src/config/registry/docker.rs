@@ -40,6 +40,12 @@ pub struct DockerConfig {
     pub metadata_ttl: i64,
     #[serde(default = "super::super::default_true")]
     pub serve_stale: bool,
+    /// Seconds `_catalog` and `tags/list` answer from the in-memory index before
+    /// it is rebuilt from storage; bounds how long writes this process never saw
+    /// (another replica, `nora gc --apply`, restore, import) stay invisible.
+    /// `0` rebuilds on every call.
+    #[serde(default = "default_docker_catalog_ttl")]
+    pub catalog_ttl: u64,
     /// What to do when an image name doesn't match any upstream prefix.
     /// `allow` (default) = fall through to first upstream; `deny` = reject with 403.
     #[serde(default)]
@@ -57,6 +63,65 @@ pub struct DockerUpstream {
     pub namespace: Option<String>,
     #[serde(default)]
     pub prefix: Option<String>,
+    /// Cosign signature policy for manifests pulled from this upstream.
+    /// `None` = no signature check.
+    #[serde(default)]
+    pub cosign: Option<CosignPolicy>,
+}
+
+/// What a failed cosign check does: `enforce` (default) answers 403 and caches
+/// nothing; `audit` logs and counts the failure but serves the image.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SignatureMode {
+    #[default]
+    Enforce,
+    Audit,
+}
+
+impl SignatureMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Enforce => "enforce",
+            Self::Audit => "audit",
+        }
+    }
+}
+
+/// Cosign verification policy for one Docker upstream.
+///
+/// A manifest pulled from the upstream must carry a cosign signature (the
+/// `sha256-<digest>.sig` tag, or an OCI referrer of the cosign signature
+/// artifact type) that verifies offline against one of `keys`, or against a
+/// Fulcio certificate matching one of `identities`. Key material entries are
+/// a PEM file path or inline PEM.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CosignPolicy {
+    #[serde(default)]
+    pub mode: SignatureMode,
+    /// Upstream repository globs the policy applies to (`myorg/**`, `library/nginx`);
+    /// empty = every repository on the upstream.
+    #[serde(default)]
+    pub repositories: Vec<String>,
+    /// Trusted public keys (ECDSA P-256/P-384, as written by `cosign generate-key-pair`).
+    #[serde(default)]
+    pub keys: Vec<String>,
+    /// Keyless signers: the Fulcio certificate's OIDC issuer and subject (SAN).
+    #[serde(default)]
+    pub identities: Vec<CosignIdentity>,
+    /// Fulcio root certificates that keyless signing certificates must chain to.
+    #[serde(default)]
+    pub fulcio_roots: Vec<String>,
+    /// Rekor public keys. When set, every signature must carry a Rekor bundle whose
+    /// signed entry timestamp verifies against one of them; required for keyless.
+    #[serde(default)]
+    pub rekor_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CosignIdentity {
+    pub issuer: String,
+    pub subject: String,
 }
 
 impl DockerUpstream {
@@ -97,6 +162,10 @@ fn default_docker_metadata_ttl() -> i64 {
     -1
 }
 
+fn default_docker_catalog_ttl() -> u64 {
+    30
+}
+
 /// Default Docker upstream. Single source for the serde field-default and the
 /// `Default` impl so a present-but-empty `[docker]` table keeps the upstream
 /// instead of silently going local-only (the npm/pypi `#[serde(default)]`
@@ -107,6 +176,7 @@ fn default_docker_upstreams() -> Vec<DockerUpstream> {
         auth: None,
         namespace: None,
         prefix: None,
+        cosign: None,
     }]
 }
 
@@ -118,6 +188,7 @@ impl Default for DockerConfig {
             read_timeout: 60,
             metadata_ttl: -1,
             serve_stale: true,
+            catalog_ttl: default_docker_catalog_ttl(),
             default_action: DefaultAction::default(),
             upstreams: default_docker_upstreams(),
         }
@@ -142,6 +213,9 @@ impl DockerConfig {
         if let Ok(val) = env::var("NORA_DOCKER_METADATA_TTL") {
             super::super::parse_env_warn("NORA_DOCKER_METADATA_TTL", &val, &mut self.metadata_ttl);
         }
+        if let Ok(val) = env::var("NORA_DOCKER_CATALOG_TTL") {
+            super::super::parse_env_warn("NORA_DOCKER_CATALOG_TTL", &val, &mut self.catalog_ttl);
+        }
         if let Ok(val) = env::var("NORA_DOCKER_SERVE_STALE") {
             self.serve_stale = !matches!(val.as_str(), "false" | "0");
         }
@@ -185,6 +259,7 @@ impl DockerConfig {
                         auth,
                         namespace: None,
                         prefix,
+                        cosign: None,
                     }
                 })
                 .collect();
@@ -1,8 +1,10 @@
 // Copyright (c) 2026 The NORA Authors
 // SPDX-License-Identifier: MIT
 
+use super::RepoProxyEntry;
 use crate::secrets::ProtectedString;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::env;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +26,12 @@ pub struct GoConfig {
     /// content-addressed and always immutable.
     #[serde(default = "super::super::default_metadata_ttl")]
     pub metadata_ttl: i64,
+    /// Checksum databases proxied under `/go/sumdb/{name}/`: sumdb name → upstream URL.
+    /// Lookups and tiles are cached forever, so previously verified modules keep
+    /// verifying offline; names not listed here answer 404 on `supported` and the
+    /// go command falls back to contacting the database directly.
+    #[serde(default = "default_go_sumdbs")]
+    pub sumdbs: BTreeMap<String, RepoProxyEntry>,
 }
 
 fn default_go_proxy() -> Option<String> {
@@ -34,6 +42,13 @@ pub(in crate::config) fn default_go_zip_timeout() -> u64 {
     120
 }
 
+fn default_go_sumdbs() -> BTreeMap<String, RepoProxyEntry> {
+    BTreeMap::from([(
+        "sum.golang.org".to_string(),
+        RepoProxyEntry::Simple("https://sum.golang.org".to_string()),
+    )])
+}
+
 fn default_go_max_zip_size() -> u64 {
     104_857_600 // 100MB
 }
@@ -48,6 +63,7 @@ impl Default for GoConfig {
             proxy_timeout_zip: 120,
             max_zip_size: 104_857_600,
             metadata_ttl: 300,
+            sumdbs: default_go_sumdbs(),
         }
     }
 }
@@ -83,5 +99,8 @@ impl GoConfig {
         if let Ok(val) = env::var("NORA_GO_METADATA_TTL") {
             super::super::parse_env_warn("NORA_GO_METADATA_TTL", &val, &mut self.metadata_ttl);
         }
+        if let Ok(val) = env::var("NORA_GO_SUMDBS") {
+            self.sumdbs = super::parse_repo_proxies_env(&val);
+        }
     }
 }
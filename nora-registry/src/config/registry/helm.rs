@@ -0,0 +1,69 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+use super::RepoProxyEntry;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelmConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_helm_max_file_size")]
+    pub max_file_size: u64,
+    /// Pull-through chart repos: local repo name → upstream chart repo URL
+    /// (the directory holding its `index.yaml`). Served at `/helm/{repo}/`,
+    /// read-only; the index is re-fetched after `metadata_ttl`, charts are
+    /// cached forever.
+    #[serde(default)]
+    pub proxies: BTreeMap<String, RepoProxyEntry>,
+    #[serde(default = "super::super::default_timeout")]
+    pub proxy_timeout: u64,
+    /// Staleness window (seconds) for a proxied `index.yaml`; a non-positive
+    /// value revalidates every pull.
+    #[serde(default = "super::super::default_metadata_ttl")]
+    pub metadata_ttl: i64,
+    #[serde(default = "super::super::default_true")]
+    pub serve_stale: bool,
+}
+
+fn default_helm_max_file_size() -> u64 {
+    52_428_800 // 50MiB — charts are small; large ones vendor subcharts
+}
+
+impl Default for HelmConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_file_size: default_helm_max_file_size(),
+            proxies: BTreeMap::new(),
+            proxy_timeout: 30,
+            metadata_ttl: 300,
+            serve_stale: true,
+        }
+    }
+}
+
+impl HelmConfig {
+    pub(in crate::config) fn apply_env_overrides(&mut self) {
+        if let Ok(val) = env::var("NORA_HELM_ENABLED") {
+            self.enabled = val.to_lowercase() == "true" || val == "1";
+        }
+        if let Ok(val) = env::var("NORA_HELM_MAX_FILE_SIZE") {
+            super::super::parse_env_warn("NORA_HELM_MAX_FILE_SIZE", &val, &mut self.max_file_size);
+        }
+        if let Ok(val) = env::var("NORA_HELM_PROXIES") {
+            self.proxies = super::parse_repo_proxies_env(&val);
+        }
+        if let Ok(val) = env::var("NORA_HELM_PROXY_TIMEOUT") {
+            super::super::parse_env_warn("NORA_HELM_PROXY_TIMEOUT", &val, &mut self.proxy_timeout);
+        }
+        if let Ok(val) = env::var("NORA_HELM_METADATA_TTL") {
+            super::super::parse_env_warn("NORA_HELM_METADATA_TTL", &val, &mut self.metadata_ttl);
+        }
+        if let Ok(val) = env::var("NORA_HELM_SERVE_STALE") {
+            self.serve_stale = !matches!(val.as_str(), "false" | "0");
+        }
+    }
+}
@@ -42,7 +42,8 @@ impl RepoProxyEntry {
 }
 
 /// Parse `repo=url|auth,repo2=url` env form shared by `NORA_RPM_PROXIES` /
-/// `NORA_DEB_PROXIES`.
+/// `NORA_DEB_PROXIES` / `NORA_HELM_PROXIES` / `NORA_APK_PROXIES` /
+/// `NORA_CONDA_PROXIES` / `NORA_GO_SUMDBS`.
 pub(in crate::config) fn parse_repo_proxies_env(
     val: &str,
 ) -> std::collections::BTreeMap<String, RepoProxyEntry> {
@@ -89,12 +90,16 @@ mod repo_proxy_tests {
 }
 
 mod ansible;
+mod apk;
 mod cargo;
+mod composer;
 mod conan;
+mod conda;
 mod deb;
 mod docker;
 mod gems;
 mod go;
+mod helm;
 mod maven;
 mod npm;
 mod nuget;
@@ -102,17 +107,25 @@ mod pub_dart;
 mod pypi;
 mod raw;
 mod rpm;
+mod swift;
 mod terraform;
 
 pub use self::ansible::AnsibleConfig;
+pub use self::apk::ApkConfig;
 pub use self::cargo::CargoConfig;
+pub use self::composer::ComposerConfig;
 pub use self::conan::ConanConfig;
+pub use self::conda::CondaConfig;
 pub use self::deb::DebConfig;
 // Re-export all Docker types including extract_docker_namespace (public API surface)
 #[allow(unused_imports)]
-pub use self::docker::{extract_docker_namespace, DefaultAction, DockerConfig, DockerUpstream};
+pub use self::docker::{
+    extract_docker_namespace, CosignIdentity, CosignPolicy, DefaultAction, DockerConfig,
+    DockerUpstream, SignatureMode,
+};
 pub use self::gems::GemsConfig;
 pub use self::go::GoConfig;
+pub use self::helm::HelmConfig;
 #[allow(unused_imports)]
 pub use self::maven::{MavenConfig, MavenProxy, MavenProxyEntry};
 pub use self::npm::NpmConfig;
@@ -121,4 +134,5 @@ pub use self::pub_dart::PubDartConfig;
 pub use self::pypi::PypiConfig;
 pub use self::raw::RawConfig;
 pub use self::rpm::RpmConfig;
+pub use self::swift::SwiftConfig;
 pub use self::terraform::TerraformConfig;
@@ -0,0 +1,40 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+use serde::{Deserialize, Serialize};
+use std::env;
+
+/// Swift Package Registry (SE-0292). Hosted-only: SwiftPM resolves packages it
+/// cannot find here from their source repositories, so there is no upstream.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwiftConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Upper bound on a published source archive, in bytes.
+    #[serde(default = "default_swift_max_file_size")]
+    pub max_file_size: u64,
+}
+
+fn default_swift_max_file_size() -> u64 {
+    268_435_456 // 256MiB
+}
+
+impl Default for SwiftConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_file_size: default_swift_max_file_size(),
+        }
+    }
+}
+
+impl SwiftConfig {
+    pub(in crate::config) fn apply_env_overrides(&mut self) {
+        if let Ok(val) = env::var("NORA_SWIFT_ENABLED") {
+            self.enabled = val.to_lowercase() == "true" || val == "1";
+        }
+        if let Ok(val) = env::var("NORA_SWIFT_MAX_FILE_SIZE") {
+            super::super::parse_env_warn("NORA_SWIFT_MAX_FILE_SIZE", &val, &mut self.max_file_size);
+        }
+    }
+}
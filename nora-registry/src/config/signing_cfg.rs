@@ -8,7 +8,8 @@ use std::env;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SigningConfig {
-    /// Sign rpm/deb repository indexes. On by default: with local storage a
+    /// Sign rpm/deb repository indexes and hosted Terraform providers'
+    /// `SHA256SUMS`. On by default: with local storage a
     /// key is generated at first boot; signed and unsigned clients both keep
     /// working (unsigned clients simply don't fetch the signatures).
     #[serde(default = "default_signing_enabled")]
@@ -18,17 +19,32 @@ pub struct SigningConfig {
     /// (there is no local data directory to derive from).
     #[serde(default)]
     pub key_path: String,
+    /// Path of the PEM RSA key that signs apk `APKINDEX.tar.gz` (PKCS#8 or
+    /// PKCS#1 — an existing abuild key works). Empty = derive
+    /// `<storage.path>/.signing/nora-apk.rsa` for local storage.
+    #[serde(default)]
+    pub apk_key_path: String,
+    /// File name clients install the apk public key under
+    /// (`/etc/apk/keys/{apk_key_name}`); embedded in every index signature.
+    #[serde(default = "default_apk_key_name")]
+    pub apk_key_name: String,
 }
 
 fn default_signing_enabled() -> bool {
     true
 }
 
+fn default_apk_key_name() -> String {
+    "nora.rsa.pub".to_string()
+}
+
 impl Default for SigningConfig {
     fn default() -> Self {
         Self {
             enabled: true,
             key_path: String::new(),
+            apk_key_path: String::new(),
+            apk_key_name: default_apk_key_name(),
         }
     }
 }
@@ -41,5 +57,11 @@ impl SigningConfig {
         if let Ok(val) = env::var("NORA_SIGNING_KEY_PATH") {
             self.key_path = val;
         }
+        if let Ok(val) = env::var("NORA_SIGNING_APK_KEY_PATH") {
+            self.apk_key_path = val;
+        }
+        if let Ok(val) = env::var("NORA_SIGNING_APK_KEY_NAME") {
+            self.apk_key_name = val;
+        }
     }
 }
@@ -8,12 +8,18 @@
 //! - [`BlocklistFilter`] — blocks packages by name/version/registry (issue #186)
 //! - [`AllowlistFilter`] — default-deny approved packages (issue #188)
 //! - [`NamespaceFilter`] — namespace isolation, always active (issue #185)
+//! - [`VulnerabilityFilter`] — blocks versions affected by OSV advisories
+//! - [`LicenseFilter`] — blocks versions whose declared license the policy rejects
+//! - [`TyposquatFilter`] — blocks names that imitate corpus names (typosquatting)
 //! - [`CurationEngine`] that evaluates a chain of filters
 //! - [`BlockedResponse`] for generating 403 responses
 //! - [`CurationMetrics`] for raw counters
 
-use crate::config::{CurationConfig, CurationMode};
+use crate::config::{CurationConfig, CurationMode, VulnerabilityWaiver};
+use crate::license::LicensePolicy;
+use crate::typosquat::Corpus;
 use crate::validation::ends_with_ci;
+use crate::vulndb::{Severity, VulnDb};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use serde::Deserialize;
@@ -47,6 +53,9 @@ pub struct FilterRequest {
     pub bypass: bool,
     /// Publish date as Unix timestamp (seconds). None = unknown.
     pub publish_date: Option<i64>,
+    /// Declared license (SPDX expression or free text) from the package
+    /// metadata. None = unknown or not looked up.
+    pub license: Option<String>,
 }
 
 // ============================================================================
@@ -79,6 +88,13 @@ pub trait ProxyFilter: Send + Sync {
 
     /// Evaluate a request and return a decision.
     fn evaluate(&self, request: &FilterRequest) -> Decision;
+
+    /// Whether this filter's blocks are audit-only whatever the curation mode:
+    /// the block is reported with `audited=true` and the rest of the chain still
+    /// runs, so a later filter can still block the request.
+    fn audit_only(&self) -> bool {
+        false
+    }
 }
 
 // ============================================================================
@@ -151,7 +167,8 @@ impl CurationEngine {
     /// - **Off**: returns Allow immediately, no filters run, no metrics.
     /// - **Bypass**: returns Allow with a security warning log.
     /// - **Chain**: first Block or Allow wins; Skip continues.
-    /// - **Audit**: Block decisions are returned with `audited=true`.
+    /// - **Audit**: Block decisions are returned with `audited=true`, as are
+    ///   blocks from an [`audit_only`](ProxyFilter::audit_only) filter in any mode.
     /// - **Enforce**: Block decisions are final.
     /// - All Skip → Allow.
     pub fn evaluate(&self, request: &FilterRequest) -> EvaluationResult {
@@ -164,8 +181,9 @@ impl CurationEngine {
             Decision::Block { .. } => "block",
             Decision::Skip => "skip",
         };
+        let filter = result.decided_by.as_deref().unwrap_or("none");
         crate::metrics::CURATION_DECISIONS_TOTAL
-            .with_label_values(&[decision])
+            .with_label_values(&[decision, filter])
             .inc();
         result
     }
@@ -216,17 +234,26 @@ impl CurationEngine {
                 .fetch_add(1, Ordering::Relaxed);
         }
 
-        // Run filter chain
+        // Run filter chain. The first audit-only block is remembered and
+        // reported unless a later filter blocks for real.
+        let mut audit_hit = None;
         for filter in &self.filters {
             let decision = filter.evaluate(request);
             match &decision {
                 Decision::Allow => {
                     self.metrics.allowed.fetch_add(1, Ordering::Relaxed);
-                    return EvaluationResult {
+                    return audit_hit.unwrap_or_else(|| EvaluationResult {
                         decision,
                         decided_by: Some(filter.name().to_string()),
                         audited: false,
-                    };
+                    });
+                }
+                Decision::Block { .. } if filter.audit_only() => {
+                    audit_hit.get_or_insert_with(|| EvaluationResult {
+                        decision,
+                        decided_by: Some(filter.name().to_string()),
+                        audited: true,
+                    });
                 }
                 Decision::Block { .. } => {
                     let audited = self.config.mode == CurationMode::Audit;
@@ -247,11 +274,11 @@ impl CurationEngine {
 
         // All filters skipped → Allow
         self.metrics.allowed.fetch_add(1, Ordering::Relaxed);
-        EvaluationResult {
+        audit_hit.unwrap_or(EvaluationResult {
             decision: Decision::Allow,
             decided_by: None,
             audited: false,
-        }
+        })
     }
 }
 
@@ -275,6 +302,31 @@ pub fn check_download(
     name: &str,
     version: Option<&str>,
     publish_date: Option<i64>,
+) -> Option<Response> {
+    check_download_with_license(
+        engine,
+        bypass_token,
+        headers,
+        registry,
+        name,
+        version,
+        publish_date,
+        None,
+    )
+}
+
+/// [`check_download`] for registries that can supply the declared license
+/// (see [`license_policy_active`]), so the license filter has something to check.
+#[allow(clippy::too_many_arguments)]
+pub fn check_download_with_license(
+    engine: &CurationEngine,
+    bypass_token: Option<&str>,
+    headers: &axum::http::HeaderMap,
+    registry: RegistryType,
+    name: &str,
+    version: Option<&str>,
+    publish_date: Option<i64>,
+    license: Option<&str>,
 ) -> Option<Response> {
     let bypass = match bypass_token {
         Some(token) => headers
@@ -296,6 +348,7 @@ pub fn check_download(
         integrity: None,
         bypass,
         publish_date,
+        license: license.map(|l| l.to_string()),
     };
 
     let result = engine.evaluate(&request);
@@ -350,6 +403,7 @@ pub fn check_namespace_isolation(
         integrity: None,
         bypass: false,
         publish_date: None,
+        license: None,
     };
 
     if let Some(ref ns_filter) = engine.namespace_filter {
@@ -393,6 +447,7 @@ pub fn is_internal_namespace(engine: &CurationEngine, registry: RegistryType, na
         integrity: None,
         bypass: false,
         publish_date: None,
+        license: None,
     };
     matches!(ns_filter.evaluate(&request), Decision::Block { .. })
 }
@@ -405,6 +460,13 @@ pub fn namespace_filter_active(engine: &CurationEngine) -> bool {
     engine.namespace_filter.is_some()
 }
 
+/// True when the license filter is in the chain and curation is not off.
+/// Handlers use it to decide whether to look up (and, if needed, fetch) the
+/// declared license before calling [`check_download_with_license`].
+pub fn license_policy_active(engine: &CurationEngine) -> bool {
+    engine.is_active() && engine.filters.iter().any(|f| f.name() == "license")
+}
+
 /// Extract publish date from file mtime. Used for hosted registries, and — when
 /// `server.trust_upstream_dates = false` — for proxy registries too (#513): the
 /// NORA-cache mtime is NORA-controlled, so it cannot be spoofed by an upstream
@@ -485,6 +547,7 @@ pub fn verify_integrity(
         integrity: Some(integrity),
         bypass: false, // bypass already checked in pre-download phase
         publish_date: None,
+        license: None,
     };
 
     let result = engine.evaluate(&request);
@@ -557,6 +620,7 @@ pub fn verify_integrity_by_hash(
         integrity: Some(hash.to_string()),
         bypass: false,
         publish_date: None,
+        license: None,
     };
 
     let result = engine.evaluate(&request);
@@ -760,7 +824,7 @@ pub struct BlocklistRule {
 /// - `"foo*"` — prefix match
 /// - `"*foo"` — suffix match
 /// - exact string comparison otherwise
-fn glob_match(pattern: &str, value: &str) -> bool {
+pub(crate) fn glob_match(pattern: &str, value: &str) -> bool {
     if pattern == "*" {
         return true;
     }
@@ -1026,6 +1090,82 @@ impl ProxyFilter for NamespaceFilter {
     }
 }
 
+// ============================================================================
+// TyposquatFilter — block names that imitate well-known or internal names
+// ============================================================================
+
+/// Blocks names that resemble, but are not, a name in the registry's corpus
+/// (`lodahs` for `lodash`, `@acme-corp/x` for `@acmecorp/*`) — see
+/// [`crate::typosquat`] for what counts as a lookalike.
+///
+/// Runs on metadata and download requests alike; registries without a corpus
+/// skip.
+pub struct TyposquatFilter {
+    corpora: std::collections::HashMap<RegistryType, Corpus>,
+    max_distance: usize,
+    min_length: usize,
+    /// `action = "audit"`: report lookalikes as audit decisions, never block.
+    audit: bool,
+}
+
+impl TyposquatFilter {
+    pub fn new(config: &crate::config::TyposquatConfig) -> Self {
+        Self {
+            corpora: std::collections::HashMap::new(),
+            audit: config.action == crate::config::FilterAction::Audit,
+            max_distance: config.max_distance,
+            min_length: config.min_length,
+        }
+    }
+
+    /// Compare one registry's requests with `corpus`.
+    pub fn add_corpus(&mut self, registry: RegistryType, corpus: Corpus) {
+        self.corpora.insert(registry, corpus);
+    }
+
+    /// Number of registries with a corpus.
+    pub fn registry_count(&self) -> usize {
+        self.corpora.len()
+    }
+}
+
+impl ProxyFilter for TyposquatFilter {
+    fn name(&self) -> &'static str {
+        "typosquat"
+    }
+
+    fn evaluate(&self, request: &FilterRequest) -> Decision {
+        let Some(corpus) = self.corpora.get(&request.registry) else {
+            return Decision::Skip;
+        };
+        let Some(lookalike) = corpus.lookalike(&request.name, self.max_distance, self.min_length)
+        else {
+            return Decision::Skip;
+        };
+        let reason = match lookalike.distance {
+            Some(distance) => format!(
+                "Package '{}' is {} edit{} away from '{}' (possible typosquat)",
+                request.name,
+                distance,
+                if distance == 1 { "" } else { "s" },
+                lookalike.entry
+            ),
+            None => format!(
+                "Package '{}' looks like '{}' (possible typosquat)",
+                request.name, lookalike.entry
+            ),
+        };
+        Decision::Block {
+            rule: "typosquat".to_string(),
+            reason,
+        }
+    }
+
+    fn audit_only(&self) -> bool {
+        self.audit
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -1177,6 +1317,225 @@ impl ProxyFilter for MinReleaseAgeFilter {
     }
 }
 
+// ============================================================================
+// VulnerabilityFilter — block versions with known advisories (OSV database)
+// ============================================================================
+
+/// Blocks versions that an advisory in the offline OSV database marks affected.
+///
+/// An advisory matches when its severity reaches the registry's threshold (or it
+/// is unrated and `include_unrated` is set) and no waiver covers it. Metadata
+/// requests (no version) always skip. In audit action a match is logged and
+/// counted but the filter returns `Skip`, so the rest of the chain still runs.
+pub struct VulnerabilityFilter {
+    db: std::sync::Arc<VulnDb>,
+    min_severity: Severity,
+    overrides: std::collections::HashMap<RegistryType, Severity>,
+    include_unrated: bool,
+    audit: bool,
+    waivers: Vec<VulnerabilityWaiver>,
+}
+
+impl VulnerabilityFilter {
+    pub fn new(db: std::sync::Arc<VulnDb>, config: &crate::config::VulnerabilityConfig) -> Self {
+        Self {
+            db,
+            min_severity: config.min_severity,
+            overrides: std::collections::HashMap::new(),
+            include_unrated: config.include_unrated,
+            audit: config.action == crate::config::FilterAction::Audit,
+            waivers: config.waivers.clone(),
+        }
+    }
+
+    /// Add a per-registry severity threshold.
+    pub fn add_override(&mut self, registry: RegistryType, min_severity: Severity) {
+        self.overrides.insert(registry, min_severity);
+    }
+
+    fn waived(&self, advisory: &crate::vulndb::Advisory, request: &FilterRequest) -> bool {
+        let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        self.waivers.iter().any(|w| {
+            (w.id == advisory.id || advisory.aliases.contains(&w.id))
+                && w.registry
+                    .as_deref()
+                    .is_none_or(|r| r == request.registry.as_str())
+                && w.package
+                    .as_deref()
+                    .is_none_or(|p| glob_match(p, &request.name))
+                // ISO dates compare lexically; the expiry day itself is still waived.
+                && w.expires.as_deref().is_none_or(|e| today.as_str() <= e)
+        })
+    }
+}
+
+impl ProxyFilter for VulnerabilityFilter {
+    fn name(&self) -> &'static str {
+        "vulnerability"
+    }
+
+    fn evaluate(&self, request: &FilterRequest) -> Decision {
+        let Some(ref version) = request.version else {
+            return Decision::Skip;
+        };
+        let threshold = self
+            .overrides
+            .get(&request.registry)
+            .copied()
+            .unwrap_or(self.min_severity);
+        let hits: Vec<&crate::vulndb::Advisory> = self
+            .db
+            .affecting(request.registry, &request.name, version)
+            .into_iter()
+            .filter(|a| match a.severity {
+                Some(severity) => severity >= threshold,
+                None => self.include_unrated,
+            })
+            .filter(|a| !self.waived(a, request))
+            .collect();
+        // Sorted most severe first, so the head carries the rating to report.
+        let Some(worst) = hits.first() else {
+            return Decision::Skip;
+        };
+        let severity = worst.severity.map_or("unrated", |s| s.as_str());
+        let mut reason = format!(
+            "{}@{} is affected by {}",
+            request.name,
+            version,
+            hits.iter()
+                .take(3)
+                .map(|a| format!(
+                    "{} ({})",
+                    a.id,
+                    a.severity.map_or("unrated", |s| s.as_str())
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        if hits.len() > 3 {
+            reason.push_str(&format!(" and {} more", hits.len() - 3));
+        }
+
+        let action = if self.audit { "audit" } else { "block" };
+        crate::metrics::VULNERABILITY_MATCHES_TOTAL
+            .with_label_values(&[request.registry.as_str(), severity, action])
+            .inc();
+        if self.audit {
+            tracing::warn!(
+                registry = %request.registry,
+                package = %request.name,
+                version = %version,
+                reason = %reason,
+                "[AUDIT] Vulnerable version served"
+            );
+            return Decision::Skip;
+        }
+        Decision::Block {
+            rule: "vulnerability".to_string(),
+            reason,
+        }
+    }
+}
+
+/// Registries whose download handlers supply the declared license.
+const LICENSE_REGISTRIES: [RegistryType; 5] = [
+    RegistryType::Npm,
+    RegistryType::PyPI,
+    RegistryType::Cargo,
+    RegistryType::Nuget,
+    RegistryType::Maven,
+];
+
+/// Blocks versions whose declared license the configured policy rejects.
+///
+/// The license comes from the metadata the handler already has (npm packument,
+/// PyPI core metadata, crates.io metadata, nuspec, POM) via
+/// [`check_download_with_license`]. Metadata requests and registries that
+/// declare no license skip; an unrecognizable license blocks only when
+/// `allow_unknown` is off. In audit action a violation is logged and counted
+/// but the filter returns `Skip`.
+pub struct LicenseFilter {
+    policy: LicensePolicy,
+    overrides: std::collections::HashMap<RegistryType, LicensePolicy>,
+    allow_unknown: bool,
+    audit: bool,
+}
+
+impl LicenseFilter {
+    pub fn new(config: &crate::config::LicenseConfig) -> Self {
+        Self {
+            policy: LicensePolicy::new(&config.allow, &config.deny),
+            overrides: std::collections::HashMap::new(),
+            allow_unknown: config.allow_unknown,
+            audit: config.action == crate::config::FilterAction::Audit,
+        }
+    }
+
+    /// Use a different policy for one registry.
+    pub fn add_override(&mut self, registry: RegistryType, policy: LicensePolicy) {
+        self.overrides.insert(registry, policy);
+    }
+}
+
+impl ProxyFilter for LicenseFilter {
+    fn name(&self) -> &'static str {
+        "license"
+    }
+
+    fn evaluate(&self, request: &FilterRequest) -> Decision {
+        let Some(ref version) = request.version else {
+            return Decision::Skip;
+        };
+        if !LICENSE_REGISTRIES.contains(&request.registry) {
+            return Decision::Skip;
+        }
+        let declared = request.license.as_deref().unwrap_or("");
+        let reason = match crate::license::parse(declared) {
+            None if self.allow_unknown => return Decision::Skip,
+            None => format!(
+                "{}@{} declares no recognizable license and the license policy requires one",
+                request.name, version
+            ),
+            Some(expr) => {
+                let policy = self
+                    .overrides
+                    .get(&request.registry)
+                    .unwrap_or(&self.policy);
+                let rejected = policy.rejected(&expr);
+                if rejected.is_empty() {
+                    return Decision::Skip;
+                }
+                format!(
+                    "{}@{} is licensed {}; {} not allowed by the license policy",
+                    request.name,
+                    version,
+                    expr,
+                    rejected.join(", ")
+                )
+            }
+        };
+
+        let action = if self.audit { "audit" } else { "block" };
+        crate::metrics::LICENSE_VIOLATIONS_TOTAL
+            .with_label_values(&[request.registry.as_str(), action])
+            .inc();
+        if self.audit {
+            tracing::warn!(
+                registry = %request.registry,
+                package = %request.name,
+                version = %version,
+                reason = %reason,
+                "[AUDIT] License policy violation served"
+            );
+            return Decision::Skip;
+        }
+        Decision::Block {
+            rule: "license".to_string(),
+            reason,
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -1249,6 +1608,7 @@ mod tests {
             integrity: Some("sha256-abc123".to_string()),
             bypass: false,
             publish_date: None,
+            license: None,
         }
     }
 
@@ -1261,6 +1621,7 @@ mod tests {
             integrity: None,
             bypass: false,
             publish_date: None,
+            license: None,
         }
     }
 
@@ -1273,6 +1634,7 @@ mod tests {
             integrity: None,
             bypass: true,
             publish_date: None,
+            license: None,
         }
     }
 
@@ -1730,6 +2092,7 @@ mod tests {
             integrity: None,
             bypass: false,
             publish_date: None,
+            license: None,
         }
     }
 
@@ -2455,6 +2818,7 @@ mod tests {
             integrity: Some("sha256:abc123".to_string()),
             bypass: false,
             publish_date: None,
+            license: None,
         };
         match filter.evaluate(&req) {
             Decision::Block { rule, reason } => {
@@ -3003,6 +3367,7 @@ mod tests {
             integrity: None, // pre-download: no integrity
             bypass: false,
             publish_date: None,
+            license: None,
         };
         let decision = filter.evaluate(&request);
         assert!(
@@ -3105,6 +3470,7 @@ mod tests {
             integrity: Some("sha256:abc123".to_string()), // post-download: has integrity
             bypass: false,
             publish_date: None,
+            license: None,
         };
         let decision = filter.evaluate(&request);
         assert!(
@@ -3177,6 +3543,7 @@ mod min_release_age_tests {
             integrity: None,
             bypass: false,
             publish_date: Some(now - 86400), // 1 day ago
+            license: None,
         };
         assert!(matches!(filter.evaluate(&request), Decision::Block { .. }));
     }
@@ -3196,6 +3563,7 @@ mod min_release_age_tests {
             integrity: None,
             bypass: false,
             publish_date: Some(now - 864000), // 10 days ago
+            license: None,
         };
         assert!(matches!(filter.evaluate(&request), Decision::Skip));
     }
@@ -3217,6 +3585,7 @@ mod min_release_age_tests {
             integrity: None,
             bypass: false,
             publish_date: None,
+            license: None,
         };
         assert!(matches!(filter.evaluate(&request), Decision::Skip));
     }
@@ -3235,6 +3604,7 @@ mod min_release_age_tests {
             integrity: None,
             bypass: false,
             publish_date: None,
+            license: None,
         };
         assert!(matches!(filter.evaluate(&request), Decision::Skip));
     }
@@ -3255,6 +3625,7 @@ mod min_release_age_tests {
             integrity: None,
             bypass: false,
             publish_date: Some(now - 86400),
+            license: None,
         };
         assert!(matches!(filter.evaluate(&request), Decision::Skip));
     }
@@ -3281,6 +3652,7 @@ mod min_release_age_tests {
             integrity: None,
             bypass: false,
             publish_date: Some(now - 3600), // 1 hour ago
+            license: None,
         };
         let result = engine.evaluate(&req_young);
         assert!(matches!(result.decision, Decision::Block { .. }));
@@ -3295,6 +3667,7 @@ mod min_release_age_tests {
             integrity: None,
             bypass: false,
             publish_date: Some(now - 864000), // 10 days
+            license: None,
         };
         let result = engine.evaluate(&req_old);
         assert!(!matches!(result.decision, Decision::Block { .. }));
@@ -3328,3 +3701,398 @@ mod min_release_age_tests {
         assert!(result.is_none());
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod vulnerability_tests {
+    use super::*;
+    use crate::config::{FilterAction, VulnerabilityConfig};
+    use crate::vulndb::tests::lodash_advisory;
+    use std::sync::Arc;
+
+    fn request(registry: RegistryType, name: &str, version: Option<&str>) -> FilterRequest {
+        FilterRequest {
+            registry,
+            upstream: None,
+            name: name.to_string(),
+            version: version.map(str::to_string),
+            integrity: None,
+            bypass: false,
+            publish_date: None,
+            license: None,
+        }
+    }
+
+    fn unrated_advisory() -> serde_json::Value {
+        serde_json::json!({
+            "id": "PYSEC-2024-1",
+            "affected": [{
+                "package": {"ecosystem": "PyPI", "name": "requests"},
+                "ranges": [{"type": "ECOSYSTEM", "events": [{"introduced": "2.0"}, {"fixed": "2.32.0"}]}]
+            }]
+        })
+    }
+
+    fn filter(config: VulnerabilityConfig) -> VulnerabilityFilter {
+        let db = VulnDb::from_osv(&[lodash_advisory(), unrated_advisory()]);
+        VulnerabilityFilter::new(Arc::new(db), &config)
+    }
+
+    #[test]
+    fn test_blocks_affected_version_only() {
+        let f = filter(VulnerabilityConfig::default());
+        match f.evaluate(&request(RegistryType::Npm, "lodash", Some("4.17.20"))) {
+            Decision::Block { rule, reason } => {
+                assert_eq!(rule, "vulnerability");
+                assert!(reason.contains("GHSA-35jh-r3h4-6jhm (high)"), "{reason}");
+            }
+            other => panic!("expected block, got {other:?}"),
+        }
+        let fixed = request(RegistryType::Npm, "lodash", Some("4.17.21"));
+        assert_eq!(f.evaluate(&fixed), Decision::Skip);
+        // Metadata requests carry no version and are never judged.
+        assert_eq!(
+            f.evaluate(&request(RegistryType::Npm, "lodash", None)),
+            Decision::Skip
+        );
+        // Same name in another ecosystem is another package.
+        let other = request(RegistryType::Cargo, "lodash", Some("4.17.20"));
+        assert_eq!(f.evaluate(&other), Decision::Skip);
+    }
+
+    #[test]
+    fn test_severity_threshold_and_unrated() {
+        let mut f = filter(VulnerabilityConfig {
+            min_severity: Severity::Critical,
+            ..Default::default()
+        });
+        let lodash = request(RegistryType::Npm, "lodash", Some("4.17.20"));
+        assert_eq!(f.evaluate(&lodash), Decision::Skip);
+        f.add_override(RegistryType::Npm, Severity::Medium);
+        assert!(matches!(f.evaluate(&lodash), Decision::Block { .. }));
+
+        let requests = request(RegistryType::PyPI, "requests", Some("2.31.0"));
+        assert!(matches!(f.evaluate(&requests), Decision::Block { .. }));
+        let f = filter(VulnerabilityConfig {
+            include_unrated: false,
+            ..Default::default()
+        });
+        assert_eq!(f.evaluate(&requests), Decision::Skip);
+    }
+
+    #[test]
+    fn test_waivers() {
+        let waiver = |id: &str, package: Option<&str>, expires: Option<&str>| VulnerabilityWaiver {
+            id: id.to_string(),
+            registry: None,
+            package: package.map(str::to_string),
+            expires: expires.map(str::to_string),
+            reason: None,
+        };
+        let lodash = request(RegistryType::Npm, "lodash", Some("4.17.20"));
+        for (w, waived) in [
+            (waiver("GHSA-35jh-r3h4-6jhm", None, None), true),
+            // By alias.
+            (waiver("CVE-2021-23337", None, None), true),
+            (
+                waiver("CVE-2021-23337", Some("lodash*"), Some("2999-12-31")),
+                true,
+            ),
+            (waiver("CVE-2021-23337", Some("underscore"), None), false),
+            (waiver("CVE-2021-23337", None, Some("2000-01-01")), false),
+        ] {
+            let f = filter(VulnerabilityConfig {
+                waivers: vec![w.clone()],
+                ..Default::default()
+            });
+            assert_eq!(f.evaluate(&lodash) == Decision::Skip, waived, "{w:?}");
+        }
+    }
+
+    #[test]
+    fn test_audit_action_never_blocks() {
+        let f = filter(VulnerabilityConfig {
+            action: FilterAction::Audit,
+            ..Default::default()
+        });
+        let lodash = request(RegistryType::Npm, "lodash", Some("4.17.20"));
+        assert_eq!(f.evaluate(&lodash), Decision::Skip);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod license_tests {
+    use super::*;
+    use crate::config::{FilterAction, LicenseConfig};
+
+    fn request(registry: RegistryType, license: Option<&str>) -> FilterRequest {
+        FilterRequest {
+            registry,
+            upstream: None,
+            name: "pkg".to_string(),
+            version: Some("1.0.0".to_string()),
+            integrity: None,
+            bypass: false,
+            publish_date: None,
+            license: license.map(str::to_string),
+        }
+    }
+
+    fn deny_gpl() -> LicenseConfig {
+        LicenseConfig {
+            enabled: true,
+            deny: vec!["GPL-*".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_blocks_denied_license_with_reason() {
+        let f = LicenseFilter::new(&deny_gpl());
+        assert_eq!(
+            f.evaluate(&request(RegistryType::Npm, Some("GPL-3.0-only AND MIT"))),
+            Decision::Block {
+                rule: "license".to_string(),
+                reason: "pkg@1.0.0 is licensed GPL-3.0-only AND MIT; GPL-3.0-only not allowed by the license policy".to_string(),
+            }
+        );
+        assert_eq!(
+            f.evaluate(&request(RegistryType::Npm, Some("MIT"))),
+            Decision::Skip
+        );
+        // No version (metadata) or a registry without declared licenses: no opinion.
+        let mut metadata = request(RegistryType::Npm, Some("GPL-3.0-only"));
+        metadata.version = None;
+        assert_eq!(f.evaluate(&metadata), Decision::Skip);
+        assert_eq!(
+            f.evaluate(&request(RegistryType::Docker, Some("GPL-3.0-only"))),
+            Decision::Skip
+        );
+    }
+
+    #[test]
+    fn test_unknown_license() {
+        let f = LicenseFilter::new(&deny_gpl());
+        assert_eq!(
+            f.evaluate(&request(RegistryType::PyPI, None)),
+            Decision::Skip
+        );
+        let f = LicenseFilter::new(&LicenseConfig {
+            allow_unknown: false,
+            ..deny_gpl()
+        });
+        for license in [None, Some("UNKNOWN")] {
+            match f.evaluate(&request(RegistryType::PyPI, license)) {
+                Decision::Block { reason, .. } => {
+                    assert!(reason.contains("no recognizable license"), "{reason}")
+                }
+                other => panic!("expected block, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_registry_override_and_audit_action() {
+        let mut f = LicenseFilter::new(&deny_gpl());
+        f.add_override(
+            RegistryType::Maven,
+            LicensePolicy::new(
+                &["GPL-2.0-only WITH Classpath-exception-2.0".to_string()],
+                &["GPL-*".to_string()],
+            ),
+        );
+        let classpath = Some("GPL-2.0-only WITH Classpath-exception-2.0");
+        assert_eq!(
+            f.evaluate(&request(RegistryType::Maven, classpath)),
+            Decision::Skip
+        );
+        assert!(matches!(
+            f.evaluate(&request(RegistryType::Cargo, classpath)),
+            Decision::Block { .. }
+        ));
+
+        let f = LicenseFilter::new(&LicenseConfig {
+            action: FilterAction::Audit,
+            ..deny_gpl()
+        });
+        assert_eq!(
+            f.evaluate(&request(RegistryType::Nuget, Some("GPL-3.0-only"))),
+            Decision::Skip
+        );
+    }
+
+    #[test]
+    fn test_check_download_with_license() {
+        let mut engine = CurationEngine::new(CurationConfig {
+            mode: CurationMode::Enforce,
+            ..Default::default()
+        });
+        engine.add_filter(Box::new(LicenseFilter::new(&deny_gpl())));
+        assert!(license_policy_active(&engine));
+        let headers = axum::http::HeaderMap::new();
+        let check = |license| {
+            check_download_with_license(
+                &engine,
+                None,
+                &headers,
+                RegistryType::Cargo,
+                "pkg",
+                Some("1.0.0"),
+                None,
+                license,
+            )
+        };
+        let resp = check(Some("GPL-2.0-or-later")).unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        assert_eq!(resp.headers().get("x-nora-rule").unwrap(), "license");
+        assert!(check(Some("MIT OR GPL-2.0-or-later")).is_none());
+        assert!(!license_policy_active(&CurationEngine::new(
+            CurationConfig::default()
+        )));
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod typosquat_tests {
+    use super::*;
+    use crate::config::TyposquatConfig;
+
+    fn request(registry: RegistryType, name: &str) -> FilterRequest {
+        FilterRequest {
+            registry,
+            upstream: None,
+            name: name.to_string(),
+            version: None,
+            integrity: None,
+            bypass: false,
+            publish_date: None,
+            license: None,
+        }
+    }
+
+    fn filter() -> TyposquatFilter {
+        let mut filter = TyposquatFilter::new(&TyposquatConfig {
+            enabled: true,
+            ..Default::default()
+        });
+        filter.add_corpus(
+            RegistryType::Npm,
+            Corpus::new(RegistryType::Npm, ["lodash", "@acmecorp/*"]),
+        );
+        filter
+    }
+
+    #[test]
+    fn test_blocks_lookalikes_with_reason() {
+        let f = filter();
+        assert_eq!(
+            f.evaluate(&request(RegistryType::Npm, "lodahs")),
+            Decision::Block {
+                rule: "typosquat".to_string(),
+                reason: "Package 'lodahs' is 1 edit away from 'lodash' (possible typosquat)"
+                    .to_string(),
+            }
+        );
+        assert_eq!(
+            f.evaluate(&request(RegistryType::Npm, "@acme-corp/x")),
+            Decision::Block {
+                rule: "typosquat".to_string(),
+                reason: "Package '@acme-corp/x' looks like '@acmecorp/*' (possible typosquat)"
+                    .to_string(),
+            }
+        );
+        for name in ["lodash", "@acmecorp/x", "underscore"] {
+            assert_eq!(
+                f.evaluate(&request(RegistryType::Npm, name)),
+                Decision::Skip
+            );
+        }
+        // No corpus for PyPI.
+        assert_eq!(
+            f.evaluate(&request(RegistryType::PyPI, "lodahs")),
+            Decision::Skip
+        );
+    }
+
+    #[test]
+    fn test_audit_action_serves_lookalikes() {
+        let audit_filter = || {
+            let mut f = TyposquatFilter::new(&TyposquatConfig {
+                enabled: true,
+                action: crate::config::FilterAction::Audit,
+                ..Default::default()
+            });
+            f.add_corpus(
+                RegistryType::Npm,
+                Corpus::new(RegistryType::Npm, ["lodash"]),
+            );
+            f
+        };
+        let enforce = || {
+            CurationEngine::new(CurationConfig {
+                mode: CurationMode::Enforce,
+                ..CurationConfig::default()
+            })
+        };
+
+        // Reported as an audit decision, even in enforce mode.
+        let mut engine = enforce();
+        engine.add_filter(Box::new(audit_filter()));
+        let result = engine.evaluate(&request(RegistryType::Npm, "lodahs"));
+        assert!(matches!(result.decision, Decision::Block { ref rule, .. } if rule == "typosquat"));
+        assert_eq!(result.decided_by.as_deref(), Some("typosquat"));
+        assert!(result.audited);
+        let result = engine.evaluate(&request(RegistryType::Npm, "lodash"));
+        assert_eq!(result.decision, Decision::Allow);
+        assert!(!result.audited);
+
+        // The rest of the chain still runs and can block for real.
+        struct BlockAll;
+        impl ProxyFilter for BlockAll {
+            fn name(&self) -> &'static str {
+                "block_all"
+            }
+            fn evaluate(&self, _: &FilterRequest) -> Decision {
+                Decision::Block {
+                    rule: "block_all".to_string(),
+                    reason: "test".to_string(),
+                }
+            }
+        }
+        let mut engine = enforce();
+        engine.add_filter(Box::new(audit_filter()));
+        engine.add_filter(Box::new(BlockAll));
+        let result = engine.evaluate(&request(RegistryType::Npm, "lodahs"));
+        assert_eq!(result.decided_by.as_deref(), Some("block_all"));
+        assert!(!result.audited);
+    }
+
+    #[test]
+    fn test_decisions_exported_by_filter() {
+        let decisions = |decision: &str| {
+            crate::metrics::CURATION_DECISIONS_TOTAL
+                .with_label_values(&[decision, "typosquat"])
+                .get()
+        };
+        let (audit_before, block_before) = (decisions("audit"), decisions("block"));
+
+        for (mode, counted) in [
+            (CurationMode::Audit, "audit"),
+            (CurationMode::Enforce, "block"),
+        ] {
+            let mut engine = CurationEngine::new(CurationConfig {
+                mode: mode.clone(),
+                ..CurationConfig::default()
+            });
+            engine.add_filter(Box::new(filter()));
+            let result = engine.evaluate(&request(RegistryType::Npm, "lodahs"));
+            assert_eq!(result.decided_by.as_deref(), Some("typosquat"));
+            assert_eq!(result.audited, counted == "audit");
+        }
+        // Other tests share the global registry, so counts only grow.
+        assert!(decisions("audit") > audit_before);
+        assert!(decisions("block") > block_before);
+    }
+}
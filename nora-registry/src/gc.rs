@@ -262,6 +262,11 @@ pub async fn run_gc(
         "conan/",
         "rpm/",
         "deb/",
+        "helm/",
+        "apk/",
+        "conda/",
+        "composer/",
+        "swift/",
     ] {
         let keys = storage.list(prefix).await.unwrap_or_else(|e| {
             tracing::error!("GC: storage.list({}) failed: {}", prefix, e);
@@ -396,8 +401,10 @@ fn primary_key_for_checksum(key: &str) -> Option<&str> {
 /// Revalidation validator sidecars (`<key>.meta`, #596) are produced ONLY for
 /// npm metadata, so the orphan rule is scoped to the npm prefix — otherwise a
 /// Maven artifact that legitimately ends in `.meta` could be false-deleted.
+/// PyPI's PEP 658 `<wheel>.metadata` follows the same rule under `pypi/`.
 fn is_meta_sidecar(key: &str) -> bool {
-    key.starts_with("npm/") && ends_with_ci(key, ".meta")
+    (key.starts_with("npm/") && ends_with_ci(key, ".meta"))
+        || (key.starts_with("pypi/") && ends_with_ci(key, ".metadata"))
 }
 
 /// True for any sidecar whose orphan rule is "primary artifact absent".
@@ -405,10 +412,12 @@ fn is_orphanable_sidecar(key: &str) -> bool {
     is_checksum_sidecar(key) || is_meta_sidecar(key)
 }
 
-/// Primary artifact key a sidecar belongs to (checksum or `.meta`).
+/// Primary artifact key a sidecar belongs to (checksum, `.meta` or `.metadata`).
 fn primary_key_for_sidecar(key: &str) -> Option<&str> {
     if is_meta_sidecar(key) {
-        return key.strip_suffix(".meta");
+        return key
+            .strip_suffix(".meta")
+            .or_else(|| key.strip_suffix(".metadata"));
     }
     primary_key_for_checksum(key)
 }
@@ -1242,6 +1251,43 @@ mod tests {
         );
     }
 
+    /// A wheel's PEP 658 `.metadata` is reaped once the wheel is gone.
+    #[tokio::test]
+    async fn test_gc_pypi_core_metadata_follows_wheel() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Storage::new_local(dir.path().join("data").to_str().unwrap());
+
+        storage
+            .put(
+                "pypi/gone/gone-1.0-py3-none-any.whl.metadata",
+                b"Name: gone",
+            )
+            .await
+            .unwrap();
+        storage
+            .put("pypi/live/live-1.0-py3-none-any.whl", b"wheel")
+            .await
+            .unwrap();
+        storage
+            .put(
+                "pypi/live/live-1.0-py3-none-any.whl.metadata",
+                b"Name: live",
+            )
+            .await
+            .unwrap();
+
+        run_gc(&storage, &test_publish_locks(), false, 0).await;
+
+        assert!(storage
+            .get("pypi/gone/gone-1.0-py3-none-any.whl.metadata")
+            .await
+            .is_err());
+        assert!(storage
+            .get("pypi/live/live-1.0-py3-none-any.whl.metadata")
+            .await
+            .is_ok());
+    }
+
     #[tokio::test]
     async fn test_gc_docker_deletes_orphans() {
         let dir = tempfile::tempdir().unwrap();
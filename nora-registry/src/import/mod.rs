@@ -326,7 +326,7 @@ async fn run_import(
         );
     }
 
-    let curation = crate::build_curation_engine(config)?;
+    let curation = crate::build_reloadable(config)?.curation_engine;
     let source = source::build_source(
         args.source,
         &args.url,
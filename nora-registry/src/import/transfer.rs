@@ -202,6 +202,7 @@ pub async fn transfer_artifact(
         integrity: Some(format!("sha256:{sha256}")),
         bypass: false,
         publish_date: None,
+        license: None,
     };
     let result = curation.evaluate(&req);
     if !should_commit(&result.decision, result.audited) {
@@ -0,0 +1,489 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+//! Declared-license parsing and policy evaluation for the license curation filter.
+//!
+//! Registries declare licenses in different shapes: SPDX expressions (npm, Cargo,
+//! NuGet, PyPI `License-Expression`), Cargo's legacy `MIT/Apache-2.0`, and free-text
+//! names (Maven `<licenses>`, PyPI `License:` and trove classifiers). [`parse`] turns
+//! any of them into an [`Expr`]: well-known names map to their SPDX expression and
+//! SPDX syntax (`AND`, `OR`, `WITH`, parentheses) is parsed as is. Anything else —
+//! unrecognized free text, placeholders (`UNKNOWN`, `NOASSERTION`, `SEE LICENSE IN …`),
+//! license texts — is no declaration: guessing at it could let `GNU GPL v3 or later`
+//! slip past a `GPL-*` deny.
+
+use crate::curation::glob_match;
+
+/// Longer declarations are license texts, not names or expressions.
+const MAX_DECLARED_LEN: usize = 256;
+
+/// A parsed license expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    License {
+        id: String,
+        exception: Option<String>,
+    },
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+}
+
+impl std::fmt::Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |f: &mut std::fmt::Formatter<'_>, parts: &[Expr], op: &str| {
+            for (i, part) in parts.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " {} ", op)?;
+                }
+                match part {
+                    Expr::License { .. } => write!(f, "{}", part)?,
+                    _ => write!(f, "({})", part)?,
+                }
+            }
+            Ok(())
+        };
+        match self {
+            Expr::License {
+                id,
+                exception: None,
+            } => write!(f, "{}", id),
+            Expr::License {
+                id,
+                exception: Some(exception),
+            } => write!(f, "{} WITH {}", id, exception),
+            Expr::And(parts) => join(f, parts, "AND"),
+            Expr::Or(parts) => join(f, parts, "OR"),
+        }
+    }
+}
+
+/// Parse a declared license. `None` when nothing usable is declared.
+pub fn parse(declared: &str) -> Option<Expr> {
+    let text = declared.trim();
+    let lower = text.to_ascii_lowercase();
+    if text.is_empty()
+        || text.len() > MAX_DECLARED_LEN
+        || text.contains('\n')
+        || matches!(lower.as_str(), "unknown" | "noassertion" | "none" | "n/a")
+        || lower.starts_with("see license in")
+    {
+        return None;
+    }
+    if let Some(expr) = alias(text) {
+        return parse_spdx(expr);
+    }
+    // Cargo before RFC 2582: "MIT/Apache-2.0" means either.
+    let spdx = if text.contains('/') && !text.contains(char::is_whitespace) {
+        text.replace('/', " OR ")
+    } else {
+        text.to_string()
+    };
+    parse_spdx(&spdx)
+}
+
+/// Parse several declarations of one package (Maven `<license>` elements, PyPI
+/// classifiers, npm's legacy `licenses` array) — any one of them may be chosen.
+pub fn parse_any<'a>(declared: impl IntoIterator<Item = &'a str>) -> Option<Expr> {
+    let mut parts: Vec<Expr> = declared.into_iter().filter_map(parse).collect();
+    match parts.len() {
+        0 => None,
+        1 => parts.pop(),
+        _ => Some(Expr::Or(parts)),
+    }
+}
+
+/// SPDX expression for a common free-text license name. Only letters and digits
+/// are compared, and a `+` reads as "or later".
+fn alias(name: &str) -> Option<&'static str> {
+    let key: String = name
+        .replace('+', "orlater")
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let key = key.strip_prefix("the").unwrap_or(&key);
+    let id = match key {
+        "mit" | "mitlicense" | "expat" => "MIT",
+        "apache20"
+        | "apache2"
+        | "apachev2"
+        | "asl20"
+        | "apachelicense20"
+        | "apachelicensev2"
+        | "apachelicensev20"
+        | "apachelicenseversion20"
+        | "apachesoftwarelicense20"
+        | "apachesoftwarelicenseversion20" => "Apache-2.0",
+        "bsd2clause" | "bsd2clauselicense" | "simplifiedbsd" | "simplifiedbsdlicense" => {
+            "BSD-2-Clause"
+        }
+        "bsd3clause" | "bsd3clauselicense" | "newbsd" | "newbsdlicense" | "revisedbsd"
+        | "modifiedbsd" => "BSD-3-Clause",
+        "isc" | "isclicense" | "isclicenseiscl" => "ISC",
+        "mpl20"
+        | "mozillapubliclicense20"
+        | "mozillapubliclicenseversion20"
+        | "mozillapubliclicense20mpl20" => "MPL-2.0",
+        "epl10"
+        | "eplv10"
+        | "eclipsepubliclicense10"
+        | "eclipsepubliclicensev10"
+        | "eclipsepubliclicenseversion10" => "EPL-1.0",
+        "epl20"
+        | "eplv20"
+        | "epl2"
+        | "eclipsepubliclicense20"
+        | "eclipsepubliclicensev20"
+        | "eclipsepubliclicenseversion20" => "EPL-2.0",
+        // An unversioned GPL lets the recipient choose any version.
+        "gpl" | "gnugpl" | "gnugeneralpubliclicense" | "gnugeneralpubliclicensegpl" => {
+            "GPL-1.0-or-later"
+        }
+        "gplv2"
+        | "gnugplv2"
+        | "gnugeneralpubliclicenseversion2"
+        | "gnugeneralpubliclicensev2gplv2" => "GPL-2.0-only",
+        "gplv2orlater"
+        | "gnugplv2orlater"
+        | "gnugeneralpubliclicensev2orlater"
+        | "gnugeneralpubliclicenseversion2orlater"
+        | "gnugeneralpubliclicensev2orlatergplv2orlater"
+        | "gnugeneralpubliclicensev2orlatergplv2" => "GPL-2.0-or-later",
+        "gplv2withclasspathexception"
+        | "gpl2withclasspathexception"
+        | "gnugplv2withclasspathexception"
+        | "gnugeneralpubliclicensev2withclasspathexception"
+        | "gnugeneralpubliclicenseversion2withclasspathexception"
+        | "gnugeneralpubliclicenseversion2withtheclasspathexception" => {
+            "GPL-2.0-only WITH Classpath-exception-2.0"
+        }
+        "gplv3"
+        | "gnugplv3"
+        | "gnugeneralpubliclicenseversion3"
+        | "gnugeneralpubliclicensev3gplv3" => "GPL-3.0-only",
+        "gplv3orlater"
+        | "gnugplv3orlater"
+        | "gnugeneralpubliclicensev3orlater"
+        | "gnugeneralpubliclicenseversion3orlater"
+        | "gnugeneralpubliclicensev3orlatergplv3orlater"
+        | "gnugeneralpubliclicensev3orlatergplv3" => "GPL-3.0-or-later",
+        "lgpl" | "gnulgpl" | "gnulessergeneralpubliclicense" | "gnulibrarygeneralpubliclicense" => {
+            "LGPL-2.0-or-later"
+        }
+        "lgplv21" | "gnulgplv21" | "gnulessergeneralpubliclicenseversion21" => "LGPL-2.1-only",
+        "lgplv21orlater" | "gnulgplv21orlater" | "gnulessergeneralpubliclicensev21orlater" => {
+            "LGPL-2.1-or-later"
+        }
+        "lgplv3"
+        | "gnulgplv3"
+        | "gnulessergeneralpubliclicenseversion3"
+        | "gnulessergeneralpubliclicensev3lgplv3" => "LGPL-3.0-only",
+        "lgplv3orlater"
+        | "gnulgplv3orlater"
+        | "gnulessergeneralpubliclicensev3orlater"
+        | "gnulessergeneralpubliclicensev3orlaterlgplv3orlater"
+        | "gnulessergeneralpubliclicensev3orlaterlgplv3" => "LGPL-3.0-or-later",
+        "agplv3" | "gnuafferogeneralpubliclicenseversion3" | "gnuafferogeneralpubliclicensev3" => {
+            "AGPL-3.0-only"
+        }
+        "agplv3orlater"
+        | "gnuafferogeneralpubliclicensev3orlater"
+        | "gnuafferogeneralpubliclicensev3orlateragplv3orlater"
+        | "gnuafferogeneralpubliclicensev3orlateragplv3" => "AGPL-3.0-or-later",
+        "cddl10" | "commondevelopmentanddistributionlicense10" => "CDDL-1.0",
+        "cc010" | "cc010universal" => "CC0-1.0",
+        "unlicense" => "Unlicense",
+        "pythonsoftwarefoundationlicense" => "PSF-2.0",
+        _ => return None,
+    };
+    Some(id)
+}
+
+/// Recursive-descent SPDX expression parser: `OR` binds looser than `AND`,
+/// `WITH` attaches an exception to a single license. Operators are accepted in
+/// any case; npm and PyPI metadata in the wild is not always upper case. `None`
+/// unless the whole text is a well-formed expression.
+fn parse_spdx(text: &str) -> Option<Expr> {
+    let spaced = text.replace('(', " ( ").replace(')', " ) ");
+    let tokens: Vec<&str> = spaced.split_whitespace().collect();
+    let mut pos = 0;
+    let expr = parse_or(&tokens, &mut pos)?;
+    (pos == tokens.len()).then_some(expr)
+}
+
+fn is_op(token: &str, op: &str) -> bool {
+    token.eq_ignore_ascii_case(op)
+}
+
+fn parse_or(tokens: &[&str], pos: &mut usize) -> Option<Expr> {
+    let mut parts = vec![parse_and(tokens, pos)?];
+    while tokens.get(*pos).is_some_and(|t| is_op(t, "OR")) {
+        *pos += 1;
+        parts.push(parse_and(tokens, pos)?);
+    }
+    Some(if parts.len() == 1 {
+        parts.remove(0)
+    } else {
+        Expr::Or(parts)
+    })
+}
+
+fn parse_and(tokens: &[&str], pos: &mut usize) -> Option<Expr> {
+    let mut parts = vec![parse_atom(tokens, pos)?];
+    while tokens.get(*pos).is_some_and(|t| is_op(t, "AND")) {
+        *pos += 1;
+        parts.push(parse_atom(tokens, pos)?);
+    }
+    Some(if parts.len() == 1 {
+        parts.remove(0)
+    } else {
+        Expr::And(parts)
+    })
+}
+
+fn parse_atom(tokens: &[&str], pos: &mut usize) -> Option<Expr> {
+    let token = *tokens.get(*pos)?;
+    *pos += 1;
+    if token == "(" {
+        let inner = parse_or(tokens, pos)?;
+        if tokens.get(*pos) != Some(&")") {
+            return None;
+        }
+        *pos += 1;
+        return Some(inner);
+    }
+    if !is_license_id(token) || ["AND", "OR", "WITH"].iter().any(|op| is_op(token, op)) {
+        return None;
+    }
+    let exception = if tokens.get(*pos).is_some_and(|t| is_op(t, "WITH")) {
+        let exception = *tokens.get(*pos + 1)?;
+        if !is_license_id(exception) {
+            return None;
+        }
+        *pos += 2;
+        Some(exception.to_string())
+    } else {
+        None
+    };
+    Some(Expr::License {
+        id: token.to_string(),
+        exception,
+    })
+}
+
+/// An SPDX `idstring` (letters, digits, `-`, `.`), optionally with a trailing
+/// `+`; `LicenseRef-`/`DocumentRef-…:` references included.
+fn is_license_id(token: &str) -> bool {
+    let id = token.strip_suffix('+').unwrap_or(token);
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | ':'))
+}
+
+/// Allow/deny patterns (SPDX ids or globs, case-insensitive).
+///
+/// A license passes when it matches `allow`, else fails when it matches `deny`,
+/// else passes only if `allow` is empty. `X WITH exception` is checked against
+/// `allow` as a whole first, so an exception can be approved on its own.
+#[derive(Debug, Clone, Default)]
+pub struct LicensePolicy {
+    allow: Vec<String>,
+    deny: Vec<String>,
+}
+
+impl LicensePolicy {
+    pub fn new(allow: &[String], deny: &[String]) -> Self {
+        let lower = |patterns: &[String]| patterns.iter().map(|p| p.to_lowercase()).collect();
+        Self {
+            allow: lower(allow),
+            deny: lower(deny),
+        }
+    }
+
+    /// The licenses that keep `expr` from being acceptable — empty when it is.
+    /// An `OR` is acceptable when any branch is, an `AND` when all are.
+    pub fn rejected(&self, expr: &Expr) -> Vec<String> {
+        match expr {
+            Expr::License { .. } => {
+                if self.accepts(expr) {
+                    Vec::new()
+                } else {
+                    vec![expr.to_string()]
+                }
+            }
+            Expr::And(parts) => {
+                let mut rejected: Vec<String> =
+                    parts.iter().flat_map(|p| self.rejected(p)).collect();
+                rejected.dedup();
+                rejected
+            }
+            Expr::Or(parts) => {
+                let branches: Vec<Vec<String>> = parts.iter().map(|p| self.rejected(p)).collect();
+                if branches.iter().any(|b| b.is_empty()) {
+                    return Vec::new();
+                }
+                let mut rejected: Vec<String> = branches.into_iter().flatten().collect();
+                rejected.dedup();
+                rejected
+            }
+        }
+    }
+
+    fn accepts(&self, term: &Expr) -> bool {
+        let Expr::License { id, .. } = term else {
+            return false;
+        };
+        let full = term.to_string().to_lowercase();
+        let id = id.to_lowercase();
+        let matches =
+            |patterns: &[String], value: &str| patterns.iter().any(|p| glob_match(p, value));
+        if matches(&self.allow, &full) {
+            return true;
+        }
+        if matches(&self.deny, &full) || matches(&self.deny, &id) {
+            return false;
+        }
+        self.allow.is_empty() || matches(&self.allow, &id)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn policy(allow: &[&str], deny: &[&str]) -> LicensePolicy {
+        let owned = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        LicensePolicy::new(&owned(allow), &owned(deny))
+    }
+
+    #[test]
+    fn test_parse_spdx_expressions() {
+        assert_eq!(parse("MIT").unwrap().to_string(), "MIT");
+        assert_eq!(
+            parse("(MIT OR Apache-2.0) AND BSD-3-Clause")
+                .unwrap()
+                .to_string(),
+            "(MIT OR Apache-2.0) AND BSD-3-Clause"
+        );
+        assert_eq!(
+            parse("GPL-2.0-only WITH Classpath-exception-2.0").unwrap(),
+            Expr::License {
+                id: "GPL-2.0-only".to_string(),
+                exception: Some("Classpath-exception-2.0".to_string()),
+            }
+        );
+        assert_eq!(
+            parse("mit or apache-2.0").unwrap().to_string(),
+            "mit OR apache-2.0"
+        );
+        // Cargo's legacy separator.
+        assert_eq!(
+            parse("MIT/Apache-2.0").unwrap().to_string(),
+            "MIT OR Apache-2.0"
+        );
+    }
+
+    #[test]
+    fn test_parse_names_and_placeholders() {
+        assert_eq!(
+            parse("The Apache Software License, Version 2.0")
+                .unwrap()
+                .to_string(),
+            "Apache-2.0"
+        );
+        assert_eq!(
+            parse("GNU General Public License v3 (GPLv3)")
+                .unwrap()
+                .to_string(),
+            "GPL-3.0-only"
+        );
+        assert_eq!(
+            parse("Eclipse Public License v 2.0").unwrap().to_string(),
+            "EPL-2.0"
+        );
+        assert_eq!(parse("GPL").unwrap().to_string(), "GPL-1.0-or-later");
+        assert_eq!(parse("LGPL").unwrap().to_string(), "LGPL-2.0-or-later");
+        assert_eq!(
+            parse("GNU GPL v3 or later").unwrap().to_string(),
+            "GPL-3.0-or-later"
+        );
+        assert_eq!(parse("GPLv2+").unwrap().to_string(), "GPL-2.0-or-later");
+        assert_eq!(
+            parse("GNU General Public License, version 2, with the Classpath Exception")
+                .unwrap()
+                .to_string(),
+            "GPL-2.0-only WITH Classpath-exception-2.0"
+        );
+        // Unrecognized free text and malformed expressions declare nothing.
+        for text in [
+            "Acme Corp Proprietary",
+            "GNU General Public License (some version)",
+            "MIT OR (Apache-2.0",
+            "MIT AND",
+            "Apache-2.0 WITH",
+            "BSD, see https://example.com/license",
+        ] {
+            assert_eq!(parse(text), None, "{text}");
+        }
+        for placeholder in ["", "UNKNOWN", "NOASSERTION", "SEE LICENSE IN LICENSE.md"] {
+            assert_eq!(parse(placeholder), None, "{placeholder}");
+        }
+        assert_eq!(
+            parse("Copyright (c) 2026\n\nPermission is hereby granted"),
+            None
+        );
+        assert_eq!(
+            parse_any(["MIT", "UNKNOWN", "GPL-3.0-only"])
+                .unwrap()
+                .to_string(),
+            "MIT OR GPL-3.0-only"
+        );
+        assert_eq!(parse_any([]), None);
+    }
+
+    #[test]
+    fn test_policy_deny_list() {
+        let p = policy(&[], &["GPL-*", "AGPL-*"]);
+        assert!(p.rejected(&parse("MIT").unwrap()).is_empty());
+        assert_eq!(
+            p.rejected(&parse("gpl-3.0-only").unwrap()),
+            ["gpl-3.0-only"]
+        );
+        // One acceptable branch is enough for OR, every term counts for AND.
+        assert!(p
+            .rejected(&parse("GPL-3.0-only OR MIT").unwrap())
+            .is_empty());
+        assert_eq!(
+            p.rejected(&parse("MIT AND AGPL-3.0-only").unwrap()),
+            ["AGPL-3.0-only"]
+        );
+        assert_eq!(
+            p.rejected(&parse("GPL-2.0-only WITH Classpath-exception-2.0").unwrap()),
+            ["GPL-2.0-only WITH Classpath-exception-2.0"]
+        );
+    }
+
+    #[test]
+    fn test_policy_allow_list() {
+        let p = policy(
+            &[
+                "MIT",
+                "Apache-2.0",
+                "BSD-*",
+                "GPL-2.0-only WITH Classpath-exception-2.0",
+            ],
+            &["GPL-*"],
+        );
+        assert!(p.rejected(&parse("BSD-3-Clause").unwrap()).is_empty());
+        assert!(p
+            .rejected(&parse("GPL-2.0-only WITH Classpath-exception-2.0").unwrap())
+            .is_empty());
+        assert_eq!(p.rejected(&parse("ISC").unwrap()), ["ISC"]);
+        assert_eq!(
+            p.rejected(&parse("GPL-2.0-only OR ISC").unwrap()),
+            ["GPL-2.0-only", "ISC"]
+        );
+    }
+}
@@ -36,6 +36,7 @@ mod gc;
 mod hash_pin_store;
 mod health;
 mod import;
+mod license;
 mod metrics;
 mod migrate;
 mod mirror;
@@ -51,8 +52,11 @@ mod secrets;
 mod signing;
 mod storage;
 mod tokens;
+mod typosquat;
 mod ui;
 mod validation;
+mod version;
+mod vulndb;
 
 #[cfg(test)]
 mod test_helpers;
@@ -155,6 +159,11 @@ enum Commands {
         #[command(subcommand)]
         action: CurationCommand,
     },
+    /// Offline vulnerability database for the curation vulnerability filter
+    Vulndb {
+        #[command(subcommand)]
+        action: VulndbCommand,
+    },
     /// Migrate legacy Docker storage keys to namespaced format
     MigrateDockerKeys {
         /// Dry run — show what would be migrated without modifying storage
@@ -203,6 +212,20 @@ enum CurationCommand {
     },
 }
 
+#[derive(Subcommand)]
+enum VulndbCommand {
+    /// Import an OSV dump (zip of advisory JSON files, e.g. `npm/all.zip`)
+    Import {
+        /// Path to the OSV zip file
+        file: PathBuf,
+        /// Discard the existing database instead of merging into it
+        #[arg(long)]
+        replace: bool,
+    },
+    /// Show what the vulnerability database holds
+    Status,
+}
+
 /// Per-key publish locks — shared between AppState and GC to serialize
 /// metadata read-modify-write operations on the same artifact.
 ///
@@ -227,6 +250,11 @@ pub fn acquire_publish_lock(locks: &PublishLocks, key: &str) -> Arc<tokio::sync:
 pub struct ReloadableConfig {
     pub curation_engine: curation::CurationEngine,
     pub bypass_token: Option<ProtectedString>,
+    /// Imported OSV advisories (`nora vulndb import`); `None` until a database exists.
+    /// npm audit and pub advisories answer from it instead of the upstream.
+    pub vulndb: Option<Arc<vulndb::VulnDb>>,
+    /// Parsed trust material of the Docker upstreams' cosign policies.
+    pub cosign: registry::docker_cosign::CosignTrust,
 }
 
 #[derive(Clone)]
@@ -259,8 +287,16 @@ pub struct AppState {
     /// upstream fetch (#595). In-memory and rebuildable (empty after restart).
     pub(crate) proxy_coalesce: proxy_coalesce::InflightMap<Bytes>,
     pub digest_store: Arc<digest_quarantine::DigestStore>,
-    /// Repository index signer (rpm/deb). `None` = indexes are unsigned.
+    /// Platform manifests of multi-arch indexes that passed a cosign policy;
+    /// in-memory, re-verified from the index after a restart.
+    pub(crate) cosign_children: Arc<registry::docker_cosign::VerifiedChildren>,
+    /// Repository index signer (rpm/deb indexes). `None` = unsigned.
     pub signer: Option<Arc<signing::RepoSigner>>,
+    /// The same key for terraform provider `SHA256SUMS`, loaded on first use when
+    /// no rpm/deb signer exists — see [`AppState::terraform_signer`].
+    pub(crate) terraform_signer: Arc<tokio::sync::OnceCell<Option<Arc<signing::RepoSigner>>>>,
+    /// apk `APKINDEX.tar.gz` signer (RSA). `None` = unsigned.
+    pub apk_signer: Option<Arc<signing::ApkSigner>>,
     /// Pre-compiled upstream hostname searchers for leak detection (#386)
     pub leak_finders: metrics::LeakFinders,
     /// Shared shutdown signal so on-demand background tasks (e.g. the admin
@@ -283,6 +319,33 @@ impl AppState {
             .map(|s| s.expose().to_string())
     }
 
+    /// Signer for hosted terraform provider `SHA256SUMS`: the repository signer
+    /// when rpm/deb built one, else loaded (or generated) on the first call, so a
+    /// proxy-only terraform instance never mints a key. A bad key is logged, not
+    /// fatal and not remembered: provider publishing stays disabled until a later
+    /// call loads the fixed key.
+    pub async fn terraform_signer(&self) -> Option<Arc<signing::RepoSigner>> {
+        if let Some(ref signer) = self.signer {
+            return Some(Arc::clone(signer));
+        }
+        let config = Arc::clone(&self.config);
+        let loaded = self
+            .terraform_signer
+            .get_or_try_init(|| async move {
+                tokio::task::spawn_blocking(move || load_repo_signer(&config))
+                    .await
+                    .map_err(|e| format!("signing key task failed: {e}"))?
+            })
+            .await;
+        match loaded {
+            Ok(signer) => signer.clone(),
+            Err(e) => {
+                error!(error = %e, "repository signing key error; provider publishing disabled");
+                None
+            }
+        }
+    }
+
     /// Get or create a per-key publish lock for TOCTOU protection.
     pub fn publish_lock(&self, key: &str) -> Arc<tokio::sync::Mutex<()>> {
         acquire_publish_lock(&self.publish_locks, key)
@@ -701,6 +764,7 @@ async fn main() {
                 &storage,
                 &cli_publish_locks,
                 None,
+                None,
                 &config.retention.rules,
                 true,
             )
@@ -731,6 +795,7 @@ async fn main() {
                     &storage,
                     &cli_publish_locks,
                     None,
+                    None,
                     &config.retention.rules,
                     true,
                 )
@@ -760,10 +825,12 @@ async fn main() {
                 // same key the server would, or clients start failing
                 // verification after a CLI retention pass.
                 let signer = build_signer(&config, &config.enabled_registries());
+                let apk_signer = build_apk_signer(&config, &config.enabled_registries());
                 let result = retention::run_retention(
                     &storage,
                     &cli_publish_locks,
                     signer.as_deref(),
+                    apk_signer.as_deref(),
                     &config.retention.rules,
                     false,
                 )
@@ -869,6 +936,7 @@ async fn main() {
                 run_curation_explain(&config, &package);
             }
         },
+        Some(Commands::Vulndb { action }) => run_vulndb(&config, action),
         Some(Commands::Import { action }) => {
             if let Err(e) = import::run(action, &storage, &config).await {
                 error!("Import failed: {}", e);
@@ -969,16 +1037,32 @@ async fn main() {
 /// — there is no local data directory to keep a generated key in). A present
 /// but unreadable/corrupt key is fatal: silently serving unsigned (or with a
 /// silently rotated key) would break every client pinning the public key.
+/// Terraform loads the same key lazily ([`AppState::terraform_signer`]).
 fn build_signer(
     config: &config::Config,
     enabled: &std::collections::HashSet<RegistryType>,
 ) -> Option<Arc<signing::RepoSigner>> {
-    if !enabled.contains(&RegistryType::Rpm) && !enabled.contains(&RegistryType::Deb) {
+    if ![RegistryType::Rpm, RegistryType::Deb]
+        .iter()
+        .any(|r| enabled.contains(r))
+    {
         return None;
     }
+    match load_repo_signer(config) {
+        Ok(signer) => signer,
+        Err(e) => {
+            eprintln!("Fatal: repository index signing key error: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Load or generate the repository signing key per `[signing]`; `Ok(None)` when
+/// signing is off or has nowhere to keep a key.
+fn load_repo_signer(config: &config::Config) -> Result<Option<Arc<signing::RepoSigner>>, String> {
     if !config.signing.enabled {
         info!("repository index signing disabled by config");
-        return None;
+        return Ok(None);
     }
     let path = if !config.signing.key_path.is_empty() {
         std::path::PathBuf::from(&config.signing.key_path)
@@ -989,7 +1073,7 @@ fn build_signer(
             "repository index signing disabled: storage is not local and signing.key_path \
              is not set (NORA_SIGNING_KEY_PATH)"
         );
-        return None;
+        return Ok(None);
     };
     match signing::RepoSigner::load_or_generate(&path) {
         Ok(signer) => {
@@ -1007,21 +1091,62 @@ fn build_signer(
                      Provision one key and mount it read-only on every replica."
                 );
             }
+            Ok(Some(Arc::new(signer)))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Build the apk index signer — [`build_signer`]'s counterpart for the RSA
+/// key apk verifies `APKINDEX.tar.gz` with. Same rules: off with signing,
+/// off on object storage without `signing.apk_key_path`, fatal on a
+/// present-but-unusable key.
+fn build_apk_signer(
+    config: &config::Config,
+    enabled: &std::collections::HashSet<RegistryType>,
+) -> Option<Arc<signing::ApkSigner>> {
+    if !enabled.contains(&RegistryType::Apk) || !config.signing.enabled {
+        return None;
+    }
+    let path = if !config.signing.apk_key_path.is_empty() {
+        std::path::PathBuf::from(&config.signing.apk_key_path)
+    } else if config.storage.mode == config::StorageMode::Local {
+        std::path::Path::new(&config.storage.path).join(".signing/nora-apk.rsa")
+    } else {
+        tracing::warn!(
+            "apk index signing disabled: storage is not local and signing.apk_key_path \
+             is not set (NORA_SIGNING_APK_KEY_PATH)"
+        );
+        return None;
+    };
+    match signing::ApkSigner::load_or_generate(&path, &config.signing.apk_key_name) {
+        Ok(signer) => {
+            info!(
+                key_name = %signer.key_name(),
+                path = %path.display(),
+                "apk index signing enabled"
+            );
+            if signer.was_generated() && config.storage.mode != config::StorageMode::Local {
+                tracing::warn!(
+                    path = %path.display(),
+                    "apk signing key was GENERATED on this boot while artifacts live in an \
+                     object store — provision one key and mount it read-only on every replica."
+                );
+            }
             Some(Arc::new(signer))
         }
         Err(e) => {
-            eprintln!("Fatal: repository index signing key error: {e}");
+            eprintln!("Fatal: apk index signing key error: {e}");
             std::process::exit(1);
         }
     }
 }
 
-/// Load per-registry min_release_age overrides from CurationConfig into the filter.
-fn load_registry_overrides(
-    filter: &mut curation::MinReleaseAgeFilter,
+/// The `[curation.{registry}]` override tables, by registry.
+fn registry_curation_overrides(
     curation_config: &config::CurationConfig,
-) {
-    let registry_overrides: &[(RegistryType, &config::RegistryCurationOverride)] = &[
+) -> [(RegistryType, &config::RegistryCurationOverride); 16] {
+    [
         (RegistryType::Npm, &curation_config.npm),
         (RegistryType::PyPI, &curation_config.pypi),
         (RegistryType::Cargo, &curation_config.cargo),
@@ -1034,13 +1159,85 @@ fn load_registry_overrides(
         (RegistryType::Nuget, &curation_config.nuget),
         (RegistryType::PubDart, &curation_config.pub_dart),
         (RegistryType::Conan, &curation_config.conan),
-    ];
+        (RegistryType::Helm, &curation_config.helm),
+        (RegistryType::Apk, &curation_config.apk),
+        (RegistryType::Conda, &curation_config.conda),
+        (RegistryType::Composer, &curation_config.composer),
+    ]
+}
+
+/// License filter with `[curation.{registry}]` allow/deny overrides applied;
+/// an override replaces only the list it sets.
+fn license_filter(curation_config: &config::CurationConfig) -> curation::LicenseFilter {
+    let licenses = &curation_config.licenses;
+    let mut filter = curation::LicenseFilter::new(licenses);
+    for (registry, override_cfg) in registry_curation_overrides(curation_config) {
+        if override_cfg.license_allow.is_none() && override_cfg.license_deny.is_none() {
+            continue;
+        }
+        filter.add_override(
+            registry,
+            license::LicensePolicy::new(
+                override_cfg
+                    .license_allow
+                    .as_deref()
+                    .unwrap_or(&licenses.allow),
+                override_cfg
+                    .license_deny
+                    .as_deref()
+                    .unwrap_or(&licenses.deny),
+            ),
+        );
+    }
+    filter
+}
+
+/// Typosquat filter comparing each registry with `[curation.typosquat].corpus_path`,
+/// its own `[curation.{registry}].typosquat_corpus`, and the scoped internal
+/// namespaces — a lookalike of `@acme/*` is dependency confusion by another name.
+fn typosquat_filter(
+    curation_config: &config::CurationConfig,
+) -> Result<curation::TyposquatFilter, String> {
+    let mut shared = match curation_config.typosquat.corpus_path {
+        Some(ref path) => typosquat::read_names(path)?,
+        None => Vec::new(),
+    };
+    shared.extend(
+        curation_config
+            .internal_namespaces
+            .iter()
+            .filter(|ns| ns.ends_with("/*") || ns.ends_with("/**"))
+            .cloned(),
+    );
+    let mut own = HashMap::new();
+    for (registry, override_cfg) in registry_curation_overrides(curation_config) {
+        if let Some(ref path) = override_cfg.typosquat_corpus {
+            own.insert(registry, typosquat::read_names(path)?);
+        }
+    }
+    let mut filter = curation::TyposquatFilter::new(&curation_config.typosquat);
+    for &registry in RegistryType::all() {
+        let names = shared
+            .iter()
+            .chain(own.get(&registry).into_iter().flatten());
+        let corpus = typosquat::Corpus::new(registry, names.map(String::as_str));
+        if !corpus.is_empty() {
+            filter.add_corpus(registry, corpus);
+        }
+    }
+    Ok(filter)
+}
 
-    for (registry, override_cfg) in registry_overrides {
+/// Load per-registry min_release_age overrides from CurationConfig into the filter.
+fn load_registry_overrides(
+    filter: &mut curation::MinReleaseAgeFilter,
+    curation_config: &config::CurationConfig,
+) {
+    for (registry, override_cfg) in registry_curation_overrides(curation_config) {
         if let Some(ref age_str) = override_cfg.min_release_age {
             match curation::parse_duration(age_str) {
                 Ok(secs) => {
-                    filter.add_override(*registry, secs, age_str.clone());
+                    filter.add_override(registry, secs, age_str.clone());
                     tracing::info!(
                         registry = %registry,
                         min_age = %age_str,
@@ -1061,6 +1258,47 @@ fn load_registry_overrides(
     }
 }
 
+fn run_vulndb(config: &Config, action: VulndbCommand) {
+    let db_path = config.vulndb_path();
+    match action {
+        VulndbCommand::Import { file, replace } => match vulndb::import(&file, &db_path, replace) {
+            Ok(stats) => {
+                println!("Imported {} into {}", file.display(), db_path);
+                println!("  Added/updated:  {}", stats.imported);
+                println!("  Skipped:        {}", stats.skipped);
+                println!("  Withdrawn:      {}", stats.withdrawn);
+                println!("  Invalid:        {}", stats.invalid);
+                println!("  Total:          {}", stats.total);
+                println!("\nSend SIGHUP to a running server to load the new database.");
+            }
+            Err(e) => {
+                eprintln!("ERROR: {}", e);
+                std::process::exit(1);
+            }
+        },
+        VulndbCommand::Status => match vulndb::VulnDb::load(&db_path) {
+            Ok(db) => {
+                println!("Database:    {}", db_path);
+                println!("Imported at: {}", db.imported_at());
+                println!("Advisories:  {}", db.advisory_count());
+                println!("Ecosystems:  {}", db.ecosystems().join(", "));
+                println!(
+                    "Filter:      {}",
+                    if config.curation.vulnerabilities.enabled {
+                        "enabled"
+                    } else {
+                        "disabled ([curation.vulnerabilities] enabled = false)"
+                    }
+                );
+            }
+            Err(e) => {
+                eprintln!("ERROR: {}", e);
+                std::process::exit(1);
+            }
+        },
+    }
+}
+
 fn run_curation_validate(file: &Path) {
     let content = match std::fs::read_to_string(file) {
         Ok(c) => c,
@@ -1196,6 +1434,18 @@ fn run_curation_explain(config: &Config, package_spec: &str) {
         println!("Allowlist: not configured");
     }
 
+    if config.curation.typosquat.enabled {
+        match typosquat_filter(&config.curation) {
+            Ok(filter) => {
+                println!("Typosquat: {} registries", filter.registry_count());
+                engine.add_filter(Box::new(filter));
+            }
+            Err(e) => println!("Typosquat: ERROR: {}", e),
+        }
+    } else {
+        println!("Typosquat: not configured");
+    }
+
     if !config.curation.internal_namespaces.is_empty() {
         let ns_filter = curation::NamespaceFilter::new(config.curation.internal_namespaces.clone());
         println!("Namespaces: {} patterns", ns_filter.pattern_count());
@@ -1229,6 +1479,7 @@ fn run_curation_explain(config: &Config, package_spec: &str) {
         integrity: None,
         bypass: false,
         publish_date: None,
+        license: None,
     };
 
     let result = engine.evaluate(&request);
@@ -1446,9 +1697,9 @@ async fn run_server(mut config: Config, storage: Storage) {
     // Build curation engine (shared helper, also used by SIGHUP reload).
     // Fail-closed: in enforce mode an unparsable filter aborts boot rather
     // than starting in a silent allow-all state (#586).
-    let curation_engine = build_curation_engine(&config)
-        .unwrap_or_else(|e| panic!("Cannot start in enforce mode: {e}"));
-    if curation_engine.is_active() {
+    let reloadable_config =
+        build_reloadable(&config).unwrap_or_else(|e| panic!("Cannot start in enforce mode: {e}"));
+    if reloadable_config.curation_engine.is_active() {
         info!(
             mode = %config.curation.mode,
             "Curation layer active"
@@ -1506,6 +1757,17 @@ async fn run_server(mut config: Config, storage: Storage) {
             }
             RegistryType::Rpm => registry_routes = registry_routes.merge(registry::rpm_routes()),
             RegistryType::Deb => registry_routes = registry_routes.merge(registry::deb_routes()),
+            RegistryType::Helm => registry_routes = registry_routes.merge(registry::helm_routes()),
+            RegistryType::Apk => registry_routes = registry_routes.merge(registry::apk_routes()),
+            RegistryType::Conda => {
+                registry_routes = registry_routes.merge(registry::conda_routes())
+            }
+            RegistryType::Composer => {
+                registry_routes = registry_routes.merge(registry::composer_routes())
+            }
+            RegistryType::Swift => {
+                registry_routes = registry_routes.merge(registry::swift_routes())
+            }
         }
     }
 
@@ -1567,11 +1829,7 @@ async fn run_server(mut config: Config, storage: Storage) {
         None
     };
 
-    let bypass_token = config.curation.bypass_token.clone();
-    let reloadable = Arc::new(ArcSwap::from_pointee(ReloadableConfig {
-        curation_engine,
-        bypass_token,
-    }));
+    let reloadable = Arc::new(ArcSwap::from_pointee(reloadable_config));
 
     let leak_finders = metrics::LeakFinders::new(config.upstream_hostnames());
 
@@ -1580,6 +1838,7 @@ async fn run_server(mut config: Config, storage: Storage) {
     // before AppState so on-demand handlers (admin reindex warm-up) can observe it.
     let cancel_token = tokio_util::sync::CancellationToken::new();
     let signer = build_signer(&config, &enabled_registries);
+    let apk_signer = build_apk_signer(&config, &enabled_registries);
 
     let state = AppState {
         storage,
@@ -1603,7 +1862,10 @@ async fn run_server(mut config: Config, storage: Storage) {
         circuit_breaker: Arc::new(circuit_breaker::CircuitBreakerRegistry::new(cb_config)),
         proxy_coalesce: proxy_coalesce::InflightMap::new(),
         digest_store,
+        cosign_children: Arc::new(registry::docker_cosign::VerifiedChildren::new()),
         signer,
+        terraform_signer: Arc::new(tokio::sync::OnceCell::new()),
+        apk_signer,
         leak_finders,
         cancel_token: cancel_token.clone(),
     };
@@ -1628,9 +1890,11 @@ async fn run_server(mut config: Config, storage: Storage) {
         let storage = state.storage.clone();
         let publish_locks = state.publish_locks.clone();
         let signer = state.signer.clone();
+        let apk_signer = state.apk_signer.clone();
         let rules = state.config.retention.rules.clone();
         let dry_run = state.config.retention.dry_run;
         let audit = state.audit.clone();
+        let repo_index = Arc::clone(&state.repo_index);
         cleanup_passes.push(cleanup::CleanupPass {
             name: "retention",
             interval: std::time::Duration::from_secs(state.config.retention.interval),
@@ -1638,8 +1902,10 @@ async fn run_server(mut config: Config, storage: Storage) {
                 let storage = storage.clone();
                 let publish_locks = publish_locks.clone();
                 let signer = signer.clone();
+                let apk_signer = apk_signer.clone();
                 let rules = rules.clone();
                 let audit = audit.clone();
+                let repo_index = Arc::clone(&repo_index);
                 async move {
                     info!(
                         dry_run = dry_run,
@@ -1649,6 +1915,7 @@ async fn run_server(mut config: Config, storage: Storage) {
                         &storage,
                         &publish_locks,
                         signer.as_deref(),
+                        apk_signer.as_deref(),
                         &rules,
                         dry_run,
                     )
@@ -1658,6 +1925,11 @@ async fn run_server(mut config: Config, storage: Storage) {
                         result.duration_secs, result.planned, result.deleted_keys, result.bytes_freed
                     );
 
+                    // Deleted versions must drop out of the indexes that list
+                    // them (dashboard, docker `_catalog` and `tags/list`).
+                    if result.deleted_keys > 0 {
+                        repo_index.invalidate_all();
+                    }
                     if result.planned > 0 {
                         audit.log(audit::AuditEntry::new(
                             "retention-apply",
@@ -1687,12 +1959,14 @@ async fn run_server(mut config: Config, storage: Storage) {
         let publish_locks = state.publish_locks.clone();
         let dry_run = state.config.gc.dry_run;
         let grace_secs = state.config.gc.grace_secs;
+        let repo_index = Arc::clone(&state.repo_index);
         cleanup_passes.push(cleanup::CleanupPass {
             name: "gc",
             interval: std::time::Duration::from_secs(state.config.gc.interval),
             run: Box::new(move || {
                 let storage = storage.clone();
                 let publish_locks = publish_locks.clone();
+                let repo_index = Arc::clone(&repo_index);
                 async move {
                     info!("GC scheduler: starting periodic run");
                     let result = gc::run_gc(&storage, &publish_locks, dry_run, grace_secs).await;
@@ -1701,6 +1975,11 @@ async fn run_server(mut config: Config, storage: Storage) {
                         result.duration_secs, result.orphaned, result.deleted, result.bytes_freed,
                         result.metadata_phantoms_removed, result.skipped_recent
                     );
+                    // As after retention: deleted blobs and phantoms must drop
+                    // out of the indexes that list them.
+                    if result.deleted > 0 || result.metadata_phantoms_removed > 0 {
+                        repo_index.invalidate_all();
+                    }
                 }
                 .boxed()
             }),
@@ -1974,24 +2253,68 @@ async fn shutdown_signal() {
 ///
 /// Re-reads config.toml, rebuilds the CurationEngine with new filters,
 /// and atomically swaps the old config via ArcSwap.
-/// Storage, auth, port, and other settings are NOT reloaded — only curation.
+/// Storage, auth, port, and other settings are NOT reloaded — only curation and
+/// the cosign trust material.
 fn reload_curation(state: &AppState) -> Result<(), String> {
     let config = Config::try_load()?;
 
-    // Fail-closed: `build_curation_engine` returns Err in enforce mode if any
+    // Fail-closed: `build_reloadable` returns Err in enforce mode if any
     // filter no longer parses, so a broken allowlist surfaces here and the
     // `?` short-circuits BEFORE the `store` below — the previous (working)
     // engine is kept and never swapped for an allow-all one (#586).
-    let engine = build_curation_engine(&config)?;
-
-    state.reloadable.store(Arc::new(ReloadableConfig {
-        curation_engine: engine,
-        bypass_token: config.curation.bypass_token,
-    }));
+    let reloadable = build_reloadable(&config)?;
+    state.reloadable.store(Arc::new(reloadable));
 
     Ok(())
 }
 
+/// Build the hot-reloadable state (used at startup and reload): the vulnerability
+/// database, if enabled and imported, the curation engine built on it, and the
+/// cosign trust material.
+fn build_reloadable(config: &Config) -> Result<ReloadableConfig, String> {
+    let vulndb = load_vulndb(config)?;
+    let curation_engine = build_curation_engine(config, vulndb.as_ref())?;
+    let cosign = registry::docker_cosign::CosignTrust::load(&config.docker.upstreams)?;
+    Ok(ReloadableConfig {
+        curation_engine,
+        bypass_token: config.curation.bypass_token.clone(),
+        vulndb,
+        cosign,
+    })
+}
+
+/// Load the imported vulnerability database, `None` when `[curation.vulnerabilities]`
+/// is not enabled or none has been imported.
+///
+/// A database that fails to load is fatal only when the enforce-mode vulnerability
+/// filter depends on it; otherwise it is logged and the audit endpoints fall back to
+/// the upstream.
+fn load_vulndb(config: &Config) -> Result<Option<Arc<vulndb::VulnDb>>, String> {
+    let path = config.vulndb_path();
+    if !config.curation.vulnerabilities.enabled || !std::path::Path::new(&path).exists() {
+        return Ok(None);
+    }
+    match vulndb::VulnDb::load(&path) {
+        Ok(db) => {
+            info!(
+                path = %path,
+                advisories = db.advisory_count(),
+                ecosystems = %db.ecosystems().join(","),
+                imported_at = %db.imported_at(),
+                "Vulnerability database loaded"
+            );
+            Ok(Some(Arc::new(db)))
+        }
+        Err(e) if config.curation.mode == CurationMode::Enforce => {
+            Err(format!("invalid vulnerability database {path}: {e}"))
+        }
+        Err(e) => {
+            error!(path = %path, error = %e, "Failed to load vulnerability database");
+            Ok(None)
+        }
+    }
+}
+
 /// Build a CurationEngine from the given config (used at startup and reload).
 ///
 /// Fail-closed in enforce mode: if a configured filter fails to parse, this
@@ -2003,7 +2326,10 @@ fn reload_curation(state: &AppState) -> Result<(), String> {
 ///
 /// In audit/off mode a parse error is logged and the filter dropped (the
 /// engine is advisory there), so this never returns `Err`.
-fn build_curation_engine(config: &Config) -> Result<curation::CurationEngine, String> {
+fn build_curation_engine(
+    config: &Config,
+    vulndb: Option<&Arc<vulndb::VulnDb>>,
+) -> Result<curation::CurationEngine, String> {
     let enforce = config.curation.mode == CurationMode::Enforce;
     let mut engine = curation::CurationEngine::new(config.curation.clone());
 
@@ -2020,6 +2346,44 @@ fn build_curation_engine(config: &Config) -> Result<curation::CurationEngine, St
         }
     }
 
+    // Load vulnerability filter if enabled. Ahead of the allowlist: an approved
+    // package version is still blocked once an advisory names it.
+    if config.curation.vulnerabilities.enabled {
+        let path = config.vulndb_path();
+        match vulndb {
+            Some(db) => {
+                let mut filter = curation::VulnerabilityFilter::new(
+                    Arc::clone(db),
+                    &config.curation.vulnerabilities,
+                );
+                for (registry, override_cfg) in registry_curation_overrides(&config.curation) {
+                    if let Some(severity) = override_cfg.min_severity {
+                        filter.add_override(registry, severity);
+                    }
+                }
+                engine.add_filter(Box::new(filter));
+                info!(path = %path, "Vulnerability filter loaded");
+            }
+            None if enforce => {
+                return Err(format!(
+                    "vulnerability database {path} is not loaded — run `nora vulndb import`"
+                ))
+            }
+            None => error!(path = %path, "Vulnerability filter enabled without a database"),
+        }
+    }
+
+    // License policy: also ahead of the allowlist, which approves packages, not
+    // their licenses.
+    if config.curation.licenses.enabled {
+        engine.add_filter(Box::new(license_filter(&config.curation)));
+        info!(
+            allow = config.curation.licenses.allow.len(),
+            deny = config.curation.licenses.deny.len(),
+            "License filter loaded"
+        );
+    }
+
     // Load allowlist filter if configured
     if let Some(ref path) = config.curation.allowlist_path {
         match curation::AllowlistFilter::from_file(path, config.curation.require_integrity) {
@@ -2033,6 +2397,20 @@ fn build_curation_engine(config: &Config) -> Result<curation::CurationEngine, St
         }
     }
 
+    // Typosquat filter: after the allowlist, so an approved name is never
+    // second-guessed for resembling a popular one.
+    if config.curation.typosquat.enabled {
+        match typosquat_filter(&config.curation) {
+            Ok(filter) => {
+                let count = filter.registry_count();
+                engine.add_filter(Box::new(filter));
+                info!(registries = count, "Typosquat filter loaded");
+            }
+            Err(e) if enforce => return Err(format!("invalid typosquat corpus: {e}")),
+            Err(e) => error!(error = %e, "Failed to load typosquat corpus"),
+        }
+    }
+
     // Load namespace isolation filter if configured
     if !config.curation.internal_namespaces.is_empty() {
         let ns_filter = curation::NamespaceFilter::new(config.curation.internal_namespaces.clone());
@@ -2173,8 +2551,10 @@ mod proxy_tests {
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod curation_reload_tests {
-    use super::build_curation_engine;
+    use super::build_reloadable;
     use crate::config::{Config, CurationConfig, CurationMode};
+    use crate::curation;
+    use crate::registry_type::RegistryType;
 
     fn config_with_allowlist(mode: CurationMode, allowlist_path: String) -> Config {
         Config {
@@ -2189,7 +2569,7 @@ mod curation_reload_tests {
 
     /// Regression for #586: a SIGHUP reload must NOT swap to an allow-all engine
     /// when the allowlist no longer parses. `reload_curation()` gates its
-    /// `ArcSwap::store` on `build_curation_engine(&config)?`, so this is the
+    /// `ArcSwap::store` on `build_reloadable(&config)?`, so this is the
     /// exact function the production reload path runs: in enforce mode a
     /// malformed allowlist must return `Err` (so `?` short-circuits before the
     /// swap and the previous engine survives), not a silently-dropped,
@@ -2201,7 +2581,7 @@ mod curation_reload_tests {
         std::fs::write(&path, b"{ not valid json").unwrap();
 
         let config = config_with_allowlist(CurationMode::Enforce, path.to_str().unwrap().into());
-        let result = build_curation_engine(&config);
+        let result = build_reloadable(&config);
         assert!(
             result.is_err(),
             "enforce mode must reject a malformed allowlist, got an engine"
@@ -2217,10 +2597,52 @@ mod curation_reload_tests {
         std::fs::write(&path, br#"{"version": 1, "entries": []}"#).unwrap();
 
         let config = config_with_allowlist(CurationMode::Enforce, path.to_str().unwrap().into());
-        let engine = build_curation_engine(&config).expect("valid allowlist must build");
+        let engine = build_reloadable(&config)
+            .expect("valid allowlist must build")
+            .curation_engine;
         assert!(engine.is_active(), "allowlist filter must be installed");
     }
 
+    /// The vulnerability database is a curation input like the allowlist: a missing
+    /// or corrupt database in enforce mode refuses the build (startup fails, a SIGHUP
+    /// reload keeps the previous engine); a good one is installed.
+    #[test]
+    fn enforce_requires_loadable_vulnerability_db() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db_path = dir.path().join("osv.json");
+        let mut config = Config {
+            curation: CurationConfig {
+                mode: CurationMode::Enforce,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        config.curation.vulnerabilities.enabled = true;
+        config.curation.vulnerabilities.db_path = Some(db_path.to_str().unwrap().into());
+        assert!(build_reloadable(&config).is_err());
+
+        let dump =
+            crate::vulndb::tests::osv_zip(dir.path(), &[crate::vulndb::tests::lodash_advisory()]);
+        crate::vulndb::import(&dump, db_path.to_str().unwrap(), false).unwrap();
+        let reloadable = build_reloadable(&config).expect("imported database must load");
+        assert!(reloadable.vulndb.is_some());
+        let engine = reloadable.curation_engine;
+        let request = curation::FilterRequest {
+            registry: RegistryType::Npm,
+            upstream: None,
+            name: "lodash".into(),
+            version: Some("4.17.20".into()),
+            integrity: None,
+            bypass: false,
+            publish_date: None,
+            license: None,
+        };
+        assert_eq!(
+            engine.evaluate(&request).decided_by.as_deref(),
+            Some("vulnerability")
+        );
+    }
+
     /// Audit/off mode stays lenient (matches boot behavior): a broken file
     /// there is logged and dropped, never blocking the build, since the engine
     /// is advisory.
@@ -2231,6 +2653,6 @@ mod curation_reload_tests {
         std::fs::write(&path, b"garbage").unwrap();
 
         let config = config_with_allowlist(CurationMode::Off, path.to_str().unwrap().into());
-        assert!(build_curation_engine(&config).is_ok());
+        assert!(build_reloadable(&config).is_ok());
     }
 }
@@ -35,12 +35,13 @@ pub static HTTP_REQUESTS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
 /// telemetry — previously curation only kept internal counters, so an operator
 /// could not see from Prometheus how often curation allowed vs blocked.
 /// Labels: `decision` ∈ {allow, block, audit (would-block, allowed in audit
-/// mode), skip}.
+/// mode), skip}; `filter` = the filter that decided (`typosquat`, `blocklist`,
+/// `namespace`, …), or `none` when every filter skipped.
 pub static CURATION_DECISIONS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
     register_int_counter_vec!(
         "nora_curation_decisions_total",
-        "Curation engine decisions by effective outcome",
-        &["decision"]
+        "Curation engine decisions by effective outcome and deciding filter",
+        &["decision", "filter"]
     )
     .expect("failed to create CURATION_DECISIONS_TOTAL metric at startup")
 });
@@ -80,6 +81,41 @@ pub static QUARANTINE_HOLDS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
     .expect("failed to create QUARANTINE_HOLDS_TOTAL metric at startup")
 });
 
+/// Downloads that matched a known vulnerability, by registry, the highest
+/// matched severity (`unrated` when no advisory carries one), and what the
+/// filter did (`block` = curation block; `audit` = logged, served).
+pub static VULNERABILITY_MATCHES_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "nora_vulnerability_matches_total",
+        "Downloads matching a vulnerability advisory, by registry, severity and action",
+        &["registry", "severity", "action"]
+    )
+    .expect("failed to create VULNERABILITY_MATCHES_TOTAL metric at startup")
+});
+
+/// Downloads whose declared license the license policy rejects, by registry and
+/// what the filter did (`block` = curation block; `audit` = logged, served).
+pub static LICENSE_VIOLATIONS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "nora_license_violations_total",
+        "Downloads rejected by the license policy, by registry and action",
+        &["registry", "action"]
+    )
+    .expect("failed to create LICENSE_VIOLATIONS_TOTAL metric at startup")
+});
+
+/// Cosign signature checks on Docker proxy pulls, by registry, result
+/// (`verified`/`unsigned`/`invalid`/`unavailable`) and policy mode
+/// (`enforce` blocked the pull unless verified; `audit` served it anyway).
+pub static SIGNATURE_VERIFICATIONS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "nora_signature_verifications_total",
+        "Cosign signature verifications on proxy pulls, by registry, result and mode",
+        &["registry", "result", "mode"]
+    )
+    .expect("failed to create SIGNATURE_VERIFICATIONS_TOTAL metric at startup")
+});
+
 /// Conditional revalidations where upstream answered 304 Not Modified — the
 /// cached body was reused and no body bytes were downloaded (#596).
 pub static PROXY_UPSTREAM_304_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
@@ -730,7 +766,10 @@ fn detect_registry(path: &str) -> String {
         "npm".to_string()
     } else if path.starts_with("/cargo") {
         "cargo".to_string()
-    } else if path.starts_with("/simple") || path.starts_with("/packages") {
+    } else if path.starts_with("/simple")
+        || path.starts_with("/packages")
+        || path.starts_with("/pypi/")
+    {
         "pypi".to_string()
     } else if path.starts_with("/go/") {
         "go".to_string()
@@ -752,6 +791,16 @@ fn detect_registry(path: &str) -> String {
         "rpm".to_string()
     } else if path.starts_with("/deb/") {
         "deb".to_string()
+    } else if path.starts_with("/helm/") {
+        "helm".to_string()
+    } else if path.starts_with("/apk/") {
+        "apk".to_string()
+    } else if path.starts_with("/conda/") {
+        "conda".to_string()
+    } else if path.starts_with("/composer/") {
+        "composer".to_string()
+    } else if path.starts_with("/swift/") {
+        "swift".to_string()
     } else if path.starts_with("/ui") {
         "ui".to_string()
     } else {
@@ -792,12 +841,56 @@ mod tests {
     #[test]
     fn test_detect_registry_pypi() {
         assert_eq!(detect_registry("/simple/requests/"), "pypi");
+        assert_eq!(detect_registry("/pypi/requests/json"), "pypi");
         assert_eq!(
             detect_registry("/packages/requests/1.0/requests-1.0.tar.gz"),
             "pypi"
         );
     }
 
+    #[test]
+    fn test_detect_registry_helm() {
+        assert_eq!(detect_registry("/helm/index.yaml"), "helm");
+        assert_eq!(
+            detect_registry("/helm/bitnami/charts/nginx-1.0.0.tgz"),
+            "helm"
+        );
+        assert_eq!(detect_registry("/helmfile"), "other");
+    }
+
+    #[test]
+    fn test_detect_registry_apk() {
+        assert_eq!(
+            detect_registry("/apk/alpine/v3.20/main/x86_64/APKINDEX.tar.gz"),
+            "apk"
+        );
+        assert_eq!(detect_registry("/apkindex"), "other");
+    }
+
+    #[test]
+    fn test_detect_registry_conda() {
+        assert_eq!(
+            detect_registry("/conda/conda-forge/linux-64/repodata.json"),
+            "conda"
+        );
+        assert_eq!(detect_registry("/condarc"), "other");
+    }
+
+    #[test]
+    fn test_detect_registry_composer() {
+        assert_eq!(
+            detect_registry("/composer/p2/monolog/monolog.json"),
+            "composer"
+        );
+        assert_eq!(detect_registry("/composer.json"), "other");
+    }
+
+    #[test]
+    fn test_detect_registry_swift() {
+        assert_eq!(detect_registry("/swift/mona/LinkedList/1.0.0.zip"), "swift");
+        assert_eq!(detect_registry("/swiftlint"), "other");
+    }
+
     #[test]
     fn test_detect_registry_ui() {
         assert_eq!(detect_registry("/ui/dashboard"), "ui");
@@ -975,6 +1068,11 @@ mod tests {
             "/conan/v2/conans/zlib",
             "/rpm/myrepo/repodata/repomd.xml",
             "/deb/myrepo/Packages",
+            "/helm/index.yaml",
+            "/apk/myrepo/v3.20/main/x86_64/APKINDEX.tar.gz",
+            "/conda/mychannel/noarch/repodata.json",
+            "/composer/p2/monolog/monolog.json",
+            "/swift/mona/LinkedList/1.0.0",
         ] {
             assert!(
                 !is_own_surface(p),
@@ -40,14 +40,19 @@ use crate::AppState;
         (name = "pypi", description = "PyPI Simple API"),
         (name = "go", description = "Go Module Proxy API"),
         (name = "raw", description = "Raw File Storage API"),
-        (name = "gems", description = "RubyGems Proxy API"),
+        (name = "gems", description = "RubyGems API (hosted + proxy)"),
         (name = "terraform", description = "Terraform Registry Proxy API"),
-        (name = "ansible", description = "Ansible Galaxy Proxy API"),
-        (name = "nuget", description = "NuGet v3 Registry Proxy API"),
-        (name = "pub", description = "Dart/Flutter Pub Registry Proxy API"),
-        (name = "conan", description = "Conan V2 Registry Proxy API (C/C++)"),
+        (name = "ansible", description = "Ansible Galaxy API (v3)"),
+        (name = "nuget", description = "NuGet v3 API (hosted + proxy)"),
+        (name = "pub", description = "Dart/Flutter Pub Registry API"),
+        (name = "conan", description = "Conan V2 Registry API (C/C++)"),
         (name = "rpm", description = "RPM (yum/dnf) Hosted & Pull-Through Repository API"),
         (name = "deb", description = "Debian (APT) Hosted & Pull-Through Repository API"),
+        (name = "helm", description = "Helm Chart Repository & ChartMuseum API"),
+        (name = "apk", description = "Alpine (apk) Hosted & Pull-Through Repository API"),
+        (name = "conda", description = "Conda Hosted & Pull-Through Channel API"),
+        (name = "composer", description = "PHP Composer Repository API (hosted + Packagist proxy)"),
+        (name = "swift", description = "Swift Package Registry API (SE-0292, hosted)"),
         (name = "auth", description = "Authentication & API Tokens")
     ),
     paths(
@@ -65,6 +70,7 @@ use crate::AppState;
         crate::openapi::docker_manifest_get,
         crate::openapi::docker_blob_head,
         crate::openapi::docker_blob_get,
+        crate::openapi::docker_referrers,
         // Docker - Write
         crate::openapi::docker_manifest_put,
         crate::openapi::docker_manifest_delete,
@@ -77,42 +83,80 @@ use crate::AppState;
         // npm
         crate::openapi::npm_package,
         crate::openapi::npm_publish,
+        crate::openapi::npm_search,
+        crate::openapi::npm_dist_tags,
+        crate::openapi::npm_dist_tag_put,
+        crate::openapi::npm_dist_tag_delete,
+        crate::openapi::npm_unpublish,
         // Cargo
         crate::openapi::cargo_index_config,
         crate::openapi::cargo_sparse_index,
         crate::openapi::cargo_metadata,
         crate::openapi::cargo_download,
         crate::openapi::cargo_publish,
+        crate::openapi::cargo_yank,
+        crate::openapi::cargo_unyank,
+        crate::openapi::cargo_owners,
+        crate::openapi::cargo_owners_add,
+        crate::openapi::cargo_owners_remove,
+        crate::openapi::cargo_search,
         // PyPI
         crate::openapi::pypi_simple,
         crate::openapi::pypi_package,
         crate::openapi::pypi_upload,
+        crate::openapi::pypi_core_metadata,
+        crate::openapi::pypi_project_json,
+        crate::openapi::pypi_release_json,
+        crate::openapi::pypi_yank,
+        crate::openapi::pypi_unyank,
         // Go
         crate::openapi::go_module_latest,
         crate::openapi::go_module_info,
         crate::openapi::go_module_mod,
         crate::openapi::go_module_zip,
+        crate::openapi::go_module_upload,
+        crate::openapi::go_sumdb_supported,
+        crate::openapi::go_sumdb_lookup,
+        crate::openapi::go_sumdb_tile,
+        crate::openapi::go_sumdb_latest,
         // Raw
         crate::openapi::raw_file_get,
         crate::openapi::raw_file_put,
         // RubyGems
         crate::openapi::gems_info,
+        crate::openapi::gems_versions,
         crate::openapi::gems_download,
+        crate::openapi::gems_push,
+        crate::openapi::gems_yank,
         // Terraform
         crate::openapi::terraform_service_discovery,
         crate::openapi::terraform_provider_versions,
+        crate::openapi::terraform_provider_upload,
+        crate::openapi::terraform_module_upload,
         // Ansible Galaxy
         crate::openapi::ansible_collection_list,
         crate::openapi::ansible_download,
+        crate::openapi::ansible_collection_upload,
+        crate::openapi::ansible_import_task,
         // NuGet
         crate::openapi::nuget_service_index,
         crate::openapi::nuget_download,
+        crate::openapi::nuget_push,
+        crate::openapi::nuget_unlist,
         // Pub (Dart/Flutter)
         crate::openapi::pub_package_list,
         crate::openapi::pub_archive_download,
+        crate::openapi::pub_upload_new,
+        crate::openapi::pub_upload,
+        crate::openapi::pub_upload_finalize,
         // Conan (C/C++)
         crate::openapi::conan_ping,
         crate::openapi::conan_recipe_file,
+        crate::openapi::conan_recipe_file_upload,
+        crate::openapi::conan_package_file_upload,
+        crate::openapi::conan_recipe_revision_delete,
+        crate::openapi::conan_package_revision_delete,
+        crate::openapi::conan_authenticate,
         // RPM (yum/dnf)
         crate::openapi::rpm_repomd,
         crate::openapi::rpm_upload,
@@ -128,6 +172,41 @@ use crate::AppState;
         crate::openapi::deb_delete,
         crate::openapi::deb_pubkey,
         crate::openapi::deb_reindex,
+        // Helm
+        crate::openapi::helm_index,
+        crate::openapi::helm_download,
+        crate::openapi::helm_upload,
+        crate::openapi::helm_upload_prov,
+        crate::openapi::helm_list_charts,
+        crate::openapi::helm_delete,
+        crate::openapi::helm_proxy_index,
+        // Alpine
+        crate::openapi::apk_index,
+        crate::openapi::apk_upload,
+        crate::openapi::apk_download,
+        crate::openapi::apk_delete,
+        crate::openapi::apk_pubkey,
+        crate::openapi::apk_reindex,
+        // Conda
+        crate::openapi::conda_channeldata,
+        crate::openapi::conda_repodata,
+        crate::openapi::conda_upload,
+        crate::openapi::conda_download,
+        crate::openapi::conda_delete,
+        // Composer
+        crate::openapi::composer_packages,
+        crate::openapi::composer_metadata,
+        crate::openapi::composer_dist,
+        crate::openapi::composer_upload,
+        crate::openapi::composer_delete,
+        // Swift
+        crate::openapi::swift_list_releases,
+        crate::openapi::swift_release,
+        crate::openapi::swift_manifest,
+        crate::openapi::swift_archive,
+        crate::openapi::swift_identifiers,
+        crate::openapi::swift_publish,
+        crate::openapi::swift_login,
         // Tokens
         crate::openapi::create_token,
         crate::openapi::list_tokens,
@@ -337,8 +416,13 @@ pub async fn docker_version() {}
     get,
     path = "/v2/_catalog",
     tag = "docker",
+    params(
+        ("n" = Option<usize>, Query, description = "Page size; a Link header points to the next page"),
+        ("last" = Option<String>, Query, description = "Return repositories after this one")
+    ),
     responses(
         (status = 200, description = "Repository list", body = DockerCatalog),
+        (status = 400, description = "Invalid n", body = ErrorResponse),
         (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
     )
 )]
@@ -350,7 +434,9 @@ pub async fn docker_catalog() {}
     path = "/v2/{name}/tags/list",
     tag = "docker",
     params(
-        ("name" = String, Path, description = "Repository name (e.g., 'alpine' or 'library/nginx')")
+        ("name" = String, Path, description = "Repository name (e.g., 'alpine' or 'library/nginx')"),
+        ("n" = Option<usize>, Query, description = "Page size; a Link header points to the next page"),
+        ("last" = Option<String>, Query, description = "Return tags after this one")
     ),
     responses(
         (status = 200, description = "Tag list", body = DockerTags),
@@ -415,6 +501,28 @@ pub async fn docker_blob_head() {}
 )]
 pub async fn docker_blob_get() {}
 
+/// List referrers of a manifest (OCI 1.1)
+///
+/// Image index of the manifests whose `subject` is the digest. `artifactType`
+/// filtering is reported in the `OCI-Filters-Applied` header.
+#[utoipa::path(
+    get,
+    path = "/v2/{name}/referrers/{digest}",
+    tag = "docker",
+    params(
+        ("name" = String, Path, description = "Repository name"),
+        ("digest" = String, Path, description = "Subject manifest digest (sha256:...)"),
+        ("artifactType" = Option<String>, Query, description = "Only referrers of this artifact type")
+    ),
+    responses(
+        (status = 200, description = "OCI image index of referrer descriptors"),
+        (status = 400, description = "Invalid input", body = ErrorResponse),
+        (status = 404, description = "Proxied image without referrers upstream or locally; use the tag schema"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn docker_referrers() {}
+
 // -------------------- Docker Registry v2 - Write Operations --------------------
 
 /// Push manifest
@@ -427,7 +535,7 @@ pub async fn docker_blob_get() {}
         ("reference" = String, Path, description = "Tag or digest")
     ),
     responses(
-        (status = 201, description = "Manifest created, Docker-Content-Digest header contains digest"),
+        (status = 201, description = "Manifest created, Docker-Content-Digest header contains digest; OCI-Subject when the manifest has a subject"),
         (status = 400, description = "Invalid manifest"),
         (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
     )
@@ -588,6 +696,101 @@ pub async fn npm_package() {}
 )]
 pub async fn npm_publish() {}
 
+/// Search npm packages
+///
+/// Full-text search over hosted and cached packuments (name, description,
+/// keywords, maintainers), followed by upstream results when a proxy is set.
+/// Queries naming an internal namespace are never forwarded upstream.
+#[utoipa::path(
+    get,
+    path = "/npm/-/v1/search",
+    tag = "npm",
+    params(
+        ("text" = String, Query, description = "Search text; supports keywords:, author:, maintainer: and scope: qualifiers"),
+        ("size" = Option<usize>, Query, description = "Page size (default 20, max 250)"),
+        ("from" = Option<usize>, Query, description = "Offset of the first result")
+    ),
+    responses(
+        (status = 200, description = "Search results ({objects, total, time})"),
+        (status = 400, description = "Search text too long"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn npm_search() {}
+
+/// List npm dist-tags
+#[utoipa::path(
+    get,
+    path = "/npm/-/package/{name}/dist-tags",
+    tag = "npm",
+    params(
+        ("name" = String, Path, description = "Package name (scoped names URL-encoded, e.g. '@scope%2fpackage')")
+    ),
+    responses(
+        (status = 200, description = "Tag to version map"),
+        (status = 404, description = "Package not found")
+    )
+)]
+pub async fn npm_dist_tags() {}
+
+/// Set an npm dist-tag
+///
+/// The body is the version as a JSON string. Hosted packages only.
+#[utoipa::path(
+    put,
+    path = "/npm/-/package/{name}/dist-tags/{tag}",
+    tag = "npm",
+    params(
+        ("name" = String, Path, description = "Package name (scoped names URL-encoded)"),
+        ("tag" = String, Path, description = "Dist-tag (must not read as a version)")
+    ),
+    responses(
+        (status = 200, description = "Tag set; updated tag map"),
+        (status = 400, description = "Invalid tag or unpublished version"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 404, description = "Package not hosted")
+    )
+)]
+pub async fn npm_dist_tag_put() {}
+
+/// Remove an npm dist-tag
+#[utoipa::path(
+    delete,
+    path = "/npm/-/package/{name}/dist-tags/{tag}",
+    tag = "npm",
+    params(
+        ("name" = String, Path, description = "Package name (scoped names URL-encoded)"),
+        ("tag" = String, Path, description = "Dist-tag")
+    ),
+    responses(
+        (status = 200, description = "Tag removed; updated tag map"),
+        (status = 400, description = "The latest tag cannot be removed"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 404, description = "Tag or package not found")
+    )
+)]
+pub async fn npm_dist_tag_delete() {}
+
+/// Unpublish an npm package
+///
+/// Removes every version of a hosted package. A single version is unpublished
+/// by PUTting the packument without it to `/npm/{name}/-rev/{rev}`.
+#[utoipa::path(
+    delete,
+    path = "/npm/{name}/-rev/{rev}",
+    tag = "npm",
+    params(
+        ("name" = String, Path, description = "Package name (e.g., 'lodash' or '@scope/package')"),
+        ("rev" = String, Path, description = "Packument revision (not checked)")
+    ),
+    responses(
+        (status = 200, description = "Package unpublished"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 404, description = "Package not hosted")
+    )
+)]
+pub async fn npm_unpublish() {}
+
 // -------------------- Cargo --------------------
 
 /// Cargo sparse index configuration
@@ -675,6 +878,118 @@ pub async fn cargo_download() {}
 )]
 pub async fn cargo_publish() {}
 
+/// Yank a Cargo crate version
+///
+/// Marks a hosted version `yanked` in the sparse index. The `.crate` stays
+/// downloadable for existing lockfiles.
+#[utoipa::path(
+    delete,
+    path = "/cargo/api/v1/crates/{crate_name}/{version}/yank",
+    tag = "cargo",
+    params(
+        ("crate_name" = String, Path, description = "Crate name"),
+        ("version" = String, Path, description = "Crate version")
+    ),
+    responses(
+        (status = 200, description = "Version yanked ({\"ok\": true})"),
+        (status = 400, description = "Invalid input"),
+        (status = 403, description = "Not an owner, or outside namespace scope"),
+        (status = 404, description = "No such hosted version")
+    )
+)]
+pub async fn cargo_yank() {}
+
+/// Unyank a Cargo crate version
+#[utoipa::path(
+    put,
+    path = "/cargo/api/v1/crates/{crate_name}/{version}/unyank",
+    tag = "cargo",
+    params(
+        ("crate_name" = String, Path, description = "Crate name"),
+        ("version" = String, Path, description = "Crate version")
+    ),
+    responses(
+        (status = 200, description = "Version unyanked ({\"ok\": true})"),
+        (status = 400, description = "Invalid input"),
+        (status = 403, description = "Not an owner, or outside namespace scope"),
+        (status = 404, description = "No such hosted version")
+    )
+)]
+pub async fn cargo_unyank() {}
+
+/// List Cargo crate owners
+#[utoipa::path(
+    get,
+    path = "/cargo/api/v1/crates/{crate_name}/owners",
+    tag = "cargo",
+    params(
+        ("crate_name" = String, Path, description = "Crate name")
+    ),
+    responses(
+        (status = 200, description = "Owners ({users: [{id, login, name}]})"),
+        (status = 404, description = "Crate not hosted")
+    )
+)]
+pub async fn cargo_owners() {}
+
+/// Add Cargo crate owners
+///
+/// Body `{"users": ["login", ...]}`; each login must be a NORA user. Once a
+/// crate has owners, only they and admins may publish, yank or change owners.
+#[utoipa::path(
+    put,
+    path = "/cargo/api/v1/crates/{crate_name}/owners",
+    tag = "cargo",
+    params(
+        ("crate_name" = String, Path, description = "Crate name")
+    ),
+    responses(
+        (status = 200, description = "Owners added ({ok, msg})"),
+        (status = 400, description = "Malformed body or unknown user"),
+        (status = 403, description = "Not an owner, or outside namespace scope"),
+        (status = 404, description = "Crate not hosted")
+    )
+)]
+pub async fn cargo_owners_add() {}
+
+/// Remove Cargo crate owners
+#[utoipa::path(
+    delete,
+    path = "/cargo/api/v1/crates/{crate_name}/owners",
+    tag = "cargo",
+    params(
+        ("crate_name" = String, Path, description = "Crate name")
+    ),
+    responses(
+        (status = 200, description = "Owners removed ({ok, msg})"),
+        (status = 400, description = "Malformed body, or would remove the last owner"),
+        (status = 403, description = "Not an owner, or outside namespace scope"),
+        (status = 404, description = "Crate not hosted")
+    )
+)]
+pub async fn cargo_owners_remove() {}
+
+/// Search Cargo crates
+///
+/// Name search over hosted and cached crates, followed by upstream results
+/// when a proxy is set. Queries naming an internal namespace are never
+/// forwarded upstream.
+#[utoipa::path(
+    get,
+    path = "/cargo/api/v1/crates",
+    tag = "cargo",
+    params(
+        ("q" = String, Query, description = "Search terms"),
+        ("per_page" = Option<usize>, Query, description = "Page size (default 10, max 100)")
+    ),
+    responses(
+        (status = 200, description = "Search results ({crates, meta: {total}})"),
+        (status = 400, description = "Query too long"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn cargo_search() {}
+
 // -------------------- PyPI --------------------
 
 /// PyPI Simple index
@@ -721,6 +1036,95 @@ pub async fn pypi_package() {}
 )]
 pub async fn pypi_upload() {}
 
+/// Wheel core metadata (PEP 658)
+///
+/// The `METADATA` file extracted from a stored wheel.
+#[utoipa::path(
+    get,
+    path = "/simple/{name}/{filename}.metadata",
+    tag = "pypi",
+    params(
+        ("name" = String, Path, description = "Package name"),
+        ("filename" = String, Path, description = "Wheel file name")
+    ),
+    responses(
+        (status = 200, description = "Core metadata (RFC 822 text)"),
+        (status = 404, description = "No metadata stored for this file")
+    )
+)]
+pub async fn pypi_core_metadata() {}
+
+/// Project JSON API
+///
+/// Warehouse-compatible project document, merged from the upstream and
+/// hosted/cached files.
+#[utoipa::path(
+    get,
+    path = "/pypi/{name}/json",
+    tag = "pypi",
+    params(
+        ("name" = String, Path, description = "Package name")
+    ),
+    responses(
+        (status = 200, description = "`info`, `urls` and `releases`"),
+        (status = 404, description = "Package not found")
+    )
+)]
+pub async fn pypi_project_json() {}
+
+/// Release JSON API
+#[utoipa::path(
+    get,
+    path = "/pypi/{name}/{version}/json",
+    tag = "pypi",
+    params(
+        ("name" = String, Path, description = "Package name"),
+        ("version" = String, Path, description = "Release version")
+    ),
+    responses(
+        (status = 200, description = "`info` and `urls` of the release"),
+        (status = 404, description = "Release not found")
+    )
+)]
+pub async fn pypi_release_json() {}
+
+/// Yank a release (PEP 592)
+///
+/// Optional JSON body `{"reason": "..."}`. Requires the admin role.
+#[utoipa::path(
+    put,
+    path = "/pypi/{name}/{version}/yank",
+    tag = "pypi",
+    params(
+        ("name" = String, Path, description = "Package name"),
+        ("version" = String, Path, description = "Release version")
+    ),
+    responses(
+        (status = 200, description = "Release yanked"),
+        (status = 400, description = "Invalid body"),
+        (status = 403, description = "Admin role required"),
+        (status = 404, description = "No local files for this release")
+    )
+)]
+pub async fn pypi_yank() {}
+
+/// Restore a yanked release
+#[utoipa::path(
+    delete,
+    path = "/pypi/{name}/{version}/yank",
+    tag = "pypi",
+    params(
+        ("name" = String, Path, description = "Package name"),
+        ("version" = String, Path, description = "Release version")
+    ),
+    responses(
+        (status = 200, description = "Release restored"),
+        (status = 403, description = "Admin role required"),
+        (status = 404, description = "No local files for this release")
+    )
+)]
+pub async fn pypi_unyank() {}
+
 // -------------------- Go Modules --------------------
 
 /// Get latest version of a Go module
@@ -794,6 +1198,98 @@ pub async fn go_module_mod() {}
 )]
 pub async fn go_module_zip() {}
 
+/// Publish Go module
+///
+/// Accepts a multipart upload with the module's `mod` (go.mod) and `zip` (module zip).
+#[utoipa::path(
+    put,
+    path = "/go/{module}/@v/{version}",
+    tag = "go",
+    params(
+        ("module" = String, Path, description = "Module path"),
+        ("version" = String, Path, description = "Canonical module version")
+    ),
+    responses(
+        (status = 201, description = "Module version published"),
+        (status = 400, description = "Invalid module path, version, go.mod or zip layout"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 409, description = "Version already exists"),
+        (status = 413, description = "Module zip exceeds max_zip_size"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn go_module_upload() {}
+
+/// Check whether a checksum database is proxied
+#[utoipa::path(
+    get,
+    path = "/go/sumdb/{name}/supported",
+    tag = "go",
+    params(
+        ("name" = String, Path, description = "Checksum database name (e.g., 'sum.golang.org')")
+    ),
+    responses(
+        (status = 200, description = "Checksum database is proxied"),
+        (status = 404, description = "Not proxied; the go command contacts it directly")
+    )
+)]
+pub async fn go_sumdb_supported() {}
+
+/// Look up a module version in the checksum database
+#[utoipa::path(
+    get,
+    path = "/go/sumdb/{name}/lookup/{module}@{version}",
+    tag = "go",
+    params(
+        ("name" = String, Path, description = "Checksum database name"),
+        ("module" = String, Path, description = "Module path (case-encoded)"),
+        ("version" = String, Path, description = "Module version")
+    ),
+    responses(
+        (status = 200, description = "Signed lookup record (cached forever)"),
+        (status = 400, description = "Invalid module path or version"),
+        (status = 403, description = "Module is in an internal namespace"),
+        (status = 404, description = "Not found"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn go_sumdb_lookup() {}
+
+/// Fetch a checksum database log tile
+///
+/// `path` is `{H}/{L}/{N}[.p/{W}]` as defined by `golang.org/x/mod/sumdb/tlog`.
+#[utoipa::path(
+    get,
+    path = "/go/sumdb/{name}/tile/{path}",
+    tag = "go",
+    params(
+        ("name" = String, Path, description = "Checksum database name"),
+        ("path" = String, Path, description = "Tile path (e.g., '8/0/x123/456.p/10')")
+    ),
+    responses(
+        (status = 200, description = "Tile data (cached forever)"),
+        (status = 404, description = "Not found or malformed tile path"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn go_sumdb_tile() {}
+
+/// Get the checksum database's latest signed tree head
+#[utoipa::path(
+    get,
+    path = "/go/sumdb/{name}/latest",
+    tag = "go",
+    params(
+        ("name" = String, Path, description = "Checksum database name")
+    ),
+    responses(
+        (status = 200, description = "Signed tree head; `x-nora-stale: true` when served from cache after an upstream failure"),
+        (status = 404, description = "Not found"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn go_sumdb_latest() {}
+
 // -------------------- Raw Files --------------------
 
 /// Get raw file
@@ -869,29 +1365,72 @@ pub async fn gems_info() {}
 )]
 pub async fn gems_download() {}
 
-// -------------------- Terraform --------------------
-
-/// Terraform service discovery
+/// Compact index name list (`/versions`)
 #[utoipa::path(
     get,
-    path = "/terraform/.well-known/terraform.json",
-    tag = "terraform",
+    path = "/gems/versions",
+    tag = "gems",
     responses(
-        (status = 200, description = "Service discovery JSON"),
+        (status = 200, description = "Gem names with their versions and info checksums"),
         (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
     )
 )]
-pub async fn terraform_service_discovery() {}
+pub async fn gems_versions() {}
 
-/// List Terraform provider versions
+/// Push a gem (`gem push`)
 #[utoipa::path(
-    get,
-    path = "/terraform/v1/providers/{ns}/{ptype}/versions",
-    tag = "terraform",
-    params(
-        ("ns" = String, Path, description = "Provider namespace"),
-        ("ptype" = String, Path, description = "Provider type")
-    ),
+    post,
+    path = "/gems/api/v1/gems",
+    tag = "gems",
+    request_body(content = Vec<u8>, description = "The .gem file", content_type = "application/octet-stream"),
+    responses(
+        (status = 200, description = "Gem registered"),
+        (status = 400, description = "Invalid gem"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 409, description = "Version already exists"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn gems_push() {}
+
+/// Yank a hosted gem version (`gem yank`)
+#[utoipa::path(
+    delete,
+    path = "/gems/api/v1/gems/yank",
+    tag = "gems",
+    request_body(content = String, description = "Form fields gem_name, version, platform", content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 200, description = "Version yanked"),
+        (status = 404, description = "Not a hosted version"),
+        (status = 422, description = "Already yanked"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn gems_yank() {}
+
+// -------------------- Terraform --------------------
+
+/// Terraform service discovery
+#[utoipa::path(
+    get,
+    path = "/terraform/.well-known/terraform.json",
+    tag = "terraform",
+    responses(
+        (status = 200, description = "Service discovery JSON"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn terraform_service_discovery() {}
+
+/// List Terraform provider versions
+#[utoipa::path(
+    get,
+    path = "/terraform/v1/providers/{ns}/{ptype}/versions",
+    tag = "terraform",
+    params(
+        ("ns" = String, Path, description = "Provider namespace"),
+        ("ptype" = String, Path, description = "Provider type")
+    ),
     responses(
         (status = 200, description = "Version list"),
         (status = 400, description = "Invalid input", body = ErrorResponse),
@@ -901,6 +1440,55 @@ pub async fn terraform_service_discovery() {}
 )]
 pub async fn terraform_provider_versions() {}
 
+/// Publish a Terraform provider platform
+///
+/// Accepts one provider zip per os/arch. NORA regenerates and signs the version's `SHA256SUMS`.
+#[utoipa::path(
+    put,
+    path = "/terraform/v1/providers/{ns}/{ptype}/{version}/{os}/{arch}",
+    tag = "terraform",
+    params(
+        ("ns" = String, Path, description = "Provider namespace"),
+        ("ptype" = String, Path, description = "Provider type"),
+        ("version" = String, Path, description = "Semantic version"),
+        ("os" = String, Path, description = "Target OS"),
+        ("arch" = String, Path, description = "Target architecture"),
+        ("protocols" = Option<String>, Query, description = "Plugin protocols, comma-separated (default 5.0)")
+    ),
+    responses(
+        (status = 201, description = "Platform published; body is its download metadata"),
+        (status = 400, description = "Invalid coordinates, protocols or archive"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 409, description = "Platform already exists or version is proxied"),
+        (status = 503, description = "Repository signing is disabled"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn terraform_provider_upload() {}
+
+/// Publish a Terraform module
+///
+/// Accepts the module source as a `.tar.gz` body.
+#[utoipa::path(
+    put,
+    path = "/terraform/v1/modules/{ns}/{name}/{provider}/{version}",
+    tag = "terraform",
+    params(
+        ("ns" = String, Path, description = "Module namespace"),
+        ("name" = String, Path, description = "Module name"),
+        ("provider" = String, Path, description = "Module provider"),
+        ("version" = String, Path, description = "Semantic version")
+    ),
+    responses(
+        (status = 201, description = "Module version published"),
+        (status = 400, description = "Invalid coordinates or archive"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 409, description = "Version already exists"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn terraform_module_upload() {}
+
 // -------------------- Ansible Galaxy --------------------
 
 /// List Ansible Galaxy collections
@@ -933,6 +1521,38 @@ pub async fn ansible_collection_list() {}
 )]
 pub async fn ansible_download() {}
 
+/// Publish an Ansible collection (`ansible-galaxy collection publish`)
+#[utoipa::path(
+    post,
+    path = "/ansible/v3/artifacts/collections/",
+    tag = "ansible",
+    request_body(content = Vec<u8>, description = "Multipart form: `file` ({ns}-{name}-{ver}.tar.gz) and optional `sha256`", content_type = "multipart/form-data"),
+    responses(
+        (status = 202, description = "Import task created; poll the returned `task` URL"),
+        (status = 400, description = "Missing file, bad filename or sha256 mismatch"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 409, description = "Version already exists"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn ansible_collection_upload() {}
+
+/// Ansible collection import task status
+#[utoipa::path(
+    get,
+    path = "/ansible/v3/imports/collections/{task}/",
+    tag = "ansible",
+    params(
+        ("task" = String, Path, description = "Import task ID returned by the upload")
+    ),
+    responses(
+        (status = 200, description = "Task state (`completed` or `failed`) with messages"),
+        (status = 404, description = "Unknown task"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn ansible_import_task() {}
+
 // -------------------- NuGet --------------------
 
 /// NuGet v3 service index
@@ -963,6 +1583,39 @@ pub async fn nuget_service_index() {}
 )]
 pub async fn nuget_download() {}
 
+/// Push a NuGet package (`dotnet nuget push`)
+#[utoipa::path(
+    put,
+    path = "/nuget/api/v2/package",
+    tag = "nuget",
+    request_body(content = Vec<u8>, description = "The .nupkg as the file part", content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Package pushed"),
+        (status = 400, description = "Invalid package or .nuspec"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 409, description = "Version already exists"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn nuget_push() {}
+
+/// Unlist a pushed NuGet package version (`dotnet nuget delete`)
+#[utoipa::path(
+    delete,
+    path = "/nuget/api/v2/package/{id}/{version}",
+    tag = "nuget",
+    params(
+        ("id" = String, Path, description = "Package id"),
+        ("version" = String, Path, description = "Package version")
+    ),
+    responses(
+        (status = 204, description = "Version unlisted"),
+        (status = 404, description = "Not a pushed version"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn nuget_unlist() {}
+
 // -------------------- Pub (Dart/Flutter) --------------------
 
 /// List or search pub packages
@@ -1000,6 +1653,50 @@ pub async fn pub_package_list() {}
 )]
 pub async fn pub_archive_download() {}
 
+/// Start a pub package upload (`dart pub publish`)
+#[utoipa::path(
+    get,
+    path = "/pub/api/packages/versions/new",
+    tag = "pub",
+    responses(
+        (status = 200, description = "Upload URL and form fields"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn pub_upload_new() {}
+
+/// Upload and publish a pub package archive
+#[utoipa::path(
+    post,
+    path = "/pub/api/packages/versions/upload",
+    tag = "pub",
+    request_body(content = Vec<u8>, description = "Multipart form with the package archive in `file`", content_type = "multipart/form-data"),
+    responses(
+        (status = 204, description = "Published; `Location` is the finalize URL"),
+        (status = 400, description = "Invalid archive or pubspec.yaml"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 409, description = "Version already exists"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn pub_upload() {}
+
+/// Outcome of a pub package upload
+#[utoipa::path(
+    get,
+    path = "/pub/api/packages/versions/finalize/{id}",
+    tag = "pub",
+    params(
+        ("id" = String, Path, description = "Upload ID from the upload's Location header")
+    ),
+    responses(
+        (status = 200, description = "Success message"),
+        (status = 404, description = "Unknown upload"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn pub_upload_finalize() {}
+
 // -------------------- Conan --------------------
 
 /// Conan v2 ping (capabilities check)
@@ -1036,6 +1733,113 @@ pub async fn conan_ping() {}
 )]
 pub async fn conan_recipe_file() {}
 
+/// Upload a Conan recipe file under a recipe revision
+#[utoipa::path(
+    put,
+    path = "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/files/{filename}",
+    tag = "conan",
+    params(
+        ("name" = String, Path, description = "Package name"),
+        ("ver" = String, Path, description = "Package version"),
+        ("user" = String, Path, description = "User (or _ for default)"),
+        ("chan" = String, Path, description = "Channel (or _ for default)"),
+        ("rrev" = String, Path, description = "Recipe revision hash"),
+        ("filename" = String, Path, description = "File name (e.g., conanfile.py)")
+    ),
+    request_body(content = Vec<u8>, description = "File content", content_type = "application/octet-stream"),
+    responses(
+        (status = 201, description = "File stored; the revision is listed and becomes latest if new"),
+        (status = 400, description = "Invalid coordinates or empty body"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 409, description = "The revision already holds a different file under this name"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn conan_recipe_file_upload() {}
+
+/// Upload a Conan package file under a package revision
+#[utoipa::path(
+    put,
+    path = "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages/{pkg_id}/revisions/{prev}/files/{filename}",
+    tag = "conan",
+    params(
+        ("name" = String, Path, description = "Package name"),
+        ("ver" = String, Path, description = "Package version"),
+        ("user" = String, Path, description = "User (or _ for default)"),
+        ("chan" = String, Path, description = "Channel (or _ for default)"),
+        ("rrev" = String, Path, description = "Recipe revision hash"),
+        ("pkg_id" = String, Path, description = "Package ID"),
+        ("prev" = String, Path, description = "Package revision hash"),
+        ("filename" = String, Path, description = "File name (e.g., conan_package.tgz)")
+    ),
+    request_body(content = Vec<u8>, description = "File content", content_type = "application/octet-stream"),
+    responses(
+        (status = 201, description = "File stored; the package revision is listed and becomes latest if new"),
+        (status = 400, description = "Invalid coordinates or empty body"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 409, description = "The revision already holds a different file under this name"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn conan_package_file_upload() {}
+
+/// Delete a hosted Conan recipe revision with its packages
+#[utoipa::path(
+    delete,
+    path = "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}",
+    tag = "conan",
+    params(
+        ("name" = String, Path, description = "Package name"),
+        ("ver" = String, Path, description = "Package version"),
+        ("user" = String, Path, description = "User (or _ for default)"),
+        ("chan" = String, Path, description = "Channel (or _ for default)"),
+        ("rrev" = String, Path, description = "Recipe revision hash")
+    ),
+    responses(
+        (status = 200, description = "Revision deleted; latest moves to the next newest"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 404, description = "No such hosted revision"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn conan_recipe_revision_delete() {}
+
+/// Delete a hosted Conan package revision
+#[utoipa::path(
+    delete,
+    path = "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages/{pkg_id}/revisions/{prev}",
+    tag = "conan",
+    params(
+        ("name" = String, Path, description = "Package name"),
+        ("ver" = String, Path, description = "Package version"),
+        ("user" = String, Path, description = "User (or _ for default)"),
+        ("chan" = String, Path, description = "Channel (or _ for default)"),
+        ("rrev" = String, Path, description = "Recipe revision hash"),
+        ("pkg_id" = String, Path, description = "Package ID"),
+        ("prev" = String, Path, description = "Package revision hash")
+    ),
+    responses(
+        (status = 200, description = "Package revision deleted; latest moves to the next newest"),
+        (status = 403, description = "Outside namespace scope"),
+        (status = 404, description = "No such hosted package revision"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn conan_package_revision_delete() {}
+
+/// Exchange a NORA API token (sent as the Basic password) for a Conan bearer token
+#[utoipa::path(
+    get,
+    path = "/conan/v2/users/authenticate",
+    tag = "conan",
+    responses(
+        (status = 200, description = "Bearer token (text/plain)"),
+        (status = 401, description = "Missing credentials, or a password that is not an API token"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn conan_authenticate() {}
+
 // -------------------- RPM (yum/dnf) --------------------
 
 /// Repository metadata index (dnf/yum entry point)
@@ -1263,6 +2067,546 @@ pub async fn deb_pubkey() {}
 )]
 pub async fn deb_reindex() {}
 
+// -------------------- Helm --------------------
+
+/// Hosted repository index (`helm repo add nora <base>/helm`)
+#[utoipa::path(
+    get,
+    path = "/helm/index.yaml",
+    tag = "helm",
+    responses(
+        (status = 200, description = "index.yaml generated from every published Chart.yaml"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn helm_index() {}
+
+/// Download a chart archive or its provenance file
+#[utoipa::path(
+    get,
+    path = "/helm/charts/{file}",
+    tag = "helm",
+    params(
+        ("file" = String, Path, description = "Chart file, `{name}-{version}.tgz` or `.tgz.prov`")
+    ),
+    responses(
+        (status = 200, description = "File content"),
+        (status = 404, description = "File not found"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn helm_download() {}
+
+/// Publish a chart (ChartMuseum API): raw `.tgz` body, or multipart `chart` + optional `prov`
+#[utoipa::path(
+    post,
+    path = "/helm/api/charts",
+    tag = "helm",
+    params(
+        ("force" = Option<String>, Query, description = "Overwrite an existing version")
+    ),
+    responses(
+        (status = 201, description = "Chart stored and index.yaml regenerated"),
+        (status = 400, description = "Not a valid chart archive or Chart.yaml", body = ErrorResponse),
+        (status = 409, description = "Version already exists (use ?force)", body = ErrorResponse),
+        (status = 413, description = "File exceeds helm.max_file_size"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn helm_upload() {}
+
+/// Publish a provenance file next to the chart it signs
+#[utoipa::path(
+    post,
+    path = "/helm/api/prov",
+    tag = "helm",
+    responses(
+        (status = 201, description = "Provenance file stored"),
+        (status = 400, description = "Provenance file names no chart archive", body = ErrorResponse),
+        (status = 409, description = "Provenance already exists (use ?force)", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn helm_upload_prov() {}
+
+/// List hosted charts with their versions
+#[utoipa::path(
+    get,
+    path = "/helm/api/charts",
+    tag = "helm",
+    responses(
+        (status = 200, description = "Map of chart name to index entries, newest first"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn helm_list_charts() {}
+
+/// Delete a chart version and its provenance (index.yaml is regenerated)
+#[utoipa::path(
+    delete,
+    path = "/helm/api/charts/{name}/{version}",
+    tag = "helm",
+    params(
+        ("name" = String, Path, description = "Chart name"),
+        ("version" = String, Path, description = "Chart version")
+    ),
+    responses(
+        (status = 200, description = "Version deleted"),
+        (status = 404, description = "Version not found", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn helm_delete() {}
+
+/// Index of a pull-through chart repository (chart URLs rewritten to this server)
+#[utoipa::path(
+    get,
+    path = "/helm/{repo}/index.yaml",
+    tag = "helm",
+    params(
+        ("repo" = String, Path, description = "Proxy repository name from helm.proxies")
+    ),
+    responses(
+        (status = 200, description = "Upstream index.yaml with rewritten chart URLs"),
+        (status = 404, description = "No such proxy repository"),
+        (status = 502, description = "Upstream unavailable and no cached index"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn helm_proxy_index() {}
+
+// -------------------- Alpine --------------------
+
+/// Signed package index of one `{branch}/{component}/{arch}` directory
+#[utoipa::path(
+    get,
+    path = "/apk/{repo}/{branch}/{component}/{arch}/APKINDEX.tar.gz",
+    tag = "apk",
+    params(
+        ("repo" = String, Path, description = "Repository name"),
+        ("branch" = String, Path, description = "Branch, e.g. `v3.20` or `edge`"),
+        ("component" = String, Path, description = "Component, e.g. `main`"),
+        ("arch" = String, Path, description = "Architecture, e.g. `x86_64`")
+    ),
+    responses(
+        (status = 200, description = "APKINDEX.tar.gz (signature segment + index segment)"),
+        (status = 404, description = "No packages published to this directory"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn apk_index() {}
+
+/// Publish an .apk (`.PKGINFO` is parsed; the directory index is regenerated and re-signed)
+#[utoipa::path(
+    put,
+    path = "/apk/{repo}/{branch}/{component}/{arch}/{file}",
+    tag = "apk",
+    params(
+        ("repo" = String, Path, description = "Repository name"),
+        ("branch" = String, Path, description = "Branch, e.g. `v3.20` or `edge`"),
+        ("component" = String, Path, description = "Component, e.g. `main`"),
+        ("arch" = String, Path, description = "Architecture, e.g. `x86_64`"),
+        ("file" = String, Path, description = "`{pkgname}-{pkgver}.apk`")
+    ),
+    responses(
+        (status = 201, description = "Package stored and APKINDEX regenerated"),
+        (status = 400, description = "Invalid path, not a valid apk, or name/arch mismatch", body = ErrorResponse),
+        (status = 409, description = "Repository is a pull-through proxy (read-only)"),
+        (status = 413, description = "File exceeds apk.max_file_size"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn apk_upload() {}
+
+/// Download a package (hosted, or fetched through a pull-through repo)
+#[utoipa::path(
+    get,
+    path = "/apk/{repo}/{branch}/{component}/{arch}/{file}",
+    tag = "apk",
+    params(
+        ("repo" = String, Path, description = "Repository name"),
+        ("branch" = String, Path, description = "Branch"),
+        ("component" = String, Path, description = "Component"),
+        ("arch" = String, Path, description = "Architecture"),
+        ("file" = String, Path, description = "Package file")
+    ),
+    responses(
+        (status = 200, description = "Package content"),
+        (status = 206, description = "Partial content (Range request)"),
+        (status = 404, description = "Package not found"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn apk_download() {}
+
+/// Delete a package (the directory index is regenerated and re-signed)
+#[utoipa::path(
+    delete,
+    path = "/apk/{repo}/{branch}/{component}/{arch}/{file}",
+    tag = "apk",
+    params(
+        ("repo" = String, Path, description = "Repository name"),
+        ("branch" = String, Path, description = "Branch"),
+        ("component" = String, Path, description = "Component"),
+        ("arch" = String, Path, description = "Architecture"),
+        ("file" = String, Path, description = "Package file")
+    ),
+    responses(
+        (status = 204, description = "Package deleted"),
+        (status = 404, description = "Package not found"),
+        (status = 409, description = "Repository is a pull-through proxy (read-only)"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn apk_delete() {}
+
+/// RSA public key for `/etc/apk/keys/` (404 when signing is disabled)
+#[utoipa::path(
+    get,
+    path = "/apk/{repo}/keys/{key_name}",
+    tag = "apk",
+    params(
+        ("repo" = String, Path, description = "Repository name"),
+        ("key_name" = String, Path, description = "signing.apk_key_name (default `nora.rsa.pub`)")
+    ),
+    responses(
+        (status = 200, description = "PEM public key"),
+        (status = 404, description = "Signing disabled or unknown key name"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn apk_pubkey() {}
+
+/// Reconcile a repository with storage and rebuild (re-sign) every APKINDEX
+#[utoipa::path(
+    post,
+    path = "/apk/{repo}/-/reindex",
+    tag = "apk",
+    params(
+        ("repo" = String, Path, description = "Repository name")
+    ),
+    responses(
+        (status = 200, description = "Reindexed: package, created-sidecar and removed-orphan counts"),
+        (status = 404, description = "No such repository"),
+        (status = 409, description = "Repository is a pull-through proxy (read-only)"),
+        (status = 422, description = "A stored package is not a valid apk"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn apk_reindex() {}
+
+// -------------------- Conda --------------------
+
+/// Per-package summary of a channel (subdirs, latest version, about fields)
+#[utoipa::path(
+    get,
+    path = "/conda/{channel}/channeldata.json",
+    tag = "conda",
+    params(
+        ("channel" = String, Path, description = "Channel name")
+    ),
+    responses(
+        (status = 200, description = "channeldata.json (empty for a channel with nothing published)"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn conda_channeldata() {}
+
+/// Package index of one subdir; `repodata.json.zst` serves the same document zstd-compressed
+#[utoipa::path(
+    get,
+    path = "/conda/{channel}/{subdir}/repodata.json",
+    tag = "conda",
+    params(
+        ("channel" = String, Path, description = "Channel name"),
+        ("subdir" = String, Path, description = "`noarch` or `{platform}-{arch}`, e.g. `linux-64`")
+    ),
+    responses(
+        (status = 200, description = "repodata.json (empty for a subdir with nothing published)"),
+        (status = 404, description = "Not a valid subdir"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn conda_repodata() {}
+
+/// Publish a `.conda` or `.tar.bz2` package (`info/index.json` is parsed; repodata is regenerated)
+#[utoipa::path(
+    put,
+    path = "/conda/{channel}/{subdir}/{file}",
+    tag = "conda",
+    params(
+        ("channel" = String, Path, description = "Channel name"),
+        ("subdir" = String, Path, description = "Subdir the package is built for"),
+        ("file" = String, Path, description = "`{name}-{version}-{build}.conda` or `.tar.bz2`")
+    ),
+    responses(
+        (status = 201, description = "Package stored and repodata regenerated"),
+        (status = 400, description = "Invalid path, not a valid package, or name/subdir mismatch", body = ErrorResponse),
+        (status = 409, description = "Package already exists, or the channel is a pull-through proxy (read-only)"),
+        (status = 413, description = "File exceeds conda.max_file_size"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn conda_upload() {}
+
+/// Download a package (hosted, or fetched through a pull-through channel)
+#[utoipa::path(
+    get,
+    path = "/conda/{channel}/{subdir}/{file}",
+    tag = "conda",
+    params(
+        ("channel" = String, Path, description = "Channel name"),
+        ("subdir" = String, Path, description = "Subdir"),
+        ("file" = String, Path, description = "Package file")
+    ),
+    responses(
+        (status = 200, description = "Package content"),
+        (status = 206, description = "Partial content (Range request)"),
+        (status = 403, description = "Blocked by curation policy"),
+        (status = 404, description = "Package not found"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn conda_download() {}
+
+/// Delete a package (repodata is regenerated)
+#[utoipa::path(
+    delete,
+    path = "/conda/{channel}/{subdir}/{file}",
+    tag = "conda",
+    params(
+        ("channel" = String, Path, description = "Channel name"),
+        ("subdir" = String, Path, description = "Subdir"),
+        ("file" = String, Path, description = "Package file")
+    ),
+    responses(
+        (status = 204, description = "Package deleted"),
+        (status = 404, description = "Package not found"),
+        (status = 409, description = "Channel is a pull-through proxy (read-only)"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn conda_delete() {}
+
+// -------------------- Composer --------------------
+
+/// Repository root: `metadata-url` template (and `available-packages` when hosted-only)
+#[utoipa::path(
+    get,
+    path = "/composer/packages.json",
+    tag = "composer",
+    responses(
+        (status = 200, description = "packages.json"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn composer_packages() {}
+
+/// p2 metadata of a package; `{package}~dev.json` lists its branches
+#[utoipa::path(
+    get,
+    path = "/composer/p2/{vendor}/{file}",
+    tag = "composer",
+    params(
+        ("vendor" = String, Path, description = "Vendor name"),
+        ("file" = String, Path, description = "`{package}.json` or `{package}~dev.json`")
+    ),
+    responses(
+        (status = 200, description = "Version list; dist URLs point at this server"),
+        (status = 403, description = "Blocked by curation policy"),
+        (status = 404, description = "Package not found"),
+        (status = 502, description = "Upstream error and no cached copy"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn composer_metadata() {}
+
+/// Download a dist archive (hosted, or pulled through from the upstream dist URL)
+#[utoipa::path(
+    get,
+    path = "/composer/dists/{vendor}/{package}/{reference}.zip",
+    tag = "composer",
+    params(
+        ("vendor" = String, Path, description = "Vendor name"),
+        ("package" = String, Path, description = "Package name"),
+        ("reference" = String, Path, description = "Dist reference from the metadata")
+    ),
+    responses(
+        (status = 200, description = "Dist zip"),
+        (status = 206, description = "Partial content (Range request)"),
+        (status = 403, description = "Blocked by curation policy or quarantine"),
+        (status = 404, description = "Unknown reference"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn composer_dist() {}
+
+/// Publish a dist zip as `{version}` (its `composer.json` supplies the metadata)
+#[utoipa::path(
+    put,
+    path = "/composer/packages/{vendor}/{package}/{version}",
+    tag = "composer",
+    params(
+        ("vendor" = String, Path, description = "Vendor name"),
+        ("package" = String, Path, description = "Package name"),
+        ("version" = String, Path, description = "Version, e.g. `1.2.0` or `dev-main`")
+    ),
+    responses(
+        (status = 201, description = "Version published"),
+        (status = 400, description = "Invalid name/version, not a zip, or composer.json name/version mismatch"),
+        (status = 409, description = "Version already exists"),
+        (status = 413, description = "File exceeds composer.max_file_size"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn composer_upload() {}
+
+/// Delete a hosted version
+#[utoipa::path(
+    delete,
+    path = "/composer/packages/{vendor}/{package}/{version}",
+    tag = "composer",
+    params(
+        ("vendor" = String, Path, description = "Vendor name"),
+        ("package" = String, Path, description = "Package name"),
+        ("version" = String, Path, description = "Version")
+    ),
+    responses(
+        (status = 204, description = "Version deleted"),
+        (status = 404, description = "Version not found"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn composer_delete() {}
+
+// -------------------- Swift --------------------
+
+/// List the releases of a package (`Link` header names the latest)
+#[utoipa::path(
+    get,
+    path = "/swift/{scope}/{name}",
+    tag = "swift",
+    params(
+        ("scope" = String, Path, description = "Package scope, e.g. `mona`"),
+        ("name" = String, Path, description = "Package name, e.g. `LinkedList` (an optional `.json` suffix is accepted)")
+    ),
+    responses(
+        (status = 200, description = "`{\"releases\": {version: {\"url\": ...}}}`", content_type = "application/json"),
+        (status = 404, description = "Package not found", content_type = "application/problem+json"),
+        (status = 415, description = "Unsupported API version in Accept"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn swift_list_releases() {}
+
+/// Release metadata: source archive checksum, signing info, publisher metadata
+#[utoipa::path(
+    get,
+    path = "/swift/{scope}/{name}/{version}",
+    tag = "swift",
+    params(
+        ("scope" = String, Path, description = "Package scope"),
+        ("name" = String, Path, description = "Package name"),
+        ("version" = String, Path, description = "Semver version (an optional `.json` suffix is accepted)")
+    ),
+    responses(
+        (status = 200, description = "Release metadata; `Link` names latest/successor/predecessor versions", content_type = "application/json"),
+        (status = 404, description = "Release not found", content_type = "application/problem+json"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn swift_release() {}
+
+/// Fetch `Package.swift`, or a version-specific manifest
+#[utoipa::path(
+    get,
+    path = "/swift/{scope}/{name}/{version}/Package.swift",
+    tag = "swift",
+    params(
+        ("scope" = String, Path, description = "Package scope"),
+        ("name" = String, Path, description = "Package name"),
+        ("version" = String, Path, description = "Semver version"),
+        ("swift-version" = Option<String>, Query, description = "Swift version, e.g. `5.9`, for `Package@swift-5.9.swift`")
+    ),
+    responses(
+        (status = 200, description = "Manifest", content_type = "text/x-swift"),
+        (status = 303, description = "No manifest for this swift-version; redirects to the unqualified Package.swift"),
+        (status = 404, description = "Release not found", content_type = "application/problem+json"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn swift_manifest() {}
+
+/// Download a source archive (`Digest: sha-256=...`; signature headers when signed)
+#[utoipa::path(
+    get,
+    path = "/swift/{scope}/{name}/{version}.zip",
+    tag = "swift",
+    params(
+        ("scope" = String, Path, description = "Package scope"),
+        ("name" = String, Path, description = "Package name"),
+        ("version" = String, Path, description = "Semver version")
+    ),
+    responses(
+        (status = 200, description = "Source archive", content_type = "application/zip"),
+        (status = 206, description = "Partial content (Range request)"),
+        (status = 404, description = "Release not found", content_type = "application/problem+json"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn swift_archive() {}
+
+/// Look up package identifiers by source repository URL
+#[utoipa::path(
+    get,
+    path = "/swift/identifiers",
+    tag = "swift",
+    params(
+        ("url" = String, Query, description = "Repository URL, e.g. `https://github.com/mona/LinkedList`")
+    ),
+    responses(
+        (status = 200, description = "`{\"identifiers\": [\"mona.LinkedList\"]}`", content_type = "application/json"),
+        (status = 400, description = "Missing or invalid url"),
+        (status = 404, description = "No package published from this repository"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn swift_identifiers() {}
+
+/// Publish a release (multipart `source-archive`, `metadata`, and optional signatures)
+#[utoipa::path(
+    put,
+    path = "/swift/{scope}/{name}/{version}",
+    tag = "swift",
+    params(
+        ("scope" = String, Path, description = "Package scope"),
+        ("name" = String, Path, description = "Package name"),
+        ("version" = String, Path, description = "Semver version"),
+        ("X-Swift-Package-Signature-Format" = Option<String>, Header, description = "`cms-1.0.0` for a signed release")
+    ),
+    responses(
+        (status = 201, description = "Release published; `Location` is the release URL"),
+        (status = 400, description = "Invalid identifier/version, missing source-archive, or unsupported signature format"),
+        (status = 409, description = "Release already exists"),
+        (status = 413, description = "Source archive exceeds swift.max_file_size"),
+        (status = 422, description = "Not a zip with a Package.swift, or metadata is not a JSON object"),
+        (status = 429, description = "Rate limit exceeded. Retry-After header indicates wait time")
+    )
+)]
+pub async fn swift_publish() {}
+
+/// Check credentials (`swift package-registry login`)
+#[utoipa::path(
+    post,
+    path = "/swift/login",
+    tag = "swift",
+    responses(
+        (status = 200, description = "Credentials accepted (read-only tokens included)"),
+        (status = 401, description = "Missing or invalid credentials")
+    )
+)]
+pub async fn swift_login() {}
+
 // -------------------- Auth / Tokens --------------------
 
 /// Create API token
@@ -1,7 +1,7 @@
 // Copyright (c) 2026 The Nora Authors
 // SPDX-License-Identifier: MIT
 
-//! Ansible Galaxy collection proxy (API v3).
+//! Ansible Galaxy collection registry (API v3): hosted publish + caching proxy.
 //!
 //! Implements a caching proxy for galaxy.ansible.com:
 //!   GET /ansible/                                         — API discovery
@@ -14,28 +14,42 @@
 //!
 //! Also supports full Pulp-style paths under /ansible/api/v3/plugin/ansible/...
 //!
+//! Hosted publishing (`ansible-galaxy collection publish`):
+//!   POST /ansible/v3/artifacts/collections/               — upload (multipart `file`)
+//!   GET  /ansible/v3/imports/collections/{task}/          — import task status
+//!
+//! Uploads are checked like Galaxy's importer (MANIFEST.json / FILES.json
+//! checksums) and recorded under `ansible/hosted/`; published versions are
+//! merged into the first page of the list/detail/version responses, skip
+//! curation and the quarantine, and are never fetched upstream.
+//!
 //! Namespace and collection names follow Galaxy spec: [a-z0-9_]+ (no hyphens).
 //!
 //! Client config:
 //!   ansible-galaxy collection install community.general -s http://nora:4000/ansible/
+//!   ansible-galaxy collection publish acme-tools-1.0.0.tar.gz -s http://nora:4000/ansible/
+//!   (ansible.cfg `username`/`password` — an API token works as the password)
 
 use crate::activity_log::{ActionType, ActivityEntry};
 use crate::audit::AuditEntry;
+use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
 use crate::registry::{
     circuit_open_response, nora_base_url, proxy_fetch, proxy_fetch_conditional, read_validators,
     write_validators, ProxyError, Revalidation, Validators,
 };
 use crate::registry_type::RegistryType;
 use crate::secrets::expose_opt;
+use crate::version::{cmp_semver, is_semver};
 use crate::AppState;
 use axum::{
     body::Bytes,
-    extract::{Path, RawQuery, State},
+    extract::{Multipart, Path, RawQuery, State},
     http::{header, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
-    Router,
+    routing::{get, post},
+    Extension, Router,
 };
+use sha2::Digest;
 use std::time::Duration;
 
 const UPSTREAM_DEFAULT: &str = "https://galaxy.ansible.com";
@@ -80,6 +94,23 @@ pub fn routes() -> Router<AppState> {
             "/ansible/api/v3/plugin/ansible/content/published/collections/index/{ns}/{name}/versions/{ver}/",
             get(version_detail),
         )
+        // Collection publish (`ansible-galaxy collection publish`) and import tasks
+        .route(
+            "/ansible/v3/artifacts/collections/",
+            post(collection_upload),
+        )
+        .route(
+            "/ansible/api/v3/artifacts/collections/",
+            post(collection_upload),
+        )
+        .route(
+            "/ansible/v3/imports/collections/{task}/",
+            get(import_task),
+        )
+        .route(
+            "/ansible/api/v3/imports/collections/{task}/",
+            get(import_task),
+        )
         // Collection tarball download (immutable)
         .route("/ansible/download/{filename}", get(download_tarball))
         // Artifact path alias — upstream Galaxy serves tarballs here too (#438)
@@ -116,7 +147,33 @@ async fn collection_list(State(state): State<AppState>, RawQuery(raw_query): Raw
         None => "ansible/metadata/collections.json".to_string(),
     };
 
-    proxy_json(&state, &url, "ansible-collections", &cache_key, None).await
+    let response = proxy_json(&state, &url, "ansible-collections", &cache_key, None).await;
+    if !is_first_page(raw_query.as_deref()) {
+        return response;
+    }
+    let base_url = nora_base_url(&state);
+    let mut hosted = Vec::new();
+    for (ns, name) in hosted_collections(&state.storage).await {
+        if let Some(collection) = hosted_collection(&state, &base_url, &ns, &name).await {
+            hosted.push(collection);
+        }
+    }
+    if hosted.is_empty() {
+        return response;
+    }
+    let fallback = listing_json(hosted.clone());
+    overlay_hosted(
+        response,
+        |doc| {
+            merge_listing(doc, &collection_key, hosted, &|upstream, hosted| {
+                let mut merged = upstream.clone();
+                raise_highest_version(&mut merged, hosted);
+                merged
+            })
+        },
+        Some(fallback),
+    )
+    .await
 }
 
 // ── Collection detail ──────────────────────────────────────────────────
@@ -139,14 +196,26 @@ async fn collection_detail(
     );
 
     let cache_key = format!("ansible/metadata/{}/{}.json", ns, name);
-    proxy_json(
+    let response = proxy_json(
         &state,
         &url,
         &format!("{}.{}", ns, name),
         &cache_key,
         Some(&format!("{}.{}", ns, name)),
     )
-    .await
+    .await;
+    match hosted_collection(&state, &nora_base_url(&state), &ns, &name).await {
+        Some(hosted) => {
+            let fallback = hosted.clone();
+            overlay_hosted(
+                response,
+                |doc| raise_highest_version(doc, &hosted),
+                Some(fallback),
+            )
+            .await
+        }
+        None => response,
+    }
 }
 
 // ── Version listing ────────────────────────────────────────────────────
@@ -178,13 +247,39 @@ async fn version_list(
         None => format!("ansible/metadata/{}/{}/versions.json", ns, name),
     };
 
-    proxy_json(
+    let response = proxy_json(
         &state,
         &url,
         &format!("{}.{}/versions", ns, name),
         &cache_key,
         Some(&format!("{}.{}", ns, name)),
     )
+    .await;
+    if !is_first_page(raw_query.as_deref()) {
+        return response;
+    }
+    let base_url = nora_base_url(&state);
+    let hosted: Vec<serde_json::Value> = hosted_versions(&state.storage, &ns, &name)
+        .await
+        .iter()
+        .map(|record| version_summary(&base_url, record))
+        .collect();
+    if hosted.is_empty() {
+        return response;
+    }
+    let fallback = listing_json(hosted.clone());
+    overlay_hosted(
+        response,
+        |doc| {
+            merge_listing(
+                doc,
+                &|entry| record_version(entry).to_string(),
+                hosted,
+                &|_, hosted| hosted.clone(),
+            )
+        },
+        Some(fallback),
+    )
     .await
 }
 
@@ -199,6 +294,13 @@ async fn version_detail(
         return StatusCode::BAD_REQUEST.into_response();
     }
 
+    // Published to NORA — answered locally, no curation (operator-owned).
+    if let Some(record) = hosted_record(&state.storage, &ns, &name, &ver).await {
+        state.metrics.record_download("ansible");
+        let detail = version_detail_json(&nora_base_url(&state), &record);
+        return with_json(serde_json::to_vec(&detail).unwrap_or_default());
+    }
+
     // Curation check. #733: an internal-namespace collection is operator-owned — skip curation;
     // proxy_json below already serves any local copy and blocks the upstream branch for internal.
     if !crate::curation::is_internal_namespace(
@@ -310,6 +412,13 @@ async fn download_tarball(
     }
 
     let storage_key = format!("ansible/download/{}", filename);
+    // Published to NORA: operator-owned like an internal namespace, and never
+    // fetched upstream even when the tarball has gone missing.
+    let hosted = state
+        .storage
+        .stat(&hosted_record_key(ns, name, ver))
+        .await
+        .is_some();
 
     // Release date for the digest-quarantine first-seen clock (#748/#750): the
     // Galaxy versions metadata (cached by version_list) carries per-version
@@ -329,7 +438,7 @@ async fn download_tarball(
         crate::curation::RegistryType::Ansible,
         &format!("{}.{}", ns, name),
     );
-    if !internal {
+    if !internal && !hosted {
         if let Some(response) = crate::curation::check_download(
             &state.curation().curation_engine,
             state.bypass_token().as_deref(),
@@ -351,6 +460,28 @@ async fn download_tarball(
             GateOutcome::Verified(blob) => verified_body(blob),
             GateOutcome::Unpinned(blob) => blob.into_inner(),
         };
+        if hosted {
+            state.metrics.record_download("ansible");
+            if let Some(response) = crate::registry::range::range_response(
+                &state.storage,
+                &[&storage_key],
+                &headers,
+                data.len() as u64,
+                "application/gzip",
+                &[],
+            )
+            .await
+            {
+                return response;
+            }
+            state.activity.push(ActivityEntry::new(
+                ActionType::Pull,
+                filename,
+                crate::registry_type::RegistryType::Ansible,
+                "LOCAL",
+            ));
+            return with_binary(data.to_vec());
+        }
         // Integrity check
         if let Some(response) = crate::curation::verify_integrity(
             &state.curation().curation_engine,
@@ -419,6 +550,10 @@ async fn download_tarball(
         return with_binary(data.to_vec());
     }
 
+    if hosted {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
     // #733: an internal-namespace collection with no local copy is never proxied upstream.
     if internal {
         return crate::curation::check_namespace_isolation(
@@ -499,6 +634,670 @@ async fn download_tarball(
     }
 }
 
+// ── Collection publish (v3 import API) ────────────────────────────────
+
+/// Upper bound on a collection's unpacked size.
+const MAX_COLLECTION_UNPACKED: u64 = 512 << 20;
+
+/// Metadata fields copied from `MANIFEST.json` `collection_info` into the
+/// version detail's `metadata` block (what Galaxy exposes).
+const METADATA_FIELDS: &[&str] = &[
+    "authors",
+    "dependencies",
+    "description",
+    "documentation",
+    "homepage",
+    "issues",
+    "license",
+    "repository",
+    "tags",
+];
+
+/// POST /ansible/v3/artifacts/collections/ — `ansible-galaxy collection publish`.
+///
+/// Multipart body: `file` (the `{ns}-{name}-{ver}.tar.gz` built by `ansible-galaxy
+/// collection build`) and an optional `sha256`. Request-level problems (missing
+/// file, bad filename, checksum mismatch, scope, existing version) are answered
+/// directly; the archive itself is checked as Galaxy's importer checks it and the
+/// outcome is recorded in an import task the client polls. An accepted tarball is
+/// written under the key the proxy serves, plus a record under `ansible/hosted/`
+/// that the list/detail/version endpoints merge with the upstream answers.
+async fn collection_upload(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    mut multipart: Multipart,
+) -> Response {
+    let mut file: Option<(String, Bytes)> = None;
+    let mut expected_sha256: Option<String> = None;
+    while let Ok(Some(field)) = multipart.next_field().await {
+        match field.name().unwrap_or("") {
+            "file" => {
+                let filename = field.file_name().unwrap_or("").to_string();
+                match field.bytes().await {
+                    Ok(data) => file = Some((filename, data)),
+                    Err(e) => {
+                        return galaxy_error(
+                            StatusCode::BAD_REQUEST,
+                            "invalid",
+                            &format!("Failed to read file: {}", e),
+                        )
+                    }
+                }
+            }
+            "sha256" => expected_sha256 = field.text().await.ok(),
+            _ => {
+                let _ = field.bytes().await;
+            }
+        }
+    }
+    let Some((filename, data)) = file else {
+        return galaxy_error(StatusCode::BAD_REQUEST, "invalid", "Missing `file` field");
+    };
+    let Some((ns, name, ver)) = parse_tarball_name(&filename).filter(|(_, _, v)| is_semver(v))
+    else {
+        return galaxy_error(
+            StatusCode::BAD_REQUEST,
+            "invalid",
+            "Expected a {namespace}-{name}-{version}.tar.gz file with a semver version",
+        );
+    };
+
+    // Enforce OIDC namespace_scope on the collection coordinate (#583).
+    if enforce_namespace_scope(&authority, &format!("{}/{}", ns, name)).is_err() {
+        return galaxy_error(
+            StatusCode::FORBIDDEN,
+            "forbidden",
+            "Outside namespace scope",
+        );
+    }
+
+    let sha256 = hex::encode(sha2::Sha256::digest(&data));
+    if let Some(expected) = expected_sha256.as_deref().map(str::trim) {
+        if !expected.is_empty() && !expected.eq_ignore_ascii_case(&sha256) {
+            return galaxy_error(
+                StatusCode::BAD_REQUEST,
+                "invalid",
+                "The sha256 field does not match the uploaded file",
+            );
+        }
+    }
+
+    let lock = state.publish_lock(&format!("ansible/{}/{}", ns, name));
+    let _guard = lock.lock().await;
+
+    // Versions are immutable — including one already cached from upstream.
+    let tarball_key = format!("ansible/download/{}", filename);
+    if state.storage.stat(&tarball_key).await.is_some() {
+        return galaxy_error(
+            StatusCode::CONFLICT,
+            "conflict.collection_exists",
+            &format!("Collection {}.{} version {} already exists", ns, name, ver),
+        );
+    }
+
+    let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    let collection_info = match check_collection_tarball(&data) {
+        Ok(info) => info,
+        Err(e) => return import_task_response(&state, &now, Err(e)).await,
+    };
+    let declared = |field: &str| collection_info.get(field).and_then(|v| v.as_str());
+    if declared("namespace") != Some(ns) || declared("name") != Some(name) {
+        return import_task_response(
+            &state,
+            &now,
+            Err(format!(
+                "MANIFEST.json declares {}.{}, not {}.{}",
+                declared("namespace").unwrap_or(""),
+                declared("name").unwrap_or(""),
+                ns,
+                name
+            )),
+        )
+        .await;
+    }
+    if declared("version") != Some(ver) {
+        return import_task_response(
+            &state,
+            &now,
+            Err(format!(
+                "MANIFEST.json declares version {}, not {}",
+                declared("version").unwrap_or(""),
+                ver
+            )),
+        )
+        .await;
+    }
+
+    let metadata: serde_json::Map<String, serde_json::Value> = METADATA_FIELDS
+        .iter()
+        .filter_map(|field| Some((field.to_string(), collection_info.get(*field)?.clone())))
+        .collect();
+    let record = serde_json::json!({
+        "namespace": ns,
+        "name": name,
+        "version": ver,
+        "filename": filename,
+        "sha256": sha256,
+        "size": data.len(),
+        "created_at": now,
+        "metadata": metadata,
+    });
+
+    // The tarball goes first; the record is what makes the version visible.
+    if state.storage.put(&tarball_key, &data).await.is_err() {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    if state
+        .storage
+        .put(
+            &hosted_record_key(ns, name, ver),
+            &serde_json::to_vec(&record).unwrap_or_default(),
+        )
+        .await
+        .is_err()
+    {
+        let _ = state.storage.delete(&tarball_key).await;
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    state.metrics.record_upload("ansible");
+    let artifact = format!("{}.{} v{}", ns, name, ver);
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &artifact, "ansible", ""));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        artifact.clone(),
+        crate::registry_type::RegistryType::Ansible,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("ansible");
+
+    import_task_response(&state, &now, Ok(format!("Imported {}", artifact))).await
+}
+
+/// GET /ansible/v3/imports/collections/{task}/ — import task status.
+async fn import_task(State(state): State<AppState>, Path(task): Path<String>) -> Response {
+    if !is_valid_task_id(&task) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    match state.storage.get(&import_task_key(&task)).await {
+        Ok(data) => with_json(data.to_vec()),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Record a finished import task and answer the upload with its URL (202).
+///
+/// Imports run synchronously, so the task is already `completed` or `failed` by
+/// the time the client first polls it.
+async fn import_task_response(
+    state: &AppState,
+    now: &str,
+    outcome: Result<String, String>,
+) -> Response {
+    let id = uuid::Uuid::new_v4().to_string();
+    let (task_state, message, error) = match outcome {
+        Ok(message) => ("completed", message, serde_json::Value::Null),
+        Err(description) => (
+            "failed",
+            description.clone(),
+            serde_json::json!({ "code": "failed", "description": description }),
+        ),
+    };
+    let task = serde_json::json!({
+        "id": id,
+        "state": task_state,
+        "created_at": now,
+        "updated_at": now,
+        "started_at": now,
+        "finished_at": now,
+        "error": error,
+        "messages": [{
+            "level": if task_state == "completed" { "INFO" } else { "ERROR" },
+            "message": message,
+            "time": now,
+        }],
+    });
+    if state
+        .storage
+        .put(
+            &import_task_key(&id),
+            &serde_json::to_vec(&task).unwrap_or_default(),
+        )
+        .await
+        .is_err()
+    {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    let body = serde_json::json!({
+        "task": format!("{}/ansible/v3/imports/collections/{}/", nora_base_url(state), id),
+    });
+    (
+        StatusCode::ACCEPTED,
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        )],
+        serde_json::to_vec(&body).unwrap_or_default(),
+    )
+        .into_response()
+}
+
+/// Galaxy v3 error document (`{"errors": [...]}`), which `ansible-galaxy` prints.
+fn galaxy_error(status: StatusCode, code: &str, detail: &str) -> Response {
+    let body = serde_json::json!({
+        "errors": [{
+            "status": status.as_u16().to_string(),
+            "code": code,
+            "title": status.canonical_reason().unwrap_or(""),
+            "detail": detail,
+        }]
+    });
+    (
+        status,
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        )],
+        serde_json::to_vec(&body).unwrap_or_default(),
+    )
+        .into_response()
+}
+
+/// Check a collection tarball the way Galaxy's importer does and return its
+/// `MANIFEST.json` `collection_info`.
+///
+/// - gzip tar, every member inside the collection root (no absolute paths, no
+///   `..`, no hard links, symlinks resolving inside the collection)
+/// - `MANIFEST.json` names `FILES.json` with a matching sha256
+/// - every file listed in `FILES.json` is present with a matching sha256, and
+///   every file in the archive is listed
+fn check_collection_tarball(data: &[u8]) -> Result<serde_json::Value, String> {
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::io::Read;
+    use std::path::Component;
+
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(data));
+    let mut files: BTreeMap<String, String> = BTreeMap::new();
+    let mut links: BTreeSet<String> = BTreeSet::new();
+    let mut manifest: Option<Vec<u8>> = None;
+    let mut files_json: Option<Vec<u8>> = None;
+    let mut total: u64 = 0;
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("not a gzip tar: {}", e))?
+    {
+        let mut entry = entry.map_err(|e| format!("not a gzip tar: {}", e))?;
+        let path = entry.path().map_err(|e| e.to_string())?.into_owned();
+        if path.is_absolute() || path.components().any(|c| c == Component::ParentDir) {
+            return Err(format!("entry escapes the collection: {}", path.display()));
+        }
+        let name = path
+            .components()
+            .filter(|c| *c != Component::CurDir)
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let kind = entry.header().entry_type();
+        if kind.is_hard_link() {
+            return Err(format!("hard links are not allowed: {}", name));
+        }
+        if kind.is_symlink() {
+            let target = entry
+                .link_name()
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| format!("symlink without a target: {}", name))?;
+            let mut depth = name.matches('/').count() as i64;
+            for component in target.components() {
+                match component {
+                    Component::Normal(_) => depth += 1,
+                    Component::ParentDir => depth -= 1,
+                    Component::CurDir => {}
+                    _ => return Err(format!("symlink leaves the collection: {}", name)),
+                }
+                if depth < 0 {
+                    return Err(format!("symlink leaves the collection: {}", name));
+                }
+            }
+            links.insert(name);
+            continue;
+        }
+        if !kind.is_file() {
+            continue;
+        }
+        total = total.saturating_add(entry.size());
+        if total > MAX_COLLECTION_UNPACKED {
+            return Err(format!("unpacks past {} bytes", MAX_COLLECTION_UNPACKED));
+        }
+        let mut content = Vec::with_capacity(entry.size() as usize);
+        entry
+            .read_to_end(&mut content)
+            .map_err(|e| format!("{}: {}", name, e))?;
+        match name.as_str() {
+            "MANIFEST.json" => manifest = Some(content),
+            "FILES.json" => files_json = Some(content),
+            _ => {
+                files.insert(name, hex::encode(sha2::Sha256::digest(&content)));
+            }
+        }
+    }
+
+    let manifest: serde_json::Value =
+        serde_json::from_slice(&manifest.ok_or("MANIFEST.json is missing")?)
+            .map_err(|e| format!("MANIFEST.json: {}", e))?;
+    let collection_info = manifest
+        .get("collection_info")
+        .filter(|v| v.is_object())
+        .ok_or("MANIFEST.json has no collection_info")?
+        .clone();
+    for field in ["namespace", "name", "version"] {
+        if collection_info
+            .get(field)
+            .and_then(|v| v.as_str())
+            .is_none()
+        {
+            return Err(format!("MANIFEST.json collection_info has no {}", field));
+        }
+    }
+    let files_json = files_json.ok_or("FILES.json is missing")?;
+    let file_manifest = manifest
+        .get("file_manifest_file")
+        .ok_or("MANIFEST.json has no file_manifest_file")?;
+    let field = |key: &str| file_manifest.get(key).and_then(|v| v.as_str());
+    if field("name") != Some("FILES.json") || field("chksum_type") != Some("sha256") {
+        return Err("MANIFEST.json file_manifest_file must name FILES.json (sha256)".to_string());
+    }
+    if field("chksum_sha256") != Some(hex::encode(sha2::Sha256::digest(&files_json)).as_str()) {
+        return Err("FILES.json does not match the checksum in MANIFEST.json".to_string());
+    }
+
+    let listing: serde_json::Value =
+        serde_json::from_slice(&files_json).map_err(|e| format!("FILES.json: {}", e))?;
+    let entries = listing
+        .get("files")
+        .and_then(|v| v.as_array())
+        .ok_or("FILES.json has no files list")?;
+    let mut listed: BTreeSet<&str> = BTreeSet::new();
+    for entry in entries {
+        let name = entry
+            .get("name")
+            .and_then(|v| v.as_str())
+            .ok_or("FILES.json entry without a name")?;
+        if entry.get("ftype").and_then(|v| v.as_str()) != Some("file") {
+            continue;
+        }
+        listed.insert(name);
+        if links.contains(name) {
+            continue;
+        }
+        let actual = files
+            .get(name)
+            .ok_or_else(|| format!("{} is listed in FILES.json but missing", name))?;
+        if entry.get("chksum_type").and_then(|v| v.as_str()) != Some("sha256")
+            || entry.get("chksum_sha256").and_then(|v| v.as_str()) != Some(actual.as_str())
+        {
+            return Err(format!("checksum mismatch for {}", name));
+        }
+    }
+    if let Some(unlisted) = files.keys().find(|name| !listed.contains(name.as_str())) {
+        return Err(format!("{} is not listed in FILES.json", unlisted));
+    }
+    Ok(collection_info)
+}
+
+// ── Hosted collections ────────────────────────────────────────────────
+
+/// Record of a collection version published to NORA.
+fn hosted_record_key(ns: &str, name: &str, ver: &str) -> String {
+    format!("ansible/hosted/{}/{}/{}.json", ns, name, ver)
+}
+
+fn import_task_key(id: &str) -> String {
+    format!("ansible/imports/{}.json", id)
+}
+
+async fn hosted_record(
+    storage: &crate::storage::Storage,
+    ns: &str,
+    name: &str,
+    ver: &str,
+) -> Option<serde_json::Value> {
+    let data = storage.get(&hosted_record_key(ns, name, ver)).await.ok()?;
+    serde_json::from_slice(&data).ok()
+}
+
+/// Hosted versions of a collection, highest first.
+async fn hosted_versions(
+    storage: &crate::storage::Storage,
+    ns: &str,
+    name: &str,
+) -> Vec<serde_json::Value> {
+    let prefix = format!("ansible/hosted/{}/{}/", ns, name);
+    let mut records = Vec::new();
+    for key in storage.list(&prefix).await.unwrap_or_default() {
+        let Some(ver) = key
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.strip_suffix(".json"))
+        else {
+            continue;
+        };
+        if let Some(record) = hosted_record(storage, ns, name, ver).await {
+            records.push(record);
+        }
+    }
+    records.sort_by(|a, b| cmp_semver(record_version(b), record_version(a)));
+    records
+}
+
+/// Every `(namespace, name)` with at least one hosted version.
+async fn hosted_collections(storage: &crate::storage::Storage) -> Vec<(String, String)> {
+    let mut collections: Vec<(String, String)> = storage
+        .list("ansible/hosted/")
+        .await
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|key| {
+            let mut parts = key.strip_prefix("ansible/hosted/")?.split('/');
+            Some((parts.next()?.to_string(), parts.next()?.to_string()))
+        })
+        .collect();
+    collections.sort();
+    collections.dedup();
+    collections
+}
+
+/// Hosted collection entry, or `None` when nothing was published under it.
+async fn hosted_collection(
+    state: &AppState,
+    base: &str,
+    ns: &str,
+    name: &str,
+) -> Option<serde_json::Value> {
+    let versions = hosted_versions(&state.storage, ns, name).await;
+    (!versions.is_empty()).then(|| collection_json(base, ns, name, &versions))
+}
+
+fn collection_key(collection: &serde_json::Value) -> String {
+    let field = |key: &str| collection[key].as_str().unwrap_or("").to_string();
+    format!("{}.{}", field("namespace"), field("name"))
+}
+
+fn record_version(record: &serde_json::Value) -> &str {
+    record.get("version").and_then(|v| v.as_str()).unwrap_or("")
+}
+
+fn collection_href(base: &str, ns: &str, name: &str) -> String {
+    format!("{}/ansible/v3/collections/{}/{}/", base, ns, name)
+}
+
+/// Entry of a versions listing.
+fn version_summary(base: &str, record: &serde_json::Value) -> serde_json::Value {
+    let ns = record["namespace"].as_str().unwrap_or("");
+    let name = record["name"].as_str().unwrap_or("");
+    let ver = record_version(record);
+    serde_json::json!({
+        "version": ver,
+        "href": format!("{}versions/{}/", collection_href(base, ns, name), ver),
+        "created_at": record["created_at"],
+        "updated_at": record["created_at"],
+        "requires_ansible": null,
+        "marks": [],
+    })
+}
+
+/// Version detail, as `ansible-galaxy collection install` reads it.
+fn version_detail_json(base: &str, record: &serde_json::Value) -> serde_json::Value {
+    let ns = record["namespace"].as_str().unwrap_or("");
+    let name = record["name"].as_str().unwrap_or("");
+    let filename = record["filename"].as_str().unwrap_or("");
+    let mut detail = version_summary(base, record);
+    detail["download_url"] = format!("{}/ansible/download/{}", base, filename).into();
+    detail["artifact"] = serde_json::json!({
+        "filename": filename,
+        "sha256": record["sha256"],
+        "size": record["size"],
+    });
+    detail["collection"] = serde_json::json!({
+        "href": collection_href(base, ns, name),
+        "name": name,
+    });
+    detail["namespace"] = serde_json::json!({ "name": ns });
+    detail["name"] = name.into();
+    detail["metadata"] = record["metadata"].clone();
+    detail["signatures"] = serde_json::json!([]);
+    detail
+}
+
+/// Collection entry; `versions` is non-empty and highest first.
+fn collection_json(
+    base: &str,
+    ns: &str,
+    name: &str,
+    versions: &[serde_json::Value],
+) -> serde_json::Value {
+    let href = collection_href(base, ns, name);
+    let highest = record_version(&versions[0]);
+    let created = versions
+        .iter()
+        .filter_map(|r| r["created_at"].as_str())
+        .min()
+        .unwrap_or("");
+    let updated = versions
+        .iter()
+        .filter_map(|r| r["created_at"].as_str())
+        .max()
+        .unwrap_or("");
+    serde_json::json!({
+        "href": href,
+        "namespace": ns,
+        "name": name,
+        "deprecated": false,
+        "versions_url": format!("{}versions/", href),
+        "highest_version": {
+            "href": format!("{}versions/{}/", href, highest),
+            "version": highest,
+        },
+        "created_at": created,
+        "updated_at": updated,
+        "download_count": 0,
+    })
+}
+
+/// A single-page Galaxy listing document.
+fn listing_json(data: Vec<serde_json::Value>) -> serde_json::Value {
+    serde_json::json!({
+        "meta": { "count": data.len() },
+        "links": { "first": null, "previous": null, "next": null, "last": null },
+        "data": data,
+    })
+}
+
+/// Fold hosted entries into the first page of an upstream listing.
+///
+/// Entries are matched on `key`; a hosted entry replaces an upstream one with the
+/// same key (via `replace`) and new ones go in front, bumping `meta.count`.
+fn merge_listing(
+    listing: &mut serde_json::Value,
+    key: &dyn Fn(&serde_json::Value) -> String,
+    hosted: Vec<serde_json::Value>,
+    replace: &dyn Fn(&serde_json::Value, &serde_json::Value) -> serde_json::Value,
+) {
+    let Some(data) = listing.get_mut("data").and_then(|d| d.as_array_mut()) else {
+        return;
+    };
+    let mut added = Vec::new();
+    for entry in hosted {
+        match data.iter_mut().find(|e| key(e) == key(&entry)) {
+            Some(existing) => *existing = replace(existing, &entry),
+            None => added.push(entry),
+        }
+    }
+    let count = added.len();
+    data.splice(0..0, added);
+    if let Some(meta_count) = listing
+        .get_mut("meta")
+        .and_then(|m| m.get_mut("count"))
+        .and_then(|c| c.as_u64())
+    {
+        listing["meta"]["count"] = (meta_count + count as u64).into();
+    }
+}
+
+/// Apply `merge` to a successful JSON upstream response, or answer `fallback`
+/// (hosted data only) when the upstream failed or the collection is internal.
+async fn overlay_hosted(
+    response: Response,
+    merge: impl FnOnce(&mut serde_json::Value),
+    fallback: Option<serde_json::Value>,
+) -> Response {
+    if !response.status().is_success() {
+        return match fallback {
+            Some(doc) => with_json(serde_json::to_vec(&doc).unwrap_or_default()),
+            None => response,
+        };
+    }
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+        return StatusCode::BAD_GATEWAY.into_response();
+    };
+    let Ok(mut doc) = serde_json::from_slice::<serde_json::Value>(&bytes) else {
+        return Response::from_parts(parts, axum::body::Body::from(bytes));
+    };
+    merge(&mut doc);
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(
+        parts,
+        axum::body::Body::from(serde_json::to_vec(&doc).unwrap_or_default()),
+    )
+}
+
+/// Replace `highest_version` when a hosted version is higher than the upstream one.
+fn raise_highest_version(collection: &mut serde_json::Value, hosted: &serde_json::Value) {
+    let upstream = collection["highest_version"]["version"]
+        .as_str()
+        .unwrap_or("");
+    let candidate = hosted["highest_version"]["version"].as_str().unwrap_or("");
+    if cmp_semver(candidate, upstream) == std::cmp::Ordering::Greater {
+        collection["highest_version"] = hosted["highest_version"].clone();
+    }
+}
+
+/// Split `{ns}-{name}-{ver}.tar.gz`.
+fn parse_tarball_name(filename: &str) -> Option<(&str, &str, &str)> {
+    if !is_safe_filename(filename) {
+        return None;
+    }
+    let stem = filename.strip_suffix(".tar.gz")?;
+    let mut parts = stem.splitn(3, '-');
+    let (ns, name, ver) = (parts.next()?, parts.next()?, parts.next()?);
+    (is_valid_name(ns) && is_valid_name(name) && is_valid_version(ver)).then_some((ns, name, ver))
+}
+
+fn is_valid_task_id(task: &str) -> bool {
+    !task.is_empty() && task.len() <= 64 && task.chars().all(|c| c.is_ascii_hexdigit() || c == '-')
+}
+
 // ── Generic JSON proxy with metadata caching ─────────────────────────
 
 /// Proxy a Galaxy API JSON request with metadata caching and serve-stale.
@@ -800,6 +1599,14 @@ fn extract_page_param(raw_query: Option<&str>) -> Option<String> {
     None
 }
 
+/// No `page`/`offset`, or the first one — where hosted entries are merged in.
+fn is_first_page(raw_query: Option<&str>) -> bool {
+    matches!(
+        extract_page_param(raw_query).as_deref(),
+        None | Some("0") | Some("1")
+    )
+}
+
 fn upstream_url(state: &AppState) -> String {
     state
         .config
@@ -1156,6 +1963,181 @@ mod tests {
         assert!(result.contains("http://nora:4000/ansible/download/my-collection-1.0.0.tar.gz"));
         assert!(!result.contains("hub.example.com"));
     }
+
+    // ── Collection publish ────────────────────────────────────────────
+
+    /// gzip tar with the given members (path, contents) as regular files.
+    pub(super) fn build_tarball(members: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::default(),
+        ));
+        for (path, data) in members {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, *data).unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    /// A collection as `ansible-galaxy collection build` lays it out.
+    pub(super) fn build_collection(ns: &str, name: &str, version: &str) -> Vec<u8> {
+        let readme: &[u8] = b"# test collection\n";
+        let files = serde_json::json!({
+            "files": [
+                { "name": ".", "ftype": "dir", "chksum_type": null, "chksum_sha256": null, "format": 1 },
+                {
+                    "name": "README.md",
+                    "ftype": "file",
+                    "chksum_type": "sha256",
+                    "chksum_sha256": hex::encode(sha2::Sha256::digest(readme)),
+                    "format": 1
+                }
+            ],
+            "format": 1
+        });
+        let files = serde_json::to_vec(&files).unwrap();
+        let manifest = serde_json::json!({
+            "collection_info": {
+                "namespace": ns,
+                "name": name,
+                "version": version,
+                "authors": ["Test <test@example.com>"],
+                "description": "Test collection",
+                "license": ["MIT"],
+                "tags": ["test"],
+                "dependencies": {},
+                "repository": "https://example.com/repo"
+            },
+            "file_manifest_file": {
+                "name": "FILES.json",
+                "ftype": "file",
+                "chksum_type": "sha256",
+                "chksum_sha256": hex::encode(sha2::Sha256::digest(&files)),
+                "format": 1
+            },
+            "format": 1
+        });
+        let manifest = serde_json::to_vec(&manifest).unwrap();
+        build_tarball(&[
+            ("MANIFEST.json", &manifest),
+            ("FILES.json", &files),
+            ("README.md", readme),
+        ])
+    }
+
+    #[test]
+    fn test_check_collection_tarball_accepts_built_collection() {
+        let info = check_collection_tarball(&build_collection("acme", "tools", "1.2.3")).unwrap();
+        assert_eq!(info["namespace"], "acme");
+        assert_eq!(info["name"], "tools");
+        assert_eq!(info["version"], "1.2.3");
+    }
+
+    #[test]
+    fn test_check_collection_tarball_rejects_bad_archives() {
+        assert!(check_collection_tarball(b"not a tarball").is_err());
+        let missing = build_tarball(&[("README.md", b"hi")]);
+        assert!(check_collection_tarball(&missing)
+            .unwrap_err()
+            .contains("MANIFEST.json"));
+
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::default(),
+        ));
+        let mut header = tar::Header::new_gnu();
+        header.set_entry_type(tar::EntryType::Symlink);
+        header.set_size(0);
+        builder
+            .append_link(&mut header, "roles/passwd", "../../etc/passwd")
+            .unwrap();
+        let escape = builder.into_inner().unwrap().finish().unwrap();
+        assert!(check_collection_tarball(&escape)
+            .unwrap_err()
+            .contains("symlink"));
+    }
+
+    #[test]
+    fn test_check_collection_tarball_rejects_checksum_mismatches() {
+        // Rebuild the archive with a README that no longer matches FILES.json.
+        let original = build_collection("acme", "tools", "1.0.0");
+        let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(&original[..]));
+        let mut members: Vec<(String, Vec<u8>)> = Vec::new();
+        for entry in archive.entries().unwrap() {
+            let mut entry = entry.unwrap();
+            let path = entry.path().unwrap().to_string_lossy().into_owned();
+            let mut data = Vec::new();
+            std::io::Read::read_to_end(&mut entry, &mut data).unwrap();
+            members.push((path, data));
+        }
+        let rebuild = |edit: &dyn Fn(&str, &[u8]) -> Vec<u8>, extra: Option<(&str, &[u8])>| {
+            let mut edited: Vec<(String, Vec<u8>)> = members
+                .iter()
+                .map(|(path, data)| (path.clone(), edit(path, data)))
+                .collect();
+            if let Some((path, data)) = extra {
+                edited.push((path.to_string(), data.to_vec()));
+            }
+            let refs: Vec<(&str, &[u8])> = edited
+                .iter()
+                .map(|(path, data)| (path.as_str(), data.as_slice()))
+                .collect();
+            build_tarball(&refs)
+        };
+
+        let tampered_file = rebuild(
+            &|path, data| {
+                if path == "README.md" {
+                    b"tampered".to_vec()
+                } else {
+                    data.to_vec()
+                }
+            },
+            None,
+        );
+        assert!(check_collection_tarball(&tampered_file)
+            .unwrap_err()
+            .contains("README.md"));
+
+        let tampered_listing = rebuild(
+            &|path, data| {
+                if path == "FILES.json" {
+                    let mut data = data.to_vec();
+                    data.push(b' ');
+                    data
+                } else {
+                    data.to_vec()
+                }
+            },
+            None,
+        );
+        assert!(check_collection_tarball(&tampered_listing)
+            .unwrap_err()
+            .contains("FILES.json"));
+
+        let unlisted = rebuild(&|_, data| data.to_vec(), Some(("plugins/extra.py", b"x")));
+        assert!(check_collection_tarball(&unlisted)
+            .unwrap_err()
+            .contains("plugins/extra.py"));
+    }
+
+    #[test]
+    fn test_parse_tarball_name() {
+        assert_eq!(
+            parse_tarball_name("acme-tools-1.2.3.tar.gz"),
+            Some(("acme", "tools", "1.2.3"))
+        );
+        assert_eq!(
+            parse_tarball_name("acme-tools-1.0.0-beta.1.tar.gz"),
+            Some(("acme", "tools", "1.0.0-beta.1"))
+        );
+        assert_eq!(parse_tarball_name("acme-tools.tar.gz"), None);
+        assert_eq!(parse_tarball_name("acme-tools-1.0.0.zip"), None);
+        assert_eq!(parse_tarball_name("../acme-tools-1.0.0.tar.gz"), None);
+    }
 }
 
 #[cfg(test)]
@@ -1614,4 +2596,287 @@ mod integration_tests {
         // Payload is otherwise passed through untouched.
         assert!(text.contains("\"version\":\"4.4.0\""), "data lost: {text}");
     }
+
+    // ── Collection publish ────────────────────────────────────────────
+
+    const BOUNDARY: &str = "nora-ansible-boundary";
+
+    /// Ansible enabled with an unreachable upstream, so anything not hosted fails loudly.
+    fn hosted_context() -> crate::test_helpers::TestContext {
+        create_test_context_with_config(|cfg| {
+            cfg.ansible.enabled = true;
+            cfg.ansible.proxy = Some("http://127.0.0.1:1".to_string());
+            cfg.ansible.proxy_timeout = 1;
+        })
+    }
+
+    fn upload_body(filename: &str, tarball: &[u8], sha256: Option<&str>) -> Vec<u8> {
+        let mut body = Vec::new();
+        if let Some(sha256) = sha256 {
+            body.extend_from_slice(
+                format!(
+                    "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"sha256\"\r\n\r\n{sha256}\r\n"
+                )
+                .as_bytes(),
+            );
+        }
+        body.extend_from_slice(
+            format!(
+                "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{filename}\"\r\nContent-Type: application/octet-stream\r\n\r\n"
+            )
+            .as_bytes(),
+        );
+        body.extend_from_slice(tarball);
+        body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+        body
+    }
+
+    async fn publish(
+        ctx: &crate::test_helpers::TestContext,
+        filename: &str,
+        tarball: &[u8],
+    ) -> axum::http::Response<axum::body::Body> {
+        let sha256 = hex::encode(<sha2::Sha256 as sha2::Digest>::digest(tarball));
+        let content_type = format!("multipart/form-data; boundary={BOUNDARY}");
+        send_with_headers(
+            &ctx.app,
+            Method::POST,
+            "/ansible/v3/artifacts/collections/",
+            vec![("content-type", content_type.as_str())],
+            upload_body(filename, tarball, Some(&sha256)),
+        )
+        .await
+    }
+
+    /// Follow the task URL from a publish response and return the task document.
+    async fn import_task(
+        ctx: &crate::test_helpers::TestContext,
+        resp: axum::http::Response<axum::body::Body>,
+    ) -> serde_json::Value {
+        assert_eq!(resp.status(), StatusCode::ACCEPTED);
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        let task_url = body["task"].as_str().unwrap();
+        let path = &task_url[task_url.find("/ansible/").unwrap()..];
+        let resp = send(&ctx.app, Method::GET, path, "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        serde_json::from_slice(&body_bytes(resp).await).unwrap()
+    }
+
+    async fn get_json(ctx: &crate::test_helpers::TestContext, uri: &str) -> serde_json::Value {
+        let resp = send(&ctx.app, Method::GET, uri, "").await;
+        assert_eq!(resp.status(), StatusCode::OK, "GET {}", uri);
+        serde_json::from_slice(&body_bytes(resp).await).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_ansible_publish_and_install() {
+        let ctx = hosted_context();
+        let tarball = super::tests::build_collection("acme", "tools", "1.2.0");
+        let resp = publish(&ctx, "acme-tools-1.2.0.tar.gz", &tarball).await;
+        let task = import_task(&ctx, resp).await;
+        assert_eq!(task["state"], "completed");
+
+        let versions = get_json(&ctx, "/ansible/v3/collections/acme/tools/versions/").await;
+        assert_eq!(versions["meta"]["count"], 1);
+        assert_eq!(versions["data"][0]["version"], "1.2.0");
+
+        let detail = get_json(&ctx, "/ansible/v3/collections/acme/tools/versions/1.2.0/").await;
+        assert_eq!(detail["namespace"]["name"], "acme");
+        assert_eq!(detail["metadata"]["description"], "Test collection");
+        assert_eq!(
+            detail["artifact"]["sha256"],
+            hex::encode(<sha2::Sha256 as sha2::Digest>::digest(&tarball))
+        );
+        let download_url = detail["download_url"].as_str().unwrap();
+        assert!(download_url.ends_with("/ansible/download/acme-tools-1.2.0.tar.gz"));
+
+        let collection = get_json(&ctx, "/ansible/v3/collections/acme/tools/").await;
+        assert_eq!(collection["highest_version"]["version"], "1.2.0");
+        let list = get_json(&ctx, "/ansible/v3/collections/").await;
+        assert_eq!(list["data"][0]["name"], "tools");
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/ansible/download/acme-tools-1.2.0.tar.gz",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(body_bytes(resp).await.to_vec(), tarball);
+    }
+
+    #[tokio::test]
+    async fn test_ansible_publish_merges_with_upstream_versions() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/api/v3/plugin/ansible/content/published/collections/index/acme/tools/versions/",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "meta": { "count": 1 },
+                "links": { "first": null, "previous": null, "next": null, "last": null },
+                "data": [{ "version": "1.0.0", "href": "x", "created_at": "2024-01-01T00:00:00Z" }]
+            })))
+            .mount(&mock)
+            .await;
+        let uri = mock.uri();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.ansible.enabled = true;
+            cfg.ansible.proxy = Some(uri);
+        });
+
+        let tarball = super::tests::build_collection("acme", "tools", "2.0.0");
+        let resp = publish(&ctx, "acme-tools-2.0.0.tar.gz", &tarball).await;
+        assert_eq!(import_task(&ctx, resp).await["state"], "completed");
+
+        let versions = get_json(&ctx, "/ansible/v3/collections/acme/tools/versions/").await;
+        assert_eq!(versions["meta"]["count"], 2);
+        let listed: Vec<&str> = versions["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["version"].as_str().unwrap())
+            .collect();
+        assert_eq!(listed, vec!["2.0.0", "1.0.0"]);
+    }
+
+    #[tokio::test]
+    async fn test_ansible_publish_invalid_manifest_fails_task() {
+        let ctx = hosted_context();
+        // Filename says 1.0.0, MANIFEST.json says 9.9.9.
+        let tarball = super::tests::build_collection("acme", "tools", "9.9.9");
+        let resp = publish(&ctx, "acme-tools-1.0.0.tar.gz", &tarball).await;
+        let task = import_task(&ctx, resp).await;
+        assert_eq!(task["state"], "failed");
+        assert!(task["error"]["description"]
+            .as_str()
+            .unwrap()
+            .contains("9.9.9"));
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/ansible/download/acme-tools-1.0.0.tar.gz",
+            "",
+        )
+        .await;
+        assert_ne!(resp.status(), StatusCode::OK);
+        assert!(ctx
+            .state
+            .storage
+            .list("ansible/hosted/")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ansible_publish_rejects_bad_requests() {
+        let ctx = hosted_context();
+        let tarball = super::tests::build_collection("acme", "tools", "1.0.0");
+        let content_type = format!("multipart/form-data; boundary={BOUNDARY}");
+
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::POST,
+            "/ansible/v3/artifacts/collections/",
+            vec![("content-type", content_type.as_str())],
+            upload_body("acme-tools-1.0.0.tar.gz", &tarball, Some(&"0".repeat(64))),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert!(body["errors"][0]["detail"]
+            .as_str()
+            .unwrap()
+            .contains("sha256"));
+
+        let resp = publish(&ctx, "acme-tools-latest.tar.gz", &tarball).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_ansible_publish_existing_version_conflicts() {
+        let ctx = hosted_context();
+        let tarball = super::tests::build_collection("acme", "tools", "1.0.0");
+        let resp = publish(&ctx, "acme-tools-1.0.0.tar.gz", &tarball).await;
+        assert_eq!(import_task(&ctx, resp).await["state"], "completed");
+
+        let resp = publish(&ctx, "acme-tools-1.0.0.tar.gz", &tarball).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        // A version already cached from upstream is just as immutable.
+        ctx.state
+            .storage
+            .put("ansible/download/community-general-7.0.0.tar.gz", b"cached")
+            .await
+            .unwrap();
+        let tarball = super::tests::build_collection("community", "general", "7.0.0");
+        let resp = publish(&ctx, "community-general-7.0.0.tar.gz", &tarball).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_ansible_import_task_unknown_is_404() {
+        let ctx = hosted_context();
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/ansible/v3/imports/collections/0000-1111/",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_ansible_publish_namespace_scope_enforced() {
+        use crate::auth::NamespaceAuthority;
+        use crate::config::ScopeEnforcement;
+        use axum::extract::{FromRequest, Multipart, State};
+        use axum::Extension;
+
+        let ctx = hosted_context();
+        let scoped = NamespaceAuthority::from_oidc_scope(
+            "ci",
+            &["acme/**".to_string()],
+            ScopeEnforcement::Enforce,
+        );
+        let multipart = |filename: &str, tarball: Vec<u8>| {
+            let request = axum::http::Request::builder()
+                .header(
+                    "content-type",
+                    format!("multipart/form-data; boundary={BOUNDARY}"),
+                )
+                .body(axum::body::Body::from(upload_body(
+                    filename, &tarball, None,
+                )))
+                .unwrap();
+            Multipart::from_request(request, &())
+        };
+
+        let tarball = super::tests::build_collection("other", "tools", "1.0.0");
+        let resp = super::collection_upload(
+            State(ctx.state.clone()),
+            Extension(scoped.clone()),
+            multipart("other-tools-1.0.0.tar.gz", tarball)
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let tarball = super::tests::build_collection("acme", "tools", "1.0.0");
+        let resp = super::collection_upload(
+            State(ctx.state.clone()),
+            Extension(scoped),
+            multipart("acme-tools-1.0.0.tar.gz", tarball).await.unwrap(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::ACCEPTED);
+    }
 }
@@ -0,0 +1,1370 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+//! Alpine APK registry — hosted apk repositories with a server-generated,
+//! RSA-signed `APKINDEX.tar.gz`, plus per-repo pull-through proxies.
+//!
+//! Implements:
+//!   PUT    /apk/{repo}/{branch}/{component}/{arch}/{file}.apk  — upload (parses `.PKGINFO`, regenerates the index)
+//!   GET    /apk/{repo}/{branch}/{component}/{arch}/{file}      — download a package or `APKINDEX.tar.gz`
+//!   HEAD   /apk/{repo}/{*path}                                 — existence/size check
+//!   DELETE /apk/{repo}/{branch}/{component}/{arch}/{file}.apk  — remove a package (regenerates the index)
+//!   GET    /apk/{repo}/keys/{key_name}                         — public key for `/etc/apk/keys/`
+//!   POST   /apk/{repo}/-/reindex                               — reconcile with storage, rebuild and re-sign
+//!
+//! A client points `/etc/apk/repositories` at `http://nora:4000/apk/{repo}/{branch}/{component}`
+//! and apk appends `/{arch}/APKINDEX.tar.gz` — the Alpine mirror layout. Each
+//! `{branch}/{component}/{arch}` directory has its own index, rebuilt on every
+//! publish/delete from per-package sidecars (`.PKGINFO` parsed once at upload,
+//! same shape as the rpm/deb handlers). The index is signed the way
+//! `abuild-sign` does it: a `.SIGN.RSA256.{key_name}` tar segment, gzipped and
+//! prepended to the gzipped index tar.
+
+use crate::activity_log::{ActionType, ActivityEntry};
+use crate::audit::AuditEntry;
+use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
+use crate::registry::{method_not_allowed, proxied_repo_conflict};
+use crate::validation::validate_storage_key;
+use crate::AppState;
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Extension, Router,
+};
+use base64::Engine;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use sha1::Digest;
+use std::io::{Read, Write};
+
+/// Dashboard index: group stored .apk files by repository name.
+pub const INDEX_PATTERN: (&str, &str) = ("apk/", ".apk");
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/apk/{repo}/-/reindex", axum::routing::post(reindex))
+        .route(
+            "/apk/{repo}/{*path}",
+            get(download)
+                .head(check_exists)
+                .put(upload)
+                .delete(delete_package)
+                .fallback(|| async { method_not_allowed("GET, HEAD, PUT, DELETE") }),
+        )
+}
+
+/// Sidecar prefix inside a repo. Not a valid path component for clients
+/// (uploads to it are rejected), so it can never collide with package files.
+const META_DIR: &str = ".nora-meta";
+const INDEX_FILE: &str = "APKINDEX.tar.gz";
+const KEYS_DIR: &str = "keys";
+/// Upper bound on one decompressed signature/control segment. `.PKGINFO` and
+/// the install scripts are a few KiB; anything near this is not a package.
+const MAX_CONTROL_UNPACKED: u64 = 16 << 20;
+
+fn package_key(repo: &str, path: &str) -> String {
+    format!("apk/{repo}/{path}")
+}
+
+fn sidecar_key(repo: &str, path: &str) -> String {
+    format!("apk/{repo}/{META_DIR}/{path}.json")
+}
+
+fn index_key(repo: &str, dir: &str) -> String {
+    format!("apk/{repo}/{dir}/{INDEX_FILE}")
+}
+
+/// Per-repo publish lock key. One lock for the whole repo (not per index
+/// directory) so reindex and retention can rebuild every directory at once.
+fn lock_key(repo: &str) -> String {
+    format!("apk/{repo}/APKINDEX")
+}
+
+fn valid_repo_name(repo: &str) -> bool {
+    !repo.is_empty() && repo.is_ascii() && !repo.contains('/') && !repo.starts_with('.')
+}
+
+/// Split and validate `{branch}/{component}/{arch}/{file}.apk`. The storage-key
+/// wrapper re-validates centrally; this is edge defence with format-specific
+/// rules (fixed depth, `.apk` suffix, no dot segments, `keys/` reserved).
+fn validate_package_path(repo: &str, path: &str) -> Result<PackagePath, &'static str> {
+    if !valid_repo_name(repo) {
+        return Err("Invalid repository name");
+    }
+    if !path.is_ascii() || path.contains("..") || path.contains('\0') || path.starts_with('/') {
+        return Err("Invalid path");
+    }
+    let segs: Vec<&str> = path.split('/').collect();
+    if segs
+        .iter()
+        .any(|seg| seg.is_empty() || seg.starts_with('.'))
+    {
+        return Err("Invalid path");
+    }
+    let [branch, component, arch, file] = segs[..] else {
+        return Err("Packages live at {branch}/{component}/{arch}/{file}.apk");
+    };
+    if !file.ends_with(".apk") {
+        return Err("Only .apk files can be published");
+    }
+    if branch == KEYS_DIR {
+        return Err("keys/ is reserved for the signing key");
+    }
+    if !arch
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+    {
+        return Err("Invalid architecture");
+    }
+    Ok(PackagePath {
+        branch: branch.to_string(),
+        component: component.to_string(),
+        arch: arch.to_string(),
+        file: file.to_string(),
+    })
+}
+
+struct PackagePath {
+    branch: String,
+    component: String,
+    arch: String,
+    file: String,
+}
+
+impl PackagePath {
+    fn dir(&self) -> String {
+        format!("{}/{}/{}", self.branch, self.component, self.arch)
+    }
+}
+
+// ============================================================================
+// Package metadata sidecar (parsed once at upload, read back on regeneration)
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ApkRecord {
+    name: String,
+    version: String,
+    /// `.PKGINFO` arch — the index directory's arch or `noarch`.
+    arch: String,
+    /// Architecture directory the package is indexed under.
+    index_arch: String,
+    branch: String,
+    component: String,
+    /// Path relative to the repo root — `{branch}/{component}/{arch}/{file}`.
+    href: String,
+    /// `Q1` + base64(sha1(control segment)) — the `C:` field apk verifies.
+    checksum: String,
+    size: u64,
+    #[serde(default)]
+    installed_size: u64,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    license: String,
+    #[serde(default)]
+    origin: String,
+    #[serde(default)]
+    maintainer: String,
+    #[serde(default)]
+    build_time: Option<u64>,
+    #[serde(default)]
+    commit: String,
+    #[serde(default)]
+    provider_priority: Option<u64>,
+    #[serde(default)]
+    depends: Vec<String>,
+    #[serde(default)]
+    provides: Vec<String>,
+    #[serde(default)]
+    install_if: Vec<String>,
+    #[serde(default)]
+    replaces: Vec<String>,
+    #[serde(default)]
+    upload_time: u64,
+}
+
+/// Split the leading gzip members of `data`, returning each member's raw
+/// bytes alongside its decompressed content. Stops after `max_members`; each
+/// member's decompressed size is capped at [`MAX_CONTROL_UNPACKED`].
+fn gzip_members(data: &[u8], max_members: usize) -> Result<Vec<(&[u8], Vec<u8>)>, String> {
+    let mut members = Vec::new();
+    let mut rest = data;
+    while !rest.is_empty() && members.len() < max_members {
+        let start = data.len() - rest.len();
+        let mut decoder = flate2::bufread::GzDecoder::new(rest);
+        let mut out = Vec::new();
+        (&mut decoder)
+            .take(MAX_CONTROL_UNPACKED + 1)
+            .read_to_end(&mut out)
+            .map_err(|e| format!("gzip segment {}: {e}", members.len()))?;
+        if out.len() as u64 > MAX_CONTROL_UNPACKED {
+            return Err("control segment too large".to_string());
+        }
+        rest = decoder.into_inner();
+        let end = data.len() - rest.len();
+        members.push((&data[start..end], out));
+    }
+    Ok(members)
+}
+
+/// Names of the entries in a (possibly end-block-less) tar segment, plus the
+/// contents of `.PKGINFO` when present.
+fn tar_segment(data: &[u8]) -> Result<(Vec<String>, Option<String>), String> {
+    let mut names = Vec::new();
+    let mut pkginfo = None;
+    let mut archive = tar::Archive::new(data);
+    for entry in archive.entries().map_err(|e| format!("tar: {e}"))? {
+        let mut entry = entry.map_err(|e| format!("tar: {e}"))?;
+        let name = entry
+            .path()
+            .map_err(|e| format!("tar: {e}"))?
+            .to_string_lossy()
+            .into_owned();
+        if name == ".PKGINFO" {
+            let mut text = String::new();
+            entry
+                .read_to_string(&mut text)
+                .map_err(|e| format!(".PKGINFO: {e}"))?;
+            pkginfo = Some(text);
+        }
+        names.push(name);
+    }
+    Ok((names, pkginfo))
+}
+
+/// Locate the control segment of an apk v2 package (optional signature
+/// segment, then control, then data — each its own gzip member) and return
+/// its `.PKGINFO` text with the `C:` checksum of the control member.
+fn read_control(body: &[u8]) -> Result<(String, String), String> {
+    let members = gzip_members(body, 2)?;
+    for (raw, content) in members {
+        let (names, pkginfo) = tar_segment(&content)?;
+        if let Some(text) = pkginfo {
+            let checksum = format!(
+                "Q1{}",
+                base64::engine::general_purpose::STANDARD.encode(sha1::Sha1::digest(raw))
+            );
+            return Ok((text, checksum));
+        }
+        if !names.iter().all(|n| n.starts_with(".SIGN.")) {
+            break;
+        }
+    }
+    Err("missing .PKGINFO".to_string())
+}
+
+/// Parse `.PKGINFO` into a sidecar record. `pkgname`, `pkgver` and `arch` are
+/// required; everything else is optional, as in abuild's output.
+fn extract_record(
+    pkginfo: &str,
+    checksum: String,
+    pp: &PackagePath,
+    size: u64,
+    upload_time: u64,
+) -> Result<ApkRecord, String> {
+    let mut record = ApkRecord {
+        name: String::new(),
+        version: String::new(),
+        arch: String::new(),
+        index_arch: pp.arch.clone(),
+        branch: pp.branch.clone(),
+        component: pp.component.clone(),
+        href: format!("{}/{}", pp.dir(), pp.file),
+        checksum,
+        size,
+        installed_size: 0,
+        description: String::new(),
+        url: String::new(),
+        license: String::new(),
+        origin: String::new(),
+        maintainer: String::new(),
+        build_time: None,
+        commit: String::new(),
+        provider_priority: None,
+        depends: Vec::new(),
+        provides: Vec::new(),
+        install_if: Vec::new(),
+        replaces: Vec::new(),
+        upload_time,
+    };
+    for line in pkginfo.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim().to_string());
+        match key {
+            "pkgname" => record.name = value,
+            "pkgver" => record.version = value,
+            "arch" => record.arch = value,
+            "pkgdesc" => record.description = value,
+            "url" => record.url = value,
+            "license" => record.license = value,
+            "origin" => record.origin = value,
+            "maintainer" => record.maintainer = value,
+            "commit" => record.commit = value,
+            "size" => record.installed_size = value.parse().unwrap_or(0),
+            "builddate" => record.build_time = value.parse().ok(),
+            "provider_priority" => record.provider_priority = value.parse().ok(),
+            "depend" => record.depends.push(value),
+            "provides" => record.provides.push(value),
+            "replaces" => record.replaces.push(value),
+            "install_if" => record
+                .install_if
+                .extend(value.split_whitespace().map(str::to_string)),
+            _ => {}
+        }
+    }
+    for (field, value) in [
+        ("pkgname", &record.name),
+        ("pkgver", &record.version),
+        ("arch", &record.arch),
+    ] {
+        if value.is_empty() {
+            return Err(format!(".PKGINFO missing {field}"));
+        }
+    }
+    if record.arch != pp.arch && record.arch != "noarch" {
+        return Err(format!(
+            "package arch {} does not match directory {}",
+            record.arch, pp.arch
+        ));
+    }
+    // apk fetches `{P}-{V}.apk` relative to the index — any other file name
+    // would be listed but never downloadable.
+    let expected = format!("{}-{}.apk", record.name, record.version);
+    if pp.file != expected {
+        return Err(format!("file name must be {expected}"));
+    }
+    Ok(record)
+}
+
+// ============================================================================
+// APKINDEX generation
+// ============================================================================
+
+fn generate_apkindex(pkgs: &[ApkRecord]) -> String {
+    let mut out = String::new();
+    for p in pkgs {
+        let mut field = |tag: char, value: &str| {
+            if !value.is_empty() {
+                out.push(tag);
+                out.push(':');
+                out.push_str(value);
+                out.push('\n');
+            }
+        };
+        field('C', &p.checksum);
+        field('P', &p.name);
+        field('V', &p.version);
+        field('A', &p.arch);
+        field('S', &p.size.to_string());
+        field('I', &p.installed_size.to_string());
+        field('T', &p.description);
+        field('U', &p.url);
+        field('L', &p.license);
+        field('o', &p.origin);
+        field('m', &p.maintainer);
+        field(
+            't',
+            &p.build_time.map(|t| t.to_string()).unwrap_or_default(),
+        );
+        field('c', &p.commit);
+        field(
+            'k',
+            &p.provider_priority
+                .map(|k| k.to_string())
+                .unwrap_or_default(),
+        );
+        field('D', &p.depends.join(" "));
+        field('p', &p.provides.join(" "));
+        field('i', &p.install_if.join(" "));
+        field('r', &p.replaces.join(" "));
+        out.push('\n');
+    }
+    out
+}
+
+fn gzip(data: &[u8]) -> Result<Vec<u8>, std::io::Error> {
+    let mut enc = GzEncoder::new(Vec::new(), flate2::Compression::best());
+    enc.write_all(data)?;
+    enc.finish()
+}
+
+/// Gzipped index tar (`DESCRIPTION` + `APKINDEX`). mtime 0 keeps the output
+/// deterministic: same package set → byte-identical (and identically signed) index.
+fn build_index_stream(description: &str, apkindex: &str) -> Result<Vec<u8>, std::io::Error> {
+    let mut builder = tar::Builder::new(Vec::new());
+    for (name, data) in [
+        ("DESCRIPTION", description.as_bytes()),
+        ("APKINDEX", apkindex.as_bytes()),
+    ] {
+        let mut header = tar::Header::new_ustar();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(0);
+        header.set_entry_type(tar::EntryType::Regular);
+        builder.append_data(&mut header, name, data)?;
+    }
+    gzip(&builder.into_inner()?)
+}
+
+/// Gzipped signature segment over `index_gz`, as `abuild-sign` builds it: a
+/// single `.SIGN.RSA256.{key_name}` entry with no end-of-archive blocks, so
+/// apk reads it and the index as one continuous tar stream.
+fn build_signature_stream(
+    signer: &crate::signing::ApkSigner,
+    index_gz: &[u8],
+) -> Result<Vec<u8>, String> {
+    let sig = signer.sign_sha256(index_gz)?;
+    let mut header = tar::Header::new_ustar();
+    header
+        .set_path(format!(".SIGN.RSA256.{}", signer.key_name()))
+        .map_err(|e| format!("signature entry name: {e}"))?;
+    header.set_size(sig.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(0);
+    header.set_entry_type(tar::EntryType::Regular);
+    header.set_cksum();
+    let mut segment = header.as_bytes().to_vec();
+    segment.extend_from_slice(&sig);
+    segment.resize(segment.len().div_ceil(512) * 512, 0);
+    gzip(&segment).map_err(|e| format!("gzip signature: {e}"))
+}
+
+/// Rebuild `{dir}/APKINDEX.tar.gz` of `repo` from metadata sidecars. Caller
+/// must hold the repo's publish lock. Fail-closed like the rpm/deb rebuilds:
+/// a repo with a signer never publishes an unsigned index — apk would reject
+/// it as UNTRUSTED, which is still better than serving it unverified.
+pub(crate) async fn regenerate_index(
+    storage: &crate::Storage,
+    signer: Option<&crate::signing::ApkSigner>,
+    repo: &str,
+    dir: &str,
+) -> Result<(), String> {
+    let meta_prefix = format!("apk/{repo}/{META_DIR}/{dir}/");
+    let mut pkgs: Vec<ApkRecord> = super::read_json_sidecars(storage, &meta_prefix).await?;
+    pkgs.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+
+    let description = format!("{repo} {dir}");
+    let index_gz = build_index_stream(&description, &generate_apkindex(&pkgs))
+        .map_err(|e| format!("build APKINDEX: {e}"))?;
+    let out = match signer {
+        Some(signer) => {
+            let mut out = build_signature_stream(signer, &index_gz)?;
+            out.extend_from_slice(&index_gz);
+            out
+        }
+        None => index_gz,
+    };
+    storage
+        .put(&index_key(repo, dir), &out)
+        .await
+        .map_err(|e| format!("write {dir}/{INDEX_FILE}: {e}"))
+}
+
+/// Rebuild every index directory of `repo` — each directory holding a
+/// sidecar or an existing index (so an emptied directory gets an empty
+/// index rather than a stale one). Used by reindex and retention.
+pub(crate) async fn regenerate_repo(
+    storage: &crate::Storage,
+    signer: Option<&crate::signing::ApkSigner>,
+    repo: &str,
+) -> Result<(), String> {
+    let prefix = format!("apk/{repo}/");
+    let keys = storage
+        .list(&prefix)
+        .await
+        .map_err(|e| format!("list {prefix}: {e}"))?;
+    let meta_prefix = format!("{prefix}{META_DIR}/");
+    let mut dirs = std::collections::BTreeSet::new();
+    for key in &keys {
+        let dir = if let Some(rest) = key.strip_prefix(&meta_prefix) {
+            rest.rsplit_once('/').map(|(dir, _)| dir)
+        } else {
+            key.strip_prefix(&prefix)
+                .and_then(|rest| rest.strip_suffix(&format!("/{INDEX_FILE}")))
+        };
+        if let Some(dir) = dir.filter(|d| d.split('/').count() == 3) {
+            dirs.insert(dir.to_string());
+        }
+    }
+    for dir in &dirs {
+        regenerate_index(storage, signer, repo, dir).await?;
+    }
+    Ok(())
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+async fn upload(
+    State(state): State<AppState>,
+    Path((repo, path)): Path<(String, String)>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    body: Bytes,
+) -> Response {
+    if !state.config.apk.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    if state.config.apk.proxies.contains_key(&repo) {
+        return proxied_repo_conflict();
+    }
+    let pp = match validate_package_path(&repo, &path) {
+        Ok(pp) => pp,
+        Err(msg) => return (StatusCode::BAD_REQUEST, msg).into_response(),
+    };
+    // Enforce OIDC namespace_scope on the repository name (#583).
+    if enforce_namespace_scope(&authority, &repo).is_err() {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if body.len() as u64 > state.config.apk.max_file_size {
+        return (
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "File too large. Max size: {} bytes",
+                state.config.apk.max_file_size
+            ),
+        )
+            .into_response();
+    }
+
+    let record = match read_control(&body).and_then(|(pkginfo, checksum)| {
+        extract_record(&pkginfo, checksum, &pp, body.len() as u64, now_secs())
+    }) {
+        Ok(r) => r,
+        Err(e) => {
+            return (StatusCode::BAD_REQUEST, format!("Not a valid apk: {e}")).into_response()
+        }
+    };
+    let sidecar = match serde_json::to_vec(&record) {
+        Ok(j) => j,
+        Err(e) => {
+            tracing::error!(error = %e, "apk: failed to serialize package record");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let key = package_key(&repo, &path);
+    if validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    // Serialize publishes/deletes per repo: the index rebuild is a
+    // list-read-generate-write cycle (same rationale as rpm repodata).
+    let lock = state.publish_lock(&lock_key(&repo));
+    let _guard = lock.lock().await;
+
+    if let Err(e) = state.storage.put(&key, &body).await {
+        tracing::error!(error = %e, key = %key, "apk: failed to store package");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    if let Err(e) = state
+        .storage
+        .put(&sidecar_key(&repo, &path), &sidecar)
+        .await
+    {
+        tracing::error!(error = %e, key = %key, "apk: failed to store metadata sidecar");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    if let Err(e) = regenerate_index(
+        &state.storage,
+        state.apk_signer.as_deref(),
+        &repo,
+        &pp.dir(),
+    )
+    .await
+    {
+        tracing::error!(repo = %repo, error = %e, "apk: APKINDEX regeneration failed");
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Package stored but APKINDEX regeneration failed",
+        )
+            .into_response();
+    }
+
+    let ident = format!("{}-{}", record.name, record.version);
+    state.metrics.record_upload("apk");
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &ident, "apk", ""));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        format!("{repo}/{ident}"),
+        crate::registry_type::RegistryType::Apk,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("apk");
+
+    StatusCode::CREATED.into_response()
+}
+
+async fn download(
+    State(state): State<AppState>,
+    headers: axum::http::HeaderMap,
+    Path((repo, path)): Path<(String, String)>,
+) -> Response {
+    if !state.config.apk.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    // Pull-through repo: every path proxies to the upstream mirror. Packages
+    // are immutable; APKINDEX files are TTL-bounded.
+    if let Some(entry) = state.config.apk.proxies.get(&repo) {
+        let key = package_key(&repo, &path);
+        if validate_storage_key(&key).is_err() || path.starts_with(META_DIR) {
+            return StatusCode::BAD_REQUEST.into_response();
+        }
+        let url = format!("{}/{}", entry.url().trim_end_matches('/'), path);
+        return crate::registry::repo_proxy_download(
+            &state,
+            "apk",
+            crate::registry_type::RegistryType::Apk,
+            format!("{repo}/{path}"),
+            key,
+            url,
+            entry.auth(),
+            state.config.apk.proxy_timeout,
+            state.config.apk.metadata_ttl,
+            path.ends_with(".apk"),
+            content_type(&path),
+        )
+        .await;
+    }
+    if let Some(name) = path.strip_prefix(&format!("{KEYS_DIR}/")) {
+        return match &state.apk_signer {
+            Some(signer) if name == signer.key_name() => (
+                StatusCode::OK,
+                [(header::CONTENT_TYPE, "application/x-pem-file")],
+                signer.public_key_pem().to_string(),
+            )
+                .into_response(),
+            _ => StatusCode::NOT_FOUND.into_response(),
+        };
+    }
+    let key = package_key(&repo, &path);
+    if validate_storage_key(&key).is_err() || path.starts_with(META_DIR) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    // Range applies to package payloads only — the index is rewritten on every
+    // publish, so a resumed range could splice two generations.
+    let is_package = path.ends_with(".apk");
+
+    // 206 / 416 straight from the backend's ranged read. A partial body cannot
+    // be rehashed, so this skips the pin check `get_verified` does below and
+    // relies on apk's own `C:` checksum — the docker #657 precedent.
+    if is_package {
+        if let Some(meta) = state.storage.stat(&key).await {
+            if let Some(response) = crate::registry::range::range_response(
+                &state.storage,
+                &[&key],
+                &headers,
+                meta.size,
+                content_type(&path),
+                &[],
+            )
+            .await
+            {
+                if response.status() == StatusCode::PARTIAL_CONTENT {
+                    state.metrics.record_download("apk");
+                }
+                return response;
+            }
+        }
+    }
+
+    match state.storage.get_verified(&key).await {
+        Ok(outcome) => {
+            state.metrics.record_download("apk");
+            state.activity.push(ActivityEntry::new(
+                ActionType::Pull,
+                format!("{repo}/{path}"),
+                crate::registry_type::RegistryType::Apk,
+                "LOCAL",
+            ));
+            use nora_registry::verified::{verified_body, GateOutcome};
+            let data = match outcome {
+                GateOutcome::Verified(blob) => verified_body(blob),
+                GateOutcome::Unpinned(blob) => blob.into_inner(),
+            };
+            let mut builder = axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .header(header::CONTENT_TYPE, content_type(&path));
+            if is_package {
+                builder = builder.header(header::ACCEPT_RANGES, "bytes");
+            }
+            // APKINDEX.tar.gz is rewritten in place on every publish — clients
+            // must revalidate it. Packages are immutable.
+            if path.ends_with(INDEX_FILE) {
+                builder = builder.header(header::CACHE_CONTROL, "no-cache");
+            }
+            builder
+                .body(axum::body::Body::from(data))
+                .expect("valid response")
+                .into_response()
+        }
+        Err(crate::storage::StorageError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, key = %key, "apk: failed to read artifact");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+async fn check_exists(
+    State(state): State<AppState>,
+    Path((repo, path)): Path<(String, String)>,
+) -> Response {
+    if !state.config.apk.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let key = package_key(&repo, &path);
+    if validate_storage_key(&key).is_err() || path.starts_with(META_DIR) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    match state.storage.stat(&key).await {
+        Some(meta) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_LENGTH, meta.size.to_string()),
+                (header::CONTENT_TYPE, content_type(&path).to_string()),
+            ],
+        )
+            .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn delete_package(
+    State(state): State<AppState>,
+    Path((repo, path)): Path<(String, String)>,
+    Extension(authority): Extension<NamespaceAuthority>,
+) -> Response {
+    if !state.config.apk.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    if state.config.apk.proxies.contains_key(&repo) {
+        return proxied_repo_conflict();
+    }
+    let pp = match validate_package_path(&repo, &path) {
+        Ok(pp) => pp,
+        Err(msg) => return (StatusCode::BAD_REQUEST, msg).into_response(),
+    };
+    if enforce_namespace_scope(&authority, &repo).is_err() {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let key = package_key(&repo, &path);
+    if validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    let lock = state.publish_lock(&lock_key(&repo));
+    let _guard = lock.lock().await;
+
+    match state.storage.delete(&key).await {
+        Ok(()) => {}
+        Err(crate::storage::StorageError::NotFound) => {
+            return StatusCode::NOT_FOUND.into_response()
+        }
+        Err(e) => {
+            tracing::error!(error = %e, key = %key, "apk: failed to delete package");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+    if let Err(e) = state.storage.delete(&sidecar_key(&repo, &path)).await {
+        tracing::warn!(error = %e, key = %key, "apk: failed to delete metadata sidecar");
+    }
+    if let Err(e) = regenerate_index(
+        &state.storage,
+        state.apk_signer.as_deref(),
+        &repo,
+        &pp.dir(),
+    )
+    .await
+    {
+        tracing::error!(repo = %repo, error = %e, "apk: APKINDEX regeneration failed");
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Package deleted but APKINDEX regeneration failed",
+        )
+            .into_response();
+    }
+
+    state
+        .audit
+        .log(AuditEntry::new("delete", "api", &path, "apk", ""));
+    state.repo_index.invalidate("apk");
+    StatusCode::NO_CONTENT.into_response()
+}
+
+/// Reconcile a repository with what is actually in storage, then rebuild
+/// (and re-sign) every index. Heals out-of-band changes the publish path
+/// never saw — stale sidecars of deleted packages are dropped, packages
+/// added directly to storage are parsed — and is the re-sign hook after a
+/// key change. Runs under the repo publish lock, like every rebuild.
+async fn reindex(
+    State(state): State<AppState>,
+    Path(repo): Path<String>,
+    Extension(authority): Extension<NamespaceAuthority>,
+) -> Response {
+    if !state.config.apk.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    if !valid_repo_name(&repo) {
+        return (StatusCode::BAD_REQUEST, "Invalid repository name").into_response();
+    }
+    if state.config.apk.proxies.contains_key(&repo) {
+        return proxied_repo_conflict();
+    }
+    if enforce_namespace_scope(&authority, &repo).is_err() {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+
+    let lock = state.publish_lock(&lock_key(&repo));
+    let _guard = lock.lock().await;
+
+    let prefix = format!("apk/{repo}/");
+    let keys = match state.storage.list(&prefix).await {
+        Ok(k) => k,
+        Err(e) => {
+            tracing::error!(error = %e, repo = %repo, "apk reindex: list failed");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    if keys.is_empty() {
+        return (StatusCode::NOT_FOUND, "No such repository").into_response();
+    }
+
+    let meta_prefix = format!("apk/{repo}/{META_DIR}/");
+    let mut packages: std::collections::BTreeSet<String> = std::collections::BTreeSet::new();
+    let mut sidecars: std::collections::BTreeSet<String> = std::collections::BTreeSet::new();
+    for key in &keys {
+        if let Some(rest) = key.strip_prefix(&meta_prefix) {
+            if let Some(pkg) = rest.strip_suffix(".json") {
+                sidecars.insert(pkg.to_string());
+            }
+        } else if let Some(rest) = key.strip_prefix(&prefix) {
+            if rest.ends_with(".apk") {
+                packages.insert(rest.to_string());
+            }
+        }
+    }
+
+    // Drop sidecars whose package is gone (deleted out-of-band).
+    let mut orphans_removed = 0usize;
+    for stale in sidecars.difference(&packages) {
+        match state.storage.delete(&sidecar_key(&repo, stale)).await {
+            Ok(()) => orphans_removed += 1,
+            Err(e) => {
+                tracing::error!(error = %e, repo = %repo, pkg = %stale, "apk reindex: orphan sidecar delete failed");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        }
+    }
+
+    // Parse packages that have no sidecar (added out-of-band).
+    let mut sidecars_created = 0usize;
+    for missing in packages.difference(&sidecars) {
+        let pp = match validate_package_path(&repo, missing) {
+            Ok(pp) => pp,
+            Err(msg) => {
+                return (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    format!("{missing}: {msg}"),
+                )
+                    .into_response()
+            }
+        };
+        let body = match state.storage.get(&package_key(&repo, missing)).await {
+            Ok(b) => b,
+            Err(e) => {
+                tracing::error!(error = %e, repo = %repo, pkg = %missing, "apk reindex: package read failed");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        };
+        let record = match read_control(&body).and_then(|(pkginfo, checksum)| {
+            extract_record(&pkginfo, checksum, &pp, body.len() as u64, now_secs())
+        }) {
+            Ok(r) => r,
+            Err(e) => {
+                tracing::error!(error = %e, repo = %repo, pkg = %missing, "apk reindex: not a valid apk — refusing to index");
+                return (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    format!("{missing} is not a valid apk: {e}"),
+                )
+                    .into_response();
+            }
+        };
+        let json = match serde_json::to_vec(&record) {
+            Ok(j) => j,
+            Err(e) => {
+                tracing::error!(error = %e, "apk reindex: sidecar serialize failed");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        };
+        if let Err(e) = state.storage.put(&sidecar_key(&repo, missing), &json).await {
+            tracing::error!(error = %e, repo = %repo, pkg = %missing, "apk reindex: sidecar write failed");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+        sidecars_created += 1;
+    }
+
+    if let Err(e) = regenerate_repo(&state.storage, state.apk_signer.as_deref(), &repo).await {
+        tracing::error!(repo = %repo, error = %e, "apk reindex: APKINDEX regeneration failed");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    state
+        .audit
+        .log(AuditEntry::new("reindex", "api", &repo, "apk", ""));
+    state.repo_index.invalidate("apk");
+
+    (
+        StatusCode::OK,
+        axum::Json(serde_json::json!({
+            "packages": packages.len(),
+            "sidecars_created": sidecars_created,
+            "orphans_removed": orphans_removed,
+            "signed": state.apk_signer.is_some(),
+        })),
+    )
+        .into_response()
+}
+
+fn content_type(path: &str) -> &'static str {
+    if path.ends_with(".apk") {
+        "application/vnd.apk"
+    } else if path.ends_with(".tar.gz") {
+        "application/gzip"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_helpers::{
+        body_bytes, create_test_context, create_test_context_with_config, send,
+    };
+    use axum::http::Method;
+
+    /// Gzipped tar segment; `cut` drops the end-of-archive blocks the way
+    /// `abuild-tar --cut` does for the signature and control segments.
+    fn tar_gz(files: &[(&str, &[u8])], cut: bool) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (name, data) in files {
+            let mut header = tar::Header::new_ustar();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            builder.append_data(&mut header, name, *data).unwrap();
+        }
+        let mut tar = builder.into_inner().unwrap();
+        if cut {
+            tar.truncate(tar.len() - 1024);
+        }
+        gzip(&tar).unwrap()
+    }
+
+    /// Build an apk v2 package: optional signature segment, control segment
+    /// with `.PKGINFO`, data segment. Returns the package and its control member.
+    pub(crate) fn build_test_apk(name: &str, version: &str, arch: &str) -> (Vec<u8>, Vec<u8>) {
+        let pkginfo = format!(
+            "# Generated by abuild\npkgname = {name}\npkgver = {version}\npkgdesc = Test package\n\
+             url = https://example.com\nbuilddate = 1700000000\nsize = 4096\narch = {arch}\n\
+             origin = {name}\nlicense = MIT\ndepend = musl\ndepend = so:libc.musl-x86_64.so.1\n\
+             provides = cmd:{name}=1\n"
+        );
+        let control = tar_gz(&[(".PKGINFO", pkginfo.as_bytes())], true);
+        let data = tar_gz(&[("usr/bin/hello", b"#!/bin/sh\necho hi\n")], false);
+        let sig = tar_gz(
+            &[(".SIGN.RSA.builder.rsa.pub", b"not-a-real-signature")],
+            true,
+        );
+        let mut apk = sig;
+        apk.extend_from_slice(&control);
+        apk.extend_from_slice(&data);
+        (apk, control)
+    }
+
+    fn path(name: &str) -> PackagePath {
+        validate_package_path("r", &format!("v3.20/main/x86_64/{name}")).unwrap()
+    }
+
+    #[test]
+    fn test_validate_package_path() {
+        assert!(validate_package_path("r", "v3.20/main/x86_64/a-1.0-r0.apk").is_ok());
+        for bad in [
+            "main/x86_64/a-1.0-r0.apk",
+            "v3.20/main/x86_64/a-1.0-r0.rpm",
+            "v3.20/main/x86_64/sub/a-1.0-r0.apk",
+            "v3.20/.nora-meta/x86_64/a-1.0-r0.apk",
+            "v3.20/main/../a-1.0-r0.apk",
+            "v3.20/main/x86 64/a-1.0-r0.apk",
+            "keys/main/x86_64/a-1.0-r0.apk",
+        ] {
+            assert!(validate_package_path("r", bad).is_err(), "{bad}");
+        }
+        assert!(validate_package_path(".hidden", "v3.20/main/x86_64/a-1.0-r0.apk").is_err());
+    }
+
+    #[test]
+    fn test_read_control_skips_signature_and_checksums_control() {
+        let (apk, control) = build_test_apk("hello", "1.0-r0", "x86_64");
+        let (pkginfo, checksum) = read_control(&apk).unwrap();
+        assert!(pkginfo.contains("pkgname = hello"));
+        let expected = format!(
+            "Q1{}",
+            base64::engine::general_purpose::STANDARD.encode(sha1::Sha1::digest(&control))
+        );
+        assert_eq!(checksum, expected);
+        assert!(read_control(b"not gzip").is_err());
+    }
+
+    #[test]
+    fn test_extract_record_enforces_name_and_arch() {
+        let (apk, _) = build_test_apk("hello", "1.0-r0", "x86_64");
+        let (pkginfo, checksum) = read_control(&apk).unwrap();
+        let rec =
+            extract_record(&pkginfo, checksum.clone(), &path("hello-1.0-r0.apk"), 10, 0).unwrap();
+        assert_eq!(rec.depends, vec!["musl", "so:libc.musl-x86_64.so.1"]);
+        assert_eq!(rec.installed_size, 4096);
+        assert_eq!(rec.href, "v3.20/main/x86_64/hello-1.0-r0.apk");
+        assert!(
+            extract_record(&pkginfo, checksum.clone(), &path("other-1.0-r0.apk"), 10, 0).is_err()
+        );
+        let pp = validate_package_path("r", "v3.20/main/aarch64/hello-1.0-r0.apk").unwrap();
+        assert!(extract_record(&pkginfo, checksum, &pp, 10, 0).is_err());
+    }
+
+    #[test]
+    fn test_apkindex_format() {
+        let (apk, _) = build_test_apk("hello", "1.0-r0", "x86_64");
+        let (pkginfo, checksum) = read_control(&apk).unwrap();
+        let rec = extract_record(&pkginfo, checksum, &path("hello-1.0-r0.apk"), 10, 0).unwrap();
+        let index = generate_apkindex(&[rec]);
+        assert!(index.starts_with("C:Q1"), "{index}");
+        assert!(index.contains("\nP:hello\nV:1.0-r0\nA:x86_64\nS:10\nI:4096\n"));
+        assert!(index.contains("\nt:1700000000\n"));
+        assert!(index.contains("\nD:musl so:libc.musl-x86_64.so.1\n"));
+        assert!(index.contains("\np:cmd:hello=1\n"));
+        assert!(index.ends_with("\n\n"));
+    }
+
+    /// Split a served APKINDEX.tar.gz into (signature entry name, signature,
+    /// raw index member, APKINDEX text).
+    fn split_index(data: &[u8]) -> (Option<(String, Vec<u8>)>, Vec<u8>, String) {
+        let members = gzip_members(data, 2).unwrap();
+        let mut sig = None;
+        let mut index = None;
+        for (raw, content) in members {
+            let mut archive = tar::Archive::new(&content[..]);
+            for entry in archive.entries().unwrap() {
+                let mut entry = entry.unwrap();
+                let name = entry.path().unwrap().to_string_lossy().into_owned();
+                let mut bytes = Vec::new();
+                entry.read_to_end(&mut bytes).unwrap();
+                if name.starts_with(".SIGN.") {
+                    sig = Some((name, bytes));
+                } else if name == "APKINDEX" {
+                    index = Some((raw.to_vec(), String::from_utf8(bytes).unwrap()));
+                }
+            }
+        }
+        let (raw, text) = index.unwrap();
+        (sig, raw, text)
+    }
+
+    #[tokio::test]
+    async fn test_apk_upload_generates_signed_index() {
+        let ctx = create_test_context();
+        let (apk, _) = build_test_apk("hello", "1.0-r0", "x86_64");
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/apk/myrepo/v3.20/main/x86_64/hello-1.0-r0.apk",
+            apk.clone(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/apk/myrepo/v3.20/main/x86_64/APKINDEX.tar.gz",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers()
+                .get("cache-control")
+                .unwrap()
+                .to_str()
+                .unwrap(),
+            "no-cache"
+        );
+        let (sig, index_raw, text) = split_index(&body_bytes(resp).await);
+        assert!(text.contains("P:hello\nV:1.0-r0\n"), "{text}");
+        assert!(text.contains(&format!("S:{}\n", apk.len())));
+
+        let (name, sig) = sig.expect("index must be signed");
+        assert_eq!(name, ".SIGN.RSA256.nora.rsa.pub");
+        let resp = send(&ctx.app, Method::GET, "/apk/myrepo/keys/nora.rsa.pub", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let pem = String::from_utf8(body_bytes(resp).await.to_vec()).unwrap();
+        use rsa::pkcs8::DecodePublicKey;
+        let key = rsa::RsaPublicKey::from_public_key_pem(&pem).unwrap();
+        key.verify(
+            rsa::Pkcs1v15Sign {
+                hash_len: Some(32),
+                prefix: Box::new(crate::signing::SHA256_DIGEST_INFO),
+            },
+            &<sha2::Sha256 as sha2::Digest>::digest(&index_raw),
+            &sig,
+        )
+        .expect("APKINDEX signature must verify against the served key");
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/apk/myrepo/v3.20/main/x86_64/hello-1.0-r0.apk",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], &apk[..]);
+
+        let resp = send(&ctx.app, Method::GET, "/apk/myrepo/keys/other.rsa.pub", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_apk_unsigned_index_when_signing_disabled() {
+        let ctx = create_test_context_with_config(|cfg| cfg.signing.enabled = false);
+        let (apk, _) = build_test_apk("hello", "1.0-r0", "noarch");
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/apk/myrepo/v3.20/main/aarch64/hello-1.0-r0.apk",
+            apk,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/apk/myrepo/v3.20/main/aarch64/APKINDEX.tar.gz",
+            "",
+        )
+        .await;
+        let (sig, _, text) = split_index(&body_bytes(resp).await);
+        assert!(sig.is_none());
+        assert!(text.contains("A:noarch\n"));
+    }
+
+    #[tokio::test]
+    async fn test_apk_upload_rejects_invalid() {
+        let ctx = create_test_context();
+        let (apk, _) = build_test_apk("hello", "1.0-r0", "x86_64");
+        for (path, body) in [
+            (
+                "/apk/myrepo/v3.20/main/x86_64/wrong-1.0-r0.apk",
+                apk.clone(),
+            ),
+            (
+                "/apk/myrepo/v3.20/main/aarch64/hello-1.0-r0.apk",
+                apk.clone(),
+            ),
+            ("/apk/myrepo/main/x86_64/hello-1.0-r0.apk", apk.clone()),
+            (
+                "/apk/myrepo/v3.20/main/x86_64/hello-1.0-r0.apk",
+                b"junk".to_vec(),
+            ),
+        ] {
+            let resp = send(&ctx.app, Method::PUT, path, body).await;
+            assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{path}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apk_delete_drops_package_from_index() {
+        let ctx = create_test_context();
+        for (name, version) in [("hello", "1.0-r0"), ("world", "2.0-r1")] {
+            let (apk, _) = build_test_apk(name, version, "x86_64");
+            let resp = send(
+                &ctx.app,
+                Method::PUT,
+                &format!("/apk/myrepo/v3.20/main/x86_64/{name}-{version}.apk"),
+                apk,
+            )
+            .await;
+            assert_eq!(resp.status(), StatusCode::CREATED);
+        }
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/apk/myrepo/v3.20/main/x86_64/hello-1.0-r0.apk",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/apk/myrepo/v3.20/main/x86_64/APKINDEX.tar.gz",
+            "",
+        )
+        .await;
+        let (_, _, text) = split_index(&body_bytes(resp).await);
+        assert!(!text.contains("P:hello\n"), "{text}");
+        assert!(text.contains("P:world\n"));
+
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/apk/myrepo/v3.20/main/x86_64/hello-1.0-r0.apk",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_apk_reindex_heals_out_of_band_changes() {
+        let ctx = create_test_context();
+        let (apk, _) = build_test_apk("hello", "1.0-r0", "x86_64");
+        send(
+            &ctx.app,
+            Method::PUT,
+            "/apk/myrepo/v3.20/main/x86_64/hello-1.0-r0.apk",
+            apk,
+        )
+        .await;
+        // One package removed and one added behind NORA's back.
+        ctx.state
+            .storage
+            .delete("apk/myrepo/v3.20/main/x86_64/hello-1.0-r0.apk")
+            .await
+            .unwrap();
+        let (apk, _) = build_test_apk("world", "2.0-r0", "x86_64");
+        ctx.state
+            .storage
+            .put("apk/myrepo/v3.20/community/x86_64/world-2.0-r0.apk", &apk)
+            .await
+            .unwrap();
+
+        let resp = send(&ctx.app, Method::POST, "/apk/myrepo/-/reindex", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["packages"], 1);
+        assert_eq!(json["sidecars_created"], 1);
+        assert_eq!(json["orphans_removed"], 1);
+        assert_eq!(json["signed"], true);
+
+        for (dir, present, absent) in [
+            ("main", None, "P:hello\n"),
+            ("community", Some("P:world\n"), "P:hello\n"),
+        ] {
+            let resp = send(
+                &ctx.app,
+                Method::GET,
+                &format!("/apk/myrepo/v3.20/{dir}/x86_64/APKINDEX.tar.gz"),
+                "",
+            )
+            .await;
+            assert_eq!(resp.status(), StatusCode::OK, "{dir}");
+            let (_, _, text) = split_index(&body_bytes(resp).await);
+            assert!(!text.contains(absent), "{dir}: {text}");
+            if let Some(p) = present {
+                assert!(text.contains(p), "{dir}: {text}");
+            }
+        }
+
+        let resp = send(&ctx.app, Method::POST, "/apk/missing/-/reindex", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod proxy_tests {
+    use crate::config::registry::RepoProxyEntry;
+    use crate::test_helpers::{body_bytes, create_test_context_with_config, send};
+    use axum::http::{Method, StatusCode};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_apk_proxy_passes_through_and_is_read_only() {
+        let upstream = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v3.20/main/x86_64/APKINDEX.tar.gz"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"index".to_vec()))
+            .mount(&upstream)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v3.20/main/x86_64/musl-1.2.5-r0.apk"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"apkbytes".to_vec()))
+            .mount(&upstream)
+            .await;
+
+        let uri = upstream.uri();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.apk
+                .proxies
+                .insert("alpine".to_string(), RepoProxyEntry::Simple(uri));
+        });
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/apk/alpine/v3.20/main/x86_64/APKINDEX.tar.gz",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], b"index");
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/apk/alpine/v3.20/main/x86_64/musl-1.2.5-r0.apk",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], b"apkbytes");
+
+        let (apk, _) = super::tests::build_test_apk("musl", "1.2.5-r0", "x86_64");
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/apk/alpine/v3.20/main/x86_64/musl-1.2.5-r0.apk",
+            apk,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let resp = send(&ctx.app, Method::POST, "/apk/alpine/-/reindex", "").await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+}
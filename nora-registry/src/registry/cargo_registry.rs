@@ -9,10 +9,16 @@
 //!   GET  /cargo/api/v1/crates/{crate_name}         — crate metadata (proxy)
 //!   GET  /cargo/api/v1/crates/{name}/{ver}/download — download .crate
 //!   PUT  /cargo/api/v1/crates/new                  — cargo publish
+//!   DELETE /cargo/api/v1/crates/{name}/{ver}/yank  — cargo yank
+//!   PUT  /cargo/api/v1/crates/{name}/{ver}/unyank  — cargo yank --undo
+//!   GET/PUT/DELETE /cargo/api/v1/crates/{name}/owners — cargo owner
+//!   GET  /cargo/api/v1/crates?q=                   — cargo search
 
 use crate::activity_log::{ActionType, ActivityEntry};
 use crate::audit::AuditEntry;
-use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
+use crate::auth::{
+    enforce_namespace_scope, AuthenticatedRole, AuthenticatedUser, NamespaceAuthority,
+};
 use crate::registry::{
     circuit_open_response, method_not_allowed, nora_base_url, proxy_fetch, ProxyError,
 };
@@ -20,16 +26,18 @@ use crate::registry_type::RegistryType;
 use crate::secrets::expose_opt;
 use crate::storage::Storage;
 use crate::validation::validate_storage_key;
+use crate::version::cmp_semver;
 use crate::AppState;
 use axum::{
     body::Bytes,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::{header, HeaderMap, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
-    routing::{get, put},
+    routing::{delete, get, put},
     Extension, Router,
 };
 use sha2::Digest;
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// Resolve the effective quarantine mode and TTL for the cargo registry,
@@ -58,7 +66,23 @@ pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/cargo/index/config.json", get(index_config))
         .route("/cargo/index/{*path}", get(sparse_index))
+        .route("/cargo/api/v1/crates", get(search))
         .route("/cargo/api/v1/crates/{crate_name}", get(get_metadata))
+        .route(
+            "/cargo/api/v1/crates/{crate_name}/owners",
+            get(list_owners)
+                .put(add_owners)
+                .delete(remove_owners)
+                .fallback(|| async { method_not_allowed("GET, PUT, DELETE") }),
+        )
+        .route(
+            "/cargo/api/v1/crates/{crate_name}/{version}/yank",
+            delete(yank).fallback(|| async { method_not_allowed("DELETE") }),
+        )
+        .route(
+            "/cargo/api/v1/crates/{crate_name}/{version}/unyank",
+            put(unyank).fallback(|| async { method_not_allowed("PUT") }),
+        )
         .route(
             "/cargo/api/v1/crates/{crate_name}/{version}/download",
             get(download),
@@ -387,6 +411,9 @@ async fn download(
     }
     let crate_name = crate_name.to_lowercase();
 
+    // The license filter reads the same metadata, so it primes it too.
+    let license_active = crate::curation::license_policy_active(&state.curation().curation_engine);
+
     // Extract publish date from cached Cargo metadata
     let publish_date = {
         let meta_key = format!("cargo/{}/metadata.json", crate_name);
@@ -397,7 +424,7 @@ async fn download(
         // the metadata here (synchronously, so the date is readable on this same
         // request) — gated on trust, namespace-safe, best-effort. Mirrors PyPI's
         // ensure_pypi_dates_cached.
-        if state.config.server.trust_upstream_dates {
+        if state.config.server.trust_upstream_dates || license_active {
             ensure_cargo_metadata_cached(&state, &crate_name).await;
         }
         extract_cargo_publish_date(
@@ -418,7 +445,13 @@ async fn download(
         &crate_name,
     );
     if !internal {
-        if let Some(response) = crate::curation::check_download(
+        let license = if license_active {
+            let meta_key = format!("cargo/{}/metadata.json", crate_name);
+            extract_cargo_license(&state.storage, &meta_key, &version).await
+        } else {
+            None
+        };
+        if let Some(response) = crate::curation::check_download_with_license(
             &state.curation().curation_engine,
             state.bypass_token().as_deref(),
             &headers,
@@ -426,6 +459,7 @@ async fn download(
             &crate_name,
             Some(&version),
             publish_date,
+            license.as_deref(),
         ) {
             return response;
         }
@@ -619,6 +653,8 @@ async fn download(
 async fn publish(
     State(state): State<AppState>,
     Extension(authority): Extension<NamespaceAuthority>,
+    Extension(user): Extension<AuthenticatedUser>,
+    role: Option<Extension<AuthenticatedRole>>,
     body: Bytes,
 ) -> Response {
     if body.len() < 8 {
@@ -696,20 +732,25 @@ async fn publish(
     let lock = state.publish_lock(&index_lock_key);
     let _guard = lock.lock().await;
 
+    // Once a crate has owners, only they (and admins) publish new versions.
+    let role = role.map(|Extension(role)| role);
+    if !may_manage(
+        &read_owners(&state.storage, &name).await,
+        &user,
+        role.as_ref(),
+    ) {
+        return cargo_error(
+            StatusCode::FORBIDDEN,
+            format!("must already be an owner of `{}` to publish", name),
+        );
+    }
+
     // Check version immutability
     if state.storage.stat(&crate_key).await.is_some() {
-        let err = serde_json::json!({
-            "errors": [{"detail": format!("crate version `{}@{}` already exists", name, vers)}]
-        });
-        return (
+        return cargo_error(
             StatusCode::CONFLICT,
-            [(
-                header::CONTENT_TYPE,
-                HeaderValue::from_static("application/json"),
-            )],
-            serde_json::to_vec(&err).unwrap_or_default(),
-        )
-            .into_response();
+            format!("crate version `{}@{}` already exists", name, vers),
+        );
     }
 
     // Compute checksum
@@ -780,7 +821,8 @@ async fn publish(
 
     // Write this version's index line as its OWN immutable key. Concurrent publishes of DIFFERENT
     // versions write distinct keys, so none is lost — the #39 fix (vs the old read-modify-write of
-    // the shared index file under a process-local lock).
+    // the shared index file under a process-local lock). Only `yank` rewrites it afterwards, and
+    // only its `yanked` flag.
     let entry_key = format!("cargo/index-entries/{}/{}/{}.json", prefix, name, vers);
     if state
         .storage
@@ -834,6 +876,570 @@ async fn publish(
         .into_response()
 }
 
+// ============================================================================
+// Yank, owners & search (`cargo yank`, `cargo owner`, `cargo search`)
+// ============================================================================
+
+/// Page size `cargo search` gets when it sends no `per_page` (crates.io's default).
+const SEARCH_DEFAULT_PER_PAGE: usize = 10;
+/// crates.io rejects larger pages; NORA clamps instead.
+const SEARCH_MAX_PER_PAGE: usize = 100;
+
+/// Error body in the shape cargo prints: `{"errors":[{"detail":"..."}]}`.
+fn cargo_error(status: StatusCode, detail: impl Into<String>) -> Response {
+    let err = serde_json::json!({ "errors": [{ "detail": detail.into() }] });
+    (
+        status,
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        )],
+        serde_json::to_vec(&err).unwrap_or_default(),
+    )
+        .into_response()
+}
+
+fn cargo_ok(body: serde_json::Value) -> Response {
+    (
+        StatusCode::OK,
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        )],
+        serde_json::to_vec(&body).unwrap_or_default(),
+    )
+        .into_response()
+}
+
+/// Storage key of a crate's owner list. Beside the cached `metadata.json` rather
+/// than under `cargo/index*`, which GC and retention read as index data.
+fn owners_key(name: &str) -> String {
+    format!("cargo/{}/owners.json", name)
+}
+
+/// Logins recorded by `cargo owner --add`. Empty until someone adds one: a crate
+/// without owners stays open to every writer, as it was before owners existed.
+async fn read_owners(storage: &Storage, name: &str) -> Vec<String> {
+    storage
+        .get(&owners_key(name))
+        .await
+        .ok()
+        .and_then(|data| serde_json::from_slice::<serde_json::Value>(&data).ok())
+        .and_then(|doc| {
+            doc["users"].as_array().map(|users| {
+                users
+                    .iter()
+                    .filter_map(|u| u.as_str().map(str::to_string))
+                    .collect()
+            })
+        })
+        .unwrap_or_default()
+}
+
+/// Whether the caller may publish, yank or change owners of a crate: anyone
+/// with write access while it has no owners, afterwards only its owners and
+/// admins.
+fn may_manage(
+    owners: &[String],
+    user: &AuthenticatedUser,
+    role: Option<&AuthenticatedRole>,
+) -> bool {
+    owners.is_empty() || owners.contains(&user.0) || role.is_some_and(|r| r.0.can_admin())
+}
+
+/// Whether `login` names a NORA user: an htpasswd account, the owner of an API
+/// token, or the caller themselves (an OIDC subject cannot be enumerated).
+fn is_known_user(state: &AppState, login: &str, caller: &AuthenticatedUser) -> bool {
+    (login == caller.0 && caller.0 != "anonymous")
+        || state
+            .auth
+            .as_ref()
+            .is_some_and(|auth| auth.list_users().contains(&login))
+        || state
+            .tokens
+            .as_ref()
+            .is_some_and(|tokens| tokens.list_all_tokens().iter().any(|t| t.user == login))
+}
+
+/// Whether the crate was published to NORA rather than cached from upstream.
+/// Hosted crates have per-version index entries (#39); without an upstream, an
+/// index still in the old single-file layout can only be hosted too.
+async fn is_hosted_crate(state: &AppState, prefix: &str, name: &str) -> bool {
+    let entries_prefix = format!("cargo/index-entries/{}/{}/", prefix, name);
+    if !state
+        .storage
+        .list(&entries_prefix)
+        .await
+        .unwrap_or_default()
+        .is_empty()
+    {
+        return true;
+    }
+    state.config.cargo.proxy.is_none()
+        && state
+            .storage
+            .stat(&format!("cargo/index/{}/{}", prefix, name))
+            .await
+            .is_some()
+}
+
+/// DELETE /cargo/api/v1/crates/{name}/{version}/yank — `cargo yank`.
+async fn yank(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Extension(user): Extension<AuthenticatedUser>,
+    role: Option<Extension<AuthenticatedRole>>,
+    Path((crate_name, version)): Path<(String, String)>,
+) -> Response {
+    let role = role.map(|Extension(role)| role);
+    set_yanked(
+        &state,
+        &authority,
+        &user,
+        role.as_ref(),
+        &crate_name,
+        &version,
+        true,
+    )
+    .await
+}
+
+/// PUT /cargo/api/v1/crates/{name}/{version}/unyank — `cargo yank --undo`.
+async fn unyank(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Extension(user): Extension<AuthenticatedUser>,
+    role: Option<Extension<AuthenticatedRole>>,
+    Path((crate_name, version)): Path<(String, String)>,
+) -> Response {
+    let role = role.map(|Extension(role)| role);
+    set_yanked(
+        &state,
+        &authority,
+        &user,
+        role.as_ref(),
+        &crate_name,
+        &version,
+        false,
+    )
+    .await
+}
+
+/// Flip `yanked` on a hosted version's index entry and regenerate the sparse
+/// index, under the same per-crate lock as publish. The `.crate` stays: a
+/// yanked version still downloads for existing lockfiles, cargo just stops
+/// resolving new requirements to it. Idempotent.
+async fn set_yanked(
+    state: &AppState,
+    authority: &NamespaceAuthority,
+    user: &AuthenticatedUser,
+    role: Option<&AuthenticatedRole>,
+    crate_name: &str,
+    version: &str,
+    yanked: bool,
+) -> Response {
+    if !is_valid_crate_name(crate_name) {
+        return cargo_error(StatusCode::BAD_REQUEST, "invalid crate name");
+    }
+    if validate_storage_key(version).is_err() {
+        return cargo_error(StatusCode::BAD_REQUEST, "invalid version");
+    }
+    let name = crate_name.to_lowercase();
+    if enforce_namespace_scope(authority, &name).is_err() {
+        return cargo_error(StatusCode::FORBIDDEN, "outside namespace scope");
+    }
+
+    let prefix = crate_index_prefix(&name);
+    let lock = state.publish_lock(&format!("cargo/index/{}/{}", prefix, name));
+    let _guard = lock.lock().await;
+
+    if !may_manage(&read_owners(&state.storage, &name).await, user, role) {
+        return cargo_error(
+            StatusCode::FORBIDDEN,
+            format!("must already be an owner of `{}` to yank or unyank", name),
+        );
+    }
+    if state.config.cargo.proxy.is_none() {
+        migrate_cargo_index(state, &prefix, &name).await;
+    }
+
+    let entry_key = format!("cargo/index-entries/{}/{}/{}.json", prefix, name, version);
+    let Some(mut entry) = state
+        .storage
+        .get(&entry_key)
+        .await
+        .ok()
+        .and_then(|data| serde_json::from_slice::<serde_json::Value>(&data).ok())
+    else {
+        return cargo_error(
+            StatusCode::NOT_FOUND,
+            format!(
+                "crate `{}` does not have a hosted version `{}`",
+                name, version
+            ),
+        );
+    };
+
+    if entry["yanked"].as_bool() != Some(yanked) {
+        entry["yanked"] = serde_json::Value::Bool(yanked);
+        let line = serde_json::to_string(&entry).unwrap_or_default();
+        if state
+            .storage
+            .put(&entry_key, line.as_bytes())
+            .await
+            .is_err()
+            || regenerate_cargo_index(&state.storage, &prefix, &name)
+                .await
+                .is_err()
+        {
+            tracing::error!(crate_name = %name, version, "cargo yank: failed to rewrite sparse index");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+        let action = if yanked { "yank" } else { "unyank" };
+        state.audit.log(AuditEntry::new(
+            action,
+            "api",
+            &format!("{}@{}", name, version),
+            "cargo",
+            "",
+        ));
+    }
+
+    cargo_ok(serde_json::json!({ "ok": true }))
+}
+
+/// GET /cargo/api/v1/crates/{name}/owners — `cargo owner --list`.
+async fn list_owners(State(state): State<AppState>, Path(crate_name): Path<String>) -> Response {
+    if !is_valid_crate_name(&crate_name) {
+        return cargo_error(StatusCode::BAD_REQUEST, "invalid crate name");
+    }
+    let name = crate_name.to_lowercase();
+    if !is_hosted_crate(&state, &crate_index_prefix(&name), &name).await {
+        return cargo_error(
+            StatusCode::NOT_FOUND,
+            format!("crate `{}` is not hosted here", name),
+        );
+    }
+    cargo_ok(owners_response(&read_owners(&state.storage, &name).await))
+}
+
+/// crates.io's owner list shape; `id` is only the position, NORA users have no
+/// numeric id.
+fn owners_response(owners: &[String]) -> serde_json::Value {
+    let users: Vec<serde_json::Value> = owners
+        .iter()
+        .enumerate()
+        .map(|(i, login)| serde_json::json!({ "id": i + 1, "login": login, "name": login }))
+        .collect();
+    serde_json::json!({ "users": users })
+}
+
+/// PUT /cargo/api/v1/crates/{name}/owners — `cargo owner --add`.
+async fn add_owners(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Extension(user): Extension<AuthenticatedUser>,
+    role: Option<Extension<AuthenticatedRole>>,
+    Path(crate_name): Path<String>,
+    body: Bytes,
+) -> Response {
+    let role = role.map(|Extension(role)| role);
+    change_owners(
+        &state,
+        &authority,
+        &user,
+        role.as_ref(),
+        &crate_name,
+        &body,
+        true,
+    )
+    .await
+}
+
+/// DELETE /cargo/api/v1/crates/{name}/owners — `cargo owner --remove`.
+async fn remove_owners(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Extension(user): Extension<AuthenticatedUser>,
+    role: Option<Extension<AuthenticatedRole>>,
+    Path(crate_name): Path<String>,
+    body: Bytes,
+) -> Response {
+    let role = role.map(|Extension(role)| role);
+    change_owners(
+        &state,
+        &authority,
+        &user,
+        role.as_ref(),
+        &crate_name,
+        &body,
+        false,
+    )
+    .await
+}
+
+/// Add or remove owners of a hosted crate. Owners are NORA users; unlike
+/// crates.io there is no invitation step, an added login is an owner at once.
+/// The last owner cannot be removed.
+async fn change_owners(
+    state: &AppState,
+    authority: &NamespaceAuthority,
+    user: &AuthenticatedUser,
+    role: Option<&AuthenticatedRole>,
+    crate_name: &str,
+    body: &[u8],
+    add: bool,
+) -> Response {
+    if !is_valid_crate_name(crate_name) {
+        return cargo_error(StatusCode::BAD_REQUEST, "invalid crate name");
+    }
+    let name = crate_name.to_lowercase();
+    if enforce_namespace_scope(authority, &name).is_err() {
+        return cargo_error(StatusCode::FORBIDDEN, "outside namespace scope");
+    }
+    let logins: Vec<String> = match serde_json::from_slice::<serde_json::Value>(body)
+        .ok()
+        .and_then(|doc| {
+            doc["users"].as_array().map(|users| {
+                users
+                    .iter()
+                    .map(|u| u.as_str().map(str::to_string))
+                    .collect::<Option<Vec<_>>>()
+            })
+        }) {
+        Some(Some(logins)) if !logins.is_empty() => logins,
+        _ => {
+            return cargo_error(
+                StatusCode::BAD_REQUEST,
+                "expected a JSON body {\"users\": [\"login\", ...]}",
+            )
+        }
+    };
+
+    let prefix = crate_index_prefix(&name);
+    let lock = state.publish_lock(&format!("cargo/index/{}/{}", prefix, name));
+    let _guard = lock.lock().await;
+
+    if !is_hosted_crate(state, &prefix, &name).await {
+        return cargo_error(
+            StatusCode::NOT_FOUND,
+            format!("crate `{}` is not hosted here", name),
+        );
+    }
+    let mut owners = read_owners(&state.storage, &name).await;
+    if !may_manage(&owners, user, role) {
+        return cargo_error(
+            StatusCode::FORBIDDEN,
+            format!("only owners of `{}` can change its owners", name),
+        );
+    }
+
+    let msg = if add {
+        if let Some(unknown) = logins.iter().find(|l| !is_known_user(state, l, user)) {
+            return cargo_error(
+                StatusCode::BAD_REQUEST,
+                format!("could not find user with login `{}`", unknown),
+            );
+        }
+        for login in &logins {
+            if !owners.contains(login) {
+                owners.push(login.clone());
+            }
+        }
+        format!("{} added as owner(s) of `{}`", logins.join(", "), name)
+    } else {
+        owners.retain(|o| !logins.contains(o));
+        if owners.is_empty() {
+            return cargo_error(
+                StatusCode::BAD_REQUEST,
+                "cannot remove all individual owners of a crate",
+            );
+        }
+        "owners successfully removed".to_string()
+    };
+
+    let doc = serde_json::json!({ "users": owners });
+    if state
+        .storage
+        .put(
+            &owners_key(&name),
+            &serde_json::to_vec(&doc).unwrap_or_default(),
+        )
+        .await
+        .is_err()
+    {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    state.audit.log(AuditEntry::new(
+        if add { "owner-add" } else { "owner-remove" },
+        "api",
+        &name,
+        "cargo",
+        &logins.join(","),
+    ));
+
+    cargo_ok(serde_json::json!({ "ok": true, "msg": msg }))
+}
+
+/// GET /cargo/api/v1/crates?q=&per_page= — `cargo search`.
+///
+/// Crates NORA holds (hosted and cached) come first, then upstream results
+/// that aren't already listed. A query that touches an internal namespace is
+/// never forwarded (#68); an upstream failure degrades to the local results.
+async fn search(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Response {
+    let q = params.get("q").map(|q| q.trim()).unwrap_or_default();
+    if q.len() > 256 {
+        return cargo_error(StatusCode::BAD_REQUEST, "query is too long");
+    }
+    let per_page = params
+        .get("per_page")
+        .and_then(|p| p.parse::<usize>().ok())
+        .unwrap_or(SEARCH_DEFAULT_PER_PAGE)
+        .clamp(1, SEARCH_MAX_PER_PAGE);
+    let terms: Vec<String> = q.split_whitespace().map(normalize_crate_term).collect();
+
+    let local = local_search(&state, &terms).await;
+    let mut total = local.len();
+    let mut crates = Vec::new();
+    for name in local.iter().take(per_page) {
+        crates.push(local_search_entry(&state, name).await);
+    }
+
+    let engine = &state.curation().curation_engine;
+    let internal = q.split_whitespace().any(|term| {
+        crate::curation::is_internal_namespace(
+            engine,
+            crate::curation::RegistryType::Cargo,
+            &term.to_lowercase(),
+        )
+    });
+    if let (Some(proxy_url), false, false) = (&state.config.cargo.proxy, internal, q.is_empty()) {
+        let url = format!(
+            "{}/api/v1/crates?q={}&per_page={}",
+            proxy_url.trim_end_matches('/'),
+            percent_encoding::utf8_percent_encode(q, percent_encoding::NON_ALPHANUMERIC),
+            (per_page - crates.len()).max(1)
+        );
+        match proxy_fetch(
+            &state.http_client,
+            &url,
+            Duration::from_secs(state.config.cargo.proxy_timeout),
+            expose_opt(&state.config.cargo.proxy_auth),
+            &state.circuit_breaker,
+            RegistryType::Cargo,
+        )
+        .await
+        .map(|data| serde_json::from_slice::<serde_json::Value>(&data))
+        {
+            Ok(Ok(upstream)) => {
+                state.activity.push(ActivityEntry::new(
+                    ActionType::ProxyFetch,
+                    format!("search:{}", q),
+                    RegistryType::Cargo,
+                    "PROXY",
+                ));
+                let mut duplicates = 0;
+                for entry in upstream["crates"].as_array().cloned().unwrap_or_default() {
+                    let name = entry["name"].as_str().unwrap_or_default().to_lowercase();
+                    if local.contains(&name) {
+                        duplicates += 1;
+                    } else if crates.len() < per_page {
+                        crates.push(entry);
+                    }
+                }
+                total += upstream["meta"]["total"]
+                    .as_u64()
+                    .map_or(0, |t| t as usize)
+                    .saturating_sub(duplicates);
+            }
+            Ok(Err(e)) => tracing::debug!(error = %e, "cargo search: unparsable upstream response"),
+            Err(e) => tracing::debug!(error = ?e, "cargo search upstream error"),
+        }
+    }
+
+    cargo_ok(serde_json::json!({ "crates": crates, "meta": { "total": total } }))
+}
+
+/// crates.io treats `-` and `_` alike in crate names; so does search.
+fn normalize_crate_term(term: &str) -> String {
+    term.to_lowercase().replace('_', "-")
+}
+
+/// Names of the crates with a sparse index in storage that contain every term,
+/// an exact match first and the rest alphabetical.
+async fn local_search(state: &AppState, terms: &[String]) -> Vec<String> {
+    let keys = state.storage.list("cargo/index/").await.unwrap_or_default();
+    let exact = terms.join("-");
+    let mut names: Vec<String> = keys
+        .iter()
+        .filter_map(|key| key.rsplit('/').next())
+        .filter(|name| {
+            let normalized = normalize_crate_term(name);
+            terms.iter().all(|term| normalized.contains(term.as_str()))
+        })
+        .map(str::to_string)
+        .collect();
+    names.sort_by(|a, b| {
+        (normalize_crate_term(b) == exact)
+            .cmp(&(normalize_crate_term(a) == exact))
+            .then_with(|| a.cmp(b))
+    });
+    names.dedup();
+    names
+}
+
+/// One `cargo search` row for a crate in storage. The description is only
+/// known for crates whose crates.io metadata has been cached.
+async fn local_search_entry(state: &AppState, name: &str) -> serde_json::Value {
+    let index_key = format!("cargo/index/{}/{}", crate_index_prefix(name), name);
+    let max_version = state
+        .storage
+        .get(&index_key)
+        .await
+        .ok()
+        .and_then(|index| max_version(&index));
+    let description = state
+        .storage
+        .get(&format!("cargo/{}/metadata.json", name))
+        .await
+        .ok()
+        .and_then(|data| serde_json::from_slice::<serde_json::Value>(&data).ok())
+        .and_then(|meta| meta["crate"]["description"].as_str().map(str::to_string));
+    serde_json::json!({
+        "name": name,
+        "max_version": max_version.unwrap_or_else(|| "0.0.0".to_string()),
+        "description": description,
+    })
+}
+
+/// Highest version in a sparse index file, preferring versions that are not
+/// yanked (crates.io's `max_version` skips yanked ones unless all are).
+fn max_version(index: &[u8]) -> Option<String> {
+    let mut best: Option<(bool, String)> = None;
+    for line in index.split(|&b| b == b'\n').filter(|l| !l.is_empty()) {
+        let Ok(entry) = serde_json::from_slice::<serde_json::Value>(line) else {
+            continue;
+        };
+        let Some(vers) = entry["vers"].as_str() else {
+            continue;
+        };
+        let live = !entry["yanked"].as_bool().unwrap_or(false);
+        let better = match &best {
+            None => true,
+            Some((best_live, best_vers)) => {
+                (live, cmp_semver(vers, best_vers)) > (*best_live, std::cmp::Ordering::Equal)
+            }
+        };
+        if better {
+            best = Some((live, vers.to_string()));
+        }
+    }
+    best.map(|(_, vers)| vers)
+}
+
 // ============================================================================
 // Helpers
 // ============================================================================
@@ -984,6 +1590,24 @@ async fn extract_cargo_publish_date(
     None
 }
 
+/// Declared license (`versions[].license`, an SPDX expression) of one version
+/// in cached crates.io metadata.
+async fn extract_cargo_license(
+    storage: &crate::storage::Storage,
+    metadata_key: &str,
+    version: &str,
+) -> Option<String> {
+    let data = storage.get(metadata_key).await.ok()?;
+    let json: serde_json::Value = serde_json::from_slice(&data).ok()?;
+    json.get("versions")?
+        .as_array()?
+        .iter()
+        .find(|v| v.get("num").and_then(|n| n.as_str()) == Some(version))?
+        .get("license")?
+        .as_str()
+        .map(|l| l.to_string())
+}
+
 // ============================================================================
 // Unit Tests
 // ============================================================================
@@ -1050,6 +1674,37 @@ mod tests {
         assert!(!is_valid_crate_name(&"a".repeat(65)));
     }
 
+    #[test]
+    fn test_max_version_skips_yanked() {
+        let index = concat!(
+            r#"{"name":"foo","vers":"0.9.0","yanked":false}"#,
+            "\n",
+            r#"{"name":"foo","vers":"0.10.0","yanked":false}"#,
+            "\n",
+            r#"{"name":"foo","vers":"1.0.0","yanked":true}"#,
+            "\n",
+        );
+        assert_eq!(max_version(index.as_bytes()).as_deref(), Some("0.10.0"));
+
+        // All yanked: the highest one is still reported.
+        let all_yanked = concat!(
+            r#"{"name":"foo","vers":"0.1.0","yanked":true}"#,
+            "\n",
+            r#"{"name":"foo","vers":"0.2.0","yanked":true}"#,
+        );
+        assert_eq!(max_version(all_yanked.as_bytes()).as_deref(), Some("0.2.0"));
+        assert_eq!(max_version(b""), None);
+    }
+
+    #[test]
+    fn test_owners_response_shape() {
+        let doc = owners_response(&["alice".to_string(), "bob".to_string()]);
+        assert_eq!(doc["users"][0]["id"], 1);
+        assert_eq!(doc["users"][0]["login"], "alice");
+        assert_eq!(doc["users"][1]["name"], "bob");
+        assert_eq!(owners_response(&[])["users"], serde_json::json!([]));
+    }
+
     // ── Index rebuild is all-or-fail ────────────────────────────────────
 
     #[tokio::test]
@@ -1133,7 +1788,7 @@ mod integration_tests {
 
     #[tokio::test]
     async fn test_cargo_namespace_scope_enforced() {
-        use crate::auth::NamespaceAuthority;
+        use crate::auth::{AuthenticatedUser, NamespaceAuthority};
         use crate::config::ScopeEnforcement;
         use axum::body::Bytes;
         use axum::extract::State;
@@ -1153,6 +1808,8 @@ mod integration_tests {
         let resp = super::publish(
             State(ctx.state.clone()),
             Extension(scoped.clone()),
+            Extension(AuthenticatedUser("ci".to_string())),
+            None,
             Bytes::from(payload),
         )
         .await;
@@ -1164,6 +1821,8 @@ mod integration_tests {
         let resp = super::publish(
             State(ctx.state.clone()),
             Extension(scoped),
+            Extension(AuthenticatedUser("ci".to_string())),
+            None,
             Bytes::from(payload),
         )
         .await;
@@ -2045,6 +2704,164 @@ mod integration_tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_cargo_download_blocked_as_typosquat() {
+        use crate::test_helpers::{create_test_context_with_config, send_with_headers};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let corpus_path = dir.path().join("cargo.txt");
+        std::fs::write(&corpus_path, "# popular crates\nserde\nserde_json\n").unwrap();
+
+        let corpus = corpus_path.to_str().unwrap().to_string();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.curation.mode = crate::config::CurationMode::Enforce;
+            cfg.curation.typosquat.enabled = true;
+            cfg.curation.cargo.typosquat_corpus = Some(corpus);
+        });
+        for name in ["serde-json", "serde-jsno"] {
+            ctx.state
+                .storage
+                .put(
+                    &format!("cargo/{name}/1.0.0/{name}-1.0.0.crate"),
+                    b"crate-data",
+                )
+                .await
+                .unwrap();
+        }
+
+        // `serde-json` is `serde_json` to crates.io; `serde-jsno` only resembles it.
+        for (name, status, decision) in [
+            ("serde-json", StatusCode::OK, None),
+            ("serde-jsno", StatusCode::FORBIDDEN, Some("blocked")),
+        ] {
+            let resp = send_with_headers(
+                &ctx.app,
+                Method::GET,
+                &format!("/cargo/api/v1/crates/{name}/1.0.0/download"),
+                vec![],
+                "",
+            )
+            .await;
+            assert_eq!(resp.status(), status, "{name}");
+            assert_eq!(
+                resp.headers()
+                    .get("x-nora-decision")
+                    .and_then(|v| v.to_str().ok()),
+                decision,
+                "{name}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cargo_download_blocked_by_vulnerability_db() {
+        use crate::test_helpers::{create_test_context_with_config, send_with_headers};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let advisory = serde_json::json!({
+            "id": "RUSTSEC-2024-0001",
+            "aliases": ["GHSA-xxxx-yyyy-zzzz"],
+            "affected": [{
+                "package": {"ecosystem": "crates.io", "name": "evil-crate"},
+                "ranges": [{"type": "SEMVER", "events": [{"introduced": "0"}, {"fixed": "1.0.1"}]}]
+            }],
+            "database_specific": {"severity": "critical"}
+        });
+        let dump = crate::vulndb::tests::osv_zip(dir.path(), &[advisory]);
+        let db_path = dir.path().join("osv.json").to_str().unwrap().to_string();
+        crate::vulndb::import(&dump, &db_path, false).unwrap();
+
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.curation.mode = crate::config::CurationMode::Enforce;
+            cfg.curation.vulnerabilities.enabled = true;
+            cfg.curation.vulnerabilities.db_path = Some(db_path);
+        });
+        for version in ["1.0.0", "1.0.1"] {
+            ctx.state
+                .storage
+                .put(
+                    &format!("cargo/evil-crate/{version}/evil-crate-{version}.crate"),
+                    b"crate-data",
+                )
+                .await
+                .unwrap();
+        }
+
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::GET,
+            "/cargo/api/v1/crates/evil-crate/1.0.0/download",
+            vec![],
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        assert_eq!(
+            resp.headers()
+                .get("x-nora-rule")
+                .and_then(|v| v.to_str().ok()),
+            Some("vulnerability")
+        );
+
+        // The fixed release downloads.
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::GET,
+            "/cargo/api/v1/crates/evil-crate/1.0.1/download",
+            vec![],
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_cargo_download_license_allowlist() {
+        use crate::test_helpers::{create_test_context_with_config, send};
+
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.cargo.proxy = None;
+            cfg.curation.mode = crate::config::CurationMode::Enforce;
+            cfg.curation.licenses.enabled = true;
+            cfg.curation.licenses.allow = vec!["MIT".to_string(), "Apache-2.0".to_string()];
+            cfg.curation.licenses.allow_unknown = false;
+        });
+        let meta = serde_json::json!({"versions": [
+            {"num": "1.0.0", "license": "GPL-3.0-or-later"},
+            {"num": "2.0.0", "license": "MIT/Apache-2.0"}
+        ]});
+        ctx.state
+            .storage
+            .put("cargo/mixed/metadata.json", meta.to_string().as_bytes())
+            .await
+            .unwrap();
+        for (name, version) in [("mixed", "1.0.0"), ("mixed", "2.0.0"), ("bare", "1.0.0")] {
+            ctx.state
+                .storage
+                .put(
+                    &format!("cargo/{name}/{version}/{name}-{version}.crate"),
+                    b"crate-data",
+                )
+                .await
+                .unwrap();
+        }
+
+        let get = |path: &'static str| send(&ctx.app, Method::GET, path, "");
+        let resp = get("/cargo/api/v1/crates/mixed/1.0.0/download").await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        assert_eq!(
+            resp.headers()
+                .get("x-nora-rule")
+                .and_then(|v| v.to_str().ok()),
+            Some("license")
+        );
+        let resp = get("/cargo/api/v1/crates/mixed/2.0.0/download").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        // No metadata and no upstream: the license is unknown, which this policy rejects.
+        let resp = get("/cargo/api/v1/crates/bare/1.0.0/download").await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
     #[tokio::test]
     async fn test_cargo_download_allowed_by_curation() {
         use crate::test_helpers::{create_test_context_with_config, send_with_headers};
@@ -2153,4 +2970,384 @@ mod integration_tests {
         );
         assert_eq!(&body_bytes(resp).await[..], krate);
     }
+
+    // ── Yank, owners & search ─────────────────────────────────────────
+
+    async fn publish_crate(ctx: &crate::test_helpers::TestContext, name: &str, vers: &str) {
+        let metadata = serde_json::json!({"name": name, "vers": vers, "deps": [], "features": {}});
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/cargo/api/v1/crates/new",
+            Body::from(build_publish_payload(&metadata, b"crate-data")),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK, "publish {name}@{vers}");
+    }
+
+    async fn index_lines(
+        ctx: &crate::test_helpers::TestContext,
+        path: &str,
+    ) -> Vec<serde_json::Value> {
+        let resp = send(&ctx.app, Method::GET, path, "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        body_bytes(resp)
+            .await
+            .split(|&b| b == b'\n')
+            .filter(|l| !l.is_empty())
+            .map(|l| serde_json::from_slice(l).unwrap())
+            .collect()
+    }
+
+    fn yanked_of(lines: &[serde_json::Value], vers: &str) -> bool {
+        lines
+            .iter()
+            .find(|l| l["vers"] == vers)
+            .and_then(|l| l["yanked"].as_bool())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_cargo_yank_and_unyank() {
+        let ctx = create_test_context();
+        publish_crate(&ctx, "yankme", "0.1.0").await;
+        publish_crate(&ctx, "yankme", "0.2.0").await;
+
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/cargo/api/v1/crates/yankme/0.2.0/yank",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(body["ok"], true);
+
+        let lines = index_lines(&ctx, "/cargo/index/ya/nk/yankme").await;
+        assert_eq!(lines.len(), 2);
+        assert!(yanked_of(&lines, "0.2.0"));
+        assert!(!yanked_of(&lines, "0.1.0"));
+
+        // Yanking again is a no-op; the .crate itself still downloads.
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/cargo/api/v1/crates/yankme/0.2.0/yank",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/cargo/api/v1/crates/yankme/0.2.0/download",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/cargo/api/v1/crates/yankme/0.2.0/unyank",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let lines = index_lines(&ctx, "/cargo/index/ya/nk/yankme").await;
+        assert!(!yanked_of(&lines, "0.2.0"));
+
+        // A re-publish of a yanked version stays a conflict.
+        let metadata = serde_json::json!({"name":"yankme","vers":"0.2.0","deps":[],"features":{}});
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/cargo/api/v1/crates/new",
+            Body::from(build_publish_payload(&metadata, b"other")),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_cargo_yank_errors() {
+        let ctx = create_test_context();
+        publish_crate(&ctx, "yankme", "0.1.0").await;
+
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/cargo/api/v1/crates/yankme/9.9.9/yank",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert!(body["errors"][0]["detail"]
+            .as_str()
+            .unwrap()
+            .contains("9.9.9"));
+
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/cargo/api/v1/crates/-bad/0.1.0/yank",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/cargo/api/v1/crates/yankme/0.1.0/yank",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    fn basic(user: &str, pass: &str) -> String {
+        use base64::Engine;
+        format!(
+            "Basic {}",
+            base64::engine::general_purpose::STANDARD.encode(format!("{user}:{pass}"))
+        )
+    }
+
+    #[tokio::test]
+    async fn test_cargo_owners_restrict_management() {
+        use crate::test_helpers::{create_test_context_with_auth, send_with_headers};
+
+        let ctx = create_test_context_with_auth(&[("alice", "a-pass"), ("bob", "b-pass")]);
+        let alice = basic("alice", "a-pass");
+        let bob = basic("bob", "b-pass");
+        let req = |method: Method, uri: &'static str, auth: String, body: &'static str| {
+            let app = ctx.app.clone();
+            async move {
+                send_with_headers(
+                    &app,
+                    method,
+                    uri,
+                    vec![("authorization", auth.as_str())],
+                    body,
+                )
+                .await
+            }
+        };
+        let owners = "/cargo/api/v1/crates/shared/owners";
+
+        let metadata = serde_json::json!({"name":"shared","vers":"0.1.0","deps":[],"features":{}});
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::PUT,
+            "/cargo/api/v1/crates/new",
+            vec![("authorization", alice.as_str())],
+            Body::from(build_publish_payload(&metadata, b"v1")),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        // No owners yet: the list is empty and any writer may manage the crate.
+        let resp = req(Method::GET, owners, alice.clone(), "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(body["users"], serde_json::json!([]));
+
+        let resp = req(
+            Method::PUT,
+            owners,
+            alice.clone(),
+            r#"{"users":["mallory"]}"#,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let resp = req(Method::PUT, owners, alice.clone(), r#"{"users":["alice"]}"#).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(body["ok"], true);
+
+        let resp = req(Method::GET, owners, bob.clone(), "").await;
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(body["users"][0]["login"], "alice");
+
+        // bob is not an owner: no yank, no owner changes, no new versions.
+        let yank = "/cargo/api/v1/crates/shared/0.1.0/yank";
+        let resp = req(Method::DELETE, yank, bob.clone(), "").await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let resp = req(Method::PUT, owners, bob.clone(), r#"{"users":["bob"]}"#).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let metadata = serde_json::json!({"name":"shared","vers":"0.2.0","deps":[],"features":{}});
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::PUT,
+            "/cargo/api/v1/crates/new",
+            vec![("authorization", bob.as_str())],
+            Body::from(build_publish_payload(&metadata, b"v2")),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        // Once alice adds bob, he can yank; the last owner cannot be removed.
+        let resp = req(Method::PUT, owners, alice.clone(), r#"{"users":["bob"]}"#).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = req(Method::DELETE, yank, bob.clone(), "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = req(
+            Method::DELETE,
+            owners,
+            bob.clone(),
+            r#"{"users":["alice"]}"#,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = req(Method::DELETE, owners, bob.clone(), r#"{"users":["bob"]}"#).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        // An admin token overrides ownership.
+        let admin_token = ctx
+            .state
+            .tokens
+            .as_ref()
+            .unwrap()
+            .create_token("root", 1, None, crate::tokens::Role::Admin)
+            .unwrap();
+        let resp = req(
+            Method::PUT,
+            "/cargo/api/v1/crates/shared/0.1.0/unyank",
+            format!("Bearer {admin_token}"),
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_cargo_owners_not_hosted() {
+        let ctx = create_test_context();
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/cargo/api/v1/crates/nope/owners",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/cargo/api/v1/crates/nope/owners",
+            r#"{"users":["anyone"]}"#,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    async fn search_json(app: &axum::Router, uri: &str) -> serde_json::Value {
+        let resp = send(app, Method::GET, uri, "").await;
+        assert_eq!(resp.status(), StatusCode::OK, "{uri}");
+        serde_json::from_slice(&body_bytes(resp).await).unwrap()
+    }
+
+    fn crate_names(result: &serde_json::Value) -> Vec<&str> {
+        result["crates"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| c["name"].as_str().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_cargo_search_local() {
+        let ctx = create_test_context();
+        publish_crate(&ctx, "acme-utils", "0.1.0").await;
+        publish_crate(&ctx, "acme", "1.0.0").await;
+        publish_crate(&ctx, "acme", "1.1.0").await;
+        publish_crate(&ctx, "other", "0.1.0").await;
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/cargo/api/v1/crates/acme/1.1.0/yank",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let result = search_json(&ctx.app, "/cargo/api/v1/crates?q=acme").await;
+        assert_eq!(crate_names(&result), vec!["acme", "acme-utils"]);
+        assert_eq!(result["meta"]["total"], 2);
+        assert_eq!(result["crates"][0]["max_version"], "1.0.0");
+
+        // `_` and `-` match alike; per_page limits the page, not the total.
+        let result = search_json(&ctx.app, "/cargo/api/v1/crates?q=acme_utils").await;
+        assert_eq!(crate_names(&result), vec!["acme-utils"]);
+        let result = search_json(&ctx.app, "/cargo/api/v1/crates?q=acme&per_page=1").await;
+        assert_eq!(crate_names(&result), vec!["acme"]);
+        assert_eq!(result["meta"]["total"], 2);
+
+        let result = search_json(&ctx.app, "/cargo/api/v1/crates?q=zzz").await;
+        assert!(crate_names(&result).is_empty());
+        assert_eq!(result["meta"]["total"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_cargo_search_merges_upstream() {
+        use crate::test_helpers::create_test_context_with_config;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let upstream = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/crates"))
+            .and(query_param("q", "serde"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "crates": [
+                    {"name": "serde", "max_version": "1.0.200", "description": "upstream"},
+                    {"name": "serde_json", "max_version": "1.0.100", "description": "JSON"}
+                ],
+                "meta": {"total": 40}
+            })))
+            .expect(1)
+            .mount(&upstream)
+            .await;
+
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.cargo.proxy = Some(upstream.uri());
+        });
+        publish_crate(&ctx, "serde", "9.0.0").await;
+
+        let result = search_json(&ctx.app, "/cargo/api/v1/crates?q=serde").await;
+        // The hosted crate wins over upstream's namesake.
+        assert_eq!(crate_names(&result), vec!["serde", "serde_json"]);
+        assert_eq!(result["crates"][0]["max_version"], "9.0.0");
+        assert_eq!(result["meta"]["total"], 40);
+    }
+
+    #[tokio::test]
+    async fn test_cargo_search_internal_namespace_not_forwarded() {
+        use crate::test_helpers::create_test_context_with_config;
+        use wiremock::matchers::any;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let upstream = MockServer::start().await;
+        // Any upstream hit would leak the internal name — mount a canary.
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(200).set_body_string("LEAKED"))
+            .expect(0)
+            .mount(&upstream)
+            .await;
+
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.cargo.proxy = Some(upstream.uri());
+            cfg.curation.mode = crate::config::CurationMode::Enforce;
+            cfg.curation.internal_namespaces = vec!["acme-*".to_string()];
+        });
+        publish_crate(&ctx, "acme-secret", "0.1.0").await;
+
+        let result = search_json(&ctx.app, "/cargo/api/v1/crates?q=acme-secret").await;
+        assert_eq!(crate_names(&result), vec!["acme-secret"]);
+        assert_eq!(result["meta"]["total"], 1);
+    }
 }
@@ -0,0 +1,1304 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+//! PHP Composer repository — hosted packages plus a Packagist pull-through proxy.
+//!
+//! Implements the Composer v2 repository layout:
+//!   GET    /composer/packages.json                              — repository root (`metadata-url`)
+//!   GET    /composer/p2/{vendor}/{package}.json                 — tagged releases
+//!   GET    /composer/p2/{vendor}/{package}~dev.json             — dev branches
+//!   GET    /composer/dists/{vendor}/{package}/{reference}.zip   — dist archive
+//!   PUT    /composer/packages/{vendor}/{package}/{version}      — upload a dist zip (reads `composer.json`)
+//!   DELETE /composer/packages/{vendor}/{package}/{version}      — remove a hosted version
+//!
+//! Client config:
+//!   composer config repositories.nora composer http://nora:4000/composer
+//!   composer config repositories.packagist false    # resolve everything through NORA
+//!
+//! A package with at least one uploaded version is hosted: its metadata is
+//! built from the upload records and never fetched from or merged with the
+//! upstream (dependency confusion). Every other name is proxied: the upstream
+//! p2 file is cached for `metadata_ttl` and each version's `dist.url` is
+//! rewritten to `/composer/dists/...`, so archives are pulled through NORA,
+//! gated by curation and the digest quarantine, and cached forever. `source`
+//! entries are passed through untouched — `--prefer-source` clones bypass NORA.
+
+use crate::activity_log::{ActionType, ActivityEntry};
+use crate::audit::AuditEntry;
+use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
+use crate::cache_ttl::is_within_ttl;
+use crate::registry::{
+    circuit_open_response, nora_base_url, proxy_fetch, read_json_sidecars, ProxyError,
+};
+use crate::registry_type::RegistryType;
+use crate::secrets::expose_opt;
+use crate::validation::validate_storage_key;
+use crate::AppState;
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, put},
+    Extension, Router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sha1::Digest;
+use std::collections::{BTreeSet, HashMap};
+use std::io::Read;
+use std::time::Duration;
+
+/// Storage prefix and file suffix for repo index scanning.
+pub const INDEX_PATTERN: (&str, &str) = ("composer/dists/", ".zip");
+
+const JSON_CONTENT_TYPE: &str = "application/json";
+const ZIP_CONTENT_TYPE: &str = "application/zip";
+/// Upper bound on the `composer.json` read from an uploaded dist.
+const MAX_COMPOSER_JSON: u64 = 1 << 20;
+/// Root-package settings of a `composer.json` — meaningless (or, for
+/// `repositories`, misleading) in a dependency's metadata. `version` is
+/// replaced by the version the dist is published as.
+const ROOT_ONLY_FIELDS: &[&str] = &[
+    "repositories",
+    "config",
+    "minimum-stability",
+    "prefer-stable",
+    "version",
+];
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/composer/packages.json", get(packages_json))
+        .route("/composer/p2/{vendor}/{file}", get(package_metadata))
+        .route(
+            "/composer/dists/{vendor}/{package}/{file}",
+            get(download_dist),
+        )
+        .route(
+            "/composer/packages/{vendor}/{package}/{version}",
+            put(upload).delete(delete_version),
+        )
+}
+
+// ============================================================================
+// Names and storage keys
+// ============================================================================
+
+/// One half of a package name (`vendor` or `package`): lowercase
+/// alphanumerics joined by `.`, `_` or `-`, as Composer's schema requires.
+fn valid_name_part(part: &str) -> bool {
+    !part.is_empty()
+        && part.len() <= 128
+        && part.bytes().all(|b| {
+            b.is_ascii_lowercase() || b.is_ascii_digit() || matches!(b, b'.' | b'_' | b'-')
+        })
+        && part.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && part.ends_with(|c: char| c.is_ascii_alphanumeric())
+        && !part.contains("..")
+}
+
+/// A published version (`1.2.0`, `v2.0.0-RC1`, `dev-main`, `2.x-dev`) — one
+/// path segment, so branch names with `/` cannot be uploaded.
+fn valid_version(version: &str) -> bool {
+    !version.is_empty()
+        && version.len() <= 128
+        && !version.starts_with('.')
+        && version
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'+' | b'-'))
+}
+
+/// A dist reference (a git commit for Packagist, the zip's sha1 for uploads).
+fn valid_reference(reference: &str) -> bool {
+    !reference.is_empty()
+        && reference.len() <= 128
+        && !reference.starts_with('.')
+        && reference
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-'))
+}
+
+/// Branches (`dev-main`, `2.x-dev`) are served from the `~dev` metadata file.
+fn is_dev_version(version: &str) -> bool {
+    version.starts_with("dev-") || version.ends_with("-dev")
+}
+
+/// `{package}.json` → (`package`, false), `{package}~dev.json` → (`package`, true).
+fn split_metadata_file(file: &str) -> Option<(&str, bool)> {
+    let stem = file.strip_suffix(".json")?;
+    Some(match stem.strip_suffix("~dev") {
+        Some(package) => (package, true),
+        None => (stem, false),
+    })
+}
+
+fn hosted_prefix(name: &str) -> String {
+    format!("composer/hosted/{name}/")
+}
+
+fn hosted_key(name: &str, version: &str) -> String {
+    format!("composer/hosted/{name}/{version}.json")
+}
+
+fn dist_key(name: &str, reference: &str) -> String {
+    format!("composer/dists/{name}/{reference}.zip")
+}
+
+fn metadata_key(name: &str, dev: bool) -> String {
+    format!("composer/p2/{name}{}.json", if dev { "~dev" } else { "" })
+}
+
+fn dist_url(base: &str, name: &str, reference: &str) -> String {
+    format!("{base}/composer/dists/{name}/{reference}.zip")
+}
+
+/// The reference a dist is served under. Entries without a usable
+/// `reference` are keyed by the sha1 of their upstream URL instead.
+fn dist_reference(dist: &Map<String, Value>) -> String {
+    match dist.get("reference").and_then(Value::as_str) {
+        Some(reference) if valid_reference(reference) => reference.to_string(),
+        _ => {
+            let url = dist.get("url").and_then(Value::as_str).unwrap_or_default();
+            hex::encode(sha1::Sha1::digest(url.as_bytes()))
+        }
+    }
+}
+
+fn json_response(data: Vec<u8>) -> Response {
+    (
+        StatusCode::OK,
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(JSON_CONTENT_TYPE),
+            ),
+            (header::CACHE_CONTROL, HeaderValue::from_static("no-cache")),
+        ],
+        data,
+    )
+        .into_response()
+}
+
+// ============================================================================
+// Hosted packages
+// ============================================================================
+
+/// One uploaded version, stored at `composer/hosted/{vendor}/{package}/{version}.json`.
+/// The dist reference is the zip's sha1, so it doubles as `dist.shasum`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct HostedVersion {
+    pub(crate) version: String,
+    pub(crate) shasum: String,
+    pub(crate) size: u64,
+    /// Upload time, RFC 3339 — Composer's `time` field.
+    pub(crate) time: String,
+    /// `composer.json` of the dist, minus [`ROOT_ONLY_FIELDS`].
+    package: Map<String, Value>,
+}
+
+/// p2 version entry for a hosted version, with the dist URL on this node.
+fn hosted_entry(base: &str, name: &str, record: &HostedVersion) -> Value {
+    let mut entry = record.package.clone();
+    entry.insert("name".into(), json!(name));
+    entry.insert("version".into(), json!(record.version));
+    entry.insert("time".into(), json!(record.time));
+    entry.insert(
+        "dist".into(),
+        json!({
+            "type": "zip",
+            "url": dist_url(base, name, &record.shasum),
+            "reference": record.shasum,
+            "shasum": record.shasum,
+        }),
+    );
+    Value::Object(entry)
+}
+
+/// p2 metadata of a hosted package: releases, or branches for `~dev`.
+fn render_hosted(base: &str, name: &str, mut records: Vec<HostedVersion>, dev: bool) -> Vec<u8> {
+    records.retain(|r| is_dev_version(&r.version) == dev);
+    // Newest first, like Packagist; Composer does its own version ordering.
+    records.sort_by(|a, b| b.time.cmp(&a.time).then_with(|| b.version.cmp(&a.version)));
+    let entries: Vec<Value> = records
+        .iter()
+        .map(|r| hosted_entry(base, name, r))
+        .collect();
+    serde_json::to_vec(&json!({ "packages": { name: entries } })).unwrap_or_default()
+}
+
+/// `vendor/package` of every hosted package, sorted.
+async fn hosted_package_names(storage: &crate::storage::Storage) -> Vec<String> {
+    let keys = storage.list("composer/hosted/").await.unwrap_or_default();
+    let names: BTreeSet<String> = keys
+        .iter()
+        .filter_map(|key| {
+            let mut parts = key.strip_prefix("composer/hosted/")?.split('/');
+            Some(format!("{}/{}", parts.next()?, parts.next()?))
+        })
+        .collect();
+    names.into_iter().collect()
+}
+
+/// `composer.json` of a dist zip: at the root, or inside the single top-level
+/// directory that GitHub-style archives wrap everything in.
+fn read_composer_json(data: &[u8]) -> Result<Map<String, Value>, String> {
+    let mut zip = zip::ZipArchive::new(std::io::Cursor::new(data))
+        .map_err(|e| format!("not a zip archive: {e}"))?;
+    let member = zip
+        .file_names()
+        .filter(|n| {
+            *n == "composer.json" || (n.ends_with("/composer.json") && n.matches('/').count() == 1)
+        })
+        .min_by_key(|n| n.len())
+        .map(str::to_string)
+        .ok_or("no composer.json at the archive root")?;
+    let mut raw = Vec::new();
+    zip.by_name(&member)
+        .map_err(|e| format!("{member}: {e}"))?
+        .take(MAX_COMPOSER_JSON + 1)
+        .read_to_end(&mut raw)
+        .map_err(|e| format!("{member}: {e}"))?;
+    if raw.len() as u64 > MAX_COMPOSER_JSON {
+        return Err(format!("{member} is too large"));
+    }
+    match serde_json::from_slice(&raw) {
+        Ok(Value::Object(manifest)) => Ok(manifest),
+        Ok(_) => Err(format!("{member} is not a JSON object")),
+        Err(e) => Err(format!("{member}: {e}")),
+    }
+}
+
+/// Check an uploaded `composer.json` against the upload path.
+fn check_manifest(manifest: &Map<String, Value>, name: &str, version: &str) -> Result<(), String> {
+    match manifest.get("name").and_then(Value::as_str) {
+        Some(n) if n.eq_ignore_ascii_case(name) => {}
+        Some(n) => return Err(format!("composer.json name {n:?} does not match {name}")),
+        None => return Err("composer.json has no name".to_string()),
+    }
+    match manifest.get("version") {
+        None => Ok(()),
+        Some(Value::String(v)) if v == version => Ok(()),
+        Some(v) => Err(format!(
+            "composer.json version {v} does not match upload version {version}"
+        )),
+    }
+}
+
+/// PUT /composer/packages/{vendor}/{package}/{version} — publish a dist zip.
+///
+/// Versions are immutable: re-uploading one is a 409 (delete it first).
+async fn upload(
+    State(state): State<AppState>,
+    Path((vendor, package, version)): Path<(String, String, String)>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    body: Bytes,
+) -> Response {
+    if !valid_name_part(&vendor) || !valid_name_part(&package) {
+        return (
+            StatusCode::BAD_REQUEST,
+            "Invalid package name: expected lowercase vendor/package",
+        )
+            .into_response();
+    }
+    if !valid_version(&version) {
+        return (StatusCode::BAD_REQUEST, "Invalid version").into_response();
+    }
+    let name = format!("{vendor}/{package}");
+    // Enforce OIDC namespace_scope on the package name (#583).
+    if enforce_namespace_scope(&authority, &name).is_err() {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if body.len() as u64 > state.config.composer.max_file_size {
+        return (
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "File too large. Max size: {} bytes",
+                state.config.composer.max_file_size
+            ),
+        )
+            .into_response();
+    }
+
+    let mut manifest = match read_composer_json(&body) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("Not a valid Composer dist: {e}"),
+            )
+                .into_response()
+        }
+    };
+    if let Err(e) = check_manifest(&manifest, &name, &version) {
+        return (StatusCode::BAD_REQUEST, e).into_response();
+    }
+    for field in ROOT_ONLY_FIELDS {
+        manifest.remove(*field);
+    }
+    let record = HostedVersion {
+        version: version.clone(),
+        shasum: hex::encode(sha1::Sha1::digest(&body)),
+        size: body.len() as u64,
+        time: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+        package: manifest,
+    };
+    let record_json = match serde_json::to_vec(&record) {
+        Ok(j) => j,
+        Err(e) => {
+            tracing::error!(error = %e, "composer: failed to serialize version record");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let key = dist_key(&name, &record.shasum);
+    let record_key = hosted_key(&name, &version);
+    if validate_storage_key(&key).is_err() || validate_storage_key(&record_key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    let lock = state.publish_lock(&format!("composer/{name}"));
+    let _guard = lock.lock().await;
+
+    if state.storage.stat(&record_key).await.is_some() {
+        return (StatusCode::CONFLICT, "Version already exists").into_response();
+    }
+    // Dist first; the record is what makes the version visible.
+    if let Err(e) = state.storage.put(&key, &body).await {
+        tracing::error!(error = %e, key = %key, "composer: failed to store dist");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    if let Err(e) = state.storage.put(&record_key, &record_json).await {
+        tracing::error!(error = %e, key = %record_key, "composer: failed to store version record");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    let ident = format!("{name}@{version}");
+    state.metrics.record_upload("composer");
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &ident, "composer", ""));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        ident,
+        RegistryType::Composer,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("composer");
+
+    StatusCode::CREATED.into_response()
+}
+
+/// DELETE /composer/packages/{vendor}/{package}/{version} — remove a hosted
+/// version. The dist goes too unless another version was uploaded from the
+/// same zip.
+async fn delete_version(
+    State(state): State<AppState>,
+    Path((vendor, package, version)): Path<(String, String, String)>,
+    Extension(authority): Extension<NamespaceAuthority>,
+) -> Response {
+    if !valid_name_part(&vendor) || !valid_name_part(&package) || !valid_version(&version) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let name = format!("{vendor}/{package}");
+    if enforce_namespace_scope(&authority, &name).is_err() {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let record_key = hosted_key(&name, &version);
+    if validate_storage_key(&record_key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    let lock = state.publish_lock(&format!("composer/{name}"));
+    let _guard = lock.lock().await;
+
+    let record = match state.storage.get(&record_key).await {
+        Ok(data) => serde_json::from_slice::<HostedVersion>(&data).ok(),
+        Err(crate::storage::StorageError::NotFound) => {
+            return StatusCode::NOT_FOUND.into_response()
+        }
+        Err(e) => {
+            tracing::error!(error = %e, key = %record_key, "composer: failed to read version record");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    if let Err(e) = state.storage.delete(&record_key).await {
+        tracing::error!(error = %e, key = %record_key, "composer: failed to delete version record");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    if let Some(record) = record {
+        let shared = read_json_sidecars::<HostedVersion>(&state.storage, &hosted_prefix(&name))
+            .await
+            .map_or(true, |rest| rest.iter().any(|r| r.shasum == record.shasum));
+        if !shared {
+            let key = dist_key(&name, &record.shasum);
+            if let Err(e) = state.storage.delete(&key).await {
+                tracing::warn!(error = %e, key = %key, "composer: failed to delete dist");
+            }
+        }
+    }
+
+    state.audit.log(AuditEntry::new(
+        "delete",
+        "api",
+        &format!("{name}@{version}"),
+        "composer",
+        "",
+    ));
+    state.repo_index.invalidate("composer");
+    StatusCode::NO_CONTENT.into_response()
+}
+
+// ============================================================================
+// Metadata
+// ============================================================================
+
+/// GET /composer/packages.json — the repository root.
+async fn packages_json(State(state): State<AppState>) -> Response {
+    let base = nora_base_url(&state);
+    let mut root = json!({
+        "packages": {},
+        "metadata-url": format!("{base}/composer/p2/%package%.json"),
+    });
+    // Without an upstream the hosted names are the whole repository; listing
+    // them spares Composer a 404 round trip per unknown dependency.
+    if state.config.composer.proxy.is_none() {
+        root["available-packages"] = json!(hosted_package_names(&state.storage).await);
+    }
+    json_response(serde_json::to_vec(&root).unwrap_or_default())
+}
+
+/// GET /composer/p2/{vendor}/{package}.json (and `~dev.json`).
+async fn package_metadata(
+    State(state): State<AppState>,
+    headers: axum::http::HeaderMap,
+    Path((vendor, file)): Path<(String, String)>,
+) -> Response {
+    // Composer asks for lowercase names only; anything else is unknown.
+    let Some((package, dev)) = split_metadata_file(&file) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    if !valid_name_part(&vendor) || !valid_name_part(package) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let name = format!("{vendor}/{package}");
+
+    match read_json_sidecars::<HostedVersion>(&state.storage, &hosted_prefix(&name)).await {
+        Ok(records) if !records.is_empty() => {
+            return json_response(render_hosted(&nora_base_url(&state), &name, records, dev));
+        }
+        Ok(_) => {}
+        Err(e) => {
+            // Never fall through to the upstream for a name that may be hosted.
+            tracing::error!(package = %name, error = %e, "composer: failed to read hosted versions");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+    proxy_metadata(&state, &headers, &name, dev).await
+}
+
+/// Rewrite every version's `dist.url` to this node's dist endpoint.
+///
+/// Returns `None` for a body that is not p2 metadata — serving it unrewritten
+/// would hand clients upstream dist URLs.
+fn rewrite_dist_urls(data: &[u8], base: &str) -> Option<Vec<u8>> {
+    let mut root: Value = serde_json::from_slice(data).ok()?;
+    let packages = root.get_mut("packages")?.as_object_mut()?;
+    for (name, versions) in packages.iter_mut() {
+        let Some(versions) = versions.as_array_mut() else {
+            continue;
+        };
+        // Minified entries omit an unchanged `dist` (or mark it `"__unset"`);
+        // only objects carry a URL.
+        for dist in versions
+            .iter_mut()
+            .filter_map(|v| v.get_mut("dist")?.as_object_mut())
+        {
+            if dist.contains_key("url") {
+                let reference = dist_reference(dist);
+                dist.insert("url".into(), json!(dist_url(base, name, &reference)));
+            }
+        }
+    }
+    serde_json::to_vec(&root).ok()
+}
+
+/// Fetch the upstream p2 file and cache it as received (rewriting happens on
+/// every serve, so a changed `public_url` takes effect immediately).
+async fn fetch_metadata(state: &AppState, name: &str, dev: bool) -> Result<Vec<u8>, ProxyError> {
+    let Some(proxy_url) = &state.config.composer.proxy else {
+        return Err(ProxyError::NotFound);
+    };
+    let url = format!(
+        "{}/p2/{name}{}.json",
+        proxy_url.trim_end_matches('/'),
+        if dev { "~dev" } else { "" }
+    );
+    let data = proxy_fetch(
+        &state.http_client,
+        &url,
+        Duration::from_secs(state.config.composer.proxy_timeout),
+        expose_opt(&state.config.composer.proxy_auth),
+        &state.circuit_breaker,
+        RegistryType::Composer,
+    )
+    .await?;
+    let key = metadata_key(name, dev);
+    if let Err(e) = state.storage.put(&key, &data).await {
+        tracing::warn!(key = %key, error = %e, "composer: failed to cache proxy metadata");
+    }
+    Ok(data)
+}
+
+async fn proxy_metadata(
+    state: &AppState,
+    headers: &axum::http::HeaderMap,
+    name: &str,
+    dev: bool,
+) -> Response {
+    // Curation check. #733 serve-local: an internal-namespace package is operator-owned — skip
+    // curation and serve any local copy below; block the upstream branch separately.
+    let engine = &state.curation().curation_engine;
+    let internal = crate::curation::is_internal_namespace(engine, RegistryType::Composer, name);
+    if !internal {
+        if let Some(response) = crate::curation::check_download(
+            engine,
+            state.bypass_token().as_deref(),
+            headers,
+            RegistryType::Composer,
+            name,
+            None,
+            None,
+        ) {
+            return response;
+        }
+    }
+
+    let base = nora_base_url(state);
+    let serve = |data: &[u8]| match rewrite_dist_urls(data, &base) {
+        Some(body) => json_response(body),
+        None => {
+            tracing::warn!(package = %name, "composer: upstream metadata is not p2 JSON");
+            StatusCode::BAD_GATEWAY.into_response()
+        }
+    };
+
+    let key = metadata_key(name, dev);
+    let cached = state.storage.get(&key).await.ok();
+    if let Some(ref data) = cached {
+        if let Some(meta) = state.storage.stat(&key).await {
+            if is_within_ttl(meta.modified, state.config.composer.metadata_ttl) {
+                return serve(data);
+            }
+        }
+    }
+
+    // #733: an internal-namespace package — serve any (stale) local copy, else block; never proxy.
+    if internal {
+        if let Some(ref data) = cached {
+            return serve(data);
+        }
+        return crate::curation::check_namespace_isolation(engine, RegistryType::Composer, name)
+            .unwrap_or_else(|| StatusCode::NOT_FOUND.into_response());
+    }
+
+    match fetch_metadata(state, name, dev).await {
+        Ok(data) => {
+            state.repo_index.invalidate("composer");
+            serve(&data)
+        }
+        Err(ProxyError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(ProxyError::CircuitOpen(reg)) => circuit_open_response(&reg),
+        Err(e) => {
+            if let Some(ref data) = cached {
+                if state.config.composer.serve_stale {
+                    tracing::warn!(
+                        registry = "composer",
+                        package = %name,
+                        error = ?e,
+                        "Composer upstream error, serving stale metadata"
+                    );
+                    let mut response = serve(data);
+                    response.headers_mut().insert(
+                        header::HeaderName::from_static("x-nora-stale"),
+                        HeaderValue::from_static("true"),
+                    );
+                    return response;
+                }
+            }
+            tracing::debug!(error = ?e, package = %name, "composer metadata upstream error");
+            StatusCode::BAD_GATEWAY.into_response()
+        }
+    }
+}
+
+// ============================================================================
+// Dists
+// ============================================================================
+
+/// Where a proxied dist comes from, per the upstream metadata.
+#[derive(Debug, PartialEq)]
+struct UpstreamDist {
+    version: String,
+    url: String,
+    time: Option<String>,
+}
+
+/// Undo Composer 2 metadata minification: each entry lists only the fields
+/// that changed from the previous one, `"__unset"` marking a removed field.
+fn expand_minified(versions: &[Value]) -> Vec<Map<String, Value>> {
+    let mut expanded = Vec::with_capacity(versions.len());
+    let mut current = Map::new();
+    for entry in versions.iter().filter_map(Value::as_object) {
+        for (k, v) in entry {
+            if v == "__unset" {
+                current.remove(k);
+            } else {
+                current.insert(k.clone(), v.clone());
+            }
+        }
+        expanded.push(current.clone());
+    }
+    expanded
+}
+
+/// The version of `name` whose dist is served under `reference`.
+fn find_dist(data: &[u8], name: &str, reference: &str) -> Option<UpstreamDist> {
+    let root: Value = serde_json::from_slice(data).ok()?;
+    let versions = root.get("packages")?.get(name)?.as_array()?;
+    let entries = if root.get("minified").is_some() {
+        expand_minified(versions)
+    } else {
+        versions
+            .iter()
+            .filter_map(|v| v.as_object().cloned())
+            .collect()
+    };
+    entries.into_iter().find_map(|entry| {
+        let dist = entry.get("dist")?.as_object()?;
+        if dist_reference(dist) != reference {
+            return None;
+        }
+        Some(UpstreamDist {
+            version: entry.get("version")?.as_str()?.to_string(),
+            url: dist.get("url")?.as_str()?.to_string(),
+            time: entry
+                .get("time")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+        })
+    })
+}
+
+/// Look `reference` up in the cached metadata. A miss refreshes a stale (or
+/// never fetched) file once; a fresh one is what clients were served, so an
+/// unknown reference is not worth an upstream round trip.
+async fn upstream_dist(state: &AppState, name: &str, reference: &str) -> Option<UpstreamDist> {
+    let mut refresh = Vec::new();
+    for dev in [false, true] {
+        let key = metadata_key(name, dev);
+        if let Ok(data) = state.storage.get(&key).await {
+            if let Some(dist) = find_dist(&data, name, reference) {
+                return Some(dist);
+            }
+            let fresh = state.storage.stat(&key).await.is_some_and(|meta| {
+                is_within_ttl(meta.modified, state.config.composer.metadata_ttl)
+            });
+            if fresh {
+                continue;
+            }
+        }
+        refresh.push(dev);
+    }
+    for dev in refresh {
+        if let Ok(data) = fetch_metadata(state, name, dev).await {
+            if let Some(dist) = find_dist(&data, name, reference) {
+                return Some(dist);
+            }
+        }
+    }
+    None
+}
+
+/// Serve a dist from storage (hosted, or a proxied one already cached).
+async fn serve_local_dist(
+    state: &AppState,
+    headers: &axum::http::HeaderMap,
+    name: &str,
+    key: &str,
+) -> Option<Response> {
+    let outcome = state.storage.get_verified(key).await.ok()?;
+    use nora_registry::verified::{verified_body, GateOutcome};
+    let data = match outcome {
+        GateOutcome::Verified(blob) => verified_body(blob),
+        GateOutcome::Unpinned(blob) => blob.into_inner(),
+    };
+    // Range request: 206 Partial Content, or 416 past the end (#657).
+    if let Some(response) = crate::registry::range::range_response(
+        &state.storage,
+        &[key],
+        headers,
+        data.len() as u64,
+        ZIP_CONTENT_TYPE,
+        &[],
+    )
+    .await
+    {
+        if response.status() == StatusCode::PARTIAL_CONTENT {
+            state.metrics.record_download("composer");
+        }
+        return Some(response);
+    }
+    state.metrics.record_download("composer");
+    state.metrics.record_cache_hit("composer");
+    state.activity.push(ActivityEntry::new(
+        ActionType::Pull,
+        name.to_string(),
+        RegistryType::Composer,
+        "LOCAL",
+    ));
+    Some(
+        (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, ZIP_CONTENT_TYPE),
+                (header::ACCEPT_RANGES, "bytes"),
+            ],
+            data,
+        )
+            .into_response(),
+    )
+}
+
+/// GET /composer/dists/{vendor}/{package}/{reference}.zip
+async fn download_dist(
+    State(state): State<AppState>,
+    headers: axum::http::HeaderMap,
+    Path((vendor, package, file)): Path<(String, String, String)>,
+) -> Response {
+    let Some(reference) = file.strip_suffix(".zip") else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    if !valid_name_part(&vendor) || !valid_name_part(&package) || !valid_reference(reference) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let name = format!("{vendor}/{package}");
+    let key = dist_key(&name, reference);
+    if validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    // Hosted: operator-owned, never fetched upstream even when the dist is
+    // missing. A listing error counts as hosted — fail closed.
+    let hosted = match state.storage.list(&hosted_prefix(&name)).await {
+        Ok(keys) => !keys.is_empty(),
+        Err(_) => true,
+    };
+    let engine = &state.curation().curation_engine;
+    let internal = crate::curation::is_internal_namespace(engine, RegistryType::Composer, &name);
+    if hosted || internal {
+        if let Some(response) = serve_local_dist(&state, &headers, &name, &key).await {
+            return response;
+        }
+        if hosted {
+            return StatusCode::NOT_FOUND.into_response();
+        }
+        // #733: an internal-namespace package with no local copy is never proxied upstream.
+        return crate::curation::check_namespace_isolation(engine, RegistryType::Composer, &name)
+            .unwrap_or_else(|| StatusCode::NOT_FOUND.into_response());
+    }
+
+    let Some(proxy_url) = state.config.composer.proxy.as_deref() else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let Some(dist) = upstream_dist(&state, &name, reference).await else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let publish_date = if state.config.server.trust_upstream_dates {
+        dist.time
+            .as_deref()
+            .and_then(crate::curation::parse_iso8601_to_unix)
+    } else {
+        None
+    };
+    if let Some(response) = crate::curation::check_download(
+        engine,
+        state.bypass_token().as_deref(),
+        &headers,
+        RegistryType::Composer,
+        &name,
+        Some(&dist.version),
+        publish_date,
+    ) {
+        return response;
+    }
+
+    // Packagist dists live on GitHub/GitLab; the proxy credential is only
+    // ever sent to the proxy itself.
+    let auth = if dist.url.starts_with(proxy_url.trim_end_matches('/')) {
+        expose_opt(&state.config.composer.proxy_auth)
+    } else {
+        None
+    };
+    crate::registry::repo_proxy_download(
+        &state,
+        "composer",
+        RegistryType::Composer,
+        format!("{name}@{}", dist.version),
+        key,
+        dist.url,
+        auth,
+        state.config.composer.proxy_timeout,
+        state.config.composer.metadata_ttl,
+        true,
+        ZIP_CONTENT_TYPE,
+    )
+    .await
+}
+
+/// Version per dist reference of `name`, from hosted records and cached
+/// upstream metadata — for the UI, which lists stored dists.
+pub(crate) async fn dist_versions(
+    storage: &crate::storage::Storage,
+    name: &str,
+) -> HashMap<String, String> {
+    let mut versions = HashMap::new();
+    for dev in [false, true] {
+        let Ok(data) = storage.get(&metadata_key(name, dev)).await else {
+            continue;
+        };
+        let Ok(root) = serde_json::from_slice::<Value>(&data) else {
+            continue;
+        };
+        let Some(entries) = root["packages"][name].as_array() else {
+            continue;
+        };
+        for entry in expand_minified(entries) {
+            if let (Some(dist), Some(version)) = (
+                entry.get("dist").and_then(Value::as_object),
+                entry.get("version").and_then(Value::as_str),
+            ) {
+                versions.insert(dist_reference(dist), version.to_string());
+            }
+        }
+    }
+    for record in read_json_sidecars::<HostedVersion>(storage, &hosted_prefix(name))
+        .await
+        .unwrap_or_default()
+    {
+        versions.insert(record.shasum, record.version);
+    }
+    versions
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{
+        body_bytes, create_test_context, create_test_context_with_config, send, send_with_headers,
+        TestContext,
+    };
+    use axum::http::Method;
+    use std::io::Write;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// A GitHub-style dist: everything under one top-level directory.
+    fn dist_zip(manifest: Value) -> Vec<u8> {
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        let mut w = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for (member, data) in [
+            ("acme-lib-1a2b3c/composer.json", manifest.to_string()),
+            (
+                "acme-lib-1a2b3c/src/Lib.php",
+                "<?php\nnamespace Acme;\nclass Lib {}\n".to_string(),
+            ),
+        ] {
+            w.start_file(member, options).unwrap();
+            w.write_all(data.as_bytes()).unwrap();
+        }
+        w.finish().unwrap().into_inner()
+    }
+
+    fn manifest(name: &str) -> Value {
+        json!({
+            "name": name,
+            "description": "Test library",
+            "type": "library",
+            "license": "MIT",
+            "require": {"php": ">=8.1"},
+            "autoload": {"psr-4": {"Acme\\": "src/"}},
+            "repositories": [{"type": "vcs", "url": "https://git.example.com/acme/lib"}],
+        })
+    }
+
+    async fn get_json(ctx: &TestContext, uri: &str) -> Value {
+        let resp = send(&ctx.app, Method::GET, uri, "").await;
+        assert_eq!(resp.status(), StatusCode::OK, "{uri}");
+        serde_json::from_slice(&body_bytes(resp).await).unwrap()
+    }
+
+    #[test]
+    fn test_valid_name_part() {
+        for ok in ["monolog", "symfony", "php-http", "acme_lib", "a.b", "x1"] {
+            assert!(valid_name_part(ok), "{ok}");
+        }
+        for bad in ["", "Monolog", "-x", "x-", "a..b", "a/b", ".hidden"] {
+            assert!(!valid_name_part(bad), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_split_metadata_file() {
+        assert_eq!(
+            split_metadata_file("monolog.json"),
+            Some(("monolog", false))
+        );
+        assert_eq!(
+            split_metadata_file("monolog~dev.json"),
+            Some(("monolog", true))
+        );
+        assert_eq!(split_metadata_file("monolog"), None);
+    }
+
+    #[test]
+    fn test_read_composer_json_root_or_wrapped() {
+        let wrapped = dist_zip(manifest("acme/lib"));
+        assert_eq!(read_composer_json(&wrapped).unwrap()["name"], "acme/lib");
+
+        let options = zip::write::SimpleFileOptions::default();
+        let mut w = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        w.start_file("composer.json", options).unwrap();
+        w.write_all(br#"{"name": "acme/root"}"#).unwrap();
+        w.start_file("vendor/x/y/composer.json", options).unwrap();
+        w.write_all(br#"{"name": "x/y"}"#).unwrap();
+        let root = w.finish().unwrap().into_inner();
+        assert_eq!(read_composer_json(&root).unwrap()["name"], "acme/root");
+
+        assert!(read_composer_json(b"not a zip").is_err());
+    }
+
+    #[test]
+    fn test_expand_minified() {
+        let versions = json!([
+            {"version": "2.0.0", "license": ["MIT"], "dist": {"reference": "b"}},
+            {"version": "1.0.0", "dist": {"reference": "a"}},
+            {"version": "0.9.0", "license": "__unset"},
+        ]);
+        let expanded = expand_minified(versions.as_array().unwrap());
+        assert_eq!(expanded[1]["license"], json!(["MIT"]));
+        assert_eq!(expanded[2]["dist"]["reference"], "a");
+        assert!(!expanded[2].contains_key("license"));
+    }
+
+    #[tokio::test]
+    async fn test_composer_packages_json() {
+        let ctx = create_test_context();
+        let root = get_json(&ctx, "/composer/packages.json").await;
+        assert!(root["metadata-url"]
+            .as_str()
+            .unwrap()
+            .ends_with("/composer/p2/%package%.json"));
+        assert!(
+            root.get("available-packages").is_none(),
+            "a proxy can resolve any name"
+        );
+
+        let ctx = create_test_context_with_config(|cfg| cfg.composer.proxy = None);
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/composer/packages/acme/lib/1.0.0",
+            dist_zip(manifest("acme/lib")),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let root = get_json(&ctx, "/composer/packages.json").await;
+        assert_eq!(root["available-packages"], json!(["acme/lib"]));
+    }
+
+    #[tokio::test]
+    async fn test_composer_upload_metadata_download_delete() {
+        let ctx = create_test_context();
+        let dist = dist_zip(manifest("acme/lib"));
+        let shasum = hex::encode(sha1::Sha1::digest(&dist));
+
+        for version in ["1.0.0", "dev-main"] {
+            let resp = send(
+                &ctx.app,
+                Method::PUT,
+                &format!("/composer/packages/acme/lib/{version}"),
+                dist.clone(),
+            )
+            .await;
+            assert_eq!(resp.status(), StatusCode::CREATED, "{version}");
+        }
+
+        let meta = get_json(&ctx, "/composer/p2/acme/lib.json").await;
+        let versions = meta["packages"]["acme/lib"].as_array().unwrap();
+        assert_eq!(versions.len(), 1, "branches are served from ~dev");
+        let entry = &versions[0];
+        assert_eq!(entry["version"], "1.0.0");
+        assert_eq!(entry["require"]["php"], ">=8.1");
+        assert!(
+            entry.get("repositories").is_none(),
+            "root-only field dropped"
+        );
+        assert_eq!(entry["dist"]["type"], "zip");
+        assert_eq!(entry["dist"]["shasum"], shasum.as_str());
+        let url = entry["dist"]["url"].as_str().unwrap();
+        assert!(url.ends_with(&format!("/composer/dists/acme/lib/{shasum}.zip")));
+
+        let dev = get_json(&ctx, "/composer/p2/acme/lib~dev.json").await;
+        assert_eq!(dev["packages"]["acme/lib"][0]["version"], "dev-main");
+
+        let path = format!("/composer/dists/acme/lib/{shasum}.zip");
+        let resp = send(&ctx.app, Method::GET, &path, "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers()[header::CONTENT_TYPE], ZIP_CONTENT_TYPE);
+        assert_eq!(&body_bytes(resp).await[..], &dist[..]);
+
+        // Versions are immutable.
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/composer/packages/acme/lib/1.0.0",
+            dist.clone(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        // The dist is shared with dev-main, so it survives the first delete.
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/composer/packages/acme/lib/1.0.0",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        let meta = get_json(&ctx, "/composer/p2/acme/lib.json").await;
+        assert_eq!(meta["packages"]["acme/lib"], json!([]));
+        let resp = send(&ctx.app, Method::GET, &path, "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/composer/packages/acme/lib/dev-main",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        let resp = send(&ctx.app, Method::GET, &path, "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/composer/packages/acme/lib/dev-main",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_composer_upload_rejects_invalid_dist() {
+        let ctx = create_test_context();
+        let mut versioned = manifest("acme/lib");
+        versioned["version"] = json!("2.0.0");
+        for (uri, body) in [
+            ("/composer/packages/acme/lib/1.0.0", b"junk".to_vec()),
+            // composer.json names another package.
+            (
+                "/composer/packages/acme/lib/1.0.0",
+                dist_zip(manifest("acme/other")),
+            ),
+            // composer.json pins another version.
+            ("/composer/packages/acme/lib/1.0.0", dist_zip(versioned)),
+            (
+                "/composer/packages/Acme/lib/1.0.0",
+                dist_zip(manifest("acme/lib")),
+            ),
+        ] {
+            let resp = send(&ctx.app, Method::PUT, uri, body).await;
+            assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{uri}");
+        }
+
+        let ctx = create_test_context_with_config(|cfg| cfg.composer.max_file_size = 16);
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/composer/packages/acme/lib/1.0.0",
+            dist_zip(manifest("acme/lib")),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    async fn proxy_context(
+        upstream: &MockServer,
+        customize: impl FnOnce(&mut crate::config::Config),
+    ) -> TestContext {
+        let url = upstream.uri();
+        create_test_context_with_config(move |cfg| {
+            cfg.composer.proxy = Some(url);
+            customize(cfg);
+        })
+    }
+
+    #[tokio::test]
+    async fn test_composer_proxy_rewrites_dist_urls() {
+        let upstream = MockServer::start().await;
+        let dist = dist_zip(manifest("monolog/monolog"));
+        let metadata = json!({
+            "minified": "composer/2.0",
+            "packages": {"monolog/monolog": [
+                {
+                    "name": "monolog/monolog",
+                    "version": "3.7.0",
+                    "time": "2024-06-28T09:40:51+00:00",
+                    "dist": {"type": "zip", "url": format!("{}/zipball/f4393b6", upstream.uri()), "reference": "f4393b6", "shasum": ""},
+                    "source": {"type": "git", "url": "https://github.com/Seldaek/monolog.git", "reference": "f4393b6"},
+                },
+                {
+                    "version": "3.6.0",
+                    "dist": {"type": "zip", "url": format!("{}/zipball/4b18b21", upstream.uri()), "reference": "4b18b21", "shasum": ""},
+                },
+            ]},
+        });
+        Mock::given(method("GET"))
+            .and(path("/p2/monolog/monolog.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&metadata))
+            .expect(1)
+            .mount(&upstream)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/zipball/4b18b21"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(dist.clone()))
+            .expect(1)
+            .mount(&upstream)
+            .await;
+        let ctx = proxy_context(&upstream, |_| {}).await;
+
+        let meta = get_json(&ctx, "/composer/p2/monolog/monolog.json").await;
+        let versions = meta["packages"]["monolog/monolog"].as_array().unwrap();
+        for (entry, reference) in versions.iter().zip(["f4393b6", "4b18b21"]) {
+            let url = entry["dist"]["url"].as_str().unwrap();
+            assert!(
+                url.ends_with(&format!("/composer/dists/monolog/monolog/{reference}.zip")),
+                "{url}"
+            );
+        }
+        assert_eq!(
+            versions[0]["source"]["url"],
+            "https://github.com/Seldaek/monolog.git"
+        );
+        assert_eq!(meta["minified"], "composer/2.0");
+
+        for _ in 0..2 {
+            let resp = send(
+                &ctx.app,
+                Method::GET,
+                "/composer/dists/monolog/monolog/4b18b21.zip",
+                "",
+            )
+            .await;
+            assert_eq!(resp.status(), StatusCode::OK);
+            assert_eq!(&body_bytes(resp).await[..], &dist[..]);
+            // Second pull is served from the cache (the upstream mock expects one hit).
+            for _ in 0..50 {
+                if ctx
+                    .state
+                    .storage
+                    .stat("composer/dists/monolog/monolog/4b18b21.zip")
+                    .await
+                    .is_some()
+                {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        }
+        assert_eq!(
+            dist_versions(&ctx.state.storage, "monolog/monolog").await["4b18b21"],
+            "3.6.0"
+        );
+
+        // A reference absent from the metadata is not fetched anywhere.
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/composer/dists/monolog/monolog/deadbeef.zip",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_composer_hosted_package_never_proxied() {
+        let upstream = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"packages": {}})))
+            .expect(0)
+            .mount(&upstream)
+            .await;
+        let ctx = proxy_context(&upstream, |_| {}).await;
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/composer/packages/acme/lib/1.0.0",
+            dist_zip(manifest("acme/lib")),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let dev = get_json(&ctx, "/composer/p2/acme/lib~dev.json").await;
+        assert_eq!(dev["packages"]["acme/lib"], json!([]));
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/composer/dists/acme/lib/0123abcd.zip",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_composer_proxy_blocked_by_curation() {
+        let upstream = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"packages": {}})))
+            .expect(0)
+            .mount(&upstream)
+            .await;
+        let dir = tempfile::TempDir::new().unwrap();
+        let blocklist = dir.path().join("blocklist.json");
+        let rules = json!({
+            "version": 1,
+            "rules": [{"registry": "composer", "name": "evil/pkg", "version": "*", "reason": "known malware"}]
+        });
+        std::fs::write(&blocklist, rules.to_string()).unwrap();
+        let bl_path = blocklist.to_str().unwrap().to_string();
+        let ctx = proxy_context(&upstream, move |cfg| {
+            cfg.curation.mode = crate::config::CurationMode::Enforce;
+            cfg.curation.blocklist_path = Some(bl_path);
+        })
+        .await;
+
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::GET,
+            "/composer/p2/evil/pkg.json",
+            vec![],
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        assert_eq!(resp.headers()["x-nora-decision"], "blocked");
+    }
+}
@@ -17,16 +17,31 @@
 //!   GET /conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages/{pkg_id}/revisions/{prev}/files — list package files
 //!   GET /conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages/{pkg_id}/revisions/{prev}/files/{filename} — download package file (immutable)
 //!
+//!   GET /conan/v2/users/authenticate               — exchange an API token for a bearer token
+//!   GET /conan/v2/users/check_credentials          — verify the bearer token
+//!   PUT /conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/files/{filename} — upload recipe file
+//!   PUT /conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages/{pkg_id}/revisions/{prev}/files/{filename} — upload package file
+//!   DELETE /conan/v2/conans/{name}/{ver}/{user}/{chan} — remove all hosted recipe revisions
+//!   DELETE /conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev} — remove a hosted recipe revision
+//!   DELETE /conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages — remove all hosted packages of a revision
+//!   DELETE /conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages/{pkg_id}/revisions/{prev} — remove a hosted package revision
+//!
 //! ## Client config
 //!   conan remote add nora http://nora:4000/conan
+//!   conan remote login nora <user> -p <nra_ API token>
 //!
 //! ## Design
 //! - All revision-scoped files are immutably cached (revisions never change)
 //! - Metadata endpoints (latest, revisions, search) use TTL-based caching
+//! - Uploaded revisions are recorded in a hosted index (`hosted/revisions.json` +
+//!   `hosted/latest.json`) next to the proxy cache. A recipe or package with a
+//!   hosted index is answered from NORA alone — its revision list never mixes in
+//!   ConanCenter revisions — and its files skip the curation and quarantine gates
 //! - Uses a single wildcard route + dispatcher pattern (deep URL hierarchy)
 
 use crate::activity_log::{ActionType, ActivityEntry};
 use crate::audit::AuditEntry;
+use crate::auth::{enforce_namespace_scope, AuthenticatedUser, NamespaceAuthority};
 use crate::registry::{
     circuit_open_response, proxy_fetch, proxy_fetch_conditional, proxy_fetch_text, read_validators,
     write_validators, ProxyError, Revalidation, Validators,
@@ -39,9 +54,11 @@ use axum::{
     extract::{Path, Query, State},
     http::{header, HeaderMap, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
-    Router,
+    routing::{delete, get},
+    Extension, Router,
 };
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
 
@@ -53,16 +70,24 @@ pub fn routes() -> Router<AppState> {
         .route("/conan/v2/ping", get(ping))
         // Search
         .route("/conan/v2/conans/search", get(search))
+        // Login (bearer token exchange) used by `conan remote login` / `conan upload`
+        .route("/conan/v2/users/authenticate", get(authenticate))
+        .route("/conan/v2/users/check_credentials", get(check_credentials))
         // Recipe file download (deepest, most specific — must be before shorter patterns)
         .route(
             "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages/{pkg_id}/revisions/{prev}/files/{filename}",
-            get(package_file_download),
+            get(package_file_download).put(package_file_upload),
         )
         // Package file listing
         .route(
             "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages/{pkg_id}/revisions/{prev}/files",
             get(package_file_list),
         )
+        // Package revision delete
+        .route(
+            "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages/{pkg_id}/revisions/{prev}",
+            delete(package_revision_delete),
+        )
         // Package revision list
         .route(
             "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages/{pkg_id}/revisions",
@@ -73,16 +98,26 @@ pub fn routes() -> Router<AppState> {
             "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages/{pkg_id}/latest",
             get(package_latest),
         )
+        // Delete all packages of a recipe revision
+        .route(
+            "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/packages",
+            delete(packages_delete),
+        )
         // Recipe file download
         .route(
             "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/files/{filename}",
-            get(recipe_file_download),
+            get(recipe_file_download).put(recipe_file_upload),
         )
         // Recipe file listing
         .route(
             "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}/files",
             get(recipe_file_list),
         )
+        // Recipe revision delete
+        .route(
+            "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions/{rrev}",
+            delete(recipe_revision_delete),
+        )
         // Recipe revision list
         .route(
             "/conan/v2/conans/{name}/{ver}/{user}/{chan}/revisions",
@@ -93,6 +128,11 @@ pub fn routes() -> Router<AppState> {
             "/conan/v2/conans/{name}/{ver}/{user}/{chan}/latest",
             get(recipe_latest),
         )
+        // Delete every hosted revision of a recipe
+        .route(
+            "/conan/v2/conans/{name}/{ver}/{user}/{chan}",
+            delete(recipe_delete),
+        )
 }
 
 // ── Ping ──────────────────────────────────────────────────────────────
@@ -197,6 +237,11 @@ async fn recipe_latest(
     }
 
     let ref_str = format!("{}/{}/{}/{}", name, ver, user, chan);
+    if let Some(response) =
+        hosted_index_response(&state, &hosted_recipe_prefix(&ref_str), "latest.json").await
+    {
+        return response;
+    }
     let storage_key = format!("conan/{}/latest.json", ref_str);
 
     // Eager cache read — preserve data for serve-stale fallback
@@ -253,6 +298,11 @@ async fn recipe_revisions(
     }
 
     let ref_str = format!("{}/{}/{}/{}", name, ver, user, chan);
+    if let Some(response) =
+        hosted_index_response(&state, &hosted_recipe_prefix(&ref_str), "revisions.json").await
+    {
+        return response;
+    }
     let storage_key = format!("conan/{}/revisions.json", ref_str);
 
     let cached_data = state.storage.get(&storage_key).await.ok();
@@ -388,7 +438,9 @@ async fn recipe_file_download(
         crate::curation::RegistryType::Conan,
         &name,
     );
-    if !internal {
+    // Revisions published to NORA are operator-owned too: no curation or quarantine.
+    let hosted = is_hosted_revision(&state.storage, &hosted_recipe_prefix(&ref_str), &rrev).await;
+    if !internal && !hosted {
         if let Some(response) = crate::curation::check_download(
             &state.curation().curation_engine,
             state.bypass_token().as_deref(),
@@ -413,14 +465,16 @@ async fn recipe_file_download(
             GateOutcome::Unpinned(blob) => blob.into_inner(),
         };
         // Curation integrity
-        if let Some(response) = crate::curation::verify_integrity(
-            &state.curation().curation_engine,
-            crate::curation::RegistryType::Conan,
-            &name,
-            Some(&ver),
-            &data,
-        ) {
-            return response;
+        if !hosted {
+            if let Some(response) = crate::curation::verify_integrity(
+                &state.curation().curation_engine,
+                crate::curation::RegistryType::Conan,
+                &name,
+                Some(&ver),
+                &data,
+            ) {
+                return response;
+            }
         }
 
         let (q_mode, q_secs) = crate::digest_quarantine::resolve_global(
@@ -437,16 +491,18 @@ async fn recipe_file_download(
                 .as_deref()
                 .or(state.config.curation.quarantine_ttl.as_deref()),
         );
-        if let Some(resp) = crate::digest_quarantine::proxy_gate_dated(
-            &state.digest_store,
-            "conan",
-            &data,
-            &q_mode,
-            q_secs,
-            "cache",
-            publish_date,
-        ) {
-            return resp;
+        if !hosted {
+            if let Some(resp) = crate::digest_quarantine::proxy_gate_dated(
+                &state.digest_store,
+                "conan",
+                &data,
+                &q_mode,
+                q_secs,
+                "cache",
+                publish_date,
+            ) {
+                return resp;
+            }
         }
 
         // Range request: 206 Partial Content, or 416 when the client asks past the
@@ -480,6 +536,11 @@ async fn recipe_file_download(
         return with_binary(data.to_vec());
     }
 
+    // A hosted revision lives in NORA alone — a missing file is never fetched upstream.
+    if hosted {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
     // #733: an internal-namespace recipe with no local copy is never proxied upstream.
     if internal {
         return crate::curation::check_namespace_isolation(
@@ -584,6 +645,15 @@ async fn package_latest(
     }
 
     let ref_str = format!("{}/{}/{}/{}", name, ver, user, chan);
+    if let Some(response) = hosted_index_response(
+        &state,
+        &hosted_package_prefix(&ref_str, &rrev, &pkg_id),
+        "latest.json",
+    )
+    .await
+    {
+        return response;
+    }
     let storage_key = format!(
         "conan/{}/revisions/{}/packages/{}/latest.json",
         ref_str, rrev, pkg_id
@@ -657,6 +727,15 @@ async fn package_revisions(
     }
 
     let ref_str = format!("{}/{}/{}/{}", name, ver, user, chan);
+    if let Some(response) = hosted_index_response(
+        &state,
+        &hosted_package_prefix(&ref_str, &rrev, &pkg_id),
+        "revisions.json",
+    )
+    .await
+    {
+        return response;
+    }
     let storage_key = format!(
         "conan/{}/revisions/{}/packages/{}/revisions.json",
         ref_str, rrev, pkg_id
@@ -816,7 +895,14 @@ async fn package_file_download(
         crate::curation::RegistryType::Conan,
         &name,
     );
-    if !internal {
+    // Revisions published to NORA are operator-owned too: no curation or quarantine.
+    let hosted = is_hosted_revision(
+        &state.storage,
+        &hosted_package_prefix(&ref_str, &rrev, &pkg_id),
+        &prev,
+    )
+    .await;
+    if !internal && !hosted {
         if let Some(response) = crate::curation::check_download(
             &state.curation().curation_engine,
             state.bypass_token().as_deref(),
@@ -844,14 +930,16 @@ async fn package_file_download(
             GateOutcome::Unpinned(blob) => blob.into_inner(),
         };
         // Curation integrity
-        if let Some(response) = crate::curation::verify_integrity(
-            &state.curation().curation_engine,
-            crate::curation::RegistryType::Conan,
-            &name,
-            Some(&ver),
-            &data,
-        ) {
-            return response;
+        if !hosted {
+            if let Some(response) = crate::curation::verify_integrity(
+                &state.curation().curation_engine,
+                crate::curation::RegistryType::Conan,
+                &name,
+                Some(&ver),
+                &data,
+            ) {
+                return response;
+            }
         }
 
         let (q_mode, q_secs) = crate::digest_quarantine::resolve_global(
@@ -868,16 +956,18 @@ async fn package_file_download(
                 .as_deref()
                 .or(state.config.curation.quarantine_ttl.as_deref()),
         );
-        if let Some(resp) = crate::digest_quarantine::proxy_gate_dated(
-            &state.digest_store,
-            "conan",
-            &data,
-            &q_mode,
-            q_secs,
-            "cache",
-            publish_date,
-        ) {
-            return resp;
+        if !hosted {
+            if let Some(resp) = crate::digest_quarantine::proxy_gate_dated(
+                &state.digest_store,
+                "conan",
+                &data,
+                &q_mode,
+                q_secs,
+                "cache",
+                publish_date,
+            ) {
+                return resp;
+            }
         }
 
         // Range request: 206 Partial Content, or 416 when the client asks past the
@@ -911,6 +1001,11 @@ async fn package_file_download(
         return with_binary(data.to_vec());
     }
 
+    // A hosted revision lives in NORA alone — a missing file is never fetched upstream.
+    if hosted {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
     // #733: an internal-namespace package with no local copy is never proxied upstream.
     if internal {
         return crate::curation::check_namespace_isolation(
@@ -993,185 +1088,782 @@ async fn package_file_download(
     }
 }
 
-// ── Shared fetch helpers ──────────────────────────────────────────────
+// ── Login ─────────────────────────────────────────────────────────────
 
-/// Fetch JSON from upstream, cache with TTL (mutable content).
-async fn fetch_and_cache_json(
-    state: &AppState,
-    url: &str,
-    storage_key: &str,
-    artifact: &str,
-    cached: Option<Bytes>,
-) -> Response {
-    // Revalidate stale metadata with a conditional request when enabled and fall
-    // back to a full fetch otherwise. Empty validators ⇒ no conditional headers ⇒
-    // always a 200, which is also how the first fetch captures validators.
-    let validators = if state.config.conan.revalidate {
-        read_validators(&state.storage, storage_key)
-            .await
-            .unwrap_or_default()
-    } else {
-        Validators::default()
-    };
-    let had_validators = validators.is_some();
+/// `conan remote login` trades Basic credentials for a bearer token that the
+/// client then sends on every request. A NORA API token already is a bearer
+/// credential, so it is handed straight back; an htpasswd password has no
+/// bearer form and is refused.
+async fn authenticate(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    if !state.config.auth.enabled {
+        return with_text("anonymous".to_string());
+    }
+    match bearer_credential(&state, &headers) {
+        Some(token) => with_text(token),
+        None => (
+            StatusCode::UNAUTHORIZED,
+            "Conan login requires a NORA API token as the password",
+        )
+            .into_response(),
+    }
+}
 
-    match proxy_fetch_conditional(
-        &state.http_client,
-        url,
-        Duration::from_secs(state.config.conan.proxy_timeout),
-        expose_opt(&state.config.conan.proxy_auth),
-        &validators,
-        &state.circuit_breaker,
-        RegistryType::Conan,
-    )
-    .await
-    {
-        // Upstream unchanged — serve the cached body and bump its freshness so we
-        // do not revalidate again until the next TTL window. No body downloaded.
-        Ok(Revalidation::NotModified) => {
-            let body = match state.storage.get(storage_key).await {
-                Ok(b) => b,
-                // Body vanished under us — use the eagerly-read copy, or 502.
-                Err(_) => match cached {
-                    Some(b) => b,
-                    None => return StatusCode::BAD_GATEWAY.into_response(),
-                },
-            };
-            crate::metrics::PROXY_UPSTREAM_304_TOTAL
-                .with_label_values(&["conan"])
-                .inc();
-            crate::metrics::PROXY_REVALIDATION_BYTES_SAVED_TOTAL
-                .with_label_values(&["conan"])
-                .inc_by(body.len() as u64);
-            state.metrics.record_download("conan");
-            state.metrics.record_cache_hit("conan");
-            // Re-put bumps the file mtime (the freshness source) without download.
-            let storage = state.storage.clone();
-            let key_clone = storage_key.to_string();
-            let bump = body.clone();
-            tokio::spawn(async move {
-                let _ = storage.put(&key_clone, &bump).await;
-            });
-            with_json(body.to_vec())
-        }
-        // New body — cache the raw bytes first, then persist the fresh validators.
-        Ok(Revalidation::Modified { body, validators }) => {
-            state.metrics.record_download("conan");
-            state.metrics.record_cache_miss("conan");
-            state.activity.push(ActivityEntry::new(
-                ActionType::ProxyFetch,
-                artifact.to_string(),
-                crate::registry_type::RegistryType::Conan,
-                "PROXY",
-            ));
-            state
-                .audit
-                .log(AuditEntry::new("proxy_fetch", "api", "", "conan", ""));
+/// The auth middleware has already validated the credentials by the time this runs.
+async fn check_credentials(Extension(user): Extension<AuthenticatedUser>) -> Response {
+    with_text(user.0)
+}
 
-            let raw = Bytes::from(body);
-            let storage = state.storage.clone();
-            let key_clone = storage_key.to_string();
-            let raw_for_cache = raw.clone();
-            tokio::spawn(async move {
-                if let Err(e) = storage.put(&key_clone, &raw_for_cache).await {
-                    tracing::warn!(key = %key_clone, error = ?e, "conan proxy: failed to cache metadata");
-                    return;
-                }
-                write_validators(&storage, &key_clone, &validators).await;
-            });
-            with_json(raw.to_vec())
-        }
-        Err(ProxyError::CircuitOpen(reg)) => circuit_open_response(&reg),
-        Err(ProxyError::NotFound) => StatusCode::NOT_FOUND.into_response(),
-        Err(e) => {
-            if had_validators {
-                crate::metrics::PROXY_REVALIDATION_ERRORS_TOTAL
-                    .with_label_values(&["conan"])
-                    .inc();
-            }
-            if let Some(ref data) = cached {
-                if state.config.conan.serve_stale {
-                    tracing::warn!(
-                        registry = "conan",
-                        artifact,
-                        error = ?e,
-                        "Conan upstream error, serving stale metadata"
-                    );
-                    return (
-                        StatusCode::OK,
-                        [
-                            (
-                                header::CONTENT_TYPE,
-                                HeaderValue::from_static("application/json"),
-                            ),
-                            (
-                                header::CACHE_CONTROL,
-                                HeaderValue::from_static("public, max-age=0, must-revalidate"),
-                            ),
-                            (
-                                axum::http::header::HeaderName::from_static("x-nora-stale"),
-                                HeaderValue::from_static("true"),
-                            ),
-                        ],
-                        data.to_vec(),
-                    )
-                        .into_response();
-                }
-            }
-            tracing::debug!(url, error = ?e, "Conan upstream error");
-            StatusCode::BAD_GATEWAY.into_response()
-        }
+/// The credential Conan should present as `Authorization: Bearer …`.
+fn bearer_credential(state: &AppState, headers: &HeaderMap) -> Option<String> {
+    let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
+    if let Some(token) = value.strip_prefix("Bearer ") {
+        return Some(token.to_string());
     }
+    let decoded = STANDARD.decode(value.strip_prefix("Basic ")?).ok()?;
+    let credentials = String::from_utf8(decoded).ok()?;
+    let (_, password) = credentials.split_once(':')?;
+    state
+        .tokens
+        .as_ref()?
+        .verify_token(password)
+        .ok()
+        .map(|_| password.to_string())
 }
 
-/// Fetch JSON from upstream, cache immutably (content scoped to revision, never changes).
-async fn fetch_and_cache_immutable_json(
-    state: &AppState,
-    url: &str,
-    storage_key: &str,
-    artifact: &str,
-) -> Response {
-    match proxy_fetch_text(
-        &state.http_client,
-        url,
-        Duration::from_secs(state.config.conan.proxy_timeout),
-        expose_opt(&state.config.conan.proxy_auth),
-        None,
-        &state.circuit_breaker,
-        RegistryType::Conan,
+// ── Upload (hosted revisions) ─────────────────────────────────────────
+
+/// One entry of a Conan revision list (`{"revision": …, "time": …}`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct RevisionEntry {
+    revision: String,
+    time: String,
+}
+
+/// Hosted revision index of a recipe (`ref_str` is `name/ver/user/chan`).
+fn hosted_recipe_prefix(ref_str: &str) -> String {
+    format!("conan/{}/hosted", ref_str)
+}
+
+/// Hosted revision index of a binary package under a recipe revision.
+fn hosted_package_prefix(ref_str: &str, rrev: &str, pkg_id: &str) -> String {
+    format!(
+        "conan/{}/revisions/{}/packages/{}/hosted",
+        ref_str, rrev, pkg_id
     )
-    .await
-    {
-        Ok(text) => {
-            state.metrics.record_download("conan");
-            state.metrics.record_cache_miss("conan");
-            state.activity.push(ActivityEntry::new(
-                ActionType::ProxyFetch,
-                artifact.to_string(),
-                crate::registry_type::RegistryType::Conan,
-                "PROXY",
-            ));
-            state
-                .audit
-                .log(AuditEntry::new("proxy_fetch", "api", "", "conan", ""));
+}
 
-            state.spawn_cache_immutable(
-                "conan",
-                storage_key.to_string(),
-                Bytes::from(text.clone()),
-            );
-            with_json(text.into_bytes())
-        }
-        Err(ProxyError::CircuitOpen(reg)) => circuit_open_response(&reg),
-        Err(ProxyError::NotFound) => StatusCode::NOT_FOUND.into_response(),
-        Err(e) => {
-            tracing::debug!(url, error = ?e, "Conan upstream error");
-            StatusCode::BAD_GATEWAY.into_response()
-        }
-    }
+/// Serve `latest.json` / `revisions.json` from a hosted index, if there is one.
+async fn hosted_index_response(state: &AppState, prefix: &str, file: &str) -> Option<Response> {
+    let data = state
+        .storage
+        .get(&format!("{}/{}", prefix, file))
+        .await
+        .ok()?;
+    state.metrics.record_download("conan");
+    state.metrics.record_cache_hit("conan");
+    Some(with_json(data.to_vec()))
 }
 
-// ── Helpers ──────────────────────────────────────────────────────────
+/// Revisions in a hosted index, newest first.
+async fn hosted_revisions(storage: &crate::storage::Storage, prefix: &str) -> Vec<RevisionEntry> {
+    #[derive(Deserialize)]
+    struct Index {
+        revisions: Vec<RevisionEntry>,
+    }
+    storage
+        .get(&format!("{}/revisions.json", prefix))
+        .await
+        .ok()
+        .and_then(|data| serde_json::from_slice::<Index>(&data).ok())
+        .map(|index| index.revisions)
+        .unwrap_or_default()
+}
+
+async fn is_hosted_revision(storage: &crate::storage::Storage, prefix: &str, rev: &str) -> bool {
+    hosted_revisions(storage, prefix)
+        .await
+        .iter()
+        .any(|entry| entry.revision == rev)
+}
+
+/// Rewrite a hosted index: the revision list, then the `latest` pointer. An empty
+/// list removes the index, handing the reference back to the proxy. Callers hold
+/// the reference's publish lock.
+async fn write_hosted_index(
+    storage: &crate::storage::Storage,
+    prefix: &str,
+    reference: &str,
+    revisions: &[RevisionEntry],
+) -> Result<(), crate::storage::StorageError> {
+    let revisions_key = format!("{}/revisions.json", prefix);
+    let latest_key = format!("{}/latest.json", prefix);
+    let Some(latest) = revisions.first() else {
+        storage.delete(&latest_key).await.ok();
+        storage.delete(&revisions_key).await.ok();
+        return Ok(());
+    };
+    let index = serde_json::json!({ "reference": reference, "revisions": revisions });
+    storage
+        .put(
+            &revisions_key,
+            &serde_json::to_vec(&index).unwrap_or_default(),
+        )
+        .await?;
+    storage
+        .put(&latest_key, &serde_json::to_vec(latest).unwrap_or_default())
+        .await
+}
+
+/// Add a revision to the front of a list (newest first); a known revision keeps
+/// its original time and position. Returns whether the list changed.
+fn record_revision(revisions: &mut Vec<RevisionEntry>, rev: &str, time: String) -> bool {
+    if revisions.iter().any(|entry| entry.revision == rev) {
+        return false;
+    }
+    revisions.insert(
+        0,
+        RevisionEntry {
+            revision: rev.to_string(),
+            time,
+        },
+    );
+    true
+}
+
+/// Revision timestamp in the format ConanCenter uses (`2024-01-15T10:30:00.000+0000`).
+fn revision_time_now() -> String {
+    chrono::Utc::now()
+        .format("%Y-%m-%dT%H:%M:%S%.3f%z")
+        .to_string()
+}
+
+/// Store one file of a revision and rebuild that revision's `files.json`.
+///
+/// `dir` is the revision directory (the parent of `files/`). Revision contents
+/// are content-addressed, so re-uploading an identical file is a no-op while a
+/// different body for an existing file is a conflict.
+async fn store_revision_file(
+    state: &AppState,
+    dir: &str,
+    filename: &str,
+    body: &Bytes,
+) -> Result<(), Response> {
+    let file_key = format!("{}/files/{}", dir, filename);
+    if let Ok(existing) = state.storage.get(&file_key).await {
+        if existing[..] == body[..] {
+            return Ok(());
+        }
+        return Err((
+            StatusCode::CONFLICT,
+            format!("{} already exists in this revision", filename),
+        )
+            .into_response());
+    }
+    if state.storage.put(&file_key, body).await.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR.into_response());
+    }
+
+    let files_prefix = format!("{}/files/", dir);
+    let files: serde_json::Map<String, serde_json::Value> = state
+        .storage
+        .list(&files_prefix)
+        .await
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|key| key.strip_prefix(&files_prefix))
+        .filter(|name| is_valid_filename(name))
+        .map(|name| (name.to_string(), serde_json::json!({})))
+        .collect();
+    let listing = serde_json::json!({ "files": files });
+    if state
+        .storage
+        .put(
+            &format!("{}/files.json", dir),
+            &serde_json::to_vec(&listing).unwrap_or_default(),
+        )
+        .await
+        .is_err()
+    {
+        let _ = state.storage.delete(&file_key).await;
+        return Err(StatusCode::INTERNAL_SERVER_ERROR.into_response());
+    }
+    Ok(())
+}
+
+/// Add `rev` to a hosted index (no-op when already listed).
+async fn publish_revision(
+    state: &AppState,
+    prefix: &str,
+    reference: &str,
+    rev: &str,
+) -> Result<(), Response> {
+    let mut revisions = hosted_revisions(&state.storage, prefix).await;
+    if record_revision(&mut revisions, rev, revision_time_now())
+        && write_hosted_index(&state.storage, prefix, reference, &revisions)
+            .await
+            .is_err()
+    {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR.into_response());
+    }
+    Ok(())
+}
+
+/// Conan's display form of a reference: `name/ver@user/chan`.
+fn display_ref(name: &str, ver: &str, user: &str, chan: &str) -> String {
+    format!("{}/{}@{}/{}", name, ver, user, chan)
+}
+
+fn record_push(state: &AppState, artifact: String) {
+    state.metrics.record_upload("conan");
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &artifact, "conan", ""));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        artifact,
+        crate::registry_type::RegistryType::Conan,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("conan");
+}
+
+fn record_delete(state: &AppState, artifact: &str) {
+    state
+        .audit
+        .log(AuditEntry::new("delete", "api", artifact, "conan", ""));
+    state.repo_index.invalidate("conan");
+}
+
+async fn recipe_file_upload(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Path((name, ver, user, chan, rrev, filename)): Path<(
+        String,
+        String,
+        String,
+        String,
+        String,
+        String,
+    )>,
+    body: Bytes,
+) -> Response {
+    if !is_valid_ref(&name)
+        || !is_valid_ref(&ver)
+        || !is_valid_ref(&user)
+        || !is_valid_ref(&chan)
+        || !is_valid_revision(&rrev)
+        || !is_valid_filename(&filename)
+    {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    if body.is_empty() {
+        return (StatusCode::BAD_REQUEST, "Empty file").into_response();
+    }
+
+    // Enforce OIDC namespace_scope on the recipe reference (#583).
+    let ref_str = format!("{}/{}/{}/{}", name, ver, user, chan);
+    if enforce_namespace_scope(&authority, &ref_str).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    let lock = state.publish_lock(&format!("conan/{}", ref_str));
+    let _guard = lock.lock().await;
+
+    let dir = format!("conan/{}/revisions/{}", ref_str, rrev);
+    if let Err(response) = store_revision_file(&state, &dir, &filename, &body).await {
+        return response;
+    }
+    let reference = display_ref(&name, &ver, &user, &chan);
+    if let Err(response) =
+        publish_revision(&state, &hosted_recipe_prefix(&ref_str), &reference, &rrev).await
+    {
+        return response;
+    }
+
+    record_push(&state, format!("{}#{} {}", reference, rrev, filename));
+    StatusCode::CREATED.into_response()
+}
+
+async fn package_file_upload(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Path((name, ver, user, chan, rrev, pkg_id, prev, filename)): Path<(
+        String,
+        String,
+        String,
+        String,
+        String,
+        String,
+        String,
+        String,
+    )>,
+    body: Bytes,
+) -> Response {
+    if !is_valid_ref(&name)
+        || !is_valid_ref(&ver)
+        || !is_valid_ref(&user)
+        || !is_valid_ref(&chan)
+        || !is_valid_revision(&rrev)
+        || !is_valid_revision(&pkg_id)
+        || !is_valid_revision(&prev)
+        || !is_valid_filename(&filename)
+    {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    if body.is_empty() {
+        return (StatusCode::BAD_REQUEST, "Empty file").into_response();
+    }
+
+    let ref_str = format!("{}/{}/{}/{}", name, ver, user, chan);
+    if enforce_namespace_scope(&authority, &ref_str).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    // Binaries may be built from a proxied recipe revision, so the recipe
+    // revision itself need not be hosted. One lock per reference covers both.
+    let lock = state.publish_lock(&format!("conan/{}", ref_str));
+    let _guard = lock.lock().await;
+
+    let dir = format!(
+        "conan/{}/revisions/{}/packages/{}/revisions/{}",
+        ref_str, rrev, pkg_id, prev
+    );
+    if let Err(response) = store_revision_file(&state, &dir, &filename, &body).await {
+        return response;
+    }
+    let reference = format!(
+        "{}#{}:{}",
+        display_ref(&name, &ver, &user, &chan),
+        rrev,
+        pkg_id
+    );
+    if let Err(response) = publish_revision(
+        &state,
+        &hosted_package_prefix(&ref_str, &rrev, &pkg_id),
+        &reference,
+        &prev,
+    )
+    .await
+    {
+        return response;
+    }
+
+    record_push(&state, format!("{}#{} {}", reference, prev, filename));
+    StatusCode::CREATED.into_response()
+}
+
+// ── Revision delete (hosted only) ─────────────────────────────────────
+
+/// Delete every key under `prefix`.
+async fn delete_prefix(storage: &crate::storage::Storage, prefix: &str) {
+    for key in storage.list(prefix).await.unwrap_or_default() {
+        let _ = storage.delete(&key).await;
+    }
+}
+
+/// Remove the hosted packages of a recipe revision (every package id with a hosted index).
+async fn delete_hosted_packages(
+    storage: &crate::storage::Storage,
+    ref_str: &str,
+    rrev: &str,
+) -> usize {
+    let packages_prefix = format!("conan/{}/revisions/{}/packages/", ref_str, rrev);
+    let mut pkg_ids: Vec<String> = storage
+        .list(&packages_prefix)
+        .await
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|key| {
+            key.strip_prefix(&packages_prefix)?
+                .strip_suffix("/hosted/revisions.json")
+        })
+        .map(str::to_string)
+        .collect();
+    pkg_ids.sort();
+    pkg_ids.dedup();
+    for pkg_id in &pkg_ids {
+        let prefix = hosted_package_prefix(ref_str, rrev, pkg_id);
+        for entry in hosted_revisions(storage, &prefix).await {
+            delete_prefix(
+                storage,
+                &format!(
+                    "{}{}/revisions/{}/",
+                    packages_prefix, pkg_id, entry.revision
+                ),
+            )
+            .await;
+        }
+        delete_prefix(storage, &format!("{}/", prefix)).await;
+    }
+    pkg_ids.len()
+}
+
+/// Remove hosted recipe revisions (all of them when `only` is `None`). Returns
+/// `false` when there was nothing hosted to remove.
+async fn delete_recipe_revisions(
+    state: &AppState,
+    ref_str: &str,
+    reference: &str,
+    only: Option<&str>,
+) -> Result<bool, Response> {
+    let prefix = hosted_recipe_prefix(ref_str);
+    let mut revisions = hosted_revisions(&state.storage, &prefix).await;
+    let (removed, kept): (Vec<_>, Vec<_>) = revisions
+        .drain(..)
+        .partition(|entry| only.is_none_or(|rev| entry.revision == rev));
+    if removed.is_empty() {
+        return Ok(false);
+    }
+    // Unlist first so a half-deleted revision is never advertised.
+    if write_hosted_index(&state.storage, &prefix, reference, &kept)
+        .await
+        .is_err()
+    {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR.into_response());
+    }
+    for entry in &removed {
+        delete_hosted_packages(&state.storage, ref_str, &entry.revision).await;
+        delete_prefix(
+            &state.storage,
+            &format!("conan/{}/revisions/{}/", ref_str, entry.revision),
+        )
+        .await;
+    }
+    Ok(true)
+}
+
+async fn recipe_delete(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Path((name, ver, user, chan)): Path<(String, String, String, String)>,
+) -> Response {
+    if !is_valid_ref(&name) || !is_valid_ref(&ver) || !is_valid_ref(&user) || !is_valid_ref(&chan) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let ref_str = format!("{}/{}/{}/{}", name, ver, user, chan);
+    if enforce_namespace_scope(&authority, &ref_str).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    let lock = state.publish_lock(&format!("conan/{}", ref_str));
+    let _guard = lock.lock().await;
+
+    let reference = display_ref(&name, &ver, &user, &chan);
+    match delete_recipe_revisions(&state, &ref_str, &reference, None).await {
+        Ok(true) => {
+            record_delete(&state, &reference);
+            StatusCode::OK.into_response()
+        }
+        Ok(false) => StatusCode::NOT_FOUND.into_response(),
+        Err(response) => response,
+    }
+}
+
+async fn recipe_revision_delete(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Path((name, ver, user, chan, rrev)): Path<(String, String, String, String, String)>,
+) -> Response {
+    if !is_valid_ref(&name)
+        || !is_valid_ref(&ver)
+        || !is_valid_ref(&user)
+        || !is_valid_ref(&chan)
+        || !is_valid_revision(&rrev)
+    {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let ref_str = format!("{}/{}/{}/{}", name, ver, user, chan);
+    if enforce_namespace_scope(&authority, &ref_str).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    let lock = state.publish_lock(&format!("conan/{}", ref_str));
+    let _guard = lock.lock().await;
+
+    let reference = display_ref(&name, &ver, &user, &chan);
+    match delete_recipe_revisions(&state, &ref_str, &reference, Some(&rrev)).await {
+        Ok(true) => {
+            record_delete(&state, &format!("{}#{}", reference, rrev));
+            StatusCode::OK.into_response()
+        }
+        Ok(false) => StatusCode::NOT_FOUND.into_response(),
+        Err(response) => response,
+    }
+}
+
+async fn packages_delete(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Path((name, ver, user, chan, rrev)): Path<(String, String, String, String, String)>,
+) -> Response {
+    if !is_valid_ref(&name)
+        || !is_valid_ref(&ver)
+        || !is_valid_ref(&user)
+        || !is_valid_ref(&chan)
+        || !is_valid_revision(&rrev)
+    {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let ref_str = format!("{}/{}/{}/{}", name, ver, user, chan);
+    if enforce_namespace_scope(&authority, &ref_str).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    let lock = state.publish_lock(&format!("conan/{}", ref_str));
+    let _guard = lock.lock().await;
+
+    if delete_hosted_packages(&state.storage, &ref_str, &rrev).await == 0 {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    record_delete(
+        &state,
+        &format!("{}#{}:*", display_ref(&name, &ver, &user, &chan), rrev),
+    );
+    StatusCode::OK.into_response()
+}
+
+async fn package_revision_delete(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Path((name, ver, user, chan, rrev, pkg_id, prev)): Path<(
+        String,
+        String,
+        String,
+        String,
+        String,
+        String,
+        String,
+    )>,
+) -> Response {
+    if !is_valid_ref(&name)
+        || !is_valid_ref(&ver)
+        || !is_valid_ref(&user)
+        || !is_valid_ref(&chan)
+        || !is_valid_revision(&rrev)
+        || !is_valid_revision(&pkg_id)
+        || !is_valid_revision(&prev)
+    {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let ref_str = format!("{}/{}/{}/{}", name, ver, user, chan);
+    if enforce_namespace_scope(&authority, &ref_str).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    let lock = state.publish_lock(&format!("conan/{}", ref_str));
+    let _guard = lock.lock().await;
+
+    let prefix = hosted_package_prefix(&ref_str, &rrev, &pkg_id);
+    let mut revisions = hosted_revisions(&state.storage, &prefix).await;
+    let before = revisions.len();
+    revisions.retain(|entry| entry.revision != prev);
+    if revisions.len() == before {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let reference = format!(
+        "{}#{}:{}",
+        display_ref(&name, &ver, &user, &chan),
+        rrev,
+        pkg_id
+    );
+    if write_hosted_index(&state.storage, &prefix, &reference, &revisions)
+        .await
+        .is_err()
+    {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    delete_prefix(
+        &state.storage,
+        &format!(
+            "conan/{}/revisions/{}/packages/{}/revisions/{}/",
+            ref_str, rrev, pkg_id, prev
+        ),
+    )
+    .await;
+
+    record_delete(&state, &format!("{}#{}", reference, prev));
+    StatusCode::OK.into_response()
+}
+
+// ── Shared fetch helpers ──────────────────────────────────────────────
+
+/// Fetch JSON from upstream, cache with TTL (mutable content).
+async fn fetch_and_cache_json(
+    state: &AppState,
+    url: &str,
+    storage_key: &str,
+    artifact: &str,
+    cached: Option<Bytes>,
+) -> Response {
+    // Revalidate stale metadata with a conditional request when enabled and fall
+    // back to a full fetch otherwise. Empty validators ⇒ no conditional headers ⇒
+    // always a 200, which is also how the first fetch captures validators.
+    let validators = if state.config.conan.revalidate {
+        read_validators(&state.storage, storage_key)
+            .await
+            .unwrap_or_default()
+    } else {
+        Validators::default()
+    };
+    let had_validators = validators.is_some();
+
+    match proxy_fetch_conditional(
+        &state.http_client,
+        url,
+        Duration::from_secs(state.config.conan.proxy_timeout),
+        expose_opt(&state.config.conan.proxy_auth),
+        &validators,
+        &state.circuit_breaker,
+        RegistryType::Conan,
+    )
+    .await
+    {
+        // Upstream unchanged — serve the cached body and bump its freshness so we
+        // do not revalidate again until the next TTL window. No body downloaded.
+        Ok(Revalidation::NotModified) => {
+            let body = match state.storage.get(storage_key).await {
+                Ok(b) => b,
+                // Body vanished under us — use the eagerly-read copy, or 502.
+                Err(_) => match cached {
+                    Some(b) => b,
+                    None => return StatusCode::BAD_GATEWAY.into_response(),
+                },
+            };
+            crate::metrics::PROXY_UPSTREAM_304_TOTAL
+                .with_label_values(&["conan"])
+                .inc();
+            crate::metrics::PROXY_REVALIDATION_BYTES_SAVED_TOTAL
+                .with_label_values(&["conan"])
+                .inc_by(body.len() as u64);
+            state.metrics.record_download("conan");
+            state.metrics.record_cache_hit("conan");
+            // Re-put bumps the file mtime (the freshness source) without download.
+            let storage = state.storage.clone();
+            let key_clone = storage_key.to_string();
+            let bump = body.clone();
+            tokio::spawn(async move {
+                let _ = storage.put(&key_clone, &bump).await;
+            });
+            with_json(body.to_vec())
+        }
+        // New body — cache the raw bytes first, then persist the fresh validators.
+        Ok(Revalidation::Modified { body, validators }) => {
+            state.metrics.record_download("conan");
+            state.metrics.record_cache_miss("conan");
+            state.activity.push(ActivityEntry::new(
+                ActionType::ProxyFetch,
+                artifact.to_string(),
+                crate::registry_type::RegistryType::Conan,
+                "PROXY",
+            ));
+            state
+                .audit
+                .log(AuditEntry::new("proxy_fetch", "api", "", "conan", ""));
+
+            let raw = Bytes::from(body);
+            let storage = state.storage.clone();
+            let key_clone = storage_key.to_string();
+            let raw_for_cache = raw.clone();
+            tokio::spawn(async move {
+                if let Err(e) = storage.put(&key_clone, &raw_for_cache).await {
+                    tracing::warn!(key = %key_clone, error = ?e, "conan proxy: failed to cache metadata");
+                    return;
+                }
+                write_validators(&storage, &key_clone, &validators).await;
+            });
+            with_json(raw.to_vec())
+        }
+        Err(ProxyError::CircuitOpen(reg)) => circuit_open_response(&reg),
+        Err(ProxyError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            if had_validators {
+                crate::metrics::PROXY_REVALIDATION_ERRORS_TOTAL
+                    .with_label_values(&["conan"])
+                    .inc();
+            }
+            if let Some(ref data) = cached {
+                if state.config.conan.serve_stale {
+                    tracing::warn!(
+                        registry = "conan",
+                        artifact,
+                        error = ?e,
+                        "Conan upstream error, serving stale metadata"
+                    );
+                    return (
+                        StatusCode::OK,
+                        [
+                            (
+                                header::CONTENT_TYPE,
+                                HeaderValue::from_static("application/json"),
+                            ),
+                            (
+                                header::CACHE_CONTROL,
+                                HeaderValue::from_static("public, max-age=0, must-revalidate"),
+                            ),
+                            (
+                                axum::http::header::HeaderName::from_static("x-nora-stale"),
+                                HeaderValue::from_static("true"),
+                            ),
+                        ],
+                        data.to_vec(),
+                    )
+                        .into_response();
+                }
+            }
+            tracing::debug!(url, error = ?e, "Conan upstream error");
+            StatusCode::BAD_GATEWAY.into_response()
+        }
+    }
+}
+
+/// Fetch JSON from upstream, cache immutably (content scoped to revision, never changes).
+async fn fetch_and_cache_immutable_json(
+    state: &AppState,
+    url: &str,
+    storage_key: &str,
+    artifact: &str,
+) -> Response {
+    match proxy_fetch_text(
+        &state.http_client,
+        url,
+        Duration::from_secs(state.config.conan.proxy_timeout),
+        expose_opt(&state.config.conan.proxy_auth),
+        None,
+        &state.circuit_breaker,
+        RegistryType::Conan,
+    )
+    .await
+    {
+        Ok(text) => {
+            state.metrics.record_download("conan");
+            state.metrics.record_cache_miss("conan");
+            state.activity.push(ActivityEntry::new(
+                ActionType::ProxyFetch,
+                artifact.to_string(),
+                crate::registry_type::RegistryType::Conan,
+                "PROXY",
+            ));
+            state
+                .audit
+                .log(AuditEntry::new("proxy_fetch", "api", "", "conan", ""));
+
+            state.spawn_cache_immutable(
+                "conan",
+                storage_key.to_string(),
+                Bytes::from(text.clone()),
+            );
+            with_json(text.into_bytes())
+        }
+        Err(ProxyError::CircuitOpen(reg)) => circuit_open_response(&reg),
+        Err(ProxyError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::debug!(url, error = ?e, "Conan upstream error");
+            StatusCode::BAD_GATEWAY.into_response()
+        }
+    }
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────
 
 /// Extract publish date from cached Conan recipe revision metadata.
 ///
@@ -1228,6 +1920,15 @@ fn with_json(data: Vec<u8>) -> Response {
         .into_response()
 }
 
+fn with_text(data: String) -> Response {
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, HeaderValue::from_static("text/plain"))],
+        data,
+    )
+        .into_response()
+}
+
 fn with_binary(data: Vec<u8>) -> Response {
     (
         StatusCode::OK,
@@ -1357,16 +2058,70 @@ mod tests {
             .as_secs();
         assert!(!is_within_ttl(now - 7200, 3600));
     }
+
+    #[test]
+    fn test_record_revision_newest_first() {
+        let mut revisions = Vec::new();
+        assert!(record_revision(&mut revisions, "aaa", "t1".to_string()));
+        assert!(record_revision(&mut revisions, "bbb", "t2".to_string()));
+        assert_eq!(revisions[0].revision, "bbb");
+        assert_eq!(revisions[1].revision, "aaa");
+    }
+
+    #[test]
+    fn test_record_revision_known_keeps_time() {
+        let mut revisions = Vec::new();
+        record_revision(&mut revisions, "aaa", "t1".to_string());
+        record_revision(&mut revisions, "bbb", "t2".to_string());
+        assert!(!record_revision(&mut revisions, "aaa", "t3".to_string()));
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[1].time, "t1");
+    }
+
+    #[test]
+    fn test_revision_time_parses() {
+        let time = revision_time_now();
+        assert!(time.ends_with("+0000"), "{}", time);
+        assert!(crate::curation::parse_iso8601_to_unix(&time).is_some());
+    }
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod integration_tests {
     use crate::test_helpers::{
-        body_bytes, create_test_context_with_config, send, send_with_headers,
+        body_bytes, create_test_context_with_config, send, send_with_headers, TestContext,
     };
     use axum::http::{Method, StatusCode};
 
+    const RECIPE: &str = "/conan/v2/conans/mylib/1.0/acme/stable";
+
+    /// Conan enabled with an unreachable upstream, so anything not hosted fails loudly.
+    fn hosted_context() -> TestContext {
+        create_test_context_with_config(|cfg| {
+            cfg.conan.enabled = true;
+            cfg.conan.proxy = Some("http://127.0.0.1:1".to_string());
+            cfg.conan.proxy_timeout = 1;
+        })
+    }
+
+    async fn get_json(ctx: &TestContext, uri: &str) -> serde_json::Value {
+        let resp = send(&ctx.app, Method::GET, uri, "").await;
+        assert_eq!(resp.status(), StatusCode::OK, "GET {}", uri);
+        serde_json::from_slice(&body_bytes(resp).await).unwrap()
+    }
+
+    async fn upload_recipe(ctx: &TestContext, rrev: &str) {
+        for (file, body) in [
+            ("conanfile.py", "class MyLib(ConanFile):\n    pass\n"),
+            ("conanmanifest.txt", "1700000000\nconanfile.py: abc\n"),
+        ] {
+            let uri = format!("{}/revisions/{}/files/{}", RECIPE, rrev, file);
+            let resp = send(&ctx.app, Method::PUT, &uri, body).await;
+            assert_eq!(resp.status(), StatusCode::CREATED, "PUT {}", uri);
+        }
+    }
+
     #[tokio::test]
     async fn test_conan_disabled_returns_404() {
         let ctx = create_test_context_with_config(|cfg| {
@@ -1822,4 +2577,378 @@ mod integration_tests {
             .get();
         assert!(after > before, "a 304 revalidation must be recorded");
     }
+
+    #[tokio::test]
+    async fn test_conan_upload_recipe_and_package() {
+        let ctx = hosted_context();
+        upload_recipe(&ctx, "rrev1").await;
+
+        let revisions = get_json(&ctx, &format!("{}/revisions", RECIPE)).await;
+        assert_eq!(revisions["reference"], "mylib/1.0@acme/stable");
+        assert_eq!(revisions["revisions"][0]["revision"], "rrev1");
+        let latest = get_json(&ctx, &format!("{}/latest", RECIPE)).await;
+        assert_eq!(latest["revision"], "rrev1");
+        assert!(latest["time"].as_str().unwrap().ends_with("+0000"));
+        let files = get_json(&ctx, &format!("{}/revisions/rrev1/files", RECIPE)).await;
+        assert!(files["files"]["conanfile.py"].is_object());
+        assert!(files["files"]["conanmanifest.txt"].is_object());
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &format!("{}/revisions/rrev1/files/conanfile.py", RECIPE),
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(body_bytes(resp).await.starts_with(b"class MyLib"));
+
+        let pkg = format!("{}/revisions/rrev1/packages/pkg1", RECIPE);
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            &format!("{}/revisions/prev1/files/conan_package.tgz", pkg),
+            "binary",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let revisions = get_json(&ctx, &format!("{}/revisions", pkg)).await;
+        assert_eq!(revisions["reference"], "mylib/1.0@acme/stable#rrev1:pkg1");
+        assert_eq!(revisions["revisions"][0]["revision"], "prev1");
+        let latest = get_json(&ctx, &format!("{}/latest", pkg)).await;
+        assert_eq!(latest["revision"], "prev1");
+        let files = get_json(&ctx, &format!("{}/revisions/prev1/files", pkg)).await;
+        assert!(files["files"]["conan_package.tgz"].is_object());
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &format!("{}/revisions/prev1/files/conan_package.tgz", pkg),
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], b"binary");
+    }
+
+    #[tokio::test]
+    async fn test_conan_upload_same_file_idempotent_different_conflicts() {
+        let ctx = hosted_context();
+        upload_recipe(&ctx, "rrev1").await;
+        upload_recipe(&ctx, "rrev1").await;
+
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            &format!("{}/revisions/rrev1/files/conanfile.py", RECIPE),
+            "tampered",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        let revisions = get_json(&ctx, &format!("{}/revisions", RECIPE)).await;
+        assert_eq!(revisions["revisions"].as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_conan_upload_rejects_empty_and_invalid() {
+        let ctx = hosted_context();
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            &format!("{}/revisions/rrev1/files/conanfile.py", RECIPE),
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            &format!("{}/revisions/rr-ev/files/conanfile.py", RECIPE),
+            "x",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_conan_latest_tracks_newest_and_delete_rolls_back() {
+        let ctx = hosted_context();
+        upload_recipe(&ctx, "rrev1").await;
+        upload_recipe(&ctx, "rrev2").await;
+        assert_eq!(
+            get_json(&ctx, &format!("{}/latest", RECIPE)).await["revision"],
+            "rrev2"
+        );
+
+        let rrev2 = format!("{}/revisions/rrev2", RECIPE);
+        let resp = send(&ctx.app, Method::DELETE, &rrev2, "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            get_json(&ctx, &format!("{}/latest", RECIPE)).await["revision"],
+            "rrev1"
+        );
+        let revisions = get_json(&ctx, &format!("{}/revisions", RECIPE)).await;
+        assert_eq!(revisions["revisions"].as_array().unwrap().len(), 1);
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &format!("{}/files/conanfile.py", rrev2),
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_GATEWAY);
+
+        // Deleting the same revision again: nothing hosted left to remove.
+        let resp = send(&ctx.app, Method::DELETE, &rrev2, "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_conan_recipe_delete_hands_reference_back_to_proxy() {
+        let ctx = hosted_context();
+        upload_recipe(&ctx, "rrev1").await;
+        let pkg_file = format!(
+            "{}/revisions/rrev1/packages/pkg1/revisions/prev1/files/conan_package.tgz",
+            RECIPE
+        );
+        send(&ctx.app, Method::PUT, &pkg_file, "binary").await;
+
+        let resp = send(&ctx.app, Method::DELETE, RECIPE, "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(ctx
+            .state
+            .storage
+            .list("conan/mylib/1.0/acme/stable/")
+            .await
+            .unwrap()
+            .is_empty());
+
+        // No hosted index left: the revision list goes upstream (unreachable here).
+        let resp = send(&ctx.app, Method::GET, &format!("{}/revisions", RECIPE), "").await;
+        assert_eq!(resp.status(), StatusCode::BAD_GATEWAY);
+        let resp = send(&ctx.app, Method::DELETE, RECIPE, "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_conan_package_revision_delete() {
+        let ctx = hosted_context();
+        upload_recipe(&ctx, "rrev1").await;
+        let pkg = format!("{}/revisions/rrev1/packages/pkg1", RECIPE);
+        for prev in ["prev1", "prev2"] {
+            let uri = format!("{}/revisions/{}/files/conan_package.tgz", pkg, prev);
+            let resp = send(&ctx.app, Method::PUT, &uri, prev).await;
+            assert_eq!(resp.status(), StatusCode::CREATED);
+        }
+        assert_eq!(
+            get_json(&ctx, &format!("{}/latest", pkg)).await["revision"],
+            "prev2"
+        );
+
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            &format!("{}/revisions/prev2", pkg),
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            get_json(&ctx, &format!("{}/latest", pkg)).await["revision"],
+            "prev1"
+        );
+
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            &format!("{}/revisions/rrev1/packages", RECIPE),
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &format!("{}/revisions/prev1/files/conan_package.tgz", pkg),
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_GATEWAY);
+        // The recipe revision itself is untouched.
+        assert_eq!(
+            get_json(&ctx, &format!("{}/latest", RECIPE)).await["revision"],
+            "rrev1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_conan_delete_cached_revision_not_found() {
+        let ctx = hosted_context();
+        let key = "conan/zlib/1.2.13/_/_/revisions/abc123/files/conanfile.py";
+        ctx.state.storage.put(key, b"cached").await.unwrap();
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/conan/v2/conans/zlib/1.2.13/_/_/revisions/abc123",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        assert!(ctx.state.storage.get(key).await.is_ok());
+    }
+
+    /// A hosted revision is operator-owned: a curation block on the name does not
+    /// apply to it, while a proxied revision of the same name stays blocked.
+    #[tokio::test]
+    async fn test_conan_hosted_revision_skips_curation() {
+        let blocklist_dir = tempfile::TempDir::new().unwrap();
+        let blocklist_path = blocklist_dir.path().join("blocklist.json");
+        let blocklist = serde_json::json!({
+            "version": 1,
+            "rules": [{"registry": "conan", "name": "mylib", "version": "*", "reason": "test"}]
+        });
+        std::fs::write(&blocklist_path, serde_json::to_string(&blocklist).unwrap()).unwrap();
+        let bl_path = blocklist_path.to_str().unwrap().to_string();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.conan.enabled = true;
+            cfg.conan.proxy = Some("http://127.0.0.1:1".to_string());
+            cfg.conan.proxy_timeout = 1;
+            cfg.curation.mode = crate::config::CurationMode::Enforce;
+            cfg.curation.blocklist_path = Some(bl_path);
+        });
+        upload_recipe(&ctx, "rrev1").await;
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &format!("{}/revisions/rrev1/files/conanfile.py", RECIPE),
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        ctx.state
+            .storage
+            .put(
+                "conan/mylib/1.0/acme/stable/revisions/cafe/files/conanfile.py",
+                b"cached",
+            )
+            .await
+            .unwrap();
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &format!("{}/revisions/cafe/files/conanfile.py", RECIPE),
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_conan_upload_namespace_scope_enforced() {
+        use crate::auth::NamespaceAuthority;
+        use crate::config::ScopeEnforcement;
+        use axum::body::Bytes;
+        use axum::extract::{Path, State};
+        use axum::Extension;
+
+        let ctx = hosted_context();
+        let scoped = NamespaceAuthority::from_oidc_scope(
+            "ci",
+            &["mylib/**".to_string()],
+            ScopeEnforcement::Enforce,
+        );
+        let coords = |name: &str| {
+            Path((
+                name.to_string(),
+                "1.0".to_string(),
+                "_".to_string(),
+                "_".to_string(),
+                "rrev1".to_string(),
+                "conanfile.py".to_string(),
+            ))
+        };
+
+        let resp = super::recipe_file_upload(
+            State(ctx.state.clone()),
+            Extension(scoped.clone()),
+            coords("otherlib"),
+            Bytes::from_static(b"x"),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let resp = super::recipe_file_upload(
+            State(ctx.state.clone()),
+            Extension(scoped),
+            coords("mylib"),
+            Bytes::from_static(b"x"),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_conan_login_exchanges_api_token() {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+
+        let ctx = crate::test_helpers::create_test_context_with_auth_and_config(
+            &[("alice", "hunter2")],
+            |cfg| cfg.conan.enabled = true,
+        );
+        let token = ctx
+            .state
+            .tokens
+            .as_ref()
+            .unwrap()
+            .create_token("alice", 1, None, crate::tokens::Role::Write)
+            .unwrap();
+
+        let basic = format!("Basic {}", STANDARD.encode(format!("alice:{}", token)));
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::GET,
+            "/conan/v2/users/authenticate",
+            vec![("authorization", &basic)],
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], token.as_bytes());
+
+        let bearer = format!("Bearer {}", token);
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::GET,
+            "/conan/v2/users/check_credentials",
+            vec![("authorization", &bearer)],
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], b"alice");
+
+        // An htpasswd password has no bearer form.
+        let basic = format!("Basic {}", STANDARD.encode("alice:hunter2"));
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::GET,
+            "/conan/v2/users/authenticate",
+            vec![("authorization", &basic)],
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/conan/v2/users/check_credentials",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
 }
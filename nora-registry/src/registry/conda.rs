@@ -0,0 +1,1411 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+//! Conda channels — hosted channels with server-generated `repodata.json`,
+//! plus per-channel pull-through proxies (conda-forge, bioconda, …).
+//!
+//! Implements:
+//!   GET    /conda/{channel}/channeldata.json          — per-package summary across subdirs
+//!   GET    /conda/{channel}/{subdir}/repodata.json     — package index of one subdir (`.zst` too)
+//!   GET    /conda/{channel}/{subdir}/{file}            — download a `.conda` / `.tar.bz2` package
+//!   HEAD   /conda/{channel}/{subdir}/{file}            — existence/size check
+//!   PUT    /conda/{channel}/{subdir}/{file}            — upload (parses `info/index.json`, regenerates repodata)
+//!   DELETE /conda/{channel}/{subdir}/{file}            — remove a package (regenerates repodata)
+//!
+//! A client adds `http://nora:4000/conda/{channel}` as a channel; conda and
+//! mamba append `/{subdir}/repodata.json` (or `.zst`) for the platform subdir
+//! and `noarch`. Every publish/delete rebuilds the subdir's repodata and the
+//! channel's `channeldata.json` from per-package sidecars (`info/index.json`
+//! parsed once at upload, same shape as the rpm/deb handlers). A subdir with
+//! nothing published serves an empty repodata — conda treats a missing
+//! `noarch/repodata.json` as a broken channel.
+//!
+//! Channels listed in `[conda.proxies]` are read-only mirrors of an upstream
+//! channel: metadata is re-fetched after `metadata_ttl`, packages are cached
+//! forever, gated by curation on `name`/`version` and by the digest
+//! quarantine like every other proxied artifact.
+
+use crate::activity_log::{ActionType, ActivityEntry};
+use crate::audit::AuditEntry;
+use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
+use crate::config::registry::RepoProxyEntry;
+use crate::registry::{method_not_allowed, proxied_repo_conflict, read_json_sidecars};
+use crate::registry_type::RegistryType;
+use crate::validation::validate_storage_key;
+use crate::AppState;
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Extension, Router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sha2::Digest;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Read;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/conda/{channel}/channeldata.json", get(channeldata))
+        .route(
+            "/conda/{channel}/{subdir}/{file}",
+            get(download)
+                .head(check_exists)
+                .put(upload)
+                .delete(delete_package)
+                .fallback(|| async { method_not_allowed("GET, HEAD, PUT, DELETE") }),
+        )
+}
+
+/// Sidecar directory inside a channel. Not a valid subdir name, so it can
+/// never collide with a platform directory.
+const META_DIR: &str = ".nora-meta";
+const REPODATA: &str = "repodata.json";
+const REPODATA_ZST: &str = "repodata.json.zst";
+const CHANNELDATA: &str = "channeldata.json";
+/// `about.json` fields carried into `channeldata.json`.
+const ABOUT_FIELDS: &[&str] = &[
+    "summary",
+    "description",
+    "home",
+    "license",
+    "dev_url",
+    "doc_url",
+    "source_url",
+];
+/// Upper bound on `info/index.json` / `info/about.json`.
+const MAX_INFO_JSON: u64 = 1 << 20;
+/// Upper bound on the `info-*.tar.zst` member of a `.conda` package.
+const MAX_INFO_ARCHIVE: u64 = 64 << 20;
+/// Upper bound on what is unpacked while looking for `info/`. conda-build
+/// writes `info/` first, so a real package stops far below this.
+const MAX_SCAN_UNPACKED: u64 = 4 << 30;
+
+fn package_key(channel: &str, subdir: &str, file: &str) -> String {
+    format!("conda/{channel}/{subdir}/{file}")
+}
+
+fn sidecar_key(channel: &str, subdir: &str, file: &str) -> String {
+    format!("conda/{channel}/{META_DIR}/{subdir}/{file}.json")
+}
+
+fn meta_prefix(channel: &str) -> String {
+    format!("conda/{channel}/{META_DIR}/")
+}
+
+fn repodata_key(channel: &str, subdir: &str) -> String {
+    format!("conda/{channel}/{subdir}/{REPODATA}")
+}
+
+fn channeldata_key(channel: &str) -> String {
+    format!("conda/{channel}/{CHANNELDATA}")
+}
+
+/// Per-channel publish lock key: `channeldata.json` spans every subdir.
+fn lock_key(channel: &str) -> String {
+    format!("conda/{channel}/repodata")
+}
+
+fn valid_channel_name(channel: &str) -> bool {
+    !channel.is_empty()
+        && channel.len() <= 100
+        && channel.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && channel
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+}
+
+/// `noarch` or `{platform}-{arch}` (`linux-64`, `osx-arm64`, `win-64`, …).
+fn valid_subdir(subdir: &str) -> bool {
+    let part = |s: &str| {
+        !s.is_empty()
+            && s.bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+    };
+    subdir == "noarch"
+        || subdir
+            .split_once('-')
+            .is_some_and(|(p, a)| part(p) && part(a))
+}
+
+/// A file name that is safe as one storage-key segment.
+fn is_safe_file(file: &str) -> bool {
+    !file.is_empty()
+        && file.is_ascii()
+        && !file.starts_with('.')
+        && !file.contains(['/', '\\', '\0'])
+        && !file.contains("..")
+}
+
+pub(crate) fn is_package_file(file: &str) -> bool {
+    file.ends_with(".conda") || file.ends_with(".tar.bz2")
+}
+
+fn package_ext(file: &str) -> &'static str {
+    if file.ends_with(".conda") {
+        ".conda"
+    } else {
+        ".tar.bz2"
+    }
+}
+
+/// Split `{name}-{version}-{build}.conda` (or `.tar.bz2`) from the right —
+/// versions and build strings never contain `-`, names may.
+pub(crate) fn split_package_filename(file: &str) -> Option<(&str, &str, &str)> {
+    let stem = file
+        .strip_suffix(".conda")
+        .or_else(|| file.strip_suffix(".tar.bz2"))?;
+    let mut parts = stem.rsplitn(3, '-');
+    let (build, version, name) = (parts.next()?, parts.next()?, parts.next()?);
+    (!name.is_empty() && !version.is_empty() && !build.is_empty()).then_some((name, version, build))
+}
+
+fn valid_package_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 200
+        && name.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+}
+
+fn content_type(file: &str) -> &'static str {
+    if file.ends_with(".json") {
+        "application/json"
+    } else if file.ends_with(".zst") {
+        "application/zstd"
+    } else if file.ends_with(".tar.bz2") {
+        "application/x-bzip2"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// ============================================================================
+// Package parsing and repodata generation
+// ============================================================================
+
+/// One hosted package: its repodata record plus what `channeldata.json` needs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct PackageSidecar {
+    pub subdir: String,
+    pub file: String,
+    /// `info/index.json` plus `md5`, `sha256` and `size` — the repodata entry verbatim.
+    pub record: Map<String, Value>,
+    /// The [`ABOUT_FIELDS`] of `info/about.json`.
+    #[serde(default)]
+    pub about: BTreeMap<String, Value>,
+    pub upload_time: u64,
+}
+
+impl PackageSidecar {
+    /// Build time in milliseconds: the package `timestamp` (which conda-build
+    /// writes in ms, older builds in seconds), else the upload time.
+    fn build_time_ms(&self) -> i64 {
+        match self.record.get("timestamp").and_then(Value::as_i64) {
+            Some(ts) => normalize_timestamp_ms(ts),
+            None => self.upload_time as i64 * 1000,
+        }
+    }
+}
+
+/// conda's own rule: a timestamp past year 9999 in seconds is milliseconds.
+fn normalize_timestamp_ms(ts: i64) -> i64 {
+    if ts > 253_402_300_799 {
+        ts
+    } else {
+        ts * 1000
+    }
+}
+
+/// `info/index.json` and `info/about.json` of a package.
+struct PackageInfo {
+    index: Map<String, Value>,
+    about: BTreeMap<String, Value>,
+}
+
+/// Read `info/` from a `.conda` (zip holding `info-{stem}.tar.zst`) or a
+/// legacy `.tar.bz2` package.
+fn read_package_info(data: &[u8], file: &str) -> Result<PackageInfo, String> {
+    if !file.ends_with(".conda") {
+        return read_info_tar(bzip2::read::BzDecoder::new(data));
+    }
+    let mut zip = zip::ZipArchive::new(std::io::Cursor::new(data))
+        .map_err(|e| format!("not a .conda archive: {e}"))?;
+    let member = zip
+        .file_names()
+        .find(|n| n.starts_with("info-") && n.ends_with(".tar.zst"))
+        .map(str::to_string)
+        .ok_or("no info-*.tar.zst member")?;
+    let mut compressed = Vec::new();
+    zip.by_name(&member)
+        .map_err(|e| format!("{member}: {e}"))?
+        .take(MAX_INFO_ARCHIVE + 1)
+        .read_to_end(&mut compressed)
+        .map_err(|e| format!("{member}: {e}"))?;
+    if compressed.len() as u64 > MAX_INFO_ARCHIVE {
+        return Err(format!("{member} is too large"));
+    }
+    let decoder = ruzstd::decoding::StreamingDecoder::new(&compressed[..])
+        .map_err(|e| format!("{member}: {e}"))?;
+    read_info_tar(decoder)
+}
+
+fn read_info_tar(reader: impl Read) -> Result<PackageInfo, String> {
+    let mut archive = tar::Archive::new(reader);
+    let mut index = None;
+    let mut about = None;
+    let mut total: u64 = 0;
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("not a tar archive: {e}"))?
+    {
+        let mut entry = entry.map_err(|e| format!("not a tar archive: {e}"))?;
+        total = total.saturating_add(entry.size());
+        if total > MAX_SCAN_UNPACKED {
+            return Err(format!("package unpacks past {MAX_SCAN_UNPACKED} bytes"));
+        }
+        let path = entry.path().map_err(|e| e.to_string())?;
+        let path = path.to_string_lossy();
+        let slot = match path.strip_prefix("./").unwrap_or(&path) {
+            "info/index.json" => &mut index,
+            "info/about.json" => &mut about,
+            _ => continue,
+        };
+        if entry.size() > MAX_INFO_JSON {
+            return Err("info/ metadata is too large".to_string());
+        }
+        let mut content = Vec::new();
+        entry
+            .read_to_end(&mut content)
+            .map_err(|e| format!("info/: {e}"))?;
+        *slot = Some(content);
+        if index.is_some() && about.is_some() {
+            break;
+        }
+    }
+
+    let index = index.ok_or("package has no info/index.json")?;
+    let index: Map<String, Value> = serde_json::from_slice(&index)
+        .map_err(|e| format!("info/index.json is not a JSON object: {e}"))?;
+    // about.json is optional and informational — a malformed one is ignored.
+    let about = about
+        .and_then(|a| serde_json::from_slice::<Map<String, Value>>(&a).ok())
+        .map(|a| {
+            a.into_iter()
+                .filter(|(k, _)| ABOUT_FIELDS.contains(&k.as_str()))
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(PackageInfo { index, about })
+}
+
+/// Check `info/index.json` against the upload path: the file must be named
+/// `{name}-{version}-{build}{ext}` and built for the subdir it is uploaded to.
+/// Returns the package name.
+fn check_index<'a>(
+    index: &'a Map<String, Value>,
+    subdir: &str,
+    file: &str,
+) -> Result<&'a str, String> {
+    let field = |key: &str| {
+        index
+            .get(key)
+            .and_then(Value::as_str)
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| format!("info/index.json has no `{key}`"))
+    };
+    let (name, version, build) = (field("name")?, field("version")?, field("build")?);
+    if !valid_package_name(name) {
+        return Err(format!("`{name}` is not a valid package name"));
+    }
+    let expected = format!("{name}-{version}-{build}{}", package_ext(file));
+    if file != expected {
+        return Err(format!("File name must be {expected}"));
+    }
+    // Pre-4.x noarch packages carry `noarch` but no `subdir`.
+    let built_for = match index.get("subdir").and_then(Value::as_str) {
+        Some(s) => s,
+        None if index.contains_key("noarch") => "noarch",
+        None => return Err("info/index.json has no `subdir`".to_string()),
+    };
+    if built_for != subdir {
+        return Err(format!(
+            "Package is built for {built_for}, uploaded to {subdir}"
+        ));
+    }
+    Ok(name)
+}
+
+/// Render `repodata.json` for one subdir. Records are keyed by file name,
+/// `.conda` and `.tar.bz2` in their own maps, as conda-index writes them.
+fn render_repodata<'a>(
+    subdir: &str,
+    packages: impl Iterator<Item = &'a PackageSidecar>,
+) -> Vec<u8> {
+    let mut tar_bz2 = BTreeMap::new();
+    let mut conda = BTreeMap::new();
+    for package in packages {
+        let map = if package.file.ends_with(".conda") {
+            &mut conda
+        } else {
+            &mut tar_bz2
+        };
+        map.insert(package.file.as_str(), &package.record);
+    }
+    serde_json::to_vec(&json!({
+        "info": { "subdir": subdir },
+        "packages": tar_bz2,
+        "packages.conda": conda,
+        "removed": [],
+        "repodata_version": 1,
+    }))
+    .unwrap_or_default()
+}
+
+/// Render `channeldata.json`: per package, the subdirs it is built for and the
+/// version/about fields of its newest build (by build timestamp).
+fn render_channeldata(packages: &[PackageSidecar]) -> Vec<u8> {
+    let mut subdirs = BTreeSet::new();
+    let mut latest: BTreeMap<&str, (&PackageSidecar, BTreeSet<&str>)> = BTreeMap::new();
+    for package in packages {
+        let Some(name) = package.record.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        subdirs.insert(package.subdir.as_str());
+        let (newest, in_subdirs) = latest.entry(name).or_insert((package, BTreeSet::new()));
+        in_subdirs.insert(package.subdir.as_str());
+        if package.build_time_ms() > newest.build_time_ms() {
+            *newest = package;
+        }
+    }
+    let packages: BTreeMap<&str, Value> = latest
+        .into_iter()
+        .map(|(name, (newest, in_subdirs))| {
+            let mut entry: Map<String, Value> = newest
+                .about
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect();
+            entry.insert("subdirs".into(), json!(in_subdirs));
+            entry.insert(
+                "version".into(),
+                newest.record.get("version").cloned().unwrap_or(Value::Null),
+            );
+            entry.insert("timestamp".into(), json!(newest.build_time_ms() / 1000));
+            (name, Value::Object(entry))
+        })
+        .collect();
+    serde_json::to_vec(&json!({
+        "channeldata_version": 1,
+        "packages": packages,
+        "subdirs": subdirs,
+    }))
+    .unwrap_or_default()
+}
+
+/// Rebuild `{subdir}/repodata.json` (+ `.zst`) and `channeldata.json` of a
+/// hosted channel from its sidecars. Callers hold the channel publish lock.
+pub(crate) async fn regenerate_channel(
+    storage: &crate::storage::Storage,
+    channel: &str,
+    subdir: &str,
+) -> Result<(), String> {
+    let packages = read_json_sidecars::<PackageSidecar>(storage, &meta_prefix(channel)).await?;
+    let repodata = render_repodata(subdir, packages.iter().filter(|p| p.subdir == subdir));
+    let zst = ruzstd::encoding::compress_to_vec(
+        &repodata[..],
+        ruzstd::encoding::CompressionLevel::Fastest,
+    );
+    let key = repodata_key(channel, subdir);
+    storage
+        .put(&key, &repodata)
+        .await
+        .map_err(|e| format!("write {key}: {e}"))?;
+    storage
+        .put(&format!("{key}.zst"), &zst)
+        .await
+        .map_err(|e| format!("write {key}.zst: {e}"))?;
+    storage
+        .put(&channeldata_key(channel), &render_channeldata(&packages))
+        .await
+        .map_err(|e| format!("write {CHANNELDATA}: {e}"))
+}
+
+// ============================================================================
+// Hosted channels
+// ============================================================================
+
+fn metadata_response(data: Bytes, file: &str) -> Response {
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, content_type(file)),
+            // Rewritten in place on every publish — clients must revalidate.
+            (header::CACHE_CONTROL, "no-cache"),
+        ],
+        data,
+    )
+        .into_response()
+}
+
+/// Stored metadata, or `empty()` when nothing has been published yet.
+async fn hosted_metadata(
+    state: &AppState,
+    key: &str,
+    file: &str,
+    empty: impl FnOnce() -> Vec<u8>,
+) -> Response {
+    match state.storage.get(key).await {
+        Ok(data) => metadata_response(data, file),
+        Err(crate::storage::StorageError::NotFound) => {
+            metadata_response(Bytes::from(empty()), file)
+        }
+        Err(e) => {
+            tracing::error!(error = %e, key = %key, "conda: failed to read metadata");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+async fn channeldata(State(state): State<AppState>, Path(channel): Path<String>) -> Response {
+    if !state.config.conda.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    if !valid_channel_name(&channel) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    if let Some(entry) = state.config.conda.proxies.get(&channel) {
+        return proxy_metadata(&state, &channel, entry, CHANNELDATA.to_string()).await;
+    }
+    hosted_metadata(&state, &channeldata_key(&channel), CHANNELDATA, || {
+        render_channeldata(&[])
+    })
+    .await
+}
+
+async fn download(
+    State(state): State<AppState>,
+    headers: axum::http::HeaderMap,
+    Path((channel, subdir, file)): Path<(String, String, String)>,
+) -> Response {
+    if !state.config.conda.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    if !valid_channel_name(&channel) || !is_safe_file(&subdir) || !is_safe_file(&file) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    if let Some(entry) = state.config.conda.proxies.get(&channel) {
+        return if is_package_file(&file) {
+            proxy_package(&state, &headers, &channel, entry, &subdir, &file).await
+        } else {
+            proxy_metadata(&state, &channel, entry, format!("{subdir}/{file}")).await
+        };
+    }
+    if !valid_subdir(&subdir) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    match file.as_str() {
+        REPODATA => {
+            return hosted_metadata(&state, &repodata_key(&channel, &subdir), &file, || {
+                render_repodata(&subdir, std::iter::empty())
+            })
+            .await
+        }
+        REPODATA_ZST => {
+            let key = format!("{}.zst", repodata_key(&channel, &subdir));
+            return hosted_metadata(&state, &key, &file, || {
+                ruzstd::encoding::compress_to_vec(
+                    &render_repodata(&subdir, std::iter::empty())[..],
+                    ruzstd::encoding::CompressionLevel::Fastest,
+                )
+            })
+            .await;
+        }
+        // `current_repodata.json`, sharded repodata, …: a 404 makes clients
+        // fall back to the full repodata.json.
+        _ if !is_package_file(&file) => return StatusCode::NOT_FOUND.into_response(),
+        _ => {}
+    }
+    let key = package_key(&channel, &subdir, &file);
+    if validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    // 206 / 416 straight from the backend's ranged read. A partial body cannot
+    // be rehashed, so this skips the pin check `get_verified` does below and
+    // relies on the repodata `sha256` — the docker #657 precedent.
+    if let Some(meta) = state.storage.stat(&key).await {
+        if let Some(response) = crate::registry::range::range_response(
+            &state.storage,
+            &[&key],
+            &headers,
+            meta.size,
+            content_type(&file),
+            &[],
+        )
+        .await
+        {
+            if response.status() == StatusCode::PARTIAL_CONTENT {
+                state.metrics.record_download("conda");
+            }
+            return response;
+        }
+    }
+
+    match state.storage.get_verified(&key).await {
+        Ok(outcome) => {
+            use nora_registry::verified::{verified_body, GateOutcome};
+            let data = match outcome {
+                GateOutcome::Verified(blob) => verified_body(blob),
+                GateOutcome::Unpinned(blob) => blob.into_inner(),
+            };
+            state.metrics.record_download("conda");
+            state.activity.push(ActivityEntry::new(
+                ActionType::Pull,
+                format!("{channel}/{subdir}/{file}"),
+                RegistryType::Conda,
+                "LOCAL",
+            ));
+            (
+                StatusCode::OK,
+                [
+                    (header::CONTENT_TYPE, content_type(&file)),
+                    (header::ACCEPT_RANGES, "bytes"),
+                ],
+                data,
+            )
+                .into_response()
+        }
+        Err(crate::storage::StorageError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, key = %key, "conda: failed to read package");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+async fn check_exists(
+    State(state): State<AppState>,
+    headers: axum::http::HeaderMap,
+    Path((channel, subdir, file)): Path<(String, String, String)>,
+) -> Response {
+    if !state.config.conda.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    // Metadata (synthesized when empty) and proxied files answer as GET does.
+    if !is_package_file(&file) || state.config.conda.proxies.contains_key(&channel) {
+        return download(State(state), headers, Path((channel, subdir, file))).await;
+    }
+    let key = package_key(&channel, &subdir, &file);
+    if !valid_channel_name(&channel)
+        || !is_safe_file(&subdir)
+        || !is_safe_file(&file)
+        || validate_storage_key(&key).is_err()
+    {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    match state.storage.stat(&key).await {
+        Some(meta) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_LENGTH, meta.size.to_string()),
+                (header::CONTENT_TYPE, content_type(&file).to_string()),
+            ],
+        )
+            .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Validate the `{channel}/{subdir}/{file}` of an upload or delete.
+fn validate_package_path(channel: &str, subdir: &str, file: &str) -> Result<(), &'static str> {
+    if !valid_channel_name(channel) {
+        return Err("Invalid channel name");
+    }
+    if !valid_subdir(subdir) {
+        return Err("Invalid subdir: expected noarch or {platform}-{arch}");
+    }
+    if !is_safe_file(file) || !is_package_file(file) {
+        return Err("Only .conda and .tar.bz2 packages can be published");
+    }
+    Ok(())
+}
+
+/// PUT /conda/{channel}/{subdir}/{file} — publish a package.
+///
+/// Packages are immutable: re-uploading an existing file is a 409 (delete it
+/// first), as on anaconda.org.
+async fn upload(
+    State(state): State<AppState>,
+    Path((channel, subdir, file)): Path<(String, String, String)>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    body: Bytes,
+) -> Response {
+    if !state.config.conda.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    if state.config.conda.proxies.contains_key(&channel) {
+        return proxied_repo_conflict();
+    }
+    if let Err(msg) = validate_package_path(&channel, &subdir, &file) {
+        return (StatusCode::BAD_REQUEST, msg).into_response();
+    }
+    // Enforce OIDC namespace_scope on the channel name (#583).
+    if enforce_namespace_scope(&authority, &channel).is_err() {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if body.len() as u64 > state.config.conda.max_file_size {
+        return (
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "File too large. Max size: {} bytes",
+                state.config.conda.max_file_size
+            ),
+        )
+            .into_response();
+    }
+
+    let info = match read_package_info(&body, &file) {
+        Ok(info) => info,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("Not a valid conda package: {e}"),
+            )
+                .into_response()
+        }
+    };
+    let ident = match check_index(&info.index, &subdir, &file) {
+        Ok(name) => format!(
+            "{name}@{}",
+            info.index["version"].as_str().unwrap_or_default()
+        ),
+        Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
+    };
+    let mut record = info.index;
+    record.insert("md5".into(), json!(hex::encode(md5::Md5::digest(&body))));
+    record.insert(
+        "sha256".into(),
+        json!(hex::encode(sha2::Sha256::digest(&body))),
+    );
+    record.insert("size".into(), json!(body.len()));
+    let sidecar = PackageSidecar {
+        subdir: subdir.clone(),
+        file: file.clone(),
+        record,
+        about: info.about,
+        upload_time: now_secs(),
+    };
+    let sidecar = match serde_json::to_vec(&sidecar) {
+        Ok(j) => j,
+        Err(e) => {
+            tracing::error!(error = %e, "conda: failed to serialize package record");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let key = package_key(&channel, &subdir, &file);
+    if validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    // Serialize publishes/deletes per channel: the repodata rebuild is a
+    // list-read-generate-write cycle (same rationale as rpm repodata).
+    let lock = state.publish_lock(&lock_key(&channel));
+    let _guard = lock.lock().await;
+
+    if state.storage.stat(&key).await.is_some() {
+        return (StatusCode::CONFLICT, "Package already exists").into_response();
+    }
+    // Package first; the sidecar is what makes it visible.
+    if let Err(e) = state.storage.put(&key, &body).await {
+        tracing::error!(error = %e, key = %key, "conda: failed to store package");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    if let Err(e) = state
+        .storage
+        .put(&sidecar_key(&channel, &subdir, &file), &sidecar)
+        .await
+    {
+        tracing::error!(error = %e, key = %key, "conda: failed to store metadata sidecar");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    if let Err(e) = regenerate_channel(&state.storage, &channel, &subdir).await {
+        tracing::error!(channel = %channel, error = %e, "conda: repodata regeneration failed");
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Package stored but repodata regeneration failed",
+        )
+            .into_response();
+    }
+
+    state.metrics.record_upload("conda");
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &ident, "conda", &channel));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        format!("{channel}/{ident}"),
+        RegistryType::Conda,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("conda");
+
+    StatusCode::CREATED.into_response()
+}
+
+async fn delete_package(
+    State(state): State<AppState>,
+    Path((channel, subdir, file)): Path<(String, String, String)>,
+    Extension(authority): Extension<NamespaceAuthority>,
+) -> Response {
+    if !state.config.conda.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    if state.config.conda.proxies.contains_key(&channel) {
+        return proxied_repo_conflict();
+    }
+    if let Err(msg) = validate_package_path(&channel, &subdir, &file) {
+        return (StatusCode::BAD_REQUEST, msg).into_response();
+    }
+    if enforce_namespace_scope(&authority, &channel).is_err() {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let key = package_key(&channel, &subdir, &file);
+    if validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    let lock = state.publish_lock(&lock_key(&channel));
+    let _guard = lock.lock().await;
+
+    match state.storage.delete(&key).await {
+        Ok(()) => {}
+        Err(crate::storage::StorageError::NotFound) => {
+            return StatusCode::NOT_FOUND.into_response()
+        }
+        Err(e) => {
+            tracing::error!(error = %e, key = %key, "conda: failed to delete package");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+    if let Err(e) = state
+        .storage
+        .delete(&sidecar_key(&channel, &subdir, &file))
+        .await
+    {
+        tracing::warn!(error = %e, key = %key, "conda: failed to delete metadata sidecar");
+    }
+    if let Err(e) = regenerate_channel(&state.storage, &channel, &subdir).await {
+        tracing::error!(channel = %channel, error = %e, "conda: repodata regeneration failed");
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Package deleted but repodata regeneration failed",
+        )
+            .into_response();
+    }
+
+    state.audit.log(AuditEntry::new(
+        "delete",
+        "api",
+        &format!("{subdir}/{file}"),
+        "conda",
+        &channel,
+    ));
+    state.repo_index.invalidate("conda");
+    StatusCode::NO_CONTENT.into_response()
+}
+
+// ============================================================================
+// Pull-through channels
+// ============================================================================
+
+/// Channel/subdir metadata of a proxied channel, TTL-bounded.
+async fn proxy_metadata(
+    state: &AppState,
+    channel: &str,
+    entry: &RepoProxyEntry,
+    path: String,
+) -> Response {
+    let key = format!("conda/{channel}/{path}");
+    if validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let url = format!("{}/{}", entry.url().trim_end_matches('/'), path);
+    let file = path.rsplit('/').next().unwrap_or_default();
+    crate::registry::repo_proxy_download(
+        state,
+        "conda",
+        RegistryType::Conda,
+        format!("{channel}/{path}"),
+        key,
+        url,
+        entry.auth(),
+        state.config.conda.proxy_timeout,
+        state.config.conda.metadata_ttl,
+        false,
+        content_type(file),
+    )
+    .await
+}
+
+/// Upstream build time (Unix seconds) of `file` from the cached plain
+/// `repodata.json` of its subdir. A client that only ever fetched the `.zst`
+/// leaves it unknown — decompressing a conda-forge index per download is not
+/// worth an advisory date.
+async fn upstream_timestamp(
+    state: &AppState,
+    channel: &str,
+    subdir: &str,
+    file: &str,
+) -> Option<i64> {
+    let data = state
+        .storage
+        .get(&repodata_key(channel, subdir))
+        .await
+        .ok()?;
+    let needle = format!("\"{file}\"");
+    // The same name may also appear in `removed`; the record is the one keyed by it.
+    memchr::memmem::find_iter(&data, needle.as_bytes()).find_map(|pos| {
+        let rest = data[pos + needle.len()..].trim_ascii_start();
+        let rest = rest.strip_prefix(b":")?;
+        let record = serde_json::Deserializer::from_slice(rest)
+            .into_iter::<Value>()
+            .next()?
+            .ok()?;
+        let ts = record.get("timestamp")?.as_i64()?;
+        Some(normalize_timestamp_ms(ts) / 1000)
+    })
+}
+
+async fn proxy_package(
+    state: &AppState,
+    headers: &axum::http::HeaderMap,
+    channel: &str,
+    entry: &RepoProxyEntry,
+    subdir: &str,
+    file: &str,
+) -> Response {
+    let key = package_key(channel, subdir, file);
+    let Some((name, version, _)) = split_package_filename(file) else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+    if validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    // Curation check. #733 serve-local: an internal-namespace package is operator-owned —
+    // serve a local copy if one exists, never fetch it upstream.
+    let engine = &state.curation().curation_engine;
+    if crate::curation::is_internal_namespace(engine, RegistryType::Conda, name) {
+        return match state.storage.get(&key).await {
+            Ok(data) => (
+                StatusCode::OK,
+                [(header::CONTENT_TYPE, content_type(file))],
+                data,
+            )
+                .into_response(),
+            Err(_) => crate::curation::check_namespace_isolation(engine, RegistryType::Conda, name)
+                .unwrap_or_else(|| StatusCode::NOT_FOUND.into_response()),
+        };
+    }
+    let publish_date = if state.config.server.trust_upstream_dates {
+        upstream_timestamp(state, channel, subdir, file).await
+    } else {
+        None
+    };
+    if let Some(response) = crate::curation::check_download(
+        engine,
+        state.bypass_token().as_deref(),
+        headers,
+        RegistryType::Conda,
+        name,
+        Some(version),
+        publish_date,
+    ) {
+        return response;
+    }
+
+    let url = format!("{}/{subdir}/{file}", entry.url().trim_end_matches('/'));
+    crate::registry::repo_proxy_download(
+        state,
+        "conda",
+        RegistryType::Conda,
+        format!("{channel}/{subdir}/{file}"),
+        key,
+        url,
+        entry.auth(),
+        state.config.conda.proxy_timeout,
+        state.config.conda.metadata_ttl,
+        true,
+        content_type(file),
+    )
+    .await
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_helpers::{
+        body_bytes, create_test_context, create_test_context_with_config, send, send_with_headers,
+        TestContext,
+    };
+    use axum::http::Method;
+    use std::io::Write;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn info_tar(name: &str, version: &str, build: &str, subdir: &str) -> Vec<u8> {
+        let index = json!({
+            "arch": null,
+            "build": build,
+            "build_number": 0,
+            "depends": ["python >=3.8"],
+            "license": "MIT",
+            "name": name,
+            "noarch": "python",
+            "platform": null,
+            "subdir": subdir,
+            "timestamp": 1_700_000_000_000_i64,
+            "version": version,
+        })
+        .to_string();
+        let about = json!({
+            "summary": format!("{name} for tests"),
+            "home": "https://example.com",
+            "license": "MIT",
+            "channels": ["conda-forge"],
+        })
+        .to_string();
+        let mut builder = tar::Builder::new(Vec::new());
+        for (path, data) in [
+            ("info/index.json", index.as_bytes()),
+            ("info/about.json", about.as_bytes()),
+        ] {
+            let mut header = tar::Header::new_ustar();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            builder.append_data(&mut header, path, data).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    /// A `.conda` package: zip of `metadata.json`, `pkg-*.tar.zst` and `info-*.tar.zst`.
+    pub(crate) fn build_test_conda(
+        name: &str,
+        version: &str,
+        build: &str,
+        subdir: &str,
+    ) -> Vec<u8> {
+        let stem = format!("{name}-{version}-{build}");
+        let zst = |data: &[u8]| {
+            ruzstd::encoding::compress_to_vec(data, ruzstd::encoding::CompressionLevel::Fastest)
+        };
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        let mut w = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for (member, data) in [
+            (
+                "metadata.json".to_string(),
+                br#"{"conda_pkg_format_version": 2}"#.to_vec(),
+            ),
+            (format!("pkg-{stem}.tar.zst"), zst(&[0u8; 1024])),
+            (
+                format!("info-{stem}.tar.zst"),
+                zst(&info_tar(name, version, build, subdir)),
+            ),
+        ] {
+            w.start_file(member, options).unwrap();
+            w.write_all(&data).unwrap();
+        }
+        w.finish().unwrap().into_inner()
+    }
+
+    fn build_test_tar_bz2(name: &str, version: &str, build: &str, subdir: &str) -> Vec<u8> {
+        let mut encoder = bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::fast());
+        encoder
+            .write_all(&info_tar(name, version, build, subdir))
+            .unwrap();
+        encoder.finish().unwrap()
+    }
+
+    async fn get_json(ctx: &TestContext, uri: &str) -> Value {
+        let resp = send(&ctx.app, Method::GET, uri, "").await;
+        assert_eq!(resp.status(), StatusCode::OK, "{uri}");
+        serde_json::from_slice(&body_bytes(resp).await).unwrap()
+    }
+
+    #[test]
+    fn test_split_package_filename() {
+        assert_eq!(
+            split_package_filename("scikit-learn-1.5.0-py312h1_0.conda"),
+            Some(("scikit-learn", "1.5.0", "py312h1_0"))
+        );
+        assert_eq!(
+            split_package_filename("zlib-1.3.1-h4ab18f5_1.tar.bz2"),
+            Some(("zlib", "1.3.1", "h4ab18f5_1"))
+        );
+        assert_eq!(split_package_filename("zlib-1.3.1.conda"), None);
+        assert_eq!(split_package_filename("zlib-1.3.1-h0.whl"), None);
+    }
+
+    #[test]
+    fn test_valid_subdir() {
+        for ok in ["noarch", "linux-64", "osx-arm64", "win-64", "linux-ppc64le"] {
+            assert!(valid_subdir(ok), "{ok}");
+        }
+        for bad in ["", "linux", "Linux-64", "linux-64-x", ".nora-meta", "-64"] {
+            assert!(!valid_subdir(bad), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_read_package_info_both_formats() {
+        for (file, data) in [
+            (
+                "acme-1.0-py_0.conda",
+                build_test_conda("acme", "1.0", "py_0", "noarch"),
+            ),
+            (
+                "acme-1.0-py_0.tar.bz2",
+                build_test_tar_bz2("acme", "1.0", "py_0", "noarch"),
+            ),
+        ] {
+            let info = read_package_info(&data, file).unwrap();
+            assert_eq!(info.index["name"], "acme");
+            assert_eq!(check_index(&info.index, "noarch", file).unwrap(), "acme");
+            assert_eq!(info.about["summary"], "acme for tests");
+            assert!(
+                !info.about.contains_key("channels"),
+                "non-channeldata fields dropped"
+            );
+        }
+        assert!(read_package_info(b"not a package", "x-1-0.conda").is_err());
+        assert!(read_package_info(b"not a package", "x-1-0.tar.bz2").is_err());
+    }
+
+    #[test]
+    fn test_check_index_rejects_mismatch() {
+        let info = read_package_info(
+            &build_test_conda("acme", "1.0", "py_0", "noarch"),
+            "acme-1.0-py_0.conda",
+        )
+        .unwrap();
+        assert!(check_index(&info.index, "noarch", "other-1.0-py_0.conda")
+            .unwrap_err()
+            .contains("acme-1.0-py_0.conda"));
+        assert!(check_index(&info.index, "linux-64", "acme-1.0-py_0.conda")
+            .unwrap_err()
+            .contains("built for noarch"));
+    }
+
+    #[tokio::test]
+    async fn test_conda_empty_channel_serves_empty_metadata() {
+        let ctx = create_test_context();
+        let repodata = get_json(&ctx, "/conda/internal/noarch/repodata.json").await;
+        assert_eq!(repodata["info"]["subdir"], "noarch");
+        assert_eq!(repodata["packages"], json!({}));
+        assert_eq!(repodata["packages.conda"], json!({}));
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/conda/internal/noarch/repodata.json.zst",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let data = body_bytes(resp).await;
+        let mut json = Vec::new();
+        ruzstd::decoding::StreamingDecoder::new(&data[..])
+            .unwrap()
+            .read_to_end(&mut json)
+            .unwrap();
+        assert_eq!(serde_json::from_slice::<Value>(&json).unwrap(), repodata);
+
+        let channeldata = get_json(&ctx, "/conda/internal/channeldata.json").await;
+        assert_eq!(channeldata["packages"], json!({}));
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/conda/internal/noarch/current_repodata.json",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/conda/internal/Linux/repodata.json",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_conda_upload_repodata_download_delete() {
+        let ctx = create_test_context();
+        let pkg = build_test_conda("acme", "1.0", "py_0", "noarch");
+        let legacy = build_test_tar_bz2("acme", "0.9", "py_0", "noarch");
+
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/conda/internal/noarch/acme-1.0-py_0.conda",
+            pkg.clone(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/conda/internal/noarch/acme-0.9-py_0.tar.bz2",
+            legacy,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let repodata = get_json(&ctx, "/conda/internal/noarch/repodata.json").await;
+        let record = &repodata["packages.conda"]["acme-1.0-py_0.conda"];
+        assert_eq!(record["name"], "acme");
+        assert_eq!(record["depends"], json!(["python >=3.8"]));
+        assert_eq!(record["size"], pkg.len());
+        assert_eq!(
+            record["sha256"],
+            hex::encode(sha2::Sha256::digest(&pkg)).as_str()
+        );
+        assert_eq!(record["md5"], hex::encode(md5::Md5::digest(&pkg)).as_str());
+        assert!(repodata["packages"]["acme-0.9-py_0.tar.bz2"].is_object());
+
+        let channeldata = get_json(&ctx, "/conda/internal/channeldata.json").await;
+        assert_eq!(channeldata["subdirs"], json!(["noarch"]));
+        assert_eq!(
+            channeldata["packages"]["acme"]["subdirs"],
+            json!(["noarch"])
+        );
+        assert_eq!(
+            channeldata["packages"]["acme"]["home"],
+            "https://example.com"
+        );
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/conda/internal/noarch/acme-1.0-py_0.conda",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], &pkg[..]);
+        let resp = send(
+            &ctx.app,
+            Method::HEAD,
+            "/conda/internal/noarch/acme-1.0-py_0.conda",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        // Packages are immutable.
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/conda/internal/noarch/acme-1.0-py_0.conda",
+            pkg.clone(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/conda/internal/noarch/acme-1.0-py_0.conda",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        let repodata = get_json(&ctx, "/conda/internal/noarch/repodata.json").await;
+        assert_eq!(repodata["packages.conda"], json!({}));
+        let channeldata = get_json(&ctx, "/conda/internal/channeldata.json").await;
+        assert_eq!(channeldata["packages"]["acme"]["version"], "0.9");
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/conda/internal/noarch/acme-1.0-py_0.conda",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_conda_upload_rejects_invalid_package() {
+        let ctx = create_test_context();
+        let pkg = build_test_conda("acme", "1.0", "py_0", "noarch");
+        for (uri, body) in [
+            (
+                "/conda/internal/noarch/acme-1.0-py_0.conda",
+                b"junk".to_vec(),
+            ),
+            // Built for noarch, uploaded to linux-64.
+            ("/conda/internal/linux-64/acme-1.0-py_0.conda", pkg.clone()),
+            // File name does not match index.json.
+            ("/conda/internal/noarch/acme-2.0-py_0.conda", pkg.clone()),
+            ("/conda/internal/noarch/acme-1.0-py_0.whl", pkg.clone()),
+        ] {
+            let resp = send(&ctx.app, Method::PUT, uri, body).await;
+            assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{uri}");
+        }
+
+        let ctx = create_test_context_with_config(|cfg| cfg.conda.max_file_size = 16);
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/conda/internal/noarch/acme-1.0-py_0.conda",
+            pkg,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    async fn proxy_context(
+        upstream: &MockServer,
+        customize: impl FnOnce(&mut crate::config::Config),
+    ) -> TestContext {
+        let url = format!("{}/conda-forge", upstream.uri());
+        create_test_context_with_config(move |cfg| {
+            cfg.conda
+                .proxies
+                .insert("conda-forge".to_string(), RepoProxyEntry::Simple(url));
+            customize(cfg);
+        })
+    }
+
+    #[tokio::test]
+    async fn test_conda_proxy_metadata_and_package() {
+        let upstream = MockServer::start().await;
+        let pkg = build_test_conda("numpy", "2.0.0", "py312h0_0", "linux-64");
+        let repodata = json!({
+            "info": {"subdir": "linux-64"},
+            "packages.conda": {"numpy-2.0.0-py312h0_0.conda": {"name": "numpy", "timestamp": 1_700_000_000_000_i64}},
+        });
+        Mock::given(method("GET"))
+            .and(path("/conda-forge/linux-64/repodata.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&repodata))
+            .mount(&upstream)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/conda-forge/linux-64/numpy-2.0.0-py312h0_0.conda"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(pkg.clone()))
+            .expect(1)
+            .mount(&upstream)
+            .await;
+        let ctx = proxy_context(&upstream, |_| {}).await;
+
+        assert_eq!(
+            get_json(&ctx, "/conda/conda-forge/linux-64/repodata.json").await,
+            repodata
+        );
+        for _ in 0..2 {
+            let resp = send(
+                &ctx.app,
+                Method::GET,
+                "/conda/conda-forge/linux-64/numpy-2.0.0-py312h0_0.conda",
+                "",
+            )
+            .await;
+            assert_eq!(resp.status(), StatusCode::OK);
+            assert_eq!(&body_bytes(resp).await[..], &pkg[..]);
+            // Second pull is served from the cache (the upstream mock expects one hit).
+            for _ in 0..50 {
+                if ctx
+                    .state
+                    .storage
+                    .stat("conda/conda-forge/linux-64/numpy-2.0.0-py312h0_0.conda")
+                    .await
+                    .is_some()
+                {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        }
+        assert_eq!(
+            upstream_timestamp(
+                &ctx.state,
+                "conda-forge",
+                "linux-64",
+                "numpy-2.0.0-py312h0_0.conda"
+            )
+            .await,
+            Some(1_700_000_000)
+        );
+
+        // Proxied channels are read-only.
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/conda/conda-forge/linux-64/numpy-2.0.0-py312h0_0.conda",
+            pkg,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/conda/conda-forge/linux-64/numpy-2.0.0-py312h0_0.conda",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_conda_proxy_blocked_by_curation() {
+        let upstream = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"pkg".to_vec()))
+            .expect(0)
+            .mount(&upstream)
+            .await;
+        let dir = tempfile::TempDir::new().unwrap();
+        let blocklist = dir.path().join("blocklist.json");
+        let rules = json!({
+            "version": 1,
+            "rules": [{"registry": "conda", "name": "evil", "version": "*", "reason": "known malware"}]
+        });
+        std::fs::write(&blocklist, rules.to_string()).unwrap();
+        let bl_path = blocklist.to_str().unwrap().to_string();
+        let ctx = proxy_context(&upstream, move |cfg| {
+            cfg.curation.mode = crate::config::CurationMode::Enforce;
+            cfg.curation.blocklist_path = Some(bl_path);
+        })
+        .await;
+
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::GET,
+            "/conda/conda-forge/noarch/evil-1.0-py_0.conda",
+            vec![],
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        assert_eq!(resp.headers()["x-nora-decision"], "blocked");
+    }
+}
@@ -73,6 +73,17 @@ fn manifest_prefix(namespace: Option<&str>, name: &str) -> String {
     }
 }
 
+/// Build a namespaced storage key for the OCI referrers index of a subject digest.
+///
+/// Hosted (pushed) referrers live under the bare name; a proxied upstream's
+/// index is cached under its namespace.
+fn referrers_key(namespace: Option<&str>, name: &str, subject: &str) -> String {
+    match namespace {
+        Some(ns) => format!("docker/{}/{}/referrers/{}.json", ns, name, subject),
+        None => format!("docker/{}/referrers/{}.json", name, subject),
+    }
+}
+
 /// Result of Docker image name canonicalization.
 ///
 /// Unifies both config-aware prefix routing and hostname-based namespace
@@ -961,7 +972,24 @@ async fn docker_v2_dispatch(
         };
     }
 
-    // 3. Manifests: {name}/manifests/{reference}
+    // 3. Referrers (OCI 1.1): {name}/referrers/{digest}. A digest never contains
+    // '/', so only a valid digest after the last `/referrers/` selects this route.
+    if let Some((name, digest)) = rest.rsplit_once("/referrers/") {
+        if !name.is_empty() && validate_digest(digest).is_ok() {
+            if validate_docker_name(name).is_err() {
+                return (StatusCode::BAD_REQUEST, "Invalid image name").into_response();
+            }
+            return match method {
+                Method::GET => {
+                    let params = parse_query_string(uri.query());
+                    get_referrers(state, Path((name.to_string(), digest.to_string())), params).await
+                }
+                _ => method_not_allowed("GET"),
+            };
+        }
+    }
+
+    // 4. Manifests: {name}/manifests/{reference}
     if let Some((name, reference)) = rest.rsplit_once("/manifests/") {
         if name.is_empty() || reference.is_empty() {
             return StatusCode::NOT_FOUND.into_response();
@@ -1000,7 +1028,7 @@ async fn docker_v2_dispatch(
         };
     }
 
-    // 4. Tags list: {name}/tags/list
+    // 5. Tags list: {name}/tags/list
     if let Some(name) = rest.strip_suffix("/tags/list") {
         if name.is_empty() {
             return StatusCode::NOT_FOUND.into_response();
@@ -1009,7 +1037,10 @@ async fn docker_v2_dispatch(
             return (StatusCode::BAD_REQUEST, "Invalid image name").into_response();
         }
         return match method {
-            Method::GET => list_tags(state, Path(name.to_string())).await,
+            Method::GET => {
+                let params = parse_query_string(uri.query());
+                list_tags(state, Path(name.to_string()), params).await
+            }
             _ => method_not_allowed("GET"),
         };
     }
@@ -1061,42 +1092,81 @@ pub(crate) fn strip_docker_namespace(name: &str) -> &str {
     name
 }
 
-/// List all repositories in the registry
-async fn catalog(State(state): State<AppState>) -> Response {
-    let keys = match state.storage.list("docker/").await {
-        Ok(k) => k,
-        Err(e) => {
-            tracing::error!(error = ?e, "docker: failed to list storage for catalog");
-            return StatusCode::SERVICE_UNAVAILABLE.into_response();
-        }
+/// List all repositories in the registry, paginated per the OCI `n`/`last`
+/// parameters from the in-memory docker catalog (no storage scan per call).
+async fn catalog(State(state): State<AppState>, uri: Uri) -> Response {
+    let params = parse_query_string(uri.query());
+    let Some(catalog) = state
+        .repo_index
+        .docker_catalog(
+            &state.storage,
+            Duration::from_secs(state.config.docker.catalog_ttl),
+        )
+        .await
+    else {
+        return StatusCode::SERVICE_UNAVAILABLE.into_response();
     };
+    let repositories = catalog.repositories(&state.config.docker);
+    match paginate(repositories, &params, "/v2/_catalog") {
+        Some((page, link)) => with_link(Json(json!({ "repositories": page })), link),
+        None => pagination_number_invalid(),
+    }
+}
 
-    // Extract unique repository names from paths like "docker/{name}/manifests/..."
-    let mut repos: Vec<String> = keys
-        .iter()
-        .filter_map(|k| {
-            let rest = k.strip_prefix("docker/")?;
-            // Find the first known directory separator (manifests/ or blobs/)
-            let name = if let Some(idx) = rest.find("/manifests/") {
-                &rest[..idx]
-            } else if let Some(idx) = rest.find("/blobs/") {
-                &rest[..idx]
-            } else {
-                return None;
-            };
-            if name.is_empty() {
-                return None;
-            }
-            // Canonicalize to strip upstream namespace prefix (e.g. "docker.io/")
-            // so that images proxied through different upstreams are deduplicated.
-            Some(canonicalize(name, &state.config.docker).name)
-        })
-        .collect();
+/// One page of a sorted listing: entries after `last`, at most `n` of them, and
+/// the RFC 5988 `Link` to the next page when more remain. Without `n` the rest
+/// of the listing is returned in one response, as before pagination existed.
+/// `None` when `n` is not a number.
+fn paginate(
+    sorted: &[String],
+    params: &HashMap<String, String>,
+    path: &str,
+) -> Option<(Vec<String>, Option<String>)> {
+    let n = match params.get("n") {
+        Some(n) => Some(n.parse::<usize>().ok()?),
+        None => None,
+    };
+    let start = match params.get("last") {
+        Some(last) => sorted.partition_point(|entry| entry.as_str() <= last.as_str()),
+        None => 0,
+    };
+    let rest = &sorted[start..];
+    let page = &rest[..n.unwrap_or(rest.len()).min(rest.len())];
+    let link = match (n, page.last()) {
+        (Some(n), Some(last)) if page.len() < rest.len() => {
+            use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+            Some(format!(
+                "<{}?n={}&last={}>; rel=\"next\"",
+                path,
+                n,
+                utf8_percent_encode(last, NON_ALPHANUMERIC)
+            ))
+        }
+        _ => None,
+    };
+    Some((page.to_vec(), link))
+}
 
-    repos.sort();
-    repos.dedup();
+fn pagination_number_invalid() -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(json!({
+            "errors": [{
+                "code": "PAGINATION_NUMBER_INVALID",
+                "message": "invalid number of results requested"
+            }]
+        })),
+    )
+        .into_response()
+}
 
-    Json(json!({ "repositories": repos })).into_response()
+/// Attach a pagination `Link` header, when there is a next page.
+fn with_link(body: impl IntoResponse, link: Option<String>) -> Response {
+    let mut response = body.into_response();
+    if let Some(value) = link.and_then(|l| axum::http::HeaderValue::from_str(&l).ok()) {
+        response.headers_mut().insert(header::LINK, value);
+    }
+    response
 }
 
 async fn check_blob(
@@ -2039,6 +2109,22 @@ async fn try_fetch_and_cache(
                 use sha2::Digest;
                 let digest = format!("sha256:{}", hex::encode(sha2::Sha256::digest(&data)));
 
+                // Cosign policy: verify before anything is cached, so a blocked
+                // manifest is re-checked (not served from cache) on the next pull.
+                if let Some(resp) = super::docker_cosign::check(
+                    state,
+                    upstream,
+                    upstream_name,
+                    reference,
+                    &data,
+                    &digest,
+                    &manifest_prefix(Some(&upstream.resolved_namespace()), name),
+                )
+                .await
+                {
+                    return Some(resp);
+                }
+
                 // Quarantine: record digest, check status
                 let (q_mode, q_secs) = resolve_quarantine(state);
                 if !matches!(q_mode, crate::digest_quarantine::QuarantineMode::Off) {
@@ -2563,6 +2649,22 @@ async fn put_manifest(
         }
     }
 
+    // OCI 1.1: a manifest with a `subject` joins its subject's referrers index,
+    // and `OCI-Subject` tells the client it need not push a fallback tag. If the
+    // index can't be written the header is withheld, so the client falls back.
+    let mut oci_subject = None;
+    if let Ok(manifest) = serde_json::from_slice::<Value>(&body) {
+        if let Some(subject) = referrer_subject(&manifest) {
+            let descriptor = referrer_descriptor(&manifest, &body, &digest);
+            match update_referrers(&state, &name, subject, &digest, Some(descriptor)).await {
+                Ok(()) => oci_subject = Some(subject.to_string()),
+                Err(e) => {
+                    tracing::warn!(name = %name, subject = %subject, error = %e, "failed to update referrers index")
+                }
+            }
+        }
+    }
+
     state.metrics.record_upload("docker");
     state.activity.push(ActivityEntry::new(
         ActionType::Push,
@@ -2580,17 +2682,29 @@ async fn put_manifest(
     state.repo_index.invalidate("docker");
 
     let location = format!("/v2/{}/manifests/{}", name, reference);
-    (
+    let mut response = (
         StatusCode::CREATED,
         [
             (header::LOCATION, location),
             (HeaderName::from_static("docker-content-digest"), digest),
         ],
     )
-        .into_response()
+        .into_response();
+    if let Some(value) = oci_subject.and_then(|s| axum::http::HeaderValue::from_str(&s).ok()) {
+        response
+            .headers_mut()
+            .insert(HeaderName::from_static("oci-subject"), value);
+    }
+    response
 }
 
-async fn list_tags(State(state): State<AppState>, Path(name): Path<String>) -> Response {
+/// Tags of a repository, paginated like `catalog`. The pushed (bare) and
+/// proxied (namespaced) copies of a name list as one repository.
+async fn list_tags(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    params: HashMap<String, String>,
+) -> Response {
     let c = canonicalize(&name, &state.config.docker);
     if let Some(r) = c.denied_response() {
         return r;
@@ -2600,39 +2714,285 @@ async fn list_tags(State(state): State<AppState>, Path(name): Path<String>) -> R
     if let Err(e) = validate_docker_name(&name) {
         return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
     }
-    let prefix = manifest_prefix(ns.as_deref(), &name);
-    let legacy_prefix = manifest_prefix(None, &name);
-    let mut keys = match state.storage.list(&prefix).await {
-        Ok(k) => k,
-        Err(e) => {
-            tracing::error!(error = ?e, "docker: failed to list manifests for tags");
-            return StatusCode::SERVICE_UNAVAILABLE.into_response();
-        }
+    let Some(catalog) = state
+        .repo_index
+        .docker_catalog(
+            &state.storage,
+            Duration::from_secs(state.config.docker.catalog_ttl),
+        )
+        .await
+    else {
+        return StatusCode::SERVICE_UNAVAILABLE.into_response();
     };
-    // Also include legacy non-namespaced keys during migration
-    if prefix != legacy_prefix {
-        match state.storage.list(&legacy_prefix).await {
-            Ok(legacy_keys) => {
-                keys.extend(legacy_keys);
-                keys.sort();
-                keys.dedup();
+    let mut tags: Vec<String> = Vec::new();
+    if let Some(ns) = &ns {
+        tags.extend(
+            catalog
+                .tags
+                .get(&format!("{}/{}", ns, name))
+                .into_iter()
+                .flatten()
+                .cloned(),
+        );
+    }
+    tags.extend(catalog.tags.get(&name).into_iter().flatten().cloned());
+    tags.sort();
+    tags.dedup();
+    let path = format!("/v2/{}/tags/list", name);
+    match paginate(&tags, &params, &path) {
+        Some((page, link)) => with_link(Json(json!({"name": name, "tags": page})), link),
+        None => pagination_number_invalid(),
+    }
+}
+
+// ============================================================================
+// Referrers API (OCI Distribution 1.1)
+// ============================================================================
+
+const OCI_INDEX_MEDIA_TYPE: &str = "application/vnd.oci.image.index.v1+json";
+
+/// Digest of the manifest's `subject` descriptor, if it names a valid one.
+fn referrer_subject(manifest: &Value) -> Option<&str> {
+    manifest
+        .get("subject")
+        .and_then(|s| s.get("digest"))
+        .and_then(|d| d.as_str())
+        .filter(|d| validate_digest(d).is_ok())
+}
+
+/// Descriptor of a referrer manifest as listed in its subject's referrers index.
+/// `artifactType` falls back to the config media type, as the spec prescribes
+/// for image manifests without one.
+fn referrer_descriptor(manifest: &Value, data: &[u8], digest: &str) -> Value {
+    let mut descriptor = json!({
+        "mediaType": detect_manifest_media_type(data),
+        "digest": digest,
+        "size": data.len(),
+    });
+    let artifact_type = manifest
+        .get("artifactType")
+        .or_else(|| manifest.get("config").and_then(|c| c.get("mediaType")))
+        .and_then(|v| v.as_str());
+    if let Some(artifact_type) = artifact_type {
+        descriptor["artifactType"] = json!(artifact_type);
+    }
+    if let Some(annotations) = manifest.get("annotations").filter(|a| a.is_object()) {
+        descriptor["annotations"] = annotations.clone();
+    }
+    descriptor
+}
+
+/// Descriptors stored in a referrers index, or `None` if there is no index.
+async fn read_referrers(storage: &Storage, key: &str) -> Option<Vec<Value>> {
+    let data = storage.get(key).await.ok()?;
+    let index = serde_json::from_slice::<Value>(&data).ok()?;
+    Some(
+        index
+            .get("manifests")
+            .and_then(|m| m.as_array())
+            .cloned()
+            .unwrap_or_default(),
+    )
+}
+
+fn referrers_index(manifests: Vec<Value>) -> Value {
+    json!({
+        "schemaVersion": 2,
+        "mediaType": OCI_INDEX_MEDIA_TYPE,
+        "manifests": manifests,
+    })
+}
+
+/// Add (`Some(descriptor)`) or drop (`None`) the referrer `digest` in the hosted
+/// referrers index of `subject`. Held under the index's publish lock so
+/// concurrent signature/SBOM pushes for one image don't lose each other's entry.
+async fn update_referrers(
+    state: &AppState,
+    name: &str,
+    subject: &str,
+    digest: &str,
+    descriptor: Option<Value>,
+) -> crate::storage::Result<()> {
+    let key = referrers_key(None, name, subject);
+    let lock = state.publish_lock(&key);
+    let _guard = lock.lock().await;
+
+    let mut manifests = read_referrers(&state.storage, &key)
+        .await
+        .unwrap_or_default();
+    manifests.retain(|d| d.get("digest").and_then(|v| v.as_str()) != Some(digest));
+    manifests.extend(descriptor);
+    if manifests.is_empty() {
+        return match state.storage.delete(&key).await {
+            Ok(()) | Err(crate::storage::StorageError::NotFound) => Ok(()),
+            Err(e) => Err(e),
+        };
+    }
+    let body = serde_json::to_vec(&referrers_index(manifests)).unwrap_or_default();
+    state.storage.put(&key, &body).await
+}
+
+/// Drop a deleted manifest from its subject's referrers index, if it had a subject.
+async fn forget_referrer(state: &AppState, name: &str, data: &[u8]) {
+    use sha2::Digest;
+    let Ok(manifest) = serde_json::from_slice::<Value>(data) else {
+        return;
+    };
+    let Some(subject) = referrer_subject(&manifest) else {
+        return;
+    };
+    let digest = format!("sha256:{}", hex::encode(sha2::Sha256::digest(data)));
+    if let Err(e) = update_referrers(state, name, subject, &digest, None).await {
+        tracing::warn!(name = %name, subject = %subject, error = %e, "failed to drop deleted manifest from referrers index");
+    }
+}
+
+/// GET /v2/{name}/referrers/{digest} — image index of the manifests whose
+/// `subject` is `digest` (signatures, SBOMs, attestations).
+///
+/// Hosted referrers come from the index `put_manifest` maintains. For a proxied
+/// image the upstream's referrers are fetched (its answer is cached and served
+/// stale when it is unreachable) and merged with referrers pushed locally, so an
+/// image mirrored from upstream can be signed in NORA. When no upstream supports
+/// the API and nothing is held locally, a proxied subject gets 404 so clients
+/// fall back to the `sha256-<digest>` tag schema, which still proxies; a hosted
+/// subject always gets an index, empty if nothing refers to it.
+async fn get_referrers(
+    State(state): State<AppState>,
+    Path((name, digest)): Path<(String, String)>,
+    params: HashMap<String, String>,
+) -> Response {
+    let c = canonicalize(&name, &state.config.docker);
+    if let Some(r) = c.denied_response() {
+        return r;
+    }
+    let upstreams_to_try = c.upstreams_to_try(&state.config.docker.upstreams);
+    let ns = c.namespace;
+    let name = c.name;
+    if let Err(e) = validate_docker_name(&name) {
+        return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+    }
+    if let Err(e) = validate_digest(&digest) {
+        return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+    }
+
+    let hosted = read_referrers(&state.storage, &referrers_key(None, &name, &digest)).await;
+
+    // Same provenance rule as get_manifest: a subject under the bare key is hosted
+    // and locally authoritative; an internal-namespace name is never proxied (#733).
+    let internal = crate::curation::is_internal_namespace(
+        &state.curation().curation_engine,
+        crate::curation::RegistryType::Docker,
+        &name,
+    );
+    let subject_hosted = state
+        .storage
+        .stat(&manifest_key(None, &name, &digest))
+        .await
+        .is_some();
+    let proxied = !internal && !subject_hosted && !upstreams_to_try.is_empty();
+
+    let mut upstream = None;
+    if proxied {
+        let cache_key = referrers_key(ns.as_deref(), &name, &digest);
+        upstream = match fetch_referrers_from_upstreams(&state, &upstreams_to_try, &name, &digest)
+            .await
+        {
+            Some((manifests, raw)) => {
+                if let Err(e) = state.storage.put(&cache_key, &raw).await {
+                    tracing::warn!(key = %cache_key, error = %e, "cache write failed (referrers)");
+                    crate::metrics::CACHE_WRITE_ERRORS
+                        .with_label_values(&["docker", "referrers"])
+                        .inc();
+                }
+                Some(manifests)
             }
-            Err(e) => {
-                tracing::warn!(error = ?e, "docker: failed to list legacy manifests, continuing with namespaced only");
+            None => read_referrers(&state.storage, &cache_key).await,
+        };
+        if upstream.is_none() && hosted.is_none() {
+            return StatusCode::NOT_FOUND.into_response();
+        }
+    }
+
+    // Upstream referrers first, then local pushes it doesn't list.
+    let mut manifests: Vec<Value> = upstream.unwrap_or_default();
+    for descriptor in hosted.unwrap_or_default() {
+        let digest = descriptor.get("digest");
+        if !manifests.iter().any(|d| d.get("digest") == digest) {
+            manifests.push(descriptor);
+        }
+    }
+
+    let mut filters_applied = None;
+    if let Some(artifact_type) = params.get("artifactType") {
+        manifests.retain(|d| {
+            d.get("artifactType").and_then(|v| v.as_str()) == Some(artifact_type.as_str())
+        });
+        filters_applied = Some("artifactType");
+    }
+
+    let mut response = (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, OCI_INDEX_MEDIA_TYPE)],
+        serde_json::to_vec(&referrers_index(manifests)).unwrap_or_default(),
+    )
+        .into_response();
+    if let Some(filters) = filters_applied {
+        response.headers_mut().insert(
+            HeaderName::from_static("oci-filters-applied"),
+            axum::http::HeaderValue::from_static(filters),
+        );
+    }
+    response
+}
+
+/// The first upstream referrers index for `digest`, as (descriptors, raw body).
+/// Single-segment names are retried as `library/{name}`, like manifests.
+async fn fetch_referrers_from_upstreams(
+    state: &AppState,
+    upstreams: &[&crate::config::DockerUpstream],
+    name: &str,
+    digest: &str,
+) -> Option<(Vec<Value>, Vec<u8>)> {
+    let mut upstream_names = vec![name.to_string()];
+    if !name.contains('/') {
+        upstream_names.push(format!("library/{}", name));
+    }
+    for upstream_name in &upstream_names {
+        for upstream in upstreams {
+            match fetch_v2_from_upstream(
+                &state.http_client,
+                &upstream.url,
+                upstream_name,
+                &format!("referrers/{}", digest),
+                OCI_INDEX_MEDIA_TYPE,
+                &state.docker_auth,
+                state.config.docker.proxy_timeout,
+                expose_opt(&upstream.auth),
+                &state.circuit_breaker,
+            )
+            .await
+            {
+                Ok((data, _)) => {
+                    let manifests = serde_json::from_slice::<Value>(&data)
+                        .ok()
+                        .and_then(|index| {
+                            index.get("manifests").and_then(|m| m.as_array()).cloned()
+                        });
+                    match manifests {
+                        Some(manifests) => return Some((manifests, data)),
+                        None => {
+                            tracing::debug!(upstream = %upstream.url, name = %upstream_name, "Upstream referrers response is not an image index, trying next")
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::debug!(error = ?e, upstream = %upstream.url, name = %upstream_name, digest = %digest, "Docker referrers proxy fetch failed, trying next");
+                }
             }
         }
     }
-    let tags: Vec<String> = keys
-        .iter()
-        .filter_map(|k| {
-            k.strip_prefix(&prefix)
-                .or_else(|| k.strip_prefix(&legacy_prefix))
-                .and_then(|t| t.strip_suffix(".json"))
-                .map(String::from)
-        })
-        .filter(|t| !ends_with_ci(t, ".meta") && !t.contains(".meta."))
-        .collect();
-    (StatusCode::OK, Json(json!({"name": name, "tags": tags}))).into_response()
+    None
 }
 
 // ============================================================================
@@ -2727,12 +3087,18 @@ async fn delete_manifest(
     let lock = state.publish_lock(&key);
     let _guard = lock.lock().await;
 
+    // Read before deleting: a tag's digest copy and a referrer's subject index
+    // both follow from the manifest body.
+    let existing = storage_get_with_fallback(&state.storage, &key, &legacy_key)
+        .await
+        .ok();
+
     // If reference is a tag, also delete digest-keyed copy
     let is_tag = !reference.starts_with("sha256:");
     if is_tag {
-        if let Ok(data) = storage_get_with_fallback(&state.storage, &key, &legacy_key).await {
+        if let Some(data) = &existing {
             use sha2::Digest;
-            let digest = format!("sha256:{}", hex::encode(sha2::Sha256::digest(&data)));
+            let digest = format!("sha256:{}", hex::encode(sha2::Sha256::digest(data)));
             // Delete from both namespaced and legacy locations
             let _ = state
                 .storage
@@ -2767,6 +3133,9 @@ async fn delete_manifest(
                 .delete(&manifest_meta_key(None, &name, &reference))
                 .await;
             let _ = state.storage.delete(&meta_key).await;
+            if let Some(data) = &existing {
+                forget_referrer(&state, &name, data).await;
+            }
 
             state.audit.log(AuditEntry::new(
                 "delete",
@@ -2787,6 +3156,9 @@ async fn delete_manifest(
                         .storage
                         .delete(&manifest_meta_key(None, &name, &reference))
                         .await;
+                    if let Some(data) = &existing {
+                        forget_referrer(&state, &name, data).await;
+                    }
                     state.audit.log(AuditEntry::new(
                         "delete",
                         "api",
@@ -3050,24 +3422,52 @@ pub async fn fetch_manifest_from_upstream(
     timeout: u64,
     basic_auth: Option<&str>,
     cb: &CircuitBreakerRegistry,
+) -> Result<(Vec<u8>, String), ProxyError> {
+    // Request with Accept header for manifest types
+    let accept_header = "application/vnd.docker.distribution.manifest.v2+json, \
+                         application/vnd.docker.distribution.manifest.list.v2+json, \
+                         application/vnd.oci.image.manifest.v1+json, \
+                         application/vnd.oci.image.index.v1+json";
+    fetch_v2_from_upstream(
+        client,
+        upstream_url,
+        name,
+        &format!("manifests/{}", reference),
+        accept_header,
+        docker_auth,
+        timeout,
+        basic_auth,
+        cb,
+    )
+    .await
+}
+
+/// GET `/v2/{name}/{path}` from an upstream registry, with the bearer-token
+/// retry on 401 and circuit-breaker accounting shared by manifests and referrers.
+/// Returns (body_bytes, content_type).
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn fetch_v2_from_upstream(
+    client: &reqwest::Client,
+    upstream_url: &str,
+    name: &str,
+    path: &str,
+    accept_header: &str,
+    docker_auth: &DockerAuth,
+    timeout: u64,
+    basic_auth: Option<&str>,
+    cb: &CircuitBreakerRegistry,
 ) -> Result<(Vec<u8>, String), ProxyError> {
     let cb_key = format!("docker:{}", upstream_url.trim_end_matches('/'));
     let probe = cb.check(&cb_key)?;
 
     let url = format!(
-        "{}/v2/{}/manifests/{}",
+        "{}/v2/{}/{}",
         upstream_url.trim_end_matches('/'),
         name,
-        reference
+        path
     );
 
-    tracing::debug!(url = %url, "Fetching manifest from upstream");
-
-    // Request with Accept header for manifest types
-    let accept_header = "application/vnd.docker.distribution.manifest.v2+json, \
-                         application/vnd.docker.distribution.manifest.list.v2+json, \
-                         application/vnd.oci.image.manifest.v1+json, \
-                         application/vnd.oci.image.index.v1+json";
+    tracing::debug!(url = %url, "Fetching from upstream");
 
     // First try — with basic auth if configured
     let mut request = client
@@ -3994,6 +4394,7 @@ mod integration_tests {
                 auth: None,
                 namespace: None,
                 prefix: None,
+                cosign: None,
             }];
         });
 
@@ -4175,6 +4576,245 @@ mod integration_tests {
         assert_eq!(del.status(), StatusCode::ACCEPTED);
     }
 
+    /// A zero-config artifact manifest of `artifact_type` whose `subject` is `subject_body`.
+    fn referrers_fixture(subject_body: &[u8], artifact_type: &str) -> serde_json::Value {
+        serde_json::json!({
+            "schemaVersion": 2,
+            "mediaType": "application/vnd.oci.image.manifest.v1+json",
+            "artifactType": artifact_type,
+            "config": {
+                "mediaType": "application/vnd.oci.empty.v1+json",
+                "size": 0,
+                "digest": "sha256:0000000000000000000000000000000000000000000000000000000000000000"
+            },
+            "layers": [],
+            "subject": {
+                "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                "size": subject_body.len(),
+                "digest": format!("sha256:{}", hex::encode(sha2::Sha256::digest(subject_body)))
+            },
+            "annotations": { "org.example.kind": artifact_type }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_docker_referrers_index_follows_push_and_delete() {
+        let ctx = create_test_context();
+        seed_zero_config(&ctx.state, "signed").await;
+        let image = serde_json::to_vec(&serde_json::json!({
+            "schemaVersion": 2,
+            "mediaType": "application/vnd.oci.image.manifest.v1+json",
+            "config": {
+                "mediaType": "application/vnd.oci.image.config.v1+json",
+                "size": 0,
+                "digest": "sha256:0000000000000000000000000000000000000000000000000000000000000000"
+            },
+            "layers": []
+        }))
+        .unwrap();
+        let subject = format!("sha256:{}", hex::encode(sha2::Sha256::digest(&image)));
+        send(
+            &ctx.app,
+            Method::PUT,
+            "/v2/signed/manifests/v1",
+            image.clone(),
+        )
+        .await;
+
+        // Nothing refers to it yet: a hosted subject answers an empty index.
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &format!("/v2/signed/referrers/{subject}"),
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers()[header::CONTENT_TYPE],
+            "application/vnd.oci.image.index.v1+json"
+        );
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["manifests"], serde_json::json!([]));
+
+        let mut referrers = Vec::new();
+        for artifact_type in [
+            "application/vnd.dev.cosign.artifact.sig.v1+json",
+            "application/spdx+json",
+        ] {
+            let body = serde_json::to_vec(&referrers_fixture(&image, artifact_type)).unwrap();
+            let digest = format!("sha256:{}", hex::encode(sha2::Sha256::digest(&body)));
+            let resp = send(
+                &ctx.app,
+                Method::PUT,
+                &format!("/v2/signed/manifests/{digest}"),
+                body,
+            )
+            .await;
+            assert_eq!(resp.status(), StatusCode::CREATED);
+            assert_eq!(resp.headers()["oci-subject"], subject.as_str());
+            referrers.push(digest);
+        }
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &format!("/v2/signed/referrers/{subject}"),
+            Body::empty(),
+        )
+        .await;
+        assert!(resp.headers().get("oci-filters-applied").is_none());
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        let manifests = json["manifests"].as_array().unwrap();
+        assert_eq!(manifests.len(), 2);
+        assert_eq!(manifests[0]["digest"], referrers[0].as_str());
+        assert_eq!(
+            manifests[0]["artifactType"],
+            "application/vnd.dev.cosign.artifact.sig.v1+json"
+        );
+        assert_eq!(
+            manifests[0]["mediaType"],
+            "application/vnd.oci.image.manifest.v1+json"
+        );
+        assert_eq!(
+            manifests[1]["annotations"]["org.example.kind"],
+            "application/spdx+json"
+        );
+
+        // artifactType filter, announced via OCI-Filters-Applied.
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &format!("/v2/signed/referrers/{subject}?artifactType=application%2Fspdx%2Bjson"),
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(resp.headers()["oci-filters-applied"], "artifactType");
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["manifests"].as_array().unwrap().len(), 1);
+        assert_eq!(json["manifests"][0]["digest"], referrers[1].as_str());
+
+        // Deleting a referrer drops it from the index.
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            &format!("/v2/signed/manifests/{}", referrers[0]),
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::ACCEPTED);
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &format!("/v2/signed/referrers/{subject}"),
+            Body::empty(),
+        )
+        .await;
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        let manifests = json["manifests"].as_array().unwrap();
+        assert_eq!(manifests.len(), 1);
+        assert_eq!(manifests[0]["digest"], referrers[1].as_str());
+    }
+
+    #[tokio::test]
+    async fn test_docker_referrers_rejects_bad_digest_and_method() {
+        let ctx = create_test_context();
+        let digest = format!("sha256:{}", "a".repeat(64));
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            &format!("/v2/app/referrers/{digest}"),
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::METHOD_NOT_ALLOWED);
+        // Not a digest: not the referrers route.
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/v2/app/referrers/latest",
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    /// A proxied subject merges the upstream's referrers (cached, served when the
+    /// upstream is gone) with local pushes; with neither, 404 so clients fall back
+    /// to the tag schema.
+    #[tokio::test]
+    async fn test_docker_referrers_proxied_merge_cache_and_fallback() {
+        use crate::config::DockerUpstream;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let upstream = MockServer::start().await;
+        let subject = format!("sha256:{}", "b".repeat(64));
+        let upstream_sig = format!("sha256:{}", "c".repeat(64));
+        Mock::given(method("GET"))
+            .and(path(format!("/v2/library/app/referrers/{subject}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "schemaVersion": 2,
+                "mediaType": "application/vnd.oci.image.index.v1+json",
+                "manifests": [{
+                    "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                    "digest": upstream_sig,
+                    "size": 100,
+                    "artifactType": "application/vnd.cncf.notary.signature"
+                }]
+            })))
+            .mount(&upstream)
+            .await;
+
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.docker.upstreams = vec![DockerUpstream {
+                url: upstream.uri(),
+                auth: None,
+                namespace: None,
+                prefix: None,
+                cosign: None,
+            }];
+        });
+
+        // A signature pushed locally for the upstream image.
+        seed_zero_config(&ctx.state, "library/app").await;
+        let mut local = referrers_fixture(b"", "application/vnd.dev.cosign.artifact.sig.v1+json");
+        local["subject"]["digest"] = serde_json::json!(subject);
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/v2/library/app/manifests/sig",
+            serde_json::to_vec(&local).unwrap(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let uri = format!("/v2/library/app/referrers/{subject}");
+        let resp = send(&ctx.app, Method::GET, &uri, Body::empty()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        let manifests = json["manifests"].as_array().unwrap();
+        assert_eq!(manifests.len(), 2);
+        assert_eq!(manifests[0]["digest"], upstream_sig.as_str());
+
+        // Upstream gone: the cached index still answers.
+        upstream.reset().await;
+        let resp = send(&ctx.app, Method::GET, &uri, Body::empty()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["manifests"].as_array().unwrap().len(), 2);
+
+        // Nothing upstream, cached or local: 404 → tag-schema fallback.
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &format!("/v2/library/app/referrers/sha256:{}", "d".repeat(64)),
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
     #[tokio::test]
     async fn test_docker_delete_by_digest_removes_tag() {
         // #658: deleting a manifest by digest must also drop the tags that
@@ -4893,6 +5533,7 @@ mod integration_tests {
                 auth: None,
                 namespace: None,
                 prefix: None,
+                cosign: None,
             }];
         });
 
@@ -4996,6 +5637,154 @@ mod integration_tests {
         assert!(json["tags"].is_array());
     }
 
+    /// OCI `n`/`last` pagination with a `Link` to the next page, for both
+    /// `_catalog` and `tags/list`.
+    #[tokio::test]
+    async fn test_oci_catalog_and_tags_paginate() {
+        let ctx = create_test_context();
+        for repo in ["charlie", "alpha", "bravo"] {
+            ctx.state
+                .storage
+                .put(&format!("docker/{repo}/manifests/v1.json"), b"{}")
+                .await
+                .unwrap();
+        }
+        for tag in ["v3", "v1", "v2", "sha256:abc"] {
+            ctx.state
+                .storage
+                .put(&format!("docker/team/app/manifests/{tag}.json"), b"{}")
+                .await
+                .unwrap();
+        }
+
+        let resp = send(&ctx.app, Method::GET, "/v2/_catalog?n=2", Body::empty()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers()[header::LINK],
+            "</v2/_catalog?n=2&last=bravo>; rel=\"next\""
+        );
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["repositories"], serde_json::json!(["alpha", "bravo"]));
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/v2/_catalog?n=2&last=bravo",
+            Body::empty(),
+        )
+        .await;
+        assert!(resp.headers().get(header::LINK).is_none());
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(
+            json["repositories"],
+            serde_json::json!(["charlie", "team/app"])
+        );
+
+        // Without `n`, everything in one response; digest references are not tags.
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/v2/team/app/tags/list",
+            Body::empty(),
+        )
+        .await;
+        assert!(resp.headers().get(header::LINK).is_none());
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["tags"], serde_json::json!(["v1", "v2", "v3"]));
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/v2/team/app/tags/list?n=1&last=v1",
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(
+            resp.headers()[header::LINK],
+            "</v2/team/app/tags/list?n=1&last=v2>; rel=\"next\""
+        );
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["name"], "team/app");
+        assert_eq!(json["tags"], serde_json::json!(["v2"]));
+
+        let resp = send(&ctx.app, Method::GET, "/v2/_catalog?n=many", Body::empty()).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["errors"][0]["code"], "PAGINATION_NUMBER_INVALID");
+    }
+
+    /// Catalog names are canonicalized against the upstream config, so a copy
+    /// proxied under a dotless namespace lists as the same repository.
+    #[tokio::test]
+    async fn test_catalog_canonicalizes_with_upstream_config() {
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.docker.upstreams = vec![crate::config::DockerUpstream {
+                url: "https://mirror.example.com".to_string(),
+                auth: None,
+                namespace: Some("mirror".to_string()),
+                prefix: Some("mirror".to_string()),
+                cosign: None,
+            }];
+        });
+        for key in [
+            "docker/mirror/library/nginx/manifests/v1.json",
+            "docker/library/nginx/manifests/v2.json",
+        ] {
+            ctx.state.storage.put(key, b"{}").await.unwrap();
+        }
+
+        let resp = send(&ctx.app, Method::GET, "/v2/_catalog", Body::empty()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["repositories"], serde_json::json!(["library/nginx"]));
+    }
+
+    /// Pushes and deletes invalidate the in-memory catalog, so `tags/list`
+    /// reflects them on the next call.
+    #[tokio::test]
+    async fn test_tags_list_follows_push_and_delete() {
+        let ctx = create_test_context();
+        let manifest = serde_json::json!({
+            "schemaVersion": 2,
+            "mediaType": "application/vnd.docker.distribution.manifest.v2+json",
+            "config": {
+                "mediaType": "application/vnd.docker.container.image.v1+json",
+                "size": 0,
+                "digest": "sha256:0000000000000000000000000000000000000000000000000000000000000000"
+            },
+            "layers": []
+        });
+        seed_zero_config(&ctx.state, "live").await;
+        let list = |ctx: &crate::test_helpers::TestContext| {
+            let app = ctx.app.clone();
+            async move {
+                let resp = send(&app, Method::GET, "/v2/live/tags/list", Body::empty()).await;
+                let json: serde_json::Value =
+                    serde_json::from_slice(&body_bytes(resp).await).unwrap();
+                json["tags"].clone()
+            }
+        };
+        assert_eq!(list(&ctx).await, serde_json::json!([]));
+
+        send(
+            &ctx.app,
+            Method::PUT,
+            "/v2/live/manifests/v1",
+            Body::from(serde_json::to_vec(&manifest).unwrap()),
+        )
+        .await;
+        assert_eq!(list(&ctx).await, serde_json::json!(["v1"]));
+
+        send(
+            &ctx.app,
+            Method::DELETE,
+            "/v2/live/manifests/v1",
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(list(&ctx).await, serde_json::json!([]));
+    }
+
     /// OCI spec: manifest response MUST include Docker-Content-Digest = sha256 of body.
     #[tokio::test]
     async fn test_oci_manifest_digest_matches_body() {
@@ -5144,12 +5933,14 @@ mod integration_tests {
                     auth: None,
                     namespace: None,
                     prefix: None,
+                    cosign: None,
                 },
                 DockerUpstream {
                     url: "http://127.0.0.1:2".into(), // upstream B (stays closed)
                     auth: None,
                     namespace: None,
                     prefix: None,
+                    cosign: None,
                 },
             ];
         });
@@ -5290,6 +6081,7 @@ mod integration_tests {
             auth: None,
             namespace: Some("docker.io".to_string()),
             prefix: Some("docker-hub".to_string()),
+            cosign: None,
         }];
         let cfg = docker_config_allow(upstreams.clone());
 
@@ -5307,6 +6099,7 @@ mod integration_tests {
             auth: None,
             namespace: Some("docker.io".to_string()),
             prefix: None,
+            cosign: None,
         }];
         let cfg = docker_config_allow(upstreams.clone());
 
@@ -5333,6 +6126,7 @@ mod integration_tests {
             auth: None,
             namespace: Some("docker.io".to_string()),
             prefix: None,
+            cosign: None,
         }];
         let cfg = docker_config_allow(upstreams.clone());
 
@@ -5403,12 +6197,14 @@ mod integration_tests {
                 auth: None,
                 namespace: Some("docker.io".to_string()),
                 prefix: Some("docker-hub".to_string()),
+                cosign: None,
             },
             crate::config::DockerUpstream {
                 url: "https://ghcr.io".to_string(),
                 auth: None,
                 namespace: Some("ghcr.io".to_string()),
                 prefix: Some("ghcr".to_string()),
+                cosign: None,
             },
         ];
         let cfg = docker_config_allow(upstreams.clone());
@@ -5437,12 +6233,14 @@ mod integration_tests {
                 auth: None,
                 namespace: Some("docker.io".to_string()),
                 prefix: Some("docker-hub".to_string()),
+                cosign: None,
             },
             crate::config::DockerUpstream {
                 url: "https://ghcr.io".to_string(),
                 auth: None,
                 namespace: Some("ghcr.io".to_string()),
                 prefix: Some("ghcr".to_string()),
+                cosign: None,
             },
         ];
         let cfg = docker_config_deny(upstreams.clone());
@@ -5473,6 +6271,7 @@ mod integration_tests {
             auth: None,
             namespace: Some("docker.io".to_string()),
             prefix: None,
+            cosign: None,
         }];
         let cfg = docker_config_deny(upstreams.clone());
 
@@ -5490,6 +6289,7 @@ mod integration_tests {
             auth: None,
             namespace: Some("docker.io".to_string()),
             prefix: Some("hub".to_string()),
+            cosign: None,
         }];
         let cfg = docker_config_deny(upstreams);
 
@@ -5541,6 +6341,7 @@ mod integration_tests {
                 auth: None,
                 namespace: None,
                 prefix: None,
+                cosign: None,
             }];
         });
 
@@ -5609,6 +6410,7 @@ mod integration_tests {
                 auth: None,
                 namespace: None,
                 prefix: None,
+                cosign: None,
             }];
         });
 
@@ -5664,6 +6466,7 @@ mod integration_tests {
                 auth: None,
                 namespace: None,
                 prefix: None,
+                cosign: None,
             }];
         });
 
@@ -5762,6 +6565,7 @@ mod integration_tests {
             auth: None,
             namespace: None,
             prefix: None,
+            cosign: None,
         }
     }
 
@@ -0,0 +1,1459 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+//! Cosign signature policy for manifests pulled through a Docker upstream.
+//!
+//! A manifest fetched from an upstream with a `cosign` policy is verified before it is
+//! cached or served. The signatures are the ones `cosign sign` pushes next to the image —
+//! the `sha256-<digest>.sig` tag, or an OCI referrer of the cosign signature artifact type
+//! — and each signature layer is checked offline:
+//!
+//! 1. the layer blob (the "simple signing" payload) hashes to its descriptor digest and
+//!    names the pulled manifest digest;
+//! 2. the signature verifies against a trusted key, or against a Fulcio certificate that
+//!    chains to a configured root, is issued for code signing and carries a trusted
+//!    OIDC issuer + subject;
+//! 3. with `rekor_keys` set, the attached Rekor bundle's signed entry timestamp verifies,
+//!    the logged entry is this signature over this payload, and (keyless) the entry was
+//!    integrated while the short-lived certificate was valid.
+//!
+//! cosign signs the digest a tag resolves to, which for a multi-arch image is the index:
+//! the platform manifests the client fetches next carry no signature of their own and
+//! inherit the index's verdict through [`VerifiedChildren`].
+
+use super::docker::{fetch_manifest_from_upstream, fetch_v2_from_upstream};
+use crate::config::{CosignPolicy, DockerUpstream, SignatureMode};
+use crate::registry::ProxyError;
+use crate::secrets::expose_opt;
+use crate::AppState;
+use axum::response::{IntoResponse, Response};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use parking_lot::Mutex;
+use serde_json::Value;
+use sha2::Digest;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use x509_cert::der::{Decode, DecodePem, Encode};
+use x509_cert::ext::pkix::name::GeneralName;
+use x509_cert::ext::pkix::{BasicConstraints, ExtendedKeyUsage, SubjectAltName};
+use x509_cert::Certificate;
+
+const SIG_ARTIFACT_TYPE: &str = "application/vnd.dev.cosign.artifact.sig.v1+json";
+const SIMPLESIGNING_TYPE: &str = "application/vnd.dev.cosign.simplesigning.v1+json";
+const ANN_SIGNATURE: &str = "dev.cosignproject.cosign/signature";
+const ANN_CERTIFICATE: &str = "dev.sigstore.cosign/certificate";
+const ANN_CHAIN: &str = "dev.sigstore.cosign/chain";
+const ANN_BUNDLE: &str = "dev.sigstore.cosign/bundle";
+/// Fulcio certificate extensions holding the OIDC issuer (v2 DER UTF8String, v1 raw).
+const OID_FULCIO_ISSUER_V2: &str = "1.3.6.1.4.1.57264.1.8";
+const OID_FULCIO_ISSUER_V1: &str = "1.3.6.1.4.1.57264.1.1";
+/// id-kp-codeSigning.
+const OID_CODE_SIGNING: &str = "1.3.6.1.5.5.7.3.3";
+const OID_ECDSA_SHA256: &str = "1.2.840.10045.4.3.2";
+const OID_ECDSA_SHA384: &str = "1.2.840.10045.4.3.3";
+/// A simple-signing payload is a few hundred bytes; anything larger is not one.
+const MAX_PAYLOAD_SIZE: u64 = 64 * 1024;
+/// Signature manifests tried per image (a re-signed image carries several).
+const MAX_SIGNATURE_MANIFESTS: usize = 8;
+/// Cached manifests read when looking for the index a platform manifest belongs to.
+const MAX_CACHED_INDEXES: usize = 256;
+
+/// Platform manifests of recently verified multi-arch indexes, keyed
+/// `{upstream}|{name}@{digest}`. In-memory: a platform manifest missing here (after a
+/// restart, or once its entry expired) is re-checked against the cached index that lists it.
+pub struct VerifiedChildren {
+    inner: Mutex<HashMap<String, Instant>>,
+}
+
+/// How long a verified index vouches for its platform manifests.
+const CHILD_TTL: Duration = Duration::from_secs(3600);
+const MAX_CHILDREN: usize = 10_000;
+
+impl VerifiedChildren {
+    pub fn new() -> Self {
+        Self {
+            inner: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn key(upstream: &str, name: &str, digest: &str) -> String {
+        format!("{}|{}@{}", upstream, name, digest)
+    }
+
+    fn insert(&self, upstream: &str, name: &str, digests: impl IntoIterator<Item = String>) {
+        let mut map = self.inner.lock();
+        if map.len() >= MAX_CHILDREN {
+            map.retain(|_, at| at.elapsed() < CHILD_TTL);
+        }
+        let now = Instant::now();
+        for digest in digests {
+            map.insert(Self::key(upstream, name, &digest), now);
+        }
+    }
+
+    fn contains(&self, upstream: &str, name: &str, digest: &str) -> bool {
+        self.inner
+            .lock()
+            .get(&Self::key(upstream, name, digest))
+            .is_some_and(|at| at.elapsed() < CHILD_TTL)
+    }
+
+    /// Forget every verdict, as a restart does.
+    #[cfg(test)]
+    pub(crate) fn clear(&self) {
+        self.inner.lock().clear();
+    }
+}
+
+impl Default for VerifiedChildren {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Outcome of checking one manifest against a policy.
+#[derive(Debug, PartialEq)]
+enum Verdict {
+    Verified,
+    /// No cosign signature exists for the digest.
+    Unsigned,
+    /// Signatures exist but none satisfies the policy.
+    Invalid(String),
+    /// The signatures could not be fetched from the upstream.
+    Unavailable(String),
+}
+
+impl Verdict {
+    fn label(&self) -> &'static str {
+        match self {
+            Verdict::Verified => "verified",
+            Verdict::Unsigned => "unsigned",
+            Verdict::Invalid(_) => "invalid",
+            Verdict::Unavailable(_) => "unavailable",
+        }
+    }
+}
+
+/// Apply `upstream`'s cosign policy to a manifest just fetched from it. `None` = serve it;
+/// `Some` = the 403 to return instead (enforce mode only — audit mode logs and serves).
+/// `cached` is the storage prefix of the upstream's cached manifests for `name`.
+pub(super) async fn check(
+    state: &AppState,
+    upstream: &DockerUpstream,
+    name: &str,
+    reference: &str,
+    manifest: &[u8],
+    digest: &str,
+    cached: &str,
+) -> Option<Response> {
+    let policy = upstream.cosign.as_ref()?;
+    if !policy.repositories.is_empty()
+        && !policy
+            .repositories
+            .iter()
+            .any(|p| crate::curation::glob_match(p, name))
+    {
+        return None;
+    }
+    // The signatures, attestations and SBOMs cosign attaches are not images themselves.
+    if is_cosign_tag(reference) {
+        return None;
+    }
+    let json: Value = serde_json::from_slice(manifest).ok()?;
+    if json.get("subject").is_some() {
+        return None;
+    }
+
+    let verdict = if state.cosign_children.contains(&upstream.url, name, digest) {
+        Verdict::Verified
+    } else {
+        match verify(state, upstream, policy, name, digest).await {
+            Verdict::Unsigned
+                if verify_cached_index(state, upstream, policy, name, cached, digest).await =>
+            {
+                Verdict::Verified
+            }
+            verdict => verdict,
+        }
+    };
+    crate::metrics::SIGNATURE_VERIFICATIONS_TOTAL
+        .with_label_values(&["docker", verdict.label(), policy.mode.as_str()])
+        .inc();
+
+    let reason = match verdict {
+        Verdict::Verified => {
+            if let Some(children) = json.get("manifests").and_then(|m| m.as_array()) {
+                state.cosign_children.insert(
+                    &upstream.url,
+                    name,
+                    children
+                        .iter()
+                        .filter_map(|c| c.get("digest").and_then(|d| d.as_str()))
+                        .map(str::to_string),
+                );
+            }
+            return None;
+        }
+        Verdict::Unsigned => "image is not signed".to_string(),
+        Verdict::Invalid(why) => format!("no valid signature: {}", why),
+        Verdict::Unavailable(why) => format!("signature unavailable: {}", why),
+    };
+    tracing::warn!(
+        upstream = %upstream.url,
+        name = %name,
+        reference = %reference,
+        digest = %digest,
+        mode = policy.mode.as_str(),
+        reason = %reason,
+        "Cosign policy: signature check failed"
+    );
+    if policy.mode == SignatureMode::Audit {
+        return None;
+    }
+    Some(
+        crate::curation::BlockedResponse {
+            rule: "cosign".to_string(),
+            reason,
+            registry: "docker".to_string(),
+            package: name.to_string(),
+            version: Some(digest.to_string()),
+        }
+        .into_response(),
+    )
+}
+
+/// An unsigned platform manifest whose index was served from cache has not been vouched
+/// for in this run: find the cached index listing `digest`, verify the index and, if it
+/// passes, remember its platform manifests.
+async fn verify_cached_index(
+    state: &AppState,
+    upstream: &DockerUpstream,
+    policy: &CosignPolicy,
+    name: &str,
+    cached: &str,
+    digest: &str,
+) -> bool {
+    let Ok(keys) = state.storage.list(cached).await else {
+        return false;
+    };
+    // Indexes are cached under their digest as well as their tag; one copy is enough.
+    let candidates = keys.iter().filter(|k| {
+        k.strip_prefix(cached)
+            .and_then(|r| r.strip_suffix(".json"))
+            .is_some_and(|r| r.starts_with("sha256:") && !r.ends_with(".meta"))
+    });
+    for key in candidates.take(MAX_CACHED_INDEXES) {
+        let Ok(data) = state.storage.get(key).await else {
+            continue;
+        };
+        let Some(children) = serde_json::from_slice::<Value>(&data)
+            .ok()
+            .and_then(|json| json.get("manifests").and_then(|m| m.as_array()).cloned())
+        else {
+            continue;
+        };
+        let children: Vec<String> = children
+            .iter()
+            .filter_map(|c| c.get("digest").and_then(|d| d.as_str()))
+            .map(str::to_string)
+            .collect();
+        if !children.iter().any(|c| c == digest) {
+            continue;
+        }
+        if verify(state, upstream, policy, name, &sha256_digest(&data)).await == Verdict::Verified {
+            state.cosign_children.insert(&upstream.url, name, children);
+            return true;
+        }
+    }
+    false
+}
+
+/// `sha256-<hex>.sig` / `.att` / `.sbom` — the tags cosign attaches to a digest.
+fn is_cosign_tag(reference: &str) -> bool {
+    reference
+        .strip_prefix("sha256-")
+        .and_then(|rest| rest.split_once('.'))
+        .is_some_and(|(hex, _)| hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+async fn verify(
+    state: &AppState,
+    upstream: &DockerUpstream,
+    policy: &CosignPolicy,
+    name: &str,
+    digest: &str,
+) -> Verdict {
+    let trust = match state.curation().cosign.get(&upstream.url) {
+        Ok(t) => t,
+        Err(e) => return Verdict::Invalid(e),
+    };
+    let manifests = match fetch_signature_manifests(state, upstream, name, digest).await {
+        Ok(m) if m.is_empty() => return Verdict::Unsigned,
+        Ok(m) => m,
+        Err(e) => return Verdict::Unavailable(e),
+    };
+
+    let mut last_error = "no cosign signature layer".to_string();
+    for sig_manifest in &manifests {
+        let layers = sig_manifest
+            .get("layers")
+            .and_then(|l| l.as_array())
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+        for layer in layers {
+            let payload = match fetch_payload(state, upstream, name, layer).await {
+                Ok(p) => p,
+                Err(e) => {
+                    last_error = e;
+                    continue;
+                }
+            };
+            match verify_layer(&trust, policy, layer, &payload, digest) {
+                Ok(()) => return Verdict::Verified,
+                Err(e) => last_error = e,
+            }
+        }
+    }
+    Verdict::Invalid(last_error)
+}
+
+/// The cosign signature manifests for `digest`: the `.sig` tag, else OCI referrers.
+/// Empty = the upstream has no signature for it.
+async fn fetch_signature_manifests(
+    state: &AppState,
+    upstream: &DockerUpstream,
+    name: &str,
+    digest: &str,
+) -> Result<Vec<Value>, String> {
+    let tag = format!("{}.sig", digest.replacen(':', "-", 1));
+    match fetch_manifest_from_upstream(
+        &state.http_client,
+        &upstream.url,
+        name,
+        &tag,
+        &state.docker_auth,
+        state.config.docker.proxy_timeout,
+        expose_opt(&upstream.auth),
+        &state.circuit_breaker,
+    )
+    .await
+    {
+        Ok((data, _)) => {
+            return serde_json::from_slice(&data)
+                .map(|m| vec![m])
+                .map_err(|e| format!("malformed signature manifest: {}", e))
+        }
+        Err(ProxyError::Upstream(404)) => {}
+        Err(e) => return Err(format!("{:?}", e)),
+    }
+
+    let index = match fetch_v2_from_upstream(
+        &state.http_client,
+        &upstream.url,
+        name,
+        &format!(
+            "referrers/{}?artifactType={}",
+            digest,
+            SIG_ARTIFACT_TYPE.replace('/', "%2F").replace('+', "%2B")
+        ),
+        "application/vnd.oci.image.index.v1+json",
+        &state.docker_auth,
+        state.config.docker.proxy_timeout,
+        expose_opt(&upstream.auth),
+        &state.circuit_breaker,
+    )
+    .await
+    {
+        Ok((data, _)) => serde_json::from_slice::<Value>(&data)
+            .map_err(|e| format!("malformed referrers index: {}", e))?,
+        // No referrers API (or nothing refers to the digest): unsigned.
+        Err(ProxyError::Upstream(404)) => return Ok(Vec::new()),
+        Err(e) => return Err(format!("{:?}", e)),
+    };
+
+    let mut manifests = Vec::new();
+    let referrers = index
+        .get("manifests")
+        .and_then(|m| m.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+    for descriptor in referrers
+        .iter()
+        .filter(|d| d.get("artifactType").and_then(|t| t.as_str()) == Some(SIG_ARTIFACT_TYPE))
+        .take(MAX_SIGNATURE_MANIFESTS)
+    {
+        let Some(sig_digest) = descriptor.get("digest").and_then(|d| d.as_str()) else {
+            continue;
+        };
+        let (data, _) = fetch_manifest_from_upstream(
+            &state.http_client,
+            &upstream.url,
+            name,
+            sig_digest,
+            &state.docker_auth,
+            state.config.docker.proxy_timeout,
+            expose_opt(&upstream.auth),
+            &state.circuit_breaker,
+        )
+        .await
+        .map_err(|e| format!("{:?}", e))?;
+        if sha256_digest(&data) != sig_digest {
+            return Err(format!("signature manifest {} digest mismatch", sig_digest));
+        }
+        manifests.push(
+            serde_json::from_slice(&data)
+                .map_err(|e| format!("malformed signature manifest: {}", e))?,
+        );
+    }
+    Ok(manifests)
+}
+
+/// Fetch a signature layer's simple-signing payload and check it against its digest.
+async fn fetch_payload(
+    state: &AppState,
+    upstream: &DockerUpstream,
+    name: &str,
+    layer: &Value,
+) -> Result<Vec<u8>, String> {
+    if layer.get("mediaType").and_then(|m| m.as_str()) != Some(SIMPLESIGNING_TYPE) {
+        return Err("not a cosign signature layer".to_string());
+    }
+    let layer_digest = layer
+        .get("digest")
+        .and_then(|d| d.as_str())
+        .filter(|d| d.starts_with("sha256:"))
+        .ok_or("signature layer without a sha256 digest")?;
+    if layer.get("size").and_then(|s| s.as_u64()).unwrap_or(0) > MAX_PAYLOAD_SIZE {
+        return Err("signature payload too large".to_string());
+    }
+    let (payload, _) = fetch_v2_from_upstream(
+        &state.http_client,
+        &upstream.url,
+        name,
+        &format!("blobs/{}", layer_digest),
+        "*/*",
+        &state.docker_auth,
+        state.config.docker.proxy_timeout,
+        expose_opt(&upstream.auth),
+        &state.circuit_breaker,
+    )
+    .await
+    .map_err(|e| format!("{:?}", e))?;
+    if sha256_digest(&payload) != layer_digest {
+        return Err("signature payload digest mismatch".to_string());
+    }
+    Ok(payload)
+}
+
+/// Verify one signature layer whose payload has already been fetched and digest-checked.
+fn verify_layer(
+    trust: &TrustMaterial,
+    policy: &CosignPolicy,
+    layer: &Value,
+    payload: &[u8],
+    digest: &str,
+) -> Result<(), String> {
+    check_payload(payload, digest)?;
+    let annotation = |key: &str| {
+        layer
+            .get("annotations")
+            .and_then(|a| a.get(key))
+            .and_then(|v| v.as_str())
+    };
+    let signature = STANDARD
+        .decode(annotation(ANN_SIGNATURE).ok_or("signature annotation missing")?)
+        .map_err(|_| "signature annotation is not base64")?;
+
+    let integrated_time = if trust.rekor.is_empty() {
+        None
+    } else {
+        let bundle = annotation(ANN_BUNDLE).ok_or("Rekor bundle missing")?;
+        Some(verify_bundle(&trust.rekor, bundle, &signature, payload)?)
+    };
+
+    if trust
+        .keys
+        .iter()
+        .any(|key| key.verify_message(payload, &signature))
+    {
+        return Ok(());
+    }
+
+    if let (Some(cert_pem), false) = (annotation(ANN_CERTIFICATE), policy.identities.is_empty()) {
+        let leaf = Certificate::from_pem(cert_pem.as_bytes())
+            .map_err(|_| "malformed signing certificate")?;
+        let chain = match annotation(ANN_CHAIN) {
+            Some(pem) => Certificate::load_pem_chain(pem.as_bytes())
+                .map_err(|_| "malformed certificate chain")?,
+            None => Vec::new(),
+        };
+        verify_chain(&leaf, &chain, &trust.fulcio_roots)?;
+        if !is_code_signing(&leaf) {
+            return Err("signing certificate is not issued for code signing".to_string());
+        }
+        let (issuer, subject) = certificate_identity(&leaf)?;
+        if !policy
+            .identities
+            .iter()
+            .any(|id| id.issuer == issuer && crate::curation::glob_match(&id.subject, &subject))
+        {
+            return Err(format!(
+                "certificate identity {} ({}) is not trusted",
+                subject, issuer
+            ));
+        }
+        // A Fulcio certificate lives for minutes; the Rekor entry proves it was used then.
+        let signed_at = integrated_time.ok_or("keyless signature without a Rekor bundle")?;
+        let validity = &leaf.tbs_certificate.validity;
+        let not_before = validity.not_before.to_unix_duration().as_secs() as i64;
+        let not_after = validity.not_after.to_unix_duration().as_secs() as i64;
+        if signed_at < not_before || signed_at > not_after {
+            return Err("signed outside the certificate's validity".to_string());
+        }
+        let key = VerifyingKey::from_spki_der(
+            &leaf
+                .tbs_certificate
+                .subject_public_key_info
+                .to_der()
+                .map_err(|_| "malformed certificate key")?,
+        )?;
+        if key.verify_message(payload, &signature) {
+            return Ok(());
+        }
+        return Err("signature does not match the signing certificate".to_string());
+    }
+    Err("signature does not verify against any trusted key".to_string())
+}
+
+/// The payload must be a cosign image signature naming exactly the pulled manifest.
+fn check_payload(payload: &[u8], digest: &str) -> Result<(), String> {
+    let json: Value =
+        serde_json::from_slice(payload).map_err(|_| "signature payload is not JSON")?;
+    let critical = json.get("critical").ok_or("payload without `critical`")?;
+    if critical.get("type").and_then(|t| t.as_str()) != Some("cosign container image signature") {
+        return Err("payload is not a cosign image signature".to_string());
+    }
+    let signed = critical
+        .get("image")
+        .and_then(|i| i.get("docker-manifest-digest"))
+        .and_then(|d| d.as_str());
+    if signed != Some(digest) {
+        return Err("signature is for a different manifest".to_string());
+    }
+    Ok(())
+}
+
+/// Verify a cosign Rekor bundle offline; returns the entry's integration time.
+fn verify_bundle(
+    rekor: &[VerifyingKey],
+    bundle: &str,
+    signature: &[u8],
+    payload: &[u8],
+) -> Result<i64, String> {
+    let bundle: Value = serde_json::from_str(bundle).map_err(|_| "malformed Rekor bundle")?;
+    let set = bundle
+        .get("SignedEntryTimestamp")
+        .and_then(|s| s.as_str())
+        .and_then(|s| STANDARD.decode(s).ok())
+        .ok_or("Rekor bundle without a signed entry timestamp")?;
+    let entry = bundle
+        .get("Payload")
+        .ok_or("Rekor bundle without payload")?;
+    let body = entry.get("body").and_then(|b| b.as_str());
+    let integrated_time = entry.get("integratedTime").and_then(|t| t.as_i64());
+    let log_index = entry.get("logIndex").and_then(|i| i.as_i64());
+    let log_id = entry.get("logID").and_then(|i| i.as_str());
+    let (Some(body), Some(integrated_time), Some(log_index), Some(log_id)) =
+        (body, integrated_time, log_index, log_id)
+    else {
+        return Err("incomplete Rekor bundle".to_string());
+    };
+
+    // The SET signs the canonical (RFC 8785) JSON of the payload: sorted keys, no spaces.
+    let canonical = format!(
+        r#"{{"body":{},"integratedTime":{},"logID":{},"logIndex":{}}}"#,
+        Value::from(body),
+        integrated_time,
+        Value::from(log_id),
+        log_index
+    );
+    if !rekor
+        .iter()
+        .any(|key| key.verify_message(canonical.as_bytes(), &set))
+    {
+        return Err("Rekor signed entry timestamp does not verify".to_string());
+    }
+
+    // The logged entry must be this signature over this payload.
+    let entry: Value = STANDARD
+        .decode(body)
+        .ok()
+        .and_then(|b| serde_json::from_slice(&b).ok())
+        .ok_or("malformed Rekor entry")?;
+    if entry.get("kind").and_then(|k| k.as_str()) != Some("hashedrekord") {
+        return Err("unsupported Rekor entry kind".to_string());
+    }
+    let spec = entry.get("spec").ok_or("malformed Rekor entry")?;
+    let logged_sig = spec
+        .pointer("/signature/content")
+        .and_then(|s| s.as_str())
+        .and_then(|s| STANDARD.decode(s).ok());
+    let logged_hash = spec.pointer("/data/hash/value").and_then(|h| h.as_str());
+    if logged_sig.as_deref() != Some(signature)
+        || logged_hash != Some(hex::encode(sha2::Sha256::digest(payload)).as_str())
+    {
+        return Err("Rekor entry is for a different signature".to_string());
+    }
+    Ok(integrated_time)
+}
+
+/// Walk `leaf` up through the untrusted `chain` until a certificate is signed by one of
+/// the configured roots.
+fn verify_chain(
+    leaf: &Certificate,
+    chain: &[Certificate],
+    roots: &[Certificate],
+) -> Result<(), String> {
+    let mut current = leaf;
+    for _ in 0..=chain.len() {
+        let issuer = &current.tbs_certificate.issuer;
+        if roots
+            .iter()
+            .any(|root| &root.tbs_certificate.subject == issuer && signed_by(current, root))
+        {
+            return Ok(());
+        }
+        current = chain
+            .iter()
+            .find(|c| &c.tbs_certificate.subject == issuer && is_ca(c) && signed_by(current, c))
+            .ok_or("certificate does not chain to a trusted Fulcio root")?;
+    }
+    Err("certificate does not chain to a trusted Fulcio root".to_string())
+}
+
+fn is_ca(cert: &Certificate) -> bool {
+    matches!(
+        cert.tbs_certificate.get::<BasicConstraints>(),
+        Ok(Some((_, bc))) if bc.ca
+    )
+}
+
+fn signed_by(cert: &Certificate, issuer: &Certificate) -> bool {
+    let (Ok(tbs), Some(signature), Ok(issuer_spki)) = (
+        cert.tbs_certificate.to_der(),
+        cert.signature.as_bytes(),
+        issuer.tbs_certificate.subject_public_key_info.to_der(),
+    ) else {
+        return false;
+    };
+    let Ok(key) = VerifyingKey::from_spki_der(&issuer_spki) else {
+        return false;
+    };
+    let prehash = match cert.signature_algorithm.oid.to_string().as_str() {
+        OID_ECDSA_SHA256 => sha2::Sha256::digest(&tbs).to_vec(),
+        OID_ECDSA_SHA384 => sha2::Sha384::digest(&tbs).to_vec(),
+        _ => return false,
+    };
+    key.verify_prehash(&prehash, signature)
+}
+
+/// Fulcio marks every certificate it issues with the code-signing extended key usage.
+fn is_code_signing(cert: &Certificate) -> bool {
+    matches!(
+        cert.tbs_certificate.get::<ExtendedKeyUsage>(),
+        Ok(Some((_, eku))) if eku.0.iter().any(|oid| oid.to_string() == OID_CODE_SIGNING)
+    )
+}
+
+/// The Fulcio certificate's (OIDC issuer, subject) pair.
+fn certificate_identity(cert: &Certificate) -> Result<(String, String), String> {
+    let subject = match cert.tbs_certificate.get::<SubjectAltName>() {
+        Ok(Some((_, san))) => san.0.iter().find_map(|name| match name {
+            GeneralName::UniformResourceIdentifier(uri) => Some(uri.as_str().to_string()),
+            GeneralName::Rfc822Name(email) => Some(email.as_str().to_string()),
+            _ => None,
+        }),
+        _ => None,
+    }
+    .ok_or("signing certificate without a subject alternative name")?;
+
+    let mut issuer = None;
+    for ext in cert.tbs_certificate.extensions.as_deref().unwrap_or(&[]) {
+        let oid = ext.extn_id.to_string();
+        if oid == OID_FULCIO_ISSUER_V2 {
+            issuer = String::from_der(ext.extn_value.as_bytes()).ok();
+            break;
+        }
+        if oid == OID_FULCIO_ISSUER_V1 {
+            issuer = std::str::from_utf8(ext.extn_value.as_bytes())
+                .ok()
+                .map(str::to_string);
+        }
+    }
+    let issuer = issuer.ok_or("signing certificate without an OIDC issuer")?;
+    Ok((issuer, subject))
+}
+
+fn sha256_digest(data: &[u8]) -> String {
+    format!("sha256:{}", hex::encode(sha2::Sha256::digest(data)))
+}
+
+/// An ECDSA public key: cosign keys and Fulcio leaves are P-256, Fulcio CAs P-384.
+enum VerifyingKey {
+    P256(p256::ecdsa::VerifyingKey),
+    P384(p384::ecdsa::VerifyingKey),
+}
+
+impl VerifyingKey {
+    fn from_spki_der(der: &[u8]) -> Result<Self, String> {
+        use p256::pkcs8::DecodePublicKey;
+        if let Ok(key) = p256::ecdsa::VerifyingKey::from_public_key_der(der) {
+            return Ok(Self::P256(key));
+        }
+        p384::ecdsa::VerifyingKey::from_public_key_der(der)
+            .map(Self::P384)
+            .map_err(|_| "unsupported public key (expected ECDSA P-256 or P-384)".to_string())
+    }
+
+    fn from_pem(pem: &str) -> Result<Self, String> {
+        let (label, der) = x509_cert::der::pem::decode_vec(pem.trim().as_bytes())
+            .map_err(|_| "malformed PEM public key")?;
+        if label != "PUBLIC KEY" {
+            return Err(format!("expected a PUBLIC KEY, found {}", label));
+        }
+        Self::from_spki_der(&der)
+    }
+
+    /// Verify a DER signature over `message`, hashed the way cosign does for the curve.
+    fn verify_message(&self, message: &[u8], signature: &[u8]) -> bool {
+        match self {
+            Self::P256(_) => self.verify_prehash(&sha2::Sha256::digest(message), signature),
+            Self::P384(_) => {
+                self.verify_prehash(&sha2::Sha384::digest(message), signature)
+                    || self.verify_prehash(&sha2::Sha256::digest(message), signature)
+            }
+        }
+    }
+
+    fn verify_prehash(&self, prehash: &[u8], signature: &[u8]) -> bool {
+        use p256::ecdsa::signature::hazmat::PrehashVerifier;
+        match self {
+            Self::P256(key) => p256::ecdsa::Signature::from_der(signature)
+                .is_ok_and(|sig| key.verify_prehash(prehash, &sig).is_ok()),
+            Self::P384(key) => p384::ecdsa::Signature::from_der(signature)
+                .is_ok_and(|sig| key.verify_prehash(prehash, &sig).is_ok()),
+        }
+    }
+}
+
+/// Trust material of every Docker upstream with a cosign policy, keyed by upstream URL.
+/// Parsed when the config is loaded and on SIGHUP, so a pull reads no key files.
+#[derive(Default)]
+pub struct CosignTrust {
+    upstreams: HashMap<String, Result<Arc<TrustMaterial>, String>>,
+}
+
+impl CosignTrust {
+    /// Parse the keys, Fulcio roots and Rekor keys of `upstreams`' cosign policies.
+    /// Material that fails to load is an error for an enforce policy; an audit policy
+    /// logs it and fails (but serves) every check.
+    pub fn load(upstreams: &[DockerUpstream]) -> Result<Self, String> {
+        let mut loaded = HashMap::new();
+        for upstream in upstreams {
+            let Some(ref policy) = upstream.cosign else {
+                continue;
+            };
+            let trust = match TrustMaterial::load(policy) {
+                Ok(trust) => Ok(Arc::new(trust)),
+                Err(e) if policy.mode == SignatureMode::Enforce => {
+                    return Err(format!("cosign policy for {}: {}", upstream.url, e));
+                }
+                Err(e) => {
+                    tracing::error!(upstream = %upstream.url, error = %e, "Cosign policy: cannot load trust material");
+                    Err(e)
+                }
+            };
+            loaded.insert(upstream.url.clone(), trust);
+        }
+        Ok(Self { upstreams: loaded })
+    }
+
+    fn get(&self, upstream: &str) -> Result<Arc<TrustMaterial>, String> {
+        self.upstreams
+            .get(upstream)
+            .cloned()
+            .unwrap_or_else(|| Err("no trust material loaded".to_string()))
+    }
+}
+
+/// Keys and certificates a policy trusts, parsed from PEM files or inline PEM.
+struct TrustMaterial {
+    keys: Vec<VerifyingKey>,
+    fulcio_roots: Vec<Certificate>,
+    rekor: Vec<VerifyingKey>,
+}
+
+impl TrustMaterial {
+    fn load(policy: &CosignPolicy) -> Result<Self, String> {
+        let keys = policy
+            .keys
+            .iter()
+            .map(|k| read_pem(k).and_then(|pem| VerifyingKey::from_pem(&pem)))
+            .collect::<Result<_, _>>()?;
+        let rekor = policy
+            .rekor_keys
+            .iter()
+            .map(|k| read_pem(k).and_then(|pem| VerifyingKey::from_pem(&pem)))
+            .collect::<Result<_, _>>()?;
+        let mut fulcio_roots = Vec::new();
+        for root in &policy.fulcio_roots {
+            let pem = read_pem(root)?;
+            fulcio_roots.extend(
+                Certificate::load_pem_chain(pem.as_bytes())
+                    .map_err(|_| format!("malformed Fulcio root {}", root))?,
+            );
+        }
+        Ok(Self {
+            keys,
+            fulcio_roots,
+            rekor,
+        })
+    }
+}
+
+/// A key-material entry is inline PEM or a path to a PEM file.
+fn read_pem(entry: &str) -> Result<String, String> {
+    if entry.trim_start().starts_with("-----BEGIN") {
+        return Ok(entry.to_string());
+    }
+    std::fs::read_to_string(entry).map_err(|e| format!("cannot read {}: {}", entry, e))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::config::CosignIdentity;
+    use p256::ecdsa::signature::Signer;
+    use p256::pkcs8::EncodePublicKey;
+    use std::str::FromStr;
+    use x509_cert::der::asn1::{BitString, Ia5String, OctetString, UtcTime};
+    use x509_cert::ext::Extension;
+    use x509_cert::name::Name;
+    use x509_cert::serial_number::SerialNumber;
+    use x509_cert::spki::{AlgorithmIdentifierOwned, ObjectIdentifier, SubjectPublicKeyInfoOwned};
+    use x509_cert::time::{Time, Validity};
+    use x509_cert::TbsCertificate;
+
+    const DIGEST: &str = "sha256:1111111111111111111111111111111111111111111111111111111111111111";
+
+    fn payload_for(digest: &str) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "critical": {
+                "identity": {"docker-reference": "example.com/app"},
+                "image": {"docker-manifest-digest": digest},
+                "type": "cosign container image signature"
+            },
+            "optional": null
+        }))
+        .unwrap()
+    }
+
+    fn key_pair() -> (p256::ecdsa::SigningKey, String) {
+        let sk = p256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let pem = sk
+            .verifying_key()
+            .to_public_key_pem(Default::default())
+            .unwrap();
+        (sk, pem)
+    }
+
+    fn sign(sk: &p256::ecdsa::SigningKey, msg: &[u8]) -> Vec<u8> {
+        let sig: p256::ecdsa::Signature = sk.sign(msg);
+        sig.to_der().as_bytes().to_vec()
+    }
+
+    fn layer(signature: &[u8], extra: &[(&str, String)]) -> Value {
+        let mut annotations = serde_json::Map::new();
+        annotations.insert(ANN_SIGNATURE.into(), STANDARD.encode(signature).into());
+        for (k, v) in extra {
+            annotations.insert((*k).into(), v.clone().into());
+        }
+        serde_json::json!({
+            "mediaType": SIMPLESIGNING_TYPE,
+            "digest": "sha256:00",
+            "size": 200,
+            "annotations": annotations,
+        })
+    }
+
+    fn policy_with_key(pem: &str) -> CosignPolicy {
+        CosignPolicy {
+            keys: vec![pem.to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_key_signature_verifies() {
+        let (sk, pem) = key_pair();
+        let payload = payload_for(DIGEST);
+        let policy = policy_with_key(&pem);
+        let trust = TrustMaterial::load(&policy).unwrap();
+        let signed = layer(&sign(&sk, &payload), &[]);
+        assert!(verify_layer(&trust, &policy, &signed, &payload, DIGEST).is_ok());
+
+        // Another key's signature, or a payload for another manifest, is rejected.
+        let (other, _) = key_pair();
+        let forged = layer(&sign(&other, &payload), &[]);
+        assert!(verify_layer(&trust, &policy, &forged, &payload, DIGEST).is_err());
+        let other_digest = DIGEST.replace('1', "2");
+        let err = verify_layer(&trust, &policy, &signed, &payload, &other_digest).unwrap_err();
+        assert!(err.contains("different manifest"), "{err}");
+    }
+
+    fn rekor_bundle(rekor: &p256::ecdsa::SigningKey, signature: &[u8], payload: &[u8]) -> String {
+        let entry = serde_json::json!({
+            "apiVersion": "0.0.1",
+            "kind": "hashedrekord",
+            "spec": {
+                "data": {"hash": {"algorithm": "sha256", "value": hex::encode(sha2::Sha256::digest(payload))}},
+                "signature": {"content": STANDARD.encode(signature), "publicKey": {"content": ""}}
+            }
+        });
+        let body = STANDARD.encode(serde_json::to_vec(&entry).unwrap());
+        let integrated_time = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let canonical = format!(
+            r#"{{"body":"{}","integratedTime":{},"logID":"abcd","logIndex":7}}"#,
+            body, integrated_time
+        );
+        serde_json::json!({
+            "SignedEntryTimestamp": STANDARD.encode(sign(rekor, canonical.as_bytes())),
+            "Payload": {"body": body, "integratedTime": integrated_time, "logIndex": 7, "logID": "abcd"}
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_rekor_bundle_required_and_bound_to_signature() {
+        let (sk, pem) = key_pair();
+        let (rekor, rekor_pem) = key_pair();
+        let payload = payload_for(DIGEST);
+        let signature = sign(&sk, &payload);
+        let policy = CosignPolicy {
+            rekor_keys: vec![rekor_pem],
+            ..policy_with_key(&pem)
+        };
+        let trust = TrustMaterial::load(&policy).unwrap();
+
+        let bare = layer(&signature, &[]);
+        let err = verify_layer(&trust, &policy, &bare, &payload, DIGEST).unwrap_err();
+        assert!(err.contains("Rekor bundle missing"), "{err}");
+
+        let bundle = rekor_bundle(&rekor, &signature, &payload);
+        let bundled = layer(&signature, &[(ANN_BUNDLE, bundle)]);
+        assert!(verify_layer(&trust, &policy, &bundled, &payload, DIGEST).is_ok());
+
+        // A bundle logged for a different signature does not vouch for this one.
+        let other_sig = sign(&sk, b"something else");
+        let wrong = layer(
+            &signature,
+            &[(ANN_BUNDLE, rekor_bundle(&rekor, &other_sig, &payload))],
+        );
+        assert!(verify_layer(&trust, &policy, &wrong, &payload, DIGEST).is_err());
+    }
+
+    fn name(s: &str) -> Name {
+        Name::from_str(s).unwrap()
+    }
+
+    /// Mint a certificate for `subject_key`, signed by `issuer_key` with ecdsa-with-SHA384.
+    fn mint(
+        subject: &str,
+        issuer: &str,
+        subject_spki: SubjectPublicKeyInfoOwned,
+        issuer_key: &p384::ecdsa::SigningKey,
+        extensions: Vec<Extension>,
+    ) -> Certificate {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap();
+        let alg = AlgorithmIdentifierOwned {
+            oid: ObjectIdentifier::new_unwrap(OID_ECDSA_SHA384),
+            parameters: None,
+        };
+        let tbs = TbsCertificate {
+            version: x509_cert::Version::V3,
+            serial_number: SerialNumber::from(1u32),
+            signature: alg.clone(),
+            issuer: name(issuer),
+            validity: Validity {
+                not_before: Time::UtcTime(
+                    UtcTime::from_unix_duration(now - Duration::from_secs(300)).unwrap(),
+                ),
+                not_after: Time::UtcTime(
+                    UtcTime::from_unix_duration(now + Duration::from_secs(300)).unwrap(),
+                ),
+            },
+            subject: name(subject),
+            subject_public_key_info: subject_spki,
+            issuer_unique_id: None,
+            subject_unique_id: None,
+            extensions: Some(extensions),
+        };
+        let sig: p384::ecdsa::Signature = issuer_key.sign(&tbs.to_der().unwrap());
+        Certificate {
+            tbs_certificate: tbs,
+            signature_algorithm: alg,
+            signature: BitString::from_bytes(sig.to_der().as_bytes()).unwrap(),
+        }
+    }
+
+    fn ext(oid: &str, value: Vec<u8>) -> Extension {
+        Extension {
+            extn_id: ObjectIdentifier::new_unwrap(oid),
+            critical: false,
+            extn_value: OctetString::new(value).unwrap(),
+        }
+    }
+
+    fn ca_ext() -> Extension {
+        ext(
+            "2.5.29.19",
+            BasicConstraints {
+                ca: true,
+                path_len_constraint: None,
+            }
+            .to_der()
+            .unwrap(),
+        )
+    }
+
+    fn spki_of<K: EncodePublicKey>(key: &K) -> SubjectPublicKeyInfoOwned {
+        SubjectPublicKeyInfoOwned::from_der(key.to_public_key_der().unwrap().as_bytes()).unwrap()
+    }
+
+    fn pem_of(cert: &Certificate) -> String {
+        use x509_cert::der::EncodePem;
+        cert.to_pem(Default::default()).unwrap()
+    }
+
+    /// A keyless signature over `payload_for(DIGEST)`: leaf ← intermediate (from the chain
+    /// annotation) ← root, and a policy trusting the root, the Rekor key and the leaf's
+    /// identity. The leaf carries the code-signing EKU Fulcio issues unless `code_signing`
+    /// is false.
+    fn keyless_fixture(code_signing: bool) -> (Value, CosignPolicy) {
+        let root_key = p384::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let mid_key = p384::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let (leaf_key, _) = key_pair();
+        let (rekor, rekor_pem) = key_pair();
+
+        let root = mint(
+            "CN=sigstore",
+            "CN=sigstore",
+            spki_of(root_key.verifying_key()),
+            &root_key,
+            vec![ca_ext()],
+        );
+        let mid = mint(
+            "CN=sigstore-intermediate",
+            "CN=sigstore",
+            spki_of(mid_key.verifying_key()),
+            &root_key,
+            vec![ca_ext()],
+        );
+        let san = SubjectAltName(vec![GeneralName::UniformResourceIdentifier(
+            Ia5String::new(
+                "https://github.com/acme/app/.github/workflows/release.yml@refs/heads/main",
+            )
+            .unwrap(),
+        )]);
+        let mut extensions = vec![
+            ext("2.5.29.17", san.to_der().unwrap()),
+            ext(
+                OID_FULCIO_ISSUER_V2,
+                x509_cert::der::asn1::Utf8StringRef::new(
+                    "https://token.actions.githubusercontent.com",
+                )
+                .unwrap()
+                .to_der()
+                .unwrap(),
+            ),
+        ];
+        if code_signing {
+            let eku = ExtendedKeyUsage(vec![ObjectIdentifier::new_unwrap(OID_CODE_SIGNING)]);
+            extensions.push(ext("2.5.29.37", eku.to_der().unwrap()));
+        }
+        let leaf = mint(
+            "CN=sigstore",
+            "CN=sigstore-intermediate",
+            spki_of(leaf_key.verifying_key()),
+            &mid_key,
+            extensions,
+        );
+
+        let payload = payload_for(DIGEST);
+        let signature = sign(&leaf_key, &payload);
+        let layer = layer(
+            &signature,
+            &[
+                (ANN_CERTIFICATE, pem_of(&leaf)),
+                (ANN_CHAIN, format!("{}{}", pem_of(&mid), pem_of(&root))),
+                (ANN_BUNDLE, rekor_bundle(&rekor, &signature, &payload)),
+            ],
+        );
+        let policy = CosignPolicy {
+            identities: vec![CosignIdentity {
+                issuer: "https://token.actions.githubusercontent.com".into(),
+                subject: "https://github.com/acme/*".into(),
+            }],
+            fulcio_roots: vec![pem_of(&root)],
+            rekor_keys: vec![rekor_pem],
+            ..Default::default()
+        };
+        (layer, policy)
+    }
+
+    /// Keyless: the certificate chains to a configured root and the Fulcio issuer
+    /// extension and SAN match the policy identities.
+    #[test]
+    fn test_keyless_certificate_chain_and_identity() {
+        let (layer, policy) = keyless_fixture(true);
+        let payload = payload_for(DIGEST);
+        let trust = TrustMaterial::load(&policy).unwrap();
+        assert!(verify_layer(&trust, &policy, &layer, &payload, DIGEST).is_ok());
+
+        // Another workload's identity is refused.
+        let strict = CosignPolicy {
+            identities: vec![CosignIdentity {
+                issuer: "https://token.actions.githubusercontent.com".into(),
+                subject: "https://github.com/other/*".into(),
+            }],
+            ..policy.clone()
+        };
+        let err = verify_layer(&trust, &strict, &layer, &payload, DIGEST).unwrap_err();
+        assert!(err.contains("not trusted"), "{err}");
+
+        // A chain rooted elsewhere is refused.
+        let rogue_key = p384::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let rogue = mint(
+            "CN=sigstore",
+            "CN=sigstore",
+            spki_of(rogue_key.verifying_key()),
+            &rogue_key,
+            vec![ca_ext()],
+        );
+        let untrusted = CosignPolicy {
+            fulcio_roots: vec![pem_of(&rogue)],
+            ..policy.clone()
+        };
+        let trust = TrustMaterial::load(&untrusted).unwrap();
+        let err = verify_layer(&trust, &untrusted, &layer, &payload, DIGEST).unwrap_err();
+        assert!(err.contains("trusted Fulcio root"), "{err}");
+    }
+
+    /// A certificate from the trusted CA that was not issued for code signing (no
+    /// `id-kp-codeSigning` extended key usage) does not vouch for a signature.
+    #[test]
+    fn test_keyless_certificate_without_code_signing_rejected() {
+        let (layer, policy) = keyless_fixture(false);
+        let trust = TrustMaterial::load(&policy).unwrap();
+        let err = verify_layer(&trust, &policy, &layer, &payload_for(DIGEST), DIGEST).unwrap_err();
+        assert!(err.contains("code signing"), "{err}");
+    }
+
+    #[test]
+    fn test_is_cosign_tag() {
+        let hex = "a".repeat(64);
+        assert!(is_cosign_tag(&format!("sha256-{hex}.sig")));
+        assert!(is_cosign_tag(&format!("sha256-{hex}.att")));
+        assert!(!is_cosign_tag("sha256-abc.sig"));
+        assert!(!is_cosign_tag("latest"));
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod integration_tests {
+    use super::{ANN_SIGNATURE, SIMPLESIGNING_TYPE};
+    use crate::config::{CosignPolicy, DockerUpstream, SignatureMode};
+    use crate::test_helpers::{create_test_context_with_config, send, TestContext};
+    use axum::body::Body;
+    use axum::http::{Method, StatusCode};
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use p256::ecdsa::signature::Signer;
+    use p256::pkcs8::EncodePublicKey;
+    use sha2::Digest;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const OCI_MANIFEST: &str = "application/vnd.oci.image.manifest.v1+json";
+
+    fn digest_of(data: &[u8]) -> String {
+        format!("sha256:{}", hex::encode(sha2::Sha256::digest(data)))
+    }
+
+    fn image_manifest() -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "schemaVersion": 2,
+            "mediaType": OCI_MANIFEST,
+            "config": {"mediaType": "application/vnd.oci.image.config.v1+json", "digest": "sha256:aa", "size": 2},
+            "layers": []
+        }))
+        .unwrap()
+    }
+
+    async fn serve_manifest(
+        upstream: &MockServer,
+        reference: &str,
+        body: Vec<u8>,
+        media_type: &str,
+    ) {
+        Mock::given(method("GET"))
+            .and(path(format!("/v2/library/app/manifests/{reference}")))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", media_type)
+                    .set_body_bytes(body),
+            )
+            .mount(upstream)
+            .await;
+    }
+
+    /// Push what `cosign sign --key` pushes for `digest`: the payload blob and the
+    /// `sha256-<hex>.sig` manifest whose layer carries the signature annotation.
+    async fn serve_signature(upstream: &MockServer, digest: &str, sk: &p256::ecdsa::SigningKey) {
+        let payload = serde_json::to_vec(&serde_json::json!({
+            "critical": {
+                "identity": {"docker-reference": "example.com/library/app"},
+                "image": {"docker-manifest-digest": digest},
+                "type": "cosign container image signature"
+            },
+            "optional": null
+        }))
+        .unwrap();
+        let sig: p256::ecdsa::Signature = sk.sign(&payload);
+        let payload_digest = digest_of(&payload);
+        let sig_manifest = serde_json::to_vec(&serde_json::json!({
+            "schemaVersion": 2,
+            "mediaType": OCI_MANIFEST,
+            "config": {"mediaType": "application/vnd.oci.image.config.v1+json", "digest": "sha256:bb", "size": 2},
+            "layers": [{
+                "mediaType": SIMPLESIGNING_TYPE,
+                "digest": payload_digest,
+                "size": payload.len(),
+                "annotations": {ANN_SIGNATURE: STANDARD.encode(sig.to_der().as_bytes())}
+            }]
+        }))
+        .unwrap();
+        serve_manifest(
+            upstream,
+            &format!("{}.sig", digest.replacen(':', "-", 1)),
+            sig_manifest,
+            OCI_MANIFEST,
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/v2/library/app/blobs/{payload_digest}")))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(payload))
+            .mount(upstream)
+            .await;
+    }
+
+    fn context(
+        upstream: &MockServer,
+        key: &p256::ecdsa::SigningKey,
+        mode: SignatureMode,
+    ) -> TestContext {
+        let pem = key
+            .verifying_key()
+            .to_public_key_pem(Default::default())
+            .unwrap();
+        let url = upstream.uri();
+        create_test_context_with_config(move |cfg| {
+            cfg.docker.upstreams = vec![DockerUpstream {
+                url,
+                auth: None,
+                namespace: None,
+                prefix: None,
+                cosign: Some(CosignPolicy {
+                    mode,
+                    keys: vec![pem],
+                    ..Default::default()
+                }),
+            }];
+        })
+    }
+
+    fn random_key() -> p256::ecdsa::SigningKey {
+        p256::ecdsa::SigningKey::random(&mut rand::thread_rng())
+    }
+
+    #[tokio::test]
+    async fn test_signed_image_is_served() {
+        let upstream = MockServer::start().await;
+        let key = random_key();
+        let manifest = image_manifest();
+        serve_manifest(&upstream, "latest", manifest.clone(), OCI_MANIFEST).await;
+        serve_signature(&upstream, &digest_of(&manifest), &key).await;
+
+        let ctx = context(&upstream, &key, SignatureMode::Enforce);
+        let response = send(
+            &ctx.app,
+            Method::GET,
+            "/v2/library/app/manifests/latest",
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_image_blocked_and_not_cached() {
+        let upstream = MockServer::start().await;
+        serve_manifest(&upstream, "latest", image_manifest(), OCI_MANIFEST).await;
+
+        let ctx = context(&upstream, &random_key(), SignatureMode::Enforce);
+        for _ in 0..2 {
+            // The second pull must be re-checked, not served from a cache the first filled.
+            let response = send(
+                &ctx.app,
+                Method::GET,
+                "/v2/library/app/manifests/latest",
+                Body::empty(),
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+            assert_eq!(response.headers()["x-nora-rule"], "cosign");
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_image_served_in_audit_mode() {
+        let upstream = MockServer::start().await;
+        serve_manifest(&upstream, "latest", image_manifest(), OCI_MANIFEST).await;
+
+        let ctx = context(&upstream, &random_key(), SignatureMode::Audit);
+        let response = send(
+            &ctx.app,
+            Method::GET,
+            "/v2/library/app/manifests/latest",
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_signature_by_untrusted_key_blocked() {
+        let upstream = MockServer::start().await;
+        let manifest = image_manifest();
+        serve_manifest(&upstream, "latest", manifest.clone(), OCI_MANIFEST).await;
+        serve_signature(&upstream, &digest_of(&manifest), &random_key()).await;
+
+        let ctx = context(&upstream, &random_key(), SignatureMode::Enforce);
+        let response = send(
+            &ctx.app,
+            Method::GET,
+            "/v2/library/app/manifests/latest",
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    /// Serve a multi-arch index signed by `key` as `latest`, and its one unsigned
+    /// platform manifest; returns the platform manifest's digest.
+    async fn serve_signed_index(upstream: &MockServer, key: &p256::ecdsa::SigningKey) -> String {
+        let child = image_manifest();
+        let child_digest = digest_of(&child);
+        let index_type = "application/vnd.oci.image.index.v1+json";
+        let index = serde_json::to_vec(&serde_json::json!({
+            "schemaVersion": 2,
+            "mediaType": index_type,
+            "manifests": [{"mediaType": OCI_MANIFEST, "digest": child_digest, "size": child.len()}]
+        }))
+        .unwrap();
+        serve_manifest(upstream, "latest", index.clone(), index_type).await;
+        serve_manifest(upstream, &child_digest, child, OCI_MANIFEST).await;
+        serve_signature(upstream, &digest_of(&index), key).await;
+        child_digest
+    }
+
+    /// A signed multi-arch index vouches for its platform manifests, which carry no
+    /// signature of their own; a digest outside the index does not inherit it.
+    #[tokio::test]
+    async fn test_index_signature_covers_platform_manifests() {
+        let upstream = MockServer::start().await;
+        let key = random_key();
+        let child_digest = serve_signed_index(&upstream, &key).await;
+        let stray = serde_json::to_vec(
+            &serde_json::json!({"schemaVersion": 2, "mediaType": OCI_MANIFEST, "layers": [1]}),
+        )
+        .unwrap();
+        let stray_digest = digest_of(&stray);
+        serve_manifest(&upstream, &stray_digest, stray, OCI_MANIFEST).await;
+
+        let ctx = context(&upstream, &key, SignatureMode::Enforce);
+        let response = send(
+            &ctx.app,
+            Method::GET,
+            "/v2/library/app/manifests/latest",
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = send(
+            &ctx.app,
+            Method::GET,
+            &format!("/v2/library/app/manifests/{child_digest}"),
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = send(
+            &ctx.app,
+            Method::GET,
+            &format!("/v2/library/app/manifests/{stray_digest}"),
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    /// After a restart the verdicts are gone, but the index the client pulled before is
+    /// still cached: its platform manifest is checked against that index, not blocked.
+    #[tokio::test]
+    async fn test_platform_manifest_of_cached_index_after_restart() {
+        let upstream = MockServer::start().await;
+        let key = random_key();
+        let child_digest = serve_signed_index(&upstream, &key).await;
+
+        let ctx = context(&upstream, &key, SignatureMode::Enforce);
+        let response = send(
+            &ctx.app,
+            Method::GET,
+            "/v2/library/app/manifests/latest",
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        // Let the background cache write land, then forget the verdicts.
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        ctx.state.cosign_children.clear();
+
+        let response = send(
+            &ctx.app,
+            Method::GET,
+            &format!("/v2/library/app/manifests/{child_digest}"),
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -1,21 +1,31 @@
 // Copyright (c) 2026 The Nora Authors
 // SPDX-License-Identifier: MIT
 
-//! RubyGems proxy registry.
+//! RubyGems registry: hosted gems plus a caching proxy for rubygems.org.
 //!
-//! Implements a caching proxy for rubygems.org:
 //!   GET /gems/specs.4.8.gz             — full gem index (binary, mutable, TTL cache)
 //!   GET /gems/latest_specs.4.8.gz      — latest gem index (binary, mutable, TTL cache)
 //!   GET /gems/prerelease_specs.4.8.gz  — prerelease index (binary, mutable, TTL cache)
+//!   GET /gems/versions                 — compact index name list (text, mutable, TTL cache)
 //!   GET /gems/info/{name}              — compact index (text, mutable, TTL cache)
 //!   GET /gems/gems/{name}-{version}.gem — gem download (binary, immutable cache)
 //!   GET /gems/quick/Marshal.4.8/{name}-{version}.gemspec.rz — gemspec (binary, immutable cache)
+//!   POST   /gems/api/v1/gems           — `gem push` (raw .gem body)
+//!   DELETE /gems/api/v1/gems/yank      — `gem yank` (form: gem_name, version, platform)
+//!
+//! Pushed gems are recorded under `gems/hosted/{name}/{version}.json` and merged
+//! over the upstream indexes: appended to the Marshal specs arrays, to
+//! `/versions`, and to `/info/{name}` (where a hosted version replaces an
+//! upstream line of the same version). Yank keeps the `.gem` for audit and
+//! drops the version from every index.
 //!
 //! Client config:
 //!   bundle config mirror.https://rubygems.org http://nora:4000/gems/
+//!   gem push --host http://nora:4000/gems acme-1.0.0.gem   (GEM_HOST_API_KEY=nra_…)
 
 use crate::activity_log::{ActionType, ActivityEntry};
 use crate::audit::AuditEntry;
+use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
 use crate::registry::{
     circuit_open_response, proxy_fetch, proxy_fetch_conditional, read_validators, write_validators,
     ProxyError, Revalidation, Validators,
@@ -25,12 +35,17 @@ use crate::secrets::expose_opt;
 use crate::AppState;
 use axum::{
     body::Bytes,
-    extract::{Path, State},
-    http::{header, HeaderValue, StatusCode},
+    extract::{Form, Path, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
-    Router,
+    routing::{delete, get, post},
+    Extension, Router,
 };
+use serde::{Deserialize, Serialize};
+use serde_yaml_ng::Value as YamlValue;
+use sha2::Digest;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
 use std::time::Duration;
 
 const UPSTREAM_DEFAULT: &str = "https://rubygems.org";
@@ -42,11 +57,15 @@ pub fn routes() -> Router<AppState> {
         .route("/gems/latest_specs.4.8.gz", get(latest_specs_index))
         .route("/gems/prerelease_specs.4.8.gz", get(prerelease_specs_index))
         // Compact index (mutable)
+        .route("/gems/versions", get(versions_index))
         .route("/gems/info/{name}", get(compact_index))
         // Gem download (immutable) — wildcard because axum forbids two params per segment
         .route("/gems/gems/{filename}", get(download_gem))
         // Gemspec (immutable)
         .route("/gems/quick/Marshal.4.8/{filename}", get(download_gemspec))
+        // Hosted publishing
+        .route("/gems/api/v1/gems", post(push_gem))
+        .route("/gems/api/v1/gems/yank", delete(yank_gem))
 }
 
 use crate::cache_ttl::is_within_ttl;
@@ -54,18 +73,32 @@ use crate::cache_ttl::is_within_ttl;
 // ── Index endpoints (mutable, TTL cached) ─────────────────────────────
 
 async fn specs_index(State(state): State<AppState>) -> Response {
-    fetch_index(&state, "specs.4.8.gz").await
+    specs_with_hosted(&state, "specs.4.8.gz").await
 }
 
 async fn latest_specs_index(State(state): State<AppState>) -> Response {
-    fetch_index(&state, "latest_specs.4.8.gz").await
+    specs_with_hosted(&state, "latest_specs.4.8.gz").await
 }
 
 async fn prerelease_specs_index(State(state): State<AppState>) -> Response {
-    fetch_index(&state, "prerelease_specs.4.8.gz").await
+    specs_with_hosted(&state, "prerelease_specs.4.8.gz").await
+}
+
+/// A mutable upstream index, fresh or (under `serve_stale`) from the cache.
+struct UpstreamIndex {
+    data: Vec<u8>,
+    stale: bool,
 }
 
 async fn fetch_index(state: &AppState, filename: &str) -> Response {
+    match upstream_index(state, filename).await {
+        Ok(index) if index.stale => stale_response(index.data, "application/gzip"),
+        Ok(index) => with_binary(index.data, "application/gzip"),
+        Err(response) => response,
+    }
+}
+
+async fn upstream_index(state: &AppState, filename: &str) -> Result<UpstreamIndex, Response> {
     let storage_key = format!("gems/{}", filename);
 
     // Eager cache read — preserve data for serve-stale fallback
@@ -81,7 +114,10 @@ async fn fetch_index(state: &AppState, filename: &str) -> Response {
                     crate::registry_type::RegistryType::Gems,
                     "CACHE",
                 ));
-                return with_binary(data.to_vec(), "application/gzip");
+                return Ok(UpstreamIndex {
+                    data: data.to_vec(),
+                    stale: false,
+                });
             }
         }
     }
@@ -115,10 +151,13 @@ async fn fetch_index(state: &AppState, filename: &str) -> Response {
 
             // Cache in background (overwrite — mutable content)
             state.spawn_cache("gems", storage_key, Bytes::from(bytes.clone()));
-            with_binary(bytes, "application/gzip")
+            Ok(UpstreamIndex {
+                data: bytes,
+                stale: false,
+            })
         }
-        Err(ProxyError::NotFound) => StatusCode::NOT_FOUND.into_response(),
-        Err(ProxyError::CircuitOpen(reg)) => circuit_open_response(&reg),
+        Err(ProxyError::NotFound) => Err(StatusCode::NOT_FOUND.into_response()),
+        Err(ProxyError::CircuitOpen(reg)) => Err(circuit_open_response(&reg)),
         Err(e) => {
             if let Some(ref data) = cached_data {
                 if state.config.gems.serve_stale {
@@ -128,29 +167,14 @@ async fn fetch_index(state: &AppState, filename: &str) -> Response {
                         error = ?e,
                         "RubyGems upstream error, serving stale index"
                     );
-                    return (
-                        StatusCode::OK,
-                        [
-                            (
-                                header::CONTENT_TYPE,
-                                HeaderValue::from_static("application/gzip"),
-                            ),
-                            (
-                                header::CACHE_CONTROL,
-                                HeaderValue::from_static("public, max-age=0, must-revalidate"),
-                            ),
-                            (
-                                axum::http::header::HeaderName::from_static("x-nora-stale"),
-                                HeaderValue::from_static("true"),
-                            ),
-                        ],
-                        data.to_vec(),
-                    )
-                        .into_response();
+                    return Ok(UpstreamIndex {
+                        data: data.to_vec(),
+                        stale: true,
+                    });
                 }
             }
             tracing::debug!(filename, error = ?e, "RubyGems upstream error");
-            StatusCode::BAD_GATEWAY.into_response()
+            Err(StatusCode::BAD_GATEWAY.into_response())
         }
     }
 }
@@ -159,12 +183,24 @@ async fn fetch_index(state: &AppState, filename: &str) -> Response {
 
 async fn compact_index(
     State(state): State<AppState>,
-    headers: axum::http::HeaderMap,
+    headers: HeaderMap,
     Path(name): Path<String>,
 ) -> Response {
     if !is_valid_gem_name(&name) {
         return StatusCode::BAD_REQUEST.into_response();
     }
+    let hosted = hosted_versions(&state.storage, &name).await;
+    if hosted.is_empty() {
+        return upstream_compact_index(&state, &headers, &name).await;
+    }
+    // Hosted versions skip curation; upstream's lines still pass through it.
+    let upstream = upstream_info(&state, &headers, &name).await;
+    state.metrics.record_download("gems");
+    with_text(merge_info(upstream.as_deref(), &hosted))
+}
+
+async fn upstream_compact_index(state: &AppState, headers: &HeaderMap, name: &str) -> Response {
+    let name = name.to_string();
 
     // Curation check. #733 serve-local: an internal-namespace gem is operator-owned — skip
     // curation and serve any local copy below; block the upstream branch separately.
@@ -177,7 +213,7 @@ async fn compact_index(
         if let Some(response) = crate::curation::check_download(
             &state.curation().curation_engine,
             state.bypass_token().as_deref(),
-            &headers,
+            headers,
             crate::curation::RegistryType::Gems,
             &name,
             None,
@@ -222,7 +258,7 @@ async fn compact_index(
         .unwrap_or_else(|| StatusCode::NOT_FOUND.into_response());
     }
 
-    let proxy_url = upstream_url(&state);
+    let proxy_url = upstream_url(state);
     let url = format!("{}/info/{}", proxy_url.trim_end_matches('/'), name);
 
     // Revalidate stale metadata with a conditional request when enabled (a cheap
@@ -401,6 +437,10 @@ async fn download_gem(
 
     let artifact = format!("{}-{}", name, version);
     let storage_key = format!("gems/gems/{}.gem", artifact);
+    // A gem pushed to NORA: no curation, no quarantine, never fetched upstream.
+    let hosted = hosted_record(&state.storage, &name, &version)
+        .await
+        .is_some();
 
     // Release date for the digest-quarantine first-seen clock (#748/#750). The
     // compact index carries no dates, so fetch the per-version created_at from the
@@ -410,7 +450,9 @@ async fn download_gem(
     // (quarantine `record` is idempotent), so a cheap local stat skips the round-trip.
     let cached_meta = state.storage.stat(&storage_key).await;
     let already_cached = cached_meta.is_some();
-    let publish_date = if state.config.gems.proxy.is_none() {
+    let publish_date = if hosted {
+        None
+    } else if state.config.gems.proxy.is_none() {
         crate::curation::extract_mtime_as_publish_date(&state.storage, &storage_key).await
     } else if !already_cached && state.config.server.trust_upstream_dates {
         match state.config.gems.proxy.as_deref() {
@@ -437,7 +479,7 @@ async fn download_gem(
         crate::curation::RegistryType::Gems,
         &name,
     );
-    if !internal {
+    if !internal && !hosted {
         if let Some(response) = crate::curation::check_download(
             &state.curation().curation_engine,
             state.bypass_token().as_deref(),
@@ -472,7 +514,7 @@ async fn download_gem(
     // partial read cannot be hashed, so neither the quarantine gate nor the curation
     // integrity check can run on it: the range serve stands down while quarantine holds
     // artifacts, and integrity is the client's own checksum, as docker does.
-    if matches!(q_mode, crate::digest_quarantine::QuarantineMode::Off) {
+    if hosted || matches!(q_mode, crate::digest_quarantine::QuarantineMode::Off) {
         if let Some(meta) = cached_meta.as_ref() {
             if let Some(response) = crate::registry::range::range_response(
                 &state.storage,
@@ -505,14 +547,16 @@ async fn download_gem(
             GateOutcome::Unpinned(blob) => blob.into_inner(),
         };
         // Curation integrity
-        if let Some(response) = crate::curation::verify_integrity(
-            &state.curation().curation_engine,
-            crate::curation::RegistryType::Gems,
-            &name,
-            Some(&version),
-            &data,
-        ) {
-            return response;
+        if !hosted {
+            if let Some(response) = crate::curation::verify_integrity(
+                &state.curation().curation_engine,
+                crate::curation::RegistryType::Gems,
+                &name,
+                Some(&version),
+                &data,
+            ) {
+                return response;
+            }
         }
 
         state.metrics.record_download("gems");
@@ -523,7 +567,9 @@ async fn download_gem(
             crate::registry_type::RegistryType::Gems,
             "CACHE",
         ));
-        if let Some(resp) = crate::digest_quarantine::proxy_gate_dated(
+        if hosted {
+            // Served as-is below.
+        } else if let Some(resp) = crate::digest_quarantine::proxy_gate_dated(
             &state.digest_store,
             "gems",
             &data,
@@ -541,6 +587,11 @@ async fn download_gem(
         return response;
     }
 
+    // A hosted gem whose file is gone is a 404, never an upstream fetch.
+    if hosted {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
     // #733: an internal-namespace gem with no local copy is never proxied upstream.
     if internal {
         return crate::curation::check_namespace_isolation(
@@ -620,11 +671,15 @@ async fn download_gemspec(State(state): State<AppState>, Path(filename): Path<St
 
     let artifact = format!("{}-{}", name, version);
     let storage_key = format!("gems/quick/Marshal.4.8/{}.gemspec.rz", artifact);
+    let hosted = hosted_record(&state.storage, &name, &version)
+        .await
+        .is_some();
 
     // Mirror the .gem's release date so the gemspec matures together (#748/#750).
     // #754: only fetch on a cache MISS (idempotent record → date ignored on a hit).
     let already_cached = state.storage.stat(&storage_key).await.is_some();
-    let publish_date = if !already_cached
+    let publish_date = if !hosted
+        && !already_cached
         && state.config.gems.proxy.is_some()
         && state.config.server.trust_upstream_dates
     {
@@ -660,6 +715,9 @@ async fn download_gemspec(State(state): State<AppState>, Path(filename): Path<St
             crate::registry_type::RegistryType::Gems,
             "CACHE",
         ));
+        if hosted {
+            return with_binary(data.to_vec(), "application/octet-stream");
+        }
         let (q_mode, q_secs) = crate::digest_quarantine::resolve_global(
             state.config.curation.gems.quarantine.as_ref().or(state
                 .config
@@ -688,6 +746,10 @@ async fn download_gemspec(State(state): State<AppState>, Path(filename): Path<St
         return with_binary(data.to_vec(), "application/octet-stream");
     }
 
+    if hosted {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
     // #68 namespace isolation: a cached internal gem's spec was served above; an
     // internal name with no local copy must not be fetched upstream.
     if let Some(response) = crate::curation::check_namespace_isolation(
@@ -765,185 +827,1161 @@ async fn download_gemspec(State(state): State<AppState>, Path(filename): Path<St
     }
 }
 
-// ── Helpers ────────────────────────────────────────────────────────────
+// ── Hosted publishing ──────────────────────────────────────────────────
 
-fn upstream_url(state: &AppState) -> String {
+/// Largest gemspec accepted once `metadata.gz` is inflated.
+const MAX_GEMSPEC_SIZE: u64 = 1024 * 1024;
+
+/// POST /gems/api/v1/gems — `gem push`. The body is the raw `.gem`.
+async fn push_gem(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    body: Bytes,
+) -> Response {
+    if body.is_empty() {
+        return (StatusCode::BAD_REQUEST, "Empty gem").into_response();
+    }
+    let spec = match read_gemspec(&body) {
+        Ok(spec) => spec,
+        Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
+    };
+    let name = spec.name.clone();
+    let full = full_version(&spec.version, &spec.platform);
+    if !is_valid_gem_name(&name) {
+        return (
+            StatusCode::BAD_REQUEST,
+            "gemspec `name` is not a valid gem name",
+        )
+            .into_response();
+    }
+    if !is_valid_version(&spec.version) || !is_valid_version(&full) {
+        return (
+            StatusCode::BAD_REQUEST,
+            "gemspec `version` or `platform` is not valid",
+        )
+            .into_response();
+    }
+    // Downloads find the version by splitting the file name, so a platform that
+    // splits differently ("universal-darwin-22") could never be served back.
+    if split_gem_filename(&format!("{}-{}", name, full)) != Some((name.clone(), full.clone())) {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("{}-{}.gem cannot be served unambiguously", name, full),
+        )
+            .into_response();
+    }
+
+    // Enforce OIDC namespace_scope on the gem name (#583).
+    if enforce_namespace_scope(&authority, &name).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    let lock = state.publish_lock(&format!("gems/{}", name));
+    let _guard = lock.lock().await;
+
+    // Versions are immutable — including one already cached from upstream, and
+    // one that was pushed and later yanked.
+    let artifact = format!("{}-{}", name, full);
+    let key = format!("gems/gems/{}.gem", artifact);
+    if state.storage.stat(&key).await.is_some() {
+        return (
+            StatusCode::CONFLICT,
+            "Repushing of gem versions is not allowed. Please bump the version number.",
+        )
+            .into_response();
+    }
+
+    let quick = match quick_spec(&spec) {
+        Ok(quick) => quick,
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+    let record = HostedGem {
+        name: name.clone(),
+        version: spec.version.clone(),
+        platform: spec.platform.clone(),
+        sha256: hex::encode(sha2::Sha256::digest(&body)),
+        dependencies: spec
+            .dependencies
+            .iter()
+            .filter(|dep| dep.runtime)
+            .map(|dep| (dep.name.clone(), requirement_clauses(&dep.requirement)))
+            .collect(),
+        required_ruby_version: requirement_clauses(&spec.required_ruby_version),
+        required_rubygems_version: requirement_clauses(&spec.required_rubygems_version),
+        pushed_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        yanked_at: None,
+    };
+
+    // Gem and quick spec first; the record is what makes the version visible.
+    let quick_key = format!("gems/quick/Marshal.4.8/{}.gemspec.rz", artifact);
+    let record_key = hosted_record_key(&name, &full);
+    let stored = state.storage.put(&key, &body).await.is_ok()
+        && state.storage.put(&quick_key, &quick).await.is_ok()
+        && state
+            .storage
+            .put(
+                &record_key,
+                &serde_json::to_vec(&record).unwrap_or_default(),
+            )
+            .await
+            .is_ok();
+    if !stored {
+        for k in [&record_key, &quick_key, &key] {
+            let _ = state.storage.delete(k).await;
+        }
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    state.metrics.record_upload("gems");
     state
-        .config
-        .gems
-        .proxy
-        .clone()
-        .unwrap_or_else(|| UPSTREAM_DEFAULT.to_string())
-}
+        .audit
+        .log(AuditEntry::new("push", "api", &artifact, "gems", ""));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        artifact,
+        crate::registry_type::RegistryType::Gems,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("gems");
 
-fn with_binary(data: Vec<u8>, content_type: &'static str) -> Response {
     (
         StatusCode::OK,
-        [
-            (header::CONTENT_TYPE, HeaderValue::from_static(content_type)),
-            (
-                header::CACHE_CONTROL,
-                HeaderValue::from_static("public, max-age=31536000, immutable"),
-            ),
-        ],
-        data,
+        format!("Successfully registered gem: {} ({})", name, full),
     )
         .into_response()
 }
 
-fn with_text(data: Vec<u8>) -> Response {
+/// Form fields of `gem yank`.
+#[derive(Debug, Deserialize)]
+struct YankForm {
+    gem_name: String,
+    version: String,
+    #[serde(default)]
+    platform: Option<String>,
+}
+
+/// DELETE /gems/api/v1/gems/yank — drop a hosted version from the indexes.
+///
+/// The `.gem` stays in storage (and downloadable by its exact name) for audit
+/// and existing lockfiles; only the index entries go away.
+async fn yank_gem(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Form(form): Form<YankForm>,
+) -> Response {
+    let full = full_version(&form.version, form.platform.as_deref().unwrap_or("ruby"));
+    if !is_valid_gem_name(&form.gem_name) || !is_valid_version(&full) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    if enforce_namespace_scope(&authority, &form.gem_name).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    let lock = state.publish_lock(&format!("gems/{}", form.gem_name));
+    let _guard = lock.lock().await;
+
+    let Some(mut record) = hosted_record(&state.storage, &form.gem_name, &full).await else {
+        return (StatusCode::NOT_FOUND, "This rubygem could not be found.").into_response();
+    };
+    if record.yanked_at.is_some() {
+        return (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("The version {} has already been yanked.", full),
+        )
+            .into_response();
+    }
+    record.yanked_at = Some(chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true));
+    if state
+        .storage
+        .put(
+            &hosted_record_key(&form.gem_name, &full),
+            &serde_json::to_vec(&record).unwrap_or_default(),
+        )
+        .await
+        .is_err()
+    {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    let artifact = format!("{}-{}", form.gem_name, full);
+    state
+        .audit
+        .log(AuditEntry::new("yank", "api", &artifact, "gems", ""));
+    state.repo_index.invalidate("gems");
+
     (
         StatusCode::OK,
-        [
-            (
-                header::CONTENT_TYPE,
-                HeaderValue::from_static("text/plain; charset=utf-8"),
-            ),
-            (
-                header::CACHE_CONTROL,
-                HeaderValue::from_static("public, max-age=60, must-revalidate"),
-            ),
-        ],
-        data,
+        format!("Successfully deleted gem: {} ({})", form.gem_name, full),
     )
         .into_response()
 }
 
-/// Split gem filename "name-version" into (name, version).
-/// The version starts at the last hyphen followed by a digit.
-/// Examples:
-///   "rails-7.0.0"      → ("rails", "7.0.0")
-///   "rack-test-1.0.0"  → ("rack-test", "1.0.0")
-///   "rspec-core-3.12"  → ("rspec-core", "3.12")
-pub fn split_gem_filename(stem: &str) -> Option<(String, String)> {
-    // Find the last '-' that is followed by a digit (start of version)
-    let mut split_pos = None;
-    for (i, c) in stem.char_indices() {
-        if c == '-' {
-            if let Some(next) = stem[i + 1..].chars().next() {
-                if next.is_ascii_digit() {
-                    split_pos = Some(i);
+/// A requirement as `(operator, version)` pairs, e.g. `[("~>", "1.0")]`.
+type Requirement = Vec<(String, String)>;
+
+/// The parts of a pushed gem's specification NORA indexes and re-serves.
+#[derive(Debug)]
+struct GemSpec {
+    name: String,
+    version: String,
+    platform: String,
+    summary: Option<String>,
+    description: Option<String>,
+    homepage: Option<String>,
+    email: Vec<String>,
+    authors: Vec<String>,
+    licenses: Vec<String>,
+    metadata: Vec<(String, String)>,
+    /// `YYYY-MM-DD`.
+    date: Option<String>,
+    rubygems_version: String,
+    specification_version: usize,
+    required_ruby_version: Requirement,
+    required_rubygems_version: Requirement,
+    dependencies: Vec<GemDependency>,
+}
+
+#[derive(Debug)]
+struct GemDependency {
+    name: String,
+    requirement: Requirement,
+    /// `:runtime` (false means `:development`).
+    runtime: bool,
+}
+
+/// Read the YAML gemspec from a `.gem`'s `metadata.gz`.
+///
+/// A `.gem` is a plain tar holding `metadata.gz`, `data.tar.gz` and
+/// `checksums.yaml.gz`; the gemspec is serialized with Ruby object tags
+/// (`!ruby/object:Gem::Version`), which are looked through.
+fn read_gemspec(gem: &[u8]) -> Result<GemSpec, String> {
+    use std::io::Read;
+
+    let mut archive = tar::Archive::new(gem);
+    let mut metadata: Option<Vec<u8>> = None;
+    let mut has_data = false;
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("Gem is not a tar archive: {}", e))?
+    {
+        let entry = entry.map_err(|e| format!("Gem is not a tar archive: {}", e))?;
+        let path = entry
+            .path()
+            .map_err(|e| e.to_string())?
+            .to_string_lossy()
+            .into_owned();
+        match path.as_str() {
+            "metadata.gz" => {
+                let mut yaml = Vec::new();
+                flate2::read::GzDecoder::new(entry)
+                    .take(MAX_GEMSPEC_SIZE + 1)
+                    .read_to_end(&mut yaml)
+                    .map_err(|e| format!("metadata.gz: {}", e))?;
+                if yaml.len() as u64 > MAX_GEMSPEC_SIZE {
+                    return Err("metadata.gz is too large".to_string());
                 }
+                metadata = Some(yaml);
             }
+            "data.tar.gz" => has_data = true,
+            _ => {}
         }
     }
-    let pos = split_pos?;
-    let name = &stem[..pos];
-    let version = &stem[pos + 1..];
-    if name.is_empty() || version.is_empty() {
-        return None;
+    let metadata = metadata.ok_or("Gem has no metadata.gz")?;
+    if !has_data {
+        return Err("Gem has no data.tar.gz".to_string());
     }
-    Some((name.to_string(), version.to_string()))
-}
 
-/// Validate gem name: alphanumeric, hyphens, underscores, dots.
-/// No path traversal, no slashes, no null bytes.
-fn is_valid_gem_name(name: &str) -> bool {
-    !name.is_empty()
-        && name.len() <= 256
-        && !name.contains('/')
-        && !name.contains('\0')
-        && !name.contains("..")
-        && name
-            .chars()
-            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+    let yaml: YamlValue = serde_yaml_ng::from_slice(&metadata)
+        .map_err(|e| format!("metadata.gz is not a valid gemspec: {}", e))?;
+    let spec = untag(&yaml);
+    let field = |key: &str| spec.get(key).map(untag);
+    let text = |key: &str| field(key).and_then(yaml_text);
+
+    let name = text("name").ok_or("gemspec has no `name`")?;
+    let version = field("version")
+        .and_then(|v| v.get("version"))
+        .and_then(yaml_text)
+        .ok_or("gemspec has no `version`")?;
+    let dependencies = field("dependencies")
+        .and_then(YamlValue::as_sequence)
+        .map(|deps| {
+            deps.iter()
+                .map(untag)
+                .filter_map(|dep| {
+                    Some(GemDependency {
+                        name: dep.get("name").and_then(yaml_text)?,
+                        requirement: yaml_requirement(dep.get("requirement")),
+                        runtime: dep.get("type").and_then(yaml_text).as_deref()
+                            != Some(":development"),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let metadata = field("metadata")
+        .and_then(YamlValue::as_mapping)
+        .map(|map| {
+            map.iter()
+                .filter_map(|(k, v)| Some((yaml_text(k)?, yaml_text(v)?)))
+                .collect()
+        })
+        .unwrap_or_default();
+    let date = text("date").filter(|d| {
+        let b = d.as_bytes();
+        b.len() >= 10
+            && b[4] == b'-'
+            && b[7] == b'-'
+            && [0, 1, 2, 3, 5, 6, 8, 9]
+                .iter()
+                .all(|&i| b[i].is_ascii_digit())
+    });
+
+    Ok(GemSpec {
+        name,
+        version,
+        platform: text("platform").unwrap_or_else(|| "ruby".to_string()),
+        summary: text("summary"),
+        description: text("description"),
+        homepage: text("homepage"),
+        email: field("email").map(yaml_list).unwrap_or_default(),
+        authors: field("authors").map(yaml_list).unwrap_or_default(),
+        licenses: field("licenses").map(yaml_list).unwrap_or_default(),
+        metadata,
+        date: date.map(|d| d[..10].to_string()),
+        rubygems_version: text("rubygems_version").unwrap_or_else(|| "0".to_string()),
+        specification_version: field("specification_version")
+            .and_then(YamlValue::as_u64)
+            .map_or(4, |v| v as usize),
+        required_ruby_version: yaml_requirement(field("required_ruby_version")),
+        required_rubygems_version: yaml_requirement(field("required_rubygems_version")),
+        dependencies,
+    })
 }
 
-/// Validate version string: digits, dots, hyphens, alphanumeric, ".pre", ".beta", etc.
-fn is_valid_version(version: &str) -> bool {
-    !version.is_empty()
-        && version.len() <= 128
-        && !version.contains('/')
-        && !version.contains('\0')
-        && !version.contains("..")
-        && version
-            .chars()
-            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_')
+/// Look through Ruby object tags (`!ruby/object:…`) to the value underneath.
+fn untag(value: &YamlValue) -> &YamlValue {
+    match value {
+        YamlValue::Tagged(tagged) => untag(&tagged.value),
+        other => other,
+    }
 }
 
-// ── Tests ──────────────────────────────────────────────────────────────
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::time::{SystemTime, UNIX_EPOCH};
-
-    #[test]
-    fn test_valid_gem_names() {
-        assert!(is_valid_gem_name("rails"));
-        assert!(is_valid_gem_name("activerecord"));
-        assert!(is_valid_gem_name("rack-test"));
-        assert!(is_valid_gem_name("ruby_parser"));
-        assert!(is_valid_gem_name("nokogiri"));
-        assert!(is_valid_gem_name("rspec-core"));
+fn yaml_text(value: &YamlValue) -> Option<String> {
+    match untag(value) {
+        YamlValue::String(s) => Some(s.clone()),
+        YamlValue::Number(n) => Some(n.to_string()),
+        _ => None,
     }
+}
 
-    #[test]
-    fn test_invalid_gem_names() {
-        assert!(!is_valid_gem_name(""));
-        assert!(!is_valid_gem_name("../evil"));
-        assert!(!is_valid_gem_name("foo/bar"));
-        assert!(!is_valid_gem_name("foo\0bar"));
-        assert!(!is_valid_gem_name("foo bar"));
+/// A string list that Ruby may also write as a single string (`email`).
+fn yaml_list(value: &YamlValue) -> Vec<String> {
+    match untag(value) {
+        YamlValue::Sequence(items) => items.iter().filter_map(yaml_text).collect(),
+        other => yaml_text(other).into_iter().collect(),
     }
+}
 
-    #[test]
-    fn test_valid_versions() {
-        assert!(is_valid_version("1.0.0"));
-        assert!(is_valid_version("3.2.1"));
-        assert!(is_valid_version("1.0.0.pre"));
-        assert!(is_valid_version("2.0.0.beta1"));
-        assert!(is_valid_version("1.0.0-rc1"));
+/// A `Gem::Requirement` (`requirements: [[op, Gem::Version]]`); absent means `>= 0`.
+fn yaml_requirement(value: Option<&YamlValue>) -> Requirement {
+    let clauses: Requirement = value
+        .map(untag)
+        .and_then(|req| req.get("requirements"))
+        .and_then(|reqs| untag(reqs).as_sequence())
+        .map(|reqs| {
+            reqs.iter()
+                .filter_map(|clause| {
+                    let clause = untag(clause).as_sequence()?;
+                    let op = yaml_text(clause.first()?)?;
+                    let version = untag(clause.get(1)?).get("version").and_then(yaml_text)?;
+                    Some((op, version))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    if clauses.is_empty() {
+        vec![(">=".to_string(), "0".to_string())]
+    } else {
+        clauses
     }
+}
 
-    #[test]
-    fn test_invalid_versions() {
-        assert!(!is_valid_version(""));
-        assert!(!is_valid_version("../1.0"));
-        assert!(!is_valid_version("1.0/evil"));
-        assert!(!is_valid_version("1.0\0evil"));
+/// `1.0.0` on the `ruby` platform, `1.0.0-java` elsewhere (file names, compact index).
+fn full_version(version: &str, platform: &str) -> String {
+    if platform.is_empty() || platform == "ruby" {
+        version.to_string()
+    } else {
+        format!("{}-{}", version, platform)
     }
+}
 
-    #[test]
-    fn test_split_gem_filename_simple() {
-        let (name, ver) = split_gem_filename("rails-7.0.0").unwrap();
-        assert_eq!(name, "rails");
-        assert_eq!(ver, "7.0.0");
-    }
+/// Requirement clauses as the compact index writes them (`~> 1.0`).
+fn requirement_clauses(requirement: &Requirement) -> Vec<String> {
+    requirement
+        .iter()
+        .map(|(op, version)| format!("{} {}", op, version))
+        .collect()
+}
 
-    #[test]
-    fn test_split_gem_filename_with_hyphens() {
-        let (name, ver) = split_gem_filename("rack-test-1.0.0").unwrap();
-        assert_eq!(name, "rack-test");
-        assert_eq!(ver, "1.0.0");
-    }
+/// Record of a version pushed to NORA, kept after a yank.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HostedGem {
+    name: String,
+    version: String,
+    platform: String,
+    /// sha256 of the `.gem` — the compact index `checksum:`.
+    sha256: String,
+    /// Runtime dependencies with their requirement clauses.
+    dependencies: Vec<(String, Vec<String>)>,
+    required_ruby_version: Vec<String>,
+    required_rubygems_version: Vec<String>,
+    pushed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    yanked_at: Option<String>,
+}
 
-    #[test]
-    fn test_split_gem_filename_complex() {
-        let (name, ver) = split_gem_filename("rspec-core-3.12.0").unwrap();
-        assert_eq!(name, "rspec-core");
-        assert_eq!(ver, "3.12.0");
+impl HostedGem {
+    fn full_version(&self) -> String {
+        full_version(&self.version, &self.platform)
     }
 
-    #[test]
-    fn test_split_gem_filename_pre_release() {
-        let (name, ver) = split_gem_filename("rails-7.0.0.pre").unwrap();
-        assert_eq!(name, "rails");
-        assert_eq!(ver, "7.0.0.pre");
+    fn yanked(&self) -> bool {
+        self.yanked_at.is_some()
     }
 
-    #[test]
-    fn test_split_gem_filename_no_version() {
-        assert!(split_gem_filename("noversion").is_none());
+    /// RubyGems treats any version containing a letter as a pre-release.
+    fn prerelease(&self) -> bool {
+        self.version.chars().any(|c| c.is_ascii_alphabetic())
     }
 
-    #[test]
-    fn test_split_gem_filename_empty() {
-        assert!(split_gem_filename("").is_none());
+    /// Compact index line: `1.0.0 rack:~> 3.0|checksum:…,ruby:>= 3.0`.
+    fn info_line(&self) -> String {
+        let deps: Vec<String> = self
+            .dependencies
+            .iter()
+            .map(|(name, clauses)| format!("{}:{}", name, clauses.join("&")))
+            .collect();
+        let mut line = format!(
+            "{} {}|checksum:{}",
+            self.full_version(),
+            deps.join(","),
+            self.sha256
+        );
+        for (key, clauses) in [
+            ("ruby", &self.required_ruby_version),
+            ("rubygems", &self.required_rubygems_version),
+        ] {
+            if clauses.iter().any(|c| c != ">= 0") {
+                line.push_str(&format!(",{}:{}", key, clauses.join("&")));
+            }
+        }
+        line
     }
+}
 
-    #[test]
-    fn test_ttl_fresh() {
+fn hosted_record_key(name: &str, full_version: &str) -> String {
+    format!("gems/hosted/{}/{}.json", name, full_version)
+}
+
+async fn hosted_record(
+    storage: &crate::storage::Storage,
+    name: &str,
+    full_version: &str,
+) -> Option<HostedGem> {
+    let data = storage
+        .get(&hosted_record_key(name, full_version))
+        .await
+        .ok()?;
+    serde_json::from_slice(&data).ok()
+}
+
+/// Hosted records of one gem (yanked included), oldest version first.
+async fn hosted_versions(storage: &crate::storage::Storage, name: &str) -> Vec<HostedGem> {
+    hosted_records(storage, &format!("gems/hosted/{}/", name)).await
+}
+
+/// Every hosted record, by name then version.
+async fn hosted_gems(storage: &crate::storage::Storage) -> Vec<HostedGem> {
+    hosted_records(storage, "gems/hosted/").await
+}
+
+async fn hosted_records(storage: &crate::storage::Storage, prefix: &str) -> Vec<HostedGem> {
+    let mut records = Vec::new();
+    for key in storage.list(prefix).await.unwrap_or_default() {
+        if !key.ends_with(".json") {
+            continue;
+        }
+        if let Ok(data) = storage.get(&key).await {
+            if let Ok(record) = serde_json::from_slice::<HostedGem>(&data) {
+                records.push(record);
+            }
+        }
+    }
+    records.sort_by(|a, b| {
+        a.name
+            .cmp(&b.name)
+            .then_with(|| cmp_gem_versions(&a.version, &b.version))
+            .then_with(|| a.platform.cmp(&b.platform))
+    });
+    records
+}
+
+/// `Gem::Version` ordering: numeric and alphabetic segments, letters sort before
+/// numbers (so `1.0.a` < `1.0`), missing segments count as zero.
+fn cmp_gem_versions(a: &str, b: &str) -> Ordering {
+    fn segments(version: &str) -> Vec<Result<u64, &str>> {
+        let mut out = Vec::new();
+        let mut start: Option<usize> = None;
+        let bytes = version.as_bytes();
+        for i in 0..=bytes.len() {
+            let boundary = match (start, bytes.get(i)) {
+                (Some(s), Some(c)) => {
+                    !c.is_ascii_alphanumeric() || bytes[s].is_ascii_digit() != c.is_ascii_digit()
+                }
+                (Some(_), None) => true,
+                _ => false,
+            };
+            if boundary {
+                if let Some(s) = start.take() {
+                    let seg = &version[s..i];
+                    out.push(seg.parse::<u64>().map_err(|_| seg));
+                }
+            }
+            if start.is_none() && bytes.get(i).is_some_and(u8::is_ascii_alphanumeric) {
+                start = Some(i);
+            }
+        }
+        out
+    }
+    let (sa, sb) = (segments(a), segments(b));
+    for i in 0..sa.len().max(sb.len()) {
+        let x = sa.get(i).cloned().unwrap_or(Ok(0));
+        let y = sb.get(i).cloned().unwrap_or(Ok(0));
+        let ord = match (x, y) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            (Err(x), Err(y)) => x.cmp(y),
+            (Err(_), Ok(_)) => Ordering::Less,
+            (Ok(_), Err(_)) => Ordering::Greater,
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Hosted entries of one Marshal specs index: `specs` holds released versions,
+/// `prerelease_specs` the pre-releases, `latest_specs` the newest release per
+/// name and platform. Yanked versions are in none of them.
+fn hosted_specs_entries<'a>(filename: &str, hosted: &'a [HostedGem]) -> Vec<&'a HostedGem> {
+    let live = hosted.iter().filter(|g| !g.yanked());
+    match filename {
+        "prerelease_specs.4.8.gz" => live.filter(|g| g.prerelease()).collect(),
+        "latest_specs.4.8.gz" => {
+            let mut latest: BTreeMap<(&str, &str), &HostedGem> = BTreeMap::new();
+            for gem in live.filter(|g| !g.prerelease()) {
+                let slot = latest.entry((&gem.name, &gem.platform)).or_insert(gem);
+                if cmp_gem_versions(&gem.version, &slot.version) == Ordering::Greater {
+                    *slot = gem;
+                }
+            }
+            latest.into_values().collect()
+        }
+        _ => live.filter(|g| !g.prerelease()).collect(),
+    }
+}
+
+/// Serve a Marshal specs index with the hosted entries appended to upstream's.
+async fn specs_with_hosted(state: &AppState, filename: &str) -> Response {
+    let hosted = hosted_gems(&state.storage).await;
+    if hosted.is_empty() {
+        return fetch_index(state, filename).await;
+    }
+    let entries = hosted_specs_entries(filename, &hosted);
+    let mut fragment = Marshal::default();
+    for gem in &entries {
+        fragment.array(3);
+        fragment.string(&gem.name);
+        fragment.version(&gem.version);
+        fragment.string(&gem.platform);
+    }
+    let upstream = upstream_index(state, filename)
+        .await
+        .ok()
+        .map(|index| index.data);
+
+    // Re-gzipping the full upstream index is costly, so the last merge is kept
+    // until either side changes.
+    let stamp = format!(
+        "{}:{}",
+        hex::encode(sha2::Sha256::digest(
+            upstream.as_deref().unwrap_or_default()
+        )),
+        hex::encode(sha2::Sha256::digest(&fragment.0))
+    );
+    let merged_key = format!("gems/merged/{}", filename);
+    let stamp_key = format!("{}.stamp", merged_key);
+    if state.storage.get(&stamp_key).await.ok().as_deref() == Some(stamp.as_bytes()) {
+        if let Ok(merged) = state.storage.get(&merged_key).await {
+            state.metrics.record_download("gems");
+            return with_index(merged.to_vec(), "application/gzip");
+        }
+    }
+
+    let count = entries.len();
+    let merged = tokio::task::spawn_blocking(move || {
+        append_specs(upstream.as_deref(), &fragment.0, count).or_else(|e| {
+            tracing::warn!(error = %e, "RubyGems upstream specs index unreadable, serving hosted gems only");
+            append_specs(None, &fragment.0, count)
+        })
+    })
+    .await;
+    let merged = match merged {
+        Ok(Ok(merged)) => merged,
+        _ => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+    if state.storage.put(&merged_key, &merged).await.is_ok() {
+        let _ = state.storage.put(&stamp_key, stamp.as_bytes()).await;
+    }
+    state.metrics.record_download("gems");
+    with_index(merged, "application/gzip")
+}
+
+/// Append `count` Marshal-encoded elements to a gzipped Marshal array
+/// (`specs.4.8.gz`), or to an empty one. The upstream elements are copied
+/// untouched; only the array length is rewritten.
+fn append_specs(
+    upstream_gz: Option<&[u8]>,
+    fragment: &[u8],
+    count: usize,
+) -> Result<Vec<u8>, String> {
+    use std::io::{Read, Write};
+
+    let raw = match upstream_gz {
+        Some(gz) => {
+            let mut raw = Vec::new();
+            flate2::read::GzDecoder::new(gz)
+                .read_to_end(&mut raw)
+                .map_err(|e| format!("specs index is not gzip: {}", e))?;
+            raw
+        }
+        None => b"\x04\x08[\x00".to_vec(),
+    };
+    if raw.get(..3) != Some(b"\x04\x08[".as_slice()) {
+        return Err("specs index is not a Marshal 4.8 array".to_string());
+    }
+    let (len, used) = read_long(&raw[3..]).ok_or("specs index has a bad array length")?;
+
+    let mut out = Marshal::default();
+    out.0.extend_from_slice(b"\x04\x08");
+    out.array(len + count);
+    out.0.extend_from_slice(&raw[3 + used..]);
+    out.0.extend_from_slice(fragment);
+
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    gz.write_all(&out.0).map_err(|e| e.to_string())?;
+    gz.finish().map_err(|e| e.to_string())
+}
+
+/// Compact index `info/{name}`: upstream's lines, minus any version NORA hosts,
+/// followed by the hosted versions that are not yanked.
+fn merge_info(upstream: Option<&[u8]>, hosted: &[HostedGem]) -> Vec<u8> {
+    let shadowed: HashSet<String> = hosted.iter().map(HostedGem::full_version).collect();
+    let mut out = String::from("---\n");
+    if let Some(upstream) = upstream {
+        for line in String::from_utf8_lossy(upstream).lines() {
+            let version = line.split(' ').next().unwrap_or_default();
+            if line.is_empty() || line == "---" || shadowed.contains(version) {
+                continue;
+            }
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    for gem in hosted.iter().filter(|g| !g.yanked()) {
+        out.push_str(&gem.info_line());
+        out.push('\n');
+    }
+    out.into_bytes()
+}
+
+/// Upstream `info/{name}` body, when upstream (or the cache) has one.
+async fn upstream_info(state: &AppState, headers: &HeaderMap, name: &str) -> Option<Vec<u8>> {
+    let response = upstream_compact_index(state, headers, name).await;
+    if !response.status().is_success() {
+        return None;
+    }
+    axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .ok()
+        .map(|body| body.to_vec())
+}
+
+/// GET /gems/versions — compact index name list, with a line per hosted gem.
+///
+/// Clients read later lines as additions (`-1.0.0` removes a version) and take
+/// the last checksum for a name, so hosted lines are appended after upstream's.
+async fn versions_index(State(state): State<AppState>) -> Response {
+    let hosted = hosted_gems(&state.storage).await;
+    let upstream = upstream_index(&state, "versions").await;
+    if hosted.is_empty() {
+        return match upstream {
+            Ok(index) if index.stale => stale_response(index.data, "text/plain; charset=utf-8"),
+            Ok(index) => with_text(index.data),
+            Err(response) => response,
+        };
+    }
+
+    let mut body = match upstream {
+        Ok(index) => index.data,
+        Err(_) => format!(
+            "created_at: {}\n---\n",
+            chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        )
+        .into_bytes(),
+    };
+    let mut by_name: BTreeMap<String, Vec<HostedGem>> = BTreeMap::new();
+    for gem in hosted {
+        by_name.entry(gem.name.clone()).or_default().push(gem);
+    }
+    // Names upstream also serves need its info lines folded into the checksum.
+    let upstream_names: HashSet<String> = String::from_utf8_lossy(&body)
+        .lines()
+        .filter_map(|line| line.split(' ').next())
+        .filter(|name| by_name.contains_key(*name))
+        .map(str::to_string)
+        .collect();
+    if !body.is_empty() && !body.ends_with(b"\n") {
+        body.push(b'\n');
+    }
+    for (name, gems) in &by_name {
+        let upstream = if upstream_names.contains(name) {
+            upstream_info(&state, &HeaderMap::new(), name).await
+        } else {
+            None
+        };
+        let info = merge_info(upstream.as_deref(), gems);
+        let versions: Vec<String> = gems
+            .iter()
+            .map(|g| {
+                if g.yanked() {
+                    format!("-{}", g.full_version())
+                } else {
+                    g.full_version()
+                }
+            })
+            .collect();
+        body.extend_from_slice(
+            format!(
+                "{} {} {}\n",
+                name,
+                versions.join(","),
+                hex::encode(md5::Md5::digest(&info))
+            )
+            .as_bytes(),
+        );
+    }
+    state.metrics.record_download("gems");
+    with_text(body)
+}
+
+/// Deflated `Marshal.dump(spec)` — the `quick/Marshal.4.8/*.gemspec.rz` format.
+///
+/// `Gem::Specification` dumps itself through `_dump`, a nested Marshal array of
+/// its fields in a fixed order (see `Gem::Specification._load`).
+fn quick_spec(spec: &GemSpec) -> std::io::Result<Vec<u8>> {
+    use std::io::Write;
+
+    let mut fields = Marshal::default();
+    fields.0.extend_from_slice(b"\x04\x08");
+    fields.array(19);
+    fields.string(&spec.rubygems_version);
+    fields.int(spec.specification_version);
+    fields.string(&spec.name);
+    fields.version(&spec.version);
+    fields.opt_string(spec.date.as_deref());
+    fields.opt_string(spec.summary.as_deref());
+    fields.requirement(&spec.required_ruby_version);
+    fields.requirement(&spec.required_rubygems_version);
+    fields.string(&spec.platform);
+    fields.array(spec.dependencies.len());
+    for dep in &spec.dependencies {
+        fields.0.push(b'o');
+        fields.symbol("Gem::Dependency");
+        fields.long(5);
+        fields.symbol("@name");
+        fields.string(&dep.name);
+        fields.symbol("@requirement");
+        fields.requirement(&dep.requirement);
+        fields.symbol("@type");
+        fields.symbol(if dep.runtime {
+            "runtime"
+        } else {
+            "development"
+        });
+        fields.symbol("@prerelease");
+        fields.bool(false);
+        fields.symbol("@version_requirements");
+        fields.requirement(&dep.requirement);
+    }
+    fields.string(""); // rubyforge_project
+    match spec.email.as_slice() {
+        [] => fields.nil(),
+        [email] => fields.string(email),
+        emails => fields.strings(emails),
+    }
+    fields.strings(&spec.authors);
+    fields.opt_string(spec.description.as_deref());
+    fields.opt_string(spec.homepage.as_deref());
+    fields.bool(true); // has_rdoc
+    fields.string(&spec.platform);
+    fields.strings(&spec.licenses);
+    fields.0.push(b'{');
+    fields.long(spec.metadata.len());
+    for (key, value) in &spec.metadata {
+        fields.string(key);
+        fields.string(value);
+    }
+
+    let mut dump = Marshal::default();
+    dump.0.extend_from_slice(b"\x04\x08u");
+    dump.symbol("Gem::Specification");
+    dump.long(fields.0.len());
+    dump.0.extend_from_slice(&fields.0);
+
+    let mut zlib = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    zlib.write_all(&dump.0)?;
+    zlib.finish()
+}
+
+/// Writer for the subset of Ruby's Marshal 4.8 format the RubyGems indexes use.
+///
+/// Symbols are always written in full, never as `;` back-references: Ruby's
+/// loader numbers symbols by position and accepts repeats, so the output can be
+/// appended to an array written by another dumper.
+#[derive(Default)]
+struct Marshal(Vec<u8>);
+
+impl Marshal {
+    /// `w_long`: 1..122 inline as `n + 5`, larger values as a byte count and
+    /// little-endian bytes.
+    fn long(&mut self, n: usize) {
+        match n {
+            0 => self.0.push(0),
+            1..=122 => self.0.push(n as u8 + 5),
+            _ => {
+                let bytes = (n as u64).to_le_bytes();
+                let len = bytes.iter().rposition(|b| *b != 0).map_or(1, |p| p + 1);
+                self.0.push(len as u8);
+                self.0.extend_from_slice(&bytes[..len]);
+            }
+        }
+    }
+
+    fn nil(&mut self) {
+        self.0.push(b'0');
+    }
+
+    fn bool(&mut self, value: bool) {
+        self.0.push(if value { b'T' } else { b'F' });
+    }
+
+    fn int(&mut self, n: usize) {
+        self.0.push(b'i');
+        self.long(n);
+    }
+
+    fn symbol(&mut self, name: &str) {
+        self.0.push(b':');
+        self.long(name.len());
+        self.0.extend_from_slice(name.as_bytes());
+    }
+
+    /// A UTF-8 `String`: the bytes, then the `E => true` encoding ivar.
+    fn string(&mut self, s: &str) {
+        self.0.extend_from_slice(b"I\"");
+        self.long(s.len());
+        self.0.extend_from_slice(s.as_bytes());
+        self.long(1);
+        self.symbol("E");
+        self.bool(true);
+    }
+
+    fn opt_string(&mut self, s: Option<&str>) {
+        match s {
+            Some(s) => self.string(s),
+            None => self.nil(),
+        }
+    }
+
+    fn strings(&mut self, items: &[String]) {
+        self.array(items.len());
+        for item in items {
+            self.string(item);
+        }
+    }
+
+    fn array(&mut self, len: usize) {
+        self.0.push(b'[');
+        self.long(len);
+    }
+
+    /// `Gem::Version` dumps as `[version_string]` through `marshal_dump`.
+    fn version(&mut self, version: &str) {
+        self.0.push(b'U');
+        self.symbol("Gem::Version");
+        self.array(1);
+        self.string(version);
+    }
+
+    /// `Gem::Requirement` dumps as `[[[op, Gem::Version], …]]`.
+    fn requirement(&mut self, requirement: &Requirement) {
+        self.0.push(b'U');
+        self.symbol("Gem::Requirement");
+        self.array(1);
+        self.array(requirement.len());
+        for (op, version) in requirement {
+            self.array(2);
+            self.string(op);
+            self.version(version);
+        }
+    }
+}
+
+/// Read a non-negative `w_long`, returning the value and the bytes consumed.
+fn read_long(data: &[u8]) -> Option<(usize, usize)> {
+    let c = *data.first()? as i8;
+    match c {
+        0 => Some((0, 1)),
+        1..=4 => {
+            let n = c as usize;
+            let value = data
+                .get(1..=n)?
+                .iter()
+                .rev()
+                .fold(0usize, |acc, b| (acc << 8) | *b as usize);
+            Some((value, n + 1))
+        }
+        5..=127 => Some((c as usize - 5, 1)),
+        _ => None,
+    }
+}
+
+// ── Helpers ────────────────────────────────────────────────────────────
+
+fn upstream_url(state: &AppState) -> String {
+    state
+        .config
+        .gems
+        .proxy
+        .clone()
+        .unwrap_or_else(|| UPSTREAM_DEFAULT.to_string())
+}
+
+fn with_binary(data: Vec<u8>, content_type: &'static str) -> Response {
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static(content_type)),
+            (
+                header::CACHE_CONTROL,
+                HeaderValue::from_static("public, max-age=31536000, immutable"),
+            ),
+        ],
+        data,
+    )
+        .into_response()
+}
+
+/// A mutable binary index (merged specs): short-lived, unlike `with_binary`.
+fn with_index(data: Vec<u8>, content_type: &'static str) -> Response {
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static(content_type)),
+            (
+                header::CACHE_CONTROL,
+                HeaderValue::from_static("public, max-age=60, must-revalidate"),
+            ),
+        ],
+        data,
+    )
+        .into_response()
+}
+
+/// Cached index served after an upstream error (`serve_stale`).
+fn stale_response(data: Vec<u8>, content_type: &'static str) -> Response {
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static(content_type)),
+            (
+                header::CACHE_CONTROL,
+                HeaderValue::from_static("public, max-age=0, must-revalidate"),
+            ),
+            (
+                axum::http::header::HeaderName::from_static("x-nora-stale"),
+                HeaderValue::from_static("true"),
+            ),
+        ],
+        data,
+    )
+        .into_response()
+}
+
+fn with_text(data: Vec<u8>) -> Response {
+    (
+        StatusCode::OK,
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("text/plain; charset=utf-8"),
+            ),
+            (
+                header::CACHE_CONTROL,
+                HeaderValue::from_static("public, max-age=60, must-revalidate"),
+            ),
+        ],
+        data,
+    )
+        .into_response()
+}
+
+/// Split gem filename "name-version" into (name, version).
+/// The version starts at the last hyphen followed by a digit.
+/// Examples:
+///   "rails-7.0.0"      → ("rails", "7.0.0")
+///   "rack-test-1.0.0"  → ("rack-test", "1.0.0")
+///   "rspec-core-3.12"  → ("rspec-core", "3.12")
+pub fn split_gem_filename(stem: &str) -> Option<(String, String)> {
+    // Find the last '-' that is followed by a digit (start of version)
+    let mut split_pos = None;
+    for (i, c) in stem.char_indices() {
+        if c == '-' {
+            if let Some(next) = stem[i + 1..].chars().next() {
+                if next.is_ascii_digit() {
+                    split_pos = Some(i);
+                }
+            }
+        }
+    }
+    let pos = split_pos?;
+    let name = &stem[..pos];
+    let version = &stem[pos + 1..];
+    if name.is_empty() || version.is_empty() {
+        return None;
+    }
+    Some((name.to_string(), version.to_string()))
+}
+
+/// Validate gem name: alphanumeric, hyphens, underscores, dots.
+/// No path traversal, no slashes, no null bytes.
+fn is_valid_gem_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 256
+        && !name.contains('/')
+        && !name.contains('\0')
+        && !name.contains("..")
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+}
+
+/// Validate version string: digits, dots, hyphens, alphanumeric, ".pre", ".beta", etc.
+fn is_valid_version(version: &str) -> bool {
+    !version.is_empty()
+        && version.len() <= 128
+        && !version.contains('/')
+        && !version.contains('\0')
+        && !version.contains("..")
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_')
+}
+
+// ── Tests ──────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn test_valid_gem_names() {
+        assert!(is_valid_gem_name("rails"));
+        assert!(is_valid_gem_name("activerecord"));
+        assert!(is_valid_gem_name("rack-test"));
+        assert!(is_valid_gem_name("ruby_parser"));
+        assert!(is_valid_gem_name("nokogiri"));
+        assert!(is_valid_gem_name("rspec-core"));
+    }
+
+    #[test]
+    fn test_invalid_gem_names() {
+        assert!(!is_valid_gem_name(""));
+        assert!(!is_valid_gem_name("../evil"));
+        assert!(!is_valid_gem_name("foo/bar"));
+        assert!(!is_valid_gem_name("foo\0bar"));
+        assert!(!is_valid_gem_name("foo bar"));
+    }
+
+    #[test]
+    fn test_valid_versions() {
+        assert!(is_valid_version("1.0.0"));
+        assert!(is_valid_version("3.2.1"));
+        assert!(is_valid_version("1.0.0.pre"));
+        assert!(is_valid_version("2.0.0.beta1"));
+        assert!(is_valid_version("1.0.0-rc1"));
+    }
+
+    #[test]
+    fn test_invalid_versions() {
+        assert!(!is_valid_version(""));
+        assert!(!is_valid_version("../1.0"));
+        assert!(!is_valid_version("1.0/evil"));
+        assert!(!is_valid_version("1.0\0evil"));
+    }
+
+    #[test]
+    fn test_split_gem_filename_simple() {
+        let (name, ver) = split_gem_filename("rails-7.0.0").unwrap();
+        assert_eq!(name, "rails");
+        assert_eq!(ver, "7.0.0");
+    }
+
+    #[test]
+    fn test_split_gem_filename_with_hyphens() {
+        let (name, ver) = split_gem_filename("rack-test-1.0.0").unwrap();
+        assert_eq!(name, "rack-test");
+        assert_eq!(ver, "1.0.0");
+    }
+
+    #[test]
+    fn test_split_gem_filename_complex() {
+        let (name, ver) = split_gem_filename("rspec-core-3.12.0").unwrap();
+        assert_eq!(name, "rspec-core");
+        assert_eq!(ver, "3.12.0");
+    }
+
+    #[test]
+    fn test_split_gem_filename_pre_release() {
+        let (name, ver) = split_gem_filename("rails-7.0.0.pre").unwrap();
+        assert_eq!(name, "rails");
+        assert_eq!(ver, "7.0.0.pre");
+    }
+
+    #[test]
+    fn test_split_gem_filename_no_version() {
+        assert!(split_gem_filename("noversion").is_none());
+    }
+
+    #[test]
+    fn test_split_gem_filename_empty() {
+        assert!(split_gem_filename("").is_none());
+    }
+
+    #[test]
+    fn test_ttl_fresh() {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
@@ -959,6 +1997,320 @@ mod tests {
             .as_secs();
         assert!(!is_within_ttl(now - 7200, 3600)); // 2h ago, TTL 1h
     }
+
+    // ── Hosted publishing ─────────────────────────────────────────────
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        use std::io::Write;
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gz.write_all(data).unwrap();
+        gz.finish().unwrap()
+    }
+
+    fn gunzip(data: &[u8]) -> Vec<u8> {
+        use std::io::Read;
+        let mut out = Vec::new();
+        flate2::read::GzDecoder::new(data)
+            .read_to_end(&mut out)
+            .unwrap();
+        out
+    }
+
+    /// A gemspec as `gem build` serializes it into `metadata.gz`.
+    pub(super) fn gemspec_yaml(name: &str, version: &str, platform: &str) -> String {
+        format!(
+            r#"--- !ruby/object:Gem::Specification
+name: {name}
+version: !ruby/object:Gem::Version
+  version: {version}
+platform: {platform}
+authors:
+- Acme Corp
+autorequire:
+bindir: bin
+cert_chain: []
+date: 2024-01-15 00:00:00.000000000 Z
+dependencies:
+- !ruby/object:Gem::Dependency
+  name: rack
+  requirement: !ruby/object:Gem::Requirement
+    requirements:
+    - - "~>"
+      - !ruby/object:Gem::Version
+        version: '3.0'
+  type: :runtime
+  prerelease: false
+  version_requirements: !ruby/object:Gem::Requirement
+    requirements:
+    - - "~>"
+      - !ruby/object:Gem::Version
+        version: '3.0'
+- !ruby/object:Gem::Dependency
+  name: rspec
+  requirement: !ruby/object:Gem::Requirement
+    requirements:
+    - - ">="
+      - !ruby/object:Gem::Version
+        version: '0'
+  type: :development
+  prerelease: false
+  version_requirements: !ruby/object:Gem::Requirement
+    requirements:
+    - - ">="
+      - !ruby/object:Gem::Version
+        version: '0'
+description: Internal widgets.
+email: dev@acme.example
+executables: []
+extensions: []
+extra_rdoc_files: []
+files:
+- lib/{name}.rb
+homepage: https://acme.example/widgets
+licenses:
+- MIT
+metadata:
+  source_code_uri: https://acme.example/src
+post_install_message:
+rdoc_options: []
+require_paths:
+- lib
+required_ruby_version: !ruby/object:Gem::Requirement
+  requirements:
+  - - ">="
+    - !ruby/object:Gem::Version
+      version: '3.0'
+required_rubygems_version: !ruby/object:Gem::Requirement
+  requirements:
+  - - ">="
+    - !ruby/object:Gem::Version
+      version: '0'
+requirements: []
+rubygems_version: 3.5.3
+signing_key:
+specification_version: 4
+summary: Widgets for Acme.
+test_files: []
+"#
+        )
+    }
+
+    /// A `.gem` around the given gemspec YAML: a plain tar of `metadata.gz`,
+    /// `data.tar.gz` and `checksums.yaml.gz`.
+    pub(super) fn build_gem_from(yaml: &str) -> Vec<u8> {
+        let mut data = tar::Builder::new(Vec::new());
+        let mut header = tar::Header::new_gnu();
+        header.set_size(4);
+        header.set_mode(0o644);
+        header.set_cksum();
+        data.append_data(&mut header, "lib/acme.rb", b"# ok".as_slice())
+            .unwrap();
+        let data = gzip(&data.into_inner().unwrap());
+
+        let mut gem = tar::Builder::new(Vec::new());
+        for (path, body) in [
+            ("metadata.gz", gzip(yaml.as_bytes())),
+            ("data.tar.gz", data),
+            ("checksums.yaml.gz", gzip(b"---\n")),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(body.len() as u64);
+            header.set_mode(0o444);
+            header.set_cksum();
+            gem.append_data(&mut header, path, body.as_slice()).unwrap();
+        }
+        gem.into_inner().unwrap()
+    }
+
+    pub(super) fn build_gem(name: &str, version: &str, platform: &str) -> Vec<u8> {
+        build_gem_from(&gemspec_yaml(name, version, platform))
+    }
+
+    fn hosted(version: &str) -> HostedGem {
+        HostedGem {
+            name: "acme-widgets".to_string(),
+            version: version.to_string(),
+            platform: "ruby".to_string(),
+            sha256: "abc".to_string(),
+            dependencies: vec![("rack".to_string(), vec!["~> 3.0".to_string()])],
+            required_ruby_version: vec![">= 3.0".to_string()],
+            required_rubygems_version: vec![">= 0".to_string()],
+            pushed_at: "2024-01-15T00:00:00Z".to_string(),
+            yanked_at: None,
+        }
+    }
+
+    #[test]
+    fn test_read_gemspec() {
+        let spec = read_gemspec(&build_gem("acme-widgets", "1.0.0", "ruby")).unwrap();
+        assert_eq!(spec.name, "acme-widgets");
+        assert_eq!(spec.version, "1.0.0");
+        assert_eq!(spec.platform, "ruby");
+        assert_eq!(spec.date.as_deref(), Some("2024-01-15"));
+        assert_eq!(spec.email, vec!["dev@acme.example"]);
+        assert_eq!(spec.specification_version, 4);
+        assert_eq!(
+            spec.required_ruby_version,
+            vec![(">=".to_string(), "3.0".to_string())]
+        );
+        assert_eq!(spec.dependencies.len(), 2);
+        assert_eq!(spec.dependencies[0].name, "rack");
+        assert_eq!(
+            spec.dependencies[0].requirement,
+            vec![("~>".to_string(), "3.0".to_string())]
+        );
+        assert!(spec.dependencies[0].runtime);
+        assert!(!spec.dependencies[1].runtime);
+        assert_eq!(
+            spec.metadata,
+            vec![(
+                "source_code_uri".to_string(),
+                "https://acme.example/src".to_string()
+            )]
+        );
+
+        assert!(read_gemspec(b"not a gem").is_err());
+        let no_version = gemspec_yaml("acme-widgets", "1.0.0", "ruby")
+            .replace("version: !ruby/object:Gem::Version\n  version: 1.0.0\n", "");
+        assert!(read_gemspec(&build_gem_from(&no_version))
+            .unwrap_err()
+            .contains("version"));
+        assert!(read_gemspec(&build_gem_from("name: [unclosed"))
+            .unwrap_err()
+            .contains("gemspec"));
+    }
+
+    #[test]
+    fn test_marshal_encoding() {
+        let long = |n: usize| {
+            let mut m = Marshal::default();
+            m.long(n);
+            m.0
+        };
+        assert_eq!(long(0), vec![0]);
+        assert_eq!(long(5), vec![10]);
+        assert_eq!(long(122), vec![127]);
+        assert_eq!(long(123), vec![1, 123]);
+        assert_eq!(long(256), vec![2, 0, 1]);
+        for n in [0, 1, 122, 123, 255, 256, 65_536, 1_500_000] {
+            let bytes = long(n);
+            assert_eq!(read_long(&bytes), Some((n, bytes.len())));
+        }
+
+        // Marshal.dump(["a"])
+        let mut m = Marshal::default();
+        m.0.extend_from_slice(b"\x04\x08");
+        m.strings(&["a".to_string()]);
+        assert_eq!(m.0, b"\x04\x08[\x06I\"\x06a\x06:\x06ET");
+
+        // Marshal.dump(Gem::Version.new("1.0.0")), minus the header
+        let mut m = Marshal::default();
+        m.version("1.0.0");
+        assert_eq!(m.0, b"U:\x11Gem::Version[\x06I\"\x0a1.0.0\x06:\x06ET");
+    }
+
+    #[test]
+    fn test_append_specs_rewrites_length() {
+        let mut fragment = Marshal::default();
+        fragment.array(3);
+        fragment.string("acme-widgets");
+        fragment.version("1.0.0");
+        fragment.string("ruby");
+
+        // 122 elements is the largest single-byte length; one more needs two bytes.
+        let mut upstream = b"\x04\x08[\x7f".to_vec();
+        upstream.extend(std::iter::repeat_n(b'0', 122));
+        let merged = gunzip(&append_specs(Some(&gzip(&upstream)), &fragment.0, 1).unwrap());
+        let mut expected = b"\x04\x08[\x01\x7b".to_vec();
+        expected.extend(std::iter::repeat_n(b'0', 122));
+        expected.extend_from_slice(&fragment.0);
+        assert_eq!(merged, expected);
+
+        let alone = gunzip(&append_specs(None, &fragment.0, 1).unwrap());
+        assert_eq!(alone[..4], *b"\x04\x08[\x06");
+        assert_eq!(alone[4..], fragment.0[..]);
+
+        assert!(append_specs(Some(&gzip(b"\x04\x08{\x00")), &fragment.0, 1).is_err());
+        assert!(append_specs(Some(b"not gzip"), &fragment.0, 1).is_err());
+    }
+
+    #[test]
+    fn test_info_line_and_merge() {
+        assert_eq!(
+            hosted("1.0.0").info_line(),
+            "1.0.0 rack:~> 3.0|checksum:abc,ruby:>= 3.0"
+        );
+        let mut java = hosted("1.0.0");
+        java.platform = "java".to_string();
+        java.dependencies.clear();
+        java.required_ruby_version = vec![">= 0".to_string()];
+        assert_eq!(java.info_line(), "1.0.0-java |checksum:abc");
+
+        let mut yanked = hosted("1.1.0");
+        yanked.yanked_at = Some("2024-02-01T00:00:00Z".to_string());
+        let upstream = b"---\n0.9.0 |checksum:x\n1.0.0 |checksum:y\n";
+        let merged = merge_info(Some(upstream), &[hosted("1.0.0"), yanked]);
+        assert_eq!(
+            String::from_utf8(merged).unwrap(),
+            "---\n0.9.0 |checksum:x\n1.0.0 rack:~> 3.0|checksum:abc,ruby:>= 3.0\n"
+        );
+    }
+
+    #[test]
+    fn test_cmp_gem_versions() {
+        assert_eq!(cmp_gem_versions("1.10.0", "1.9.0"), Ordering::Greater);
+        assert_eq!(cmp_gem_versions("1.0.a", "1.0"), Ordering::Less);
+        assert_eq!(cmp_gem_versions("2.0.0.rc1", "2.0.0"), Ordering::Less);
+        assert_eq!(cmp_gem_versions("1.0", "1.0.0"), Ordering::Equal);
+        assert_eq!(
+            cmp_gem_versions("1.0.0.beta2", "1.0.0.beta10"),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_hosted_specs_entries() {
+        let mut yanked = hosted("1.2.0");
+        yanked.yanked_at = Some("2024-02-01T00:00:00Z".to_string());
+        let gems = vec![
+            hosted("1.0.0"),
+            hosted("1.1.0"),
+            yanked,
+            hosted("2.0.0.pre"),
+        ];
+        let versions = |filename: &str| -> Vec<String> {
+            hosted_specs_entries(filename, &gems)
+                .iter()
+                .map(|g| g.version.clone())
+                .collect()
+        };
+        assert_eq!(versions("specs.4.8.gz"), vec!["1.0.0", "1.1.0"]);
+        assert_eq!(versions("latest_specs.4.8.gz"), vec!["1.1.0"]);
+        assert_eq!(versions("prerelease_specs.4.8.gz"), vec!["2.0.0.pre"]);
+    }
+
+    #[test]
+    fn test_quick_spec_layout() {
+        use std::io::Read;
+        let spec = read_gemspec(&build_gem("acme-widgets", "1.0.0", "ruby")).unwrap();
+        let mut dump = Vec::new();
+        flate2::read::ZlibDecoder::new(quick_spec(&spec).unwrap().as_slice())
+            .read_to_end(&mut dump)
+            .unwrap();
+        let header = b"\x04\x08u:\x17Gem::Specification";
+        assert_eq!(dump[..header.len()], header[..]);
+        let (len, used) = read_long(&dump[header.len()..]).unwrap();
+        let fields = &dump[header.len() + used..];
+        assert_eq!(fields.len(), len);
+        // The `_dump` payload: a 19-element array led by rubygems_version and
+        // specification_version.
+        assert!(fields.starts_with(b"\x04\x08[\x18I\"\x0a3.5.3\x06:\x06ETi\x09"));
+        let contains = |needle: &[u8]| fields.windows(needle.len()).any(|w| w == needle);
+        assert!(contains(b"o:\x14Gem::Dependency\x0a"));
+        assert!(contains(b":\x0a@type:\x0cruntime"));
+        assert!(contains(b":\x0a@type:\x10development"));
+        assert!(contains(b"I\"\x0f2024-01-15"));
+    }
 }
 
 #[cfg(test)]
@@ -1294,4 +2646,376 @@ mod integration_tests {
             .get();
         assert!(after > before, "a 304 revalidation must be recorded");
     }
+
+    // ── Hosted publishing ─────────────────────────────────────────────
+
+    use super::tests::build_gem;
+
+    /// Gems enabled with an unreachable upstream, so anything not hosted fails loudly.
+    fn hosted_context() -> crate::test_helpers::TestContext {
+        create_test_context_with_config(|cfg| {
+            cfg.gems.enabled = true;
+            cfg.gems.proxy = Some("http://127.0.0.1:1".to_string());
+            cfg.gems.proxy_timeout = 1;
+        })
+    }
+
+    async fn push(
+        ctx: &crate::test_helpers::TestContext,
+        gem: &[u8],
+    ) -> axum::http::Response<axum::body::Body> {
+        send_with_headers(
+            &ctx.app,
+            Method::POST,
+            "/gems/api/v1/gems",
+            vec![("content-type", "application/octet-stream")],
+            gem.to_vec(),
+        )
+        .await
+    }
+
+    async fn yank(
+        ctx: &crate::test_helpers::TestContext,
+        form: &str,
+    ) -> axum::http::Response<axum::body::Body> {
+        send_with_headers(
+            &ctx.app,
+            Method::DELETE,
+            "/gems/api/v1/gems/yank",
+            vec![("content-type", "application/x-www-form-urlencoded")],
+            form.as_bytes().to_vec(),
+        )
+        .await
+    }
+
+    async fn get_text(ctx: &crate::test_helpers::TestContext, uri: &str) -> String {
+        let resp = send(&ctx.app, Method::GET, uri, "").await;
+        assert_eq!(resp.status(), StatusCode::OK, "GET {}", uri);
+        String::from_utf8(body_bytes(resp).await.to_vec()).unwrap()
+    }
+
+    fn sha256(data: &[u8]) -> String {
+        hex::encode(<sha2::Sha256 as sha2::Digest>::digest(data))
+    }
+
+    /// `gem push` sends its API key as the bare `Authorization` value.
+    #[tokio::test]
+    async fn test_gems_push_with_bare_api_key() {
+        let ctx = crate::test_helpers::create_test_context_with_auth_and_config(
+            &[("admin", "secret")],
+            |cfg| {
+                cfg.gems.enabled = true;
+                cfg.gems.proxy = Some("http://127.0.0.1:1".to_string());
+                cfg.gems.proxy_timeout = 1;
+            },
+        );
+        let token = ctx
+            .state
+            .tokens
+            .as_ref()
+            .unwrap()
+            .create_token("admin", 30, None, crate::tokens::Role::Write)
+            .unwrap();
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::POST,
+            "/gems/api/v1/gems",
+            vec![
+                ("content-type", "application/octet-stream"),
+                ("authorization", &token),
+            ],
+            build_gem("acme-widgets", "1.0.0", "ruby"),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::DELETE,
+            "/gems/api/v1/gems/yank",
+            vec![
+                ("content-type", "application/x-www-form-urlencoded"),
+                ("authorization", &token),
+            ],
+            b"gem_name=acme-widgets&version=1.0.0".to_vec(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_gems_push_and_install() {
+        let ctx = hosted_context();
+        let gem = build_gem("acme-widgets", "1.0.0", "ruby");
+        let resp = push(&ctx, &gem).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = String::from_utf8(body_bytes(resp).await.to_vec()).unwrap();
+        assert_eq!(body, "Successfully registered gem: acme-widgets (1.0.0)");
+
+        let info = get_text(&ctx, "/gems/info/acme-widgets").await;
+        assert_eq!(
+            info,
+            format!(
+                "---\n1.0.0 rack:~> 3.0|checksum:{},ruby:>= 3.0\n",
+                sha256(&gem)
+            )
+        );
+
+        // No upstream: /versions is synthesized, and its checksum is the info's md5.
+        let versions = get_text(&ctx, "/gems/versions").await;
+        assert!(versions.starts_with("created_at: "));
+        let md5 = hex::encode(<md5::Md5 as sha2::Digest>::digest(info.as_bytes()));
+        assert!(versions.ends_with(&format!("---\nacme-widgets 1.0.0 {}\n", md5)));
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/gems/gems/acme-widgets-1.0.0.gem",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(body_bytes(resp).await.to_vec(), gem);
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/gems/quick/Marshal.4.8/acme-widgets-1.0.0.gemspec.rz",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = send(&ctx.app, Method::GET, "/gems/specs.4.8.gz", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let specs = body_bytes(resp).await;
+        let mut raw = Vec::new();
+        std::io::Read::read_to_end(&mut flate2::read::GzDecoder::new(&specs[..]), &mut raw)
+            .unwrap();
+        assert!(raw.starts_with(b"\x04\x08[\x06[\x08I\"\x11acme-widgets"));
+
+        // A hosted gem whose file is gone is a 404, not an upstream fetch (502).
+        ctx.state
+            .storage
+            .delete("gems/gems/acme-widgets-1.0.0.gem")
+            .await
+            .unwrap();
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/gems/gems/acme-widgets-1.0.0.gem",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_gems_push_platform_gem() {
+        let ctx = hosted_context();
+        let gem = build_gem("acme-native", "2.1.0", "x86_64-linux");
+        assert_eq!(push(&ctx, &gem).await.status(), StatusCode::OK);
+        let info = get_text(&ctx, "/gems/info/acme-native").await;
+        assert!(info.contains("\n2.1.0-x86_64-linux rack:~> 3.0|"));
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/gems/gems/acme-native-2.1.0-x86_64-linux.gem",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        // "-22" would split as the version, so the file could not be served back.
+        let gem = build_gem("acme-native", "2.1.0", "universal-darwin-22");
+        assert_eq!(push(&ctx, &gem).await.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_gems_push_existing_version_conflicts() {
+        let ctx = hosted_context();
+        let gem = build_gem("acme-widgets", "1.0.0", "ruby");
+        assert_eq!(push(&ctx, &gem).await.status(), StatusCode::OK);
+        assert_eq!(push(&ctx, &gem).await.status(), StatusCode::CONFLICT);
+
+        // A version already cached from upstream is just as immutable.
+        ctx.state
+            .storage
+            .put("gems/gems/rails-7.0.0.gem", b"cached")
+            .await
+            .unwrap();
+        let resp = push(&ctx, &build_gem("rails", "7.0.0", "ruby")).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_gems_push_rejects_invalid_gems() {
+        let ctx = hosted_context();
+        assert_eq!(push(&ctx, b"").await.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            push(&ctx, b"not a gem").await.status(),
+            StatusCode::BAD_REQUEST
+        );
+        let resp = push(&ctx, &build_gem("acme widgets", "1.0.0", "ruby")).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert!(ctx
+            .state
+            .storage
+            .list("gems/hosted/")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_gems_yank_removes_from_indexes_keeps_file() {
+        let ctx = hosted_context();
+        let old = build_gem("acme-widgets", "1.0.0", "ruby");
+        let new = build_gem("acme-widgets", "1.1.0", "ruby");
+        assert_eq!(push(&ctx, &old).await.status(), StatusCode::OK);
+        assert_eq!(push(&ctx, &new).await.status(), StatusCode::OK);
+
+        let resp = yank(&ctx, "gem_name=acme-widgets&version=1.1.0").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let info = get_text(&ctx, "/gems/info/acme-widgets").await;
+        assert!(info.contains("\n1.0.0 "));
+        assert!(!info.contains("1.1.0"));
+        let versions = get_text(&ctx, "/gems/versions").await;
+        assert!(versions.contains("\nacme-widgets 1.0.0,-1.1.0 "));
+
+        let resp = send(&ctx.app, Method::GET, "/gems/latest_specs.4.8.gz", "").await;
+        let specs = body_bytes(resp).await;
+        let mut raw = Vec::new();
+        std::io::Read::read_to_end(&mut flate2::read::GzDecoder::new(&specs[..]), &mut raw)
+            .unwrap();
+        assert!(raw.windows(5).any(|w| w == b"1.0.0"));
+        assert!(!raw.windows(5).any(|w| w == b"1.1.0"));
+
+        // The file stays for audit (and existing lockfiles).
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/gems/gems/acme-widgets-1.1.0.gem",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(body_bytes(resp).await.to_vec(), new);
+
+        let resp = yank(&ctx, "gem_name=acme-widgets&version=1.1.0").await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let resp = yank(&ctx, "gem_name=acme-widgets&version=9.9.9").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        // A yanked version cannot be pushed again.
+        assert_eq!(push(&ctx, &new).await.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_gems_hosted_merged_over_upstream() {
+        use std::io::Write;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Upstream specs: [["rails", Gem::Version("7.0.0"), "ruby"]]
+        let mut upstream_specs = b"\x04\x08[\x06[\x08".to_vec();
+        upstream_specs.extend_from_slice(b"I\"\x0arails\x06:\x06ET");
+        upstream_specs.extend_from_slice(b"U:\x11Gem::Version[\x06I\"\x0a7.0.0\x06:\x06ET");
+        upstream_specs.extend_from_slice(b"I\"\x09ruby\x06:\x06ET");
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gz.write_all(&upstream_specs).unwrap();
+        let upstream_specs_gz = gz.finish().unwrap();
+
+        let upstream = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/specs.4.8.gz"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(upstream_specs_gz))
+            .mount(&upstream)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/versions"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "created_at: 2024-01-01T00:00:00Z\n---\nacme-widgets 0.9.0,1.0.0 0123\nrails 7.0.0 4567\n",
+            ))
+            .mount(&upstream)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/info/acme-widgets"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("---\n0.9.0 |checksum:aaa\n1.0.0 |checksum:bbb\n"),
+            )
+            .mount(&upstream)
+            .await;
+        let uri = upstream.uri();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.gems.enabled = true;
+            cfg.gems.proxy = Some(uri);
+        });
+
+        let gem = build_gem("acme-widgets", "1.0.0", "ruby");
+        assert_eq!(push(&ctx, &gem).await.status(), StatusCode::OK);
+
+        // The hosted 1.0.0 replaces upstream's line; 0.9.0 stays.
+        let info = get_text(&ctx, "/gems/info/acme-widgets").await;
+        assert_eq!(
+            info,
+            format!(
+                "---\n0.9.0 |checksum:aaa\n1.0.0 rack:~> 3.0|checksum:{},ruby:>= 3.0\n",
+                sha256(&gem)
+            )
+        );
+        let versions = get_text(&ctx, "/gems/versions").await;
+        let md5 = hex::encode(<md5::Md5 as sha2::Digest>::digest(info.as_bytes()));
+        assert!(versions.starts_with("created_at: 2024-01-01T00:00:00Z\n---\n"));
+        assert!(versions.ends_with(&format!("rails 7.0.0 4567\nacme-widgets 1.0.0 {}\n", md5)));
+
+        for _ in 0..2 {
+            // The second request is served from the stored merge.
+            let resp = send(&ctx.app, Method::GET, "/gems/specs.4.8.gz", "").await;
+            assert_eq!(resp.status(), StatusCode::OK);
+            let specs = body_bytes(resp).await;
+            let mut raw = Vec::new();
+            std::io::Read::read_to_end(&mut flate2::read::GzDecoder::new(&specs[..]), &mut raw)
+                .unwrap();
+            assert!(raw.starts_with(b"\x04\x08[\x07[\x08I\"\x0arails"));
+            assert!(raw.ends_with(b"I\"\x0a1.0.0\x06:\x06ETI\"\x09ruby\x06:\x06ET"));
+        }
+        assert!(ctx
+            .state
+            .storage
+            .stat("gems/merged/specs.4.8.gz")
+            .await
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_gems_push_namespace_scope_enforced() {
+        use crate::auth::NamespaceAuthority;
+        use crate::config::ScopeEnforcement;
+        use axum::extract::State;
+        use axum::Extension;
+
+        let ctx = hosted_context();
+        let scoped = NamespaceAuthority::from_oidc_scope(
+            "ci",
+            &["acme-*".to_string()],
+            ScopeEnforcement::Enforce,
+        );
+        let resp = super::push_gem(
+            State(ctx.state.clone()),
+            Extension(scoped.clone()),
+            build_gem("other-widgets", "1.0.0", "ruby").into(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let resp = super::push_gem(
+            State(ctx.state.clone()),
+            Extension(scoped),
+            build_gem("acme-widgets", "1.0.0", "ruby").into(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
 }
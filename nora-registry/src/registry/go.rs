@@ -9,27 +9,34 @@
 //!   GET /go/{module}/@v/{ver}.mod   — go.mod file
 //!   GET /go/{module}/@v/{ver}.zip   — module zip archive
 //!   GET /go/{module}/@latest        — latest version info
+//!
+//! Plus the checksum database passthrough (`/go/sumdb/…`, see `go_sumdb`) and hosted
+//! publishing (not part of GOPROXY):
+//!   PUT /go/{module}/@v/{ver}       — upload a module (multipart: `mod` + `zip`)
 
 use crate::activity_log::{ActionType, ActivityEntry};
 use crate::audit::AuditEntry;
+use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
 use crate::registry::{circuit_open_response, proxy_fetch, proxy_fetch_text, ProxyError};
 use crate::registry_type::RegistryType;
 use crate::secrets::expose_opt;
+use crate::storage::Storage;
 use crate::validation::ends_with_ci;
 use crate::AppState;
 use axum::body::Bytes;
 use axum::{
-    extract::{Path, State},
+    extract::{Multipart, Path, State},
     http::{header, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
     routing::get,
-    Router,
+    Extension, Router,
 };
 use percent_encoding::percent_decode;
+use std::cmp::Ordering;
 use std::time::Duration;
 
 pub fn routes() -> Router<AppState> {
-    Router::new().route("/go/{*path}", get(handle))
+    Router::new().route("/go/{*path}", get(handle).put(upload))
 }
 
 /// Main handler — parses the wildcard path and dispatches to the right logic.
@@ -52,6 +59,11 @@ async fn handle(
         return StatusCode::BAD_REQUEST.into_response();
     }
 
+    // "sumdb" has no dot, so it can never be the first element of a module path.
+    if let Some(rest) = path.strip_prefix("sumdb/") {
+        return super::go_sumdb::handle(&state, rest).await;
+    }
+
     // Split: "github.com/!azure/sdk/@v/v1.0.0.info" → module + file
     let (module_encoded, file) = match split_go_path(&path) {
         Some(parts) => parts,
@@ -74,11 +86,20 @@ async fn handle(
         None
     };
 
+    // A version published to NORA (see `upload`) is trusted at publish time: it never passes
+    // through proxy curation or the first-seen quarantine, exactly like the other hosted formats.
+    let hosted_zip = match go_curation {
+        Some((_, Some(ref version))) => {
+            is_hosted_version(&state.storage, &module_encoded, version).await
+        }
+        _ => false,
+    };
+
     // Hoisted to function scope so the digest-quarantine serve gate can seed
     // first_seen from a trusted upstream release date (#750).
     let mut publish_date: Option<i64> = None;
-    // Curation check — .zip downloads only (metadata passes through)
-    if let Some((ref module_name, ref version)) = go_curation {
+    // Curation check — proxied .zip downloads only (metadata passes through)
+    if let Some((ref module_name, ref version)) = go_curation.as_ref().filter(|_| !hosted_zip) {
         // Extract publish date from cached .info file
         publish_date = if let Some(ref ver) = version {
             let info_key = format!("go/{}/@v/{}.info", module_encoded, ver);
@@ -165,7 +186,7 @@ async fn handle(
                 crate::registry_type::RegistryType::Go,
                 "CACHE",
             ));
-            // Quarantine only the .zip module archive (the artifact). The
+            // Quarantine only the proxied .zip module archive (the artifact). The
             // .info/.mod/@v/list/@latest endpoints serve metadata — never gate them.
             if ends_with_ci(&file, ".zip") {
                 let (q_mode, q_secs) = crate::digest_quarantine::resolve_global(
@@ -180,16 +201,18 @@ async fn handle(
                         .quarantine_ttl
                         .as_deref()),
                 );
-                if let Some(resp) = crate::digest_quarantine::proxy_gate_dated(
-                    &state.digest_store,
-                    "go",
-                    data,
-                    &q_mode,
-                    q_secs,
-                    "cache",
-                    publish_date,
-                ) {
-                    return resp;
+                if !hosted_zip {
+                    if let Some(resp) = crate::digest_quarantine::proxy_gate_dated(
+                        &state.digest_store,
+                        "go",
+                        data,
+                        &q_mode,
+                        q_secs,
+                        "cache",
+                        publish_date,
+                    ) {
+                        return resp;
+                    }
                 }
 
                 // Resume support: 206 for a `Range` request, 416 when the client asks
@@ -260,6 +283,14 @@ async fn handle(
         .unwrap_or_else(|| StatusCode::NOT_FOUND.into_response());
     }
 
+    // Hosted versions are overlaid on the upstream listing endpoints, so GOPROXY=nora resolves a
+    // module's private and public versions together.
+    let hosted = if file == "@v/list" || file == "@latest" {
+        hosted_versions(&state.storage, &module_encoded).await
+    } else {
+        Vec::new()
+    };
+
     let upstream_url = format!(
         "{}/{}",
         proxy_url.trim_end_matches('/'),
@@ -298,6 +329,18 @@ async fn handle(
         .map(|s| s.into_bytes())
     };
 
+    // An upstream that has never heard of a module (a private module outside the internal
+    // namespaces) answers 404; its hosted versions alone are then the listing.
+    let data = match data {
+        Ok(bytes) if !hosted.is_empty() => {
+            Ok(overlay_hosted(&state.storage, &module_encoded, &file, Some(bytes), &hosted).await)
+        }
+        Err(ProxyError::NotFound) if !hosted.is_empty() => {
+            Ok(overlay_hosted(&state.storage, &module_encoded, &file, None, &hosted).await)
+        }
+        other => other,
+    };
+
     match data {
         Ok(bytes) => {
             // Enforce size limit for .zip
@@ -392,6 +435,527 @@ async fn handle(
     }
 }
 
+// ============================================================================
+// Hosted publishing
+// ============================================================================
+
+/// Upper bound on a module zip's total uncompressed size (golang.org/x/mod/zip `MaxZipFile`).
+const MAX_ZIP_UNCOMPRESSED: u64 = 500 << 20;
+/// Upper bound on the zip's root `go.mod` (x/mod `MaxGoMod`).
+const MAX_GO_MOD: u64 = 16 << 20;
+/// Upper bound on the zip's root `LICENSE` (x/mod `MaxLICENSE`).
+const MAX_LICENSE: u64 = 16 << 20;
+
+/// PUT /go/{module}/@v/{version} — publish a module version to NORA.
+///
+/// Multipart body: `mod` (the go.mod) and `zip` (the module zip, laid out as the go command
+/// builds it: every entry under `{module}@{version}/`). The three files are written under the
+/// same keys the proxy handler serves, so `GOPROXY=nora` downloads a hosted version exactly
+/// like a cached one. A marker under `@hosted/` records that the version was published here
+/// rather than proxied; the listing endpoints overlay hosted versions on the upstream ones.
+async fn upload(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Path(path): Path<String>,
+    mut multipart: Multipart,
+) -> Response {
+    let path = percent_decode(path.as_bytes())
+        .decode_utf8()
+        .map(|s| s.into_owned())
+        .unwrap_or(path);
+    if !is_safe_path(&path) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let Some((module_encoded, version)) = split_go_path(&path).and_then(|(m, file)| {
+        file.strip_prefix("@v/")
+            .filter(|v| is_canonical_go_version(v))
+            .map(|v| (m.clone(), v.to_string()))
+    }) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            "Expected PUT /go/{module}/@v/{version} with a canonical version",
+        )
+            .into_response();
+    };
+    let Ok(module) = decode_module_path(&module_encoded) else {
+        return (StatusCode::BAD_REQUEST, "Invalid module path encoding").into_response();
+    };
+    if let Err(e) = check_module_path(&module).and_then(|()| check_path_major(&module, &version)) {
+        return (StatusCode::BAD_REQUEST, e).into_response();
+    }
+
+    // Enforce OIDC namespace_scope on the module coordinate (#583).
+    if enforce_namespace_scope(&authority, &module).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    let mut go_mod: Option<Vec<u8>> = None;
+    let mut zip_data: Option<Vec<u8>> = None;
+    while let Ok(Some(field)) = multipart.next_field().await {
+        let field_name = field.name().unwrap_or("").to_string();
+        let slot = match field_name.as_str() {
+            "mod" => &mut go_mod,
+            "zip" => &mut zip_data,
+            _ => {
+                let _ = field.bytes().await;
+                continue;
+            }
+        };
+        match field.bytes().await {
+            Ok(b) => *slot = Some(b.to_vec()),
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    format!("Failed to read {}: {}", field_name, e),
+                )
+                    .into_response()
+            }
+        }
+    }
+    let (Some(go_mod), Some(zip_data)) = (go_mod, zip_data) else {
+        return (StatusCode::BAD_REQUEST, "Missing `mod` or `zip` field").into_response();
+    };
+
+    if zip_data.len() as u64 > state.config.go.max_zip_size {
+        return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+    }
+    match go_mod_module_path(&go_mod) {
+        Some(declared) if declared == module => {}
+        Some(declared) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("go.mod declares module {}, not {}", declared, module),
+            )
+                .into_response()
+        }
+        None => return (StatusCode::BAD_REQUEST, "go.mod has no module directive").into_response(),
+    }
+    if let Err(e) = check_module_zip(&module, &version, &zip_data, &go_mod) {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("Invalid module zip: {}", e),
+        )
+            .into_response();
+    }
+
+    // Serialize with other publishes of the same module: @v/list and @latest are rewritten.
+    let list_key = format!("go/{}/@v/list", module_encoded);
+    let lock = state.publish_lock(&list_key);
+    let _guard = lock.lock().await;
+
+    // Versions are immutable — a proxied copy already in the cache counts too, since clients
+    // may have recorded its hash in go.sum.
+    let zip_key = format!("go/{}/@v/{}.zip", module_encoded, version);
+    if state.storage.stat(&zip_key).await.is_some() {
+        return (
+            StatusCode::CONFLICT,
+            format!("{}@{} already exists", module, version),
+        )
+            .into_response();
+    }
+
+    let info = serde_json::json!({
+        "Version": version,
+        "Time": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+    });
+    let info_bytes = serde_json::to_vec(&info).unwrap_or_default();
+
+    // The .zip goes FIRST: immutability is checked against it, so a failure below leaves the
+    // version re-publishable once the zip is removed.
+    if state.storage.put(&zip_key, &zip_data).await.is_err() {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    let files = [
+        (format!("go/{}/@v/{}.mod", module_encoded, version), &go_mod),
+        (
+            format!("go/{}/@v/{}.info", module_encoded, version),
+            &info_bytes,
+        ),
+        (hosted_marker_key(&module_encoded, &version), &info_bytes),
+    ];
+    for (key, data) in &files {
+        if state.storage.put(key, data).await.is_err() {
+            let _ = state.storage.delete(&zip_key).await;
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+
+    // Regenerate the listing endpoints over whatever was cached from upstream.
+    let hosted = hosted_versions(&state.storage, &module_encoded).await;
+    for file in ["@v/list", "@latest"] {
+        let key = format!("go/{}/{}", module_encoded, file);
+        let existing = state.storage.get(&key).await.ok().map(|b| b.to_vec());
+        let merged = overlay_hosted(&state.storage, &module_encoded, file, existing, &hosted).await;
+        if state.storage.put(&key, &merged).await.is_err() {
+            tracing::error!(module = %module, file, "go publish: failed to update listing");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+
+    state.metrics.record_upload("go");
+    let artifact = format!("{}@{}", module, version);
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &artifact, "go", ""));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        artifact,
+        crate::registry_type::RegistryType::Go,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("go");
+
+    (
+        StatusCode::CREATED,
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        )],
+        info_bytes,
+    )
+        .into_response()
+}
+
+/// Storage key of the marker recording that `version` was published to NORA.
+///
+/// Lives beside `@v/` rather than inside it so GC, retention and the repo index (all keyed on
+/// `/@v/`) never mistake it for a module file, and `split_go_path` never serves it.
+fn hosted_marker_key(module_encoded: &str, version: &str) -> String {
+    format!("go/{}/@hosted/{}", module_encoded, version)
+}
+
+async fn is_hosted_version(storage: &Storage, module_encoded: &str, version: &str) -> bool {
+    storage
+        .stat(&hosted_marker_key(module_encoded, version))
+        .await
+        .is_some()
+}
+
+/// Versions of `module_encoded` published to NORA whose .zip is still present (retention may
+/// have removed the module files and left the marker behind).
+async fn hosted_versions(storage: &Storage, module_encoded: &str) -> Vec<String> {
+    let prefix = format!("go/{}/@hosted/", module_encoded);
+    let mut versions = Vec::new();
+    for key in storage.list(&prefix).await.unwrap_or_default() {
+        let Some(version) = key.strip_prefix(&prefix) else {
+            continue;
+        };
+        if !is_canonical_go_version(version) {
+            continue;
+        }
+        let zip_key = format!("go/{}/@v/{}.zip", module_encoded, version);
+        if storage.stat(&zip_key).await.is_some() {
+            versions.push(version.to_string());
+        }
+    }
+    versions
+}
+
+/// Merge hosted versions into a listing response (`@v/list` or `@latest`).
+///
+/// `upstream` is the upstream (or previously cached) body, `None` when there is none. For
+/// `@v/list` the result is the sorted union; for `@latest` it is whichever `.info` names the
+/// later version, hosted winning ties.
+async fn overlay_hosted(
+    storage: &Storage,
+    module_encoded: &str,
+    file: &str,
+    upstream: Option<Vec<u8>>,
+    hosted: &[String],
+) -> Vec<u8> {
+    if file == "@v/list" {
+        let upstream_text = upstream
+            .as_deref()
+            .map(String::from_utf8_lossy)
+            .unwrap_or_default();
+        return merge_version_list(&upstream_text, hosted).into_bytes();
+    }
+
+    let Some(hosted_latest) = pick_latest(hosted.iter().map(String::as_str)) else {
+        return upstream.unwrap_or_default();
+    };
+    let upstream_version = upstream
+        .as_deref()
+        .and_then(|b| serde_json::from_slice::<serde_json::Value>(b).ok())
+        .and_then(|v| v.get("Version")?.as_str().map(str::to_string));
+    if let (Some(bytes), Some(up)) = (&upstream, &upstream_version) {
+        if pick_latest([up.as_str(), hosted_latest]) == Some(up.as_str()) && up != hosted_latest {
+            return bytes.clone();
+        }
+    }
+    let info_key = format!("go/{}/@v/{}.info", module_encoded, hosted_latest);
+    match storage.get(&info_key).await {
+        Ok(info) => info.to_vec(),
+        Err(_) => upstream.unwrap_or_default(),
+    }
+}
+
+/// Sorted, de-duplicated union of an `@v/list` body and extra versions, one per line.
+fn merge_version_list(list: &str, extra: &[String]) -> String {
+    let mut versions: Vec<&str> = list
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .chain(extra.iter().map(String::as_str))
+        .collect();
+    versions.sort_by(|a, b| cmp_go_versions(a, b));
+    versions.dedup();
+    let mut out = versions.join("\n");
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+/// The version `@latest` resolves to: the highest release, or the highest pre-release when
+/// there is no release (the go command's own preference).
+fn pick_latest<'a>(versions: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let (releases, prereleases): (Vec<&str>, Vec<&str>) = versions
+        .into_iter()
+        .filter(|v| is_canonical_go_version(v))
+        .partition(|v| go_prerelease(v).is_none());
+    let newest = |vs: Vec<&'a str>| vs.into_iter().max_by(|a, b| cmp_go_versions(a, b));
+    newest(releases).or_else(|| newest(prereleases))
+}
+
+/// The `-prerelease` part of a canonical version, without the `+build` suffix.
+fn go_prerelease(v: &str) -> Option<&str> {
+    let core_pre = v.split('+').next().unwrap_or(v);
+    core_pre.split_once('-').map(|(_, pre)| pre)
+}
+
+/// Semantic-version ordering for Go versions (build metadata such as `+incompatible` ignored).
+/// Non-canonical strings sort before every canonical version, lexically among themselves.
+pub(crate) fn cmp_go_versions(a: &str, b: &str) -> Ordering {
+    fn core(v: &str) -> Option<[u64; 3]> {
+        if !is_canonical_go_version(v) {
+            return None;
+        }
+        let core_pre = v[1..].split('+').next().unwrap_or(v);
+        let mut parts = core_pre.split('-').next()?.split('.');
+        Some([
+            parts.next()?.parse().ok()?,
+            parts.next()?.parse().ok()?,
+            parts.next()?.parse().ok()?,
+        ])
+    }
+    match (core(a), core(b)) {
+        (None, None) => return a.cmp(b),
+        (None, Some(_)) => return Ordering::Less,
+        (Some(_), None) => return Ordering::Greater,
+        (Some(ca), Some(cb)) if ca != cb => return ca.cmp(&cb),
+        _ => {}
+    }
+    match (go_prerelease(a), go_prerelease(b)) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(pa), Some(pb)) => {
+            let mut ia = pa.split('.');
+            let mut ib = pb.split('.');
+            loop {
+                match (ia.next(), ib.next()) {
+                    (None, None) => return Ordering::Equal,
+                    (None, Some(_)) => return Ordering::Less,
+                    (Some(_), None) => return Ordering::Greater,
+                    (Some(x), Some(y)) => {
+                        let ord = match (x.parse::<u64>(), y.parse::<u64>()) {
+                            (Ok(nx), Ok(ny)) => nx.cmp(&ny),
+                            (Ok(_), Err(_)) => Ordering::Less,
+                            (Err(_), Ok(_)) => Ordering::Greater,
+                            (Err(_), Err(_)) => x.cmp(y),
+                        };
+                        if ord != Ordering::Equal {
+                            return ord;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Validate a module path the way `module.CheckPath` does: slash-separated non-empty elements
+/// of `[A-Za-z0-9._~-]`, no element starting or ending with a dot, and a first element that
+/// looks like a host name (lowercase, contains a dot, no leading dash).
+fn check_module_path(module: &str) -> Result<(), String> {
+    let mut elems = module.split('/');
+    let first = elems.next().unwrap_or_default();
+    if first.is_empty()
+        || !first.contains('.')
+        || first.starts_with('-')
+        || !first
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'.' || b == b'-')
+    {
+        return Err(format!(
+            "malformed module path {:?}: first element must be a host name",
+            module
+        ));
+    }
+    for elem in module.split('/') {
+        if elem.is_empty()
+            || elem.starts_with('.')
+            || elem.ends_with('.')
+            || !elem
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'~' | b'-'))
+        {
+            return Err(format!(
+                "malformed module path {:?}: invalid element {:?}",
+                module, elem
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check that `version` is compatible with the module path's major-version suffix: `/vN`
+/// (N ≥ 2) requires a vN.x.y version, no suffix allows v0/v1 or `+incompatible` v2+.
+/// `gopkg.in` paths encode the major differently and are not checked.
+fn check_path_major(module: &str, version: &str) -> Result<(), String> {
+    if module.starts_with("gopkg.in/") {
+        return Ok(());
+    }
+    let major: u64 = version[1..]
+        .split('.')
+        .next()
+        .and_then(|m| m.parse().ok())
+        .unwrap_or(0);
+    let incompatible = version.ends_with("+incompatible");
+    let suffix = module
+        .rsplit_once('/')
+        .and_then(|(_, last)| last.strip_prefix('v'))
+        .filter(|n| is_numeric_id(n))
+        .and_then(|n| n.parse::<u64>().ok())
+        .filter(|&n| n >= 2);
+    match suffix {
+        Some(n) if incompatible => Err(format!(
+            "version {} is +incompatible but module path ends in /v{}",
+            version, n
+        )),
+        Some(n) if n != major => Err(format!(
+            "version {} does not match module path major version v{}",
+            version, n
+        )),
+        None if major >= 2 && !incompatible => Err(format!(
+            "version {} requires a /v{} module path suffix",
+            version, major
+        )),
+        None if major < 2 && incompatible => Err(format!(
+            "version {} is +incompatible but has major version below 2",
+            version
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// The module path declared by a go.mod's `module` directive.
+fn go_mod_module_path(go_mod: &[u8]) -> Option<String> {
+    let text = std::str::from_utf8(go_mod).ok()?;
+    text.lines().find_map(|line| {
+        let line = line.split("//").next().unwrap_or(line).trim();
+        let rest = line.strip_prefix("module")?;
+        if !rest.starts_with(char::is_whitespace) {
+            return None;
+        }
+        let path = rest.trim().trim_matches(|c| c == '"' || c == '`');
+        (!path.is_empty()).then(|| path.to_string())
+    })
+}
+
+/// Validate a module zip's layout the way golang.org/x/mod/zip `CheckZip` does: every file
+/// lives under `{module}@{version}/` at a portable path, no two paths collide case-insensitively,
+/// no file belongs to a nested module, the root go.mod matches the uploaded one, and the size
+/// limits hold. Directory entries are tolerated and ignored.
+fn check_module_zip(module: &str, version: &str, data: &[u8], go_mod: &[u8]) -> Result<(), String> {
+    use std::io::Read;
+
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(data)).map_err(|e| e.to_string())?;
+    let prefix = format!("{}@{}/", module, version);
+    let mut seen = std::collections::HashSet::new();
+    let mut total: u64 = 0;
+    for i in 0..archive.len() {
+        let (name, size, is_dir) = {
+            let entry = archive.by_index_raw(i).map_err(|e| e.to_string())?;
+            (entry.name().to_string(), entry.size(), entry.is_dir())
+        };
+        if is_dir {
+            continue;
+        }
+        let Some(rel) = name.strip_prefix(&prefix) else {
+            return Err(format!("{}: path does not have prefix {:?}", name, prefix));
+        };
+        check_zip_file_path(rel).map_err(|e| format!("{}: {}", name, e))?;
+        if !seen.insert(rel.to_lowercase()) {
+            return Err(format!("{}: multiple entries for file", name));
+        }
+        if rel.ends_with("/go.mod") {
+            return Err(format!("{}: file is in another module", name));
+        }
+        total = total.saturating_add(size);
+        if total > MAX_ZIP_UNCOMPRESSED {
+            return Err(format!(
+                "total uncompressed size exceeds {} bytes",
+                MAX_ZIP_UNCOMPRESSED
+            ));
+        }
+        match rel {
+            "go.mod" => {
+                if size > MAX_GO_MOD {
+                    return Err(format!("go.mod exceeds {} bytes", MAX_GO_MOD));
+                }
+                let mut contents = Vec::new();
+                archive
+                    .by_index(i)
+                    .map_err(|e| e.to_string())?
+                    .take(MAX_GO_MOD + 1)
+                    .read_to_end(&mut contents)
+                    .map_err(|e| e.to_string())?;
+                if contents != go_mod {
+                    return Err("go.mod in zip does not match uploaded go.mod".to_string());
+                }
+            }
+            "LICENSE" if size > MAX_LICENSE => {
+                return Err(format!("LICENSE exceeds {} bytes", MAX_LICENSE));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// A zip file path must be clean, slash-separated, and portable (x/mod `module.CheckFilePath`).
+fn check_zip_file_path(rel: &str) -> Result<(), String> {
+    if rel.is_empty() || rel.starts_with('/') || rel.ends_with('/') || rel.contains("//") {
+        return Err("malformed file path".to_string());
+    }
+    for elem in rel.split('/') {
+        if elem == "." || elem == ".." {
+            return Err(format!("invalid path element {:?}", elem));
+        }
+        if elem.ends_with('.') {
+            return Err(format!("trailing dot in path element {:?}", elem));
+        }
+        if let Some(c) = elem
+            .chars()
+            .find(|&c| !(c.is_alphanumeric() || "!#$%&()+,-.=@[]^_{}~ ".contains(c)))
+        {
+            return Err(format!("invalid char {:?}", c));
+        }
+        let stem = elem.split('.').next().unwrap_or(elem).to_ascii_uppercase();
+        let reserved = matches!(stem.as_str(), "CON" | "PRN" | "AUX" | "NUL")
+            || ((stem.starts_with("COM") || stem.starts_with("LPT"))
+                && stem.len() == 4
+                && stem.as_bytes()[3].is_ascii_digit());
+        if reserved {
+            return Err(format!("{:?} disallowed as path element", elem));
+        }
+    }
+    Ok(())
+}
+
 // ============================================================================
 // Module path encoding/decoding
 // ============================================================================
@@ -421,7 +985,7 @@ async fn extract_go_publish_date(
 ///
 /// Go module proxy spec requires uppercase letters to be encoded as `!`
 /// followed by the lowercase letter. Raw uppercase in encoded path is invalid.
-fn decode_module_path(encoded: &str) -> Result<String, ()> {
+pub(super) fn decode_module_path(encoded: &str) -> Result<String, ()> {
     let mut result = String::with_capacity(encoded.len());
     let mut chars = encoded.chars();
     while let Some(c) = chars.next() {
@@ -601,7 +1165,11 @@ fn go_cache_fresh(
 /// Build response with Content-Type and Cache-Control headers.
 /// `is_mutable` controls caching: mutable endpoints (@v/list, @latest) get short TTL,
 /// immutable content (.zip, .mod, .info) gets long TTL.
-fn with_content_type(data: Vec<u8>, content_type: &'static str, is_mutable: bool) -> Response {
+pub(super) fn with_content_type(
+    data: Vec<u8>,
+    content_type: &'static str,
+    is_mutable: bool,
+) -> Response {
     let cache_control = if is_mutable {
         "public, max-age=60, must-revalidate"
     } else {
@@ -990,12 +1558,159 @@ mod tests {
             "github.com/user/repo@v1.0.0"
         );
     }
+
+    // ── Hosted publishing ───────────────────────────────────────────────
+
+    pub(super) fn build_module_zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        use std::io::Write;
+        let mut w = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for (name, data) in entries {
+            w.start_file(*name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            w.write_all(data).unwrap();
+        }
+        w.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_check_module_zip_valid() {
+        let go_mod = b"module example.com/mod\n";
+        let zip = build_module_zip(&[
+            ("example.com/mod@v1.0.0/go.mod", go_mod),
+            ("example.com/mod@v1.0.0/mod.go", b"package mod"),
+            ("example.com/mod@v1.0.0/internal/x.go", b"package internal"),
+        ]);
+        assert!(check_module_zip("example.com/mod", "v1.0.0", &zip, go_mod).is_ok());
+    }
+
+    #[test]
+    fn test_check_module_zip_rejects_bad_layout() {
+        let go_mod = b"module example.com/mod\n";
+        let check = |entries: &[(&str, &[u8])]| {
+            check_module_zip(
+                "example.com/mod",
+                "v1.0.0",
+                &build_module_zip(entries),
+                go_mod,
+            )
+        };
+        // Wrong prefix
+        assert!(check(&[("example.com/mod@v1.0.1/a.go", b"")])
+            .unwrap_err()
+            .contains("prefix"));
+        // Case-insensitive duplicate
+        assert!(check(&[
+            ("example.com/mod@v1.0.0/A.go", b""),
+            ("example.com/mod@v1.0.0/a.go", b"")
+        ])
+        .unwrap_err()
+        .contains("multiple entries"));
+        // Nested module
+        assert!(check(&[("example.com/mod@v1.0.0/sub/go.mod", b"module x")])
+            .unwrap_err()
+            .contains("another module"));
+        // go.mod mismatch
+        assert!(
+            check(&[("example.com/mod@v1.0.0/go.mod", b"module other.com/x")])
+                .unwrap_err()
+                .contains("does not match")
+        );
+        // Non-portable paths
+        assert!(check(&[("example.com/mod@v1.0.0/../evil", b"")]).is_err());
+        assert!(check(&[("example.com/mod@v1.0.0/a:b.go", b"")]).is_err());
+        assert!(check(&[("example.com/mod@v1.0.0/con.go", b"")]).is_err());
+        // Not a zip at all
+        assert!(check_module_zip("example.com/mod", "v1.0.0", b"nope", go_mod).is_err());
+    }
+
+    #[test]
+    fn test_check_module_path() {
+        assert!(check_module_path("example.com/mod").is_ok());
+        assert!(check_module_path("github.com/Azure/sdk-go_x.y~z").is_ok());
+        assert!(check_module_path("nodot/mod").is_err());
+        assert!(check_module_path("Example.com/mod").is_err());
+        assert!(check_module_path("example.com//mod").is_err());
+        assert!(check_module_path("example.com/.hidden").is_err());
+        assert!(check_module_path("example.com/a b").is_err());
+    }
+
+    #[test]
+    fn test_check_path_major() {
+        assert!(check_path_major("example.com/mod", "v0.1.0").is_ok());
+        assert!(check_path_major("example.com/mod", "v1.2.3").is_ok());
+        assert!(check_path_major("example.com/mod", "v2.0.0+incompatible").is_ok());
+        assert!(check_path_major("example.com/mod", "v2.0.0").is_err());
+        assert!(check_path_major("example.com/mod/v2", "v2.1.0").is_ok());
+        assert!(check_path_major("example.com/mod/v2", "v3.0.0").is_err());
+        assert!(check_path_major("example.com/mod/v2", "v1.0.0").is_err());
+        assert!(check_path_major("example.com/mod/v2", "v2.0.0+incompatible").is_err());
+        assert!(check_path_major("gopkg.in/yaml.v3", "v3.0.1").is_ok());
+    }
+
+    #[test]
+    fn test_go_mod_module_path() {
+        assert_eq!(
+            go_mod_module_path(b"// comment\nmodule example.com/mod // trailing\n\ngo 1.22\n"),
+            Some("example.com/mod".to_string())
+        );
+        assert_eq!(
+            go_mod_module_path(b"module \"example.com/quoted\"\n"),
+            Some("example.com/quoted".to_string())
+        );
+        assert_eq!(go_mod_module_path(b"go 1.22\n"), None);
+        assert_eq!(go_mod_module_path(b"modules x\n"), None);
+    }
+
+    #[test]
+    fn test_cmp_go_versions() {
+        let mut v = vec![
+            "v1.10.0",
+            "v1.2.0",
+            "v1.2.0-rc.1",
+            "v1.2.0-beta.2",
+            "v1.2.0-beta.11",
+            "v0.0.0-20210101000000-abcdef123456",
+            "v2.0.0+incompatible",
+        ];
+        v.sort_by(|a, b| cmp_go_versions(a, b));
+        assert_eq!(
+            v,
+            [
+                "v0.0.0-20210101000000-abcdef123456",
+                "v1.2.0-beta.2",
+                "v1.2.0-beta.11",
+                "v1.2.0-rc.1",
+                "v1.2.0",
+                "v1.10.0",
+                "v2.0.0+incompatible",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pick_latest_prefers_release() {
+        assert_eq!(pick_latest(["v1.0.0", "v2.0.0-rc.1"]), Some("v1.0.0"));
+        assert_eq!(
+            pick_latest(["v2.0.0-rc.1", "v2.0.0-rc.2"]),
+            Some("v2.0.0-rc.2")
+        );
+        assert_eq!(pick_latest(["master"]), None);
+    }
+
+    #[test]
+    fn test_merge_version_list() {
+        let merged = merge_version_list("v1.1.0\nv1.0.0\n", &["v1.0.1".into(), "v1.0.0".into()]);
+        assert_eq!(merged, "v1.0.0\nv1.0.1\nv1.1.0\n");
+        assert_eq!(merge_version_list("", &[]), "");
+    }
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod integration_tests {
-    use crate::test_helpers::{body_bytes, create_test_context, send, send_with_headers};
+    use crate::test_helpers::{
+        body_bytes, create_test_context, create_test_context_with_config, send, send_with_headers,
+    };
     use axum::http::{Method, StatusCode};
 
     /// A cached module .zip resumes: 206 for a satisfiable range, 416 once the
@@ -1081,4 +1796,177 @@ mod integration_tests {
         assert_eq!(resp.status(), StatusCode::OK);
         assert!(resp.headers().get("accept-ranges").is_none());
     }
+
+    const BOUNDARY: &str = "nora-go-boundary";
+
+    fn upload_body(go_mod: &[u8], zip: &[u8]) -> Vec<u8> {
+        let mut body = Vec::new();
+        for (name, data) in [("mod", go_mod), ("zip", zip)] {
+            body.extend_from_slice(
+                format!(
+                    "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{name}\"; filename=\"{name}\"\r\n\r\n"
+                )
+                .as_bytes(),
+            );
+            body.extend_from_slice(data);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{BOUNDARY}--\r\n").as_bytes());
+        body
+    }
+
+    async fn publish(
+        app: &axum::Router,
+        module: &str,
+        version: &str,
+        go_mod: &[u8],
+    ) -> axum::http::Response<axum::body::Body> {
+        let prefix = format!("{module}@{version}");
+        let zip = super::tests::build_module_zip(&[
+            (&format!("{prefix}/go.mod"), go_mod),
+            (&format!("{prefix}/lib.go"), b"package lib"),
+        ]);
+        let content_type = format!("multipart/form-data; boundary={BOUNDARY}");
+        send_with_headers(
+            app,
+            Method::PUT,
+            &format!("/go/{}/@v/{version}", super::encode_module_path(module)),
+            vec![("content-type", content_type.as_str())],
+            upload_body(go_mod, &zip),
+        )
+        .await
+    }
+
+    /// A published version is served through the regular GOPROXY endpoints.
+    #[tokio::test]
+    async fn test_go_publish_then_download() {
+        let ctx = create_test_context();
+        let go_mod = b"module example.com/Private/lib\n\ngo 1.22\n";
+
+        let resp = publish(&ctx.app, "example.com/Private/lib", "v1.0.0", go_mod).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let resp = publish(&ctx.app, "example.com/Private/lib", "v1.1.0-rc.1", go_mod).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let base = "/go/example.com/!private/lib";
+        let resp = send(&ctx.app, Method::GET, &format!("{base}/@v/list"), "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], b"v1.0.0\nv1.1.0-rc.1\n");
+
+        let resp = send(&ctx.app, Method::GET, &format!("{base}/@latest"), "").await;
+        let info: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(info["Version"], "v1.0.0");
+
+        let resp = send(&ctx.app, Method::GET, &format!("{base}/@v/v1.0.0.mod"), "").await;
+        assert_eq!(&body_bytes(resp).await[..], go_mod);
+
+        let resp = send(&ctx.app, Method::GET, &format!("{base}/@v/v1.0.0.zip"), "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let zip = body_bytes(resp).await;
+        assert!(super::check_module_zip("example.com/Private/lib", "v1.0.0", &zip, go_mod).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_go_publish_conflict_and_validation() {
+        let ctx = create_test_context();
+        let go_mod = b"module example.com/lib\n";
+
+        let resp = publish(&ctx.app, "example.com/lib", "v1.0.0", go_mod).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        // Versions are immutable
+        let resp = publish(&ctx.app, "example.com/lib", "v1.0.0", go_mod).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        // go.mod must declare the module being published
+        let resp = publish(
+            &ctx.app,
+            "example.com/lib",
+            "v1.0.1",
+            b"module other.com/x\n",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        // Non-canonical versions and major-version mismatches are rejected
+        let resp = publish(&ctx.app, "example.com/lib", "master", go_mod).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let resp = publish(&ctx.app, "example.com/lib", "v2.0.0", go_mod).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    /// Hosted versions are merged into the upstream listing, and survive an upstream 404.
+    #[tokio::test]
+    async fn test_go_hosted_overlays_upstream() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/example.com/lib/@v/list"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("v1.0.0\nv1.2.0\n"))
+            .mount(&server)
+            .await;
+        let url = server.uri();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.go.proxy = Some(url);
+            cfg.go.metadata_ttl = 0;
+        });
+
+        let resp = publish(
+            &ctx.app,
+            "example.com/lib",
+            "v1.1.0",
+            b"module example.com/lib\n",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let resp = send(&ctx.app, Method::GET, "/go/example.com/lib/@v/list", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], b"v1.0.0\nv1.1.0\nv1.2.0\n");
+
+        // Unknown upstream: only the hosted versions
+        let resp = publish(
+            &ctx.app,
+            "example.com/private",
+            "v0.1.0",
+            b"module example.com/private\n",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let resp = send(&ctx.app, Method::GET, "/go/example.com/private/@latest", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let info: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(info["Version"], "v0.1.0");
+    }
+
+    #[tokio::test]
+    async fn test_go_publish_namespace_scope_enforced() {
+        use crate::auth::NamespaceAuthority;
+        use crate::config::ScopeEnforcement;
+        use axum::extract::{FromRequest, Multipart, Path, State};
+        use axum::Extension;
+
+        let ctx = create_test_context();
+        let scoped = NamespaceAuthority::from_oidc_scope(
+            "ci",
+            &["example.com/acme/**".to_string()],
+            ScopeEnforcement::Enforce,
+        );
+        let go_mod = b"module example.com/other\n";
+        let zip = super::tests::build_module_zip(&[("example.com/other@v1.0.0/go.mod", go_mod)]);
+        let request = axum::http::Request::builder()
+            .header(
+                "content-type",
+                format!("multipart/form-data; boundary={BOUNDARY}"),
+            )
+            .body(axum::body::Body::from(upload_body(go_mod, &zip)))
+            .unwrap();
+        let multipart = Multipart::from_request(request, &()).await.unwrap();
+        let resp = super::upload(
+            State(ctx.state.clone()),
+            Extension(scoped),
+            Path("example.com/other/@v/v1.0.0".to_string()),
+            multipart,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
 }
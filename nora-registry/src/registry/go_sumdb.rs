@@ -0,0 +1,477 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+//! Go checksum database proxy (the GOPROXY `/sumdb/` passthrough).
+//!
+//! With `GOPROXY=http://nora/go`, the go command asks the proxy before contacting a
+//! checksum database directly:
+//!   GET /go/sumdb/{name}/supported                  — 200 when `name` is proxied
+//!   GET /go/sumdb/{name}/lookup/{module}@{version}  — signed record for one module version
+//!   GET /go/sumdb/{name}/tile/{H}/{L}/{N}[.p/{W}]   — transparency-log tile
+//!   GET /go/sumdb/{name}/latest                     — latest signed tree head
+//!
+//! Lookups and tiles never change once published, so they are cached forever. The log only
+//! grows, so a partial hash tile is a prefix of every wider tile at the same position: a
+//! partial the client has not asked for before is cut from a cached wider copy. That keeps
+//! previously verified modules verifiable with the upstream unreachable.
+
+use super::go::{decode_module_path, with_content_type};
+use crate::config::registry::RepoProxyEntry;
+use crate::registry::{circuit_open_response, proxy_fetch, ProxyError};
+use crate::registry_type::RegistryType;
+use crate::AppState;
+use axum::body::Bytes;
+use axum::http::{HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use std::time::Duration;
+
+/// Size of one hash in a hash tile (SHA-256).
+const HASH_SIZE: usize = 32;
+
+/// Dispatch `/go/sumdb/{name}/{rest}`; `path` is everything after `sumdb/`.
+pub(super) async fn handle(state: &AppState, path: &str) -> Response {
+    let Some((name, rest)) = path.split_once('/') else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let Some(upstream) = state.config.go.sumdbs.get(name) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let key = format!("go/sumdb/{}/{}", name, rest);
+    if rest == "supported" {
+        return StatusCode::OK.into_response();
+    }
+    if rest == "latest" {
+        return fetch_latest(state, upstream, &key).await;
+    }
+    if let Some(target) = rest.strip_prefix("lookup/") {
+        let Some((module, version)) = target.rsplit_once('@') else {
+            return StatusCode::BAD_REQUEST.into_response();
+        };
+        if version.is_empty() || version.contains('/') {
+            return StatusCode::BAD_REQUEST.into_response();
+        }
+        let Ok(module_path) = decode_module_path(module) else {
+            return StatusCode::BAD_REQUEST.into_response();
+        };
+        if let Ok(data) = state.storage.get(&key).await {
+            return with_content_type(data.to_vec(), "text/plain; charset=utf-8", false);
+        }
+        // #733: asking the public database about an internal module leaks its name, which is
+        // exactly what GONOSUMDB exists to prevent — never forward it.
+        if crate::curation::is_internal_namespace(
+            &state.curation().curation_engine,
+            crate::curation::RegistryType::Go,
+            &module_path,
+        ) {
+            return crate::curation::check_namespace_isolation(
+                &state.curation().curation_engine,
+                crate::curation::RegistryType::Go,
+                &module_path,
+            )
+            .unwrap_or_else(|| StatusCode::NOT_FOUND.into_response());
+        }
+        return fetch_immutable(state, upstream, rest, key, "text/plain; charset=utf-8").await;
+    }
+    if let Some(tile) = rest.strip_prefix("tile/").and_then(parse_tile) {
+        if let Ok(data) = state.storage.get(&key).await {
+            return with_content_type(data.to_vec(), "application/octet-stream", false);
+        }
+        if let Some(data) = derive_partial_tile(state, name, &tile).await {
+            return with_content_type(data, "application/octet-stream", false);
+        }
+        return fetch_immutable(state, upstream, rest, key, "application/octet-stream").await;
+    }
+    StatusCode::NOT_FOUND.into_response()
+}
+
+/// A parsed `tile/{H}/{L}/{N}[.p/{W}]` path.
+#[derive(Debug, PartialEq)]
+struct Tile {
+    height: u32,
+    /// Tree level, or `None` for a data tile (`L` = `data`).
+    level: Option<u32>,
+    /// The index exactly as it appears in the path (`x123/456`).
+    index: String,
+    /// Width of a partial tile; `None` for a full one.
+    width: Option<u32>,
+}
+
+/// Parse the path after `tile/` (tlog `ParseTilePath`). Rejecting anything else keeps
+/// arbitrary keys out of the cache.
+fn parse_tile(path: &str) -> Option<Tile> {
+    let (height, rest) = path.split_once('/')?;
+    let (level, rest) = rest.split_once('/')?;
+    let height: u32 = parse_decimal(height)?;
+    if !(1..=30).contains(&height) {
+        return None;
+    }
+    let level = match level {
+        "data" => None,
+        l => Some(parse_decimal(l).filter(|l| *l < 64)?),
+    };
+    let (index, width) = match rest.split_once(".p/") {
+        Some((index, w)) => {
+            let w: u32 = parse_decimal(w)?;
+            if w == 0 || w >= 1 << height {
+                return None;
+            }
+            (index, Some(w))
+        }
+        None => (rest, None),
+    };
+    let mut groups = index.split('/').peekable();
+    while let Some(group) = groups.next() {
+        let digits = if groups.peek().is_some() {
+            group.strip_prefix('x')?
+        } else {
+            group
+        };
+        if digits.len() != 3 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+    }
+    Some(Tile {
+        height,
+        level,
+        index: index.to_string(),
+        width,
+    })
+}
+
+/// Canonical unsigned decimal: no sign, no leading zeros.
+fn parse_decimal(s: &str) -> Option<u32> {
+    if s.is_empty() || (s.len() > 1 && s.starts_with('0')) {
+        return None;
+    }
+    if !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Serve a partial hash tile from a cached full tile or a wider partial at the same position.
+/// Data tiles hold variable-length records and are never cut.
+async fn derive_partial_tile(state: &AppState, name: &str, tile: &Tile) -> Option<Vec<u8>> {
+    let width = tile.width? as usize;
+    let level = tile.level?;
+    let base = format!(
+        "go/sumdb/{}/tile/{}/{}/{}",
+        name, tile.height, level, tile.index
+    );
+    let need = width * HASH_SIZE;
+
+    let mut candidates = vec![base.clone()];
+    let partial_prefix = format!("{}.p/", base);
+    if let Ok(keys) = state.storage.list(&partial_prefix).await {
+        candidates.extend(keys.into_iter().filter(|k| {
+            k.strip_prefix(&partial_prefix)
+                .and_then(parse_decimal)
+                .is_some_and(|w| w as usize > width)
+        }));
+    }
+    for key in candidates {
+        if let Ok(data) = state.storage.get(&key).await {
+            if data.len() >= need {
+                tracing::debug!(key = %key, width, "Go sumdb: partial tile served from wider copy");
+                return Some(data[..need].to_vec());
+            }
+        }
+    }
+    None
+}
+
+/// Fetch a lookup or tile from the checksum database and cache it forever.
+async fn fetch_immutable(
+    state: &AppState,
+    upstream: &RepoProxyEntry,
+    rest: &str,
+    key: String,
+    content_type: &'static str,
+) -> Response {
+    match fetch_upstream(state, upstream, rest).await {
+        Ok(data) => {
+            state.spawn_cache_immutable("go", key, Bytes::from(data.clone()));
+            with_content_type(data, content_type, false)
+        }
+        Err(e) => upstream_error(rest, e),
+    }
+}
+
+/// `latest` moves with every new log entry: revalidate after `metadata_ttl`, and fall back
+/// to the cached tree head when the database is unreachable.
+async fn fetch_latest(state: &AppState, upstream: &RepoProxyEntry, key: &str) -> Response {
+    let cached = state.storage.get(key).await.ok();
+    if let Some(ref data) = cached {
+        let modified = state.storage.stat(key).await.map(|m| m.modified);
+        if crate::cache_ttl::mutable_ref_fresh(true, state.config.go.metadata_ttl, modified) {
+            return with_content_type(data.to_vec(), "text/plain; charset=utf-8", true);
+        }
+    }
+    match fetch_upstream(state, upstream, "latest").await {
+        Ok(data) => {
+            state.spawn_cache("go", key.to_string(), Bytes::from(data.clone()));
+            with_content_type(data, "text/plain; charset=utf-8", true)
+        }
+        Err(e) => match cached {
+            Some(data) => {
+                tracing::warn!(key = %key, "Go sumdb upstream failed, serving stale tree head");
+                let mut response =
+                    with_content_type(data.to_vec(), "text/plain; charset=utf-8", true);
+                response.headers_mut().insert(
+                    axum::http::header::HeaderName::from_static("x-nora-stale"),
+                    HeaderValue::from_static("true"),
+                );
+                response
+            }
+            None => upstream_error("latest", e),
+        },
+    }
+}
+
+async fn fetch_upstream(
+    state: &AppState,
+    upstream: &RepoProxyEntry,
+    rest: &str,
+) -> Result<Vec<u8>, ProxyError> {
+    let url = format!("{}/{}", upstream.url().trim_end_matches('/'), rest);
+    proxy_fetch(
+        &state.http_client,
+        &url,
+        Duration::from_secs(state.config.go.proxy_timeout),
+        upstream.auth(),
+        &state.circuit_breaker,
+        RegistryType::Go,
+    )
+    .await
+}
+
+fn upstream_error(rest: &str, e: ProxyError) -> Response {
+    match e {
+        ProxyError::NotFound => StatusCode::NOT_FOUND.into_response(),
+        ProxyError::CircuitOpen(reg) => circuit_open_response(&reg),
+        _ => {
+            tracing::debug!(path = rest, error = ?e, "Go sumdb upstream error");
+            StatusCode::BAD_GATEWAY.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tile() {
+        assert_eq!(
+            parse_tile("8/0/x123/456.p/10"),
+            Some(Tile {
+                height: 8,
+                level: Some(0),
+                index: "x123/456".into(),
+                width: Some(10),
+            })
+        );
+        assert_eq!(
+            parse_tile("8/data/000"),
+            Some(Tile {
+                height: 8,
+                level: None,
+                index: "000".into(),
+                width: None,
+            })
+        );
+        assert!(parse_tile("8/0/123/456").is_none()); // inner group without `x`
+        assert!(parse_tile("8/0/x12").is_none());
+        assert!(parse_tile("8/0/001.p/256").is_none()); // width must be < 2^H
+        assert!(parse_tile("8/0/001.p/0").is_none());
+        assert!(parse_tile("08/0/001").is_none());
+        assert!(parse_tile("8/-1/001").is_none());
+        assert!(parse_tile("8/0").is_none());
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod integration_tests {
+    use crate::config::registry::RepoProxyEntry;
+    use crate::test_helpers::{body_bytes, create_test_context_with_config, send};
+    use axum::http::{Method, StatusCode};
+    use std::collections::BTreeMap;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn sumdbs(url: String) -> BTreeMap<String, RepoProxyEntry> {
+        BTreeMap::from([("sum.golang.org".to_string(), RepoProxyEntry::Simple(url))])
+    }
+
+    async fn wait_cached(storage: &crate::storage::Storage, key: &str) {
+        for _ in 0..50 {
+            if storage.stat(key).await.is_some() {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        panic!("{key} was never cached");
+    }
+
+    #[tokio::test]
+    async fn test_sumdb_supported_only_for_configured_names() {
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.go.sumdbs = sumdbs("http://127.0.0.1:1".into());
+        });
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/go/sumdb/sum.golang.org/supported",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/go/sumdb/sum.example.com/supported",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    /// A lookup is cached once fetched and still answers after the database goes away.
+    #[tokio::test]
+    async fn test_sumdb_lookup_cached_for_offline() {
+        let server = MockServer::start().await;
+        let record = "123\nexample.com/lib v1.0.0 h1:abc=\n\n— sum.golang.org sig\n";
+        Mock::given(method("GET"))
+            .and(path("/lookup/example.com/!lib@v1.0.0"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(record))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let url = server.uri();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.go.sumdbs = sumdbs(url);
+        });
+        let uri = "/go/sumdb/sum.golang.org/lookup/example.com/!lib@v1.0.0";
+
+        let resp = send(&ctx.app, Method::GET, uri, "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], record.as_bytes());
+        wait_cached(
+            &ctx.state.storage,
+            "go/sumdb/sum.golang.org/lookup/example.com/!lib@v1.0.0",
+        )
+        .await;
+
+        drop(server);
+        let resp = send(&ctx.app, Method::GET, uri, "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], record.as_bytes());
+    }
+
+    /// A narrower partial hash tile is cut from the cached full tile without asking upstream.
+    #[tokio::test]
+    async fn test_sumdb_partial_tile_from_cached_full_tile() {
+        let server = MockServer::start().await;
+        let full: Vec<u8> = (0..=255u8).flat_map(|b| [b; 32]).collect();
+        Mock::given(method("GET"))
+            .and(path("/tile/8/0/x001/002"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(full.clone()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let url = server.uri();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.go.sumdbs = sumdbs(url);
+        });
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/go/sumdb/sum.golang.org/tile/8/0/x001/002",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(body_bytes(resp).await.len(), 256 * 32);
+        wait_cached(
+            &ctx.state.storage,
+            "go/sumdb/sum.golang.org/tile/8/0/x001/002",
+        )
+        .await;
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/go/sumdb/sum.golang.org/tile/8/0/x001/002.p/3",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], &full[..3 * 32]);
+
+        // Malformed tile paths are rejected before reaching storage or upstream.
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/go/sumdb/sum.golang.org/tile/8/0/1/002",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_sumdb_latest_serves_stale_when_upstream_down() {
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.go.sumdbs = sumdbs("http://127.0.0.1:1".into());
+            cfg.go.metadata_ttl = 0;
+        });
+        ctx.state
+            .storage
+            .put(
+                "go/sumdb/sum.golang.org/latest",
+                b"go.sum database tree\n42\n",
+            )
+            .await
+            .unwrap();
+
+        let resp = send(&ctx.app, Method::GET, "/go/sumdb/sum.golang.org/latest", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers()
+                .get("x-nora-stale")
+                .unwrap()
+                .to_str()
+                .unwrap(),
+            "true"
+        );
+        assert_eq!(&body_bytes(resp).await[..], b"go.sum database tree\n42\n");
+    }
+
+    /// An internal module is never looked up in the public database.
+    #[tokio::test]
+    async fn test_sumdb_lookup_internal_namespace_not_forwarded() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("leaked"))
+            .expect(0)
+            .mount(&server)
+            .await;
+        let url = server.uri();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.go.sumdbs = sumdbs(url);
+            cfg.curation.internal_namespaces = vec!["corp.example.com/*".to_string()];
+        });
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/go/sumdb/sum.golang.org/lookup/corp.example.com/secret@v1.0.0",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
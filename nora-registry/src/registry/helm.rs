@@ -0,0 +1,1344 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+//! Helm chart repository — a hosted repo with a ChartMuseum-compatible API
+//! plus per-repo pull-through proxies of upstream chart repositories.
+//!
+//! Hosted repo (`helm repo add nora http://nora:4000/helm`):
+//!   GET    /helm/index.yaml                   — generated from every chart's Chart.yaml
+//!   GET    /helm/charts/{file}                — chart archive or `.prov` file
+//!   HEAD   /helm/charts/{file}                — existence/size check
+//!
+//! ChartMuseum API (`helm cm-push`, `curl --data-binary`):
+//!   POST   /helm/api/charts                   — upload: raw `.tgz` body, or multipart `chart` (+ `prov`)
+//!   POST   /helm/api/prov                     — upload a provenance file on its own
+//!   GET    /helm/api/charts                   — every chart with its versions
+//!   GET    /helm/api/charts/{name}            — versions of one chart
+//!   GET    /helm/api/charts/{name}/{version}  — one chart version
+//!   DELETE /helm/api/charts/{name}/{version}  — remove a version and its provenance
+//!
+//! Versions are immutable: an upload of an existing chart or provenance file
+//! is a 409, `helm cm-push --force` included. Every publish/delete rebuilds
+//! `index.yaml` from per-version sidecars (Chart.yaml parsed once at upload,
+//! same shape as the rpm/deb handlers), so a rebuild never re-reads chart
+//! archives. Index URLs are relative (`charts/{file}`),
+//! which helm resolves against the repo URL.
+//!
+//! Pull-through repos (`[helm.proxies] bitnami = "https://charts.bitnami.com/bitnami"`):
+//!   GET    /helm/{repo}/index.yaml            — upstream index, chart URLs rewritten
+//!   GET    /helm/{repo}/charts/{file}         — chart archive, fetched once and cached
+//!
+//! The upstream index is cached under `metadata_ttl`, alongside a map from each
+//! rewritten file name to its original (often absolute, off-host) URL. Chart
+//! downloads are gated by curation on `name`/`version` and by the digest
+//! quarantine like every other proxied artifact.
+
+use crate::activity_log::{ActionType, ActivityEntry};
+use crate::audit::AuditEntry;
+use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
+use crate::cache_ttl::is_within_ttl;
+use crate::config::registry::RepoProxyEntry;
+use crate::registry::{circuit_open_response, proxy_fetch, read_json_sidecars, ProxyError};
+use crate::registry_type::RegistryType;
+use crate::validation::validate_storage_key;
+use crate::version::{cmp_semver, is_semver};
+use crate::AppState;
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Multipart, Path, Request, State},
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::Digest;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/helm/index.yaml", get(hosted_index))
+        .route("/helm/charts/{file}", get(download).head(check_exists))
+        .route("/helm/api/charts", get(list_charts).post(upload_chart))
+        .route("/helm/api/prov", post(upload_prov))
+        .route("/helm/api/charts/{name}", get(list_versions))
+        .route(
+            "/helm/api/charts/{name}/{version}",
+            get(describe_version).delete(delete_version),
+        )
+        .route("/helm/{repo}/index.yaml", get(proxy_index))
+        .route("/helm/{repo}/charts/{file}", get(proxy_chart))
+}
+
+const INDEX_KEY: &str = "helm/index.yaml";
+/// Per-version sidecars of the hosted repo — the source `index.yaml` is built from.
+const META_PREFIX: &str = "helm/.nora-meta/";
+const INDEX_CONTENT_TYPE: &str = "application/x-yaml";
+const CHART_CONTENT_TYPE: &str = "application/gzip";
+const PROV_CONTENT_TYPE: &str = "application/pgp-signature";
+/// Upper bound on a chart archive's unpacked size (checked up to Chart.yaml).
+const MAX_CHART_UNPACKED: u64 = 256 << 20;
+/// Upper bound on `Chart.yaml`.
+const MAX_CHART_YAML_SIZE: u64 = 1 << 20;
+
+fn chart_key(file: &str) -> String {
+    format!("helm/charts/{file}")
+}
+
+fn sidecar_key(name: &str, version: &str) -> String {
+    format!("{META_PREFIX}{name}-{version}.json")
+}
+
+fn proxy_index_key(repo: &str) -> String {
+    format!("helm/{repo}/index.yaml")
+}
+
+fn proxy_refs_key(repo: &str) -> String {
+    format!("helm/{repo}/.nora-meta/charts.json")
+}
+
+fn proxy_chart_key(repo: &str, file: &str) -> String {
+    format!("helm/{repo}/charts/{file}")
+}
+
+// ============================================================================
+// Chart parsing and index generation
+// ============================================================================
+
+/// Split `{name}-{version}.tgz` at the first `-` followed by a semantic version.
+///
+/// Names may themselves contain dashes and digits (`k8s-1-agent`), so the
+/// split is the leftmost one whose tail parses as a version. Uploads are
+/// rejected unless their file name splits back to the Chart.yaml name/version.
+pub(crate) fn split_chart_filename(file: &str) -> Option<(&str, &str)> {
+    let stem = file.strip_suffix(".tgz")?;
+    stem.match_indices('-').find_map(|(i, _)| {
+        let (name, version) = (&stem[..i], &stem[i + 1..]);
+        (!name.is_empty() && is_semver(version.strip_prefix('v').unwrap_or(version)))
+            .then_some((name, version))
+    })
+}
+
+/// A chart or provenance file name that is safe as one storage-key segment.
+fn is_chart_file(file: &str) -> bool {
+    !file.is_empty()
+        && file.is_ascii()
+        && !file.starts_with('.')
+        && !file.contains(['/', '\\'])
+        && !file.contains("..")
+        && (file.ends_with(".tgz") || file.ends_with(".tgz.prov"))
+}
+
+fn is_valid_chart_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 250
+        && name.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+}
+
+fn content_type(file: &str) -> &'static str {
+    if file.ends_with(".prov") {
+        PROV_CONTENT_TYPE
+    } else {
+        CHART_CONTENT_TYPE
+    }
+}
+
+/// Read `{chart}/Chart.yaml` from a chart archive as JSON.
+///
+/// The archive must be a gzip tar whose entries stay inside the chart (no
+/// absolute paths or `..`) and unpack to at most [`MAX_CHART_UNPACKED`] bytes
+/// up to the Chart.yaml entry.
+fn read_chart_yaml(data: &[u8]) -> Result<Value, String> {
+    use std::io::Read;
+    use std::path::Component;
+
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(data));
+    let mut total: u64 = 0;
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("Chart is not a gzip tar: {}", e))?
+    {
+        let mut entry = entry.map_err(|e| format!("Chart is not a gzip tar: {}", e))?;
+        let path = entry.path().map_err(|e| e.to_string())?.into_owned();
+        if path.is_absolute() || path.components().any(|c| c == Component::ParentDir) {
+            return Err(format!(
+                "Chart entry escapes the archive: {}",
+                path.display()
+            ));
+        }
+        total = total.saturating_add(entry.size());
+        if total > MAX_CHART_UNPACKED {
+            return Err(format!("Chart unpacks past {} bytes", MAX_CHART_UNPACKED));
+        }
+        let parts: Vec<_> = path
+            .components()
+            .filter(|c| *c != Component::CurDir)
+            .collect();
+        if parts.len() == 2
+            && parts[1].as_os_str() == "Chart.yaml"
+            && entry.header().entry_type().is_file()
+        {
+            if entry.size() > MAX_CHART_YAML_SIZE {
+                return Err("Chart.yaml is too large".to_string());
+            }
+            let mut content = Vec::new();
+            entry
+                .read_to_end(&mut content)
+                .map_err(|e| format!("Chart.yaml: {}", e))?;
+            return parse_chart_yaml(&content);
+        }
+    }
+    Err("Chart has no {chart}/Chart.yaml".to_string())
+}
+
+fn parse_chart_yaml(content: &[u8]) -> Result<Value, String> {
+    let mut chart: Value = serde_yaml_ng::from_slice(content)
+        .map_err(|e| format!("Chart.yaml is not valid YAML: {}", e))?;
+    let Some(obj) = chart.as_object_mut() else {
+        return Err("Chart.yaml must be a mapping".to_string());
+    };
+    for field in ["apiVersion", "name", "version"] {
+        if !obj.get(field).is_some_and(Value::is_string) {
+            return Err(format!("Chart.yaml has no `{}`", field));
+        }
+    }
+    if !matches!(obj["apiVersion"].as_str(), Some("v1" | "v2")) {
+        return Err("Chart.yaml `apiVersion` must be v1 or v2".to_string());
+    }
+    // An unquoted `appVersion: 1.10` parses as a number; helm reads it as a
+    // string and rejects an index that carries a number.
+    if let Some(app) = obj.get_mut("appVersion") {
+        if app.is_number() {
+            *app = Value::String(app.to_string());
+        }
+    }
+    Ok(chart)
+}
+
+/// The chart file a provenance file signs: the first entry of its `files:` block.
+fn prov_chart_file(prov: &[u8]) -> Option<String> {
+    let text = std::str::from_utf8(prov).ok()?;
+    let mut lines = text.lines().skip_while(|l| l.trim_end() != "files:");
+    lines.next()?;
+    let (file, sum) = lines.next()?.trim().split_once(':')?;
+    sum.trim_start()
+        .starts_with("sha256:")
+        .then(|| file.trim().to_string())
+}
+
+/// Render `index.yaml` from chart-version entries: grouped by name, newest first.
+fn render_index(entries: Vec<Value>) -> Result<String, String> {
+    let mut grouped: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+    for entry in entries {
+        if let Some(name) = entry["name"].as_str() {
+            grouped.entry(name.to_string()).or_default().push(entry);
+        }
+    }
+    for versions in grouped.values_mut() {
+        versions.sort_by(|a, b| {
+            cmp_chart_versions(
+                b["version"].as_str().unwrap_or_default(),
+                a["version"].as_str().unwrap_or_default(),
+            )
+        });
+    }
+    let index = json!({
+        "apiVersion": "v1",
+        "entries": grouped,
+        "generated": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+    });
+    serde_yaml_ng::to_string(&index).map_err(|e| format!("render index.yaml: {e}"))
+}
+
+/// Rebuild the hosted `index.yaml` from the sidecars. Callers hold the
+/// publish lock on [`INDEX_KEY`].
+async fn regenerate_index(storage: &crate::storage::Storage) -> Result<(), String> {
+    let entries = read_json_sidecars::<Value>(storage, META_PREFIX).await?;
+    let index = render_index(entries)?;
+    storage
+        .put(INDEX_KEY, index.as_bytes())
+        .await
+        .map_err(|e| format!("write index.yaml: {e}"))
+}
+
+/// Chart version ordering: semver, ignoring the leading `v` Helm tolerates.
+fn cmp_chart_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    fn strip(v: &str) -> &str {
+        v.strip_prefix('v').unwrap_or(v)
+    }
+    cmp_semver(strip(a), strip(b))
+}
+
+fn helm_error(status: StatusCode, message: &str) -> Response {
+    (status, Json(json!({ "error": message }))).into_response()
+}
+
+fn yaml_response(data: Bytes) -> Response {
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, INDEX_CONTENT_TYPE),
+            // Rewritten in place on every publish — clients must revalidate.
+            (header::CACHE_CONTROL, "no-cache"),
+        ],
+        data,
+    )
+        .into_response()
+}
+
+// ============================================================================
+// Hosted repository
+// ============================================================================
+
+async fn hosted_index(State(state): State<AppState>) -> Response {
+    if !state.config.helm.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    match state.storage.get(INDEX_KEY).await {
+        Ok(data) => yaml_response(data),
+        // Nothing published yet: an empty index so `helm repo add` succeeds.
+        Err(crate::storage::StorageError::NotFound) => match render_index(Vec::new()) {
+            Ok(index) => yaml_response(Bytes::from(index)),
+            Err(e) => {
+                tracing::error!(error = %e, "helm: failed to render empty index");
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            }
+        },
+        Err(e) => {
+            tracing::error!(error = %e, "helm: failed to read index.yaml");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+async fn download(State(state): State<AppState>, Path(file): Path<String>) -> Response {
+    if !state.config.helm.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let key = chart_key(&file);
+    if !is_chart_file(&file) || validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    match state.storage.get_verified(&key).await {
+        Ok(outcome) => {
+            use nora_registry::verified::{verified_body, GateOutcome};
+            let data = match outcome {
+                GateOutcome::Verified(blob) => verified_body(blob),
+                GateOutcome::Unpinned(blob) => blob.into_inner(),
+            };
+            state.metrics.record_download("helm");
+            state.activity.push(ActivityEntry::new(
+                ActionType::Pull,
+                file.clone(),
+                RegistryType::Helm,
+                "LOCAL",
+            ));
+            (
+                StatusCode::OK,
+                [(header::CONTENT_TYPE, content_type(&file))],
+                data,
+            )
+                .into_response()
+        }
+        Err(crate::storage::StorageError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, key = %key, "helm: failed to read chart");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+async fn check_exists(State(state): State<AppState>, Path(file): Path<String>) -> Response {
+    if !state.config.helm.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let key = chart_key(&file);
+    if !is_chart_file(&file) || validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    match state.storage.stat(&key).await {
+        Some(meta) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_LENGTH, meta.size.to_string()),
+                (header::CONTENT_TYPE, content_type(&file).to_string()),
+            ],
+        )
+            .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Request body of an upload: multipart `chart`/`prov` fields, or the raw file.
+async fn read_upload(
+    state: &AppState,
+    request: Request,
+    raw_field: &str,
+) -> Result<(Option<Bytes>, Option<Bytes>), Response> {
+    let multipart = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("multipart/form-data"));
+    if !multipart {
+        let body = Bytes::from_request(request, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        return Ok(if raw_field == "prov" {
+            (None, Some(body))
+        } else {
+            (Some(body), None)
+        });
+    }
+
+    let mut multipart = Multipart::from_request(request, state)
+        .await
+        .map_err(IntoResponse::into_response)?;
+    let (mut chart, mut prov) = (None, None);
+    while let Ok(Some(field)) = multipart.next_field().await {
+        let name = field.name().unwrap_or_default().to_string();
+        let data = field.bytes().await.map_err(|e| {
+            helm_error(
+                StatusCode::BAD_REQUEST,
+                &format!("Failed to read `{name}` field: {e}"),
+            )
+        })?;
+        match name.as_str() {
+            "chart" => chart = Some(data),
+            "prov" => prov = Some(data),
+            _ => {}
+        }
+    }
+    Ok((chart, prov))
+}
+
+/// POST /helm/api/charts — publish a chart (and optionally its provenance).
+///
+/// The version is taken from the archive's Chart.yaml; the stored file name is
+/// always the canonical `{name}-{version}.tgz`, whatever the client called it.
+async fn upload_chart(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    request: Request,
+) -> Response {
+    if !state.config.helm.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let (chart, prov) = match read_upload(&state, request, "chart").await {
+        Ok(parts) => parts,
+        Err(response) => return response,
+    };
+    let Some(chart) = chart.filter(|c| !c.is_empty()) else {
+        return helm_error(StatusCode::BAD_REQUEST, "Missing chart archive");
+    };
+    if chart.len() as u64 > state.config.helm.max_file_size {
+        return helm_error(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            &format!(
+                "Chart too large. Max size: {} bytes",
+                state.config.helm.max_file_size
+            ),
+        );
+    }
+
+    let metadata = match read_chart_yaml(&chart) {
+        Ok(m) => m,
+        Err(e) => return helm_error(StatusCode::BAD_REQUEST, &e),
+    };
+    let name = metadata["name"].as_str().unwrap_or_default().to_string();
+    let version = metadata["version"].as_str().unwrap_or_default().to_string();
+    if !is_valid_chart_name(&name) {
+        return helm_error(
+            StatusCode::BAD_REQUEST,
+            "Chart.yaml `name` is not a valid chart name",
+        );
+    }
+    let file = format!("{name}-{version}.tgz");
+    if split_chart_filename(&file) != Some((name.as_str(), version.as_str())) {
+        return helm_error(
+            StatusCode::BAD_REQUEST,
+            "Chart.yaml `version` is not a semantic version",
+        );
+    }
+    // Enforce OIDC namespace_scope on the chart name (#583).
+    if enforce_namespace_scope(&authority, &name).is_err() {
+        return helm_error(StatusCode::FORBIDDEN, "Outside namespace scope");
+    }
+    let key = chart_key(&file);
+    if validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    let lock = state.publish_lock(INDEX_KEY);
+    let _guard = lock.lock().await;
+
+    if state.storage.stat(&key).await.is_some() {
+        return helm_error(StatusCode::CONFLICT, "file already exists");
+    }
+
+    let created = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+    let mut entry = metadata;
+    if let Some(obj) = entry.as_object_mut() {
+        obj.insert("urls".into(), json!([format!("charts/{file}")]));
+        obj.insert("created".into(), json!(created));
+        obj.insert(
+            "digest".into(),
+            json!(hex::encode(sha2::Sha256::digest(&chart))),
+        );
+    }
+
+    // Archive (and provenance) first; the sidecar is what makes the version visible.
+    if let Err(e) = state.storage.put(&key, &chart).await {
+        tracing::error!(error = %e, key = %key, "helm: failed to store chart");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    if let Some(prov) = prov.filter(|p| !p.is_empty()) {
+        if let Err(e) = state.storage.put(&format!("{key}.prov"), &prov).await {
+            tracing::error!(error = %e, key = %key, "helm: failed to store provenance");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+    let sidecar = serde_json::to_vec(&entry).unwrap_or_default();
+    if let Err(e) = state
+        .storage
+        .put(&sidecar_key(&name, &version), &sidecar)
+        .await
+    {
+        tracing::error!(error = %e, key = %key, "helm: failed to store metadata sidecar");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    if let Err(e) = regenerate_index(&state.storage).await {
+        tracing::error!(error = %e, "helm: index regeneration failed");
+        return helm_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Chart stored but index regeneration failed",
+        );
+    }
+
+    let artifact = format!("{name}@{version}");
+    state.metrics.record_upload("helm");
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &artifact, "helm", ""));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        artifact,
+        RegistryType::Helm,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("helm");
+
+    (StatusCode::CREATED, Json(json!({ "saved": true }))).into_response()
+}
+
+/// POST /helm/api/prov — store a provenance file next to the chart it signs.
+async fn upload_prov(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    request: Request,
+) -> Response {
+    if !state.config.helm.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let prov = match read_upload(&state, request, "prov").await {
+        Ok((_, prov)) => prov,
+        Err(response) => return response,
+    };
+    let Some(prov) = prov.filter(|p| !p.is_empty()) else {
+        return helm_error(StatusCode::BAD_REQUEST, "Missing provenance file");
+    };
+    let Some(file) = prov_chart_file(&prov).filter(|f| is_chart_file(f) && f.ends_with(".tgz"))
+    else {
+        return helm_error(
+            StatusCode::BAD_REQUEST,
+            "Provenance file names no chart archive",
+        );
+    };
+    let Some((name, _)) = split_chart_filename(&file) else {
+        return helm_error(
+            StatusCode::BAD_REQUEST,
+            "Provenance file names no chart archive",
+        );
+    };
+    if enforce_namespace_scope(&authority, name).is_err() {
+        return helm_error(StatusCode::FORBIDDEN, "Outside namespace scope");
+    }
+    let key = format!("{}.prov", chart_key(&file));
+    if validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    let lock = state.publish_lock(INDEX_KEY);
+    let _guard = lock.lock().await;
+
+    if state.storage.stat(&key).await.is_some() {
+        return helm_error(StatusCode::CONFLICT, "file already exists");
+    }
+    if let Err(e) = state.storage.put(&key, &prov).await {
+        tracing::error!(error = %e, key = %key, "helm: failed to store provenance");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &file, "helm", "prov"));
+
+    (StatusCode::CREATED, Json(json!({ "saved": true }))).into_response()
+}
+
+/// Hosted chart versions grouped by name, newest first.
+async fn hosted_charts(state: &AppState) -> Result<BTreeMap<String, Vec<Value>>, Response> {
+    let entries = read_json_sidecars::<Value>(&state.storage, META_PREFIX)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "helm: failed to read sidecars");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        })?;
+    let mut grouped: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+    for entry in entries {
+        if let Some(name) = entry["name"].as_str() {
+            grouped.entry(name.to_string()).or_default().push(entry);
+        }
+    }
+    for versions in grouped.values_mut() {
+        versions.sort_by(|a, b| {
+            cmp_chart_versions(
+                b["version"].as_str().unwrap_or_default(),
+                a["version"].as_str().unwrap_or_default(),
+            )
+        });
+    }
+    Ok(grouped)
+}
+
+async fn list_charts(State(state): State<AppState>) -> Response {
+    if !state.config.helm.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    match hosted_charts(&state).await {
+        Ok(charts) => Json(charts).into_response(),
+        Err(response) => response,
+    }
+}
+
+async fn list_versions(State(state): State<AppState>, Path(name): Path<String>) -> Response {
+    if !state.config.helm.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    match hosted_charts(&state).await {
+        Ok(mut charts) => match charts.remove(&name) {
+            Some(versions) => Json(versions).into_response(),
+            None => helm_error(StatusCode::NOT_FOUND, "chart not found"),
+        },
+        Err(response) => response,
+    }
+}
+
+async fn describe_version(
+    State(state): State<AppState>,
+    Path((name, version)): Path<(String, String)>,
+) -> Response {
+    if !state.config.helm.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    if !is_valid_chart_name(&name) || !is_chart_file(&format!("{name}-{version}.tgz")) {
+        return helm_error(StatusCode::BAD_REQUEST, "Invalid chart name or version");
+    }
+    match state.storage.get(&sidecar_key(&name, &version)).await {
+        Ok(data) => match serde_json::from_slice::<Value>(&data) {
+            Ok(entry) => Json(entry).into_response(),
+            Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+        },
+        Err(_) => helm_error(StatusCode::NOT_FOUND, "chart version not found"),
+    }
+}
+
+/// DELETE /helm/api/charts/{name}/{version}
+async fn delete_version(
+    State(state): State<AppState>,
+    Path((name, version)): Path<(String, String)>,
+    Extension(authority): Extension<NamespaceAuthority>,
+) -> Response {
+    if !state.config.helm.enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let file = format!("{name}-{version}.tgz");
+    if !is_valid_chart_name(&name) || !is_chart_file(&file) {
+        return helm_error(StatusCode::BAD_REQUEST, "Invalid chart name or version");
+    }
+    if enforce_namespace_scope(&authority, &name).is_err() {
+        return helm_error(StatusCode::FORBIDDEN, "Outside namespace scope");
+    }
+    let key = chart_key(&file);
+    if validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    let lock = state.publish_lock(INDEX_KEY);
+    let _guard = lock.lock().await;
+
+    match state.storage.delete(&key).await {
+        Ok(()) => {}
+        Err(crate::storage::StorageError::NotFound) => {
+            return helm_error(StatusCode::NOT_FOUND, "chart version not found")
+        }
+        Err(e) => {
+            tracing::error!(error = %e, key = %key, "helm: failed to delete chart");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+    for extra in [format!("{key}.prov"), sidecar_key(&name, &version)] {
+        match state.storage.delete(&extra).await {
+            Ok(()) | Err(crate::storage::StorageError::NotFound) => {}
+            Err(e) => tracing::warn!(error = %e, key = %extra, "helm: failed to delete"),
+        }
+    }
+    if let Err(e) = regenerate_index(&state.storage).await {
+        tracing::error!(error = %e, "helm: index regeneration failed");
+        return helm_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Chart deleted but index regeneration failed",
+        );
+    }
+
+    state.audit.log(AuditEntry::new(
+        "delete",
+        "api",
+        &format!("{name}@{version}"),
+        "helm",
+        "",
+    ));
+    state.repo_index.invalidate("helm");
+    Json(json!({ "deleted": true })).into_response()
+}
+
+// ============================================================================
+// Pull-through repositories
+// ============================================================================
+
+/// Where a rewritten `charts/{file}` of a proxied index really lives.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ChartRef {
+    url: String,
+    name: String,
+    version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created: Option<String>,
+}
+
+/// Resolve a chart URL from an upstream index against the repo URL, the way
+/// helm does: absolute URLs as-is, `/path` against the origin, anything else
+/// relative to the repo directory.
+fn resolve_chart_url(repo_url: &str, url: &str) -> String {
+    if url.starts_with("http://") || url.starts_with("https://") {
+        return url.to_string();
+    }
+    let base = repo_url.trim_end_matches('/');
+    match url.strip_prefix('/') {
+        Some(path) => {
+            let origin_end = base
+                .find("://")
+                .and_then(|i| base[i + 3..].find('/').map(|j| i + 3 + j))
+                .unwrap_or(base.len());
+            format!("{}/{}", &base[..origin_end], path)
+        }
+        None => format!("{}/{}", base, url.trim_start_matches("./")),
+    }
+}
+
+/// Point one chart version of an upstream index at `charts/{file}` and record
+/// the original URL. Versions whose archive has no usable file name are dropped
+/// rather than left pointing at the upstream.
+fn rewrite_chart_version(
+    version: &mut Value,
+    repo_url: &str,
+    refs: &mut BTreeMap<String, ChartRef>,
+) -> bool {
+    let (Some(name), Some(ver), Some(url)) = (
+        version["name"].as_str(),
+        version["version"].as_str(),
+        version["urls"][0].as_str(),
+    ) else {
+        return false;
+    };
+    let url = resolve_chart_url(repo_url, url);
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let file = path.rsplit('/').next().unwrap_or_default().to_string();
+    if !is_chart_file(&file) || !file.ends_with(".tgz") {
+        return false;
+    }
+    let chart = ChartRef {
+        url,
+        name: name.to_string(),
+        version: ver.to_string(),
+        created: version["created"].as_str().map(str::to_string),
+    };
+    refs.entry(file.clone()).or_insert(chart);
+    version["urls"] = json!([format!("charts/{file}")]);
+    true
+}
+
+/// Rewrite an upstream `index.yaml`, returning it with the file → origin map.
+fn rewrite_proxied_index(
+    data: &[u8],
+    repo_url: &str,
+) -> Result<(String, BTreeMap<String, ChartRef>), String> {
+    let mut index: Value = serde_yaml_ng::from_slice(data)
+        .map_err(|e| format!("upstream index.yaml is not valid YAML: {e}"))?;
+    let mut refs = BTreeMap::new();
+    let entries = index
+        .get_mut("entries")
+        .and_then(Value::as_object_mut)
+        .ok_or("upstream index.yaml has no `entries`")?;
+    for versions in entries.values_mut() {
+        if let Some(list) = versions.as_array_mut() {
+            list.retain_mut(|v| v.is_object() && rewrite_chart_version(v, repo_url, &mut refs));
+        }
+    }
+    // ChartMuseum's `serverInfo.contextPath` would steer helm back upstream.
+    if let Some(obj) = index.as_object_mut() {
+        obj.remove("serverInfo");
+    }
+    let yaml = serde_yaml_ng::to_string(&index).map_err(|e| format!("render index.yaml: {e}"))?;
+    Ok((yaml, refs))
+}
+
+/// Index of a proxied repo: the cache while fresh, else the upstream (rewritten
+/// and cached with its chart map), else the stale cache when `serve_stale`.
+/// The flag is `true` when the served copy is stale.
+async fn proxied_index(
+    state: &AppState,
+    repo: &str,
+    entry: &RepoProxyEntry,
+) -> Result<(Bytes, bool), Response> {
+    let key = proxy_index_key(repo);
+    let cached = state.storage.get(&key).await.ok();
+    if let Some(ref data) = cached {
+        if let Some(meta) = state.storage.stat(&key).await {
+            if is_within_ttl(meta.modified, state.config.helm.metadata_ttl) {
+                return Ok((data.clone(), false));
+            }
+        }
+    }
+
+    let url = format!("{}/index.yaml", entry.url().trim_end_matches('/'));
+    let fetched = proxy_fetch(
+        &state.http_client,
+        &url,
+        Duration::from_secs(state.config.helm.proxy_timeout),
+        entry.auth(),
+        &state.circuit_breaker,
+        RegistryType::Helm,
+    )
+    .await;
+    let error = match fetched {
+        Ok(data) => match rewrite_proxied_index(&data, entry.url()) {
+            Ok((index, refs)) => {
+                let refs = serde_json::to_vec(&refs).unwrap_or_default();
+                // Map first: a chart download resolving against the new index
+                // must find every file it lists.
+                if let Err(e) = state.storage.put(&proxy_refs_key(repo), &refs).await {
+                    tracing::warn!(repo, error = %e, "helm: failed to cache chart map");
+                } else if let Err(e) = state.storage.put(&key, index.as_bytes()).await {
+                    tracing::warn!(repo, error = %e, "helm: failed to cache index.yaml");
+                }
+                return Ok((Bytes::from(index), false));
+            }
+            Err(e) => ProxyError::Network(e),
+        },
+        Err(ProxyError::NotFound) => return Err(StatusCode::NOT_FOUND.into_response()),
+        Err(ProxyError::CircuitOpen(reg)) => return Err(circuit_open_response(&reg)),
+        Err(e) => e,
+    };
+    if let Some(data) = cached {
+        if state.config.helm.serve_stale {
+            tracing::warn!(
+                registry = "helm",
+                repo,
+                error = ?error,
+                "Helm upstream error, serving stale index.yaml"
+            );
+            return Ok((data, true));
+        }
+    }
+    tracing::debug!(repo, error = ?error, "helm index upstream error");
+    Err(StatusCode::BAD_GATEWAY.into_response())
+}
+
+async fn proxy_index(State(state): State<AppState>, Path(repo): Path<String>) -> Response {
+    let Some(entry) = state.config.helm.proxies.get(&repo) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    match proxied_index(&state, &repo, entry).await {
+        Ok((data, stale)) => {
+            let mut response = yaml_response(data);
+            if stale {
+                response.headers_mut().insert(
+                    header::HeaderName::from_static("x-nora-stale"),
+                    HeaderValue::from_static("true"),
+                );
+            }
+            response
+        }
+        Err(response) => response,
+    }
+}
+
+async fn chart_ref(state: &AppState, repo: &str, file: &str) -> Option<ChartRef> {
+    let data = state.storage.get(&proxy_refs_key(repo)).await.ok()?;
+    let mut refs: BTreeMap<String, ChartRef> = serde_json::from_slice(&data).ok()?;
+    refs.remove(file)
+}
+
+async fn proxy_chart(
+    State(state): State<AppState>,
+    headers: axum::http::HeaderMap,
+    Path((repo, file)): Path<(String, String)>,
+) -> Response {
+    let Some(entry) = state.config.helm.proxies.get(&repo) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let key = proxy_chart_key(&repo, &file);
+    if !is_chart_file(&file) || validate_storage_key(&key).is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    // A `.prov` sits next to the archive it signs, upstream as here.
+    let archive = file.strip_suffix(".prov").unwrap_or(&file);
+    let mut chart = chart_ref(&state, &repo, archive).await;
+    if chart.is_none() {
+        // Never indexed, or listed by a newer upstream index: refresh it once.
+        if let Err(response) = proxied_index(&state, &repo, entry).await {
+            return response;
+        }
+        chart = chart_ref(&state, &repo, archive).await;
+    }
+    let Some(chart) = chart else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    // Curation check. #733 serve-local: an internal-namespace chart is operator-owned —
+    // serve a local copy if one exists, never fetch it upstream.
+    let engine = &state.curation().curation_engine;
+    if crate::curation::is_internal_namespace(engine, RegistryType::Helm, &chart.name) {
+        return match state.storage.get(&key).await {
+            Ok(data) => (
+                StatusCode::OK,
+                [(header::CONTENT_TYPE, content_type(&file))],
+                data,
+            )
+                .into_response(),
+            Err(_) => {
+                crate::curation::check_namespace_isolation(engine, RegistryType::Helm, &chart.name)
+                    .unwrap_or_else(|| StatusCode::NOT_FOUND.into_response())
+            }
+        };
+    }
+    let publish_date = if state.config.server.trust_upstream_dates {
+        chart
+            .created
+            .as_deref()
+            .and_then(crate::curation::parse_iso8601_to_unix)
+    } else {
+        None
+    };
+    if let Some(response) = crate::curation::check_download(
+        engine,
+        state.bypass_token().as_deref(),
+        &headers,
+        RegistryType::Helm,
+        &chart.name,
+        Some(&chart.version),
+        publish_date,
+    ) {
+        return response;
+    }
+
+    let url = if file.ends_with(".prov") {
+        format!("{}.prov", chart.url)
+    } else {
+        chart.url
+    };
+    crate::registry::repo_proxy_download(
+        &state,
+        "helm",
+        RegistryType::Helm,
+        format!("{repo}/{file}"),
+        key,
+        url,
+        entry.auth(),
+        state.config.helm.proxy_timeout,
+        state.config.helm.metadata_ttl,
+        true,
+        content_type(&file),
+    )
+    .await
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{
+        body_bytes, create_test_context, create_test_context_with_config, send, send_with_headers,
+        TestContext,
+    };
+    use axum::http::Method;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const BOUNDARY: &str = "nora-helm-boundary";
+
+    /// A chart archive as `helm package` builds it.
+    fn build_chart(name: &str, version: &str) -> Vec<u8> {
+        let chart_yaml = format!(
+            "apiVersion: v2\nname: {name}\nversion: {version}\nappVersion: 1.10\ndescription: Internal service.\ntype: application\n"
+        );
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::default(),
+        ));
+        for (path, data) in [
+            (format!("{name}/Chart.yaml"), chart_yaml.as_bytes()),
+            (
+                format!("{name}/templates/service.yaml"),
+                b"kind: Service\n".as_slice(),
+            ),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, data).unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    fn prov_for(file: &str) -> String {
+        format!(
+            "-----BEGIN PGP SIGNED MESSAGE-----\nHash: SHA512\n\napiVersion: v2\nname: acme\n\n...\nfiles:\n  {file}: sha256:00ff\n-----BEGIN PGP SIGNATURE-----\n\nwsBcBAEBCgAQ\n-----END PGP SIGNATURE-----\n"
+        )
+    }
+
+    fn multipart_body(parts: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut body = Vec::new();
+        for (field, data) in parts {
+            body.extend_from_slice(
+                format!(
+                    "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{field}\"; filename=\"{field}.bin\"\r\nContent-Type: application/octet-stream\r\n\r\n"
+                )
+                .as_bytes(),
+            );
+            body.extend_from_slice(data);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{BOUNDARY}--\r\n").as_bytes());
+        body
+    }
+
+    async fn get_index(ctx: &TestContext, uri: &str) -> Value {
+        let resp = send(&ctx.app, Method::GET, uri, "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        serde_yaml_ng::from_slice(&body_bytes(resp).await).unwrap()
+    }
+
+    #[test]
+    fn test_split_chart_filename() {
+        assert_eq!(
+            split_chart_filename("nginx-1.2.3.tgz"),
+            Some(("nginx", "1.2.3"))
+        );
+        assert_eq!(
+            split_chart_filename("k8s-1-agent-0.1.0-rc.1.tgz"),
+            Some(("k8s-1-agent", "0.1.0-rc.1"))
+        );
+        assert_eq!(
+            split_chart_filename("tool-v2.0.0.tgz"),
+            Some(("tool", "v2.0.0"))
+        );
+        assert_eq!(split_chart_filename("nginx-1.2.tgz"), None);
+        assert_eq!(split_chart_filename("nginx-1.2.3.tgz.prov"), None);
+    }
+
+    #[test]
+    fn test_read_chart_yaml() {
+        let chart = read_chart_yaml(&build_chart("acme", "0.1.0")).unwrap();
+        assert_eq!(chart["name"], "acme");
+        assert_eq!(chart["version"], "0.1.0");
+        assert_eq!(
+            chart["appVersion"], "1.1",
+            "numeric appVersion is stringified"
+        );
+
+        assert!(read_chart_yaml(b"not an archive").is_err());
+        assert!(parse_chart_yaml(b"apiVersion: v2\nname: acme\n")
+            .unwrap_err()
+            .contains("version"));
+        assert!(
+            parse_chart_yaml(b"apiVersion: v9\nname: acme\nversion: 1.0.0\n")
+                .unwrap_err()
+                .contains("apiVersion")
+        );
+    }
+
+    #[test]
+    fn test_prov_chart_file() {
+        assert_eq!(
+            prov_chart_file(prov_for("acme-0.1.0.tgz").as_bytes()).as_deref(),
+            Some("acme-0.1.0.tgz")
+        );
+        assert_eq!(prov_chart_file(b"no files block"), None);
+    }
+
+    #[test]
+    fn test_rewrite_proxied_index() {
+        let upstream = r#"
+apiVersion: v1
+entries:
+  nginx:
+  - name: nginx
+    version: 1.0.0
+    created: "2026-01-02T03:04:05Z"
+    urls: ["https://github.com/acme/charts/releases/download/nginx-1.0.0/nginx-1.0.0.tgz"]
+  - name: nginx
+    version: 0.9.0
+    urls: ["charts/nginx-0.9.0.tgz"]
+  broken:
+  - name: broken
+    version: 1.0.0
+    urls: []
+serverInfo:
+  contextPath: /upstream
+"#;
+        let (index, refs) =
+            rewrite_proxied_index(upstream.as_bytes(), "https://charts.acme.io/stable/").unwrap();
+        let index: Value = serde_yaml_ng::from_str(&index).unwrap();
+        assert_eq!(
+            index["entries"]["nginx"][0]["urls"][0],
+            "charts/nginx-1.0.0.tgz"
+        );
+        assert_eq!(
+            index["entries"]["nginx"][1]["urls"][0],
+            "charts/nginx-0.9.0.tgz"
+        );
+        assert_eq!(index["entries"]["broken"], json!([]));
+        assert!(index.get("serverInfo").is_none());
+
+        assert_eq!(
+            refs["nginx-1.0.0.tgz"].url,
+            "https://github.com/acme/charts/releases/download/nginx-1.0.0/nginx-1.0.0.tgz"
+        );
+        assert_eq!(
+            refs["nginx-1.0.0.tgz"].created.as_deref(),
+            Some("2026-01-02T03:04:05Z")
+        );
+        assert_eq!(
+            refs["nginx-0.9.0.tgz"].url,
+            "https://charts.acme.io/stable/charts/nginx-0.9.0.tgz"
+        );
+    }
+
+    #[test]
+    fn test_resolve_chart_url() {
+        assert_eq!(
+            resolve_chart_url("https://h/repo", "/dl/a-1.0.0.tgz"),
+            "https://h/dl/a-1.0.0.tgz"
+        );
+        assert_eq!(
+            resolve_chart_url("https://h/repo", "./a-1.0.0.tgz"),
+            "https://h/repo/a-1.0.0.tgz"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_helm_empty_repo_serves_empty_index() {
+        let ctx = create_test_context();
+        let index = get_index(&ctx, "/helm/index.yaml").await;
+        assert_eq!(index["apiVersion"], "v1");
+        assert_eq!(index["entries"], json!({}));
+    }
+
+    #[tokio::test]
+    async fn test_helm_upload_index_download_delete() {
+        let ctx = create_test_context();
+        let chart = build_chart("acme", "0.1.0");
+
+        let resp = send(&ctx.app, Method::POST, "/helm/api/charts", chart.clone()).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let body: Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(body["saved"], true);
+
+        let index = get_index(&ctx, "/helm/index.yaml").await;
+        let entry = &index["entries"]["acme"][0];
+        assert_eq!(entry["version"], "0.1.0");
+        assert_eq!(entry["urls"][0], "charts/acme-0.1.0.tgz");
+        assert_eq!(
+            entry["digest"],
+            hex::encode(sha2::Sha256::digest(&chart)).as_str()
+        );
+
+        let resp = send(&ctx.app, Method::GET, "/helm/charts/acme-0.1.0.tgz", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], &chart[..]);
+
+        // Versions are immutable; `?force` is not honored.
+        for uri in ["/helm/api/charts", "/helm/api/charts?force"] {
+            let resp = send(&ctx.app, Method::POST, uri, build_chart("acme", "0.1.0")).await;
+            assert_eq!(resp.status(), StatusCode::CONFLICT, "{uri}");
+        }
+        let resp = send(&ctx.app, Method::GET, "/helm/charts/acme-0.1.0.tgz", "").await;
+        assert_eq!(&body_bytes(resp).await[..], &chart[..]);
+
+        let resp = send(&ctx.app, Method::GET, "/helm/api/charts/acme", "").await;
+        let versions: Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(versions.as_array().unwrap().len(), 1);
+
+        let resp = send(&ctx.app, Method::DELETE, "/helm/api/charts/acme/0.1.0", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let index = get_index(&ctx, "/helm/index.yaml").await;
+        assert_eq!(index["entries"], json!({}));
+        let resp = send(&ctx.app, Method::GET, "/helm/charts/acme-0.1.0.tgz", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = send(&ctx.app, Method::DELETE, "/helm/api/charts/acme/0.1.0", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_helm_multipart_upload_with_prov() {
+        let ctx = create_test_context();
+        let prov = prov_for("acme-0.2.0.tgz");
+        let content_type = format!("multipart/form-data; boundary={BOUNDARY}");
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::POST,
+            "/helm/api/charts",
+            vec![("content-type", content_type.as_str())],
+            multipart_body(&[
+                ("chart", &build_chart("acme", "0.2.0")),
+                ("prov", prov.as_bytes()),
+            ]),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/helm/charts/acme-0.2.0.tgz.prov",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers()["content-type"], PROV_CONTENT_TYPE);
+        assert_eq!(&body_bytes(resp).await[..], prov.as_bytes());
+
+        // A provenance file alone lands next to the chart it names.
+        let prov = prov_for("acme-0.3.0.tgz");
+        let resp = send(&ctx.app, Method::POST, "/helm/api/prov", prov.clone()).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let resp = send(
+            &ctx.app,
+            Method::HEAD,
+            "/helm/charts/acme-0.3.0.tgz.prov",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_helm_upload_rejects_invalid_chart() {
+        let ctx = create_test_context();
+        let resp = send(&ctx.app, Method::POST, "/helm/api/charts", "not a chart").await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let resp = send(
+            &ctx.app,
+            Method::POST,
+            "/helm/api/charts",
+            build_chart("acme", "1.0"),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_helm_proxy_rewrites_index_and_caches_chart() {
+        let upstream = MockServer::start().await;
+        let chart = build_chart("nginx", "1.0.0");
+        let index = format!(
+            "apiVersion: v1\nentries:\n  nginx:\n  - name: nginx\n    version: 1.0.0\n    urls:\n    - {}/releases/nginx-1.0.0.tgz\n",
+            upstream.uri()
+        );
+        Mock::given(method("GET"))
+            .and(path("/stable/index.yaml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(index))
+            .mount(&upstream)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/releases/nginx-1.0.0.tgz"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(chart.clone()))
+            .expect(1)
+            .mount(&upstream)
+            .await;
+
+        let url = format!("{}/stable", upstream.uri());
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.helm
+                .proxies
+                .insert("acme".to_string(), RepoProxyEntry::Simple(url));
+        });
+
+        let index = get_index(&ctx, "/helm/acme/index.yaml").await;
+        assert_eq!(
+            index["entries"]["nginx"][0]["urls"][0],
+            "charts/nginx-1.0.0.tgz"
+        );
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/helm/acme/charts/nginx-1.0.0.tgz",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], &chart[..]);
+
+        // Second pull is served from the cache (the upstream mock expects one hit).
+        for _ in 0..50 {
+            if ctx
+                .state
+                .storage
+                .stat("helm/acme/charts/nginx-1.0.0.tgz")
+                .await
+                .is_some()
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/helm/acme/charts/nginx-1.0.0.tgz",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/helm/acme/charts/unknown-1.0.0.tgz",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = send(&ctx.app, Method::GET, "/helm/other/index.yaml", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -364,7 +364,17 @@ async fn download(
     // Curation check — only for versioned artifact files, not metadata
     if let Some((ref maven_name, ref maven_version)) = curation_coords {
         if !internal {
-            if let Some(response) = crate::curation::check_download(
+            let license = match classify_path(&path) {
+                MavenPathKind::VersionFile(coords)
+                    if crate::curation::license_policy_active(
+                        &state.curation().curation_engine,
+                    ) =>
+                {
+                    maven_declared_license(&state, &coords).await
+                }
+                _ => None,
+            };
+            if let Some(response) = crate::curation::check_download_with_license(
                 &state.curation().curation_engine,
                 state.bypass_token().as_deref(),
                 &headers,
@@ -372,6 +382,7 @@ async fn download(
                 maven_name,
                 Some(maven_version),
                 publish_date,
+                license.as_deref(),
             ) {
                 return response;
             }
@@ -914,6 +925,79 @@ async fn compute_and_store_checksums(storage: &crate::storage::Storage, key: &st
 // Metadata generation
 // ============================================================================
 
+/// Declared license of a release, for the license filter: the `<licenses>` of
+/// its POM, fetched from the proxies when missing. The fetched POM is not cached:
+/// only the download path stores a POM, after its own curation and integrity
+/// checks. A license inherited from a parent POM is not followed, so such an
+/// artifact declares none.
+async fn maven_declared_license(state: &AppState, coords: &MavenCoordinates) -> Option<String> {
+    let pom_path = format!(
+        "{}/{}/{}/{}-{}.pom",
+        coords.group_path, coords.artifact_id, coords.version, coords.artifact_id, coords.version
+    );
+    let key = storage_key(&pom_path);
+    let pom = match state.storage.get(&key).await {
+        Ok(data) => data.to_vec(),
+        Err(_) => {
+            let mut fetched = None;
+            for proxy in &state.config.maven.proxies {
+                let url = format!("{}/{}", proxy.url().trim_end_matches('/'), pom_path);
+                if let Ok(data) = proxy_fetch(
+                    &state.http_client,
+                    &url,
+                    Duration::from_secs(state.config.maven.proxy_timeout),
+                    proxy.auth(),
+                    &state.circuit_breaker,
+                    RegistryType::Maven,
+                )
+                .await
+                {
+                    fetched = Some(data);
+                    break;
+                }
+            }
+            fetched?
+        }
+    };
+    let names = parse_pom_licenses(&pom);
+    crate::license::parse_any(names.iter().map(String::as_str)).map(|e| e.to_string())
+}
+
+/// The `<name>` of each `<project><licenses><license>` in a POM.
+fn parse_pom_licenses(data: &[u8]) -> Vec<String> {
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().trim_text(true);
+    let mut path: Vec<Vec<u8>> = Vec::new();
+    let mut names = Vec::new();
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(element)) => {
+                let name = element.local_name().as_ref().to_vec();
+                if name == b"name" && path == [&b"project"[..], b"licenses", b"license"] {
+                    let Some(text) = reader
+                        .read_text(element.name())
+                        .ok()
+                        .and_then(|t| t.decode().ok().map(|t| t.into_owned()))
+                    else {
+                        break;
+                    };
+                    if let Ok(license) = quick_xml::escape::unescape(&text) {
+                        names.push(license.trim().to_string());
+                    }
+                    continue;
+                }
+                path.push(name);
+            }
+            Ok(Event::End(_)) => {
+                path.pop();
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+    names
+}
+
 #[derive(Default)]
 struct ArtifactMetadata {
     latest: Option<String>,
@@ -1655,6 +1739,27 @@ mod tests {
         assert_eq!(classify_metadata_level(b"<metadata>"), None);
         assert_eq!(classify_metadata_level(b"<project/>"), None);
     }
+
+    #[test]
+    fn test_parse_pom_licenses() {
+        let pom = br#"<?xml version="1.0"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <name>lib</name>
+  <licenses>
+    <license><name>Eclipse Public License - v 2.0</name></license>
+    <license><name>GNU General Public License, version 2, with the Classpath Exception</name></license>
+  </licenses>
+  <developers><developer><name>Jane</name></developer></developers>
+</project>"#;
+        assert_eq!(
+            parse_pom_licenses(pom),
+            vec![
+                "Eclipse Public License - v 2.0",
+                "GNU General Public License, version 2, with the Classpath Exception"
+            ]
+        );
+        assert!(parse_pom_licenses(b"<project><name>x</name></project>").is_empty());
+    }
 }
 
 // ============================================================================
@@ -2504,4 +2609,83 @@ mod integration_tests {
         assert!(resp.headers().get(header::ACCEPT_RANGES).is_none());
         assert_eq!(body_bytes(resp).await.as_ref(), &xml[..]);
     }
+
+    /// The license filter reads `<licenses>` from the release's POM — fetched from
+    /// the proxy when only the jar was requested, without caching it.
+    #[tokio::test]
+    async fn test_maven_jar_blocked_by_license_policy() {
+        use crate::config::MavenProxyEntry;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let upstream = MockServer::start().await;
+        let pom = |license: &str| {
+            format!(
+                "<project><dependencies><dependency><name>not a license</name></dependency></dependencies>\
+                 <licenses><license><name>{license}</name></license></licenses></project>"
+            )
+        };
+        Mock::given(method("GET"))
+            .and(path("/org/example/copyleft/1.0/copyleft-1.0.pom"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(pom("GNU General Public License v3 (GPLv3)")),
+            )
+            .expect(1)
+            .mount(&upstream)
+            .await;
+        let upstream_url = upstream.uri();
+        let ctx = create_test_context_with_config(move |config| {
+            config.maven.proxies = vec![MavenProxyEntry::Simple(upstream_url)];
+            config.curation.mode = crate::config::CurationMode::Enforce;
+            config.curation.licenses.enabled = true;
+            config.curation.licenses.deny = vec!["GPL-*".to_string()];
+        });
+        ctx.state
+            .storage
+            .put(
+                "maven/org/example/permissive/1.0/permissive-1.0.pom",
+                pom("The Apache Software License, Version 2.0").as_bytes(),
+            )
+            .await
+            .unwrap();
+        ctx.state
+            .storage
+            .put(
+                "maven/org/example/permissive/1.0/permissive-1.0.jar",
+                b"jar",
+            )
+            .await
+            .unwrap();
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/maven2/org/example/copyleft/1.0/copyleft-1.0.jar",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(body["context"]["package"], "org.example:copyleft");
+        assert_eq!(
+            body["context"]["reason"],
+            "org.example:copyleft@1.0 is licensed GPL-3.0-only; GPL-3.0-only not allowed by the license policy"
+        );
+        assert!(ctx
+            .state
+            .storage
+            .stat("maven/org/example/copyleft/1.0/copyleft-1.0.pom")
+            .await
+            .is_none());
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/maven2/org/example/permissive/1.0/permissive-1.0.jar",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
 }
@@ -2,21 +2,28 @@
 // SPDX-License-Identifier: MIT
 
 pub(crate) mod ansible;
+pub(crate) mod apk;
 mod cargo_registry;
+pub(crate) mod composer;
 mod conan;
+pub(crate) mod conda;
 pub(crate) mod deb;
 pub mod docker;
 pub mod docker_auth;
+pub(crate) mod docker_cosign;
 pub(crate) mod gems;
-mod go;
+pub(crate) mod go;
+mod go_sumdb;
+pub(crate) mod helm;
 mod maven;
 mod npm;
 pub(crate) mod nuget;
 pub(crate) mod pub_dart;
-mod pypi;
+pub(crate) mod pypi;
 pub(crate) mod range;
 mod raw;
 pub(crate) mod rpm;
+pub(crate) mod swift;
 pub(crate) mod terraform;
 
 // Cross-registry regression suite for namespace isolation on metadata paths (contrib-kit#68).
@@ -24,13 +31,17 @@ pub(crate) mod terraform;
 mod ns_isolation_metadata_tests;
 
 pub use ansible::routes as ansible_routes;
+pub use apk::routes as apk_routes;
 pub use cargo_registry::routes as cargo_routes;
+pub use composer::routes as composer_routes;
 pub use conan::routes as conan_routes;
+pub use conda::routes as conda_routes;
 pub use deb::routes as deb_routes;
 pub use docker::routes as docker_routes;
 pub use docker_auth::DockerAuth;
 pub use gems::routes as gems_routes;
 pub use go::routes as go_routes;
+pub use helm::routes as helm_routes;
 pub use maven::routes as maven_routes;
 pub use npm::routes as npm_routes;
 
@@ -45,6 +56,7 @@ pub use pypi::routes as pypi_routes;
 pub use raw::routes as raw_routes;
 pub(crate) use raw::storage_key as raw_storage_key;
 pub use rpm::routes as rpm_routes;
+pub use swift::routes as swift_routes;
 pub use terraform::routes as terraform_routes;
 
 use crate::circuit_breaker::CircuitBreakerRegistry;
@@ -328,9 +340,10 @@ pub(crate) async fn proxy_fetch_text(
     .await
 }
 
-/// One pull-through download for a per-repo proxied rpm/deb repository.
+/// One pull-through download for a per-repo proxied rpm/deb/helm repository.
 ///
-/// Shared by `rpm::download` and `deb::download` — the flow is the Maven proxy
+/// Shared by `rpm::download`, `deb::download` and `helm::proxy_chart` (which
+/// runs its own name-based curation first) — the flow is the Maven proxy
 /// flow minus name-based curation (a proxied repo is declared in config and
 /// disjoint from hosted repos, so dependency-confusion gating does not apply;
 /// the digest quarantine still gates immutable packages):
@@ -354,6 +367,10 @@ pub(crate) async fn repo_proxy_download(
 
     let q_override = match registry {
         "rpm" => &state.config.curation.rpm,
+        "helm" => &state.config.curation.helm,
+        "apk" => &state.config.curation.apk,
+        "conda" => &state.config.curation.conda,
+        "composer" => &state.config.curation.composer,
         _ => &state.config.curation.deb,
     };
     let (q_mode, q_secs) = crate::digest_quarantine::resolve_global(
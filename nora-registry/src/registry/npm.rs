@@ -10,29 +10,47 @@ use crate::registry::{
     proxy_forward_post, read_validators, write_validators, ProxyError, Revalidation, Validators,
 };
 use crate::registry_type::RegistryType;
+use crate::repo_index::SearchDoc;
 use crate::secrets::expose_opt;
 use crate::AppState;
 use axum::{
     body::Bytes,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::{header, HeaderMap, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
+    routing::{get, put},
     Extension, Router,
 };
 use base64::Engine;
 use sha2::Digest;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 pub fn routes() -> Router<AppState> {
-    Router::new().route(
-        "/npm/{*path}",
-        get(handle_request)
-            .put(handle_publish)
-            .post(handle_npm_post)
-            .fallback(|| async { method_not_allowed("GET, PUT, POST") }),
-    )
+    Router::new()
+        .route(
+            "/npm/-/v1/search",
+            get(handle_search).fallback(|| async { method_not_allowed("GET") }),
+        )
+        .route(
+            "/npm/-/package/{package}/dist-tags",
+            get(dist_tags_list).fallback(|| async { method_not_allowed("GET") }),
+        )
+        .route(
+            "/npm/-/package/{package}/dist-tags/{tag}",
+            put(dist_tag_put)
+                .delete(dist_tag_delete)
+                .fallback(|| async { method_not_allowed("PUT, DELETE") }),
+        )
+        .route(
+            "/npm/{*path}",
+            get(handle_request)
+                .put(handle_publish)
+                .post(handle_npm_post)
+                .delete(handle_unpublish)
+                .fallback(|| async { method_not_allowed("GET, PUT, POST, DELETE") }),
+        )
 }
 
 /// Max body accepted on the `npm audit` POST. Real audit payloads are well under
@@ -40,14 +58,17 @@ pub fn routes() -> Router<AppState> {
 /// client shove a huge body through NORA into the upstream (#597 SEC).
 const NPM_AUDIT_BODY_CAP: usize = 8 * 1024 * 1024;
 
-/// `npm audit` proxy (#597). npm sends its audit request as a POST — npm7 to
+/// `npm audit` (#597). npm sends its audit request as a POST — npm7 to
 /// `/-/npm/v1/security/advisories/bulk` (body `{"<pkg>":["<ver>",…]}`), npm6 to
 /// `/-/npm/v1/security/audits/quick` (body = a possibly-gzipped lockfile).
 ///
-/// NORA keeps no advisory DB, so for a remote/proxy repo it forwards the request to
-/// the configured upstream and returns the response verbatim. Any other POST path
-/// is a 405. Dependency-confusion guard (#68/#733): internal-namespace package
-/// names must not leak upstream — see `strip_internal_bulk` / the quick refuse.
+/// With `[curation.vulnerabilities]` enabled and an imported advisory database
+/// (`nora vulndb import`) that covers npm, both are answered locally for every
+/// package, hosted and internal ones included, and nothing is forwarded. Otherwise
+/// a remote/proxy repo forwards the request to the
+/// configured upstream and returns the response verbatim. Any other POST path is a
+/// 405. Dependency-confusion guard (#68/#733): on the forward path internal-namespace
+/// package names must not leak upstream — see `strip_internal_bulk` / the quick refuse.
 async fn handle_npm_post(
     State(state): State<AppState>,
     Path(path): Path<String>,
@@ -69,7 +90,15 @@ async fn handle_npm_post(
         Err(_) => return StatusCode::PAYLOAD_TOO_LARGE.into_response(),
     };
 
-    // Only a remote/proxy repo can answer audits (advisories come from upstream).
+    let encoding = headers
+        .get(header::CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok());
+    // A database without npm advisories would report every package clean.
+    if let Some(db) = (state.curation().vulndb.clone()).filter(|db| db.covers(RegistryType::Npm)) {
+        return npm_local_audit(&db, is_quick, encoding, &body);
+    }
+
+    // Without a local database only a remote/proxy repo can answer audits.
     // Hosted-only (no proxy) → npm-compatible empty result so `npm audit` doesn't
     // hard-fail — npm treats a 200 `{}` as "no advisories".
     let Some(proxy_url) = state.config.npm.proxy.clone() else {
@@ -84,10 +113,7 @@ async fn handle_npm_post(
     // verbatim.
     let engine = &state.curation().curation_engine;
     let filter_active = crate::curation::namespace_filter_active(engine);
-    let content_encoded = headers
-        .get(header::CONTENT_ENCODING)
-        .and_then(|v| v.to_str().ok())
-        .is_some_and(|s| !s.eq_ignore_ascii_case("identity"));
+    let content_encoded = encoding.is_some_and(|s| !s.eq_ignore_ascii_case("identity"));
     let forward_body: Vec<u8> = if !filter_active {
         body.to_vec()
     } else if is_quick || content_encoded {
@@ -168,6 +194,243 @@ fn npm_empty_audit() -> Response {
         .into_response()
 }
 
+/// Answer an audit request from the imported advisory database: a bulk request
+/// gets the bulk-advisories shape, a quick one the npm6 audit report.
+fn npm_local_audit(
+    db: &crate::vulndb::VulnDb,
+    is_quick: bool,
+    encoding: Option<&str>,
+    body: &[u8],
+) -> Response {
+    let Some(json) = decode_audit_body(encoding, body)
+        .and_then(|b| serde_json::from_slice::<serde_json::Value>(&b).ok())
+    else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+    let report = if is_quick {
+        npm_quick_report(db, &json)
+    } else {
+        npm_bulk_report(db, &json)
+    };
+    (
+        StatusCode::OK,
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        )],
+        report.to_string(),
+    )
+        .into_response()
+}
+
+/// The audit body with its `Content-Encoding` undone, still bounded by
+/// `NPM_AUDIT_BODY_CAP` once inflated. npm gzips both audit bodies; a gzip body
+/// without the header is recognised by its magic bytes.
+fn decode_audit_body(encoding: Option<&str>, body: &[u8]) -> Option<Vec<u8>> {
+    use std::io::Read;
+    let encoding = encoding.map(str::to_ascii_lowercase);
+    let gzip = match encoding.as_deref() {
+        Some("gzip") | Some("x-gzip") => true,
+        None | Some("identity") => body.starts_with(&[0x1f, 0x8b]),
+        _ => return None,
+    };
+    if !gzip {
+        return Some(body.to_vec());
+    }
+    let mut out = Vec::new();
+    flate2::read::GzDecoder::new(body)
+        .take(NPM_AUDIT_BODY_CAP as u64 + 1)
+        .read_to_end(&mut out)
+        .ok()?;
+    (out.len() <= NPM_AUDIT_BODY_CAP).then_some(out)
+}
+
+/// One advisory in npm's bulk-advisories shape. An unrated advisory carries no
+/// `severity`, which npm reports as `high`.
+fn npm_advisory_entry(
+    advisory: &crate::vulndb::Advisory,
+    affected: &crate::vulndb::Affected,
+) -> serde_json::Value {
+    let mut entry = serde_json::json!({
+        "id": advisory.id,
+        "url": format!("https://osv.dev/vulnerability/{}", advisory.id),
+        "title": if advisory.summary.is_empty() { &advisory.id } else { &advisory.summary },
+        "vulnerable_versions": affected.range_expr(),
+    });
+    if let Some(severity) = advisory.severity {
+        entry["severity"] = severity.ghsa_name().into();
+    }
+    entry
+}
+
+/// `advisories/bulk` answer: for each requested package, the advisories that
+/// affect at least one of its requested versions. Packages without any are omitted.
+fn npm_bulk_report(db: &crate::vulndb::VulnDb, request: &serde_json::Value) -> serde_json::Value {
+    let mut report = serde_json::Map::new();
+    for (name, versions) in request.as_object().into_iter().flatten() {
+        let versions: Vec<&str> = versions
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|v| v.as_str())
+            .collect();
+        let mut seen = std::collections::HashSet::new();
+        let entries: Vec<serde_json::Value> = db
+            .advisories_for(RegistryType::Npm, name)
+            .filter(|(_, affected)| versions.iter().any(|v| affected.affects(v)))
+            .filter(|(advisory, _)| seen.insert(advisory.id.as_str()))
+            .map(|(advisory, affected)| npm_advisory_entry(advisory, affected))
+            .collect();
+        if !entries.is_empty() {
+            report.insert(name.clone(), entries.into());
+        }
+    }
+    serde_json::Value::Object(report)
+}
+
+/// Installed packages of a lockfile: name → version → dependency paths (`a>b>c`).
+type LockfileDeps =
+    std::collections::BTreeMap<String, std::collections::BTreeMap<String, Vec<String>>>;
+
+/// The installed packages of a lockfile, plus the number of prod and dev entries.
+/// Reads the nested `dependencies` of a v1 lockfile (npm6's quick body) or the flat
+/// `packages` map of v2/v3.
+fn lockfile_deps(lock: &serde_json::Value) -> (LockfileDeps, usize, usize) {
+    fn walk(
+        deps: &serde_json::Map<String, serde_json::Value>,
+        prefix: &str,
+        out: &mut LockfileDeps,
+        counts: &mut (usize, usize),
+    ) {
+        for (name, dep) in deps {
+            let path = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{}>{}", prefix, name)
+            };
+            if let Some(version) = dep.get("version").and_then(|v| v.as_str()) {
+                record(out, counts, name, version, path.clone(), dep);
+            }
+            if let Some(nested) = dep.get("dependencies").and_then(|d| d.as_object()) {
+                walk(nested, &path, out, counts);
+            }
+        }
+    }
+    fn record(
+        out: &mut LockfileDeps,
+        counts: &mut (usize, usize),
+        name: &str,
+        version: &str,
+        path: String,
+        dep: &serde_json::Value,
+    ) {
+        if dep.get("dev").and_then(|d| d.as_bool()) == Some(true) {
+            counts.1 += 1;
+        } else {
+            counts.0 += 1;
+        }
+        out.entry(name.to_string())
+            .or_default()
+            .entry(version.to_string())
+            .or_default()
+            .push(path);
+    }
+
+    let mut out = LockfileDeps::new();
+    let mut counts = (0, 0);
+    if let Some(deps) = lock.get("dependencies").and_then(|d| d.as_object()) {
+        walk(deps, "", &mut out, &mut counts);
+    } else if let Some(packages) = lock.get("packages").and_then(|p| p.as_object()) {
+        for (location, dep) in packages {
+            // `""` is the root project; linked workspaces carry no version.
+            let segments: Vec<&str> = location
+                .split("node_modules/")
+                .skip(1)
+                .map(|s| s.trim_end_matches('/'))
+                .collect();
+            let (Some(name), Some(version)) =
+                (segments.last(), dep.get("version").and_then(|v| v.as_str()))
+            else {
+                continue;
+            };
+            let name = dep.get("name").and_then(|n| n.as_str()).unwrap_or(name);
+            record(
+                &mut out,
+                &mut counts,
+                name,
+                version,
+                segments.join(">"),
+                dep,
+            );
+        }
+    }
+    (out, counts.0, counts.1)
+}
+
+/// `audits/quick` answer: the npm6 audit report for a lockfile, with one finding
+/// per affected installed version. Vulnerability counts are per dependency path.
+fn npm_quick_report(db: &crate::vulndb::VulnDb, lock: &serde_json::Value) -> serde_json::Value {
+    let (deps, prod, dev) = lockfile_deps(lock);
+    let mut advisories = serde_json::Map::new();
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for (name, versions) in &deps {
+        for (advisory, affected) in db.advisories_for(RegistryType::Npm, name) {
+            let hits: Vec<(&String, &Vec<String>)> = versions
+                .iter()
+                .filter(|(version, _)| affected.affects(version))
+                .collect();
+            if hits.is_empty() {
+                continue;
+            }
+            let paths: usize = hits.iter().map(|(_, paths)| paths.len()).sum();
+            let findings: Vec<serde_json::Value> = hits
+                .iter()
+                .map(|(version, paths)| serde_json::json!({"version": version, "paths": paths}))
+                .collect();
+            let severity = advisory.severity.map_or("high", |s| s.ghsa_name());
+            *counts.entry(severity).or_default() += paths;
+
+            let mut entry = npm_advisory_entry(advisory, affected);
+            entry["module_name"] = name.as_str().into();
+            entry["overview"] = advisory.summary.as_str().into();
+            entry["cves"] = advisory
+                .aliases
+                .iter()
+                .filter(|a| a.starts_with("CVE-"))
+                .cloned()
+                .collect::<Vec<_>>()
+                .into();
+            entry["findings"] = findings.into();
+            // An advisory naming two installed packages is reported once for each.
+            let key = if advisories.contains_key(&advisory.id) {
+                format!("{}:{}", advisory.id, name)
+            } else {
+                advisory.id.clone()
+            };
+            advisories.insert(key, entry);
+        }
+    }
+    let count = |s: &str| counts.get(s).copied().unwrap_or(0);
+    serde_json::json!({
+        "actions": [],
+        "advisories": advisories,
+        "muted": [],
+        "metadata": {
+            "vulnerabilities": {
+                "info": 0,
+                "low": count("low"),
+                "moderate": count("moderate"),
+                "high": count("high"),
+                "critical": count("critical"),
+            },
+            "dependencies": prod,
+            "devDependencies": dev,
+            "optionalDependencies": 0,
+            "totalDependencies": prod + dev,
+        },
+    })
+}
+
 /// Strip internal-namespace package keys from an npm7 bulk-advisories body
 /// (`{"<pkg>":[…]}`). `Some(bytes)` = the (possibly unchanged) body safe to forward;
 /// `None` = the body is NOT the expected JSON object, so the caller must fail closed
@@ -269,6 +532,374 @@ async fn handle_whoami(user: &AuthenticatedUser) -> Response {
     axum::Json(serde_json::json!({ "username": user.0 })).into_response()
 }
 
+/// The registry's own `size` default and cap for `/-/v1/search`.
+const SEARCH_DEFAULT_SIZE: usize = 20;
+const SEARCH_MAX_SIZE: usize = 250;
+
+/// `npm search` (`GET /-/v1/search?text=&size=&from=`).
+///
+/// Full-text match over every packument NORA holds — hosted publishes and cached
+/// proxy packuments alike — on name, description, keywords and maintainers,
+/// answered in the registry's `{objects, total, time}` shape. Local hits page
+/// first; with a proxy configured, the upstream's results fill the rest of the
+/// page, minus any package already matched locally. #68: a query naming an
+/// internal namespace is answered locally and never forwarded, as conan
+/// `search` does.
+async fn handle_search(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Response {
+    let text = params.get("text").map(|t| t.trim()).unwrap_or_default();
+    if text.len() > 256 {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let size = params
+        .get("size")
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(SEARCH_DEFAULT_SIZE)
+        .clamp(1, SEARCH_MAX_SIZE);
+    let from = params
+        .get("from")
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(0);
+
+    let query = SearchQuery::parse(text);
+    let local = local_search(&state, &query).await;
+    let mut total = local.len();
+    let local_names: std::collections::HashSet<String> = local
+        .iter()
+        .filter_map(|o| o["package"]["name"].as_str().map(str::to_string))
+        .collect();
+    let upstream_from = from.saturating_sub(local.len());
+    let mut objects: Vec<serde_json::Value> = local.into_iter().skip(from).take(size).collect();
+
+    let engine = &state.curation().curation_engine;
+    let internal = query.namespace_probes().iter().any(|name| {
+        crate::curation::is_internal_namespace(engine, crate::curation::RegistryType::Npm, name)
+    });
+    // An empty query lists what NORA holds; the upstream would reject it. A page
+    // the local hits already fill needs nothing from the upstream.
+    let wanted = size.saturating_sub(objects.len());
+    if let (Some(proxy_url), false, false, true) = (
+        &state.config.npm.proxy,
+        internal,
+        text.is_empty(),
+        wanted > 0,
+    ) {
+        let url = format!(
+            "{}/-/v1/search?text={}&size={}&from={}",
+            proxy_url.trim_end_matches('/'),
+            percent_encoding::utf8_percent_encode(text, percent_encoding::NON_ALPHANUMERIC),
+            wanted,
+            upstream_from
+        );
+        match proxy_fetch(
+            &state.http_client,
+            &url,
+            Duration::from_secs(state.config.npm.proxy_timeout),
+            expose_opt(&state.config.npm.proxy_auth),
+            &state.circuit_breaker,
+            RegistryType::Npm,
+        )
+        .await
+        .map(|data| serde_json::from_slice::<serde_json::Value>(&data))
+        {
+            Ok(Ok(upstream)) => {
+                state.activity.push(ActivityEntry::new(
+                    ActionType::ProxyFetch,
+                    format!("search:{}", text),
+                    RegistryType::Npm,
+                    "PROXY",
+                ));
+                let upstream_objects = upstream["objects"].as_array().cloned().unwrap_or_default();
+                let mut duplicates = 0;
+                for object in upstream_objects {
+                    let name = object["package"]["name"].as_str().unwrap_or_default();
+                    if local_names.contains(name) {
+                        duplicates += 1;
+                    } else if objects.len() < size {
+                        objects.push(object);
+                    }
+                }
+                total += upstream["total"]
+                    .as_u64()
+                    .map_or(0, |t| t as usize)
+                    .saturating_sub(duplicates);
+            }
+            // Upstream trouble degrades to the local results rather than failing
+            // `npm search` outright.
+            Ok(Err(e)) => tracing::debug!(error = %e, "npm search: unparsable upstream response"),
+            Err(e) => tracing::debug!(error = ?e, "npm search upstream error"),
+        }
+    }
+
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/json")],
+        serde_json::to_vec(&serde_json::json!({
+            "objects": objects,
+            "total": total,
+            "time": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        }))
+        .unwrap_or_default(),
+    )
+        .into_response()
+}
+
+/// `npm search` text split into plain terms and the qualifiers the registry
+/// understands. Every term and qualifier must match (AND); qualifiers NORA has
+/// no data for (`not:`, `is:`, `boost-exact:`) are ignored.
+#[derive(Debug, Default, PartialEq)]
+struct SearchQuery {
+    terms: Vec<String>,
+    keywords: Vec<String>,
+    authors: Vec<String>,
+    maintainers: Vec<String>,
+    scopes: Vec<String>,
+}
+
+impl SearchQuery {
+    fn parse(text: &str) -> Self {
+        let mut query = SearchQuery::default();
+        for word in text.split_whitespace().map(str::to_lowercase) {
+            match word.split_once(':') {
+                Some(("keywords", list)) => query.keywords.extend(
+                    list.split(',')
+                        .filter(|k| !k.is_empty())
+                        .map(str::to_string),
+                ),
+                Some(("author", name)) => query.authors.push(name.to_string()),
+                Some(("maintainer", name)) => query.maintainers.push(name.to_string()),
+                Some(("scope", scope)) => {
+                    query.scopes.push(scope.trim_start_matches('@').to_string())
+                }
+                Some(("not" | "is" | "boost-exact", _)) => {}
+                _ => query.terms.push(word),
+            }
+        }
+        query
+    }
+
+    /// Package-name shapes the query could reveal, for the internal-namespace
+    /// check: each term as-is, a bare `@scope` term or `scope:` qualifier as
+    /// `@scope/`.
+    fn namespace_probes(&self) -> Vec<String> {
+        let mut probes = Vec::new();
+        for term in &self.terms {
+            probes.push(term.clone());
+            if term.starts_with('@') && !term.contains('/') {
+                probes.push(format!("{}/", term));
+            }
+        }
+        probes.extend(self.scopes.iter().map(|s| format!("@{}/", s)));
+        probes
+    }
+
+    /// Relevance of `doc` in `0.0..=1.0`, or `None` when it does not match.
+    fn score(&self, doc: &SearchDoc) -> Option<f64> {
+        let name = doc.name.to_lowercase();
+        let lower = |v: &[String]| v.iter().map(|s| s.to_lowercase()).collect::<Vec<_>>();
+        let keywords = lower(&doc.keywords);
+        let maintainers: Vec<String> = doc
+            .maintainers
+            .iter()
+            .map(|(n, _)| n.to_lowercase())
+            .collect();
+        let description = doc.description.to_lowercase();
+
+        if !self
+            .scopes
+            .iter()
+            .all(|s| name.starts_with(&format!("@{}/", s)))
+            || !self.keywords.iter().all(|k| keywords.contains(k))
+            || !self.maintainers.iter().all(|m| maintainers.contains(m))
+        {
+            return None;
+        }
+        let author = doc.author.as_deref().map(str::to_lowercase);
+        let publisher = doc.publisher.as_ref().map(|(n, _)| n.to_lowercase());
+        if !self
+            .authors
+            .iter()
+            .all(|a| author.as_ref() == Some(a) || publisher.as_ref() == Some(a))
+        {
+            return None;
+        }
+        let matches = |term: &String| {
+            name.contains(term.as_str())
+                || description.contains(term.as_str())
+                || keywords.iter().any(|k| k.contains(term.as_str()))
+                || maintainers.iter().any(|m| m.contains(term.as_str()))
+        };
+        if !self.terms.iter().all(matches) {
+            return None;
+        }
+        Some(if self.terms.len() == 1 && self.terms[0] == name {
+            1.0
+        } else if !self.terms.is_empty() && self.terms.iter().all(|t| name.contains(t.as_str())) {
+            0.8
+        } else {
+            0.5
+        })
+    }
+}
+
+/// A stored `metadata.json` for the search index; packuments without a `time`
+/// entry (hosted publishes) date from `modified`, the file's mtime.
+fn stored_search_doc(data: &[u8], modified: u64) -> Option<SearchDoc> {
+    let mut doc = search_doc(&serde_json::from_slice(data).ok()?)?;
+    if doc.date.is_none() {
+        doc.date = chrono::DateTime::from_timestamp(modified as i64, 0)
+            .map(|d| d.to_rfc3339_opts(chrono::SecondsFormat::Millis, true));
+    }
+    Some(doc)
+}
+
+/// The searchable summary of a packument: top-level fields, falling back to the
+/// `latest` version document (a hosted packument carries description, keywords
+/// and maintainers only per version).
+fn search_doc(packument: &serde_json::Value) -> Option<SearchDoc> {
+    let name = packument["name"].as_str()?.to_string();
+    let versions = packument["versions"].as_object();
+    let version = packument["dist-tags"]["latest"]
+        .as_str()
+        .map(str::to_string)
+        .or_else(|| versions.and_then(|v| max_semver(v.keys())))?;
+    let latest = &packument["versions"][&version];
+    let field = |key: &str| match &packument[key] {
+        serde_json::Value::Null => &latest[key],
+        v => v,
+    };
+
+    let keywords = field("keywords")
+        .as_array()
+        .map(|a| {
+            a.iter()
+                .filter_map(|k| k.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    let maintainers = field("maintainers")
+        .as_array()
+        .map(|a| a.iter().filter_map(npm_person).collect())
+        .unwrap_or_default();
+    let date = packument["time"][&version]
+        .as_str()
+        .or_else(|| packument["time"]["modified"].as_str())
+        .map(str::to_string);
+
+    let mut links = serde_json::Map::new();
+    if let Some(homepage) = field("homepage").as_str() {
+        links.insert("homepage".to_string(), homepage.into());
+    }
+    let repository = field("repository");
+    if let Some(url) = repository["url"].as_str().or(repository.as_str()) {
+        links.insert("repository".to_string(), url.into());
+    }
+    let bugs = field("bugs");
+    if let Some(url) = bugs["url"].as_str().or(bugs.as_str()) {
+        links.insert("bugs".to_string(), url.into());
+    }
+
+    Some(SearchDoc {
+        description: field("description")
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        author: npm_person(field("author")).map(|(n, _)| n),
+        publisher: npm_person(&latest["_npmUser"]),
+        name,
+        version,
+        keywords,
+        maintainers,
+        date,
+        links,
+    })
+}
+
+/// One entry of the search response's `objects` array.
+fn search_object(doc: &SearchDoc, relevance: f64) -> serde_json::Value {
+    let person =
+        |(name, email): &(String, String)| serde_json::json!({ "username": name, "email": email });
+    let mut package = serde_json::json!({
+        "name": doc.name,
+        "version": doc.version,
+        "description": doc.description,
+        "keywords": doc.keywords,
+        "date": doc.date,
+        "links": doc.links,
+        "maintainers": doc.maintainers.iter().map(person).collect::<Vec<_>>(),
+    });
+    if let Some(scope) = doc
+        .name
+        .strip_prefix('@')
+        .and_then(|n| n.split_once('/'))
+        .map(|(scope, _)| scope)
+    {
+        package["scope"] = scope.into();
+    } else {
+        package["scope"] = "unscoped".into();
+    }
+    if let Some(publisher) = doc.publisher.as_ref().or_else(|| doc.maintainers.first()) {
+        package["publisher"] = person(publisher);
+    }
+    if let Some(author) = &doc.author {
+        package["author"] = serde_json::json!({ "name": author });
+    }
+    // NORA keeps no quality/popularity/maintenance signals; the detail
+    // scores stay zero and `final` carries text relevance alone.
+    serde_json::json!({
+        "package": package,
+        "score": {
+            "final": relevance,
+            "detail": { "quality": 0.0, "popularity": 0.0, "maintenance": 0.0 },
+        },
+        "searchScore": relevance,
+    })
+}
+
+/// An npm person field: `{"name", "email"}` or the `"Name <email> (url)"` string
+/// form. `_npmUser` and maintainers use the object form.
+fn npm_person(value: &serde_json::Value) -> Option<(String, String)> {
+    if let Some(s) = value.as_str() {
+        let name = s.split(['<', '(']).next().unwrap_or(s).trim();
+        let email = s
+            .split_once('<')
+            .and_then(|(_, rest)| rest.split_once('>'))
+            .map(|(email, _)| email.trim())
+            .unwrap_or_default();
+        return (!name.is_empty()).then(|| (name.to_string(), email.to_string()));
+    }
+    let name = value["name"].as_str()?.to_string();
+    let email = value["email"].as_str().unwrap_or_default().to_string();
+    Some((name, email))
+}
+
+/// Every packument in storage that matches `query`, best match first, from the
+/// search index in [`crate::repo_index::RepoIndex::npm_search`].
+async fn local_search(state: &AppState, query: &SearchQuery) -> Vec<serde_json::Value> {
+    let docs = state
+        .repo_index
+        .npm_search(&state.storage, stored_search_doc)
+        .await;
+    let mut hits: Vec<(f64, &SearchDoc)> = docs
+        .values()
+        .filter_map(|doc| query.score(doc).map(|relevance| (relevance, doc)))
+        .collect();
+    hits.sort_by(|(ra, a), (rb, b)| rb.total_cmp(ra).then_with(|| a.name.cmp(&b.name)));
+    hits.iter().map(|(r, doc)| search_object(doc, *r)).collect()
+}
+
+/// Add a packument the proxy just cached to the search index.
+async fn index_for_search(repo_index: &crate::repo_index::RepoIndex, packument: &[u8]) {
+    if let Some(doc) = serde_json::from_slice(packument)
+        .ok()
+        .and_then(|p| search_doc(&p))
+    {
+        repo_index.upsert_npm_search(doc).await;
+    }
+}
+
 // LOCK-SAFE: cache-through proxy — get miss → fetch upstream → put; no RMW race
 async fn handle_request(
     State(state): State<AppState>,
@@ -335,6 +966,19 @@ async fn handle_request(
         None
     };
 
+    // Declared license for the license filter, from the same packument (primed
+    // regardless of trust: the license is checked, not the date).
+    let license = match tarball_version {
+        Some(ref ver)
+            if crate::curation::license_policy_active(&state.curation().curation_engine) =>
+        {
+            ensure_npm_metadata_cached(&state, &package_name).await;
+            let meta_key = format!("npm/{}/metadata.json", package_name);
+            extract_npm_license(&state.storage, &meta_key, ver).await
+        }
+        _ => None,
+    };
+
     // Curation check — tarball downloads only (metadata passes through)
     if is_tarball {
         // #733: an internal-namespace package is operator-owned — skip curation; the cache-hit
@@ -345,7 +989,7 @@ async fn handle_request(
             crate::curation::RegistryType::Npm,
             &package_name,
         ) {
-            if let Some(response) = crate::curation::check_download(
+            if let Some(response) = crate::curation::check_download_with_license(
                 &state.curation().curation_engine,
                 state.bypass_token().as_deref(),
                 &headers,
@@ -353,6 +997,7 @@ async fn handle_request(
                 &package_name,
                 tarball_version.as_deref(),
                 publish_date,
+                license.as_deref(),
             ) {
                 return response;
             }
@@ -629,6 +1274,8 @@ async fn handle_request(
                         tracing::warn!(key = %key_clone, error = ?e, "npm proxy: failed to cache artifact");
                     } else if invalidate_npm {
                         repo_index.invalidate("npm");
+                    } else {
+                        index_for_search(&repo_index, &data_to_cache).await;
                     }
                 });
 
@@ -735,6 +1382,7 @@ async fn refetch_metadata(state: &AppState, path: &str, key: &str) -> Option<Vec
                 });
 
             let storage = state.storage.clone();
+            let repo_index = Arc::clone(&state.repo_index);
             let key_clone = key.to_string();
             let cache_data = rewritten.clone();
             tokio::spawn(async move {
@@ -745,6 +1393,7 @@ async fn refetch_metadata(state: &AppState, path: &str, key: &str) -> Option<Vec
                     return;
                 }
                 write_validators(&storage, &key_clone, &validators).await;
+                index_for_search(&repo_index, &cache_data).await;
             });
 
             Some(rewritten)
@@ -784,7 +1433,12 @@ async fn handle_publish(
     Extension(authority): Extension<NamespaceAuthority>,
     body: Bytes,
 ) -> Response {
-    let package_name = path;
+    // `npm unpublish {pkg}@{version}` PUTs the trimmed packument to the CouchDB
+    // revision URL `/{pkg}/-rev/{rev}`; see `rewrite_packument`.
+    let (package_name, revision_put) = match path.split_once("/-rev/") {
+        Some((name, _rev)) => (name.to_string(), true),
+        None => (path, false),
+    };
 
     // Enforce OIDC namespace_scope on the package coordinate (#583).
     if enforce_namespace_scope(&authority, &package_name).is_err() {
@@ -820,9 +1474,13 @@ async fn handle_publish(
         }
     }
 
+    // No tarballs: a packument rewrite (`npm deprecate`, version unpublish).
     let attachments = match payload.get("_attachments").and_then(|a| a.as_object()) {
-        Some(a) => a,
-        None => return (StatusCode::BAD_REQUEST, "Missing _attachments").into_response(),
+        Some(a) if !a.is_empty() && !revision_put => a,
+        Some(a) if !a.is_empty() => {
+            return (StatusCode::BAD_REQUEST, "Cannot publish to a revision URL").into_response()
+        }
+        _ => return rewrite_packument(&state, &package_name, &payload, revision_put).await,
     };
 
     let new_versions = match payload.get("versions").and_then(|v| v.as_object()) {
@@ -881,6 +1539,14 @@ async fn handle_publish(
             )
                 .into_response();
         }
+        let unpublished_key = format!("npm/{}/unpublished/{}", package_name, ver);
+        if state.storage.stat(&unpublished_key).await.is_some() {
+            return (
+                StatusCode::CONFLICT,
+                format!("Version {} was unpublished and cannot be reused", ver),
+            )
+                .into_response();
+        }
     }
 
     // Lazily migrate an old embedded-packument package to per-version keys BEFORE writing the new
@@ -1045,89 +1711,528 @@ async fn handle_publish(
 }
 
 // ============================================================================
-// Helpers
+// dist-tags, deprecate, unpublish
 // ============================================================================
 
-/// Regenerate the npm packument (`metadata.json`) by LISTING the immutable per-version keys, the
-/// dist-tag pointers and the package-level fields — never by read-modify-write of the shared file.
-/// Concurrent publishers each write their own `versions/{v}.json`; whoever regenerates last lists
-/// them all, so no version is permanently lost (the maven scan-regenerate guarantee, #39). Old
-/// embedded-packument packages are migrated to per-version keys by `migrate_embedded_packument`
-/// in the publish handler BEFORE the new version is written, so this stays a pure list-derive.
-async fn regenerate_packument(state: &AppState, package_name: &str) -> Result<(), ()> {
-    let versions_prefix = format!("npm/{}/versions/", package_name);
-    let version_keys = state
+/// Versions published to NORA for `package_name` — its per-version keys. Empty for
+/// a package NORA only caches from upstream: those are not NORA's to retag,
+/// deprecate or unpublish.
+async fn hosted_versions(state: &AppState, package_name: &str) -> Vec<String> {
+    let prefix = format!("npm/{}/versions/", package_name);
+    state
         .storage
-        .list(&versions_prefix)
+        .list(&prefix)
         .await
-        .unwrap_or_default();
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|key| key.strip_prefix(&prefix)?.strip_suffix(".json"))
+        .filter(|ver| !ver.contains('/'))
+        .map(str::to_string)
+        .collect()
+}
 
-    // versions map, keyed by the filename (version) without the .json suffix.
-    let mut versions = serde_json::Map::new();
-    for key in &version_keys {
-        let Ok(data) = state.storage.get(key).await else {
-            continue;
-        };
-        let Ok(vd) = serde_json::from_slice::<serde_json::Value>(&data) else {
-            continue;
-        };
-        if let Some(ver) = key.rsplit('/').next().and_then(|f| f.strip_suffix(".json")) {
-            versions.insert(ver.to_string(), vd);
-        }
+/// The `dist-tags` of the stored packument, hosted or cached.
+async fn dist_tags_response(state: &AppState, package_name: &str) -> Response {
+    let key = format!("npm/{}/metadata.json", package_name);
+    let Ok(data) = state.storage.get(&key).await else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    match serde_json::from_slice::<serde_json::Value>(&data) {
+        Ok(packument) => axum::Json(
+            packument
+                .get("dist-tags")
+                .cloned()
+                .unwrap_or_else(|| serde_json::json!({})),
+        )
+        .into_response(),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
     }
+}
 
-    // dist-tags from the pointer keys (+ derive `latest` if publish left it unset).
-    let dt_prefix = format!("npm/{}/dist-tags/", package_name);
-    let mut dist_tags = serde_json::Map::new();
-    for key in state.storage.list(&dt_prefix).await.unwrap_or_default() {
-        if let Ok(data) = state.storage.get(&key).await {
-            if let (Some(tag), Ok(ver)) = (key.rsplit('/').next(), String::from_utf8(data.to_vec()))
-            {
-                dist_tags.insert(tag.to_string(), serde_json::Value::String(ver));
-            }
-        }
+/// A dist-tag npm accepts: a short `[A-Za-z0-9._-]` word that does not read as a
+/// version or range (`1.0`, `v2`, `x`) — `npm install pkg@<tag>` tries a range
+/// first, so such a tag could never be installed by name.
+fn is_valid_dist_tag(tag: &str) -> bool {
+    let reads_as_version = matches!(tag, "x" | "X")
+        || tag
+            .strip_prefix(['v', 'V'])
+            .unwrap_or(tag)
+            .starts_with(|c: char| c.is_ascii_digit());
+    tag.len() <= 128
+        && !reads_as_version
+        && tag.starts_with(|c: char| c.is_ascii_alphabetic())
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+}
+
+/// `npm dist-tag ls` — `GET /-/package/{pkg}/dist-tags`.
+async fn dist_tags_list(State(state): State<AppState>, Path(package): Path<String>) -> Response {
+    dist_tags_response(&state, &package).await
+}
+
+/// `npm dist-tag add {pkg}@{version} {tag}` — `PUT /-/package/{pkg}/dist-tags/{tag}`
+/// with the version as a JSON string body. Hosted packages only; the version
+/// must be published.
+async fn dist_tag_put(
+    State(state): State<AppState>,
+    Path((package, tag)): Path<(String, String)>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    body: Bytes,
+) -> Response {
+    if enforce_namespace_scope(&authority, &package).is_err() {
+        return StatusCode::FORBIDDEN.into_response();
     }
-    if !dist_tags.contains_key("latest") {
-        if let Some(latest) = max_semver(versions.keys()) {
-            dist_tags.insert("latest".to_string(), serde_json::Value::String(latest));
-        }
+    if !is_valid_dist_tag(&tag) {
+        return (StatusCode::BAD_REQUEST, "Invalid dist-tag").into_response();
     }
+    let Ok(version) = serde_json::from_slice::<String>(&body) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            "Expected the version as a JSON string",
+        )
+            .into_response();
+    };
 
-    // Package-level fields + the assembled maps -> the packument.
-    let mut packument = match state
+    let lock = state.publish_lock(&format!("npm/{}/metadata.json", package));
+    let _guard = lock.lock().await;
+
+    let versions = hosted_versions(&state, &package).await;
+    if versions.is_empty() {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    if !versions.contains(&version) {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("Version {} is not published", version),
+        )
+            .into_response();
+    }
+    let tag_key = format!("npm/{}/dist-tags/{}", package, tag);
+    if state
         .storage
-        .get(&format!("npm/{}/pkg.json", package_name))
+        .put(&tag_key, version.as_bytes())
         .await
+        .is_err()
+        || regenerate_packument(&state, &package).await.is_err()
     {
-        Ok(d) => serde_json::from_slice(&d).unwrap_or_else(|_| serde_json::json!({})),
-        Err(_) => serde_json::json!({}),
-    };
-    let obj = packument.as_object_mut().ok_or(())?;
-    obj.insert(
-        "name".to_string(),
-        serde_json::Value::String(package_name.to_string()),
-    );
-    obj.insert(
-        "dist-tags".to_string(),
-        serde_json::Value::Object(dist_tags),
-    );
-    obj.insert("versions".to_string(), serde_json::Value::Object(versions));
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
 
-    let bytes = serde_json::to_vec(&packument).map_err(|_| ())?;
-    state
-        .storage
-        .put(&format!("npm/{}/metadata.json", package_name), &bytes)
-        .await
-        .map_err(|_| ())
+    state.audit.log(AuditEntry::new(
+        "dist-tag",
+        "api",
+        &format!("{}@{}", package, version),
+        "npm",
+        &format!("add {}", tag),
+    ));
+    dist_tags_response(&state, &package).await
 }
 
-/// Seed per-version keys from an old embedded packument (versions inside `metadata.json`, no
-/// per-version keys) so the scan-regenerate path does not drop them. Idempotent; a no-op once the
-/// package has per-version keys. Runs BEFORE the new version is written.
-async fn migrate_embedded_packument(
-    state: &AppState,
-    package_name: &str,
-    existing: &serde_json::Value,
+/// `npm dist-tag rm {pkg} {tag}` — `DELETE /-/package/{pkg}/dist-tags/{tag}`.
+/// `latest` cannot be removed, as on the public registry.
+async fn dist_tag_delete(
+    State(state): State<AppState>,
+    Path((package, tag)): Path<(String, String)>,
+    Extension(authority): Extension<NamespaceAuthority>,
+) -> Response {
+    if enforce_namespace_scope(&authority, &package).is_err() {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if tag == "latest" {
+        return (
+            StatusCode::BAD_REQUEST,
+            "The latest dist-tag cannot be removed",
+        )
+            .into_response();
+    }
+    if !is_valid_dist_tag(&tag) {
+        return (StatusCode::BAD_REQUEST, "Invalid dist-tag").into_response();
+    }
+
+    let lock = state.publish_lock(&format!("npm/{}/metadata.json", package));
+    let _guard = lock.lock().await;
+
+    let tag_key = format!("npm/{}/dist-tags/{}", package, tag);
+    if hosted_versions(&state, &package).await.is_empty()
+        || state.storage.stat(&tag_key).await.is_none()
+    {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    if state.storage.delete(&tag_key).await.is_err()
+        || regenerate_packument(&state, &package).await.is_err()
+    {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    state.audit.log(AuditEntry::new(
+        "dist-tag",
+        "api",
+        &package,
+        "npm",
+        &format!("rm {}", tag),
+    ));
+    dist_tags_response(&state, &package).await
+}
+
+/// A packument PUT without attachments: `npm deprecate` (every version sent back
+/// with its `deprecated` message, `""` to clear it) or, at `/{pkg}/-rev/{rev}`,
+/// `npm unpublish {pkg}@{version}` (the packument minus that version). Only the
+/// `deprecated` field of a stored version is taken from the body — the rest of
+/// a version document stays as published — and dist-tags are derived, not
+/// copied. Versions missing from the body are unpublished only on the `-rev`
+/// form, so a stale packument on a plain PUT never drops a version published in
+/// the meantime.
+async fn rewrite_packument(
+    state: &AppState,
+    package_name: &str,
+    payload: &serde_json::Value,
+    unpublish: bool,
+) -> Response {
+    let Some(incoming) = payload.get("versions").and_then(|v| v.as_object()) else {
+        return (StatusCode::BAD_REQUEST, "Missing versions").into_response();
+    };
+
+    let lock = state.publish_lock(&format!("npm/{}/metadata.json", package_name));
+    let _guard = lock.lock().await;
+
+    let stored = hosted_versions(state, package_name).await;
+    // A version NORA does not hold is a publish, and a publish carries its tarball.
+    if incoming.keys().any(|ver| !stored.contains(ver)) {
+        return (StatusCode::BAD_REQUEST, "Missing _attachments").into_response();
+    }
+    if stored.is_empty() {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let removed: Vec<&String> = stored
+        .iter()
+        .filter(|ver| unpublish && !incoming.contains_key(*ver))
+        .collect();
+    if removed.len() == stored.len() {
+        return unpublish_package(state, package_name).await;
+    }
+
+    let mut deprecated = Vec::new();
+    for (ver, doc) in incoming {
+        let version_key = format!("npm/{}/versions/{}.json", package_name, ver);
+        let Some(mut stored_doc) = state
+            .storage
+            .get(&version_key)
+            .await
+            .ok()
+            .and_then(|data| serde_json::from_slice::<serde_json::Value>(&data).ok())
+        else {
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        };
+        let message = doc
+            .get("deprecated")
+            .and_then(|d| d.as_str())
+            .filter(|m| !m.is_empty());
+        if stored_doc.get("deprecated").and_then(|d| d.as_str()) == message {
+            continue;
+        }
+        let Some(fields) = stored_doc.as_object_mut() else {
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        };
+        match message {
+            Some(m) => fields.insert("deprecated".to_string(), m.into()),
+            None => fields.remove("deprecated"),
+        };
+        let Ok(bytes) = serde_json::to_vec(&stored_doc) else {
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        };
+        if let Err(e) = state.storage.put(&version_key, &bytes).await {
+            tracing::error!(key = %version_key, error = ?e, "npm deprecate: failed to store version");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+        deprecated.push((ver, message.unwrap_or_default()));
+    }
+    for ver in &removed {
+        if remove_version(state, package_name, ver).await.is_err() {
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+    if regenerate_packument(state, package_name).await.is_err() {
+        tracing::error!(package = %package_name, "npm: failed to regenerate packument");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    for (ver, message) in deprecated {
+        state.audit.log(AuditEntry::new(
+            "deprecate",
+            "api",
+            &format!("{}@{}", package_name, ver),
+            "npm",
+            message,
+        ));
+    }
+    for ver in &removed {
+        state.audit.log(AuditEntry::new(
+            "unpublish",
+            "api",
+            &format!("{}@{}", package_name, ver),
+            "npm",
+            "",
+        ));
+    }
+    if !removed.is_empty() {
+        state.repo_index.invalidate("npm");
+    }
+    StatusCode::OK.into_response()
+}
+
+/// Delete `key` if it exists. Deleting through storage also tombstones the key's
+/// hash pin.
+async fn delete_if_present(state: &AppState, key: &str) -> Result<(), ()> {
+    if state.storage.stat(key).await.is_none() {
+        return Ok(());
+    }
+    state.storage.delete(key).await.map_err(|e| {
+        tracing::error!(key = %key, error = ?e, "npm unpublish: failed to delete");
+    })
+}
+
+/// Marks an unpublished version so it can never be published again with
+/// different bytes — a lockfile pinning the old integrity would otherwise
+/// install something else under the same number. npmjs refuses reuse the same
+/// way.
+async fn write_unpublished_marker(
+    state: &AppState,
+    package_name: &str,
+    version: &str,
+) -> Result<(), ()> {
+    let key = format!("npm/{}/unpublished/{}", package_name, version);
+    state
+        .storage
+        .put(&key, chrono::Utc::now().to_rfc3339().as_bytes())
+        .await
+        .map_err(|e| {
+            tracing::error!(key = %key, error = ?e, "npm unpublish: failed to record version");
+        })
+}
+
+/// Drop one published version: mark it unpublished, then remove its version
+/// document (so an interrupted removal never lists a version without its
+/// tarball), any dist-tag pointing at it, and the tarball with its `.sha256`
+/// sidecar. The caller regenerates the packument, where a removed `latest` falls
+/// back to the highest remaining version.
+async fn remove_version(state: &AppState, package_name: &str, version: &str) -> Result<(), ()> {
+    write_unpublished_marker(state, package_name, version).await?;
+    delete_if_present(
+        state,
+        &format!("npm/{}/versions/{}.json", package_name, version),
+    )
+    .await?;
+    let tags_prefix = format!("npm/{}/dist-tags/", package_name);
+    for key in state.storage.list(&tags_prefix).await.unwrap_or_default() {
+        if state
+            .storage
+            .get(&key)
+            .await
+            .is_ok_and(|v| v == version.as_bytes())
+        {
+            delete_if_present(state, &key).await?;
+        }
+    }
+    let short_name = package_name.rsplit('/').next().unwrap_or(package_name);
+    let tarball_key = format!(
+        "npm/{}/tarballs/{}-{}.tgz",
+        package_name, short_name, version
+    );
+    delete_if_present(state, &format!("{}.sha256", tarball_key)).await?;
+    delete_if_present(state, &tarball_key).await
+}
+
+/// Remove a whole package: mark its versions unpublished, drop the packument so
+/// an interrupted removal leaves nothing advertising the package, then every
+/// remaining key under it but the markers. The caller holds the package's
+/// publish lock.
+async fn unpublish_package(state: &AppState, package_name: &str) -> Response {
+    for ver in hosted_versions(state, package_name).await {
+        if write_unpublished_marker(state, package_name, &ver)
+            .await
+            .is_err()
+        {
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+    let prefix = format!("npm/{}/", package_name);
+    let metadata_key = format!("{}metadata.json", prefix);
+    if delete_if_present(state, &metadata_key).await.is_err() {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    let markers = format!("{}unpublished/", prefix);
+    for key in state.storage.list(&prefix).await.unwrap_or_default() {
+        if key.starts_with(&markers) {
+            continue;
+        }
+        if delete_if_present(state, &key).await.is_err() {
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+
+    state
+        .audit
+        .log(AuditEntry::new("unpublish", "api", package_name, "npm", ""));
+    state.repo_index.invalidate("npm");
+    state.repo_index.invalidate_npm_search();
+    StatusCode::OK.into_response()
+}
+
+/// `npm unpublish` deletes at CouchDB revision URLs: `/{pkg}/-rev/{rev}` removes the
+/// whole package, `/{pkg}/-/{file}/-rev/{rev}` the tarball of a version already
+/// dropped from the packument. NORA serializes on the publish lock instead of
+/// revisions, so `{rev}` is not checked.
+async fn handle_unpublish(
+    State(state): State<AppState>,
+    Path(path): Path<String>,
+    Extension(authority): Extension<NamespaceAuthority>,
+) -> Response {
+    let Some((target, _rev)) = path.rsplit_once("/-rev/") else {
+        return method_not_allowed("GET, PUT, POST");
+    };
+    let (package_name, tarball) = match target.split_once("/-/") {
+        // libnpmpublish addresses the tarball by the pathname of its `dist.tarball`
+        // URL, which already carries NORA's `/npm` mount (`/npm/npm/{pkg}/-/…`).
+        // No package is named `npm/…` (an unscoped name has no `/`), so the
+        // doubled prefix is unambiguous.
+        Some((pkg, file)) => (pkg.strip_prefix("npm/").unwrap_or(pkg), Some(file)),
+        None => (target, None),
+    };
+    if enforce_namespace_scope(&authority, package_name).is_err() {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+
+    let lock = state.publish_lock(&format!("npm/{}/metadata.json", package_name));
+    let _guard = lock.lock().await;
+
+    let Some(file) = tarball else {
+        if hosted_versions(&state, package_name).await.is_empty() {
+            return StatusCode::NOT_FOUND.into_response();
+        }
+        return unpublish_package(&state, package_name).await;
+    };
+
+    if !is_valid_attachment_name(file) {
+        return (StatusCode::BAD_REQUEST, "Invalid tarball filename").into_response();
+    }
+    // A tarball leaves with its version (the packument PUT), never on its own.
+    if let Some(ver) = crate::curation::parse_npm_tarball_version(package_name, file) {
+        let version_key = format!("npm/{}/versions/{}.json", package_name, ver);
+        if state.storage.stat(&version_key).await.is_some() {
+            return (
+                StatusCode::CONFLICT,
+                format!("Version {} is still published", ver),
+            )
+                .into_response();
+        }
+    }
+    let tarball_key = format!("npm/{}/tarballs/{}", package_name, file);
+    if state.storage.stat(&tarball_key).await.is_some() {
+        if delete_if_present(&state, &format!("{}.sha256", tarball_key))
+            .await
+            .is_err()
+            || delete_if_present(&state, &tarball_key).await.is_err()
+        {
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+        state.audit.log(AuditEntry::new(
+            "unpublish",
+            "api",
+            &format!("{}/-/{}", package_name, file),
+            "npm",
+            "",
+        ));
+    }
+    StatusCode::OK.into_response()
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Regenerate the npm packument (`metadata.json`) by LISTING the immutable per-version keys, the
+/// dist-tag pointers and the package-level fields — never by read-modify-write of the shared file.
+/// Concurrent publishers each write their own `versions/{v}.json`; whoever regenerates last lists
+/// them all, so no version is permanently lost (the maven scan-regenerate guarantee, #39). Old
+/// embedded-packument packages are migrated to per-version keys by `migrate_embedded_packument`
+/// in the publish handler BEFORE the new version is written, so this stays a pure list-derive.
+async fn regenerate_packument(state: &AppState, package_name: &str) -> Result<(), ()> {
+    let versions_prefix = format!("npm/{}/versions/", package_name);
+    let version_keys = state
+        .storage
+        .list(&versions_prefix)
+        .await
+        .unwrap_or_default();
+
+    // versions map, keyed by the filename (version) without the .json suffix.
+    let mut versions = serde_json::Map::new();
+    for key in &version_keys {
+        let Ok(data) = state.storage.get(key).await else {
+            continue;
+        };
+        let Ok(vd) = serde_json::from_slice::<serde_json::Value>(&data) else {
+            continue;
+        };
+        if let Some(ver) = key.rsplit('/').next().and_then(|f| f.strip_suffix(".json")) {
+            versions.insert(ver.to_string(), vd);
+        }
+    }
+
+    // dist-tags from the pointer keys (+ derive `latest` if publish left it unset).
+    let dt_prefix = format!("npm/{}/dist-tags/", package_name);
+    let mut dist_tags = serde_json::Map::new();
+    for key in state.storage.list(&dt_prefix).await.unwrap_or_default() {
+        if let Ok(data) = state.storage.get(&key).await {
+            if let (Some(tag), Ok(ver)) = (key.rsplit('/').next(), String::from_utf8(data.to_vec()))
+            {
+                dist_tags.insert(tag.to_string(), serde_json::Value::String(ver));
+            }
+        }
+    }
+    if !dist_tags.contains_key("latest") {
+        if let Some(latest) = max_semver(versions.keys()) {
+            dist_tags.insert("latest".to_string(), serde_json::Value::String(latest));
+        }
+    }
+
+    // Package-level fields + the assembled maps -> the packument.
+    let mut packument = match state
+        .storage
+        .get(&format!("npm/{}/pkg.json", package_name))
+        .await
+    {
+        Ok(d) => serde_json::from_slice(&d).unwrap_or_else(|_| serde_json::json!({})),
+        Err(_) => serde_json::json!({}),
+    };
+    let obj = packument.as_object_mut().ok_or(())?;
+    obj.insert(
+        "name".to_string(),
+        serde_json::Value::String(package_name.to_string()),
+    );
+    obj.insert(
+        "dist-tags".to_string(),
+        serde_json::Value::Object(dist_tags),
+    );
+    obj.insert("versions".to_string(), serde_json::Value::Object(versions));
+
+    let bytes = serde_json::to_vec(&packument).map_err(|_| ())?;
+    state
+        .storage
+        .put(&format!("npm/{}/metadata.json", package_name), &bytes)
+        .await
+        .map_err(|_| ())?;
+    state.repo_index.invalidate_npm_search();
+    Ok(())
+}
+
+/// Seed per-version keys from an old embedded packument (versions inside `metadata.json`, no
+/// per-version keys) so the scan-regenerate path does not drop them. Idempotent; a no-op once the
+/// package has per-version keys. Runs BEFORE the new version is written.
+async fn migrate_embedded_packument(
+    state: &AppState,
+    package_name: &str,
+    existing: &serde_json::Value,
 ) {
     let versions_prefix = format!("npm/{}/versions/", package_name);
     if !state
@@ -1226,7 +2331,9 @@ async fn ensure_npm_metadata_cached(state: &AppState, package_name: &str) {
             let nora_npm_base = format!("{}/npm", nora_base.trim_end_matches('/'));
             replace_upstream_bytes(&data, upstream_trimmed, &nora_npm_base)
         });
-        let _ = state.storage.put(&key, &rewritten).await;
+        if state.storage.put(&key, &rewritten).await.is_ok() {
+            index_for_search(&state.repo_index, &rewritten).await;
+        }
     }
 }
 
@@ -1254,6 +2361,28 @@ async fn extract_npm_publish_date(
     crate::curation::parse_iso8601_to_unix(date_str)
 }
 
+/// Declared license of one version in cached npm metadata: `license` (an SPDX
+/// expression, or `{ "type": … }` in old packages) or the legacy `licenses` array.
+async fn extract_npm_license(
+    storage: &crate::storage::Storage,
+    metadata_key: &str,
+    version: &str,
+) -> Option<String> {
+    fn license_name(value: &serde_json::Value) -> Option<&str> {
+        value
+            .as_str()
+            .or_else(|| value.get("type").and_then(|t| t.as_str()))
+    }
+    let data = storage.get(metadata_key).await.ok()?;
+    let json: serde_json::Value = serde_json::from_slice(&data).ok()?;
+    let manifest = json.get("versions")?.get(version)?;
+    if let Some(license) = manifest.get("license").and_then(license_name) {
+        return Some(license.to_string());
+    }
+    let licenses = manifest.get("licenses")?.as_array()?;
+    crate::license::parse_any(licenses.iter().filter_map(license_name)).map(|e| e.to_string())
+}
+
 fn with_content_type(
     is_tarball: bool,
     data: Bytes,
@@ -1591,6 +2720,106 @@ mod tests {
         assert!(!is_valid_attachment_name("file name.tgz")); // space
         assert!(!is_valid_attachment_name("file;cmd.tgz")); // semicolon
     }
+
+    #[test]
+    fn test_is_valid_dist_tag() {
+        for tag in ["latest", "next", "beta-2", "lts.iron", "Canary_1"] {
+            assert!(is_valid_dist_tag(tag), "{tag}");
+        }
+        for tag in [
+            "", "1.0", "v2", "V2.1", "x", "*", "a/b", "-next", "tag name",
+        ] {
+            assert!(!is_valid_dist_tag(tag), "{tag}");
+        }
+        assert!(!is_valid_dist_tag(&"a".repeat(129)));
+    }
+
+    #[test]
+    fn test_search_query_parse() {
+        let q = SearchQuery::parse(
+            "  Widgets keywords:UI,forms scope:@acme author:jane not:deprecated ",
+        );
+        assert_eq!(q.terms, vec!["widgets"]);
+        assert_eq!(q.keywords, vec!["ui", "forms"]);
+        assert_eq!(q.scopes, vec!["acme"]);
+        assert_eq!(q.authors, vec!["jane"]);
+        assert!(q.maintainers.is_empty());
+        assert_eq!(
+            SearchQuery::parse("@acme scope:corp").namespace_probes(),
+            vec!["@acme", "@acme/", "@corp/"]
+        );
+    }
+
+    #[test]
+    fn test_search_doc_falls_back_to_latest_version() {
+        // A hosted packument: descriptive fields live on the version documents.
+        let packument = serde_json::json!({
+            "name": "@acme/widgets",
+            "dist-tags": { "latest": "1.1.0" },
+            "versions": {
+                "1.0.0": { "description": "old" },
+                "1.1.0": {
+                    "description": "Form widgets",
+                    "keywords": ["ui", "forms"],
+                    "author": "Jane Doe <jane@acme.example> (https://acme.example)",
+                    "maintainers": [{ "name": "jane", "email": "jane@acme.example" }],
+                    "_npmUser": { "name": "ci", "email": "ci@acme.example" },
+                    "repository": { "type": "git", "url": "git+https://acme.example/w.git" }
+                }
+            }
+        });
+        let doc = search_doc(&packument).unwrap();
+        assert_eq!(doc.version, "1.1.0");
+        assert_eq!(doc.description, "Form widgets");
+        assert_eq!(doc.author.as_deref(), Some("Jane Doe"));
+        assert_eq!(doc.date, None);
+
+        let object = search_object(&doc, 0.5);
+        assert_eq!(object["package"]["scope"], "acme");
+        assert_eq!(object["package"]["publisher"]["username"], "ci");
+        assert_eq!(
+            object["package"]["maintainers"][0]["email"],
+            "jane@acme.example"
+        );
+        assert_eq!(
+            object["package"]["links"]["repository"],
+            "git+https://acme.example/w.git"
+        );
+        assert_eq!(object["score"]["final"], 0.5);
+
+        // Without dist-tags the highest version stands in for `latest`.
+        let untagged = serde_json::json!({
+            "name": "w",
+            "versions": { "1.2.0": {}, "1.10.0": {} },
+            "time": { "1.10.0": "2024-01-15T00:00:00.000Z" }
+        });
+        let doc = search_doc(&untagged).unwrap();
+        assert_eq!(doc.version, "1.10.0");
+        assert_eq!(doc.date.as_deref(), Some("2024-01-15T00:00:00.000Z"));
+        assert!(search_doc(&serde_json::json!({ "name": "x" })).is_none());
+    }
+
+    #[test]
+    fn test_search_query_score() {
+        let doc = SearchDoc {
+            name: "@acme/widgets".to_string(),
+            description: "Form widgets for Acme".to_string(),
+            keywords: vec!["UI".to_string()],
+            maintainers: vec![("jane".to_string(), String::new())],
+            ..Default::default()
+        };
+        let score = |text: &str| SearchQuery::parse(text).score(&doc);
+        assert_eq!(score("@acme/widgets"), Some(1.0));
+        assert_eq!(score("widgets"), Some(0.8));
+        assert_eq!(score("form"), Some(0.5));
+        assert_eq!(score("jan"), Some(0.5));
+        assert_eq!(score("keywords:ui scope:acme maintainer:jane"), Some(0.5));
+        assert_eq!(score(""), Some(0.5));
+        assert_eq!(score("widgets gadgets"), None);
+        assert_eq!(score("keywords:cli"), None);
+        assert_eq!(score("scope:other"), None);
+        assert_eq!(score("author:jane"), None);
+    }
 }
 
 #[cfg(test)]
@@ -2443,6 +3672,703 @@ mod integration_tests {
         );
     }
 
+    // ── npm search (/-/v1/search) ──
+
+    async fn search_json(app: &axum::Router, uri: &str) -> serde_json::Value {
+        let resp = send(app, Method::GET, uri, "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        serde_json::from_slice(&body_bytes(resp).await).unwrap()
+    }
+
+    fn names(result: &serde_json::Value) -> Vec<&str> {
+        result["objects"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|o| o["package"]["name"].as_str().unwrap())
+            .collect()
+    }
+
+    async fn publish_version(app: &axum::Router, name: &str, version: &str, tag: &str) {
+        let short = name.rsplit('/').next().unwrap();
+        let payload = serde_json::json!({
+            "name": name,
+            "versions": { version: { "name": name, "version": version, "dist": {} } },
+            "_attachments": { format!("{short}-{version}.tgz"): {
+                "data": base64::engine::general_purpose::STANDARD.encode(format!("tgz {version}"))
+            } },
+            "dist-tags": { tag: version }
+        });
+        let resp = send(
+            app,
+            Method::PUT,
+            &format!("/npm/{}", name.replace('/', "%2f")),
+            Body::from(serde_json::to_vec(&payload).unwrap()),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_npm_search_hosted_and_cached() {
+        let ctx = create_test_context();
+
+        let payload = serde_json::json!({
+            "name": "@acme/forms",
+            "description": "Form widgets",
+            "versions": { "1.0.0": {
+                "name": "@acme/forms",
+                "version": "1.0.0",
+                "description": "Form widgets",
+                "keywords": ["ui", "forms"],
+                "maintainers": [{ "name": "jane", "email": "jane@acme.example" }],
+                "dist": {}
+            } },
+            "_attachments": { "@acme/forms-1.0.0.tgz": {
+                "data": base64::engine::general_purpose::STANDARD.encode(b"tgz")
+            } },
+            "dist-tags": { "latest": "1.0.0" }
+        });
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/npm/@acme%2fforms",
+            Body::from(serde_json::to_vec(&payload).unwrap()),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        ctx_seed_metadata(
+            &ctx,
+            "react-forms",
+            &serde_json::json!({
+                "name": "react-forms",
+                "description": "Forms for React",
+                "keywords": ["react"],
+                "maintainers": [{ "name": "bob", "email": "bob@example.com" }],
+                "dist-tags": { "latest": "2.0.0" },
+                "versions": { "2.0.0": {} },
+                "time": { "2.0.0": "2024-01-15T00:00:00.000Z" }
+            }),
+        )
+        .await;
+        ctx_seed_metadata(
+            &ctx,
+            "lodash",
+            &serde_json::json!({
+                "name": "lodash",
+                "dist-tags": { "latest": "4.17.21" },
+                "versions": { "4.17.21": {} }
+            }),
+        )
+        .await;
+
+        // Name matches outrank description matches.
+        let result = search_json(&ctx.app, "/npm/-/v1/search?text=forms").await;
+        assert_eq!(names(&result), vec!["@acme/forms", "react-forms"]);
+        assert_eq!(result["total"], 2);
+        let hosted = &result["objects"][0]["package"];
+        assert_eq!(hosted["version"], "1.0.0");
+        assert_eq!(hosted["scope"], "acme");
+        assert_eq!(hosted["keywords"], serde_json::json!(["ui", "forms"]));
+        assert!(hosted["date"].as_str().unwrap().ends_with('Z'));
+        assert_eq!(
+            result["objects"][1]["package"]["date"],
+            "2024-01-15T00:00:00.000Z"
+        );
+        assert!(result["time"].is_string());
+
+        let by = |text: &'static str| {
+            let app = ctx.app.clone();
+            async move {
+                names(&search_json(&app, &format!("/npm/-/v1/search?text={text}")).await).len()
+            }
+        };
+        assert_eq!(by("maintainer:jane").await, 1);
+        assert_eq!(by("keywords:react").await, 1);
+        assert_eq!(by("scope:acme").await, 1);
+        assert_eq!(by("widgets").await, 1);
+        assert_eq!(by("nothing-matches").await, 0);
+
+        // size/from page over the ranked matches; `total` counts them all.
+        let page = search_json(&ctx.app, "/npm/-/v1/search?text=&size=2&from=1").await;
+        assert_eq!(names(&page), vec!["lodash", "react-forms"]);
+        assert_eq!(page["total"], 3);
+
+        let resp = send(&ctx.app, Method::PUT, "/npm/-/v1/search", "").await;
+        assert_eq!(resp.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn test_npm_search_merges_upstream() {
+        use crate::test_helpers::create_test_context_with_config;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let upstream = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/-/v1/search"))
+            .and(query_param("text", "react forms"))
+            .and(query_param("size", "2"))
+            .and(query_param("from", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "objects": [
+                    { "package": { "name": "react-forms", "version": "2.0.0" } },
+                    { "package": { "name": "react-hook-form", "version": "7.0.0" } }
+                ],
+                "total": 40
+            })))
+            .expect(1)
+            .mount(&upstream)
+            .await;
+
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.npm.proxy = Some(upstream.uri());
+        });
+        ctx_seed_metadata(
+            &ctx,
+            "react-forms",
+            &serde_json::json!({
+                "name": "react-forms",
+                "description": "Forms for React",
+                "dist-tags": { "latest": "2.0.0" },
+                "versions": { "2.0.0": {} }
+            }),
+        )
+        .await;
+
+        // The cached packument leads; upstream's copy of it is dropped.
+        let result = search_json(&ctx.app, "/npm/-/v1/search?text=react+forms&size=3").await;
+        assert_eq!(names(&result), vec!["react-forms", "react-hook-form"]);
+        assert_eq!(result["total"], 40);
+    }
+
+    #[tokio::test]
+    async fn test_npm_search_full_local_page_not_forwarded() {
+        use crate::test_helpers::create_test_context_with_config;
+        use wiremock::matchers::any;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let upstream = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&upstream)
+            .await;
+
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.npm.proxy = Some(upstream.uri());
+        });
+        ctx_seed_metadata(
+            &ctx,
+            "react-forms",
+            &serde_json::json!({
+                "name": "react-forms",
+                "dist-tags": { "latest": "2.0.0" },
+                "versions": { "2.0.0": {} }
+            }),
+        )
+        .await;
+
+        let result = search_json(&ctx.app, "/npm/-/v1/search?text=react&size=1").await;
+        assert_eq!(names(&result), vec!["react-forms"]);
+    }
+
+    #[tokio::test]
+    async fn test_npm_search_internal_namespace_not_forwarded() {
+        use crate::test_helpers::create_test_context_with_config;
+        use wiremock::matchers::any;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let upstream = MockServer::start().await;
+        // Any upstream hit would leak the internal name — mount a canary.
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(200).set_body_string("LEAKED"))
+            .expect(0)
+            .mount(&upstream)
+            .await;
+
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.npm.proxy = Some(upstream.uri());
+            cfg.curation.mode = crate::config::CurationMode::Enforce;
+            cfg.curation.internal_namespaces = vec!["@internal/*".to_string()];
+        });
+        ctx_seed_metadata(
+            &ctx,
+            "@internal/secret",
+            &serde_json::json!({
+                "name": "@internal/secret",
+                "dist-tags": { "latest": "1.0.0" },
+                "versions": { "1.0.0": {} }
+            }),
+        )
+        .await;
+
+        for uri in [
+            "/npm/-/v1/search?text=@internal/secret",
+            "/npm/-/v1/search?text=@internal",
+            "/npm/-/v1/search?text=scope:internal",
+        ] {
+            let result = search_json(&ctx.app, uri).await;
+            assert_eq!(names(&result), vec!["@internal/secret"], "{uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_npm_search_upstream_down_serves_local() {
+        use crate::test_helpers::create_test_context_with_config;
+
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.npm.proxy = Some("http://127.0.0.1:1".to_string());
+            cfg.npm.proxy_timeout = 1;
+        });
+        ctx_seed_metadata(
+            &ctx,
+            "lodash",
+            &serde_json::json!({
+                "name": "lodash",
+                "dist-tags": { "latest": "4.17.21" },
+                "versions": { "4.17.21": {} }
+            }),
+        )
+        .await;
+
+        let result = search_json(&ctx.app, "/npm/-/v1/search?text=lodash").await;
+        assert_eq!(names(&result), vec!["lodash"]);
+        assert_eq!(result["total"], 1);
+        assert_eq!(result["objects"][0]["searchScore"], 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_npm_search_index_follows_publish_and_unpublish() {
+        let ctx = create_test_context();
+        publish_version(&ctx.app, "widgets", "1.0.0", "latest").await;
+        let search = || search_json(&ctx.app, "/npm/-/v1/search?text=");
+        assert_eq!(names(&search().await), vec!["widgets"]);
+
+        // Searches answer from the index, not storage…
+        ctx_seed_metadata(
+            &ctx,
+            "lodash",
+            &serde_json::json!({
+                "name": "lodash",
+                "dist-tags": { "latest": "4.17.21" },
+                "versions": { "4.17.21": {} }
+            }),
+        )
+        .await;
+        assert_eq!(names(&search().await), vec!["widgets"]);
+
+        // …which a publish rebuilds…
+        publish_version(&ctx.app, "gadgets", "1.0.0", "latest").await;
+        let result = search().await;
+        assert_eq!(names(&result), vec!["gadgets", "lodash", "widgets"]);
+        assert_eq!(result["objects"][2]["package"]["version"], "1.0.0");
+        publish_version(&ctx.app, "widgets", "1.1.0", "latest").await;
+        let result = search().await;
+        assert_eq!(result["objects"][2]["package"]["version"], "1.1.0");
+
+        // …and so does an unpublish.
+        let resp = send(&ctx.app, Method::DELETE, "/npm/widgets/-rev/1", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(names(&search().await), vec!["gadgets", "lodash"]);
+    }
+
+    // ── dist-tags, deprecate, unpublish ──
+
+    async fn packument(ctx: &crate::test_helpers::TestContext, name: &str) -> serde_json::Value {
+        let data = ctx
+            .state
+            .storage
+            .get(&format!("npm/{name}/metadata.json"))
+            .await
+            .unwrap();
+        serde_json::from_slice(&data).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_npm_dist_tag_add_ls_rm() {
+        let ctx = create_test_context();
+        publish_version(&ctx.app, "@acme/forms", "1.0.0", "latest").await;
+        publish_version(&ctx.app, "@acme/forms", "2.0.0-beta.1", "next").await;
+
+        let uri = "/npm/-/package/@acme%2fforms/dist-tags";
+        let resp = send(&ctx.app, Method::GET, uri, "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let tags: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(
+            tags,
+            serde_json::json!({ "latest": "1.0.0", "next": "2.0.0-beta.1" })
+        );
+
+        let resp = send(&ctx.app, Method::PUT, &format!("{uri}/stable"), "\"1.0.0\"").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let tags: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(tags["stable"], "1.0.0");
+        assert_eq!(
+            packument(&ctx, "@acme/forms").await["dist-tags"]["stable"],
+            "1.0.0"
+        );
+
+        // Moving `latest` is an ordinary retag.
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            &format!("{uri}/latest"),
+            "\"2.0.0-beta.1\"",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            packument(&ctx, "@acme/forms").await["dist-tags"]["latest"],
+            "2.0.0-beta.1"
+        );
+
+        for (tag, body, status) in [
+            ("stable", "\"9.9.9\"", StatusCode::BAD_REQUEST),
+            ("stable", "1.0.0", StatusCode::BAD_REQUEST),
+            ("1.0", "\"1.0.0\"", StatusCode::BAD_REQUEST),
+            ("v2", "\"1.0.0\"", StatusCode::BAD_REQUEST),
+        ] {
+            let resp = send(&ctx.app, Method::PUT, &format!("{uri}/{tag}"), body).await;
+            assert_eq!(resp.status(), status, "{tag} {body}");
+        }
+
+        let resp = send(&ctx.app, Method::DELETE, &format!("{uri}/stable"), "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let tags = packument(&ctx, "@acme/forms").await["dist-tags"].clone();
+        assert!(tags.get("stable").is_none());
+        let resp = send(&ctx.app, Method::DELETE, &format!("{uri}/stable"), "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = send(&ctx.app, Method::DELETE, &format!("{uri}/latest"), "").await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_npm_dist_tag_cached_package_read_only() {
+        let ctx = create_test_context();
+        ctx_seed_metadata(
+            &ctx,
+            "lodash",
+            &serde_json::json!({
+                "name": "lodash",
+                "dist-tags": { "latest": "4.17.21" },
+                "versions": { "4.17.21": {} }
+            }),
+        )
+        .await;
+
+        let resp = send(&ctx.app, Method::GET, "/npm/-/package/lodash/dist-tags", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let tags: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(tags["latest"], "4.17.21");
+
+        let uri = "/npm/-/package/lodash/dist-tags/mine";
+        let resp = send(&ctx.app, Method::PUT, uri, "\"4.17.21\"").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = send(&ctx.app, Method::GET, "/npm/-/package/nope/dist-tags", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_npm_deprecate_and_undeprecate() {
+        let ctx = create_test_context();
+        publish_version(&ctx.app, "widgets", "1.0.0", "latest").await;
+        publish_version(&ctx.app, "widgets", "1.1.0", "latest").await;
+
+        // `npm deprecate widgets@1.0.0 "use 1.1"`: the fetched packument comes back
+        // with the message set. A stale copy missing 1.1.0 must not drop it.
+        let mut doc = packument(&ctx, "widgets").await;
+        doc["versions"]["1.0.0"]["deprecated"] = "use 1.1".into();
+        doc["versions"]["1.0.0"]["dist"]["tarball"] = "http://evil.example/x.tgz".into();
+        doc["versions"].as_object_mut().unwrap().remove("1.1.0");
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/npm/widgets",
+            Body::from(serde_json::to_vec(&doc).unwrap()),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let doc = packument(&ctx, "widgets").await;
+        assert_eq!(doc["versions"]["1.0.0"]["deprecated"], "use 1.1");
+        assert!(doc["versions"]["1.0.0"]["dist"]["tarball"]
+            .as_str()
+            .unwrap()
+            .ends_with("/npm/widgets/-/widgets-1.0.0.tgz"));
+        assert!(doc["versions"].get("1.1.0").is_some());
+
+        // An empty message clears it.
+        let mut doc = doc;
+        doc["versions"]["1.0.0"]["deprecated"] = "".into();
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/npm/widgets",
+            Body::from(serde_json::to_vec(&doc).unwrap()),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(packument(&ctx, "widgets").await["versions"]["1.0.0"]
+            .get("deprecated")
+            .is_none());
+
+        // A version NORA does not hold still needs its tarball.
+        doc["versions"]["2.0.0"] = serde_json::json!({ "dist": {} });
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/npm/widgets",
+            Body::from(serde_json::to_vec(&doc).unwrap()),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_npm_unpublish_version() {
+        let ctx = create_test_context();
+        publish_version(&ctx.app, "widgets", "1.0.0", "latest").await;
+        publish_version(&ctx.app, "widgets", "1.1.0", "latest").await;
+        let tarball = "npm/widgets/tarballs/widgets-1.1.0.tgz";
+        for _ in 0..200 {
+            if ctx.state.storage.get_pin_hash(tarball).is_some() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert!(ctx.state.storage.get_pin_hash(tarball).is_some());
+
+        // What libnpmpublish sends for `npm unpublish widgets@1.1.0`.
+        let mut doc = packument(&ctx, "widgets").await;
+        doc["versions"].as_object_mut().unwrap().remove("1.1.0");
+        doc["dist-tags"]["latest"] = "1.0.0".into();
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/npm/widgets/-rev/undefined",
+            Body::from(serde_json::to_vec(&doc).unwrap()),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let doc = packument(&ctx, "widgets").await;
+        assert!(doc["versions"].get("1.1.0").is_none());
+        assert_eq!(doc["dist-tags"]["latest"], "1.0.0");
+        let storage = &ctx.state.storage;
+        assert!(storage.stat(tarball).await.is_none());
+        assert!(storage.stat(&format!("{tarball}.sha256")).await.is_none());
+        assert!(storage.get_pin_hash(tarball).is_none());
+        assert!(storage
+            .stat("npm/widgets/versions/1.1.0.json")
+            .await
+            .is_none());
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/npm/widgets/-/widgets-1.1.0.tgz",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        // Its follow-up tarball DELETE, at the doubled `/npm/npm/…` pathname, is a no-op.
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/npm/npm/widgets/-/widgets-1.1.0.tgz/-rev/2-abc",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        // A live version's tarball cannot be deleted on its own.
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/npm/widgets/-/widgets-1.0.0.tgz/-rev/2-abc",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/npm/widgets/-/widgets-1.0.0.tgz",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        // The number is burned: republishing it would change what it installs.
+        let payload = serde_json::json!({
+            "name": "widgets",
+            "versions": { "1.1.0": { "dist": {} } },
+            "_attachments": { "widgets-1.1.0.tgz": { "data": "b3RoZXI=" } }
+        });
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/npm/widgets",
+            Body::from(serde_json::to_vec(&payload).unwrap()),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_npm_unpublish_package() {
+        let ctx = create_test_context();
+        publish_version(&ctx.app, "@acme/forms", "1.0.0", "latest").await;
+        publish_version(&ctx.app, "@acme/forms", "1.1.0", "latest").await;
+
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/npm/@acme%2fforms/-rev/3-abc",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let mut left = ctx.state.storage.list("npm/@acme/forms/").await.unwrap();
+        left.sort();
+        assert_eq!(
+            left,
+            vec![
+                "npm/@acme/forms/unpublished/1.0.0",
+                "npm/@acme/forms/unpublished/1.1.0"
+            ]
+        );
+        let resp = send(&ctx.app, Method::GET, "/npm/@acme%2fforms", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/npm/@acme%2fforms/-rev/3-abc",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        // DELETE outside a revision URL is not an npm verb.
+        let resp = send(&ctx.app, Method::DELETE, "/npm/widgets", "").await;
+        assert_eq!(resp.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+        // Removing the last version through the packument PUT removes the package.
+        publish_version(&ctx.app, "widgets", "1.0.0", "latest").await;
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/npm/widgets/-rev/1-abc",
+            Body::from(r#"{"name":"widgets","versions":{}}"#),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            ctx.state.storage.list("npm/widgets/").await.unwrap(),
+            vec!["npm/widgets/unpublished/1.0.0"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_npm_manage_namespace_scope_enforced() {
+        use crate::auth::NamespaceAuthority;
+        use crate::config::ScopeEnforcement;
+        use axum::body::Bytes;
+        use axum::extract::{Path, State};
+        use axum::Extension;
+
+        let ctx = create_test_context();
+        publish_version(&ctx.app, "@other/pkg", "1.0.0", "latest").await;
+        let scoped = NamespaceAuthority::from_oidc_scope(
+            "ci",
+            &["@myorg/**".to_string()],
+            ScopeEnforcement::Enforce,
+        );
+
+        let resp = super::dist_tag_put(
+            State(ctx.state.clone()),
+            Path(("@other/pkg".to_string(), "next".to_string())),
+            Extension(scoped.clone()),
+            Bytes::from_static(b"\"1.0.0\""),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let resp = super::dist_tag_delete(
+            State(ctx.state.clone()),
+            Path(("@other/pkg".to_string(), "next".to_string())),
+            Extension(scoped.clone()),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        for path in ["@other/pkg/-rev/1", "npm/@other/pkg/-/pkg-1.0.0.tgz/-rev/1"] {
+            let resp = super::handle_unpublish(
+                State(ctx.state.clone()),
+                Path(path.to_string()),
+                Extension(scoped.clone()),
+            )
+            .await;
+            assert_eq!(resp.status(), StatusCode::FORBIDDEN, "{path}");
+        }
+        let resp = super::handle_publish(
+            State(ctx.state.clone()),
+            Path("@other/pkg/-rev/1".to_string()),
+            Extension(scoped),
+            Bytes::from_static(br#"{"name":"@other/pkg","versions":{}}"#),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        assert!(ctx
+            .state
+            .storage
+            .stat("npm/@other/pkg/versions/1.0.0.json")
+            .await
+            .is_some());
+    }
+
+    /// The license filter reads `versions[v].license` from the cached packument:
+    /// a denied license is a 403 naming it, a dual license with one acceptable
+    /// branch passes.
+    #[tokio::test]
+    async fn test_npm_tarball_blocked_by_license_policy() {
+        use crate::test_helpers::create_test_context_with_config;
+        use axum::http::{Method, StatusCode};
+
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.npm.proxy = None;
+            cfg.curation.mode = crate::config::CurationMode::Enforce;
+            cfg.curation.licenses.enabled = true;
+            cfg.curation.licenses.deny = vec!["GPL-*".to_string()];
+        });
+        let packument = |license: serde_json::Value| serde_json::json!({"versions": {"1.0.0": {"version": "1.0.0", "license": license}}});
+        ctx_seed_metadata(&ctx, "copyleft", &packument("GPL-3.0-only".into())).await;
+        ctx_seed_metadata(&ctx, "dual", &packument("(GPL-3.0-only OR MIT)".into())).await;
+        ctx_seed_metadata(
+            &ctx,
+            "legacy",
+            &packument(serde_json::json!({"type": "GPL-2.0"})),
+        )
+        .await;
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/npm/copyleft/-/copyleft-1.0.0.tgz",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(body["context"]["rule"], "license");
+        assert_eq!(
+            body["context"]["reason"],
+            "copyleft@1.0.0 is licensed GPL-3.0-only; GPL-3.0-only not allowed by the license policy"
+        );
+        let resp = send(&ctx.app, Method::GET, "/npm/legacy/-/legacy-1.0.0.tgz", "").await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let resp = send(&ctx.app, Method::GET, "/npm/dual/-/dual-1.0.0.tgz", "").await;
+        assert_ne!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
     // ── ensure_npm_metadata_cached test helpers ──
 
     async fn ctx_seed_metadata(
@@ -3061,6 +4987,174 @@ mod spec_conformance_tests {
         );
     }
 
+    /// With an imported advisory database, bulk audit is answered locally — internal
+    /// packages included — and the upstream is never contacted.
+    #[tokio::test]
+    async fn test_npm_audit_bulk_from_local_vulndb() {
+        use crate::test_helpers::{body_bytes, create_test_context_with_config, send};
+        use axum::http::{Method, StatusCode};
+        use wiremock::matchers::any;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let upstream = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(200).set_body_string("LEAKED"))
+            .mount(&upstream)
+            .await;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let internal = serde_json::json!({
+            "id": "GHSA-int-0001",
+            "affected": [{
+                "package": {"ecosystem": "npm", "name": "@internal/secret"},
+                "ranges": [{"type": "SEMVER", "events": [{"introduced": "1.0.0"}, {"last_affected": "1.2.0"}]}]
+            }]
+        });
+        let db_path = crate::vulndb::tests::db_with(
+            dir.path(),
+            &[crate::vulndb::tests::lodash_advisory(), internal],
+        );
+        let proxy_url = upstream.uri();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.npm.proxy = Some(proxy_url);
+            cfg.curation.internal_namespaces = vec!["@internal/*".to_string()];
+            cfg.curation.vulnerabilities.enabled = true;
+            cfg.curation.vulnerabilities.db_path = Some(db_path);
+        });
+
+        let resp = send(
+            &ctx.app,
+            Method::POST,
+            "/npm/-/npm/v1/security/advisories/bulk",
+            r#"{"lodash":["4.17.20","4.17.21"],"@internal/secret":["1.1.0"],"left-pad":["1.0.0"]}"#,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let report: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(report.as_object().unwrap().len(), 2, "{report}");
+        let lodash = &report["lodash"][0];
+        assert_eq!(lodash["id"], "GHSA-35jh-r3h4-6jhm");
+        assert_eq!(lodash["severity"], "high");
+        assert_eq!(lodash["vulnerable_versions"], "<4.17.21");
+        let internal = &report["@internal/secret"][0];
+        assert_eq!(internal["vulnerable_versions"], ">=1.0.0 <=1.2.0");
+        assert!(internal.get("severity").is_none(), "unrated: {internal}");
+        assert!(upstream.received_requests().await.unwrap().is_empty());
+    }
+
+    /// npm6 quick audit: a gzipped lockfile is walked and answered with the audit
+    /// report shape, one finding per affected installed version.
+    #[tokio::test]
+    async fn test_npm_audit_quick_from_local_vulndb() {
+        use crate::test_helpers::{body_bytes, create_test_context_with_config, send_with_headers};
+        use axum::http::{Method, StatusCode};
+        use std::io::Write;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let db_path =
+            crate::vulndb::tests::db_with(dir.path(), &[crate::vulndb::tests::lodash_advisory()]);
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.npm.proxy = None;
+            cfg.curation.vulnerabilities.enabled = true;
+            cfg.curation.vulnerabilities.db_path = Some(db_path);
+        });
+
+        let lockfile = serde_json::json!({
+            "name": "app",
+            "version": "1.0.0",
+            "requires": true,
+            "dependencies": {
+                "lodash": {"version": "4.17.20"},
+                "express": {
+                    "version": "4.18.0",
+                    "dependencies": {"lodash": {"version": "4.17.15", "dev": true}}
+                }
+            }
+        });
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gz.write_all(lockfile.to_string().as_bytes()).unwrap();
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::POST,
+            "/npm/-/npm/v1/security/audits/quick",
+            vec![("content-encoding", "gzip")],
+            gz.finish().unwrap(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let report: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        let advisory = &report["advisories"]["GHSA-35jh-r3h4-6jhm"];
+        assert_eq!(advisory["module_name"], "lodash");
+        assert_eq!(advisory["cves"], serde_json::json!(["CVE-2021-23337"]));
+        assert_eq!(
+            advisory["findings"],
+            serde_json::json!([
+                {"version": "4.17.15", "paths": ["express>lodash"]},
+                {"version": "4.17.20", "paths": ["lodash"]}
+            ])
+        );
+        assert_eq!(report["metadata"]["vulnerabilities"]["high"], 2);
+        assert_eq!(report["metadata"]["dependencies"], 2);
+        assert_eq!(report["metadata"]["devDependencies"], 1);
+    }
+
+    /// The local answer needs the filter enabled and npm advisories in the
+    /// database; otherwise audits still go upstream.
+    #[tokio::test]
+    async fn test_npm_audit_forwarded_without_enabled_npm_vulndb() {
+        use crate::test_helpers::{body_bytes, create_test_context_with_config, send};
+        use axum::http::{Method, StatusCode};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let upstream = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/-/npm/v1/security/advisories/bulk"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"lodash":[]}"#))
+            .mount(&upstream)
+            .await;
+
+        let pypi_only = serde_json::json!({
+            "id": "PYSEC-2021-1",
+            "affected": [{
+                "package": {"ecosystem": "PyPI", "name": "django"},
+                "versions": ["1.0"]
+            }]
+        });
+        let npm_dir = tempfile::TempDir::new().unwrap();
+        let pypi_dir = tempfile::TempDir::new().unwrap();
+        for (db_path, enabled) in [
+            (
+                crate::vulndb::tests::db_with(
+                    npm_dir.path(),
+                    &[crate::vulndb::tests::lodash_advisory()],
+                ),
+                false,
+            ),
+            (
+                crate::vulndb::tests::db_with(pypi_dir.path(), &[pypi_only]),
+                true,
+            ),
+        ] {
+            let proxy_url = upstream.uri();
+            let ctx = create_test_context_with_config(move |cfg| {
+                cfg.npm.proxy = Some(proxy_url);
+                cfg.curation.vulnerabilities.enabled = enabled;
+                cfg.curation.vulnerabilities.db_path = Some(db_path);
+            });
+            let resp = send(
+                &ctx.app,
+                Method::POST,
+                "/npm/-/npm/v1/security/advisories/bulk",
+                r#"{"lodash":["4.17.20"]}"#,
+            )
+            .await;
+            assert_eq!(resp.status(), StatusCode::OK);
+            assert_eq!(&body_bytes(resp).await[..], br#"{"lodash":[]}"#);
+        }
+        assert_eq!(upstream.received_requests().await.unwrap().len(), 2);
+    }
+
     /// Review #3 regression: upstream 5xx must surface as 502 (audit-endpoint error),
     /// NOT `200 {}` (which npm renders as a false "0 vulnerabilities").
     #[tokio::test]
@@ -526,6 +526,7 @@ async fn docker_internal_manifest_miss_get_404_not_403() {
             auth: None,
             namespace: None,
             prefix: None,
+            cosign: None,
         }];
     });
     // A tag that does not exist yet in a hosted internal namespace (docker push pre-flight).
@@ -559,6 +560,7 @@ async fn docker_internal_manifest_miss_head_404_not_403() {
             auth: None,
             namespace: None,
             prefix: None,
+            cosign: None,
         }];
     });
     // The HEAD probe docker issues before pushing a new tag — 403 here is fatal to the client.
@@ -586,6 +588,7 @@ async fn docker_internal_referrers_tag_miss_head_404() {
             auth: None,
             namespace: None,
             prefix: None,
+            cosign: None,
         }];
     });
     // OCI referrers fallback tag (sha256-<digest>) for a hosted image with no attestations:
@@ -614,6 +617,7 @@ async fn docker_internal_blob_miss_get_404_and_head_agrees() {
             auth: None,
             namespace: None,
             prefix: None,
+            cosign: None,
         }];
     });
     let digest = "sha256:2222222222222222222222222222222222222222222222222222222222222222";
@@ -10,11 +10,21 @@
 //!   GET /nuget/v3/flatcontainer/{id}/{ver}/{filename}.nupkg — package download (immutable)
 //!   GET /nuget/v3/flatcontainer/{id}/{ver}/{filename}.nuspec — package spec (immutable)
 //!
+//! and hosted publishing (the PackagePublish resource):
+//!   PUT    /nuget/api/v2/package — `dotnet nuget push` (multipart .nupkg)
+//!   DELETE /nuget/api/v2/package/{id}/{version} — `dotnet nuget delete` (unlist)
+//!
+//! Pushed versions are recorded under `nuget/hosted/`; the version list,
+//! registration index and search results are generated from those records and
+//! merged with upstream's.
+//!
 //! Client config:
 //!   dotnet nuget add source http://nora:4000/nuget/v3/index.json -n nora
+//!   dotnet nuget push pkg.nupkg -s nora -k nra_…
 
 use crate::activity_log::{ActionType, ActivityEntry};
 use crate::audit::AuditEntry;
+use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
 use crate::registry::{
     circuit_open_response, nora_base_url, proxy_fetch, proxy_fetch_conditional, proxy_fetch_text,
     read_validators, write_validators, ProxyError, Revalidation, Validators,
@@ -25,13 +35,15 @@ use crate::validation::ends_with_ci;
 use crate::AppState;
 use axum::{
     body::Bytes,
-    extract::{Path, Query, State},
+    extract::{Multipart, Path, Query, State},
     http::{header, HeaderMap, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
-    Router,
+    routing::{delete, get, put},
+    Extension, Router,
 };
-use serde::Deserialize;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::Digest;
 use std::time::Duration;
 
 const UPSTREAM_DEFAULT: &str = "https://api.nuget.org";
@@ -197,6 +209,13 @@ fn routes_with_prefix(prefix: &str) -> Router<AppState> {
             get(flatcontainer_handler),
         )
         .route(&format!("/{prefix}/v3/{{*path}}"), get(nuget_catchall))
+        // PackagePublish: the client appends a slash to the advertised URL.
+        .route(&format!("/{prefix}/api/v2/package"), put(push_package))
+        .route(&format!("/{prefix}/api/v2/package/"), put(push_package))
+        .route(
+            &format!("/{prefix}/api/v2/package/{{id}}/{{version}}"),
+            delete(unlist_package),
+        )
 }
 
 // ── Service index ──────────────────────────────────────────────────────
@@ -290,9 +309,29 @@ async fn search_query(
                 crate::registry_type::RegistryType::Nuget,
                 "PROXY",
             ));
-            with_json(rewritten.into_bytes())
+            let merged = merge_hosted_search(
+                &state,
+                rewritten,
+                &query,
+                skip,
+                prerelease,
+                sem_ver_level.as_deref(),
+            )
+            .await;
+            with_json(merged)
+        }
+        Err(ProxyError::NotFound) => {
+            let merged = merge_hosted_search(
+                &state,
+                r#"{"totalHits":0,"data":[]}"#.to_string(),
+                &query,
+                skip,
+                prerelease,
+                sem_ver_level.as_deref(),
+            )
+            .await;
+            with_json(merged)
         }
-        Err(ProxyError::NotFound) => with_json(br#"{"totalHits":0,"data":[]}"#.to_vec()),
         Err(ProxyError::CircuitOpen(_) | ProxyError::Network(_) | ProxyError::Upstream(_)) => {
             if !state.config.nuget.serve_stale {
                 return StatusCode::SERVICE_UNAVAILABLE.into_response();
@@ -416,6 +455,33 @@ async fn registration_index(
     if !is_valid_package_id(&id_lower) {
         return StatusCode::BAD_REQUEST.into_response();
     }
+    let hosted = hosted_versions(&state.storage, &id_lower).await;
+    if hosted.is_empty() {
+        return upstream_registration_index(State(state), headers, Path(id)).await;
+    }
+
+    // Pushed versions are merged into whatever upstream has, and served on their
+    // own when upstream has nothing (or is blocked or unreachable).
+    let response = upstream_registration_index(State(state.clone()), headers, Path(id)).await;
+    let upstream = if response.status().is_success() {
+        response_json(response).await
+    } else {
+        None
+    };
+    let nora_nuget = format!("{}/nuget", nora_base_url(&state).trim_end_matches('/'));
+    let index = merge_registration(upstream, &nora_nuget, &id_lower, &hosted);
+    with_json_gzip(serde_json::to_vec(&index).unwrap_or_default())
+}
+
+async fn upstream_registration_index(
+    State(state): State<AppState>,
+    headers: axum::http::HeaderMap,
+    Path(id): Path<String>,
+) -> Response {
+    let id_lower = id.to_lowercase();
+    if !is_valid_package_id(&id_lower) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
 
     // Curation check. #733 serve-local: an internal-namespace package is operator-owned — skip
     // curation and serve any local copy below; block the upstream branch separately.
@@ -703,7 +769,14 @@ async fn flatcontainer_handler(
     let parts: Vec<&str> = path.splitn(3, '/').collect();
     match parts.len() {
         2 if parts[1] == "index.json" => version_list(state, parts[0]).await,
-        3 => flatcontainer_download(state, headers, &path, parts[0], parts[1], parts[2]).await,
+        3 => {
+            if let Some(response) =
+                hosted_download(&state, &headers, parts[0], parts[1], parts[2]).await
+            {
+                return response;
+            }
+            flatcontainer_download(state, headers, &path, parts[0], parts[1], parts[2]).await
+        }
         _ => StatusCode::NOT_FOUND.into_response(),
     }
 }
@@ -711,6 +784,43 @@ async fn flatcontainer_handler(
 // ── Version list ───────────────────────────────────────────────────────
 
 async fn version_list(state: AppState, id: &str) -> Response {
+    let id_lower = id.to_lowercase();
+    if !is_valid_package_id(&id_lower) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let hosted = hosted_versions(&state.storage, &id_lower).await;
+    if hosted.is_empty() {
+        return upstream_version_list(state, id).await;
+    }
+
+    // Unlisted versions stay in the list, as on nuget.org: restore still needs them.
+    let response = upstream_version_list(state, id).await;
+    let mut versions: Vec<String> = if response.status().is_success() {
+        response_json(response)
+            .await
+            .and_then(|index| {
+                index["versions"].as_array().map(|versions| {
+                    versions
+                        .iter()
+                        .filter_map(|v| v.as_str().map(str::to_lowercase))
+                        .collect()
+                })
+            })
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    for pkg in &hosted {
+        let version = pkg.nuspec.version.to_lowercase();
+        if !versions.contains(&version) {
+            versions.push(version);
+        }
+    }
+    versions.sort_by(|a, b| cmp_nuget_versions(a, b));
+    with_json(serde_json::to_vec(&serde_json::json!({ "versions": versions })).unwrap_or_default())
+}
+
+async fn upstream_version_list(state: AppState, id: &str) -> Response {
     let id = id.to_string();
     let id_lower = id.to_lowercase();
     if !is_valid_package_id(&id_lower) {
@@ -893,7 +1003,12 @@ async fn flatcontainer_download(
         )
         .await;
 
-        if let Some(response) = crate::curation::check_download(
+        let license = if crate::curation::license_policy_active(&state.curation().curation_engine) {
+            nuget_declared_license(&state, &id_lower, ver).await
+        } else {
+            None
+        };
+        if let Some(response) = crate::curation::check_download_with_license(
             &state.curation().curation_engine,
             state.bypass_token().as_deref(),
             &headers,
@@ -901,6 +1016,7 @@ async fn flatcontainer_download(
             &id_lower,
             Some(ver),
             publish_date,
+            license.as_deref(),
         ) {
             return response;
         }
@@ -1154,6 +1270,898 @@ async fn flatcontainer_download(
     }
 }
 
+// ── Hosted publishing ──────────────────────────────────────────────────
+
+/// Upper bound on the `.nuspec` read out of a pushed package.
+const MAX_NUSPEC_SIZE: u64 = 1024 * 1024;
+/// The `published` date nuget.org reports for an unlisted version.
+const UNLISTED_PUBLISHED: &str = "1900-01-01T00:00:00+00:00";
+
+/// PUT /nuget/api/v2/package — `dotnet nuget push`.
+///
+/// The `.nupkg` arrives as the single file part of a multipart body. It is stored
+/// where the flat container serves it, with its `.nuspec` beside it, plus a record
+/// under `nuget/hosted/` that the version list, registration and search are
+/// generated from.
+async fn push_package(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    mut multipart: Multipart,
+) -> Response {
+    let mut package: Option<Bytes> = None;
+    while let Ok(Some(field)) = multipart.next_field().await {
+        match field.bytes().await {
+            Ok(data) if package.is_none() && !data.is_empty() => package = Some(data),
+            Ok(_) => {}
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    format!("Failed to read package: {}", e),
+                )
+                    .into_response()
+            }
+        }
+    }
+    let Some(package) = package else {
+        return (StatusCode::BAD_REQUEST, "Missing package file").into_response();
+    };
+
+    let (mut nuspec, raw_nuspec) = match read_nuspec(&package) {
+        Ok(parsed) => parsed,
+        Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
+    };
+    if !is_valid_package_id(&nuspec.id) {
+        return (StatusCode::BAD_REQUEST, "Invalid package id").into_response();
+    }
+    let Some(version) = normalize_version(&nuspec.version) else {
+        return (StatusCode::BAD_REQUEST, "Invalid package version").into_response();
+    };
+    nuspec.version = version;
+    let id_lower = nuspec.id.to_lowercase();
+    let ver_lower = nuspec.version.to_lowercase();
+
+    // Enforce OIDC namespace_scope on the package id (#583).
+    if enforce_namespace_scope(&authority, &id_lower).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    let lock = state.publish_lock(&format!("nuget/{}", id_lower));
+    let _guard = lock.lock().await;
+
+    // Versions are immutable — including one already cached from upstream, and
+    // one that was pushed and later unlisted.
+    let nupkg_key = format!(
+        "nuget/flatcontainer/{}/{}/{}.{}.nupkg",
+        id_lower, ver_lower, id_lower, ver_lower
+    );
+    let record_key = hosted_record_key(&id_lower, &ver_lower);
+    if state.storage.stat(&nupkg_key).await.is_some()
+        || state.storage.stat(&record_key).await.is_some()
+    {
+        return (
+            StatusCode::CONFLICT,
+            format!(
+                "Package {} {} already exists and cannot be modified",
+                nuspec.id, nuspec.version
+            ),
+        )
+            .into_response();
+    }
+
+    let record = HostedPackage {
+        package_hash: base64::engine::general_purpose::STANDARD
+            .encode(sha2::Sha512::digest(&package)),
+        package_size: package.len() as u64,
+        published: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        listed: true,
+        nuspec,
+    };
+
+    // Package and manifest first; the record is what makes the version visible.
+    let nuspec_key = format!(
+        "nuget/flatcontainer/{}/{}/{}.nuspec",
+        id_lower, ver_lower, id_lower
+    );
+    let stored = state.storage.put(&nupkg_key, &package).await.is_ok()
+        && state.storage.put(&nuspec_key, &raw_nuspec).await.is_ok()
+        && state
+            .storage
+            .put(
+                &record_key,
+                &serde_json::to_vec(&record).unwrap_or_default(),
+            )
+            .await
+            .is_ok();
+    if !stored {
+        for k in [&record_key, &nuspec_key, &nupkg_key] {
+            let _ = state.storage.delete(k).await;
+        }
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    let artifact = format!("{}/{}", id_lower, ver_lower);
+    state.metrics.record_upload("nuget");
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &artifact, "nuget", ""));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        artifact,
+        crate::registry_type::RegistryType::Nuget,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("nuget");
+
+    StatusCode::CREATED.into_response()
+}
+
+/// DELETE /nuget/api/v2/package/{id}/{version} — `dotnet nuget delete`.
+///
+/// Like nuget.org, this unlists: the version drops out of search and registration
+/// listings but stays downloadable, so existing lock files keep restoring.
+async fn unlist_package(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Path((id, version)): Path<(String, String)>,
+) -> Response {
+    let id_lower = id.to_lowercase();
+    let Some(ver_lower) = normalize_version(&version).map(|v| v.to_lowercase()) else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+    if !is_valid_package_id(&id_lower) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    if enforce_namespace_scope(&authority, &id_lower).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    let lock = state.publish_lock(&format!("nuget/{}", id_lower));
+    let _guard = lock.lock().await;
+
+    let Some(mut record) = hosted_record(&state.storage, &id_lower, &ver_lower).await else {
+        return (StatusCode::NOT_FOUND, "Package not found").into_response();
+    };
+    if !record.listed {
+        return StatusCode::NO_CONTENT.into_response();
+    }
+    record.listed = false;
+    if state
+        .storage
+        .put(
+            &hosted_record_key(&id_lower, &ver_lower),
+            &serde_json::to_vec(&record).unwrap_or_default(),
+        )
+        .await
+        .is_err()
+    {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    let artifact = format!("{}/{}", id_lower, ver_lower);
+    state
+        .audit
+        .log(AuditEntry::new("unlist", "api", &artifact, "nuget", ""));
+    state.repo_index.invalidate("nuget");
+
+    StatusCode::NO_CONTENT.into_response()
+}
+
+/// Serve a hosted `.nupkg`/`.nuspec`, or `None` when `{id}/{ver}` was not pushed here.
+///
+/// Pushed packages are operator-owned: no curation or quarantine gate, and a
+/// missing file is a 404 rather than an upstream fetch.
+async fn hosted_download(
+    state: &AppState,
+    headers: &HeaderMap,
+    id: &str,
+    ver: &str,
+    filename: &str,
+) -> Option<Response> {
+    let id_lower = id.to_lowercase();
+    let ver_lower = ver.to_lowercase();
+    if !is_valid_package_id(&id_lower) || !is_valid_version(&ver_lower) {
+        return None;
+    }
+    hosted_record(&state.storage, &id_lower, &ver_lower).await?;
+
+    let filename = filename.to_lowercase();
+    let (storage_key, content_type) = if filename == format!("{}.{}.nupkg", id_lower, ver_lower) {
+        (
+            format!(
+                "nuget/flatcontainer/{}/{}/{}",
+                id_lower, ver_lower, filename
+            ),
+            "application/octet-stream",
+        )
+    } else if filename == format!("{}.nuspec", id_lower) {
+        (
+            format!(
+                "nuget/flatcontainer/{}/{}/{}",
+                id_lower, ver_lower, filename
+            ),
+            "application/xml",
+        )
+    } else {
+        return Some(StatusCode::NOT_FOUND.into_response());
+    };
+    let Ok(data) = state.storage.get(&storage_key).await else {
+        return Some(StatusCode::NOT_FOUND.into_response());
+    };
+
+    state.metrics.record_download("nuget");
+    state.metrics.record_cache_hit("nuget");
+    state.activity.push(ActivityEntry::new(
+        ActionType::Pull,
+        format!("{}/{}", id_lower, filename),
+        crate::registry_type::RegistryType::Nuget,
+        "LOCAL",
+    ));
+
+    let nupkg = content_type == "application/octet-stream";
+    if nupkg {
+        if let Some(response) = crate::registry::range::range_response(
+            &state.storage,
+            &[&storage_key],
+            headers,
+            data.len() as u64,
+            content_type,
+            &[(
+                header::CACHE_CONTROL,
+                "public, max-age=31536000, immutable".to_string(),
+            )],
+        )
+        .await
+        {
+            return Some(response);
+        }
+    }
+    let mut response = (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::CACHE_CONTROL, "public, max-age=31536000, immutable"),
+        ],
+        data.to_vec(),
+    )
+        .into_response();
+    if nupkg {
+        response
+            .headers_mut()
+            .insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    }
+    Some(response)
+}
+
+/// The `<metadata>` of a pushed package's `.nuspec` that NORA re-serves.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct Nuspec {
+    id: String,
+    version: String,
+    authors: String,
+    description: String,
+    title: String,
+    summary: String,
+    tags: Vec<String>,
+    project_url: String,
+    license_expression: String,
+    license_url: String,
+    dependency_groups: Vec<DependencyGroup>,
+}
+
+/// A `<group>` under `<dependencies>`; `target_framework` is `None` for the
+/// framework-agnostic group (and for a flat, group-less dependency list).
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct DependencyGroup {
+    target_framework: Option<String>,
+    dependencies: Vec<Dependency>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct Dependency {
+    id: String,
+    range: String,
+}
+
+/// Find the `.nuspec` at the root of a `.nupkg` and parse it.
+fn read_nuspec(nupkg: &[u8]) -> Result<(Nuspec, Vec<u8>), String> {
+    use std::io::Read;
+
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(nupkg))
+        .map_err(|e| format!("Package is not a valid .nupkg: {}", e))?;
+    let index = (0..archive.len())
+        .find(|&i| {
+            archive.by_index_raw(i).is_ok_and(|entry| {
+                !entry.name().contains('/') && ends_with_ci(entry.name(), ".nuspec")
+            })
+        })
+        .ok_or_else(|| "Package has no .nuspec at its root".to_string())?;
+    let mut entry = archive.by_index(index).map_err(|e| e.to_string())?;
+    if entry.size() > MAX_NUSPEC_SIZE {
+        return Err(format!(".nuspec exceeds {} bytes", MAX_NUSPEC_SIZE));
+    }
+    let mut raw = Vec::new();
+    entry
+        .by_ref()
+        .take(MAX_NUSPEC_SIZE + 1)
+        .read_to_end(&mut raw)
+        .map_err(|e| e.to_string())?;
+    Ok((parse_nuspec(&raw)?, raw))
+}
+
+fn parse_nuspec(xml: &[u8]) -> Result<Nuspec, String> {
+    use quick_xml::{events::Event, Reader, XmlVersion};
+
+    let attr = |element: &quick_xml::events::BytesStart, name: &str| -> Option<String> {
+        let value = element.try_get_attribute(name).ok()??;
+        Some(
+            value
+                .normalized_value(XmlVersion::Implicit1_0)
+                .ok()?
+                .trim()
+                .to_string(),
+        )
+    };
+
+    let mut reader = Reader::from_reader(xml);
+    reader.config_mut().trim_text(true);
+    let mut spec = Nuspec::default();
+    // Element names from the root down; text fields are read whole below.
+    let mut path: Vec<Vec<u8>> = Vec::new();
+    loop {
+        let (element, empty) = match reader.read_event() {
+            Ok(Event::Start(element)) => (element, false),
+            Ok(Event::Empty(element)) => (element, true),
+            Ok(Event::End(_)) => {
+                path.pop();
+                continue;
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(format!("Invalid .nuspec: {}", e)),
+            _ => continue,
+        };
+        let name = element.local_name().as_ref().to_vec();
+        let parent = path.last().map(|p| p.as_slice());
+        let in_metadata = path.len() == 2 && parent == Some(b"metadata".as_slice());
+        match name.as_slice() {
+            b"group" if parent == Some(b"dependencies".as_slice()) => {
+                spec.dependency_groups.push(DependencyGroup {
+                    target_framework: attr(&element, "targetFramework").filter(|t| !t.is_empty()),
+                    dependencies: Vec::new(),
+                });
+            }
+            b"dependency"
+                if matches!(parent, Some(b"group") | Some(b"dependencies")) && path.len() >= 3 =>
+            {
+                let id = attr(&element, "id").unwrap_or_default();
+                if !id.is_empty() {
+                    if parent == Some(b"dependencies".as_slice())
+                        && spec.dependency_groups.is_empty()
+                    {
+                        spec.dependency_groups.push(DependencyGroup::default());
+                    }
+                    if let Some(group) = spec.dependency_groups.last_mut() {
+                        let version = attr(&element, "version").unwrap_or_default();
+                        group.dependencies.push(Dependency {
+                            id,
+                            range: dependency_range(&version),
+                        });
+                    }
+                }
+            }
+            _ if in_metadata && !empty => {
+                let is_expression = name.as_slice() == b"license"
+                    && attr(&element, "type").as_deref() == Some("expression");
+                let field = match name.as_slice() {
+                    b"id" => Some(&mut spec.id),
+                    b"version" => Some(&mut spec.version),
+                    b"authors" => Some(&mut spec.authors),
+                    b"description" => Some(&mut spec.description),
+                    b"title" => Some(&mut spec.title),
+                    b"summary" => Some(&mut spec.summary),
+                    b"projectUrl" => Some(&mut spec.project_url),
+                    b"license" if is_expression => Some(&mut spec.license_expression),
+                    b"licenseUrl" => Some(&mut spec.license_url),
+                    b"tags" => {
+                        let tags = element_text(&mut reader, &element)?;
+                        spec.tags = tags.split_whitespace().map(str::to_string).collect();
+                        continue;
+                    }
+                    _ => None,
+                };
+                if let Some(field) = field {
+                    *field = element_text(&mut reader, &element)?;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        if !empty {
+            path.push(name);
+        }
+    }
+
+    if spec.id.is_empty() {
+        return Err(".nuspec has no <id>".to_string());
+    }
+    if spec.version.is_empty() {
+        return Err(".nuspec has no <version>".to_string());
+    }
+    Ok(spec)
+}
+
+/// The unescaped text of `element`, consuming its end tag.
+fn element_text(
+    reader: &mut quick_xml::Reader<&[u8]>,
+    element: &quick_xml::events::BytesStart,
+) -> Result<String, String> {
+    let invalid = |e: &dyn std::fmt::Display| format!("Invalid .nuspec: {}", e);
+    let text = reader.read_text(element.name()).map_err(|e| invalid(&e))?;
+    let text = text.decode().map_err(|e| invalid(&e))?;
+    Ok(quick_xml::escape::unescape(&text)
+        .map_err(|e| invalid(&e))?
+        .trim()
+        .to_string())
+}
+
+/// A `<dependency version>` as a registration `range`: a bare version means
+/// "at least", `[1.0.0, )`; interval notation passes through.
+fn dependency_range(version: &str) -> String {
+    if version.is_empty() {
+        "(, )".to_string()
+    } else if version.starts_with('[') || version.starts_with('(') {
+        version.to_string()
+    } else {
+        format!("[{}, )", version)
+    }
+}
+
+/// Normalize a NuGet version as nuget.org does: two to four numeric parts with
+/// leading zeros dropped, padded to three, a zero fourth part removed, and build
+/// metadata stripped (`1.01` → `1.1.0`, `1.0.0.0` → `1.0.0`, `1.0+sha` → `1.0.0`).
+fn normalize_version(version: &str) -> Option<String> {
+    let version = version.trim();
+    let version = version.split_once('+').map_or(version, |(v, _)| v);
+    let (release, pre) = match version.split_once('-') {
+        Some((release, pre)) => (release, Some(pre)),
+        None => (version, None),
+    };
+    let mut parts = release
+        .split('.')
+        .map(|p| {
+            (!p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+                .then(|| p.parse::<u64>().ok())
+                .flatten()
+        })
+        .collect::<Option<Vec<u64>>>()?;
+    if parts.is_empty() || parts.len() > 4 {
+        return None;
+    }
+    while parts.len() < 3 {
+        parts.push(0);
+    }
+    if parts.len() == 4 && parts[3] == 0 {
+        parts.pop();
+    }
+    let mut normalized = parts
+        .iter()
+        .map(u64::to_string)
+        .collect::<Vec<_>>()
+        .join(".");
+    if let Some(pre) = pre {
+        let valid = pre.split('.').all(|label| {
+            !label.is_empty()
+                && label
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        });
+        if !valid {
+            return None;
+        }
+        normalized.push('-');
+        normalized.push_str(pre);
+    }
+    Some(normalized)
+}
+
+/// Order NuGet versions: numeric parts, then a release above its pre-releases,
+/// with pre-release labels compared case-insensitively (numeric labels numerically).
+pub(crate) fn cmp_nuget_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    fn split(version: &str) -> (Vec<u64>, Option<String>) {
+        let version = version.split_once('+').map_or(version, |(v, _)| v);
+        let (release, pre) = match version.split_once('-') {
+            Some((release, pre)) => (release, Some(pre.to_lowercase())),
+            None => (version, None),
+        };
+        let mut parts: Vec<u64> = release.split('.').map(|p| p.parse().unwrap_or(0)).collect();
+        parts.resize(4, 0);
+        (parts, pre)
+    }
+
+    let (a_parts, a_pre) = split(a);
+    let (b_parts, b_pre) = split(b);
+    a_parts.cmp(&b_parts).then_with(|| match (a_pre, b_pre) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => {
+            let mut a_labels = a.split('.');
+            let mut b_labels = b.split('.');
+            loop {
+                match (a_labels.next(), b_labels.next()) {
+                    (None, None) => return Ordering::Equal,
+                    (None, Some(_)) => return Ordering::Less,
+                    (Some(_), None) => return Ordering::Greater,
+                    (Some(x), Some(y)) => {
+                        let ord = match (x.parse::<u64>(), y.parse::<u64>()) {
+                            (Ok(x), Ok(y)) => x.cmp(&y),
+                            (Ok(_), Err(_)) => Ordering::Less,
+                            (Err(_), Ok(_)) => Ordering::Greater,
+                            (Err(_), Err(_)) => x.cmp(y),
+                        };
+                        if ord != Ordering::Equal {
+                            return ord;
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// A pushed version: its manifest plus what the registration reports about the file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HostedPackage {
+    nuspec: Nuspec,
+    /// Base64 SHA-512 of the `.nupkg`, as nuget.org reports `packageHash`.
+    package_hash: String,
+    package_size: u64,
+    published: String,
+    listed: bool,
+}
+
+fn hosted_record_key(id_lower: &str, ver_lower: &str) -> String {
+    format!("nuget/hosted/{}/{}.json", id_lower, ver_lower)
+}
+
+async fn hosted_record(
+    storage: &crate::storage::Storage,
+    id_lower: &str,
+    ver_lower: &str,
+) -> Option<HostedPackage> {
+    let data = storage
+        .get(&hosted_record_key(id_lower, ver_lower))
+        .await
+        .ok()?;
+    serde_json::from_slice(&data).ok()
+}
+
+/// Every pushed version of `id_lower` (listed or not), oldest first.
+async fn hosted_versions(storage: &crate::storage::Storage, id_lower: &str) -> Vec<HostedPackage> {
+    hosted_records(storage, &format!("nuget/hosted/{}/", id_lower)).await
+}
+
+/// Every pushed version of every package, grouped by id, oldest first.
+async fn hosted_packages(storage: &crate::storage::Storage) -> Vec<Vec<HostedPackage>> {
+    let mut packages: std::collections::BTreeMap<String, Vec<HostedPackage>> =
+        std::collections::BTreeMap::new();
+    for record in hosted_records(storage, "nuget/hosted/").await {
+        packages
+            .entry(record.nuspec.id.to_lowercase())
+            .or_default()
+            .push(record);
+    }
+    packages.into_values().collect()
+}
+
+async fn hosted_records(storage: &crate::storage::Storage, prefix: &str) -> Vec<HostedPackage> {
+    let mut records = Vec::new();
+    for key in storage.list(prefix).await.unwrap_or_default() {
+        if !key.ends_with(".json") {
+            continue;
+        }
+        if let Ok(data) = storage.get(&key).await {
+            if let Ok(record) = serde_json::from_slice::<HostedPackage>(&data) {
+                records.push(record);
+            }
+        }
+    }
+    records.sort_by(|a, b| cmp_nuget_versions(&a.nuspec.version, &b.nuspec.version));
+    records
+}
+
+/// Read a JSON body back out of one of this module's responses (gzip-aware).
+async fn response_json(response: Response) -> Option<serde_json::Value> {
+    let gzip = response
+        .headers()
+        .get(header::CONTENT_ENCODING)
+        .is_some_and(|v| v == "gzip");
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .ok()?;
+    if gzip {
+        let mut data = Vec::new();
+        std::io::Read::read_to_end(&mut flate2::read::GzDecoder::new(&body[..]), &mut data).ok()?;
+        serde_json::from_slice(&data).ok()
+    } else {
+        serde_json::from_slice(&body).ok()
+    }
+}
+
+/// Registration leaf for one hosted version, shaped like nuget.org's inline items.
+fn registration_leaf(nora_nuget: &str, pkg: &HostedPackage) -> serde_json::Value {
+    let spec = &pkg.nuspec;
+    let id_lower = spec.id.to_lowercase();
+    let ver_lower = spec.version.to_lowercase();
+    let leaf = format!(
+        "{}/v3/registration/{}/{}.json",
+        nora_nuget, id_lower, ver_lower
+    );
+    let content = format!(
+        "{}/v3/flatcontainer/{}/{}/{}.{}.nupkg",
+        nora_nuget, id_lower, ver_lower, id_lower, ver_lower
+    );
+    let groups: Vec<serde_json::Value> = spec
+        .dependency_groups
+        .iter()
+        .map(|group| {
+            let dependencies: Vec<serde_json::Value> = group
+                .dependencies
+                .iter()
+                .map(|dep| {
+                    serde_json::json!({
+                        "@type": "PackageDependency",
+                        "id": dep.id,
+                        "range": dep.range,
+                        "registration": format!(
+                            "{}/v3/registration/{}/index.json",
+                            nora_nuget,
+                            dep.id.to_lowercase()
+                        ),
+                    })
+                })
+                .collect();
+            let mut group_json = serde_json::json!({
+                "@type": "PackageDependencyGroup",
+                "dependencies": dependencies,
+            });
+            if let Some(framework) = &group.target_framework {
+                group_json["targetFramework"] = serde_json::json!(framework);
+            }
+            group_json
+        })
+        .collect();
+    let published = if pkg.listed {
+        pkg.published.as_str()
+    } else {
+        UNLISTED_PUBLISHED
+    };
+
+    serde_json::json!({
+        "@id": leaf,
+        "@type": "Package",
+        "commitTimeStamp": pkg.published,
+        "catalogEntry": {
+            "@id": leaf,
+            "@type": "PackageDetails",
+            "id": spec.id,
+            "version": spec.version,
+            "authors": spec.authors,
+            "description": spec.description,
+            "title": spec.title,
+            "summary": spec.summary,
+            "tags": spec.tags,
+            "projectUrl": spec.project_url,
+            "licenseExpression": spec.license_expression,
+            "listed": pkg.listed,
+            "published": published,
+            "packageHash": pkg.package_hash,
+            "packageHashAlgorithm": "SHA512",
+            "packageSize": pkg.package_size,
+            "dependencyGroups": groups,
+            "packageContent": content,
+        },
+        "packageContent": content,
+        "registration": format!("{}/v3/registration/{}/index.json", nora_nuget, id_lower),
+    })
+}
+
+/// Merge hosted versions into an upstream registration index as one extra inline
+/// page, or build the index from them alone when upstream has none. A hosted
+/// version replaces the same version in the upstream's inline pages.
+fn merge_registration(
+    upstream: Option<serde_json::Value>,
+    nora_nuget: &str,
+    id_lower: &str,
+    hosted: &[HostedPackage],
+) -> serde_json::Value {
+    let hosted_versions: Vec<String> = hosted
+        .iter()
+        .map(|pkg| pkg.nuspec.version.to_lowercase())
+        .collect();
+    let index_url = format!("{}/v3/registration/{}/index.json", nora_nuget, id_lower);
+    let mut index = upstream
+        .filter(|v| v.get("items").is_some_and(|i| i.is_array()))
+        .unwrap_or_else(|| serde_json::json!({ "@id": index_url, "count": 0, "items": [] }));
+
+    let mut pages = index["items"].as_array().cloned().unwrap_or_default();
+    pages.retain_mut(|page| {
+        let Some(leaves) = page.get_mut("items").and_then(|i| i.as_array_mut()) else {
+            return true; // non-inline page: fetched separately, left as-is
+        };
+        leaves.retain(|leaf| {
+            let version = leaf["catalogEntry"]["version"].as_str().unwrap_or_default();
+            let normalized = normalize_version(version).unwrap_or_else(|| version.to_string());
+            !hosted_versions.contains(&normalized.to_lowercase())
+        });
+        let count = leaves.len();
+        page["count"] = serde_json::json!(count);
+        count > 0
+    });
+
+    let lower = hosted
+        .first()
+        .map(|p| p.nuspec.version.as_str())
+        .unwrap_or("");
+    let upper = hosted
+        .last()
+        .map(|p| p.nuspec.version.as_str())
+        .unwrap_or("");
+    let leaves: Vec<serde_json::Value> = hosted
+        .iter()
+        .map(|pkg| registration_leaf(nora_nuget, pkg))
+        .collect();
+    pages.push(serde_json::json!({
+        "@id": format!("{}#page/{}/{}", index_url, lower, upper),
+        "@type": "catalog:CatalogPage",
+        "count": leaves.len(),
+        "lower": lower,
+        "upper": upper,
+        "parent": index_url,
+        "items": leaves,
+    }));
+    index["count"] = serde_json::json!(pages.len());
+    index["items"] = serde_json::Value::Array(pages);
+    index
+}
+
+/// Search-result entry for a hosted package from its visible versions (oldest first).
+fn hosted_search_entry(nora_nuget: &str, versions: &[&HostedPackage]) -> serde_json::Value {
+    let latest = versions.last().map(|pkg| &pkg.nuspec);
+    let spec = latest.cloned().unwrap_or_default();
+    let id_lower = spec.id.to_lowercase();
+    let version_entries: Vec<serde_json::Value> = versions
+        .iter()
+        .map(|pkg| {
+            serde_json::json!({
+                "version": pkg.nuspec.version,
+                "downloads": 0,
+                "@id": format!(
+                    "{}/v3/registration/{}/{}.json",
+                    nora_nuget,
+                    id_lower,
+                    pkg.nuspec.version.to_lowercase()
+                ),
+            })
+        })
+        .collect();
+    let authors: Vec<&str> = spec
+        .authors
+        .split(',')
+        .map(str::trim)
+        .filter(|a| !a.is_empty())
+        .collect();
+
+    serde_json::json!({
+        "id": spec.id,
+        "version": spec.version,
+        "versions": version_entries,
+        "description": spec.description,
+        "summary": spec.summary,
+        "title": spec.title,
+        "authors": authors,
+        "tags": spec.tags,
+        "projectUrl": spec.project_url,
+        "licenseUrl": "",
+        "totalDownloads": 0,
+        "verified": false,
+        "packageTypes": [{"name": "Dependency"}],
+        "registration": format!("{}/v3/registration/{}/index.json", nora_nuget, id_lower),
+    })
+}
+
+/// Add hosted packages matching `query` to an upstream search result.
+///
+/// A package upstream also has gains the hosted versions; any other match is
+/// listed ahead of the upstream results on the first page and counted in
+/// `totalHits` on every page.
+async fn merge_hosted_search(
+    state: &AppState,
+    upstream: String,
+    query: &str,
+    skip: usize,
+    prerelease: bool,
+    sem_ver_level: Option<&str>,
+) -> Vec<u8> {
+    let hosted = hosted_packages(&state.storage).await;
+    if hosted.is_empty() {
+        return upstream.into_bytes();
+    }
+    let Ok(mut result) = serde_json::from_str::<serde_json::Value>(&upstream) else {
+        return upstream.into_bytes();
+    };
+    let Some(data) = result.get_mut("data").and_then(|d| d.as_array_mut()) else {
+        return upstream.into_bytes();
+    };
+
+    let nora_nuget = format!("{}/nuget", nora_base_url(state).trim_end_matches('/'));
+    let query_lower = query.to_lowercase();
+    let mut added = 0;
+    for versions in &hosted {
+        let Some(first) = versions.first() else {
+            continue;
+        };
+        let id = first.nuspec.id.as_str();
+        if !id.to_lowercase().contains(&query_lower) {
+            continue;
+        }
+        let visible: Vec<&HostedPackage> = versions
+            .iter()
+            .filter(|pkg| pkg.listed)
+            .filter(|pkg| prerelease || !pkg.nuspec.version.contains('-'))
+            .filter(|pkg| {
+                sem_ver_level == Some("2.0.0") || !is_semver2_version(&pkg.nuspec.version)
+            })
+            .collect();
+        if visible.is_empty() {
+            continue;
+        }
+
+        let existing = data.iter_mut().find(|entry| {
+            entry["id"]
+                .as_str()
+                .is_some_and(|other| other.eq_ignore_ascii_case(id))
+        });
+        if let Some(entry) = existing {
+            let hosted_entry = hosted_search_entry(&nora_nuget, &visible);
+            let mut merged = entry["versions"].as_array().cloned().unwrap_or_default();
+            for version in hosted_entry["versions"].as_array().into_iter().flatten() {
+                let v = version["version"].as_str().unwrap_or_default();
+                if !merged.iter().any(|m| {
+                    m["version"]
+                        .as_str()
+                        .is_some_and(|other| other.eq_ignore_ascii_case(v))
+                }) {
+                    merged.push(version.clone());
+                }
+            }
+            merged.sort_by(|a, b| {
+                cmp_nuget_versions(
+                    a["version"].as_str().unwrap_or_default(),
+                    b["version"].as_str().unwrap_or_default(),
+                )
+            });
+            if let Some(latest) = merged.last() {
+                entry["version"] = latest["version"].clone();
+            }
+            entry["versions"] = serde_json::Value::Array(merged);
+        } else {
+            if skip == 0 {
+                data.insert(added, hosted_search_entry(&nora_nuget, &visible));
+            }
+            added += 1;
+        }
+    }
+
+    let total = result["totalHits"].as_u64().unwrap_or(0) + added as u64;
+    result["totalHits"] = serde_json::json!(total);
+    serde_json::to_vec(&result).unwrap_or_else(|_| upstream.into_bytes())
+}
+
 // ── Stale-while-error helper ──────────────────────────────────────────
 
 /// Serve stale cached metadata when upstream is unreachable, or 404 if no cache.
@@ -1248,6 +2256,54 @@ async fn extract_nuget_publish_date(
     None
 }
 
+/// Declared license of a proxied version, for the license filter: the `.nuspec`
+/// `<license type="expression">`, else the expression in a licenses.nuget.org
+/// `<licenseUrl>` (what `nuget pack` writes beside it for older clients). A restore
+/// downloads the `.nupkg` without the `.nuspec`, so a missing one is fetched and
+/// cached here.
+async fn nuget_declared_license(state: &AppState, id_lower: &str, ver: &str) -> Option<String> {
+    let ver_lower = ver.to_lowercase();
+    let key = format!(
+        "nuget/flatcontainer/{}/{}/{}.nuspec",
+        id_lower, ver_lower, id_lower
+    );
+    let raw = match state.storage.get(&key).await {
+        Ok(raw) => raw.to_vec(),
+        Err(_) => {
+            let url = format!(
+                "{}/v3-flatcontainer/{}/{}/{}.nuspec",
+                upstream_url(state).trim_end_matches('/'),
+                id_lower,
+                ver_lower,
+                id_lower
+            );
+            let raw = proxy_fetch(
+                &state.http_client,
+                &url,
+                Duration::from_secs(state.config.nuget.proxy_timeout),
+                expose_opt(&state.config.nuget.proxy_auth),
+                &state.circuit_breaker,
+                RegistryType::Nuget,
+            )
+            .await
+            .ok()?;
+            state.spawn_cache_immutable("nuget", key, Bytes::from(raw.clone()));
+            raw
+        }
+    };
+    let spec = parse_nuspec(&raw).ok()?;
+    if !spec.license_expression.is_empty() {
+        return Some(spec.license_expression);
+    }
+    let expression = spec
+        .license_url
+        .strip_prefix("https://licenses.nuget.org/")?;
+    percent_encoding::percent_decode_str(expression)
+        .decode_utf8()
+        .ok()
+        .map(|e| e.into_owned())
+}
+
 fn upstream_url(state: &AppState) -> String {
     let raw = state
         .config
@@ -1343,25 +2399,34 @@ fn with_json_stale(data: Vec<u8>) -> Response {
 }
 // ── Local search helpers ───────────────────────────────────────────────
 
-/// Read cached version list from flatcontainer index.json.
+/// Read cached version list from flatcontainer index.json, plus listed hosted versions.
 async fn get_cached_versions(storage: &crate::storage::Storage, id: &str) -> Vec<String> {
     let key = format!("nuget/flatcontainer/{}/index.json", id.to_lowercase());
-    let data = match storage.get(&key).await {
-        Ok(d) => d,
-        Err(_) => return Vec::new(),
-    };
-    let json: serde_json::Value = match serde_json::from_slice(&data) {
-        Ok(v) => v,
-        Err(_) => return Vec::new(),
+    let mut versions: Vec<String> = match storage.get(&key).await {
+        Ok(data) => serde_json::from_slice::<serde_json::Value>(&data)
+            .ok()
+            .and_then(|json| {
+                json.get("versions").and_then(|v| v.as_array()).map(|arr| {
+                    arr.iter()
+                        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                        .collect()
+                })
+            })
+            .unwrap_or_default(),
+        Err(_) => Vec::new(),
     };
-    json.get("versions")
-        .and_then(|v| v.as_array())
-        .map(|arr| {
-            arr.iter()
-                .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                .collect()
-        })
-        .unwrap_or_default()
+    let hosted = hosted_versions(storage, &id.to_lowercase()).await;
+    if hosted.is_empty() {
+        return versions;
+    }
+    for pkg in hosted.iter().filter(|pkg| pkg.listed) {
+        let version = pkg.nuspec.version.to_lowercase();
+        if !versions.iter().any(|v| v.eq_ignore_ascii_case(&version)) {
+            versions.push(version);
+        }
+    }
+    versions.sort_by(|a, b| cmp_nuget_versions(a, b));
+    versions
 }
 
 /// Build one NuGet V3 search result entry.
@@ -1535,6 +2600,11 @@ fn generate_service_index(base_url: &str) -> String {
                 "@id": format!("{}/v3/autocomplete", nora_nuget),
                 "@type": "SearchAutocompleteService",
                 "comment": "NuGet autocomplete endpoint"
+            },
+            {
+                "@id": format!("{}/api/v2/package", nora_nuget),
+                "@type": "PackagePublish/2.0.0",
+                "comment": "dotnet nuget push / delete (unlist)"
             }
         ]
     });
@@ -1733,11 +2803,12 @@ mod tests {
         let json: serde_json::Value = serde_json::from_str(&result).unwrap();
         assert_eq!(json["version"], "3.0.0");
         let resources = json["resources"].as_array().unwrap();
-        assert_eq!(resources.len(), 4);
+        assert_eq!(resources.len(), 5);
         assert!(result.contains("http://nora:4000/nuget/v3/flatcontainer/"));
         assert!(result.contains("http://nora:4000/nuget/v3/registration/"));
         assert!(result.contains("http://nora:4000/nuget/v3/query"));
         assert!(result.contains("http://nora:4000/nuget/v3/autocomplete"));
+        assert!(result.contains("http://nora:4000/nuget/api/v2/package"));
     }
 
     #[test]
@@ -1772,6 +2843,7 @@ mod tests {
         assert!(types.contains(&"RegistrationsBaseUrl/3.6.0"));
         assert!(types.contains(&"SearchQueryService"));
         assert!(types.contains(&"SearchAutocompleteService"));
+        assert!(types.contains(&"PackagePublish/2.0.0"));
         // Must NOT include unimplemented resources
         assert!(!types.iter().any(|t| t.contains("Catalog")));
         assert!(!types.iter().any(|t| t.contains("RepositorySignatures")));
@@ -1922,6 +2994,206 @@ mod tests {
             "service index (with_json) must NOT have Content-Encoding header"
         );
     }
+
+    // ── Hosted publishing ─────────────────────────────────────────────
+
+    pub(super) fn nuspec_xml(id: &str, version: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<package xmlns="http://schemas.microsoft.com/packaging/2013/05/nuspec.xsd">
+  <metadata>
+    <id>{id}</id>
+    <version>{version}</version>
+    <authors>Acme Corp, Jane</authors>
+    <description>Widgets &amp; gadgets.</description>
+    <license type="expression">MIT</license>
+    <projectUrl>https://acme.example/widgets</projectUrl>
+    <tags>acme widgets</tags>
+    <dependencies>
+      <group targetFramework="net8.0">
+        <dependency id="Newtonsoft.Json" version="13.0.1" exclude="Build,Analyzers" />
+      </group>
+      <group targetFramework=".NETStandard2.0" />
+    </dependencies>
+  </metadata>
+</package>"#
+        )
+    }
+
+    /// A `.nupkg` as `dotnet pack` lays it out: the `.nuspec` at the root beside
+    /// the OPC parts and the `lib/` payload.
+    pub(super) fn build_nupkg_from(nuspec_name: &str, nuspec: &str) -> Vec<u8> {
+        use std::io::Write;
+        let mut w = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for (name, data) in [
+            ("_rels/.rels", b"<Relationships/>".as_slice()),
+            ("[Content_Types].xml", b"<Types/>".as_slice()),
+            (nuspec_name, nuspec.as_bytes()),
+            ("lib/net8.0/Acme.Widgets.dll", b"MZ".as_slice()),
+        ] {
+            w.start_file(name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            w.write_all(data).unwrap();
+        }
+        w.finish().unwrap().into_inner()
+    }
+
+    pub(super) fn build_nupkg(id: &str, version: &str) -> Vec<u8> {
+        build_nupkg_from(&format!("{}.nuspec", id), &nuspec_xml(id, version))
+    }
+
+    fn hosted(version: &str, listed: bool) -> HostedPackage {
+        HostedPackage {
+            nuspec: parse_nuspec(nuspec_xml("Acme.Widgets", version).as_bytes()).unwrap(),
+            package_hash: "aGFzaA==".to_string(),
+            package_size: 4,
+            published: "2024-01-15T00:00:00Z".to_string(),
+            listed,
+        }
+    }
+
+    #[test]
+    fn test_parse_nuspec() {
+        let spec = parse_nuspec(nuspec_xml("Acme.Widgets", "1.0.0").as_bytes()).unwrap();
+        assert_eq!(spec.id, "Acme.Widgets");
+        assert_eq!(spec.version, "1.0.0");
+        assert_eq!(spec.authors, "Acme Corp, Jane");
+        assert_eq!(spec.description, "Widgets & gadgets.");
+        assert_eq!(spec.license_expression, "MIT");
+        assert_eq!(spec.license_url, "");
+        assert_eq!(spec.tags, vec!["acme", "widgets"]);
+        assert_eq!(
+            spec.dependency_groups,
+            vec![
+                DependencyGroup {
+                    target_framework: Some("net8.0".to_string()),
+                    dependencies: vec![Dependency {
+                        id: "Newtonsoft.Json".to_string(),
+                        range: "[13.0.1, )".to_string(),
+                    }],
+                },
+                DependencyGroup {
+                    target_framework: Some(".NETStandard2.0".to_string()),
+                    dependencies: vec![],
+                },
+            ]
+        );
+
+        // Older nuspecs list dependencies without groups.
+        let flat = parse_nuspec(
+            br#"<package><metadata><id>A</id><version>1.0</version><dependencies>
+                <dependency id="B" version="[1.0,2.0)" /></dependencies></metadata></package>"#,
+        )
+        .unwrap();
+        assert_eq!(flat.dependency_groups.len(), 1);
+        assert_eq!(flat.dependency_groups[0].target_framework, None);
+        assert_eq!(flat.dependency_groups[0].dependencies[0].range, "[1.0,2.0)");
+
+        assert!(
+            parse_nuspec(b"<package><metadata><id>A</id></metadata></package>")
+                .unwrap_err()
+                .contains("version")
+        );
+        assert!(parse_nuspec(b"<package><metadata><id>A</i></metadata>").is_err());
+    }
+
+    #[test]
+    fn test_read_nuspec_from_nupkg() {
+        let (spec, raw) = read_nuspec(&build_nupkg("Acme.Widgets", "1.0.0")).unwrap();
+        assert_eq!(spec.id, "Acme.Widgets");
+        assert_eq!(raw, nuspec_xml("Acme.Widgets", "1.0.0").into_bytes());
+
+        assert!(read_nuspec(b"not a zip").is_err());
+        // A .nuspec below the root is package content, not the manifest.
+        let nested = build_nupkg_from("content/x.nuspec", &nuspec_xml("A", "1.0.0"));
+        assert!(read_nuspec(&nested).unwrap_err().contains("no .nuspec"));
+    }
+
+    #[test]
+    fn test_normalize_version() {
+        assert_eq!(normalize_version("1.0").as_deref(), Some("1.0.0"));
+        assert_eq!(normalize_version("1.01.1").as_deref(), Some("1.1.1"));
+        assert_eq!(normalize_version("1.0.0.0").as_deref(), Some("1.0.0"));
+        assert_eq!(normalize_version("1.2.3.4").as_deref(), Some("1.2.3.4"));
+        assert_eq!(
+            normalize_version("1.0.0-Beta.1+sha.5").as_deref(),
+            Some("1.0.0-Beta.1")
+        );
+        assert_eq!(normalize_version("1.0.0.0.0"), None);
+        assert_eq!(normalize_version("1.x"), None);
+        assert_eq!(normalize_version("1.0.0-"), None);
+        assert_eq!(normalize_version("1.0.0-a..b"), None);
+    }
+
+    #[test]
+    fn test_cmp_nuget_versions() {
+        use std::cmp::Ordering;
+        assert_eq!(cmp_nuget_versions("1.10.0", "1.9.0"), Ordering::Greater);
+        assert_eq!(cmp_nuget_versions("1.0.0-rc.1", "1.0.0"), Ordering::Less);
+        assert_eq!(
+            cmp_nuget_versions("1.0.0-RC.2", "1.0.0-rc.10"),
+            Ordering::Less
+        );
+        assert_eq!(
+            cmp_nuget_versions("1.0.0-alpha", "1.0.0-alpha.1"),
+            Ordering::Less
+        );
+        assert_eq!(cmp_nuget_versions("1.0.0", "1.0.0.0"), Ordering::Equal);
+        assert_eq!(cmp_nuget_versions("1.0.0.1", "1.0.0"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_merge_registration() {
+        let nora = "http://nora:4000/nuget";
+        let upstream = serde_json::json!({
+            "count": 2,
+            "items": [
+                {
+                    "@id": "u#page/0.9.0/1.0.0",
+                    "count": 2,
+                    "items": [
+                        { "catalogEntry": { "version": "0.9.0" } },
+                        { "catalogEntry": { "version": "1.0.0" } }
+                    ]
+                },
+                { "@id": "u/page/2.0.0/3.0.0.json", "count": 64 }
+            ]
+        });
+        let index = merge_registration(
+            Some(upstream),
+            nora,
+            "acme.widgets",
+            &[hosted("1.0.0", true), hosted("1.1.0", false)],
+        );
+        let pages = index["items"].as_array().unwrap();
+        assert_eq!(index["count"], 3);
+        // The hosted 1.0.0 replaces upstream's; the non-inline page is untouched.
+        assert_eq!(pages[0]["count"], 1);
+        assert_eq!(pages[0]["items"][0]["catalogEntry"]["version"], "0.9.0");
+        assert_eq!(pages[1]["count"], 64);
+        let hosted_page = &pages[2];
+        assert_eq!(hosted_page["lower"], "1.0.0");
+        assert_eq!(hosted_page["upper"], "1.1.0");
+        let leaf = &hosted_page["items"][0];
+        assert_eq!(
+            leaf["packageContent"],
+            "http://nora:4000/nuget/v3/flatcontainer/acme.widgets/1.0.0/acme.widgets.1.0.0.nupkg"
+        );
+        assert_eq!(leaf["catalogEntry"]["id"], "Acme.Widgets");
+        assert_eq!(leaf["catalogEntry"]["listed"], true);
+        assert_eq!(
+            leaf["catalogEntry"]["dependencyGroups"][0]["dependencies"][0]["range"],
+            "[13.0.1, )"
+        );
+        // Unlisted versions keep nuget.org's 1900 sentinel date.
+        let unlisted = &hosted_page["items"][1]["catalogEntry"];
+        assert_eq!(unlisted["listed"], false);
+        assert_eq!(unlisted["published"], UNLISTED_PUBLISHED);
+
+        let alone = merge_registration(None, nora, "acme.widgets", &[hosted("1.0.0", true)]);
+        assert_eq!(alone["count"], 1);
+        assert_eq!(alone["items"][0]["count"], 1);
+    }
 }
 
 #[cfg(test)]
@@ -2067,6 +3339,51 @@ mod integration_tests {
         assert_eq!(resp.status(), StatusCode::NOT_FOUND);
     }
 
+    /// The license filter fetches the `.nuspec` a restore never asks for and reads
+    /// its license — here the legacy licenses.nuget.org `<licenseUrl>`.
+    #[tokio::test]
+    async fn test_nupkg_blocked_by_license_policy() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let upstream = MockServer::start().await;
+        let nuspec = super::tests::nuspec_xml("Acme.Copyleft", "1.0.0").replace(
+            r#"<license type="expression">MIT</license>"#,
+            "<licenseUrl>https://licenses.nuget.org/GPL-3.0-only%20OR%20AGPL-3.0-only</licenseUrl>",
+        );
+        Mock::given(method("GET"))
+            .and(path(
+                "/v3-flatcontainer/acme.copyleft/1.0.0/acme.copyleft.nuspec",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_string(nuspec))
+            .expect(1)
+            .mount(&upstream)
+            .await;
+        let proxy_url = upstream.uri();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.nuget.enabled = true;
+            cfg.nuget.proxy = Some(proxy_url);
+            cfg.curation.mode = crate::config::CurationMode::Enforce;
+            cfg.curation.licenses.enabled = true;
+            cfg.curation.licenses.deny = vec!["GPL-*".to_string(), "AGPL-*".to_string()];
+        });
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/nuget/v3/flatcontainer/acme.copyleft/1.0.0/acme.copyleft.1.0.0.nupkg",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(body["context"]["rule"], "license");
+        assert!(body["context"]["reason"]
+            .as_str()
+            .unwrap()
+            .contains("GPL-3.0-only, AGPL-3.0-only not allowed"));
+    }
+
     /// Stale registration index is served with URL rewriting (#409)
     #[tokio::test]
     async fn test_serve_stale_registration_index() {
@@ -2659,6 +3976,411 @@ mod integration_tests {
         );
         assert_eq!(&body_bytes(resp).await[..], nupkg);
     }
+
+    // ── Hosted publishing ─────────────────────────────────────────────
+
+    use super::tests::build_nupkg;
+    use crate::test_helpers::{send_with_headers, TestContext};
+
+    const BOUNDARY: &str = "nora-nuget-boundary";
+
+    /// The multipart body `dotnet nuget push` sends.
+    fn push_body(nupkg: &[u8]) -> Vec<u8> {
+        let mut body = format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"package\"; filename=\"package.nupkg\"\r\nContent-Type: application/octet-stream\r\n\r\n"
+        )
+        .into_bytes();
+        body.extend_from_slice(nupkg);
+        body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+        body
+    }
+
+    async fn push(ctx: &TestContext, nupkg: &[u8]) -> axum::http::Response<axum::body::Body> {
+        let content_type = format!("multipart/form-data; boundary={BOUNDARY}");
+        send_with_headers(
+            &ctx.app,
+            Method::PUT,
+            "/nuget/api/v2/package/",
+            vec![("content-type", content_type.as_str())],
+            push_body(nupkg),
+        )
+        .await
+    }
+
+    /// `dotnet nuget push -k` sends the key as `X-NuGet-ApiKey`.
+    #[tokio::test]
+    async fn test_nuget_push_with_api_key_header() {
+        let ctx = crate::test_helpers::create_test_context_with_auth_and_config(
+            &[("admin", "secret")],
+            |cfg| {
+                cfg.nuget.enabled = true;
+                cfg.nuget.proxy = Some("http://127.0.0.1:1".to_string());
+                cfg.nuget.proxy_timeout = 1;
+                cfg.nuget.metadata_proxy_timeout = 1;
+            },
+        );
+        let token = ctx
+            .state
+            .tokens
+            .as_ref()
+            .unwrap()
+            .create_token("admin", 30, None, crate::tokens::Role::Write)
+            .unwrap();
+        let content_type = format!("multipart/form-data; boundary={BOUNDARY}");
+        for (key, status) in [
+            (token.as_str(), StatusCode::CREATED),
+            ("not-a-token", StatusCode::UNAUTHORIZED),
+        ] {
+            let resp = send_with_headers(
+                &ctx.app,
+                Method::PUT,
+                "/nuget/api/v2/package/",
+                vec![
+                    ("content-type", content_type.as_str()),
+                    ("x-nuget-apikey", key),
+                ],
+                push_body(&build_nupkg("Acme.Widgets", "1.0.0")),
+            )
+            .await;
+            assert_eq!(resp.status(), status, "{key}");
+        }
+    }
+
+    fn hosted_context(upstream: &str) -> TestContext {
+        let upstream = upstream.to_string();
+        create_test_context_with_config(move |cfg| {
+            cfg.nuget.enabled = true;
+            cfg.nuget.search_service = format!("{}/query", upstream);
+            cfg.nuget.proxy = Some(upstream);
+            cfg.nuget.proxy_timeout = 1;
+            cfg.nuget.metadata_proxy_timeout = 1;
+        })
+    }
+
+    async fn json(resp: axum::http::Response<axum::body::Body>) -> serde_json::Value {
+        assert_eq!(resp.status(), StatusCode::OK);
+        let gzip = resp
+            .headers()
+            .get("content-encoding")
+            .is_some_and(|v| v == "gzip");
+        let body = body_bytes(resp).await;
+        let body = if gzip {
+            let mut out = Vec::new();
+            std::io::Read::read_to_end(&mut flate2::read::GzDecoder::new(&body[..]), &mut out)
+                .unwrap();
+            out
+        } else {
+            body.to_vec()
+        };
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_nuget_push_and_restore() {
+        let ctx = hosted_context("http://127.0.0.1:1");
+        let nupkg = build_nupkg("Acme.Widgets", "1.0");
+        let resp = push(&ctx, &nupkg).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let resp = push(&ctx, &build_nupkg("Acme.Widgets", "1.1.0-beta.1")).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        // Versions are normalized and lowercased, as restore requests them.
+        let index = json(
+            send(
+                &ctx.app,
+                Method::GET,
+                "/nuget/v3/flatcontainer/acme.widgets/index.json",
+                "",
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(
+            index["versions"],
+            serde_json::json!(["1.0.0", "1.1.0-beta.1"])
+        );
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/nuget/v3/flatcontainer/acme.widgets/1.0.0/acme.widgets.1.0.0.nupkg",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(body_bytes(resp).await.to_vec(), nupkg);
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/nuget/v3/flatcontainer/acme.widgets/1.0.0/acme.widgets.nuspec",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(String::from_utf8_lossy(&body_bytes(resp).await).contains("<id>Acme.Widgets</id>"));
+
+        let index = json(
+            send(
+                &ctx.app,
+                Method::GET,
+                "/nuget/v3/registration/Acme.Widgets/index.json",
+                "",
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(index["count"], 1);
+        let leaves = index["items"][0]["items"].as_array().unwrap();
+        assert_eq!(leaves.len(), 2);
+        let entry = &leaves[0]["catalogEntry"];
+        assert_eq!(entry["version"], "1.0.0");
+        assert_eq!(entry["packageSize"], nupkg.len());
+        use base64::Engine;
+        use sha2::Digest;
+        assert_eq!(
+            entry["packageHash"],
+            base64::engine::general_purpose::STANDARD.encode(sha2::Sha512::digest(&nupkg))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_nuget_push_existing_version_conflicts() {
+        let ctx = hosted_context("http://127.0.0.1:1");
+        let resp = push(&ctx, &build_nupkg("Acme.Widgets", "1.0.0")).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        // 1.0 normalizes to the same version.
+        let resp = push(&ctx, &build_nupkg("acme.widgets", "1.0")).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        // A version already cached from upstream cannot be shadowed either.
+        ctx.state
+            .storage
+            .put(
+                "nuget/flatcontainer/newtonsoft.json/13.0.1/newtonsoft.json.13.0.1.nupkg",
+                b"upstream",
+            )
+            .await
+            .unwrap();
+        let resp = push(&ctx, &build_nupkg("Newtonsoft.Json", "13.0.1")).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_nuget_push_rejects_invalid_packages() {
+        let ctx = hosted_context("http://127.0.0.1:1");
+        let resp = push(&ctx, b"not a nupkg").await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let resp = push(&ctx, &build_nupkg("Acme/Widgets", "1.0.0")).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let resp = push(&ctx, &build_nupkg("Acme.Widgets", "one")).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert!(ctx.state.storage.list("nuget/").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_nuget_delete_unlists() {
+        let ctx = hosted_context("http://127.0.0.1:1");
+        push(&ctx, &build_nupkg("Acme.Widgets", "1.0.0")).await;
+        push(&ctx, &build_nupkg("Acme.Widgets", "2.0.0")).await;
+
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/nuget/api/v2/package/Acme.Widgets/2.0",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        let resp = send(
+            &ctx.app,
+            Method::DELETE,
+            "/nuget/api/v2/package/Acme.Widgets/3.0.0",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        // Unlisted: still restorable, flagged in the registration, hidden from search.
+        let index = json(
+            send(
+                &ctx.app,
+                Method::GET,
+                "/nuget/v3/flatcontainer/acme.widgets/index.json",
+                "",
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(index["versions"], serde_json::json!(["1.0.0", "2.0.0"]));
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/nuget/v3/flatcontainer/acme.widgets/2.0.0/acme.widgets.2.0.0.nupkg",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let registration = json(
+            send(
+                &ctx.app,
+                Method::GET,
+                "/nuget/v3/registration/acme.widgets/index.json",
+                "",
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(
+            registration["items"][0]["items"][1]["catalogEntry"]["listed"],
+            false
+        );
+
+        let search = json(send(&ctx.app, Method::GET, "/nuget/v3/query?q=acme", "").await).await;
+        assert_eq!(search["data"][0]["version"], "1.0.0");
+        assert_eq!(search["data"][0]["versions"].as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_nuget_hosted_merges_with_upstream() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v3-flatcontainer/acme.widgets/index.json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "versions": ["0.9.0", "1.0.0"] })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v3/registration5-gz-semver2/acme.widgets/index.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "count": 1,
+                "items": [{
+                    "count": 1,
+                    "lower": "0.9.0",
+                    "upper": "0.9.0",
+                    "items": [{ "catalogEntry": { "version": "0.9.0", "listed": true } }]
+                }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/query"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "totalHits": 1,
+                "data": [{
+                    "id": "Acme.Widgets",
+                    "version": "1.0.0",
+                    "versions": [{ "version": "0.9.0" }, { "version": "1.0.0" }]
+                }]
+            })))
+            .mount(&server)
+            .await;
+        let ctx = hosted_context(&server.uri());
+
+        push(&ctx, &build_nupkg("Acme.Widgets", "1.1.0")).await;
+        push(&ctx, &build_nupkg("Acme.Gadgets", "2.0.0")).await;
+
+        let index = json(
+            send(
+                &ctx.app,
+                Method::GET,
+                "/nuget/v3/flatcontainer/acme.widgets/index.json",
+                "",
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(
+            index["versions"],
+            serde_json::json!(["0.9.0", "1.0.0", "1.1.0"])
+        );
+
+        let registration = json(
+            send(
+                &ctx.app,
+                Method::GET,
+                "/nuget/v3/registration/acme.widgets/index.json",
+                "",
+            )
+            .await,
+        )
+        .await;
+        assert_eq!(registration["count"], 2);
+        assert_eq!(registration["items"][1]["lower"], "1.1.0");
+
+        let search = json(send(&ctx.app, Method::GET, "/nuget/v3/query?q=acme", "").await).await;
+        assert_eq!(search["totalHits"], 2);
+        let data = search["data"].as_array().unwrap();
+        assert_eq!(data[0]["id"], "Acme.Gadgets");
+        assert_eq!(data[1]["id"], "Acme.Widgets");
+        assert_eq!(data[1]["version"], "1.1.0");
+        assert_eq!(data[1]["versions"].as_array().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_nuget_service_index_advertises_publish() {
+        let ctx = hosted_context("http://127.0.0.1:1");
+        let index = json(send(&ctx.app, Method::GET, "/nuget/v3/index.json", "").await).await;
+        let publish = index["resources"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|r| r["@type"] == "PackagePublish/2.0.0")
+            .unwrap();
+        assert!(publish["@id"]
+            .as_str()
+            .unwrap()
+            .ends_with("/nuget/api/v2/package"));
+    }
+
+    #[tokio::test]
+    async fn test_nuget_push_namespace_scope_enforced() {
+        use crate::auth::NamespaceAuthority;
+        use crate::config::ScopeEnforcement;
+        use axum::extract::{FromRequest, Multipart, State};
+        use axum::Extension;
+
+        let ctx = hosted_context("http://127.0.0.1:1");
+        let scoped = NamespaceAuthority::from_oidc_scope(
+            "ci",
+            &["acme.*".to_string()],
+            ScopeEnforcement::Enforce,
+        );
+        let multipart = |nupkg: Vec<u8>| {
+            let request = axum::http::Request::builder()
+                .header(
+                    "content-type",
+                    format!("multipart/form-data; boundary={BOUNDARY}"),
+                )
+                .body(axum::body::Body::from(push_body(&nupkg)))
+                .unwrap();
+            Multipart::from_request(request, &())
+        };
+
+        let resp = super::push_package(
+            State(ctx.state.clone()),
+            Extension(scoped.clone()),
+            multipart(build_nupkg("Other.Widgets", "1.0.0"))
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let resp = super::push_package(
+            State(ctx.state.clone()),
+            Extension(scoped),
+            multipart(build_nupkg("Acme.Widgets", "1.0.0"))
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
 }
 
 // ── Spec conformance tests (#390) ─────────────────────────────────────
@@ -1,21 +1,34 @@
 // Copyright (c) 2026 The Nora Authors
 // SPDX-License-Identifier: MIT
 
-//! Dart/Flutter pub registry proxy.
+//! Dart/Flutter pub registry: hosted publishing + caching proxy.
 //!
 //! Implements hosted pub repository endpoints from repository spec v2:
 //!   GET /pub/api/packages?q={term}&page={page}         — package search
 //!   GET /pub/api/packages/{package}                    — package metadata with versions
 //!   GET /pub/api/packages/{package}/versions/{version} — version metadata
-//!   GET /pub/api/packages/{package}/advisories         — security advisories
+//!   GET /pub/api/packages/{package}/advisories         — security advisories (local DB or upstream)
 //!   GET /pub/packages/{package}/versions/{version}.tar.gz — package archive
 //!
+//! Publishing (`dart pub publish`):
+//!   GET  /pub/api/packages/versions/new                — upload URL
+//!   POST /pub/api/packages/versions/upload             — archive upload (multipart `file`)
+//!   GET  /pub/api/packages/versions/finalize/{id}      — publish outcome
+//!
+//! The upload is validated and published in one step (write role); the finalize URL
+//! only reports the outcome. Published versions are recorded under `pub/hosted/` and
+//! merged into the package listing; they skip curation and the quarantine and are
+//! never fetched upstream.
+//!
 //! Client config:
 //!   export PUB_HOSTED_URL=http://nora:4000/pub
 //!   dart pub get
+//!   dart pub token add http://nora:4000/pub   # API token, then:
+//!   dart pub publish --server http://nora:4000/pub
 
 use crate::activity_log::{ActionType, ActivityEntry};
 use crate::audit::AuditEntry;
+use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
 use crate::cache_ttl::is_within_ttl;
 use crate::registry::{
     circuit_open_response, nora_base_url as nora_base_url_shared, proxy_fetch,
@@ -25,13 +38,14 @@ use crate::registry::{
 use crate::registry_type::RegistryType;
 use crate::secrets::expose_opt;
 use crate::validation::validate_storage_key;
+use crate::version::{cmp_semver, is_semver};
 use crate::AppState;
 use axum::{
-    extract::{Path, RawQuery, State},
+    extract::{Multipart, Path, RawQuery, State},
     http::{header, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
-    Router,
+    routing::{get, post},
+    Extension, Router,
 };
 use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 use serde_json::{Map, Value};
@@ -43,6 +57,10 @@ pub const INDEX_PATTERN: (&str, &str) = ("pub/", ".tar.gz");
 
 const PUB_CONTENT_TYPE: &str = "application/vnd.pub.v2+json";
 const PUB_ARCHIVE_CONTENT_TYPE: &str = "application/octet-stream";
+/// Upper bound on a package archive's unpacked size.
+const MAX_ARCHIVE_UNPACKED: u64 = 256 << 20;
+/// Upper bound on `pubspec.yaml`.
+const MAX_PUBSPEC_SIZE: u64 = 128 << 10;
 const PATH_SEGMENT_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b' ')
     .add(b'"')
@@ -64,6 +82,12 @@ const PATH_SEGMENT_ENCODE_SET: &AsciiSet = &CONTROLS
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/pub/api/packages", get(search_packages))
+        .route("/pub/api/packages/versions/new", get(new_upload))
+        .route("/pub/api/packages/versions/upload", post(upload_package))
+        .route(
+            "/pub/api/packages/versions/finalize/{id}",
+            get(finalize_upload),
+        )
         .route(
             "/pub/api/packages/{package}/advisories",
             get(package_advisories),
@@ -149,20 +173,38 @@ async fn package_listing(
         );
     }
 
+    let hosted = hosted_versions(&state.storage, &package).await;
+    let response = upstream_package_listing(&state, &headers, &package, !hosted.is_empty()).await;
+    if hosted.is_empty() {
+        return response;
+    }
+    merge_hosted_listing(response, &pub_base_url(&state), &package, hosted).await
+}
+
+/// Package listing from the metadata cache or the upstream (already rewritten).
+///
+/// `hosted` skips the curation gate: a package published here is operator-owned, like
+/// an internal namespace; its upstream versions are merged, not gated.
+async fn upstream_package_listing(
+    state: &AppState,
+    headers: &axum::http::HeaderMap,
+    package: &str,
+    hosted: bool,
+) -> Response {
     // Curation check. #733 serve-local: an internal-namespace package is operator-owned — skip
     // curation and serve any local copy below; block the upstream branch separately.
     let internal = crate::curation::is_internal_namespace(
         &state.curation().curation_engine,
         crate::curation::RegistryType::PubDart,
-        &package,
+        package,
     );
-    if !internal {
+    if !internal && !hosted {
         if let Some(response) = crate::curation::check_download(
             &state.curation().curation_engine,
             state.bypass_token().as_deref(),
-            &headers,
+            headers,
             crate::curation::RegistryType::PubDart,
-            &package,
+            package,
             None,
             None,
         ) {
@@ -188,7 +230,7 @@ async fn package_listing(
         return crate::curation::check_namespace_isolation(
             &state.curation().curation_engine,
             crate::curation::RegistryType::PubDart,
-            &package,
+            package,
         )
         .unwrap_or_else(|| StatusCode::NOT_FOUND.into_response());
     }
@@ -200,7 +242,7 @@ async fn package_listing(
     let url = format!(
         "{}/api/packages/{}",
         proxy_url.trim_end_matches('/'),
-        encode_segment(&package)
+        encode_segment(package)
     );
 
     // Revalidate stale metadata with a conditional request when enabled (a cheap
@@ -254,9 +296,9 @@ async fn package_listing(
         // New body — rewrite, cache the rewritten body first, then persist the
         // fresh validators (body-before-sidecar ordering).
         Ok(Revalidation::Modified { body, validators }) => {
-            let nora_base = pub_base_url(&state);
-            let rewritten = rewrite_package_response(&body, &nora_base, &package).unwrap_or(body);
-            cache_bytes(&state, key.clone(), rewritten.clone()).await;
+            let nora_base = pub_base_url(state);
+            let rewritten = rewrite_package_response(&body, &nora_base, package).unwrap_or(body);
+            cache_bytes(state, key.clone(), rewritten.clone()).await;
             write_validators(&state.storage, &key, &validators).await;
             state.repo_index.invalidate("pub");
             pub_json_response(rewritten)
@@ -298,6 +340,12 @@ async fn version_metadata(
         );
     }
 
+    // Published to NORA — answered from the hosted record, never proxied.
+    if let Some(record) = hosted_record(&state.storage, &package, &version).await {
+        let entry = hosted_version_entry(&pub_base_url(&state), &package, &record);
+        return pub_json_response(serde_json::to_vec(&entry).unwrap_or_default());
+    }
+
     let key = format!("pub/api/packages/{}/versions/{}.json", package, version);
     let cached_data = state.storage.get(&key).await.ok();
     if let Some(ref data) = cached_data {
@@ -378,6 +426,14 @@ async fn package_advisories(
         );
     }
 
+    // An enabled advisory database that covers pub answers for every package,
+    // hosted and internal ones included, without contacting the upstream.
+    if let Some(db) =
+        (state.curation().vulndb.clone()).filter(|db| db.covers(RegistryType::PubDart))
+    {
+        return local_advisories(&state, &db, &package).await;
+    }
+
     let key = format!("pub/api/packages/{}/advisories.json", package);
     let cached_data = state.storage.get(&key).await.ok();
     if let Some(ref data) = cached_data {
@@ -442,6 +498,43 @@ async fn package_advisories(
     }
 }
 
+/// Advisories for `package` from the imported database, as OSV records.
+///
+/// `dart pub` reads only the explicit `versions` of an advisory, so the versions NORA
+/// knows of — hosted ones and those in a cached upstream listing — that fall inside
+/// a range are listed there.
+async fn local_advisories(state: &AppState, db: &crate::vulndb::VulnDb, package: &str) -> Response {
+    let mut known: Vec<String> = hosted_versions(&state.storage, package)
+        .await
+        .iter()
+        .filter_map(|record| record["version"].as_str().map(str::to_string))
+        .collect();
+    let listing_key = format!("pub/api/packages/{}.json", package);
+    if let Some(listing) = state
+        .storage
+        .get(&listing_key)
+        .await
+        .ok()
+        .and_then(|data| serde_json::from_slice::<Value>(&data).ok())
+    {
+        for entry in listing["versions"].as_array().into_iter().flatten() {
+            if let Some(version) = entry["version"].as_str() {
+                known.push(version.to_string());
+            }
+        }
+    }
+
+    let advisories: Vec<Value> = db
+        .advisories_for(RegistryType::PubDart, package)
+        .map(|(advisory, affected)| advisory.to_osv(affected, &known))
+        .collect();
+    let body = serde_json::json!({
+        "advisories": advisories,
+        "advisoriesUpdated": db.imported_at(),
+    });
+    pub_json_response(body.to_string().into_bytes())
+}
+
 async fn download_archive(
     State(state): State<AppState>,
     headers: axum::http::HeaderMap,
@@ -463,6 +556,14 @@ async fn download_archive(
         );
     }
 
+    // Published to NORA: operator-owned like an internal namespace, and never
+    // fetched upstream even when the archive has gone missing.
+    let hosted = state
+        .storage
+        .stat(&hosted_record_key(&package, version))
+        .await
+        .is_some();
+
     // Extract publish date from cached metadata (works for both hosted and proxy)
     let publish_date = extract_pub_publish_date(
         &state.storage,
@@ -479,7 +580,7 @@ async fn download_archive(
         crate::curation::RegistryType::PubDart,
         &package,
     );
-    if !internal {
+    if !internal && !hosted {
         if let Some(response) = crate::curation::check_download(
             &state.curation().curation_engine,
             state.bypass_token().as_deref(),
@@ -505,14 +606,16 @@ async fn download_archive(
             GateOutcome::Unpinned(blob) => blob.into_inner(),
         };
         // Integrity verification (curation allowlist)
-        if let Some(response) = crate::curation::verify_integrity(
-            &state.curation().curation_engine,
-            crate::curation::RegistryType::PubDart,
-            &package,
-            Some(version),
-            &data,
-        ) {
-            return response;
+        if !hosted {
+            if let Some(response) = crate::curation::verify_integrity(
+                &state.curation().curation_engine,
+                crate::curation::RegistryType::PubDart,
+                &package,
+                Some(version),
+                &data,
+            ) {
+                return response;
+            }
         }
 
         if let Ok(stored_hash) = state.storage.get(&format!("{}.sha256", key)).await {
@@ -538,16 +641,18 @@ async fn download_archive(
                 .as_deref()
                 .or(state.config.curation.quarantine_ttl.as_deref()),
         );
-        if let Some(resp) = crate::digest_quarantine::proxy_gate_dated(
-            &state.digest_store,
-            "pub",
-            &data,
-            &q_mode,
-            q_secs,
-            "cache",
-            publish_date,
-        ) {
-            return resp;
+        if !hosted {
+            if let Some(resp) = crate::digest_quarantine::proxy_gate_dated(
+                &state.digest_store,
+                "pub",
+                &data,
+                &q_mode,
+                q_secs,
+                "cache",
+                publish_date,
+            ) {
+                return resp;
+            }
         }
 
         // Range request: 206 Partial Content, or 416 when the client asks past the
@@ -584,6 +689,10 @@ async fn download_archive(
         return archive_response(data.to_vec());
     }
 
+    if hosted {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
     // #733: an internal-namespace package with no local copy is never proxied upstream.
     if internal {
         return crate::curation::check_namespace_isolation(
@@ -679,6 +788,360 @@ async fn download_archive(
     }
 }
 
+// ── Hosted publishing ─────────────────────────────────────────────────
+
+/// GET /pub/api/packages/versions/new — where `dart pub publish` should upload.
+async fn new_upload(State(state): State<AppState>) -> Response {
+    let body = serde_json::json!({
+        "url": format!("{}/api/packages/versions/upload", pub_base_url(&state)),
+        "fields": {},
+    });
+    pub_json_response(serde_json::to_vec(&body).unwrap_or_default())
+}
+
+/// POST /pub/api/packages/versions/upload — publish a package archive.
+///
+/// The archive (multipart `file`) must carry a `pubspec.yaml` at its root; its name
+/// and version identify the package. The archive is stored under the key the proxy
+/// serves, with the `.sha256` sidecar, plus a record under `pub/hosted/` that the
+/// package listing merges in. The response is `204` with a `Location` pointing at
+/// the finalize URL, which reports the outcome to the client.
+async fn upload_package(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    mut multipart: Multipart,
+) -> Response {
+    let mut archive: Option<axum::body::Bytes> = None;
+    while let Ok(Some(field)) = multipart.next_field().await {
+        if field.name() == Some("file") {
+            match field.bytes().await {
+                Ok(data) => archive = Some(data),
+                Err(e) => {
+                    return pub_error_response(
+                        StatusCode::BAD_REQUEST,
+                        "invalid_upload",
+                        &format!("Failed to read archive: {}", e),
+                    )
+                }
+            }
+        } else {
+            let _ = field.bytes().await;
+        }
+    }
+    let Some(archive) = archive.filter(|a| !a.is_empty()) else {
+        return pub_error_response(
+            StatusCode::BAD_REQUEST,
+            "invalid_upload",
+            "Missing `file` field",
+        );
+    };
+
+    let pubspec = match read_pubspec(&archive) {
+        Ok(pubspec) => pubspec,
+        Err(e) => {
+            return pub_error_response(StatusCode::BAD_REQUEST, "invalid_archive", &e);
+        }
+    };
+    let package = pubspec["name"].as_str().unwrap_or_default().to_string();
+    let version = pubspec["version"].as_str().unwrap_or_default().to_string();
+    if !is_valid_pub_package_name(&package) {
+        return pub_error_response(
+            StatusCode::BAD_REQUEST,
+            "invalid_pubspec",
+            "pubspec.yaml `name` is not a valid package name",
+        );
+    }
+    if !is_valid_pub_version(&version) || !is_semver(&version) {
+        return pub_error_response(
+            StatusCode::BAD_REQUEST,
+            "invalid_pubspec",
+            "pubspec.yaml `version` is not a semantic version",
+        );
+    }
+
+    // Enforce OIDC namespace_scope on the package name (#583).
+    if enforce_namespace_scope(&authority, &package).is_err() {
+        return pub_error_response(
+            StatusCode::FORBIDDEN,
+            "forbidden",
+            "Outside namespace scope",
+        );
+    }
+
+    let lock = state.publish_lock(&format!("pub/{}", package));
+    let _guard = lock.lock().await;
+
+    // Versions are immutable — including one already cached from upstream.
+    let key = format!("pub/packages/{}/versions/{}.tar.gz", package, version);
+    if state.storage.stat(&key).await.is_some() {
+        return pub_error_response(
+            StatusCode::CONFLICT,
+            "version_exists",
+            &format!("Version {} of package {} already exists", version, package),
+        );
+    }
+
+    let archive_sha256 = hex::encode(sha2::Sha256::digest(&archive));
+    let record = serde_json::json!({
+        "version": version,
+        "pubspec": pubspec,
+        "archive_sha256": archive_sha256,
+        "published": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+    });
+
+    // Archive and sidecar first; the record is what makes the version visible.
+    let sidecar = format!("{}.sha256", key);
+    let record_key = hosted_record_key(&package, &version);
+    let stored = state.storage.put(&key, &archive).await.is_ok()
+        && state
+            .storage
+            .put(&sidecar, archive_sha256.as_bytes())
+            .await
+            .is_ok()
+        && state
+            .storage
+            .put(
+                &record_key,
+                &serde_json::to_vec(&record).unwrap_or_default(),
+            )
+            .await
+            .is_ok();
+    if !stored {
+        for k in [&record_key, &sidecar, &key] {
+            let _ = state.storage.delete(k).await;
+        }
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    state.metrics.record_upload("pub");
+    let artifact = format!("{}@{}", package, version);
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &artifact, "pub", ""));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        artifact,
+        crate::registry_type::RegistryType::PubDart,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("pub");
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let outcome = serde_json::json!({
+        "success": {
+            "message": format!("Successfully uploaded {} version {}.", package, version),
+        }
+    });
+    if state
+        .storage
+        .put(
+            &format!("pub/uploads/{}.json", id),
+            &serde_json::to_vec(&outcome).unwrap_or_default(),
+        )
+        .await
+        .is_err()
+    {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    let location = format!(
+        "{}/api/packages/versions/finalize/{}",
+        pub_base_url(&state),
+        id
+    );
+    match HeaderValue::from_str(&location) {
+        Ok(location) => (StatusCode::NO_CONTENT, [(header::LOCATION, location)]).into_response(),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+/// GET /pub/api/packages/versions/finalize/{id} — outcome of an upload.
+async fn finalize_upload(State(state): State<AppState>, Path(id): Path<String>) -> Response {
+    if id.is_empty() || id.len() > 64 || !id.chars().all(|c| c.is_ascii_hexdigit() || c == '-') {
+        return pub_error_response(
+            StatusCode::BAD_REQUEST,
+            "invalid_upload",
+            "Invalid upload id",
+        );
+    }
+    match state.storage.get(&format!("pub/uploads/{}.json", id)).await {
+        Ok(data) => pub_json_response(data.to_vec()),
+        Err(_) => pub_error_response(StatusCode::NOT_FOUND, "not_found", "Unknown upload"),
+    }
+}
+
+/// Read `pubspec.yaml` from the root of a package archive as JSON.
+///
+/// The archive must be a gzip tar whose entries stay inside the package (no absolute
+/// paths or `..`) and unpack to at most [`MAX_ARCHIVE_UNPACKED`] bytes.
+fn read_pubspec(data: &[u8]) -> Result<Value, String> {
+    use std::io::Read;
+    use std::path::Component;
+
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(data));
+    let mut pubspec: Option<Vec<u8>> = None;
+    let mut total: u64 = 0;
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("Archive is not a gzip tar: {}", e))?
+    {
+        let mut entry = entry.map_err(|e| format!("Archive is not a gzip tar: {}", e))?;
+        let path = entry.path().map_err(|e| e.to_string())?.into_owned();
+        if path.is_absolute() || path.components().any(|c| c == Component::ParentDir) {
+            return Err(format!(
+                "Archive entry escapes the package: {}",
+                path.display()
+            ));
+        }
+        total = total.saturating_add(entry.size());
+        if total > MAX_ARCHIVE_UNPACKED {
+            return Err(format!(
+                "Archive unpacks past {} bytes",
+                MAX_ARCHIVE_UNPACKED
+            ));
+        }
+        let at_root = path
+            .components()
+            .filter(|c| *c != Component::CurDir)
+            .map(|c| c.as_os_str())
+            .eq([std::ffi::OsStr::new("pubspec.yaml")]);
+        if at_root && entry.header().entry_type().is_file() {
+            if entry.size() > MAX_PUBSPEC_SIZE {
+                return Err("pubspec.yaml is too large".to_string());
+            }
+            let mut content = Vec::new();
+            entry
+                .read_to_end(&mut content)
+                .map_err(|e| format!("pubspec.yaml: {}", e))?;
+            pubspec = Some(content);
+        }
+    }
+
+    let pubspec = pubspec.ok_or("Archive has no pubspec.yaml at its root")?;
+    let pubspec: Value = serde_yaml_ng::from_slice(&pubspec)
+        .map_err(|e| format!("pubspec.yaml is not valid YAML: {}", e))?;
+    if !pubspec.is_object() {
+        return Err("pubspec.yaml must be a mapping".to_string());
+    }
+    for field in ["name", "version"] {
+        if !pubspec[field].is_string() {
+            return Err(format!("pubspec.yaml has no `{}`", field));
+        }
+    }
+    Ok(pubspec)
+}
+
+/// Record of a version published to NORA.
+fn hosted_record_key(package: &str, version: &str) -> String {
+    format!("pub/hosted/{}/{}.json", package, version)
+}
+
+async fn hosted_record(
+    storage: &crate::storage::Storage,
+    package: &str,
+    version: &str,
+) -> Option<Value> {
+    let data = storage
+        .get(&hosted_record_key(package, version))
+        .await
+        .ok()?;
+    serde_json::from_slice(&data).ok()
+}
+
+/// Hosted version records of a package.
+async fn hosted_versions(storage: &crate::storage::Storage, package: &str) -> Vec<Value> {
+    let prefix = format!("pub/hosted/{}/", package);
+    let mut records = Vec::new();
+    for key in storage.list(&prefix).await.unwrap_or_default() {
+        let Some(version) = key
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.strip_suffix(".json"))
+        else {
+            continue;
+        };
+        if let Some(record) = hosted_record(storage, package, version).await {
+            records.push(record);
+        }
+    }
+    records
+}
+
+/// A hosted record as a listing / version-metadata entry.
+fn hosted_version_entry(nora_base: &str, package: &str, record: &Value) -> Value {
+    let version = record["version"].as_str().unwrap_or_default();
+    serde_json::json!({
+        "version": version,
+        "archive_url": nora_archive_url(nora_base, package, version),
+        "archive_sha256": record["archive_sha256"],
+        "pubspec": record["pubspec"],
+        "published": record["published"],
+    })
+}
+
+/// Merge hosted versions into a package listing response.
+///
+/// A hosted version replaces an upstream one with the same number; `versions` is
+/// kept in ascending order and `latest` recomputed. A failed upstream answer (404,
+/// 502, isolation block) leaves a listing of the hosted versions alone.
+async fn merge_hosted_listing(
+    response: Response,
+    nora_base: &str,
+    package: &str,
+    hosted: Vec<Value>,
+) -> Response {
+    let hosted: Vec<Value> = hosted
+        .iter()
+        .map(|record| hosted_version_entry(nora_base, package, record))
+        .collect();
+    let (parts, upstream) = if response.status().is_success() {
+        let (mut parts, body) = response.into_parts();
+        let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+            return StatusCode::BAD_GATEWAY.into_response();
+        };
+        parts.headers.remove(header::CONTENT_LENGTH);
+        (Some(parts), serde_json::from_slice::<Value>(&bytes).ok())
+    } else {
+        (None, None)
+    };
+
+    let mut listing = upstream
+        .filter(|v| v.is_object())
+        .unwrap_or_else(|| serde_json::json!({ "name": package }));
+    let mut versions: Vec<Value> = listing["versions"].as_array().cloned().unwrap_or_default();
+    for entry in hosted {
+        match versions
+            .iter_mut()
+            .find(|v| v["version"] == entry["version"])
+        {
+            Some(existing) => *existing = entry,
+            None => versions.push(entry),
+        }
+    }
+    versions.sort_by(|a, b| {
+        cmp_semver(
+            a["version"].as_str().unwrap_or_default(),
+            b["version"].as_str().unwrap_or_default(),
+        )
+    });
+    let latest = versions
+        .iter()
+        .rev()
+        .find(|v| {
+            v["retracted"] != Value::Bool(true)
+                && !v["version"].as_str().unwrap_or_default().contains('-')
+        })
+        .or_else(|| versions.last())
+        .cloned()
+        .unwrap_or(Value::Null);
+    listing["latest"] = latest;
+    listing["versions"] = Value::Array(versions);
+
+    let body = serde_json::to_vec(&listing).unwrap_or_default();
+    match parts {
+        Some(parts) => Response::from_parts(parts, axum::body::Body::from(body)),
+        None => pub_json_response(body),
+    }
+}
+
 async fn fetch_pub_api(
     state: &AppState,
     url: &str,
@@ -1396,6 +1859,74 @@ mod tests {
         assert!(json["advisories"].as_array().unwrap().is_empty());
     }
 
+    /// With an imported database, advisories come from it (never the upstream), and
+    /// known versions inside a range are expanded into `versions` for `dart pub`.
+    #[tokio::test]
+    async fn test_pub_advisories_from_local_vulndb() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("LEAKED"))
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let advisory = serde_json::json!({
+            "id": "GHSA-pub-0001",
+            "modified": "2024-05-01T00:00:00Z",
+            "summary": "Header injection in http",
+            "affected": [{
+                "package": {"ecosystem": "Pub", "name": "http"},
+                "ranges": [{"type": "ECOSYSTEM", "events": [{"introduced": "0.13.0"}, {"fixed": "1.0.0"}]}]
+            }],
+            "database_specific": {"severity": "MODERATE"}
+        });
+        let db_path = crate::vulndb::tests::db_with(dir.path(), &[advisory]);
+        let proxy_url = server.uri();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.pub_dart.enabled = true;
+            cfg.pub_dart.proxy = Some(proxy_url);
+            cfg.curation.vulnerabilities.enabled = true;
+            cfg.curation.vulnerabilities.db_path = Some(db_path);
+        });
+        let listing = serde_json::json!({
+            "name": "http",
+            "versions": [{"version": "0.12.2"}, {"version": "0.13.6"}, {"version": "1.2.0"}]
+        });
+        ctx.state
+            .storage
+            .put("pub/api/packages/http.json", listing.to_string().as_bytes())
+            .await
+            .unwrap();
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/pub/api/packages/http/advisories",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        let advisories = json["advisories"].as_array().unwrap();
+        assert_eq!(advisories.len(), 1);
+        assert_eq!(advisories[0]["id"], "GHSA-pub-0001");
+        assert_eq!(advisories[0]["database_specific"]["severity"], "MODERATE");
+        let affected = &advisories[0]["affected"][0];
+        assert_eq!(affected["package"]["ecosystem"], "Pub");
+        assert_eq!(affected["versions"], serde_json::json!(["0.13.6"]));
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/pub/api/packages/path/advisories",
+            "",
+        )
+        .await;
+        let json: Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert!(json["advisories"].as_array().unwrap().is_empty());
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_extract_pub_publish_date_found() {
         let dir = tempfile::tempdir().unwrap();
@@ -1509,4 +2040,279 @@ mod tests {
             .get();
         assert!(after > before, "a 304 revalidation must be recorded");
     }
+
+    // ── Hosted publishing ─────────────────────────────────────────────
+
+    const BOUNDARY: &str = "nora-pub-boundary";
+
+    /// A package archive as `dart pub publish` builds it.
+    fn build_archive(pubspec: &str) -> Vec<u8> {
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::default(),
+        ));
+        for (path, data) in [
+            ("pubspec.yaml", pubspec.as_bytes()),
+            (
+                "lib/acme_widgets.dart",
+                b"library acme_widgets;\n".as_slice(),
+            ),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, data).unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    fn pubspec(name: &str, version: &str) -> String {
+        format!(
+            "name: {name}\nversion: {version}\ndescription: Internal widgets.\nenvironment:\n  sdk: '>=3.0.0 <4.0.0'\ndependencies:\n  http: ^1.2.0\n"
+        )
+    }
+
+    fn upload_body(archive: &[u8]) -> Vec<u8> {
+        let mut body = format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"package.tar.gz\"\r\nContent-Type: application/octet-stream\r\n\r\n"
+        )
+        .into_bytes();
+        body.extend_from_slice(archive);
+        body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+        body
+    }
+
+    fn hosted_context() -> TestContext {
+        create_pub_proxy_test_context("http://127.0.0.1:1".to_string())
+    }
+
+    /// Run the `dart pub publish` flow: new → upload → finalize.
+    async fn publish(ctx: &TestContext, archive: &[u8]) -> axum::http::Response<axum::body::Body> {
+        let resp = send(&ctx.app, Method::GET, "/pub/api/packages/versions/new", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let new: Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        let url = new["url"].as_str().unwrap();
+        assert!(new["fields"].is_object());
+        let upload_path = &url[url.find("/pub/").unwrap()..];
+
+        let content_type = format!("multipart/form-data; boundary={BOUNDARY}");
+        send_with_headers(
+            &ctx.app,
+            Method::POST,
+            upload_path,
+            vec![("content-type", content_type.as_str())],
+            upload_body(archive),
+        )
+        .await
+    }
+
+    async fn finalize(ctx: &TestContext, resp: axum::http::Response<axum::body::Body>) -> Value {
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        let location = resp.headers()["location"].to_str().unwrap().to_string();
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &location[location.find("/pub/").unwrap()..],
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        serde_json::from_slice(&body_bytes(resp).await).unwrap()
+    }
+
+    #[test]
+    fn test_read_pubspec() {
+        let pubspec = read_pubspec(&build_archive(&pubspec("acme_widgets", "1.0.0"))).unwrap();
+        assert_eq!(pubspec["name"], "acme_widgets");
+        assert_eq!(pubspec["version"], "1.0.0");
+        assert_eq!(pubspec["dependencies"]["http"], "^1.2.0");
+
+        assert!(read_pubspec(b"not an archive").is_err());
+        assert!(read_pubspec(&build_archive("name: [unclosed"))
+            .unwrap_err()
+            .contains("YAML"));
+        assert!(read_pubspec(&build_archive("name: acme_widgets\n"))
+            .unwrap_err()
+            .contains("version"));
+    }
+
+    #[tokio::test]
+    async fn test_pub_publish_and_get() {
+        let ctx = hosted_context();
+        let archive = build_archive(&pubspec("acme_widgets", "1.0.0"));
+        let resp = publish(&ctx, &archive).await;
+        let outcome = finalize(&ctx, resp).await;
+        assert!(outcome["success"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("acme_widgets version 1.0.0"));
+
+        let resp = send(&ctx.app, Method::GET, "/pub/api/packages/acme_widgets", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let listing: Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(listing["name"], "acme_widgets");
+        assert_eq!(listing["latest"]["version"], "1.0.0");
+        let sha256 = hex::encode(sha2::Sha256::digest(&archive));
+        assert_eq!(listing["versions"][0]["archive_sha256"], sha256);
+        assert_eq!(
+            listing["versions"][0]["pubspec"]["environment"]["sdk"],
+            ">=3.0.0 <4.0.0"
+        );
+        assert!(listing["versions"][0]["archive_url"]
+            .as_str()
+            .unwrap()
+            .ends_with("/pub/packages/acme_widgets/versions/1.0.0.tar.gz"));
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/pub/api/packages/acme_widgets/versions/1.0.0",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let version: Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(version["archive_sha256"], sha256);
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/pub/packages/acme_widgets/versions/1.0.0.tar.gz",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(body_bytes(resp).await.to_vec(), archive);
+    }
+
+    #[tokio::test]
+    async fn test_pub_publish_merges_into_upstream_listing() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/packages/acme_widgets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "name": "acme_widgets",
+                "latest": { "version": "1.0.0" },
+                "versions": [
+                    { "version": "0.9.0", "archive_url": "https://pub.dev/x", "pubspec": {} },
+                    { "version": "1.0.0", "archive_url": "https://pub.dev/y", "pubspec": {} }
+                ]
+            })))
+            .mount(&server)
+            .await;
+        let ctx = create_pub_proxy_test_context(server.uri());
+
+        let resp = publish(&ctx, &build_archive(&pubspec("acme_widgets", "1.1.0"))).await;
+        finalize(&ctx, resp).await;
+        let resp = publish(
+            &ctx,
+            &build_archive(&pubspec("acme_widgets", "2.0.0-dev.1")),
+        )
+        .await;
+        finalize(&ctx, resp).await;
+
+        let resp = send(&ctx.app, Method::GET, "/pub/api/packages/acme_widgets", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let listing: Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        let versions: Vec<&str> = listing["versions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["version"].as_str().unwrap())
+            .collect();
+        assert_eq!(versions, vec!["0.9.0", "1.0.0", "1.1.0", "2.0.0-dev.1"]);
+        // Pre-releases never become `latest` while a stable version exists.
+        assert_eq!(listing["latest"]["version"], "1.1.0");
+    }
+
+    #[tokio::test]
+    async fn test_pub_publish_existing_version_conflicts() {
+        let ctx = hosted_context();
+        let archive = build_archive(&pubspec("acme_widgets", "1.0.0"));
+        let resp = publish(&ctx, &archive).await;
+        finalize(&ctx, resp).await;
+
+        let resp = publish(&ctx, &archive).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(body["error"]["code"], "version_exists");
+    }
+
+    #[tokio::test]
+    async fn test_pub_publish_rejects_invalid_archives() {
+        let ctx = hosted_context();
+        let resp = publish(&ctx, b"not an archive").await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let resp = publish(&ctx, &build_archive(&pubspec("Acme-Widgets", "1.0.0"))).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let resp = publish(&ctx, &build_archive(&pubspec("acme_widgets", "1.0"))).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert!(ctx
+            .state
+            .storage
+            .list("pub/hosted/")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_pub_finalize_unknown_upload_is_404() {
+        let ctx = hosted_context();
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/pub/api/packages/versions/finalize/0000-1111",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_pub_publish_namespace_scope_enforced() {
+        use crate::auth::NamespaceAuthority;
+        use crate::config::ScopeEnforcement;
+        use axum::extract::FromRequest;
+
+        let ctx = hosted_context();
+        let scoped = NamespaceAuthority::from_oidc_scope(
+            "ci",
+            &["acme_*".to_string()],
+            ScopeEnforcement::Enforce,
+        );
+        let multipart = |archive: Vec<u8>| {
+            let request = axum::http::Request::builder()
+                .header(
+                    "content-type",
+                    format!("multipart/form-data; boundary={BOUNDARY}"),
+                )
+                .body(axum::body::Body::from(upload_body(&archive)))
+                .unwrap();
+            Multipart::from_request(request, &())
+        };
+
+        let resp = upload_package(
+            State(ctx.state.clone()),
+            Extension(scoped.clone()),
+            multipart(build_archive(&pubspec("other_widgets", "1.0.0")))
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let resp = upload_package(
+            State(ctx.state.clone()),
+            Extension(scoped),
+            multipart(build_archive(&pubspec("acme_widgets", "1.0.0")))
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    }
 }
@@ -7,11 +7,15 @@
 //!   GET  /simple/                     — package index (HTML or JSON)
 //!   GET  /simple/{name}/              — package versions (HTML or JSON)
 //!   GET  /simple/{name}/{filename}    — download file
+//!   GET  /simple/{name}/{file}.metadata — wheel core metadata (PEP 658)
 //!   POST /simple/                     — twine upload (multipart/form-data)
+//!   GET  /pypi/{name}/json            — JSON API, project
+//!   GET  /pypi/{name}/{version}/json  — JSON API, release
+//!   PUT/DELETE /pypi/{name}/{version}/yank — yank / unyank (PEP 592, admin)
 
 use crate::activity_log::{ActionType, ActivityEntry};
 use crate::audit::AuditEntry;
-use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
+use crate::auth::{enforce_namespace_scope, AuthenticatedRole, NamespaceAuthority};
 use crate::registry::{
     circuit_open_response, method_not_allowed, nora_base_url, proxy_fetch, proxy_fetch_text,
 };
@@ -20,10 +24,11 @@ use crate::ui::components::html_escape;
 use crate::validation::ends_with_ci;
 use crate::AppState;
 use axum::{
+    body::Bytes,
     extract::{Multipart, Path, State},
     http::{header, HeaderMap, StatusCode},
     response::{Html, IntoResponse, Response},
-    routing::get,
+    routing::{get, put},
     Extension, Router,
 };
 use sha2::Digest;
@@ -44,6 +49,14 @@ pub fn routes() -> Router<AppState> {
         )
         .route("/simple/{name}/", get(package_versions))
         .route("/simple/{name}/{filename}", get(download_file))
+        .route("/pypi/{name}/json", get(project_json))
+        .route("/pypi/{name}/{version}/json", get(release_json))
+        .route(
+            "/pypi/{name}/{version}/yank",
+            put(yank)
+                .delete(unyank)
+                .fallback(|| async { method_not_allowed("PUT, DELETE") }),
+        )
 }
 
 // ============================================================================
@@ -141,23 +154,30 @@ async fn package_versions(
             return StatusCode::SERVICE_UNAVAILABLE.into_response();
         }
     };
+    let key_set: std::collections::HashSet<&str> = keys.iter().map(String::as_str).collect();
     let mut local_files: Vec<FileEntry> = Vec::new();
     for key in &keys {
         if let Some(filename) = key.strip_prefix(&prefix) {
-            if !filename.is_empty() && !ends_with_ci(filename, ".sha256") {
-                let sha256 = state
-                    .storage
-                    .get(&format!("{}.sha256", key))
-                    .await
-                    .ok()
-                    .and_then(|d| String::from_utf8(d.to_vec()).ok());
+            // Distributions only — not sidecars, core metadata or yanked.json.
+            if is_valid_pypi_filename(filename) {
+                let sha256 = read_hash(&state.storage, &format!("{}.sha256", key)).await;
+                let metadata_key = format!("{}.metadata", key);
+                let core_metadata = if key_set.contains(metadata_key.as_str()) {
+                    read_hash(&state.storage, &format!("{}.sha256", metadata_key)).await
+                } else {
+                    None
+                };
                 local_files.push(FileEntry {
                     filename: filename.to_string(),
                     sha256,
+                    core_metadata,
+                    ..Default::default()
                 });
             }
         }
     }
+    let yanked = read_yanked(&state.storage, &normalized).await;
+    apply_yanks(&normalized, &mut local_files, &yanked);
 
     // When proxy is configured, fetch upstream index and merge with local files.
     // This fixes the case where a cp314 wheel is cached but pip 3.10 needs to
@@ -205,6 +225,7 @@ async fn package_versions(
                 }
             }
         }
+        apply_yanks(&normalized, &mut upstream_files, &yanked);
         let merged = merge_file_lists(upstream_files, &local_files);
         if !merged.is_empty() {
             return if wants_json(&headers) {
@@ -260,6 +281,15 @@ async fn download_file(
 ) -> Response {
     let normalized = normalize_name(&name);
 
+    // PEP 658 core metadata is extracted from a stored wheel, so it is local
+    // only and needs no curation of its own — the wheel is checked when fetched.
+    if filename
+        .strip_suffix(".metadata")
+        .is_some_and(is_valid_pypi_filename)
+    {
+        return serve_core_metadata(&state, &normalized, &filename).await;
+    }
+
     // Curation check — before storage access
     let version = crate::curation::parse_pypi_version(&normalized, &filename);
 
@@ -287,7 +317,15 @@ async fn download_file(
         &normalized,
     );
     if !internal {
-        if let Some(response) = crate::curation::check_download(
+        let license = match version {
+            Some(ref version)
+                if crate::curation::license_policy_active(&state.curation().curation_engine) =>
+            {
+                pypi_declared_license(&state, &normalized, version).await
+            }
+            _ => None,
+        };
+        if let Some(response) = crate::curation::check_download_with_license(
             &state.curation().curation_engine,
             state.bypass_token().as_deref(),
             &headers,
@@ -295,6 +333,7 @@ async fn download_file(
             &normalized,
             version.as_deref(),
             publish_date,
+            license.as_deref(),
         ) {
             return response;
         }
@@ -479,12 +518,15 @@ async fn download_file(
                 let key_clone = key.clone();
                 let data_clone = data.clone();
                 let repo_index = Arc::clone(&state.repo_index);
+                let filename_clone = filename.clone();
                 tokio::spawn(async move {
                     if storage.put(&key_clone, &data_clone).await.is_ok() {
                         let hash = hex::encode(sha2::Sha256::digest(&data_clone));
                         let _ = storage
                             .put(&format!("{}.sha256", key_clone), hash.as_bytes())
                             .await;
+                        store_core_metadata(&storage, &key_clone, &filename_clone, &data_clone)
+                            .await;
                         repo_index.invalidate("pypi");
                     }
                 });
@@ -651,6 +693,7 @@ async fn upload(
     if let Err(e) = state.storage.put(&hash_key, computed_hash.as_bytes()).await {
         tracing::warn!(key = %hash_key, error = %e, "pypi: failed to store hash sidecar");
     }
+    store_core_metadata(&state.storage, &file_key, &filename, &data).await;
 
     state.metrics.record_upload("pypi");
     let artifact = format!("{}-{}", name, version);
@@ -668,13 +711,768 @@ async fn upload(
     StatusCode::OK.into_response()
 }
 
+// ============================================================================
+// JSON API (`/pypi/{name}/json`, `/pypi/{name}/{version}/json`)
+// ============================================================================
+
+/// GET /pypi/{name}/json — project metadata and every release's files.
+async fn project_json(State(state): State<AppState>, Path(name): Path<String>) -> Response {
+    json_api(&state, &name, None).await
+}
+
+/// GET /pypi/{name}/{version}/json — one release's metadata and files.
+async fn release_json(
+    State(state): State<AppState>,
+    Path((name, version)): Path<(String, String)>,
+) -> Response {
+    json_api(&state, &name, Some(&version)).await
+}
+
+/// Warehouse's JSON API over what NORA holds, merged with the upstream's.
+///
+/// The upstream document (from the first upstream whose index URL ends in
+/// `/simple`) provides the project's releases and `info`; its file URLs are
+/// rewritten to NORA so downloads go through the proxy. Local files — hosted
+/// uploads and cached downloads — are added where the upstream doesn't list
+/// them, and a release with no upstream document gets its `info` from the
+/// stored core metadata. Local yanks apply on top. Never cached: the document
+/// changes with every upstream release.
+async fn json_api(state: &AppState, name: &str, version: Option<&str>) -> Response {
+    let normalized = normalize_name(name);
+    // Local versions are lowercased from filenames; Warehouse matches any case.
+    let version = version.map(str::to_lowercase);
+    let version = version.as_deref();
+    let base_url = nora_base_url(state);
+    let local = local_dists(state, &normalized).await;
+    let yanked = read_yanked(&state.storage, &normalized).await;
+
+    let is_internal = crate::curation::is_internal_namespace(
+        &state.curation().curation_engine,
+        crate::curation::RegistryType::PyPI,
+        &normalized,
+    );
+    let upstream = if is_internal {
+        None
+    } else {
+        fetch_upstream_json(state, &normalized, version).await
+    };
+
+    let known_version = |v: &str| local.iter().any(|d| d.version == v);
+    let upstream_info = upstream.as_ref().map(|doc| doc["info"].clone());
+    match (&upstream, version) {
+        (None, None) if local.is_empty() => {
+            return json_api_not_found(state, &normalized, is_internal)
+        }
+        (None, Some(v)) if !known_version(v) => {
+            return json_api_not_found(state, &normalized, is_internal)
+        }
+        _ => {}
+    }
+
+    let rewrite = |file: &serde_json::Value| -> serde_json::Value {
+        let mut file = file.clone();
+        if let Some(filename) = file["filename"].as_str().map(str::to_string) {
+            file["url"] = serde_json::Value::String(format!(
+                "{}/simple/{}/{}",
+                base_url.trim_end_matches('/'),
+                normalized,
+                filename
+            ));
+        }
+        file
+    };
+
+    // Every release's files, upstream first, then local files it doesn't list.
+    let mut releases: std::collections::BTreeMap<String, Vec<serde_json::Value>> =
+        std::collections::BTreeMap::new();
+    if let Some(doc) = &upstream {
+        let upstream_releases = doc["releases"].as_object().cloned().unwrap_or_default();
+        for (v, files) in upstream_releases {
+            let files = files.as_array().map(|f| f.iter().map(rewrite).collect());
+            releases.insert(v, files.unwrap_or_default());
+        }
+        // The version endpoint has no `releases`; its `urls` are that release.
+        if let Some(v) = version {
+            let files = doc["urls"]
+                .as_array()
+                .map(|f| f.iter().map(rewrite).collect());
+            releases.insert(v.to_string(), files.unwrap_or_default());
+        }
+    }
+    for dist in &local {
+        let files = releases.entry(dist.version.clone()).or_default();
+        if !files
+            .iter()
+            .any(|f| f["filename"] == dist.filename.as_str())
+        {
+            files.push(dist.to_json(&base_url, &normalized));
+        }
+    }
+    for (v, reason) in &yanked {
+        for file in releases.get_mut(v).into_iter().flatten() {
+            file["yanked"] = serde_json::Value::Bool(true);
+            file["yanked_reason"] = serde_json::json!(reason_or_null(reason));
+        }
+    }
+
+    let target = match version {
+        Some(v) => v.to_string(),
+        None => upstream_info
+            .as_ref()
+            .and_then(|info| info["version"].as_str().map(str::to_string))
+            .or_else(|| latest_version(releases.keys(), &yanked))
+            .unwrap_or_default(),
+    };
+    let mut info = match upstream_info {
+        Some(info) if info.is_object() => info,
+        _ => {
+            let metadata = release_core_metadata(state, &normalized, &local, &target).await;
+            core_metadata_info(metadata.as_deref().unwrap_or_default(), name, &target)
+        }
+    };
+    if let Some(reason) = yanked.get(&target) {
+        info["yanked"] = serde_json::Value::Bool(true);
+        info["yanked_reason"] = serde_json::json!(reason_or_null(reason));
+    }
+    let urls = releases.get(&target).cloned().unwrap_or_default();
+
+    let mut body = serde_json::json!({ "info": info, "urls": urls });
+    if version.is_none() {
+        body["releases"] = serde_json::json!(releases);
+    }
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/json")],
+        serde_json::to_vec(&body).unwrap_or_default(),
+    )
+        .into_response()
+}
+
+fn json_api_not_found(state: &AppState, normalized: &str, is_internal: bool) -> Response {
+    if is_internal {
+        if let Some(response) = crate::curation::check_namespace_isolation(
+            &state.curation().curation_engine,
+            crate::curation::RegistryType::PyPI,
+            normalized,
+        ) {
+            return response;
+        }
+    }
+    StatusCode::NOT_FOUND.into_response()
+}
+
+/// The upstream JSON API document, from the first upstream that answers. Only
+/// upstreams whose index URL ends in `/simple` have a derivable JSON API
+/// (`…/simple/` → `…/pypi/{name}/json`); others are skipped.
+async fn fetch_upstream_json(
+    state: &AppState,
+    normalized: &str,
+    version: Option<&str>,
+) -> Option<serde_json::Value> {
+    for up in &state.config.pypi.upstreams() {
+        let Some(root) = up.url().trim_end_matches('/').strip_suffix("/simple") else {
+            continue;
+        };
+        let url = match version {
+            Some(v) => format!("{}/pypi/{}/{}/json", root, normalized, v),
+            None => format!("{}/pypi/{}/json", root, normalized),
+        };
+        match proxy_fetch(
+            &state.http_client,
+            &url,
+            Duration::from_secs(state.config.pypi.proxy_timeout),
+            up.auth(),
+            &state.circuit_breaker,
+            RegistryType::PyPI,
+        )
+        .await
+        {
+            Ok(data) => match serde_json::from_slice::<serde_json::Value>(&data) {
+                Ok(doc) if doc.is_object() => return Some(doc),
+                _ => {
+                    tracing::debug!(upstream = %up.url(), "PyPI JSON API: unparsable upstream response")
+                }
+            },
+            Err(e) => {
+                tracing::debug!(error = ?e, package = %normalized, upstream = %up.url(), "PyPI JSON API upstream fetch failed, trying next upstream")
+            }
+        }
+    }
+    None
+}
+
+/// A distribution file in storage (hosted upload or cached download).
+struct LocalDist {
+    filename: String,
+    version: String,
+    sha256: Option<String>,
+    size: u64,
+    modified: u64,
+    has_metadata: bool,
+}
+
+impl LocalDist {
+    /// The file in the JSON API's shape.
+    fn to_json(&self, base_url: &str, normalized: &str) -> serde_json::Value {
+        let wheel = ends_with_ci(&self.filename, ".whl");
+        let python_version = if wheel {
+            // {name}-{version}(-{build})?-{python}-{abi}-{platform}.whl
+            self.filename.rsplit('-').nth(2).unwrap_or("py3")
+        } else {
+            "source"
+        };
+        let uploaded = chrono::DateTime::from_timestamp(self.modified as i64, 0)
+            .unwrap_or_default()
+            .naive_utc();
+        serde_json::json!({
+            "filename": self.filename,
+            "url": format!("{}/simple/{}/{}", base_url.trim_end_matches('/'), normalized, self.filename),
+            "digests": { "sha256": self.sha256 },
+            "size": self.size,
+            "packagetype": if wheel { "bdist_wheel" } else { "sdist" },
+            "python_version": python_version,
+            "upload_time": uploaded.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            "upload_time_iso_8601": format!("{}Z", uploaded.format("%Y-%m-%dT%H:%M:%S%.6f")),
+            "yanked": false,
+            "yanked_reason": null,
+        })
+    }
+}
+
+/// Distribution files stored for a project, with their version.
+async fn local_dists(state: &AppState, normalized: &str) -> Vec<LocalDist> {
+    let prefix = format!("pypi/{}/", normalized);
+    let keys = state.storage.list(&prefix).await.unwrap_or_default();
+    let mut dists = Vec::new();
+    for key in &keys {
+        let Some(filename) = key.strip_prefix(&prefix) else {
+            continue;
+        };
+        if !is_valid_pypi_filename(filename) {
+            continue;
+        }
+        let Some(version) = dist_version(normalized, filename) else {
+            continue;
+        };
+        let (size, modified) = state
+            .storage
+            .stat(key)
+            .await
+            .map_or((0, 0), |m| (m.size, m.modified));
+        let sha256 = read_hash(&state.storage, &format!("{}.sha256", key)).await;
+        dists.push(LocalDist {
+            filename: filename.to_string(),
+            version,
+            sha256,
+            size,
+            modified,
+            has_metadata: keys.contains(&format!("{}.metadata", key)),
+        });
+    }
+    dists
+}
+
+/// Version of a distribution file. Older sdists keep the project's hyphens in
+/// the filename (`my-pkg-1.0.tar.gz`), which the curation parser doesn't expect.
+fn dist_version(normalized: &str, filename: &str) -> Option<String> {
+    crate::curation::parse_pypi_version(normalized, filename).or_else(|| {
+        let base = ["tar.gz", "tgz", "zip"]
+            .iter()
+            .find_map(|ext| filename.strip_suffix(&format!(".{}", ext)))?;
+        let rest = base.get(normalized.len() + 1..)?;
+        (normalize_name(&base[..normalized.len()]) == normalized).then(|| rest.to_lowercase())
+    })
+}
+
+/// Core metadata of a release, preferring a wheel's over an sdist's `PKG-INFO`.
+async fn release_core_metadata(
+    state: &AppState,
+    normalized: &str,
+    local: &[LocalDist],
+    version: &str,
+) -> Option<String> {
+    let dist = local
+        .iter()
+        .filter(|d| d.version == version && d.has_metadata)
+        .min_by_key(|d| !ends_with_ci(&d.filename, ".whl"))?;
+    let key = format!("pypi/{}/{}.metadata", normalized, dist.filename);
+    let data = state.storage.get(&key).await.ok()?;
+    Some(String::from_utf8_lossy(&data).into_owned())
+}
+
+/// Declared license of a release, for the license filter: from a stored wheel's
+/// core metadata, else from the upstream JSON API (the first download of a
+/// release has no local metadata yet).
+async fn pypi_declared_license(
+    state: &AppState,
+    normalized: &str,
+    version: &str,
+) -> Option<String> {
+    let local = local_dists(state, normalized).await;
+    if let Some(text) = release_core_metadata(state, normalized, &local, version).await {
+        let (fields, _) = parse_core_metadata(&text);
+        let first = |field: &str| {
+            fields
+                .iter()
+                .find(|(f, _)| f == field)
+                .map(|(_, v)| v.as_str())
+        };
+        let classifiers: Vec<&str> = fields
+            .iter()
+            .filter(|(f, _)| f == "classifier")
+            .map(|(_, v)| v.as_str())
+            .collect();
+        return declared_license(first("license-expression"), first("license"), &classifiers);
+    }
+    let doc = fetch_upstream_json(state, normalized, Some(version)).await?;
+    let info = doc.get("info")?;
+    let text = |field: &str| info.get(field).and_then(|v| v.as_str());
+    let classifiers: Vec<&str> = info
+        .get("classifiers")
+        .and_then(|c| c.as_array())
+        .map(|c| c.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_default();
+    declared_license(text("license_expression"), text("license"), &classifiers)
+}
+
+/// `License-Expression` (PEP 639), else `License` when it names a license rather
+/// than holding its text, else the `License ::` trove classifiers.
+fn declared_license(
+    expression: Option<&str>,
+    license: Option<&str>,
+    classifiers: &[&str],
+) -> Option<String> {
+    if let Some(expression) = expression.map(str::trim).filter(|e| !e.is_empty()) {
+        return Some(expression.to_string());
+    }
+    if let Some(license) = license.and_then(crate::license::parse) {
+        return Some(license.to_string());
+    }
+    let names = classifiers
+        .iter()
+        .filter_map(|c| c.strip_prefix("License ::"))
+        .filter_map(|c| c.rsplit("::").next())
+        .map(str::trim)
+        .filter(|name| *name != "OSI Approved");
+    crate::license::parse_any(names).map(|e| e.to_string())
+}
+
+// ============================================================================
+// Yanking (PEP 592)
+// ============================================================================
+
+/// Body of `PUT /pypi/{name}/{version}/yank` — optional.
+#[derive(serde::Deserialize)]
+struct YankRequest {
+    #[serde(default)]
+    reason: String,
+}
+
+/// PUT /pypi/{name}/{version}/yank — mark a release yanked.
+async fn yank(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    role: Option<Extension<AuthenticatedRole>>,
+    Path((name, version)): Path<(String, String)>,
+    body: Bytes,
+) -> Response {
+    let reason = if body.is_empty() {
+        String::new()
+    } else {
+        match serde_json::from_slice::<YankRequest>(&body) {
+            Ok(req) => req.reason,
+            Err(e) => {
+                return (StatusCode::BAD_REQUEST, format!("Invalid yank body: {}", e))
+                    .into_response()
+            }
+        }
+    };
+    set_yanked(&state, &authority, role, &name, &version, Some(reason)).await
+}
+
+/// DELETE /pypi/{name}/{version}/yank — restore a yanked release.
+async fn unyank(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    role: Option<Extension<AuthenticatedRole>>,
+    Path((name, version)): Path<(String, String)>,
+) -> Response {
+    set_yanked(&state, &authority, role, &name, &version, None).await
+}
+
+/// Record (`Some(reason)`) or clear (`None`) a yank in `pypi/{name}/yanked.json`.
+///
+/// Yanking changes what every installer resolves, so unlike upload it needs
+/// the admin role (when auth is on). Only releases NORA holds files for can
+/// be yanked; the yank then applies to the upstream's files of that version
+/// too, since pip resolves across the merged listing.
+async fn set_yanked(
+    state: &AppState,
+    authority: &NamespaceAuthority,
+    role: Option<Extension<AuthenticatedRole>>,
+    name: &str,
+    version: &str,
+    reason: Option<String>,
+) -> Response {
+    if role.is_some_and(|Extension(r)| !r.0.can_admin()) {
+        return (StatusCode::FORBIDDEN, "Yanking requires the admin role").into_response();
+    }
+    let normalized = normalize_name(name);
+    if enforce_namespace_scope(authority, &normalized).is_err() {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let version = version.to_lowercase();
+    let local = local_dists(state, &normalized).await;
+    if !local.iter().any(|d| d.version == version) {
+        return (
+            StatusCode::NOT_FOUND,
+            format!("No files for {} {}", normalized, version),
+        )
+            .into_response();
+    }
+
+    let key = yanked_key(&normalized);
+    let lock = state.publish_lock(&key);
+    let _guard = lock.lock().await;
+
+    let mut yanked = read_yanked(&state.storage, &normalized).await;
+    let (action, detail) = match reason {
+        Some(reason) => {
+            yanked.insert(version.clone(), reason.clone());
+            ("yank", reason)
+        }
+        None => {
+            yanked.remove(&version);
+            ("unyank", String::new())
+        }
+    };
+    let written = if yanked.is_empty() {
+        state.storage.delete(&key).await
+    } else {
+        state
+            .storage
+            .put(&key, &serde_json::to_vec(&yanked).unwrap_or_default())
+            .await
+    };
+    if let Err(e) = written {
+        tracing::error!(key = %key, error = %e, "pypi: failed to write yank state");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    let artifact = format!("{}-{}", normalized, version);
+    state
+        .audit
+        .log(AuditEntry::new(action, "api", &artifact, "pypi", &detail));
+    StatusCode::OK.into_response()
+}
+
+fn yanked_key(normalized: &str) -> String {
+    format!("pypi/{}/yanked.json", normalized)
+}
+
+/// Yanked versions of a project (version → reason, `""` for none given).
+async fn read_yanked(
+    storage: &crate::storage::Storage,
+    normalized: &str,
+) -> std::collections::BTreeMap<String, String> {
+    storage
+        .get(&yanked_key(normalized))
+        .await
+        .ok()
+        .and_then(|data| serde_json::from_slice(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Mark the files of locally yanked versions. Upstream yanks already on an
+/// entry are kept.
+fn apply_yanks(
+    normalized: &str,
+    files: &mut [FileEntry],
+    yanked: &std::collections::BTreeMap<String, String>,
+) {
+    if yanked.is_empty() {
+        return;
+    }
+    for file in files {
+        if let Some(reason) = dist_version(normalized, &file.filename).and_then(|v| yanked.get(&v))
+        {
+            file.yanked = Some(reason.clone());
+        }
+    }
+}
+
+/// The JSON API's `yanked_reason`: `null` when no reason was given.
+fn reason_or_null(reason: &str) -> Option<&str> {
+    (!reason.is_empty()).then_some(reason)
+}
+
+// ============================================================================
+// Core metadata (PEP 658)
+// ============================================================================
+
+/// Largest `METADATA` file extracted from a wheel.
+const MAX_CORE_METADATA_SIZE: u64 = 16 * 1024 * 1024;
+
+/// A wheel's `{name}.dist-info/METADATA`. `None` for anything that isn't a
+/// wheel or has no readable metadata — sdists carry no reliable equivalent.
+fn extract_core_metadata(filename: &str, data: &[u8]) -> Option<Vec<u8>> {
+    use std::io::Read;
+
+    if !ends_with_ci(filename, ".whl") {
+        return None;
+    }
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data)).ok()?;
+    let index = (0..archive.len()).find(|&i| {
+        archive.by_index_raw(i).is_ok_and(|entry| {
+            entry
+                .name()
+                .split_once('/')
+                .is_some_and(|(dir, file)| dir.ends_with(".dist-info") && file == "METADATA")
+        })
+    })?;
+    let mut entry = archive.by_index(index).ok()?;
+    if entry.size() > MAX_CORE_METADATA_SIZE {
+        return None;
+    }
+    let mut metadata = Vec::new();
+    entry
+        .by_ref()
+        .take(MAX_CORE_METADATA_SIZE)
+        .read_to_end(&mut metadata)
+        .ok()?;
+    Some(metadata)
+}
+
+/// Store a wheel's core metadata beside it as `{file}.metadata` (+ `.sha256`).
+/// Best-effort: a wheel without readable metadata is still stored and served,
+/// just without `data-dist-info-metadata`.
+async fn store_core_metadata(
+    storage: &crate::storage::Storage,
+    file_key: &str,
+    filename: &str,
+    data: &[u8],
+) {
+    let Some(metadata) = extract_core_metadata(filename, data) else {
+        return;
+    };
+    let key = format!("{}.metadata", file_key);
+    if let Err(e) = storage.put(&key, &metadata).await {
+        tracing::warn!(key = %key, error = %e, "pypi: failed to store core metadata");
+        return;
+    }
+    let hash = hex::encode(sha2::Sha256::digest(&metadata));
+    if let Err(e) = storage
+        .put(&format!("{}.sha256", key), hash.as_bytes())
+        .await
+    {
+        tracing::warn!(key = %key, error = %e, "pypi: failed to store core metadata hash");
+    }
+}
+
+/// GET /simple/{name}/{file}.metadata — a wheel's stored core metadata.
+async fn serve_core_metadata(state: &AppState, normalized: &str, filename: &str) -> Response {
+    let key = format!("pypi/{}/{}", normalized, filename);
+    match state.storage.get(&key).await {
+        Ok(data) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/plain; charset=utf-8"),
+                (header::CACHE_CONTROL, "public, max-age=31536000, immutable"),
+            ],
+            data,
+        )
+            .into_response(),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Header fields and body of an email-style core metadata file. Field names
+/// are lowercased; continuation lines are folded into the previous value.
+fn parse_core_metadata(text: &str) -> (Vec<(String, String)>, String) {
+    let mut fields: Vec<(String, String)> = Vec::new();
+    let mut lines = text.lines();
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = fields.last_mut() {
+                value.push('\n');
+                value.push_str(line.trim_start().trim_start_matches('|'));
+            }
+        } else if let Some((field, value)) = line.split_once(':') {
+            fields.push((field.trim().to_lowercase(), value.trim().to_string()));
+        }
+    }
+    let body = lines.collect::<Vec<_>>().join("\n");
+    (fields, body)
+}
+
+/// The JSON API's `info` object from core metadata, for releases the upstream
+/// doesn't know. Fields absent from the metadata are `null`.
+fn core_metadata_info(text: &str, name: &str, version: &str) -> serde_json::Value {
+    let (fields, body) = parse_core_metadata(text);
+    let first = |field: &str| {
+        fields
+            .iter()
+            .find(|(f, _)| f == field)
+            .map(|(_, v)| v.clone())
+    };
+    let all = |field: &str| -> Vec<String> {
+        fields
+            .iter()
+            .filter(|(f, _)| f == field)
+            .map(|(_, v)| v.clone())
+            .collect()
+    };
+    let project_urls: serde_json::Map<String, serde_json::Value> = all("project-url")
+        .iter()
+        .filter_map(|v| v.split_once(','))
+        .map(|(label, url)| (label.trim().to_string(), url.trim().into()))
+        .collect();
+    let requires_dist = all("requires-dist");
+    let description = first("description").or_else(|| (!body.is_empty()).then_some(body));
+
+    serde_json::json!({
+        "name": first("name").unwrap_or_else(|| name.to_string()),
+        "version": first("version").unwrap_or_else(|| version.to_string()),
+        "summary": first("summary"),
+        "description": description,
+        "description_content_type": first("description-content-type"),
+        "author": first("author"),
+        "author_email": first("author-email"),
+        "maintainer": first("maintainer"),
+        "maintainer_email": first("maintainer-email"),
+        "license": first("license-expression").or_else(|| first("license")),
+        "keywords": first("keywords"),
+        "classifiers": all("classifier"),
+        "platform": first("platform"),
+        "home_page": first("home-page"),
+        "download_url": first("download-url"),
+        "requires_python": first("requires-python"),
+        "requires_dist": (!requires_dist.is_empty()).then_some(requires_dist),
+        "provides_extra": all("provides-extra"),
+        "project_urls": (!project_urls.is_empty()).then_some(project_urls),
+        "yanked": false,
+        "yanked_reason": null,
+    })
+}
+
+// ============================================================================
+// Version ordering (PEP 440)
+// ============================================================================
+
+/// Latest version, as Warehouse picks `info.version`: the highest non-yanked
+/// final release, else the highest non-yanked pre-release, else the highest.
+fn latest_version<'a>(
+    versions: impl Iterator<Item = &'a String>,
+    yanked: &std::collections::BTreeMap<String, String>,
+) -> Option<String> {
+    let versions: Vec<&String> = versions.collect();
+    let highest = |keep: &dyn Fn(&str) -> bool| {
+        versions
+            .iter()
+            .filter(|v| keep(v))
+            .max_by(|a, b| cmp_versions(a, b))
+            .map(|v| v.to_string())
+    };
+    highest(&|v| !yanked.contains_key(v) && !is_prerelease(v))
+        .or_else(|| highest(&|v| !yanked.contains_key(v)))
+        .or_else(|| highest(&|_| true))
+}
+
+fn is_prerelease(version: &str) -> bool {
+    let (_, _, (phase, _)) = version_parts(version);
+    phase < 2
+}
+
+/// Compare two PEP 440 versions: epoch, release segment (zero-padded), then
+/// dev < pre-release (a < b < rc) < final < post. Local labels are ignored;
+/// anything unparsed falls back to string order.
+pub(crate) fn cmp_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    let (epoch_a, release_a, suffix_a) = version_parts(a);
+    let (epoch_b, release_b, suffix_b) = version_parts(b);
+    let len = release_a.len().max(release_b.len());
+    let segment = |r: &[u64], i: usize| r.get(i).copied().unwrap_or(0);
+    epoch_a
+        .cmp(&epoch_b)
+        .then_with(|| {
+            (0..len)
+                .map(|i| segment(&release_a, i).cmp(&segment(&release_b, i)))
+                .find(|o| o.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .then_with(|| suffix_a.cmp(&suffix_b))
+        .then_with(|| a.cmp(b))
+}
+
+/// `(epoch, release, (phase, phase number))` where phase is 0 dev, 1 pre,
+/// 2 final, 3 post. Pre-release letters order within phase 1 via the number's
+/// high bits (a < b < rc).
+fn version_parts(version: &str) -> (u64, Vec<u64>, (u8, u64)) {
+    let v = version.trim().to_lowercase();
+    let v = v.strip_prefix('v').unwrap_or(&v);
+    let v = v.split('+').next().unwrap_or(v);
+    let (epoch, v) = match v.split_once('!') {
+        Some((e, rest)) => (e.parse().unwrap_or(0), rest),
+        None => (0, v),
+    };
+    let release_end = v
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(v.len());
+    let release = v[..release_end]
+        .trim_end_matches('.')
+        .split('.')
+        .filter_map(|s| s.parse().ok())
+        .collect();
+    let suffix = v[release_end..].trim_start_matches(['.', '-', '_']);
+    let number = |s: &str| -> u64 {
+        let digits: String = s
+            .trim_start_matches(['.', '-', '_'])
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        digits.parse().unwrap_or(0)
+    };
+    let phase = if suffix.is_empty() {
+        (2, 0)
+    } else if let Some(n) = suffix.strip_prefix("dev") {
+        (0, number(n))
+    } else if let Some(n) = ["rc", "c", "preview", "pre"]
+        .iter()
+        .find_map(|p| suffix.strip_prefix(p))
+    {
+        (1, (2 << 32) | number(n))
+    } else if let Some(n) = ["beta", "b"].iter().find_map(|p| suffix.strip_prefix(p)) {
+        (1, (1 << 32) | number(n))
+    } else if let Some(n) = ["alpha", "a"].iter().find_map(|p| suffix.strip_prefix(p)) {
+        (1, number(n))
+    } else if let Some(n) = ["post", "rev", "r"]
+        .iter()
+        .find_map(|p| suffix.strip_prefix(p))
+    {
+        (3, number(n))
+    } else if suffix.starts_with(|c: char| c.is_ascii_digit()) {
+        // `1.0-1` is an implicit post-release.
+        (3, number(suffix))
+    } else {
+        (2, 0)
+    };
+    (epoch, release, phase)
+}
+
 // ============================================================================
 // PEP 691 JSON responses — typed structs per spec
 // ============================================================================
 
+#[derive(Clone, Default)]
 struct FileEntry {
     filename: String,
     sha256: Option<String>,
+    /// SHA-256 of the stored PEP 658 `.metadata` file, when there is one.
+    core_metadata: Option<String>,
+    /// PEP 592 yank reason (`""` when yanked without one).
+    yanked: Option<String>,
 }
 
 /// PEP 691 top-level response — typed to prevent field-name drift.
@@ -698,9 +1496,17 @@ struct Pep691File {
     url: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     hashes: Option<Pep691Hashes>,
+    /// PEP 714 name, with the PEP 658 one alongside for older clients.
+    #[serde(rename = "core-metadata", skip_serializing_if = "Option::is_none")]
+    core_metadata: Option<Pep691Hashes>,
+    #[serde(rename = "dist-info-metadata", skip_serializing_if = "Option::is_none")]
+    dist_info_metadata: Option<Pep691Hashes>,
+    /// `true`, or the reason string when one was given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    yanked: Option<serde_json::Value>,
 }
 
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, Clone)]
 struct Pep691Hashes {
     sha256: String,
 }
@@ -716,6 +1522,18 @@ fn versions_json_response(normalized: &str, files: &[FileEntry], base_url: &str)
                 .sha256
                 .as_ref()
                 .map(|h| Pep691Hashes { sha256: h.clone() }),
+            core_metadata: f
+                .core_metadata
+                .as_ref()
+                .map(|h| Pep691Hashes { sha256: h.clone() }),
+            dist_info_metadata: f
+                .core_metadata
+                .as_ref()
+                .map(|h| Pep691Hashes { sha256: h.clone() }),
+            yanked: f.yanked.as_ref().map(|reason| match reason.as_str() {
+                "" => serde_json::Value::Bool(true),
+                _ => serde_json::Value::String(reason.clone()),
+            }),
         })
         .collect();
 
@@ -747,13 +1565,25 @@ fn versions_html_response(normalized: &str, files: &[FileEntry], base_url: &str)
             .as_ref()
             .map(|h| format!("#sha256={}", h))
             .unwrap_or_default();
+        let mut attrs = String::new();
+        if let Some(h) = &f.core_metadata {
+            let _ = write!(
+                attrs,
+                " data-core-metadata=\"sha256={}\" data-dist-info-metadata=\"sha256={}\"",
+                h, h
+            );
+        }
+        if let Some(reason) = &f.yanked {
+            let _ = write!(attrs, " data-yanked=\"{}\"", html_escape(reason));
+        }
         let _ = writeln!(
             html,
-            "<a href=\"{}/simple/{}/{}{}\">{}</a><br>",
+            "<a href=\"{}/simple/{}/{}{}\"{}>{}</a><br>",
             base,
             normalized,
             html_escape(&f.filename),
             hash_fragment,
+            attrs,
             html_escape(&f.filename)
         );
     }
@@ -836,6 +1666,15 @@ async fn ensure_pypi_dates_cached(state: &AppState, normalized: &str) {
     }
 }
 
+/// A stored `.sha256` sidecar's hex digest.
+async fn read_hash(storage: &crate::storage::Storage, key: &str) -> Option<String> {
+    storage
+        .get(key)
+        .await
+        .ok()
+        .and_then(|d| String::from_utf8(d.to_vec()).ok())
+}
+
 /// Normalize package name according to PEP 503.
 fn normalize_name(name: &str) -> String {
     name.to_lowercase().replace(['-', '_', '.'], "-")
@@ -894,20 +1733,28 @@ fn extract_filename(url: &str) -> Option<&str> {
 
 /// Parse upstream PyPI simple index HTML into file entries.
 ///
-/// Extracts filenames and optional `#sha256=` fragments from `<a href="...">` links.
+/// Extracts filenames and optional `#sha256=` fragments from `<a href="...">` links,
+/// and PEP 592 `data-yanked` from the same anchor.
 fn parse_upstream_files(html: &str) -> Vec<FileEntry> {
     let mut files = Vec::new();
     let mut remaining = html;
 
     while let Some(href_start) = remaining.find("href=\"") {
+        let before = &remaining[..href_start];
+        let tag_head = &before[before.rfind('<').unwrap_or(0)..];
         remaining = &remaining[href_start + 6..];
         if let Some(href_end) = remaining.find('"') {
             let url = &remaining[..href_end];
             if let Some(filename) = extract_filename(url) {
                 let sha256 = url.find("#sha256=").map(|pos| url[pos + 8..].to_string());
+                let tag_end = remaining.find('>').unwrap_or(remaining.len());
+                let tag_tail = &remaining[href_end..tag_end.max(href_end)];
+                let yanked = data_yanked(tag_head).or_else(|| data_yanked(tag_tail));
                 files.push(FileEntry {
                     filename: filename.to_string(),
                     sha256,
+                    yanked,
+                    ..Default::default()
                 });
             }
             remaining = &remaining[href_end..];
@@ -916,6 +1763,28 @@ fn parse_upstream_files(html: &str) -> Vec<FileEntry> {
     files
 }
 
+/// Value of a `data-yanked` attribute in an anchor tag fragment: the unescaped
+/// reason, or `""` for a bare attribute.
+fn data_yanked(tag: &str) -> Option<String> {
+    let rest = &tag[tag.find("data-yanked")? + "data-yanked".len()..];
+    let Some(value) = rest.trim_start().strip_prefix('=') else {
+        return Some(String::new());
+    };
+    let value = value.trim_start();
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let value = &value[1..];
+    let value = &value[..value.find(quote).unwrap_or(value.len())];
+    Some(
+        value
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&#x27;", "'")
+            .replace("&amp;", "&"),
+    )
+}
+
 /// Merge upstream and local file lists.
 ///
 /// Local entries take precedence (they have verified hashes from storage).
@@ -929,10 +1798,7 @@ fn merge_file_lists(upstream: Vec<FileEntry>, local: &[FileEntry]) -> Vec<FileEn
     // never carries a duplicate filename regardless of caller input.
     for f in local {
         if seen.insert(f.filename.clone()) {
-            result.push(FileEntry {
-                filename: f.filename.clone(),
-                sha256: f.sha256.clone(),
-            });
+            result.push(f.clone());
         }
     }
 
@@ -985,6 +1851,38 @@ fn find_file_url(html: &str, target_filename: &str) -> Option<String> {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn test_declared_license_precedence() {
+        let gpl = ["License :: OSI Approved :: GNU General Public License v3 (GPLv3)"];
+        assert_eq!(
+            declared_license(Some("MIT OR Apache-2.0"), Some("BSD"), &gpl).as_deref(),
+            Some("MIT OR Apache-2.0")
+        );
+        assert_eq!(
+            declared_license(None, Some("BSD-3-Clause"), &gpl).as_deref(),
+            Some("BSD-3-Clause")
+        );
+        // A `License` NORA cannot read as a license, or holding the license
+        // text, defers to the classifiers.
+        assert_eq!(
+            declared_license(None, Some("BSD License"), &gpl).as_deref(),
+            Some("GPL-3.0-only")
+        );
+        assert_eq!(
+            declared_license(
+                Some(""),
+                Some("Copyright 2026\nPermission is granted"),
+                &gpl
+            )
+            .as_deref(),
+            Some("GPL-3.0-only")
+        );
+        assert_eq!(
+            declared_license(None, None, &["License :: OSI Approved"]),
+            None
+        );
+    }
     use proptest::prelude::*;
 
     proptest! {
@@ -1257,10 +2155,12 @@ mod tests {
         let upstream = vec![FileEntry {
             filename: "pkg-1.0-cp314-cp314-linux_x86_64.whl".to_string(),
             sha256: Some("uuu".to_string()),
+            ..Default::default()
         }];
         let local = vec![FileEntry {
             filename: "pkg-1.0-cp310-cp310-linux_x86_64.whl".to_string(),
             sha256: Some("lll".to_string()),
+            ..Default::default()
         }];
         let merged = merge_file_lists(upstream, &local);
         assert_eq!(merged.len(), 2);
@@ -1274,10 +2174,12 @@ mod tests {
         let upstream = vec![FileEntry {
             filename: "pkg-1.0.tar.gz".to_string(),
             sha256: Some("upstream-hash".to_string()),
+            ..Default::default()
         }];
         let local = vec![FileEntry {
             filename: "pkg-1.0.tar.gz".to_string(),
             sha256: Some("local-verified-hash".to_string()),
+            ..Default::default()
         }];
         let merged = merge_file_lists(upstream, &local);
         assert_eq!(merged.len(), 1);
@@ -1289,6 +2191,7 @@ mod tests {
         let local = vec![FileEntry {
             filename: "pkg-1.0.tar.gz".to_string(),
             sha256: None,
+            ..Default::default()
         }];
         let merged = merge_file_lists(vec![], &local);
         assert_eq!(merged.len(), 1);
@@ -1299,6 +2202,7 @@ mod tests {
         let upstream = vec![FileEntry {
             filename: "pkg-1.0.tar.gz".to_string(),
             sha256: Some("hash".to_string()),
+            ..Default::default()
         }];
         let merged = merge_file_lists(upstream, &[]);
         assert_eq!(merged.len(), 1);
@@ -1319,14 +2223,17 @@ mod tests {
             FileEntry {
                 filename: "torch-1.0.whl".to_string(),
                 sha256: Some("from-A".to_string()),
+                ..Default::default()
             },
             FileEntry {
                 filename: "torch-1.0.whl".to_string(),
                 sha256: Some("from-B".to_string()),
+                ..Default::default()
             },
             FileEntry {
                 filename: "torchvision-1.0.whl".to_string(),
                 sha256: Some("from-B".to_string()),
+                ..Default::default()
             },
         ];
         let merged = merge_file_lists(upstream, &[]);
@@ -1355,11 +2262,11 @@ mod tests {
         ) {
             let upstream: Vec<FileEntry> = upstream_names
                 .iter()
-                .map(|n| FileEntry { filename: n.clone(), sha256: None })
+                .map(|n| FileEntry { filename: n.clone(), sha256: None, ..Default::default() })
                 .collect();
             let local: Vec<FileEntry> = local_names
                 .iter()
-                .map(|n| FileEntry { filename: n.clone(), sha256: Some("L".to_string()) })
+                .map(|n| FileEntry { filename: n.clone(), sha256: Some("L".to_string()), ..Default::default() })
                 .collect();
             let merged = merge_file_lists(upstream, &local);
             // No filename appears twice.
@@ -1375,6 +2282,97 @@ mod tests {
             }
         }
     }
+
+    /// A minimal wheel: `{name}.dist-info/METADATA` plus a module.
+    pub(super) fn build_wheel(dist_info: &str, metadata: &str) -> Vec<u8> {
+        use std::io::Write as _;
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default();
+        writer.start_file("pkg/__init__.py", options).unwrap();
+        writer.write_all(b"").unwrap();
+        writer
+            .start_file(format!("{}/METADATA", dist_info), options)
+            .unwrap();
+        writer.write_all(metadata.as_bytes()).unwrap();
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_extract_core_metadata_from_wheel() {
+        let wheel = build_wheel("demo-1.0.dist-info", "Name: demo\nVersion: 1.0\n");
+        assert_eq!(
+            extract_core_metadata("demo-1.0-py3-none-any.whl", &wheel).as_deref(),
+            Some(&b"Name: demo\nVersion: 1.0\n"[..])
+        );
+        // Sdists and broken archives have no PEP 658 metadata.
+        assert!(extract_core_metadata("demo-1.0.tar.gz", &wheel).is_none());
+        assert!(extract_core_metadata("demo-1.0-py3-none-any.whl", b"not a zip").is_none());
+        // A METADATA file outside a top-level .dist-info is not the wheel's.
+        let nested = build_wheel("vendored/x-1.0.dist-info", "Name: x\n");
+        assert!(extract_core_metadata("demo-1.0-py3-none-any.whl", &nested).is_none());
+    }
+
+    #[test]
+    fn test_core_metadata_info() {
+        let text = "Metadata-Version: 2.1\nName: Demo\nVersion: 1.0\nSummary: A demo\n\
+                    Requires-Dist: requests>=2\nRequires-Dist: click\n\
+                    Classifier: Programming Language :: Python\n\
+                    Project-URL: Source, https://example.com/src\n\
+                    License: MIT\n  second line\n\nLong description.\n";
+        let info = core_metadata_info(text, "demo", "1.0");
+        assert_eq!(info["name"], "Demo");
+        assert_eq!(info["summary"], "A demo");
+        assert_eq!(info["requires_dist"][1], "click");
+        assert_eq!(info["classifiers"][0], "Programming Language :: Python");
+        assert_eq!(info["project_urls"]["Source"], "https://example.com/src");
+        assert_eq!(info["license"], "MIT\nsecond line");
+        assert_eq!(info["description"], "Long description.");
+        assert!(info["requires_python"].is_null());
+
+        let empty = core_metadata_info("", "demo", "2.0");
+        assert_eq!(empty["version"], "2.0");
+        assert!(empty["requires_dist"].is_null());
+    }
+
+    #[test]
+    fn test_cmp_versions_pep440() {
+        use std::cmp::Ordering::*;
+        assert_eq!(cmp_versions("1.10", "1.9"), Greater);
+        assert_eq!(cmp_versions("1.0.dev1", "1.0a1"), Less);
+        assert_eq!(cmp_versions("1.0a1", "1.0b1"), Less);
+        assert_eq!(cmp_versions("1.0b2", "1.0rc1"), Less);
+        assert_eq!(cmp_versions("1.0rc1", "1.0"), Less);
+        assert_eq!(cmp_versions("1.0", "1.0.post1"), Less);
+        assert_eq!(cmp_versions("2!0.1", "9.9"), Greater);
+        assert_eq!(cmp_versions("1.0+local", "1.0"), Greater);
+    }
+
+    #[test]
+    fn test_latest_version_prefers_stable_unyanked() {
+        let versions: Vec<String> = ["1.0", "1.1", "2.0rc1"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let mut yanked = std::collections::BTreeMap::new();
+        assert_eq!(latest_version(versions.iter(), &yanked).unwrap(), "1.1");
+        yanked.insert("1.1".to_string(), String::new());
+        assert_eq!(latest_version(versions.iter(), &yanked).unwrap(), "1.0");
+        yanked.insert("1.0".to_string(), String::new());
+        assert_eq!(latest_version(versions.iter(), &yanked).unwrap(), "2.0rc1");
+    }
+
+    #[test]
+    fn test_parse_upstream_files_data_yanked() {
+        let html = r#"<a href="https://files/x-1.0.tar.gz#sha256=aa" data-yanked="bad &amp; broken">x-1.0.tar.gz</a>
+<a data-yanked href="https://files/x-1.1.tar.gz">x-1.1.tar.gz</a>
+<a href="https://files/x-1.2.tar.gz">x-1.2.tar.gz</a>"#;
+        let files = parse_upstream_files(html);
+        assert_eq!(files.len(), 3);
+        assert_eq!(files[0].yanked.as_deref(), Some("bad & broken"));
+        assert_eq!(files[0].sha256.as_deref(), Some("aa"));
+        assert_eq!(files[1].yanked.as_deref(), Some(""));
+        assert!(files[2].yanked.is_none());
+    }
 }
 
 // ============================================================================
@@ -1619,6 +2617,345 @@ mod integration_tests {
 
         assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
+
+    /// A twine `file_upload` multipart body and its content type.
+    fn twine_upload(name: &str, version: &str, filename: &str, data: &[u8]) -> (String, Vec<u8>) {
+        let boundary = "nora-test-boundary";
+        let mut body = Vec::new();
+        for (field, value) in [
+            (":action", "file_upload"),
+            ("name", name),
+            ("version", version),
+        ] {
+            body.extend_from_slice(
+                format!(
+                    "--{boundary}\r\nContent-Disposition: form-data; name=\"{field}\"\r\n\r\n{value}\r\n"
+                )
+                .as_bytes(),
+            );
+        }
+        body.extend_from_slice(
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"content\"; filename=\"{filename}\"\r\nContent-Type: application/octet-stream\r\n\r\n"
+            )
+            .as_bytes(),
+        );
+        body.extend_from_slice(data);
+        body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+        (format!("multipart/form-data; boundary={boundary}"), body)
+    }
+
+    const DEMO_METADATA: &str =
+        "Metadata-Version: 2.1\nName: demo\nVersion: 1.0\nSummary: Demo package\nRequires-Dist: click\n";
+
+    async fn upload_demo_wheel(app: &axum::Router) {
+        let wheel = super::tests::build_wheel("demo-1.0.dist-info", DEMO_METADATA);
+        let (content_type, body) = twine_upload("demo", "1.0", "demo-1.0-py3-none-any.whl", &wheel);
+        let resp = send_with_headers(
+            app,
+            Method::POST,
+            "/simple/",
+            vec![("Content-Type", content_type.as_str())],
+            body,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_pypi_upload_wheel_serves_core_metadata() {
+        let ctx = create_test_context();
+        upload_demo_wheel(&ctx.app).await;
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/simple/demo/demo-1.0-py3-none-any.whl.metadata",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let metadata = body_bytes(resp).await;
+        assert_eq!(metadata.as_ref(), DEMO_METADATA.as_bytes());
+        let digest = hex::encode(<sha2::Sha256 as sha2::Digest>::digest(&metadata));
+
+        let resp = send(&ctx.app, Method::GET, "/simple/demo/", "").await;
+        let html = String::from_utf8(body_bytes(resp).await.to_vec()).unwrap();
+        assert!(html.contains(&format!("data-dist-info-metadata=\"sha256={digest}\"")));
+        // The .metadata file is not listed as a distribution of its own.
+        assert!(!html.contains(">demo-1.0-py3-none-any.whl.metadata<"));
+
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::GET,
+            "/simple/demo/",
+            vec![("Accept", "application/vnd.pypi.simple.v1+json")],
+            "",
+        )
+        .await;
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["files"][0]["core-metadata"]["sha256"], digest.as_str());
+
+        // No metadata for a file NORA doesn't hold.
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/simple/demo/demo-2.0-py3-none-any.whl.metadata",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_pypi_yank_and_unyank() {
+        let ctx = create_test_context();
+        ctx.state
+            .storage
+            .put("pypi/flask/flask-2.0.tar.gz", b"sdist")
+            .await
+            .unwrap();
+        ctx.state
+            .storage
+            .put("pypi/flask/flask-2.1.tar.gz", b"sdist")
+            .await
+            .unwrap();
+
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/pypi/flask/2.0/yank",
+            r#"{"reason":"broken <build>"}"#,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = send(&ctx.app, Method::GET, "/simple/flask/", "").await;
+        let html = String::from_utf8(body_bytes(resp).await.to_vec()).unwrap();
+        assert!(html.contains("flask-2.0.tar.gz\" data-yanked=\"broken &lt;build&gt;\""));
+        assert!(!html.contains("flask-2.1.tar.gz\" data-yanked"));
+
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::GET,
+            "/simple/flask/",
+            vec![("Accept", "application/vnd.pypi.simple.v1+json")],
+            "",
+        )
+        .await;
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        let yanked: Vec<_> = json["files"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| (f["filename"].as_str().unwrap(), f.get("yanked").cloned()))
+            .collect();
+        assert!(yanked.contains(&("flask-2.0.tar.gz", Some("broken <build>".into()))));
+        assert!(yanked.contains(&("flask-2.1.tar.gz", None)));
+
+        // A bare yank has no reason: `yanked: true`.
+        let resp = send(&ctx.app, Method::PUT, "/pypi/flask/2.1/yank", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = send(&ctx.app, Method::GET, "/pypi/flask/2.1/json", "").await;
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["info"]["yanked"], true);
+        assert!(json["info"]["yanked_reason"].is_null());
+        assert_eq!(json["urls"][0]["yanked"], true);
+
+        for version in ["2.0", "2.1"] {
+            let uri = format!("/pypi/flask/{version}/yank");
+            let resp = send(&ctx.app, Method::DELETE, &uri, "").await;
+            assert_eq!(resp.status(), StatusCode::OK);
+        }
+        let resp = send(&ctx.app, Method::GET, "/simple/flask/", "").await;
+        let html = String::from_utf8(body_bytes(resp).await.to_vec()).unwrap();
+        assert!(!html.contains("data-yanked"));
+        assert!(ctx
+            .state
+            .storage
+            .get("pypi/flask/yanked.json")
+            .await
+            .is_err());
+
+        // Only versions NORA holds can be yanked.
+        let resp = send(&ctx.app, Method::PUT, "/pypi/flask/9.9/yank", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = send(&ctx.app, Method::PUT, "/pypi/flask/2.0/yank", "not json").await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let resp = send(&ctx.app, Method::GET, "/pypi/flask/2.0/yank", "").await;
+        assert_eq!(resp.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn test_pypi_yank_requires_admin() {
+        use crate::test_helpers::create_test_context_with_auth;
+        use base64::Engine;
+
+        let ctx = create_test_context_with_auth(&[("dev", "dev-pass")]);
+        ctx.state
+            .storage
+            .put("pypi/flask/flask-2.0.tar.gz", b"sdist")
+            .await
+            .unwrap();
+
+        let basic = format!(
+            "Basic {}",
+            base64::engine::general_purpose::STANDARD.encode("dev:dev-pass")
+        );
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::PUT,
+            "/pypi/flask/2.0/yank",
+            vec![("Authorization", basic.as_str())],
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let admin_token = ctx
+            .state
+            .tokens
+            .as_ref()
+            .unwrap()
+            .create_token("root", 1, None, crate::tokens::Role::Admin)
+            .unwrap();
+        let bearer = format!("Bearer {admin_token}");
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::PUT,
+            "/pypi/flask/2.0/yank",
+            vec![("Authorization", bearer.as_str())],
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_pypi_json_api_local() {
+        let ctx = create_test_context();
+        upload_demo_wheel(&ctx.app).await;
+        ctx.state
+            .storage
+            .put("pypi/demo/demo-0.9.tar.gz", b"old sdist")
+            .await
+            .unwrap();
+
+        let resp = send(&ctx.app, Method::GET, "/pypi/demo/json", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["info"]["name"], "demo");
+        assert_eq!(json["info"]["version"], "1.0");
+        assert_eq!(json["info"]["summary"], "Demo package");
+        assert_eq!(json["info"]["requires_dist"][0], "click");
+        let file = &json["urls"][0];
+        assert_eq!(file["filename"], "demo-1.0-py3-none-any.whl");
+        assert_eq!(file["packagetype"], "bdist_wheel");
+        assert_eq!(file["python_version"], "py3");
+        assert!(file["url"]
+            .as_str()
+            .unwrap()
+            .ends_with("/simple/demo/demo-1.0-py3-none-any.whl"));
+        assert_eq!(file["digests"]["sha256"].as_str().unwrap().len(), 64);
+        assert_eq!(json["releases"]["0.9"][0]["packagetype"], "sdist");
+
+        let resp = send(&ctx.app, Method::GET, "/pypi/Demo/0.9/json", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["info"]["version"], "0.9");
+        assert_eq!(json["urls"][0]["filename"], "demo-0.9.tar.gz");
+        assert!(json.get("releases").is_none());
+
+        let resp = send(&ctx.app, Method::GET, "/pypi/demo/3.0/json", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = send(&ctx.app, Method::GET, "/pypi/nothing/json", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_pypi_json_api_merges_upstream() {
+        use crate::test_helpers::create_test_context_with_config;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let upstream = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/pypi/flask/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "info": {"name": "Flask", "version": "3.0", "summary": "upstream"},
+                "urls": [{"filename": "flask-3.0.tar.gz", "url": "https://files.example/flask-3.0.tar.gz"}],
+                "releases": {
+                    "3.0": [{"filename": "flask-3.0.tar.gz", "url": "https://files.example/flask-3.0.tar.gz"}]
+                }
+            })))
+            .mount(&upstream)
+            .await;
+        let upstream_url = format!("{}/simple/", upstream.uri());
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.pypi.proxy = Some(upstream_url);
+        });
+        ctx.state
+            .storage
+            .put("pypi/flask/flask-2.0.tar.gz", b"hosted")
+            .await
+            .unwrap();
+
+        let resp = send(&ctx.app, Method::GET, "/pypi/flask/json", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["info"]["summary"], "upstream");
+        let url = json["releases"]["3.0"][0]["url"].as_str().unwrap();
+        assert!(url.ends_with("/simple/flask/flask-3.0.tar.gz"), "{url}");
+        assert!(!url.contains("files.example"));
+        assert_eq!(json["releases"]["2.0"][0]["filename"], "flask-2.0.tar.gz");
+        assert_eq!(json["urls"][0]["filename"], "flask-3.0.tar.gz");
+    }
+
+    /// With no stored wheel, the license filter asks the upstream JSON API for the
+    /// release — here its license classifier.
+    #[tokio::test]
+    async fn test_pypi_download_blocked_by_license_policy() {
+        use crate::test_helpers::create_test_context_with_config;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let upstream = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/pypi/copyleft/1.0/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "info": {
+                    "license": "",
+                    "license_expression": null,
+                    "classifiers": [
+                        "License :: OSI Approved :: GNU Affero General Public License v3 or later (AGPLv3+)"
+                    ]
+                }
+            })))
+            .mount(&upstream)
+            .await;
+        let proxy_url = format!("{}/simple", upstream.uri());
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.pypi.proxy = Some(proxy_url);
+            cfg.curation.mode = crate::config::CurationMode::Enforce;
+            cfg.curation.licenses.enabled = true;
+            cfg.curation.licenses.deny = vec!["AGPL-*".to_string()];
+        });
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/simple/copyleft/copyleft-1.0.tar.gz",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let body: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(body["context"]["rule"], "license");
+        assert!(body["context"]["reason"]
+            .as_str()
+            .unwrap()
+            .contains("is licensed AGPL-3.0-or-later"));
+    }
 }
 
 // ============================================================================
@@ -1636,6 +2973,7 @@ mod spec_conformance_tests {
         let files = vec![FileEntry {
             filename: "pkg-1.0.tar.gz".into(),
             sha256: Some("abcdef1234567890".into()),
+            ..Default::default()
         }];
         let response = versions_json_response("pkg", &files, "http://nora:4000");
         let body = response.into_body();
@@ -1659,6 +2997,7 @@ mod spec_conformance_tests {
         let files = vec![FileEntry {
             filename: "pkg-1.0.tar.gz".into(),
             sha256: None,
+            ..Default::default()
         }];
         let response = versions_json_response("pkg", &files, "http://nora:4000");
         let bytes =
@@ -1696,6 +3035,7 @@ mod spec_conformance_tests {
         let files = vec![FileEntry {
             filename: "pkg-1.0.tar.gz".into(),
             sha256: None,
+            ..Default::default()
         }];
         let response = versions_json_response("pkg", &files, "http://nora:4000");
         let bytes =
@@ -1730,10 +3070,12 @@ mod spec_conformance_tests {
             FileEntry {
                 filename: "pkg-1.0.tar.gz".into(),
                 sha256: Some("aaa".into()),
+                ..Default::default()
             },
             FileEntry {
                 filename: "pkg-2.0.whl".into(),
                 sha256: Some("bbb".into()),
+                ..Default::default()
             },
         ];
         let response = versions_json_response("pkg", &files, "http://nora:4000");
@@ -1762,10 +3104,12 @@ mod spec_conformance_tests {
             FileEntry {
                 filename: "requests-2.31.0.tar.gz".into(),
                 sha256: Some("aaa111".into()),
+                ..Default::default()
             },
             FileEntry {
                 filename: "requests-2.31.0-py3-none-any.whl".into(),
                 sha256: Some("bbb222".into()),
+                ..Default::default()
             },
         ];
         let response = versions_html_response("requests", &files, "http://nora:4000");
@@ -1795,6 +3139,7 @@ mod spec_conformance_tests {
         let files = vec![FileEntry {
             filename: "pkg-1.0.tar.gz".into(),
             sha256: Some("abc".into()),
+            ..Default::default()
         }];
         let response = versions_json_response("pkg", &files, "http://nora:4000/");
         let bytes =
@@ -1845,6 +3190,7 @@ mod spec_conformance_tests {
         let files = vec![FileEntry {
             filename: "pkg-1.0.tar.gz".into(),
             sha256: Some("abc123".into()),
+            ..Default::default()
         }];
         let response = versions_json_response("pkg", &files, "http://nora:4000");
         let bytes =
@@ -1860,4 +3206,47 @@ mod spec_conformance_tests {
         // Snapshot the structure
         insta::assert_json_snapshot!("pypi_pep691_response_structure", json);
     }
+
+    /// PEP 658/714 and PEP 592 attributes in both index formats.
+    #[test]
+    fn test_core_metadata_and_yanked_rendering() {
+        let files = vec![FileEntry {
+            filename: "x-1.0-py3-none-any.whl".into(),
+            sha256: Some("aa".into()),
+            core_metadata: Some("bb".into()),
+            yanked: Some("oops".into()),
+        }];
+        let read = |response: Response| {
+            let bytes = futures::executor::block_on(axum::body::to_bytes(
+                response.into_body(),
+                1024 * 1024,
+            ))
+            .unwrap();
+            String::from_utf8(bytes.to_vec()).unwrap()
+        };
+
+        let html = read(versions_html_response("x", &files, "http://nora"));
+        assert!(html.contains(r#"data-core-metadata="sha256=bb""#));
+        assert!(html.contains(r#"data-dist-info-metadata="sha256=bb""#));
+        assert!(html.contains(r#"data-yanked="oops""#));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&read(versions_json_response("x", &files, "http://nora")))
+                .unwrap();
+        let file = &json["files"][0];
+        assert_eq!(file["core-metadata"]["sha256"], "bb");
+        assert_eq!(file["dist-info-metadata"]["sha256"], "bb");
+        assert_eq!(file["yanked"], "oops");
+
+        let plain = vec![FileEntry {
+            filename: "x-1.0.tar.gz".into(),
+            yanked: Some(String::new()),
+            ..Default::default()
+        }];
+        let json: serde_json::Value =
+            serde_json::from_str(&read(versions_json_response("x", &plain, "http://nora")))
+                .unwrap();
+        assert_eq!(json["files"][0]["yanked"], true);
+        assert!(json["files"][0].get("core-metadata").is_none());
+    }
 }
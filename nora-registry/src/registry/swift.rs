@@ -0,0 +1,1353 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+//! Swift Package Registry (SE-0292) — hosted packages for SwiftPM.
+//!
+//! Implements the registry service API, version 1:
+//!   GET    /swift/{scope}/{name}                               — list releases
+//!   GET    /swift/{scope}/{name}/{version}                     — release metadata
+//!   GET    /swift/{scope}/{name}/{version}/Package.swift       — manifest (`?swift-version=`)
+//!   GET    /swift/{scope}/{name}/{version}.zip                 — source archive
+//!   GET    /swift/identifiers?url={repository URL}             — identifiers for a repository
+//!   PUT    /swift/{scope}/{name}/{version}                     — publish (multipart)
+//!   POST   /swift/login                                        — credential check
+//!
+//! Client config:
+//!   swift package-registry set http://nora:4000/swift
+//!   swift package-registry login http://nora:4000/swift --token nra_...
+//!   swift package-registry publish acme.Lib 1.0.0
+//!
+//! Package identifiers are case-insensitive: storage keys are lowercased and
+//! each release record keeps the `scope.name` it was published as. A publish
+//! reads `Package.swift` (and any `Package@swift-X.swift`) out of the source
+//! archive once, so manifest requests never reopen the zip. Signed releases
+//! (`cms-1.0.0`) are stored and served with their signature; verifying it is
+//! the client's job. The archive goes through `Storage::put`, so its hash pin
+//! is checked on every full download.
+
+use crate::activity_log::{ActionType, ActivityEntry};
+use crate::audit::AuditEntry;
+use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
+use crate::registry::{nora_base_url, read_json_sidecars};
+use crate::registry_type::RegistryType;
+use crate::validation::validate_storage_key;
+use crate::version::{cmp_semver, is_semver};
+use crate::AppState;
+use axum::{
+    body::Bytes,
+    extract::{multipart::MultipartRejection, Multipart, Path, Query, Request, State},
+    http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Extension, Router,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sha2::Digest;
+use std::collections::HashMap;
+use std::io::Read;
+
+/// Storage prefix and file suffix for repo index scanning.
+pub const INDEX_PATTERN: (&str, &str) = ("swift/", ".zip");
+
+/// The one registry API version served (`Content-Version`, `Accept: ...v1+json`).
+const API_VERSION: &str = "1";
+const CONTENT_VERSION: &str = "content-version";
+const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+const JSON_CONTENT_TYPE: &str = "application/json";
+const ZIP_CONTENT_TYPE: &str = "application/zip";
+const SWIFT_CONTENT_TYPE: &str = "text/x-swift";
+const SIGNATURE_FORMAT_HEADER: &str = "x-swift-package-signature-format";
+const SIGNATURE_HEADER: &str = "x-swift-package-signature";
+/// The only signature format SwiftPM produces.
+const SIGNATURE_FORMAT: &str = "cms-1.0.0";
+const META_DIR: &str = ".nora-meta";
+/// Upper bound on a manifest read from an uploaded archive.
+const MAX_MANIFEST: u64 = 1 << 20;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/swift/login", post(login))
+        .route("/swift/identifiers", get(lookup_identifiers))
+        .route("/swift/{scope}/{name}", get(list_releases))
+        .route(
+            "/swift/{scope}/{name}/{version}",
+            get(release_or_archive).put(publish),
+        )
+        .route(
+            "/swift/{scope}/{name}/{version}/Package.swift",
+            get(fetch_manifest),
+        )
+        .layer(middleware::from_fn(api_version))
+}
+
+// ============================================================================
+// Identifiers and storage keys
+// ============================================================================
+
+/// A package scope: alphanumerics and single hyphens, no leading or trailing
+/// hyphen, at most 39 characters (GitHub's organization name rules).
+fn valid_scope(scope: &str) -> bool {
+    !scope.is_empty()
+        && scope.len() <= 39
+        && scope
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        && !scope.starts_with('-')
+        && !scope.ends_with('-')
+        && !scope.contains("--")
+}
+
+/// A package name: an alphanumeric followed by up to 99 alphanumerics,
+/// hyphens or underscores.
+fn valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 100
+        && name.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_'))
+}
+
+/// A release version: semver, ASCII alphanumerics plus `.`, `-` and `+`.
+fn valid_version(version: &str) -> bool {
+    version.len() <= 128
+        && is_semver(version)
+        && version
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-' | b'+'))
+}
+
+/// `Package.swift`, or a version-specific `Package@swift-5.9.swift`.
+fn is_manifest_file(file: &str) -> bool {
+    file == "Package.swift"
+        || file
+            .strip_prefix("Package@swift-")
+            .and_then(|rest| rest.strip_suffix(".swift"))
+            .is_some_and(valid_swift_version)
+}
+
+/// A `swift-version` query value: dot-separated numbers (`5`, `5.9`, `5.10.1`).
+fn valid_swift_version(version: &str) -> bool {
+    !version.is_empty()
+        && version.len() <= 16
+        && version
+            .split('.')
+            .all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn package_prefix(scope: &str, name: &str) -> String {
+    format!(
+        "swift/{}/{}/",
+        scope.to_ascii_lowercase(),
+        name.to_ascii_lowercase()
+    )
+}
+
+fn archive_key(scope: &str, name: &str, version: &str) -> String {
+    format!("{}{version}.zip", package_prefix(scope, name))
+}
+
+fn manifest_key(scope: &str, name: &str, version: &str, file: &str) -> String {
+    format!("{}{version}/{file}", package_prefix(scope, name))
+}
+
+fn release_prefix(scope: &str, name: &str) -> String {
+    format!("{}{META_DIR}/", package_prefix(scope, name))
+}
+
+fn release_key(scope: &str, name: &str, version: &str) -> String {
+    format!("{}{version}.json", release_prefix(scope, name))
+}
+
+fn identifiers_key(url: &str) -> String {
+    format!(
+        "swift/{META_DIR}/identifiers/{}.json",
+        hex::encode(sha2::Sha256::digest(url.as_bytes()))
+    )
+}
+
+/// Canonical form of a repository URL for identifier lookup: lowercase, no
+/// scheme or user info, scp-style `git@host:path` as `host/path`, no `.git`
+/// suffix or trailing slash. `https://github.com/Mona/LinkedList.git` and
+/// `git@github.com:mona/LinkedList` both become `github.com/mona/linkedlist`.
+fn normalize_repository_url(url: &str) -> Option<String> {
+    let url = url.trim().to_ascii_lowercase();
+    let rest = match url.split_once("://") {
+        Some((_, rest)) => rest.to_string(),
+        None => {
+            let (host, path) = url.split_once(':')?;
+            format!("{host}/{path}")
+        }
+    };
+    let rest = match rest.split_once('/') {
+        Some((authority, path)) => {
+            let host = authority.rsplit('@').next().unwrap_or(authority);
+            format!("{host}/{path}")
+        }
+        None => rest.rsplit('@').next().unwrap_or(&rest).to_string(),
+    };
+    let rest = rest.trim_end_matches('/');
+    let rest = rest
+        .strip_suffix(".git")
+        .unwrap_or(rest)
+        .trim_end_matches('/');
+    (rest.contains('/') && !rest.starts_with('/')).then(|| rest.to_string())
+}
+
+// ============================================================================
+// Responses
+// ============================================================================
+
+/// An RFC 7807 problem details response, as SwiftPM expects for every error.
+fn problem(status: StatusCode, detail: &str) -> Response {
+    (
+        status,
+        [(header::CONTENT_TYPE, PROBLEM_CONTENT_TYPE)],
+        serde_json::to_vec(&json!({ "detail": detail })).unwrap_or_default(),
+    )
+        .into_response()
+}
+
+/// The API version an `Accept` header asks for:
+/// `application/vnd.swift.registry.v1+json` → `1`. `None` when the header
+/// names no registry media type (plain `application/json`, `*/*`, none).
+fn requested_api_version(headers: &HeaderMap) -> Option<String> {
+    let accept = headers.get(header::ACCEPT)?.to_str().ok()?;
+    accept.split(',').find_map(|media| {
+        let media = media.split(';').next()?.trim();
+        let rest = media.strip_prefix("application/vnd.swift.registry.v")?;
+        Some(rest.split('+').next().unwrap_or(rest).to_string())
+    })
+}
+
+/// Every response carries `Content-Version: 1`; a request for any other API
+/// version is a 415 before it reaches a handler.
+async fn api_version(request: Request, next: Next) -> Response {
+    let mut response = match requested_api_version(request.headers()) {
+        Some(version) if version != API_VERSION => problem(
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            &format!("Unsupported API version: {version}"),
+        ),
+        _ => next.run(request).await,
+    };
+    response.headers_mut().insert(
+        HeaderName::from_static(CONTENT_VERSION),
+        HeaderValue::from_static(API_VERSION),
+    );
+    response
+}
+
+fn link(url: &str, rel: &str) -> String {
+    format!("<{url}>; rel=\"{rel}\"")
+}
+
+fn with_links(mut response: Response, links: &[String]) -> Response {
+    if let Ok(value) = HeaderValue::from_str(&links.join(", ")) {
+        if !links.is_empty() {
+            response.headers_mut().insert(header::LINK, value);
+        }
+    }
+    response
+}
+
+// ============================================================================
+// Releases
+// ============================================================================
+
+/// One `Package.swift` variant stored with a release.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ManifestEntry {
+    file: String,
+    /// From the `// swift-tools-version:` line, for the `Link` header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tools_version: Option<String>,
+}
+
+/// One published release, stored at `swift/{scope}/{name}/.nora-meta/{version}.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Release {
+    /// `scope.name` as published (lookups are case-insensitive).
+    pub(crate) id: String,
+    pub(crate) version: String,
+    /// SHA-256 of the source archive, hex.
+    pub(crate) checksum: String,
+    pub(crate) size: u64,
+    /// The publisher's `metadata` part (SE-0391 schema), passed through.
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub(crate) metadata: Value,
+    /// Source archive signature, base64.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signature_format: Option<String>,
+    /// `metadata` signature, base64 — kept for audit, not served.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    metadata_signature: Option<String>,
+    #[serde(default)]
+    manifests: Vec<ManifestEntry>,
+    /// Publish time, RFC 3339.
+    pub(crate) published_at: String,
+}
+
+/// Every release of a package, newest version first.
+pub(crate) async fn load_releases(
+    storage: &crate::storage::Storage,
+    scope: &str,
+    name: &str,
+) -> Vec<Release> {
+    let mut releases = read_json_sidecars::<Release>(storage, &release_prefix(scope, name))
+        .await
+        .unwrap_or_default();
+    releases.sort_by(|a, b| cmp_semver(&b.version, &a.version));
+    releases
+}
+
+async fn load_release(
+    storage: &crate::storage::Storage,
+    scope: &str,
+    name: &str,
+    version: &str,
+) -> Option<Release> {
+    let data = storage.get(&release_key(scope, name, version)).await.ok()?;
+    serde_json::from_slice(&data).ok()
+}
+
+/// Splits `{name}.json` (the explicit JSON form of a listing or metadata URL)
+/// from the bare segment.
+fn strip_json(segment: &str) -> &str {
+    segment.strip_suffix(".json").unwrap_or(segment)
+}
+
+/// GET /swift/{scope}/{name} — every release with its metadata URL.
+async fn list_releases(
+    State(state): State<AppState>,
+    Path((scope, name)): Path<(String, String)>,
+) -> Response {
+    let name = strip_json(&name);
+    if !valid_scope(&scope) || !valid_name(name) {
+        return problem(StatusCode::BAD_REQUEST, "Invalid package identifier");
+    }
+    let releases = load_releases(&state.storage, &scope, name).await;
+    let Some(latest) = releases.first() else {
+        return problem(StatusCode::NOT_FOUND, "Package not found");
+    };
+    let base = format!("{}/swift/{scope}/{name}", nora_base_url(&state));
+    let entries: Map<String, Value> = releases
+        .iter()
+        .map(|r| {
+            (
+                r.version.clone(),
+                json!({ "url": format!("{base}/{}", r.version) }),
+            )
+        })
+        .collect();
+    let links = [link(
+        &format!("{base}/{}", latest.version),
+        "latest-version",
+    )];
+    let response = (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, JSON_CONTENT_TYPE),
+            (header::CACHE_CONTROL, "no-cache"),
+        ],
+        serde_json::to_vec(&json!({ "releases": entries })).unwrap_or_default(),
+    )
+        .into_response();
+    with_links(response, &links)
+}
+
+/// GET /swift/{scope}/{name}/{version} — release metadata, or the source
+/// archive for `{version}.zip`.
+async fn release_or_archive(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((scope, name, version)): Path<(String, String, String)>,
+) -> Response {
+    if !valid_scope(&scope) || !valid_name(&name) {
+        return problem(StatusCode::BAD_REQUEST, "Invalid package identifier");
+    }
+    if let Some(version) = version.strip_suffix(".zip") {
+        return download_archive(&state, &headers, &scope, &name, version).await;
+    }
+    let version = strip_json(&version);
+    if !valid_version(version) {
+        return problem(StatusCode::BAD_REQUEST, "Invalid version");
+    }
+    let releases = load_releases(&state.storage, &scope, &name).await;
+    let Some(index) = releases.iter().position(|r| r.version == version) else {
+        return problem(StatusCode::NOT_FOUND, "Release not found");
+    };
+    let release = &releases[index];
+
+    let mut resource = json!({
+        "name": "source-archive",
+        "type": ZIP_CONTENT_TYPE,
+        "checksum": release.checksum,
+    });
+    if let (Some(signature), Some(format)) = (&release.signature, &release.signature_format) {
+        resource["signing"] = json!({
+            "signatureBase64Encoded": signature,
+            "signatureFormat": format,
+        });
+    }
+    let body = json!({
+        "id": release.id,
+        "version": release.version,
+        "resources": [resource],
+        "metadata": if release.metadata.is_null() { json!({}) } else { release.metadata.clone() },
+        "publishedAt": release.published_at,
+    });
+
+    // Releases are newest first: the successor is the previous entry.
+    let base = format!("{}/swift/{scope}/{name}", nora_base_url(&state));
+    let mut links = vec![link(
+        &format!("{base}/{}", releases[0].version),
+        "latest-version",
+    )];
+    if let Some(successor) = index.checked_sub(1).map(|i| &releases[i]) {
+        links.push(link(
+            &format!("{base}/{}", successor.version),
+            "successor-version",
+        ));
+    }
+    if let Some(predecessor) = releases.get(index + 1) {
+        links.push(link(
+            &format!("{base}/{}", predecessor.version),
+            "predecessor-version",
+        ));
+    }
+    let response = (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, JSON_CONTENT_TYPE)],
+        serde_json::to_vec(&body).unwrap_or_default(),
+    )
+        .into_response();
+    with_links(response, &links)
+}
+
+/// GET /swift/{scope}/{name}/{version}.zip — the source archive, with its
+/// checksum as `Digest` and the signature headers of a signed release.
+async fn download_archive(
+    state: &AppState,
+    headers: &HeaderMap,
+    scope: &str,
+    name: &str,
+    version: &str,
+) -> Response {
+    if !valid_version(version) {
+        return problem(StatusCode::BAD_REQUEST, "Invalid version");
+    }
+    let Some(release) = load_release(&state.storage, scope, name, version).await else {
+        return problem(StatusCode::NOT_FOUND, "Release not found");
+    };
+    let key = archive_key(scope, name, version);
+
+    let mut extra = vec![(
+        header::CONTENT_DISPOSITION,
+        format!("attachment; filename=\"{name}-{version}.zip\""),
+    )];
+    if let Ok(digest) = hex::decode(&release.checksum) {
+        extra.push((
+            HeaderName::from_static("digest"),
+            format!("sha-256={}", STANDARD.encode(digest)),
+        ));
+    }
+    if let (Some(signature), Some(format)) = (&release.signature, &release.signature_format) {
+        extra.push((
+            HeaderName::from_static(SIGNATURE_FORMAT_HEADER),
+            format.clone(),
+        ));
+        extra.push((HeaderName::from_static(SIGNATURE_HEADER), signature.clone()));
+    }
+
+    // Range request: 206 Partial Content, or 416 past the end (#657). A partial
+    // body cannot be rehashed; SwiftPM checks the release checksum itself.
+    if let Some(response) = crate::registry::range::range_response(
+        &state.storage,
+        &[&key],
+        headers,
+        release.size,
+        ZIP_CONTENT_TYPE,
+        &extra,
+    )
+    .await
+    {
+        if response.status() == StatusCode::PARTIAL_CONTENT {
+            state.metrics.record_download("swift");
+        }
+        return response;
+    }
+
+    match state.storage.get_verified(&key).await {
+        Ok(outcome) => {
+            use nora_registry::verified::{verified_body, GateOutcome};
+            let data = match outcome {
+                GateOutcome::Verified(blob) => verified_body(blob),
+                GateOutcome::Unpinned(blob) => blob.into_inner(),
+            };
+            state.metrics.record_download("swift");
+            state.activity.push(ActivityEntry::new(
+                ActionType::Pull,
+                format!("{}@{version}", release.id),
+                RegistryType::Swift,
+                "LOCAL",
+            ));
+            let mut response = (
+                StatusCode::OK,
+                [
+                    (header::CONTENT_TYPE, ZIP_CONTENT_TYPE),
+                    (header::ACCEPT_RANGES, "bytes"),
+                ],
+                data,
+            )
+                .into_response();
+            for (name, value) in extra {
+                if let Ok(value) = HeaderValue::from_str(&value) {
+                    response.headers_mut().insert(name, value);
+                }
+            }
+            response
+        }
+        Err(crate::storage::StorageError::NotFound) => {
+            problem(StatusCode::NOT_FOUND, "Source archive not found")
+        }
+        Err(e) => {
+            tracing::error!(error = %e, key = %key, "swift: failed to read source archive");
+            problem(StatusCode::INTERNAL_SERVER_ERROR, "Storage error")
+        }
+    }
+}
+
+/// GET /swift/{scope}/{name}/{version}/Package.swift[?swift-version=X]
+///
+/// The unqualified manifest lists its version-specific variants as
+/// `rel="alternate"` links. A `swift-version` without its own manifest is
+/// redirected to the unqualified one, as the spec requires.
+async fn fetch_manifest(
+    State(state): State<AppState>,
+    Path((scope, name, version)): Path<(String, String, String)>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Response {
+    if !valid_scope(&scope) || !valid_name(&name) || !valid_version(&version) {
+        return problem(StatusCode::BAD_REQUEST, "Invalid package release");
+    }
+    let file = match params.get("swift-version") {
+        Some(swift) if valid_swift_version(swift) => format!("Package@swift-{swift}.swift"),
+        Some(_) => return problem(StatusCode::BAD_REQUEST, "Invalid swift-version"),
+        None => "Package.swift".to_string(),
+    };
+    let Some(release) = load_release(&state.storage, &scope, &name, &version).await else {
+        return problem(StatusCode::NOT_FOUND, "Release not found");
+    };
+    let base = format!(
+        "{}/swift/{scope}/{name}/{version}/Package.swift",
+        nora_base_url(&state)
+    );
+    if !release.manifests.iter().any(|m| m.file == file) {
+        if file == "Package.swift" {
+            return problem(StatusCode::NOT_FOUND, "Manifest not found");
+        }
+        return (StatusCode::SEE_OTHER, [(header::LOCATION, base)]).into_response();
+    }
+
+    let key = manifest_key(&scope, &name, &version, &file);
+    let data = match state.storage.get(&key).await {
+        Ok(data) => data,
+        Err(crate::storage::StorageError::NotFound) => {
+            return problem(StatusCode::NOT_FOUND, "Manifest not found")
+        }
+        Err(e) => {
+            tracing::error!(error = %e, key = %key, "swift: failed to read manifest");
+            return problem(StatusCode::INTERNAL_SERVER_ERROR, "Storage error");
+        }
+    };
+    let links: Vec<String> = if file == "Package.swift" {
+        release
+            .manifests
+            .iter()
+            .filter_map(|m| {
+                let swift = m
+                    .file
+                    .strip_prefix("Package@swift-")?
+                    .strip_suffix(".swift")?;
+                let mut entry = format!(
+                    "<{base}?swift-version={swift}>; rel=\"alternate\"; filename=\"{}\"",
+                    m.file
+                );
+                if let Some(tools) = &m.tools_version {
+                    entry.push_str(&format!("; swift-tools-version=\"{tools}\""));
+                }
+                Some(entry)
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let response = (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, SWIFT_CONTENT_TYPE.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{file}\""),
+            ),
+        ],
+        data,
+    )
+        .into_response();
+    with_links(response, &links)
+}
+
+/// GET /swift/identifiers?url= — the packages published from a repository.
+async fn lookup_identifiers(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Response {
+    let Some(url) = params.get("url").and_then(|u| normalize_repository_url(u)) else {
+        return problem(StatusCode::BAD_REQUEST, "Missing or invalid url parameter");
+    };
+    let identifiers = read_identifiers(&state.storage, &url).await;
+    if identifiers.is_empty() {
+        return problem(StatusCode::NOT_FOUND, "No packages for this repository");
+    }
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, JSON_CONTENT_TYPE)],
+        serde_json::to_vec(&json!({ "identifiers": identifiers })).unwrap_or_default(),
+    )
+        .into_response()
+}
+
+async fn read_identifiers(storage: &crate::storage::Storage, url: &str) -> Vec<String> {
+    match storage.get(&identifiers_key(url)).await {
+        Ok(data) => serde_json::from_slice(&data).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// POST /swift/login — `swift package-registry login`. The auth middleware has
+/// already checked the credentials by the time this runs.
+async fn login() -> StatusCode {
+    StatusCode::OK
+}
+
+// ============================================================================
+// Publish
+// ============================================================================
+
+/// `// swift-tools-version:5.9` (or `// swift-tools-version: 5.9; ...`) → `5.9`.
+fn tools_version(manifest: &[u8]) -> Option<String> {
+    let line = manifest.split(|b| *b == b'\n').next()?;
+    let line = std::str::from_utf8(line).ok()?.trim();
+    let rest = line.strip_prefix("//")?.trim_start();
+    let version = rest
+        .strip_prefix("swift-tools-version")?
+        .trim_start()
+        .strip_prefix(':')?;
+    let version = version.split(';').next()?.trim();
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+/// `Package.swift` and its `Package@swift-X.swift` variants: at the archive
+/// root, or inside the single top-level directory that
+/// `swift package archive-source` wraps everything in.
+fn read_manifests(data: &[u8]) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let mut zip = zip::ZipArchive::new(std::io::Cursor::new(data))
+        .map_err(|e| format!("not a zip archive: {e}"))?;
+    let root = zip
+        .file_names()
+        .filter(|n| {
+            *n == "Package.swift" || (n.ends_with("/Package.swift") && n.matches('/').count() == 1)
+        })
+        .min_by_key(|n| n.len())
+        .map(str::to_string)
+        .ok_or("no Package.swift at the archive root")?;
+    let dir = root.trim_end_matches("Package.swift").to_string();
+    let members: Vec<String> = zip
+        .file_names()
+        .filter(|n| n.strip_prefix(dir.as_str()).is_some_and(is_manifest_file))
+        .map(str::to_string)
+        .collect();
+
+    let mut manifests = Vec::new();
+    for member in members {
+        let mut raw = Vec::new();
+        zip.by_name(&member)
+            .map_err(|e| format!("{member}: {e}"))?
+            .take(MAX_MANIFEST + 1)
+            .read_to_end(&mut raw)
+            .map_err(|e| format!("{member}: {e}"))?;
+        if raw.len() as u64 > MAX_MANIFEST {
+            return Err(format!("{member} is larger than {MAX_MANIFEST} bytes"));
+        }
+        manifests.push((member[dir.len()..].to_string(), raw));
+    }
+    manifests.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(manifests)
+}
+
+/// The publish request's multipart parts.
+#[derive(Default)]
+struct PublishParts {
+    archive: Option<Bytes>,
+    archive_signature: Option<Bytes>,
+    metadata: Option<Bytes>,
+    metadata_signature: Option<Bytes>,
+}
+
+async fn read_parts(mut multipart: Multipart) -> Result<PublishParts, String> {
+    let mut parts = PublishParts::default();
+    while let Some(field) = multipart.next_field().await.map_err(|e| e.to_string())? {
+        let name = field.name().unwrap_or("").to_string();
+        let data = field.bytes().await.map_err(|e| e.to_string())?;
+        match name.as_str() {
+            "source-archive" => parts.archive = Some(data),
+            "source-archive-signature" => parts.archive_signature = Some(data),
+            "metadata" => parts.metadata = Some(data),
+            "metadata-signature" => parts.metadata_signature = Some(data),
+            _ => {}
+        }
+    }
+    Ok(parts)
+}
+
+/// PUT /swift/{scope}/{name}/{version} — `swift package-registry publish`.
+///
+/// Multipart parts: `source-archive` (required), `metadata` (JSON), and the
+/// `source-archive-signature`/`metadata-signature` of a signed release, whose
+/// format comes in `X-Swift-Package-Signature-Format`. Releases are immutable:
+/// publishing an existing version is a 409. Publishing is synchronous, so the
+/// answer is always a 201 with the release URL in `Location`.
+async fn publish(
+    State(state): State<AppState>,
+    Path((scope, name, version)): Path<(String, String, String)>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    headers: HeaderMap,
+    multipart: Result<Multipart, MultipartRejection>,
+) -> Response {
+    if !valid_scope(&scope) || !valid_name(&name) {
+        return problem(StatusCode::BAD_REQUEST, "Invalid package identifier");
+    }
+    if !valid_version(&version) {
+        return problem(StatusCode::BAD_REQUEST, "Invalid version: expected semver");
+    }
+    // Enforce OIDC namespace_scope on the package identifier (#583).
+    let id = format!("{scope}.{name}");
+    if enforce_namespace_scope(&authority, &format!("{scope}/{name}")).is_err() {
+        return problem(StatusCode::FORBIDDEN, "Outside namespace scope");
+    }
+    let multipart = match multipart {
+        Ok(multipart) => multipart,
+        Err(e) => return problem(StatusCode::BAD_REQUEST, &e.body_text()),
+    };
+    let parts = match read_parts(multipart).await {
+        Ok(parts) => parts,
+        Err(e) => {
+            return problem(
+                StatusCode::BAD_REQUEST,
+                &format!("Malformed multipart body: {e}"),
+            )
+        }
+    };
+    let Some(archive) = parts.archive else {
+        return problem(StatusCode::BAD_REQUEST, "Missing source-archive part");
+    };
+    let max_size = state.config.swift.max_file_size;
+    if archive.len() as u64 > max_size {
+        return problem(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            &format!("Source archive too large. Max size: {max_size} bytes"),
+        );
+    }
+
+    let signature_format = headers
+        .get(SIGNATURE_FORMAT_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    match (&signature_format, &parts.archive_signature) {
+        (Some(format), Some(_)) if format != SIGNATURE_FORMAT => {
+            return problem(
+                StatusCode::BAD_REQUEST,
+                &format!("Unsupported signature format: {format}"),
+            )
+        }
+        (None, Some(_)) => {
+            return problem(
+                StatusCode::BAD_REQUEST,
+                "source-archive-signature requires X-Swift-Package-Signature-Format",
+            )
+        }
+        (Some(_), None) => {
+            return problem(
+                StatusCode::BAD_REQUEST,
+                "X-Swift-Package-Signature-Format given without a source-archive-signature",
+            )
+        }
+        _ => {}
+    }
+
+    let metadata = match &parts.metadata {
+        None => Value::Null,
+        Some(raw) if raw.iter().all(u8::is_ascii_whitespace) => Value::Null,
+        Some(raw) => match serde_json::from_slice::<Value>(raw) {
+            Ok(value @ Value::Object(_)) => value,
+            _ => {
+                return problem(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    "metadata must be a JSON object",
+                )
+            }
+        },
+    };
+    let manifests = match read_manifests(&archive) {
+        Ok(manifests) => manifests,
+        Err(e) => {
+            return problem(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                &format!("Invalid source archive: {e}"),
+            )
+        }
+    };
+
+    let release = Release {
+        id: id.clone(),
+        version: version.clone(),
+        checksum: hex::encode(sha2::Sha256::digest(&archive)),
+        size: archive.len() as u64,
+        metadata,
+        signature: parts.archive_signature.map(|s| STANDARD.encode(s)),
+        signature_format,
+        metadata_signature: parts.metadata_signature.map(|s| STANDARD.encode(s)),
+        manifests: manifests
+            .iter()
+            .map(|(file, data)| ManifestEntry {
+                file: file.clone(),
+                tools_version: tools_version(data),
+            })
+            .collect(),
+        published_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+    };
+    let release_json = match serde_json::to_vec(&release) {
+        Ok(j) => j,
+        Err(e) => {
+            tracing::error!(error = %e, "swift: failed to serialize release record");
+            return problem(StatusCode::INTERNAL_SERVER_ERROR, "Internal error");
+        }
+    };
+
+    let key = archive_key(&scope, &name, &version);
+    let record_key = release_key(&scope, &name, &version);
+    if validate_storage_key(&key).is_err() || validate_storage_key(&record_key).is_err() {
+        return problem(StatusCode::BAD_REQUEST, "Invalid package release");
+    }
+
+    let lock = state.publish_lock(&package_prefix(&scope, &name));
+    let guard = lock.lock().await;
+
+    if state.storage.stat(&record_key).await.is_some() {
+        return problem(StatusCode::CONFLICT, "Release already exists");
+    }
+    // Archive and manifests first; the record is what makes the release visible.
+    if let Err(e) = state.storage.put(&key, &archive).await {
+        tracing::error!(error = %e, key = %key, "swift: failed to store source archive");
+        return problem(StatusCode::INTERNAL_SERVER_ERROR, "Storage error");
+    }
+    for (file, data) in &manifests {
+        let key = manifest_key(&scope, &name, &version, file);
+        if let Err(e) = state.storage.put(&key, data).await {
+            tracing::error!(error = %e, key = %key, "swift: failed to store manifest");
+            return problem(StatusCode::INTERNAL_SERVER_ERROR, "Storage error");
+        }
+    }
+    if let Err(e) = state.storage.put(&record_key, &release_json).await {
+        tracing::error!(error = %e, key = %record_key, "swift: failed to store release record");
+        return problem(StatusCode::INTERNAL_SERVER_ERROR, "Storage error");
+    }
+    drop(guard);
+
+    index_repository_urls(&state, &release).await;
+
+    let ident = format!("{id}@{version}");
+    state.metrics.record_upload("swift");
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &ident, "swift", ""));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        ident,
+        RegistryType::Swift,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("swift");
+
+    let location = format!("{}/swift/{scope}/{name}/{version}", nora_base_url(&state));
+    (StatusCode::CREATED, [(header::LOCATION, location)]).into_response()
+}
+
+/// Adds the release's package to the identifier index of every repository
+/// URL in its metadata (`repositoryURLs`), for `GET /swift/identifiers`.
+async fn index_repository_urls(state: &AppState, release: &Release) {
+    let Some(urls) = release.metadata["repositoryURLs"].as_array() else {
+        return;
+    };
+    let lock = state.publish_lock(&format!("swift/{META_DIR}/identifiers"));
+    let _guard = lock.lock().await;
+    for url in urls.iter().filter_map(Value::as_str) {
+        let Some(url) = normalize_repository_url(url) else {
+            continue;
+        };
+        let mut identifiers = read_identifiers(&state.storage, &url).await;
+        if identifiers
+            .iter()
+            .any(|id| id.eq_ignore_ascii_case(&release.id))
+        {
+            continue;
+        }
+        identifiers.push(release.id.clone());
+        identifiers.sort_by_key(|id| id.to_ascii_lowercase());
+        let key = identifiers_key(&url);
+        let data = serde_json::to_vec(&identifiers).unwrap_or_default();
+        if let Err(e) = state.storage.put(&key, &data).await {
+            tracing::warn!(error = %e, key = %key, "swift: failed to update identifier index");
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{
+        body_bytes, create_test_context, create_test_context_with_config, send, send_with_headers,
+        TestContext,
+    };
+    use axum::http::Method;
+    use std::io::Write;
+
+    const BOUNDARY: &str = "nora-swift-boundary";
+
+    /// An archive as `swift package archive-source` builds it: everything
+    /// under one top-level directory.
+    fn source_zip(extra: &[(&str, &str)]) -> Vec<u8> {
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        let mut w = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let mut files = vec![
+            (
+                "LinkedList/Package.swift",
+                "// swift-tools-version:5.9\nimport PackageDescription\n",
+            ),
+            (
+                "LinkedList/Sources/LinkedList/LinkedList.swift",
+                "public struct LinkedList {}\n",
+            ),
+        ];
+        files.extend_from_slice(extra);
+        for (member, data) in files {
+            w.start_file(member, options).unwrap();
+            w.write_all(data.as_bytes()).unwrap();
+        }
+        w.finish().unwrap().into_inner()
+    }
+
+    fn multipart_body(parts: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut body = Vec::new();
+        for (field, data) in parts {
+            body.extend_from_slice(
+                format!(
+                    "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{field}\"\r\nContent-Type: application/octet-stream\r\n\r\n"
+                )
+                .as_bytes(),
+            );
+            body.extend_from_slice(data);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{BOUNDARY}--\r\n").as_bytes());
+        body
+    }
+
+    async fn publish_with(
+        ctx: &TestContext,
+        uri: &str,
+        headers: Vec<(&str, &str)>,
+        parts: &[(&str, &[u8])],
+    ) -> Response {
+        let content_type = format!("multipart/form-data; boundary={BOUNDARY}");
+        let mut all = vec![("content-type", content_type.as_str())];
+        all.extend(headers);
+        send_with_headers(&ctx.app, Method::PUT, uri, all, multipart_body(parts)).await
+    }
+
+    async fn publish_release(ctx: &TestContext, version: &str, archive: &[u8]) {
+        let metadata = json!({
+            "description": "A linked list",
+            "repositoryURLs": ["https://github.com/Mona/LinkedList.git"],
+        })
+        .to_string();
+        let resp = publish_with(
+            ctx,
+            &format!("/swift/mona/LinkedList/{version}"),
+            vec![],
+            &[
+                ("source-archive", archive),
+                ("metadata", metadata.as_bytes()),
+            ],
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED, "{version}");
+    }
+
+    async fn get_json(ctx: &TestContext, uri: &str) -> (HeaderMap, Value) {
+        let resp = send(&ctx.app, Method::GET, uri, "").await;
+        assert_eq!(resp.status(), StatusCode::OK, "{uri}");
+        let headers = resp.headers().clone();
+        (
+            headers,
+            serde_json::from_slice(&body_bytes(resp).await).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_valid_scope_and_name() {
+        for ok in ["mona", "Mona", "a-b", "x1", &"a".repeat(39)] {
+            assert!(valid_scope(ok), "{ok}");
+        }
+        for bad in ["", "-a", "a-", "a--b", "a_b", "a.b", &"a".repeat(40)] {
+            assert!(!valid_scope(bad), "{bad}");
+        }
+        for ok in ["LinkedList", "swift-nio", "a_b", "x"] {
+            assert!(valid_name(ok), "{ok}");
+        }
+        for bad in ["", "_x", "-x", "a.b", "a/b", &"a".repeat(101)] {
+            assert!(!valid_name(bad), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_normalize_repository_url() {
+        for url in [
+            "https://github.com/Mona/LinkedList",
+            "https://github.com/mona/LinkedList.git",
+            "https://user@github.com/mona/linkedlist/",
+            "ssh://git@github.com/mona/LinkedList.git",
+            "git@github.com:mona/LinkedList.git",
+        ] {
+            assert_eq!(
+                normalize_repository_url(url).as_deref(),
+                Some("github.com/mona/linkedlist"),
+                "{url}"
+            );
+        }
+        assert_eq!(normalize_repository_url("github"), None);
+        assert_eq!(normalize_repository_url("https://github.com"), None);
+    }
+
+    #[test]
+    fn test_read_manifests_root_or_wrapped() {
+        let wrapped = source_zip(&[
+            (
+                "LinkedList/Package@swift-5.7.swift",
+                "// swift-tools-version: 5.7\n",
+            ),
+            ("LinkedList/Tests/Package.swift", "// nested, ignored\n"),
+        ]);
+        let manifests = read_manifests(&wrapped).unwrap();
+        let files: Vec<&str> = manifests.iter().map(|(f, _)| f.as_str()).collect();
+        assert_eq!(files, ["Package.swift", "Package@swift-5.7.swift"]);
+        assert_eq!(tools_version(&manifests[1].1).as_deref(), Some("5.7"));
+
+        let options = zip::write::SimpleFileOptions::default();
+        let mut w = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        w.start_file("Sources/Lib/Lib.swift", options).unwrap();
+        w.write_all(b"struct Lib {}\n").unwrap();
+        let no_manifest = w.finish().unwrap().into_inner();
+        assert!(read_manifests(&no_manifest).is_err());
+        assert!(read_manifests(b"not a zip").is_err());
+    }
+
+    #[test]
+    fn test_requested_api_version() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(requested_api_version(&headers), None);
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.swift.registry.v1+json"),
+        );
+        assert_eq!(requested_api_version(&headers).as_deref(), Some("1"));
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("text/html, application/vnd.swift.registry.v2+zip;q=0.9"),
+        );
+        assert_eq!(requested_api_version(&headers).as_deref(), Some("2"));
+    }
+
+    #[tokio::test]
+    async fn test_swift_publish_and_resolve() {
+        let ctx = create_test_context();
+        let archive = source_zip(&[(
+            "LinkedList/Package@swift-5.7.swift",
+            "// swift-tools-version:5.7\nimport PackageDescription\n",
+        )]);
+        publish_release(&ctx, "1.0.0", &source_zip(&[])).await;
+        publish_release(&ctx, "1.1.0", &archive).await;
+        publish_release(&ctx, "1.1.0-beta.1", &source_zip(&[])).await;
+
+        // Listing: identifiers are case-insensitive.
+        let (headers, list) = get_json(&ctx, "/swift/MONA/linkedlist").await;
+        assert_eq!(headers[CONTENT_VERSION], "1");
+        let releases = list["releases"].as_object().unwrap();
+        assert_eq!(releases.len(), 3);
+        assert!(releases["1.0.0"]["url"]
+            .as_str()
+            .unwrap()
+            .ends_with("/swift/MONA/linkedlist/1.0.0"));
+        assert!(headers[header::LINK]
+            .to_str()
+            .unwrap()
+            .contains("/1.1.0>; rel=\"latest-version\""));
+
+        // Release metadata.
+        let (headers, meta) = get_json(&ctx, "/swift/mona/LinkedList/1.1.0-beta.1").await;
+        assert_eq!(meta["id"], "mona.LinkedList");
+        assert_eq!(meta["metadata"]["description"], "A linked list");
+        assert!(meta["publishedAt"].as_str().unwrap().ends_with('Z'));
+        let links = headers[header::LINK].to_str().unwrap();
+        assert!(links.contains("/1.1.0>; rel=\"successor-version\""));
+        assert!(links.contains("/1.0.0>; rel=\"predecessor-version\""));
+        let (_, meta) = get_json(&ctx, "/swift/mona/LinkedList/1.1.0.json").await;
+        let resource = &meta["resources"][0];
+        assert_eq!(resource["name"], "source-archive");
+        assert_eq!(
+            resource["checksum"],
+            hex::encode(sha2::Sha256::digest(&archive))
+        );
+        assert!(resource.get("signing").is_none());
+
+        // Source archive with its checksum as Digest.
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/swift/mona/LinkedList/1.1.0.zip",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers()[header::CONTENT_TYPE], ZIP_CONTENT_TYPE);
+        assert_eq!(
+            resp.headers()[header::CONTENT_DISPOSITION],
+            "attachment; filename=\"LinkedList-1.1.0.zip\""
+        );
+        assert_eq!(
+            resp.headers()["digest"].to_str().unwrap(),
+            format!(
+                "sha-256={}",
+                STANDARD.encode(sha2::Sha256::digest(&archive))
+            )
+        );
+        assert_eq!(&body_bytes(resp).await[..], &archive[..]);
+
+        // Manifests: the unqualified one links its variants; a swift-version
+        // without its own manifest redirects to the unqualified one.
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/swift/mona/LinkedList/1.1.0/Package.swift",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers()[header::CONTENT_TYPE], SWIFT_CONTENT_TYPE);
+        let links = resp.headers()[header::LINK].to_str().unwrap().to_string();
+        assert!(links.contains("?swift-version=5.7>; rel=\"alternate\""));
+        assert!(links.contains("filename=\"Package@swift-5.7.swift\""));
+        assert!(links.contains("swift-tools-version=\"5.7\""));
+        assert!(body_bytes(resp)
+            .await
+            .starts_with(b"// swift-tools-version:5.9"));
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/swift/mona/LinkedList/1.1.0/Package.swift?swift-version=5.7",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(body_bytes(resp)
+            .await
+            .starts_with(b"// swift-tools-version:5.7"));
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/swift/mona/LinkedList/1.1.0/Package.swift?swift-version=6.0",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::SEE_OTHER);
+        assert!(resp.headers()[header::LOCATION]
+            .to_str()
+            .unwrap()
+            .ends_with("/swift/mona/LinkedList/1.1.0/Package.swift"));
+
+        // Identifier lookup by any spelling of the repository URL.
+        let (_, ids) = get_json(
+            &ctx,
+            "/swift/identifiers?url=git@github.com:mona/linkedlist.git",
+        )
+        .await;
+        assert_eq!(ids["identifiers"], json!(["mona.LinkedList"]));
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/swift/identifiers?url=https://github.com/mona/other",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        assert_eq!(resp.headers()[header::CONTENT_TYPE], PROBLEM_CONTENT_TYPE);
+
+        let resp = send(&ctx.app, Method::GET, "/swift/mona/LinkedList/2.0.0", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = send(&ctx.app, Method::GET, "/swift/mona/Missing", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_swift_signed_release() {
+        let ctx = create_test_context();
+        let archive = source_zip(&[]);
+        let signature = b"\x30\x82cms-signature".to_vec();
+        let resp = publish_with(
+            &ctx,
+            "/swift/mona/Signed/1.0.0",
+            vec![(SIGNATURE_FORMAT_HEADER, SIGNATURE_FORMAT)],
+            &[
+                ("source-archive", &archive),
+                ("source-archive-signature", &signature),
+            ],
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        assert!(resp.headers()[header::LOCATION]
+            .to_str()
+            .unwrap()
+            .ends_with("/swift/mona/Signed/1.0.0"));
+
+        let (_, meta) = get_json(&ctx, "/swift/mona/Signed/1.0.0").await;
+        assert_eq!(
+            meta["resources"][0]["signing"],
+            json!({
+                "signatureBase64Encoded": STANDARD.encode(&signature),
+                "signatureFormat": SIGNATURE_FORMAT,
+            })
+        );
+        let resp = send(&ctx.app, Method::GET, "/swift/mona/Signed/1.0.0.zip", "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers()[SIGNATURE_FORMAT_HEADER], SIGNATURE_FORMAT);
+        assert_eq!(
+            resp.headers()[SIGNATURE_HEADER].to_str().unwrap(),
+            STANDARD.encode(&signature)
+        );
+
+        // Only cms-1.0.0 is accepted.
+        let resp = publish_with(
+            &ctx,
+            "/swift/mona/Signed/1.0.1",
+            vec![(SIGNATURE_FORMAT_HEADER, "pgp")],
+            &[
+                ("source-archive", &archive),
+                ("source-archive-signature", &signature),
+            ],
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_swift_publish_rejections() {
+        let ctx = create_test_context_with_config(|cfg| cfg.swift.max_file_size = 4096);
+        let archive = source_zip(&[]);
+        publish_release(&ctx, "1.0.0", &archive).await;
+
+        // Releases are immutable, whatever the identifier's case.
+        let resp = publish_with(
+            &ctx,
+            "/swift/MONA/linkedlist/1.0.0",
+            vec![],
+            &[("source-archive", &archive)],
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        assert_eq!(resp.headers()[header::CONTENT_TYPE], PROBLEM_CONTENT_TYPE);
+
+        let resp = publish_with(
+            &ctx,
+            "/swift/mona/LinkedList/2.0.0",
+            vec![],
+            &[("source-archive", b"not a zip")],
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let resp = publish_with(
+            &ctx,
+            "/swift/mona/LinkedList/2.0.0",
+            vec![],
+            &[("source-archive", &archive), ("metadata", b"[1, 2]")],
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let resp = publish_with(&ctx, "/swift/mona/LinkedList/2.0.0", vec![], &[]).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        for uri in ["/swift/mona/LinkedList/v2", "/swift/mo_na/LinkedList/2.0.0"] {
+            let resp = publish_with(&ctx, uri, vec![], &[("source-archive", &archive)]).await;
+            assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{uri}");
+        }
+
+        let big = vec![0u8; 8192];
+        let resp = publish_with(
+            &ctx,
+            "/swift/mona/LinkedList/2.0.0",
+            vec![],
+            &[("source-archive", &big)],
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let resp = send(&ctx.app, Method::GET, "/swift/mona/LinkedList/2.0.0", "").await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_swift_unsupported_api_version() {
+        let ctx = create_test_context();
+        publish_release(&ctx, "1.0.0", &source_zip(&[])).await;
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::GET,
+            "/swift/mona/LinkedList",
+            vec![("accept", "application/vnd.swift.registry.v2+json")],
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        assert_eq!(resp.headers()[CONTENT_VERSION], "1");
+
+        let resp = send_with_headers(
+            &ctx.app,
+            Method::GET,
+            "/swift/mona/LinkedList",
+            vec![("accept", "application/vnd.swift.registry.v1+json")],
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
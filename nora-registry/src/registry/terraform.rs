@@ -13,6 +13,10 @@
 //!   GET /terraform/v1/modules/{ns}/{name}/{provider}/versions — list module versions
 //!   GET /terraform/v1/modules/{ns}/{name}/{provider}/{ver}/download — module download
 //!
+//! Private registry (hosted, merged into the provider/module endpoints above):
+//!   PUT /terraform/v1/providers/{ns}/{type}/{ver}/{os}/{arch} — publish a provider zip
+//!   PUT /terraform/v1/modules/{ns}/{name}/{provider}/{ver} — publish a module (.tar.gz body)
+//!
 //! Provider Network Mirror Protocol (#801 — what `network_mirror` speaks; no service
 //! discovery, coordinates carry the origin {hostname}, providers only):
 //!   GET /terraform/{hostname}/{ns}/{type}/index.json   — list available versions
@@ -26,20 +30,23 @@
 
 use crate::activity_log::{ActionType, ActivityEntry};
 use crate::audit::AuditEntry;
+use crate::auth::{enforce_namespace_scope, NamespaceAuthority};
 use crate::registry::{
     circuit_open_response, nora_base_url, proxy_fetch, proxy_fetch_text, ProxyError,
 };
 use crate::registry_type::RegistryType;
 use crate::secrets::expose_opt;
+use crate::storage::Storage;
 use crate::AppState;
 use axum::{
     body::Bytes,
     extract::{Path, State},
     http::{header, HeaderMap, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
-    Router,
+    routing::{get, put},
+    Extension, Router,
 };
+use sha2::Digest;
 use std::time::Duration;
 
 const UPSTREAM_DEFAULT: &str = "https://registry.terraform.io";
@@ -64,6 +71,11 @@ pub fn routes() -> Router<AppState> {
             "/terraform/v1/providers/{ns}/{ptype}/{ver}/download/{os}/{arch}",
             get(provider_download_meta),
         )
+        // Provider publish (hosted) — one platform zip per request
+        .route(
+            "/terraform/v1/providers/{ns}/{ptype}/{ver}/{os}/{arch}",
+            put(provider_upload),
+        )
         // Provider binary download (cached, immutable)
         .route(
             "/terraform/v1/providers/download/{*path}",
@@ -74,6 +86,11 @@ pub fn routes() -> Router<AppState> {
             "/terraform/v1/modules/{ns}/{name}/{provider}/versions",
             get(module_versions),
         )
+        // Module publish (hosted)
+        .route(
+            "/terraform/v1/modules/{ns}/{name}/{provider}/{ver}",
+            put(module_upload),
+        )
         // Module download (returns X-Terraform-Get header)
         .route(
             "/terraform/v1/modules/{ns}/{name}/{provider}/{ver}/download",
@@ -141,13 +158,16 @@ async fn provider_versions(
     // Read cache eagerly — preserve for serve-stale fallback (#532)
     let cached_data = state.storage.get(&storage_key).await.ok();
 
+    // Hosted versions are merged into every answer, as for modules.
+    let hosted = hosted_providers(&state.storage, &ns, &ptype).await;
+
     // TTL cache — serve fresh if within TTL
     if let Some(ref data) = cached_data {
         if let Some(meta) = state.storage.stat(&storage_key).await {
             if is_within_ttl(meta.modified, state.config.terraform.metadata_ttl) {
                 state.metrics.record_download("terraform");
                 state.metrics.record_cache_hit("terraform");
-                return with_json(data.to_vec());
+                return with_json(merge_provider_versions(Some(data), &hosted));
             }
         }
     }
@@ -161,10 +181,10 @@ async fn provider_versions(
         crate::curation::RegistryType::Terraform,
         &format!("{}/{}", ns, ptype),
     ) {
-        if let Some(ref data) = cached_data {
+        if cached_data.is_some() || !hosted.is_empty() {
             state.metrics.record_download("terraform");
             state.metrics.record_cache_hit("terraform");
-            return with_json(data.to_vec());
+            return with_json(merge_provider_versions(cached_data.as_deref(), &hosted));
         }
         return crate::curation::check_namespace_isolation(
             &state.curation().curation_engine,
@@ -206,14 +226,21 @@ async fn provider_versions(
                 .audit
                 .log(AuditEntry::new("proxy_fetch", "api", "", "terraform", ""));
 
-            state.spawn_cache("terraform", storage_key, Bytes::from(text.clone()));
-            with_json(text.into_bytes())
+            let merged = merge_provider_versions(Some(text.as_bytes()), &hosted);
+            state.spawn_cache("terraform", storage_key, Bytes::from(text));
+            with_json(merged)
+        }
+        Err(_) if cached_data.is_none() && !hosted.is_empty() => {
+            state.metrics.record_download("terraform");
+            with_json(merge_provider_versions(None, &hosted))
         }
         Err(ProxyError::NotFound) => StatusCode::NOT_FOUND.into_response(),
         Err(ProxyError::CircuitOpen(reg)) => circuit_open_response(&reg),
         Err(e) => {
             tracing::debug!(provider = format!("{}/{}", ns, ptype), error = ?e, "Terraform upstream error");
-            serve_stale_or_bad_gateway(&state, cached_data, "provider_versions")
+            let stale =
+                cached_data.map(|d| Bytes::from(merge_provider_versions(Some(&d), &hosted)));
+            serve_stale_or_bad_gateway(&state, stale, "provider_versions")
         }
     }
 }
@@ -237,6 +264,18 @@ async fn provider_download_meta(
     let base_url = nora_base_url(&state);
     let artifact = format!("{}/{} v{} {}/{}", ns, ptype, ver, os, arch);
 
+    // A hosted version is answered from its publish record, signed with the instance key.
+    if let Some(hosted) = read_hosted_provider(&state.storage, &ns, &ptype, &ver).await {
+        return match hosted_download_meta(&state, &base_url, &ns, &ptype, &hosted, &os, &arch).await
+        {
+            Some(meta) => {
+                state.metrics.record_download("terraform");
+                with_json(serde_json::to_vec(&meta).unwrap_or_default())
+            }
+            None => StatusCode::NOT_FOUND.into_response(),
+        };
+    }
+
     // Extract publish date from cached metadata. The download-metadata endpoint is
     // not the artifact serve path (no quarantine here), so the /v2 lookup is not
     // gated on cache state — `already_cached = false`.
@@ -415,16 +454,28 @@ async fn provider_download_binary(
                 .as_deref()
                 .or(state.config.curation.quarantine_ttl.as_deref()),
         );
-        if let Some(resp) = crate::digest_quarantine::proxy_gate_dated(
-            &state.digest_store,
-            "terraform",
-            &data,
-            &q_mode,
-            q_secs,
-            "cache",
-            publish_date,
-        ) {
-            return resp;
+        let hosted = bin_coords.len() >= 3
+            && state
+                .storage
+                .stat(&hosted_provider_key(
+                    bin_coords[0],
+                    bin_coords[1],
+                    bin_coords[2],
+                ))
+                .await
+                .is_some();
+        if !hosted {
+            if let Some(resp) = crate::digest_quarantine::proxy_gate_dated(
+                &state.digest_store,
+                "terraform",
+                &data,
+                &q_mode,
+                q_secs,
+                "cache",
+                publish_date,
+            ) {
+                return resp;
+            }
         }
 
         // Resume support: 206 for a `Range` request, 416 when the client asks past the
@@ -531,13 +582,17 @@ async fn module_versions(
     // Read cache eagerly — preserve for serve-stale fallback (#532)
     let cached_data = state.storage.get(&storage_key).await.ok();
 
+    // Versions published to NORA are merged into every answer below. The cache keeps the bare
+    // upstream document so a publish never waits out the TTL to appear.
+    let hosted = hosted_module_versions(&state.storage, &ns, &name, &provider).await;
+
     // TTL cache — serve fresh if within TTL
     if let Some(ref data) = cached_data {
         if let Some(meta) = state.storage.stat(&storage_key).await {
             if is_within_ttl(meta.modified, state.config.terraform.metadata_ttl) {
                 state.metrics.record_download("terraform");
                 state.metrics.record_cache_hit("terraform");
-                return with_json(data.to_vec());
+                return with_json(merge_module_versions(Some(data), &hosted));
             }
         }
     }
@@ -550,10 +605,10 @@ async fn module_versions(
         crate::curation::RegistryType::Terraform,
         &format!("{}/{}/{}", ns, name, provider),
     ) {
-        if let Some(ref data) = cached_data {
+        if cached_data.is_some() || !hosted.is_empty() {
             state.metrics.record_download("terraform");
             state.metrics.record_cache_hit("terraform");
-            return with_json(data.to_vec());
+            return with_json(merge_module_versions(cached_data.as_deref(), &hosted));
         }
         return crate::curation::check_namespace_isolation(
             &state.curation().curation_engine,
@@ -596,14 +651,21 @@ async fn module_versions(
                 .audit
                 .log(AuditEntry::new("proxy_fetch", "api", "", "terraform", ""));
 
-            state.spawn_cache("terraform", storage_key, Bytes::from(text.clone()));
-            with_json(text.into_bytes())
+            let merged = merge_module_versions(Some(text.as_bytes()), &hosted);
+            state.spawn_cache("terraform", storage_key, Bytes::from(text));
+            with_json(merged)
+        }
+        // A module the upstream has never heard of is answered from the hosted versions alone.
+        Err(_) if cached_data.is_none() && !hosted.is_empty() => {
+            state.metrics.record_download("terraform");
+            with_json(merge_module_versions(None, &hosted))
         }
         Err(ProxyError::NotFound) => StatusCode::NOT_FOUND.into_response(),
         Err(ProxyError::CircuitOpen(reg)) => circuit_open_response(&reg),
         Err(e) => {
             tracing::debug!(error = ?e, "Terraform module versions error");
-            serve_stale_or_bad_gateway(&state, cached_data, "module_versions")
+            let stale = cached_data.map(|d| Bytes::from(merge_module_versions(Some(&d), &hosted)));
+            serve_stale_or_bad_gateway(&state, stale, "module_versions")
         }
     }
 }
@@ -624,6 +686,24 @@ async fn module_download(
 
     let base_url = nora_base_url(&state);
 
+    // A hosted version's source is the archive published to NORA.
+    if is_hosted_module(&state.storage, &ns, &name, &provider, &ver).await {
+        state.metrics.record_download("terraform");
+        let source = format!(
+            "{}/terraform/v1/modules/download/{}/{}/{}/{}/source?archive=tar.gz",
+            base_url.trim_end_matches('/'),
+            ns,
+            name,
+            provider,
+            ver
+        );
+        return (
+            StatusCode::NO_CONTENT,
+            [("x-terraform-get", source.as_str())],
+        )
+            .into_response();
+    }
+
     // If we have a cached source URL, return the rewritten header immediately
     let source_url_key = format!(
         "terraform/modules/{}/{}/{}/{}/_source_url",
@@ -753,15 +833,18 @@ async fn module_source_download(
                 .as_deref()
                 .or(state.config.curation.quarantine_ttl.as_deref()),
         );
-        if let Some(resp) = crate::digest_quarantine::proxy_gate(
-            &state.digest_store,
-            "terraform",
-            &data,
-            &q_mode,
-            q_secs,
-            "cache",
-        ) {
-            return resp;
+        // A hosted archive was vetted at publish — the first-seen quarantine is for proxied ones.
+        if !is_hosted_module(&state.storage, &ns, &name, &provider, &ver).await {
+            if let Some(resp) = crate::digest_quarantine::proxy_gate(
+                &state.digest_store,
+                "terraform",
+                &data,
+                &q_mode,
+                q_secs,
+                "cache",
+            ) {
+                return resp;
+            }
         }
 
         // Resume support for the module archive — same rationale as the provider
@@ -847,6 +930,558 @@ async fn module_source_download(
     }
 }
 
+// ── Provider publish (hosted) ─────────────────────────────────────────
+
+/// PUT /terraform/v1/providers/{ns}/{type}/{ver}/{os}/{arch} — publish one platform of a
+/// provider version.
+///
+/// The body is the provider zip; `?protocols=5.0,6.0` sets the plugin protocols (default
+/// `5.0`, taken from the first platform published). Each upload regenerates the version's
+/// `SHA256SUMS` and its detached signature with the instance signing key, so `terraform init`
+/// verifies a hosted provider exactly as it verifies a public one.
+async fn provider_upload(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Path((ns, ptype, ver, os, arch)): Path<(String, String, String, String, String)>,
+    axum::extract::RawQuery(query): axum::extract::RawQuery,
+    body: Bytes,
+) -> Response {
+    if !is_valid_name(&ns)
+        || !is_valid_name(&ptype)
+        || !is_semver(&ver)
+        || !is_valid_name(&os)
+        || !is_valid_name(&arch)
+    {
+        return (
+            StatusCode::BAD_REQUEST,
+            "Invalid provider coordinates (version must be semver)",
+        )
+            .into_response();
+    }
+
+    // Enforce OIDC namespace_scope on the provider coordinate (#583).
+    let provider = format!("{}/{}", ns, ptype);
+    if enforce_namespace_scope(&authority, &provider).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    // Terraform refuses a provider whose checksums are not signed — without the key there is
+    // nothing installable to publish.
+    let Some(signer) = state.terraform_signer().await else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Provider publishing requires repository signing (signing.enabled)",
+        )
+            .into_response();
+    };
+
+    let protocols = match parse_protocols(query.as_deref()) {
+        Ok(p) => p,
+        Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
+    };
+    if let Err(e) = check_provider_zip(&body) {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("Invalid provider archive: {}", e),
+        )
+            .into_response();
+    }
+
+    let lock = state.publish_lock(&hosted_provider_key(&ns, &ptype, &ver));
+    let _guard = lock.lock().await;
+
+    let filename = format!("terraform-provider-{}_{}_{}_{}.zip", ptype, ver, os, arch);
+    let dir = format!("terraform/download/{}/{}/{}", ns, ptype, ver);
+    let zip_key = format!("{}/{}", dir, filename);
+    let mut hosted = match read_hosted_provider(&state.storage, &ns, &ptype, &ver).await {
+        Some(h) => h,
+        // A version already proxied from upstream is immutable: its platforms come from there.
+        None if state
+            .storage
+            .stat(&format!(
+                "terraform/providers/{}/{}/{}/{}_{}.json",
+                ns, ptype, ver, os, arch
+            ))
+            .await
+            .is_some() =>
+        {
+            return (
+                StatusCode::CONFLICT,
+                format!(
+                    "Provider {} version {} is proxied from upstream",
+                    provider, ver
+                ),
+            )
+                .into_response();
+        }
+        None => HostedProvider {
+            version: ver.clone(),
+            protocols,
+            platforms: Vec::new(),
+        },
+    };
+    if hosted
+        .platforms
+        .iter()
+        .any(|p| p.os == os && p.arch == arch)
+        || state.storage.stat(&zip_key).await.is_some()
+    {
+        return (
+            StatusCode::CONFLICT,
+            format!(
+                "Provider {} {} {}/{} already exists",
+                provider, ver, os, arch
+            ),
+        )
+            .into_response();
+    }
+
+    hosted.platforms.push(HostedPlatform {
+        os: os.clone(),
+        arch: arch.clone(),
+        filename: filename.clone(),
+        shasum: hex::encode(sha2::Sha256::digest(&body)),
+    });
+    hosted.platforms.sort_by(|a, b| a.filename.cmp(&b.filename));
+
+    let sums = hosted
+        .platforms
+        .iter()
+        .map(|p| format!("{}  {}\n", p.shasum, p.filename))
+        .collect::<String>();
+    let signature = match signer.sign_detached_binary(sums.as_bytes()) {
+        Ok(sig) => sig,
+        Err(e) => {
+            tracing::error!(error = %e, "terraform provider publish: signing SHA256SUMS failed");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let sums_key = format!("{}/{}", dir, shasums_filename(&ptype, &ver));
+    let record = serde_json::to_vec(&hosted).unwrap_or_default();
+
+    // The zip goes first; the publish record is what makes the platform visible, and it is
+    // written last so the checksums it implies are already in place.
+    if state.storage.put(&zip_key, &body).await.is_err() {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    let writes = [
+        (sums_key.clone(), sums.into_bytes()),
+        (format!("{}.sig", sums_key), signature),
+        (hosted_provider_key(&ns, &ptype, &ver), record),
+    ];
+    for (key, data) in &writes {
+        if state.storage.put(key, data).await.is_err() {
+            let _ = state.storage.delete(&zip_key).await;
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+
+    state.metrics.record_upload("terraform");
+    let artifact = format!("{} v{} {}/{}", provider, ver, os, arch);
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &artifact, "terraform", ""));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        artifact,
+        crate::registry_type::RegistryType::Terraform,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("terraform");
+
+    let base_url = nora_base_url(&state);
+    let meta = hosted_download_meta(&state, &base_url, &ns, &ptype, &hosted, &os, &arch).await;
+    (
+        StatusCode::CREATED,
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        )],
+        serde_json::to_vec(&meta).unwrap_or_default(),
+    )
+        .into_response()
+}
+
+/// Publish record of a hosted provider version.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct HostedProvider {
+    version: String,
+    protocols: Vec<String>,
+    platforms: Vec<HostedPlatform>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct HostedPlatform {
+    os: String,
+    arch: String,
+    filename: String,
+    shasum: String,
+}
+
+fn hosted_provider_key(ns: &str, ptype: &str, ver: &str) -> String {
+    format!("terraform/providers/{}/{}/{}/hosted.json", ns, ptype, ver)
+}
+
+fn shasums_filename(ptype: &str, ver: &str) -> String {
+    format!("terraform-provider-{}_{}_SHA256SUMS", ptype, ver)
+}
+
+async fn read_hosted_provider(
+    storage: &Storage,
+    ns: &str,
+    ptype: &str,
+    ver: &str,
+) -> Option<HostedProvider> {
+    let data = storage
+        .get(&hosted_provider_key(ns, ptype, ver))
+        .await
+        .ok()?;
+    serde_json::from_slice(&data).ok()
+}
+
+/// Publish records of every hosted version of a provider.
+async fn hosted_providers(storage: &Storage, ns: &str, ptype: &str) -> Vec<HostedProvider> {
+    let prefix = format!("terraform/providers/{}/{}/", ns, ptype);
+    let mut out = Vec::new();
+    for key in storage.list(&prefix).await.unwrap_or_default() {
+        if !key.ends_with("/hosted.json") {
+            continue;
+        }
+        if let Some(hosted) = storage
+            .get(&key)
+            .await
+            .ok()
+            .and_then(|d| serde_json::from_slice::<HostedProvider>(&d).ok())
+        {
+            out.push(hosted);
+        }
+    }
+    out
+}
+
+/// Merge hosted versions into a Provider Registry `versions` document
+/// (`{"versions":[{"version","protocols","platforms":[{"os","arch"}]}]}`). A hosted version
+/// replaces an upstream entry with the same number.
+fn merge_provider_versions(upstream: Option<&[u8]>, hosted: &[HostedProvider]) -> Vec<u8> {
+    if hosted.is_empty() {
+        return upstream.map(<[u8]>::to_vec).unwrap_or_default();
+    }
+    let mut doc = upstream
+        .and_then(|d| serde_json::from_slice::<serde_json::Value>(d).ok())
+        .filter(|d| d["versions"].is_array())
+        .unwrap_or_else(|| serde_json::json!({ "versions": [] }));
+    if let Some(versions) = doc["versions"].as_array_mut() {
+        versions.retain(|v| !hosted.iter().any(|h| v["version"] == h.version.as_str()));
+        for h in hosted {
+            let platforms: Vec<serde_json::Value> = h
+                .platforms
+                .iter()
+                .map(|p| serde_json::json!({ "os": p.os, "arch": p.arch }))
+                .collect();
+            versions.push(serde_json::json!({
+                "version": h.version,
+                "protocols": h.protocols,
+                "platforms": platforms,
+            }));
+        }
+    }
+    serde_json::to_vec(&doc).unwrap_or_default()
+}
+
+/// Download metadata for one platform of a hosted provider, or `None` if it was not published.
+async fn hosted_download_meta(
+    state: &AppState,
+    base_url: &str,
+    ns: &str,
+    ptype: &str,
+    hosted: &HostedProvider,
+    os: &str,
+    arch: &str,
+) -> Option<serde_json::Value> {
+    let platform = hosted
+        .platforms
+        .iter()
+        .find(|p| p.os == os && p.arch == arch)?;
+    let download_base = format!(
+        "{}/terraform/v1/providers/download/{}/{}/{}",
+        base_url.trim_end_matches('/'),
+        ns,
+        ptype,
+        hosted.version
+    );
+    let sums = shasums_filename(ptype, &hosted.version);
+    let gpg_public_keys: Vec<serde_json::Value> = state
+        .terraform_signer()
+        .await
+        .iter()
+        .map(|signer| {
+            serde_json::json!({
+                "key_id": signer.key_id(),
+                "ascii_armor": signer.public_key_armored(),
+                "trust_signature": "",
+                "source": "NORA",
+                "source_url": null,
+            })
+        })
+        .collect();
+    Some(serde_json::json!({
+        "protocols": hosted.protocols,
+        "os": platform.os,
+        "arch": platform.arch,
+        "filename": platform.filename,
+        "download_url": format!("{}/{}", download_base, platform.filename),
+        "shasums_url": format!("{}/{}", download_base, sums),
+        "shasums_signature_url": format!("{}/{}.sig", download_base, sums),
+        "shasum": platform.shasum,
+        "signing_keys": { "gpg_public_keys": gpg_public_keys },
+    }))
+}
+
+/// `protocols=5.0,6.0` from the query string; `5.0` when absent.
+fn parse_protocols(query: Option<&str>) -> Result<Vec<String>, String> {
+    let Some(raw) = query
+        .unwrap_or("")
+        .split('&')
+        .find_map(|kv| kv.strip_prefix("protocols="))
+    else {
+        return Ok(vec!["5.0".to_string()]);
+    };
+    let raw = percent_encoding::percent_decode_str(raw).decode_utf8_lossy();
+    let protocols: Vec<String> = raw.split(',').map(|p| p.trim().to_string()).collect();
+    let valid = |p: &str| {
+        p.split_once('.').is_some_and(|(major, minor)| {
+            !major.is_empty()
+                && !minor.is_empty()
+                && major
+                    .bytes()
+                    .chain(minor.bytes())
+                    .all(|b| b.is_ascii_digit())
+        })
+    };
+    if protocols.iter().all(|p| valid(p)) {
+        Ok(protocols)
+    } else {
+        Err(format!(
+            "Invalid protocols {:?} (expected e.g. 5.0,6.0)",
+            raw
+        ))
+    }
+}
+
+/// A provider archive must be a zip with at least one regular file and no entry escaping the
+/// extraction directory.
+fn check_provider_zip(data: &[u8]) -> Result<(), String> {
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(data)).map_err(|e| e.to_string())?;
+    let mut files = 0usize;
+    for i in 0..archive.len() {
+        let entry = archive.by_index_raw(i).map_err(|e| e.to_string())?;
+        if entry.enclosed_name().is_none() {
+            return Err(format!("entry escapes the archive: {}", entry.name()));
+        }
+        if !entry.is_dir() {
+            files += 1;
+        }
+    }
+    if files == 0 {
+        return Err("no files".to_string());
+    }
+    Ok(())
+}
+
+// ── Module publish (hosted) ───────────────────────────────────────────
+
+/// Upper bound on a published module's total unpacked size.
+const MAX_MODULE_UNPACKED: u64 = 512 << 20;
+
+/// PUT /terraform/v1/modules/{ns}/{name}/{provider}/{ver} — publish a module.
+///
+/// The body is the module source as a `.tar.gz`. It is stored under the same key the source
+/// download endpoint serves, next to a `hosted.json` marker that lists the version in
+/// `module_versions` and points `module_download` back at NORA instead of the upstream.
+async fn module_upload(
+    State(state): State<AppState>,
+    Extension(authority): Extension<NamespaceAuthority>,
+    Path((ns, name, provider, ver)): Path<(String, String, String, String)>,
+    body: Bytes,
+) -> Response {
+    if !is_valid_name(&ns) || !is_valid_name(&name) || !is_valid_name(&provider) || !is_semver(&ver)
+    {
+        return (
+            StatusCode::BAD_REQUEST,
+            "Invalid module coordinates (version must be semver)",
+        )
+            .into_response();
+    }
+
+    // Enforce OIDC namespace_scope on the module coordinate (#583).
+    let module = format!("{}/{}/{}", ns, name, provider);
+    if enforce_namespace_scope(&authority, &module).is_err() {
+        return (StatusCode::FORBIDDEN, "Outside namespace scope").into_response();
+    }
+
+    if let Err(e) = check_module_archive(&body) {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("Invalid module archive: {}", e),
+        )
+            .into_response();
+    }
+
+    let lock = state.publish_lock(&format!("terraform/modules/{}", module));
+    let _guard = lock.lock().await;
+
+    // Versions are immutable — including one already cached from upstream.
+    let source_key = format!("terraform/modules/{}/{}/source.tar.gz", module, ver);
+    if state.storage.stat(&source_key).await.is_some() {
+        return (
+            StatusCode::CONFLICT,
+            format!("Module {} version {} already exists", module, ver),
+        )
+            .into_response();
+    }
+
+    let marker = serde_json::json!({
+        "version": ver,
+        "sha256": hex::encode(sha2::Sha256::digest(&body)),
+        "published_at": chrono::Utc::now().to_rfc3339(),
+    });
+    let marker_bytes = serde_json::to_vec(&marker).unwrap_or_default();
+
+    // The archive goes first; the marker is what makes the version visible.
+    if state.storage.put(&source_key, &body).await.is_err() {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+    if state
+        .storage
+        .put(&hosted_marker_key(&module, &ver), &marker_bytes)
+        .await
+        .is_err()
+    {
+        let _ = state.storage.delete(&source_key).await;
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    state.metrics.record_upload("terraform");
+    let artifact = format!("{} v{}", module, ver);
+    state
+        .audit
+        .log(AuditEntry::new("push", "api", &artifact, "terraform", ""));
+    state.activity.push(ActivityEntry::new(
+        ActionType::Push,
+        artifact,
+        crate::registry_type::RegistryType::Terraform,
+        "LOCAL",
+    ));
+    state.repo_index.invalidate("terraform");
+
+    (
+        StatusCode::CREATED,
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        )],
+        marker_bytes,
+    )
+        .into_response()
+}
+
+/// Marker recording that a module version was published to NORA (`module` is `ns/name/provider`).
+fn hosted_marker_key(module: &str, ver: &str) -> String {
+    format!("terraform/modules/{}/{}/hosted.json", module, ver)
+}
+
+async fn is_hosted_module(
+    storage: &Storage,
+    ns: &str,
+    name: &str,
+    provider: &str,
+    ver: &str,
+) -> bool {
+    let module = format!("{}/{}/{}", ns, name, provider);
+    storage
+        .stat(&hosted_marker_key(&module, ver))
+        .await
+        .is_some()
+}
+
+/// Versions of a module published to NORA.
+async fn hosted_module_versions(
+    storage: &Storage,
+    ns: &str,
+    name: &str,
+    provider: &str,
+) -> Vec<String> {
+    let prefix = format!("terraform/modules/{}/{}/{}/", ns, name, provider);
+    storage
+        .list(&prefix)
+        .await
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|key| key.strip_prefix(&prefix)?.strip_suffix("/hosted.json"))
+        .filter(|ver| is_semver(ver))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Merge hosted versions into a Module Registry `versions` document
+/// (`{"modules":[{"versions":[{"version":"1.0.0"}]}]}`). Without an upstream document, or with
+/// one that does not parse, the result lists the hosted versions alone.
+fn merge_module_versions(upstream: Option<&[u8]>, hosted: &[String]) -> Vec<u8> {
+    if hosted.is_empty() {
+        return upstream.map(<[u8]>::to_vec).unwrap_or_default();
+    }
+    let mut doc = upstream
+        .and_then(|d| serde_json::from_slice::<serde_json::Value>(d).ok())
+        .filter(|d| d["modules"][0]["versions"].is_array())
+        .unwrap_or_else(|| serde_json::json!({"modules": [{"versions": []}]}));
+    if let Some(versions) = doc["modules"][0]["versions"].as_array_mut() {
+        for ver in hosted {
+            if !versions.iter().any(|v| v["version"] == ver.as_str()) {
+                versions.push(serde_json::json!({ "version": ver }));
+            }
+        }
+    }
+    serde_json::to_vec(&doc).unwrap_or_default()
+}
+
+/// Check that a module archive is a gzip-compressed tar whose entries all stay inside the
+/// module directory, and that it does not unpack past [`MAX_MODULE_UNPACKED`].
+fn check_module_archive(data: &[u8]) -> Result<(), String> {
+    if data.is_empty() {
+        return Err("empty body".to_string());
+    }
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(data));
+    let mut total: u64 = 0;
+    let mut files = 0usize;
+    for entry in archive.entries().map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path().map_err(|e| e.to_string())?;
+        if path.is_absolute()
+            || path
+                .components()
+                .any(|c| matches!(c, std::path::Component::ParentDir))
+        {
+            return Err(format!("entry escapes the module: {}", path.display()));
+        }
+        let kind = entry.header().entry_type();
+        if kind.is_symlink() || kind.is_hard_link() {
+            return Err(format!("links are not allowed: {}", path.display()));
+        }
+        if kind.is_file() {
+            files += 1;
+        }
+        total = total.saturating_add(entry.size());
+        if total > MAX_MODULE_UNPACKED {
+            return Err(format!("unpacks past {} bytes", MAX_MODULE_UNPACKED));
+        }
+    }
+    if files == 0 {
+        return Err("no files".to_string());
+    }
+    Ok(())
+}
+
 // ── Provider Network Mirror Protocol handlers (#801) ───────────────────
 //
 // Terraform's `network_mirror` speaks a different protocol from the Registry
@@ -1635,6 +2270,12 @@ fn is_valid_version(version: &str) -> bool {
             .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_' || c == '+')
 }
 
+/// Module Registry versions are semantic versions: MAJOR.MINOR.PATCH with an optional
+/// `-prerelease` and `+build`.
+fn is_semver(version: &str) -> bool {
+    is_valid_version(version) && crate::version::is_semver(version)
+}
+
 /// Path safety validation
 fn is_safe_path(path: &str) -> bool {
     !path.contains("..")
@@ -2011,6 +2652,143 @@ mod tests {
             "relative paths should pass through"
         );
     }
+
+    // ── Hosted providers ───────────────────────────────────────────────
+
+    pub(super) fn provider_zip(name: &str) -> Vec<u8> {
+        use std::io::Write;
+        let mut w = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        w.start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        w.write_all(b"\x7fELF provider").unwrap();
+        w.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_parse_protocols() {
+        assert_eq!(parse_protocols(None).unwrap(), ["5.0"]);
+        assert_eq!(parse_protocols(Some("x=1")).unwrap(), ["5.0"]);
+        assert_eq!(
+            parse_protocols(Some("protocols=5.0%2C6.0")).unwrap(),
+            ["5.0", "6.0"]
+        );
+        assert!(parse_protocols(Some("protocols=six")).is_err());
+        assert!(parse_protocols(Some("protocols=")).is_err());
+    }
+
+    #[test]
+    fn test_check_provider_zip() {
+        assert!(check_provider_zip(&provider_zip("terraform-provider-x_v1.0.0")).is_ok());
+        assert!(check_provider_zip(&provider_zip("../escape")).is_err());
+        assert!(check_provider_zip(b"PK not really").is_err());
+    }
+
+    #[test]
+    fn test_merge_provider_versions_replaces_same_version() {
+        let upstream = br#"{"id":"acme/x","versions":[
+            {"version":"1.0.0","protocols":["5.0"],"platforms":[{"os":"linux","arch":"amd64"}]},
+            {"version":"2.0.0","protocols":["5.0"],"platforms":[]}]}"#;
+        let hosted = [HostedProvider {
+            version: "2.0.0".into(),
+            protocols: vec!["6.0".into()],
+            platforms: vec![HostedPlatform {
+                os: "darwin".into(),
+                arch: "arm64".into(),
+                filename: "f.zip".into(),
+                shasum: "00".into(),
+            }],
+        }];
+        let json: serde_json::Value =
+            serde_json::from_slice(&merge_provider_versions(Some(upstream), &hosted)).unwrap();
+        assert_eq!(json["id"], "acme/x");
+        let versions = json["versions"].as_array().unwrap();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[1]["version"], "2.0.0");
+        assert_eq!(versions[1]["protocols"][0], "6.0");
+        assert_eq!(versions[1]["platforms"][0]["os"], "darwin");
+        assert_eq!(
+            merge_provider_versions(Some(upstream), &[]),
+            upstream.to_vec()
+        );
+    }
+
+    // ── Hosted modules ─────────────────────────────────────────────────
+
+    pub(super) fn module_tar_gz(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::default(),
+        ));
+        for (path, data) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, *data).unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    #[test]
+    fn test_is_semver() {
+        assert!(is_semver("1.0.0"));
+        assert!(is_semver("0.12.3-beta.1+build.5"));
+        assert!(!is_semver("1.0"));
+        assert!(!is_semver("v1.0.0"));
+        assert!(!is_semver("1.0.x"));
+    }
+
+    #[test]
+    fn test_check_module_archive() {
+        assert!(check_module_archive(&module_tar_gz(&[("main.tf", b"variable \"x\" {}")])).is_ok());
+        assert!(check_module_archive(&module_tar_gz(&[("modules/sub/main.tf", b"")])).is_ok());
+        assert!(check_module_archive(b"").is_err());
+        assert!(check_module_archive(b"not gzip").is_err());
+        assert!(check_module_archive(&module_tar_gz(&[])).is_err());
+    }
+
+    #[test]
+    fn test_check_module_archive_rejects_traversal() {
+        // tar::Builder refuses `..` itself, so write the raw header name.
+        let mut header = tar::Header::new_gnu();
+        header.as_gnu_mut().unwrap().name[..9].copy_from_slice(b"../evil.t");
+        header.set_size(0);
+        header.set_mode(0o644);
+        header.set_cksum();
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::default(),
+        ));
+        builder.append(&header, &b""[..]).unwrap();
+        let data = builder.into_inner().unwrap().finish().unwrap();
+        assert!(check_module_archive(&data).unwrap_err().contains("escapes"));
+    }
+
+    #[test]
+    fn test_merge_module_versions() {
+        let upstream =
+            br#"{"modules":[{"source":"acme/vpc/aws","versions":[{"version":"1.0.0"}]}]}"#;
+        let merged = merge_module_versions(Some(upstream), &["1.0.0".into(), "2.0.0".into()]);
+        let json: serde_json::Value = serde_json::from_slice(&merged).unwrap();
+        assert_eq!(json["modules"][0]["source"], "acme/vpc/aws");
+        let versions: Vec<&str> = json["modules"][0]["versions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["version"].as_str().unwrap())
+            .collect();
+        assert_eq!(versions, ["1.0.0", "2.0.0"]);
+
+        // No hosted versions: upstream passes through byte-for-byte
+        assert_eq!(
+            merge_module_versions(Some(upstream), &[]),
+            upstream.to_vec()
+        );
+        // No upstream: hosted alone
+        let json: serde_json::Value =
+            serde_json::from_slice(&merge_module_versions(None, &["0.1.0".into()])).unwrap();
+        assert_eq!(json["modules"][0]["versions"][0]["version"], "0.1.0");
+    }
 }
 
 #[cfg(test)]
@@ -2603,4 +3381,307 @@ mod integration_tests {
             "bytes"
         );
     }
+
+    /// Terraform context whose upstream knows no modules (404 for everything).
+    async fn hosted_module_context() -> (crate::test_helpers::TestContext, wiremock::MockServer) {
+        let server = wiremock::MockServer::start().await;
+        let url = server.uri();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.terraform.enabled = true;
+            cfg.terraform.proxy = Some(url);
+        });
+        (ctx, server)
+    }
+
+    #[tokio::test]
+    async fn test_terraform_module_publish_then_install() {
+        let (ctx, _server) = hosted_module_context().await;
+        let archive = super::tests::module_tar_gz(&[("main.tf", b"output \"x\" { value = 1 }")]);
+
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/terraform/v1/modules/platform/vpc/aws/1.2.0",
+            archive.clone(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/terraform/v1/modules/platform/vpc/aws/versions",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["modules"][0]["versions"][0]["version"], "1.2.0");
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/terraform/v1/modules/platform/vpc/aws/1.2.0/download",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        let source = resp.headers()["x-terraform-get"].to_str().unwrap();
+        assert!(source.ends_with(
+            "/terraform/v1/modules/download/platform/vpc/aws/1.2.0/source?archive=tar.gz"
+        ));
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/terraform/v1/modules/download/platform/vpc/aws/1.2.0/source",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(&body_bytes(resp).await[..], &archive[..]);
+    }
+
+    #[tokio::test]
+    async fn test_terraform_module_publish_conflict_and_validation() {
+        let (ctx, _server) = hosted_module_context().await;
+        let archive = super::tests::module_tar_gz(&[("main.tf", b"")]);
+        let url = "/terraform/v1/modules/platform/vpc/aws/1.0.0";
+
+        let resp = send(&ctx.app, Method::PUT, url, archive.clone()).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let resp = send(&ctx.app, Method::PUT, url, archive.clone()).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/terraform/v1/modules/platform/vpc/aws/1.1.0",
+            "not an archive",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let resp = send(
+            &ctx.app,
+            Method::PUT,
+            "/terraform/v1/modules/platform/vpc/aws/latest",
+            archive,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_terraform_module_publish_namespace_scope_enforced() {
+        use crate::auth::NamespaceAuthority;
+        use crate::config::ScopeEnforcement;
+        use axum::body::Bytes;
+        use axum::extract::{Path, State};
+        use axum::Extension;
+
+        let (ctx, _server) = hosted_module_context().await;
+        let scoped = NamespaceAuthority::from_oidc_scope(
+            "ci",
+            &["platform/**".to_string()],
+            ScopeEnforcement::Enforce,
+        );
+        let archive = Bytes::from(super::tests::module_tar_gz(&[("main.tf", b"")]));
+        let coords = |ns: &str| {
+            Path((
+                ns.to_string(),
+                "vpc".to_string(),
+                "aws".to_string(),
+                "1.0.0".to_string(),
+            ))
+        };
+
+        let resp = super::module_upload(
+            State(ctx.state.clone()),
+            Extension(scoped.clone()),
+            coords("other"),
+            archive.clone(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let resp = super::module_upload(
+            State(ctx.state.clone()),
+            Extension(scoped),
+            coords("platform"),
+            archive,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    async fn publish_provider(
+        ctx: &crate::test_helpers::TestContext,
+        os: &str,
+        arch: &str,
+    ) -> axum::http::Response<axum::body::Body> {
+        let zip = super::tests::provider_zip("terraform-provider-internal_v1.0.0");
+        send(
+            &ctx.app,
+            Method::PUT,
+            &format!(
+                "/terraform/v1/providers/platform/internal/1.0.0/{os}/{arch}?protocols=5.0,6.0"
+            ),
+            zip,
+        )
+        .await
+    }
+
+    /// A hosted provider carries signed checksums that verify against the advertised key.
+    #[tokio::test]
+    async fn test_terraform_provider_publish_then_install() {
+        use pgp::composed::{Deserializable, DetachedSignature, SignedPublicKey};
+
+        let (ctx, _server) = hosted_module_context().await;
+        assert_eq!(
+            publish_provider(&ctx, "linux", "amd64").await.status(),
+            StatusCode::CREATED
+        );
+        assert_eq!(
+            publish_provider(&ctx, "darwin", "arm64").await.status(),
+            StatusCode::CREATED
+        );
+        assert_eq!(
+            publish_provider(&ctx, "linux", "amd64").await.status(),
+            StatusCode::CONFLICT
+        );
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/terraform/v1/providers/platform/internal/versions",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(json["versions"][0]["version"], "1.0.0");
+        assert_eq!(json["versions"][0]["protocols"][1], "6.0");
+        assert_eq!(
+            json["versions"][0]["platforms"].as_array().unwrap().len(),
+            2
+        );
+
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/terraform/v1/providers/platform/internal/1.0.0/download/linux/amd64",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let meta: serde_json::Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+        assert_eq!(
+            meta["filename"],
+            "terraform-provider-internal_1.0.0_linux_amd64.zip"
+        );
+        let key = &meta["signing_keys"]["gpg_public_keys"][0];
+        let signer = ctx.state.signer.as_ref().unwrap();
+        assert_eq!(key["key_id"], signer.key_id());
+
+        let path_of = |url: &serde_json::Value| {
+            let url = url.as_str().unwrap();
+            url[url.find("/terraform/").unwrap()..].to_string()
+        };
+        let resp = send(&ctx.app, Method::GET, &path_of(&meta["shasums_url"]), "").await;
+        let sums = body_bytes(resp).await;
+        let sums_text = std::str::from_utf8(&sums).unwrap();
+        assert_eq!(sums_text.lines().count(), 2);
+        assert!(sums_text.contains(&format!(
+            "{}  terraform-provider-internal_1.0.0_linux_amd64.zip",
+            meta["shasum"].as_str().unwrap()
+        )));
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            &path_of(&meta["shasums_signature_url"]),
+            "",
+        )
+        .await;
+        let sig = DetachedSignature::from_bytes(&body_bytes(resp).await[..]).unwrap();
+        let (public, _) =
+            SignedPublicKey::from_string(key["ascii_armor"].as_str().unwrap()).unwrap();
+        sig.verify(&public, &sums).unwrap();
+
+        let resp = send(&ctx.app, Method::GET, &path_of(&meta["download_url"]), "").await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            &body_bytes(resp).await[..],
+            &super::tests::provider_zip("terraform-provider-internal_v1.0.0")[..]
+        );
+
+        // Unpublished platform
+        let resp = send(
+            &ctx.app,
+            Method::GET,
+            "/terraform/v1/providers/platform/internal/1.0.0/download/windows/amd64",
+            "",
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_terraform_provider_publish_requires_signing() {
+        let ctx = create_test_context_with_config(|cfg| {
+            cfg.terraform.enabled = true;
+            cfg.signing.enabled = false;
+        });
+        assert_eq!(
+            publish_provider(&ctx, "linux", "amd64").await.status(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+    }
+
+    #[tokio::test]
+    async fn test_terraform_signing_key_loaded_on_first_use() {
+        let key_dir = tempfile::TempDir::new().unwrap();
+        let key_path = key_dir.path().join("nora.key");
+        let configured = key_path.to_str().unwrap().to_string();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.terraform.enabled = true;
+            cfg.signing.enabled = true;
+            cfg.signing.key_path = configured;
+        });
+        // Terraform alone does not build the repository signer at startup.
+        let terraform_only = [crate::registry_type::RegistryType::Terraform]
+            .into_iter()
+            .collect();
+        assert!(crate::build_signer(&ctx.state.config, &terraform_only).is_none());
+        assert!(!key_path.exists());
+
+        let mut state = ctx.state.clone();
+        state.signer = None;
+        let signer = state.terraform_signer().await.unwrap();
+        assert!(key_path.exists());
+        assert_eq!(
+            state.terraform_signer().await.unwrap().key_id(),
+            signer.key_id()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_terraform_signing_key_error_is_retried() {
+        let key_dir = tempfile::TempDir::new().unwrap();
+        let key_path = key_dir.path().join("nora.key");
+        std::fs::write(&key_path, b"not a key").unwrap();
+        let configured = key_path.to_str().unwrap().to_string();
+        let ctx = create_test_context_with_config(move |cfg| {
+            cfg.terraform.enabled = true;
+            cfg.signing.enabled = true;
+            cfg.signing.key_path = configured;
+        });
+        let mut state = ctx.state.clone();
+        state.signer = None;
+        assert!(state.terraform_signer().await.is_none());
+
+        // Fixing the key re-enables publishing without a restart.
+        std::fs::remove_file(&key_path).unwrap();
+        assert!(state.terraform_signer().await.is_some());
+        assert!(key_path.exists());
+    }
 }
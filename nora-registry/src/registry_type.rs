@@ -37,6 +37,16 @@ pub enum RegistryType {
     Rpm,
     #[serde(rename = "deb")]
     Deb,
+    #[serde(rename = "helm")]
+    Helm,
+    #[serde(rename = "apk")]
+    Apk,
+    #[serde(rename = "conda")]
+    Conda,
+    #[serde(rename = "composer")]
+    Composer,
+    #[serde(rename = "swift")]
+    Swift,
 }
 
 impl RegistryType {
@@ -58,6 +68,11 @@ impl RegistryType {
             Self::Conan => "conan",
             Self::Rpm => "rpm",
             Self::Deb => "deb",
+            Self::Helm => "helm",
+            Self::Apk => "apk",
+            Self::Conda => "conda",
+            Self::Composer => "composer",
+            Self::Swift => "swift",
         }
     }
 
@@ -79,6 +94,11 @@ impl RegistryType {
             Self::Conan => "/conan/",
             Self::Rpm => "/rpm/",
             Self::Deb => "/deb/",
+            Self::Helm => "/helm/",
+            Self::Apk => "/apk/",
+            Self::Conda => "/conda/",
+            Self::Composer => "/composer/",
+            Self::Swift => "/swift/",
         }
     }
 
@@ -100,6 +120,11 @@ impl RegistryType {
             Self::Conan => "Conan",
             Self::Rpm => "RPM",
             Self::Deb => "Debian",
+            Self::Helm => "Helm",
+            Self::Apk => "Alpine",
+            Self::Conda => "Conda",
+            Self::Composer => "Composer",
+            Self::Swift => "Swift",
         }
     }
 
@@ -134,6 +159,11 @@ impl RegistryType {
             Self::Conan,
             Self::Rpm,
             Self::Deb,
+            Self::Helm,
+            Self::Apk,
+            Self::Conda,
+            Self::Composer,
+            Self::Swift,
         ]
     }
 
@@ -155,6 +185,11 @@ impl RegistryType {
             "conan" => Some(Self::Conan),
             "rpm" | "yum" | "dnf" => Some(Self::Rpm),
             "deb" | "apt" | "debian" => Some(Self::Deb),
+            "helm" | "chartmuseum" => Some(Self::Helm),
+            "apk" | "alpine" => Some(Self::Apk),
+            "conda" | "mamba" => Some(Self::Conda),
+            "composer" | "packagist" | "php" => Some(Self::Composer),
+            "swift" | "swiftpm" => Some(Self::Swift),
             _ => None,
         }
     }
@@ -15,9 +15,10 @@ use crate::ui::components::format_timestamp;
 use crate::validation::ends_with_ci;
 use parking_lot::RwLock;
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex as AsyncMutex;
 use tracing::info;
 
@@ -33,6 +34,66 @@ pub struct RepoInfo {
     pub is_file: bool,
 }
 
+/// Docker repositories and tags, sorted, for the paginated `_catalog` and
+/// `tags/list` endpoints (OCI `n`/`last`). Rebuilt with the docker index.
+#[derive(Debug, Default)]
+pub struct DockerCatalog {
+    /// Sorted tags per storage path: `{ns}/{name}` for a proxied copy, `{name}`
+    /// for a pushed (or pre-namespacing) one. Every repository with a blob or
+    /// manifest has an entry, tagged or not.
+    pub tags: HashMap<String, Vec<String>>,
+    /// Canonical repository names, filled on first use (see [`Self::repositories`]).
+    repositories: OnceLock<Vec<String>>,
+    /// When the storage scan behind this catalog ran (`None` = never built).
+    built: Option<Instant>,
+}
+
+impl DockerCatalog {
+    /// Repository names as the registry API addresses them — each storage path
+    /// canonicalized against the docker upstream config, so the proxied and
+    /// pushed copies of an image list once — sorted and deduplicated. The
+    /// config is fixed for the process, so this is computed once per rebuild.
+    pub fn repositories(&self, docker_config: &crate::config::DockerConfig) -> &[String] {
+        self.repositories.get_or_init(|| {
+            let mut names: Vec<String> = self
+                .tags
+                .keys()
+                .map(|raw| crate::registry::docker::canonicalize(raw, docker_config).name)
+                .collect();
+            names.sort();
+            names.dedup();
+            names
+        })
+    }
+}
+
+/// The searchable summary of an npm packument, as `/-/v1/search` reports it.
+/// Parsing and scoring live with the npm registry (`registry::npm`).
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SearchDoc {
+    pub(crate) name: String,
+    pub(crate) version: String,
+    pub(crate) description: String,
+    pub(crate) keywords: Vec<String>,
+    pub(crate) author: Option<String>,
+    pub(crate) publisher: Option<(String, String)>,
+    pub(crate) maintainers: Vec<(String, String)>,
+    pub(crate) date: Option<String>,
+    pub(crate) links: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parses a stored packument (its bytes and mtime) into a [`SearchDoc`].
+pub(crate) type SearchDocParser = fn(&[u8], u64) -> Option<SearchDoc>;
+
+/// npm packuments by name, for `/-/v1/search`. Rebuilt from storage on the first
+/// search after a hosted publish/unpublish; packuments the proxy caches are
+/// upserted in place instead.
+struct NpmSearchIndex {
+    docs: RwLock<Arc<BTreeMap<String, SearchDoc>>>,
+    dirty: AtomicBool,
+    rebuild_lock: AsyncMutex<()>,
+}
+
 /// Index for a single registry type
 pub struct RegistryIndex {
     data: RwLock<Arc<Vec<RepoInfo>>>,
@@ -97,6 +158,9 @@ pub struct RepoIndex {
     /// debounce operator-triggered reindex so a tight `reindex + read` loop
     /// cannot amplify into repeated full-storage scans (see `try_accept_reindex`).
     last_reindex: AtomicU64,
+    /// Built alongside the docker `RepoInfo` list from the same storage scan.
+    docker_catalog: RwLock<Arc<DockerCatalog>>,
+    npm_search: NpmSearchIndex,
 }
 
 impl RepoIndex {
@@ -108,6 +172,12 @@ impl RepoIndex {
         Self {
             indexes,
             last_reindex: AtomicU64::new(0),
+            docker_catalog: RwLock::new(Arc::new(DockerCatalog::default())),
+            npm_search: NpmSearchIndex {
+                docs: RwLock::new(Arc::new(BTreeMap::new())),
+                dirty: AtomicBool::new(true),
+                rebuild_lock: AsyncMutex::new(()),
+            },
         }
     }
 
@@ -126,6 +196,12 @@ impl RepoIndex {
         for idx in self.indexes.values() {
             idx.invalidate();
         }
+        self.invalidate_npm_search();
+    }
+
+    /// Rebuild the npm search index from storage on the next search.
+    pub fn invalidate_npm_search(&self) {
+        self.npm_search.dirty.store(true, Ordering::Release);
     }
 
     /// Debounce gate for operator-triggered reindex. Returns `Ok(())` and records
@@ -156,9 +232,22 @@ impl RepoIndex {
             None => return Arc::new(Vec::new()),
         };
 
-        // Fast path: not dirty, return cached
+        self.rebuild_if_dirty(reg_type, index, storage).await;
+        index.get_cached()
+    }
+
+    /// Rebuild `index` from storage if it is dirty, with double-checked locking.
+    /// `false` when the storage list failed, leaving the index stale and dirty.
+    async fn rebuild_if_dirty(
+        &self,
+        reg_type: RegistryType,
+        index: &RegistryIndex,
+        storage: &Storage,
+    ) -> bool {
+        let registry = reg_type.as_str();
+        // Fast path: not dirty, nothing to rebuild
         if !index.is_dirty() {
-            return index.get_cached();
+            return true;
         }
 
         // Slow path: acquire rebuild lock (only one thread rebuilds)
@@ -167,7 +256,12 @@ impl RepoIndex {
         // Double-check under lock (another thread may have rebuilt)
         if index.is_dirty() {
             let data = match reg_type {
-                RegistryType::Docker => build_docker_index(storage).await,
+                RegistryType::Docker => {
+                    build_docker_index(storage).await.map(|(repos, catalog)| {
+                        *self.docker_catalog.write() = Arc::new(catalog);
+                        repos
+                    })
+                }
                 RegistryType::Maven => build_maven_index(storage).await,
                 RegistryType::Npm => build_npm_index(storage).await,
                 RegistryType::Cargo => build_cargo_index(storage).await,
@@ -200,11 +294,26 @@ impl RepoIndex {
                     let (p, s) = crate::registry::deb::INDEX_PATTERN;
                     build_generic_index(storage, p, s).await
                 }
+                RegistryType::Helm => build_helm_index(storage).await,
+                RegistryType::Apk => {
+                    let (p, s) = crate::registry::apk::INDEX_PATTERN;
+                    build_generic_index(storage, p, s).await
+                }
+                RegistryType::Conda => build_conda_index(storage).await,
+                RegistryType::Composer => {
+                    let (p, s) = crate::registry::composer::INDEX_PATTERN;
+                    build_two_level_index(storage, p, s, "/").await
+                }
+                RegistryType::Swift => {
+                    let (p, s) = crate::registry::swift::INDEX_PATTERN;
+                    build_two_level_index(storage, p, s, ".").await
+                }
             };
             match data {
                 Some(data) => {
                     info!(registry = registry, count = data.len(), "Index rebuilt");
                     index.set(data);
+                    return true;
                 }
                 None => {
                     // Storage list failed mid-rebuild. Leave the index dirty so the
@@ -215,11 +324,75 @@ impl RepoIndex {
                         registry = registry,
                         "index rebuild skipped: storage list failed; serving stale index"
                     );
+                    return false;
                 }
             }
         }
+        true
+    }
 
-        index.get_cached()
+    /// Docker catalog, rebuilding the docker index first if it is dirty or
+    /// older than `max_age` — writes by another replica or an offline
+    /// `nora gc`/restore/import never invalidate this process's index. `None`
+    /// when that rebuild fails: a stale catalog would list deleted tags and
+    /// miss pushed ones, so the registry API answers 503 instead.
+    pub async fn docker_catalog(
+        &self,
+        storage: &Storage,
+        max_age: Duration,
+    ) -> Option<Arc<DockerCatalog>> {
+        let index = self.indexes.get(&RegistryType::Docker)?;
+        let expired = self
+            .docker_catalog
+            .read()
+            .built
+            .is_none_or(|built| built.elapsed() >= max_age);
+        if expired {
+            index.invalidate();
+        }
+        self.rebuild_if_dirty(RegistryType::Docker, index, storage)
+            .await
+            .then(|| Arc::clone(&self.docker_catalog.read()))
+    }
+
+    /// npm search index, rebuilding it first (with `parse`) if it is dirty. A
+    /// failed rebuild leaves it dirty and serves the stale index, as
+    /// [`Self::get`] does.
+    pub(crate) async fn npm_search(
+        &self,
+        storage: &Storage,
+        parse: SearchDocParser,
+    ) -> Arc<BTreeMap<String, SearchDoc>> {
+        let index = &self.npm_search;
+        if !index.dirty.load(Ordering::Acquire) {
+            return Arc::clone(&index.docs.read());
+        }
+        let _guard = index.rebuild_lock.lock().await;
+        if index.dirty.load(Ordering::Acquire) {
+            match build_npm_search(storage, parse).await {
+                Some(docs) => {
+                    info!(count = docs.len(), "npm search index rebuilt");
+                    *index.docs.write() = Arc::new(docs);
+                    index.dirty.store(false, Ordering::Release);
+                }
+                None => tracing::warn!(
+                    "npm search index rebuild skipped: storage list failed; serving stale index"
+                ),
+            }
+        }
+        Arc::clone(&index.docs.read())
+    }
+
+    /// Add or replace one packument in the npm search index, once it is stored.
+    /// Waits out a running rebuild so that rebuild cannot drop the update.
+    pub(crate) async fn upsert_npm_search(&self, doc: SearchDoc) {
+        let index = &self.npm_search;
+        let _guard = index.rebuild_lock.lock().await;
+        // A dirty index picks the packument up from storage on its rebuild.
+        if !index.dirty.load(Ordering::Acquire) {
+            let mut docs = index.docs.write();
+            Arc::make_mut(&mut *docs).insert(doc.name.clone(), doc);
+        }
     }
 
     /// Get counts for stats (no rebuild, just current state)
@@ -262,9 +435,14 @@ async fn list_keys(storage: &Storage, prefix: &str) -> Option<Vec<(String, FileM
     }
 }
 
-async fn build_docker_index(storage: &Storage) -> Option<Vec<RepoInfo>> {
+async fn build_docker_index(storage: &Storage) -> Option<(Vec<RepoInfo>, DockerCatalog)> {
+    let started = Instant::now();
     let keys = list_keys(storage, "docker/").await?;
     let mut repos: HashMap<String, (usize, u64, u64)> = HashMap::new();
+    let mut catalog = DockerCatalog {
+        built: Some(started),
+        ..DockerCatalog::default()
+    };
 
     for (key, meta) in &keys {
         if ends_with_ci(key, ".meta.json") {
@@ -286,6 +464,7 @@ async fn build_docker_index(storage: &Storage) -> Option<Vec<RepoInfo>> {
             }
             let raw_name = parts[..boundary].join("/");
             let name = crate::registry::docker::strip_docker_namespace(&raw_name).to_string();
+            let tags = catalog.tags.entry(raw_name.clone()).or_default();
             let entry = repos.entry(name).or_insert((0, 0, 0));
 
             // Size = ACTUAL on-disk bytes of every file in the repo (blobs +
@@ -307,13 +486,46 @@ async fn build_docker_index(storage: &Storage) -> Option<Vec<RepoInfo>> {
                     let reference = reference.trim_end_matches(".json");
                     if !reference.starts_with("sha256:") {
                         entry.0 += 1;
+                        tags.push(reference.to_string());
                     }
                 }
             }
         }
     }
 
-    Some(to_sorted_vec(repos))
+    for tags in catalog.tags.values_mut() {
+        tags.sort();
+        tags.dedup();
+    }
+    Some((to_sorted_vec(repos), catalog))
+}
+
+/// Every `npm/{name}/metadata.json` packument, parsed for search.
+async fn build_npm_search(
+    storage: &Storage,
+    parse: SearchDocParser,
+) -> Option<BTreeMap<String, SearchDoc>> {
+    let keys = list_keys(storage, "npm/").await?;
+    let mut docs = BTreeMap::new();
+    for (key, meta) in &keys {
+        let Some(name) = key
+            .strip_prefix("npm/")
+            .and_then(|k| k.strip_suffix("/metadata.json"))
+        else {
+            continue;
+        };
+        // Tarball directories and the `-/` endpoints are not packages.
+        if name.is_empty() || name.starts_with('-') || name.contains("/-/") {
+            continue;
+        }
+        let Ok(data) = storage.get(key).await else {
+            continue;
+        };
+        if let Some(doc) = parse(&data, meta.modified) {
+            docs.insert(doc.name.clone(), doc);
+        }
+    }
+    Some(docs)
 }
 
 async fn build_maven_index(storage: &Storage) -> Option<Vec<RepoInfo>> {
@@ -416,9 +628,13 @@ async fn build_pypi_index(storage: &Storage) -> Option<Vec<RepoInfo>> {
                 let name = parts[0].to_string();
                 let entry = packages.entry(name).or_insert((0, 0, 0));
                 // Count only real distribution files — a checksum sidecar
-                // (`<file>.sha256`) is not a separate artifact (#588). Its bytes
-                // still count toward size so the total matches on-disk du.
-                if !crate::gc::is_checksum_sidecar(key) {
+                // (`<file>.sha256`), a wheel's `.metadata` or yanked.json is not
+                // a separate artifact (#588). Their bytes still count toward
+                // size so the total matches on-disk du.
+                if !crate::gc::is_checksum_sidecar(key)
+                    && !ends_with_ci(key, ".metadata")
+                    && !ends_with_ci(key, "/yanked.json")
+                {
                     entry.0 += 1;
                 }
 
@@ -560,6 +776,98 @@ async fn build_gems_index(storage: &Storage) -> Option<Vec<RepoInfo>> {
     Some(to_sorted_vec(packages))
 }
 
+/// Helm index: chart archives `{name}-{version}.tgz`, hosted under
+/// `helm/charts/` and cached per proxied repo under `helm/{repo}/charts/`.
+/// Grouped by chart name across repos.
+async fn build_helm_index(storage: &Storage) -> Option<Vec<RepoInfo>> {
+    let keys = list_keys(storage, "helm/").await?;
+    let mut packages: HashMap<String, (usize, u64, u64)> = HashMap::new();
+
+    for (key, meta) in &keys {
+        let file = key.rsplit('/').next().unwrap_or(key);
+        let Some((name, _)) = crate::registry::helm::split_chart_filename(file) else {
+            continue;
+        };
+        let entry = packages.entry(name.to_string()).or_insert((0, 0, 0));
+        entry.0 += 1;
+        entry.1 += meta.size;
+        if meta.modified > entry.2 {
+            entry.2 = meta.modified;
+        }
+    }
+
+    Some(to_sorted_vec(packages))
+}
+
+/// Conda index: packages under `conda/{channel}/{subdir}/`, hosted or cached
+/// from a proxied channel. Grouped by channel, like the rpm/deb/apk repos.
+async fn build_conda_index(storage: &Storage) -> Option<Vec<RepoInfo>> {
+    let keys = list_keys(storage, "conda/").await?;
+    let mut packages: HashMap<String, (usize, u64, u64)> = HashMap::new();
+
+    for (key, meta) in &keys {
+        let Some(rest) = key.strip_prefix("conda/") else {
+            continue;
+        };
+        let mut parts = rest.split('/');
+        let (Some(channel), Some(subdir), Some(file), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        if subdir.starts_with('.') || !crate::registry::conda::is_package_file(file) {
+            continue;
+        }
+        let entry = packages.entry(channel.to_string()).or_insert((0, 0, 0));
+        entry.0 += 1;
+        entry.1 += meta.size;
+        if meta.modified > entry.2 {
+            entry.2 = meta.modified;
+        }
+    }
+
+    Some(to_sorted_vec(packages))
+}
+
+/// Two-level index: keys like {prefix}{a}/{b}/{file}{suffix}, grouped by
+/// `{a}{sep}{b}` — Composer's `vendor/package` (hosted and proxied dists share
+/// `composer/dists/`) and Swift's `scope.name` (`swift/{scope}/{name}/{version}.zip`).
+/// Deeper keys (Swift's extracted manifests, sidecar dirs) are skipped.
+async fn build_two_level_index(
+    storage: &Storage,
+    prefix: &str,
+    suffix: &str,
+    sep: &str,
+) -> Option<Vec<RepoInfo>> {
+    let keys = list_keys(storage, prefix).await?;
+    let mut packages: HashMap<String, (usize, u64, u64)> = HashMap::new();
+
+    for (key, meta) in &keys {
+        let Some(rest) = key.strip_prefix(prefix) else {
+            continue;
+        };
+        let mut parts = rest.split('/');
+        let (Some(first), Some(second), Some(file), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        if !file.ends_with(suffix) || first.starts_with('.') {
+            continue;
+        }
+        let entry = packages
+            .entry(format!("{}{}{}", first, sep, second))
+            .or_insert((0, 0, 0));
+        entry.0 += 1;
+        entry.1 += meta.size;
+        if meta.modified > entry.2 {
+            entry.2 = meta.modified;
+        }
+    }
+
+    Some(to_sorted_vec(packages))
+}
+
 /// Conan index: keys like conan/{name}/{ver}/{user}/{chan}/revisions/{rev}/files/{file}
 async fn build_conan_index(storage: &Storage) -> Option<Vec<RepoInfo>> {
     let keys = list_keys(storage, "conan/").await?;
@@ -928,6 +1236,76 @@ mod tests {
         assert_eq!(size, 200 + 30, "metadata bytes still count toward size==du");
     }
 
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    #[tokio::test]
+    async fn docker_catalog_built_with_index() {
+        let (_d, s) = temp_storage();
+        for key in [
+            "docker/docker.io/library/nginx/manifests/stable.json",
+            "docker/library/nginx/manifests/latest.json",
+            "docker/library/nginx/manifests/sha256:abc.json",
+            "docker/library/nginx/manifests/latest.meta.json",
+            "docker/blobonly/blobs/sha256:lyr",
+        ] {
+            s.put(key, b"{}").await.unwrap();
+        }
+
+        let idx = RepoIndex::new();
+        let catalog = idx.docker_catalog(&s, HOUR).await.unwrap();
+        // Namespaced and bare copies are one repository.
+        assert_eq!(
+            catalog.repositories(&crate::config::DockerConfig::default()),
+            ["blobonly", "library/nginx"]
+        );
+        // Tags stay per storage path; digests and sidecars are not tags.
+        assert_eq!(catalog.tags["library/nginx"], vec!["latest"]);
+        assert_eq!(catalog.tags["docker.io/library/nginx"], vec!["stable"]);
+        assert!(catalog.tags["blobonly"].is_empty());
+
+        s.put("docker/library/nginx/manifests/edge.json", b"{}")
+            .await
+            .unwrap();
+        idx.invalidate("docker");
+        let catalog = idx.docker_catalog(&s, HOUR).await.unwrap();
+        assert_eq!(catalog.tags["library/nginx"], vec!["edge", "latest"]);
+
+        // A write this index never heard of (another replica, offline gc) shows
+        // up once the catalog is older than `max_age`.
+        s.delete("docker/library/nginx/manifests/edge.json")
+            .await
+            .unwrap();
+        let catalog = idx.docker_catalog(&s, HOUR).await.unwrap();
+        assert_eq!(catalog.tags["library/nginx"], vec!["edge", "latest"]);
+        let catalog = idx.docker_catalog(&s, Duration::ZERO).await.unwrap();
+        assert_eq!(catalog.tags["library/nginx"], vec!["latest"]);
+    }
+
+    #[tokio::test]
+    async fn docker_catalog_none_when_storage_list_fails() {
+        use wiremock::matchers::any;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let s3 = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&s3)
+            .await;
+        let s = crate::Storage::new_s3(
+            &s3.uri(),
+            "nora",
+            "us-east-1",
+            Some("key"),
+            Some("secret"),
+            false,
+        );
+
+        let idx = RepoIndex::new();
+        assert!(idx.docker_catalog(&s, HOUR).await.is_none());
+        // The UI index still serves what it has.
+        assert!(idx.get("docker", &s).await.is_empty());
+    }
+
     #[tokio::test]
     async fn docker_index_real_size_not_virtual_and_single_count() {
         let (_d, s) = temp_storage();
@@ -960,7 +1338,7 @@ mod tests {
             .await
             .unwrap();
 
-        let repos = build_docker_index(&s).await.expect("index built");
+        let (repos, _) = build_docker_index(&s).await.expect("index built");
         assert_eq!(repos.len(), 1);
         // Count = 1 tag, NOT 2 (the digest manifest is not a separate image).
         assert_eq!(repos[0].versions, 1, "tag + digest manifest double-counted");
@@ -1076,7 +1454,7 @@ mod tests {
             stat_calls: Arc::clone(&stat_calls),
         }));
 
-        let repos = build_docker_index(&storage).await.expect("index built");
+        let (repos, _) = build_docker_index(&storage).await.expect("index built");
 
         // After the fix: the rebuild made ZERO per-key stat() calls.
         assert_eq!(
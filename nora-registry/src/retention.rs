@@ -594,11 +594,15 @@ async fn collect_pypi_versions(storage: &Storage) -> Vec<(String, Vec<VersionEnt
         if let Some(rest) = key.strip_prefix("pypi/") {
             // Skip checksums and metadata.json — metadata is the package index,
             // not a version artifact. Deleting it makes the package undiscoverable.
+            // A wheel's PEP 658 `.metadata` goes with the wheel; yanked.json is
+            // project state.
             if !ends_with_ci(key, ".sha256")
                 && !ends_with_ci(key, ".sha1")
                 && !ends_with_ci(key, ".md5")
                 && !ends_with_ci(key, ".sha512")
                 && !ends_with_ci(key, "/metadata.json")
+                && !ends_with_ci(key, ".metadata")
+                && !ends_with_ci(key, "/yanked.json")
             {
                 let pkg = rest.split('/').next().unwrap_or("");
                 if !pkg.is_empty() {
@@ -618,9 +622,11 @@ async fn collect_pypi_versions(storage: &Storage) -> Vec<(String, Vec<VersionEnt
             let filename = key.rsplit('/').next().unwrap_or("");
             let (modified, size) = aggregate_meta(storage, std::slice::from_ref(key)).await;
             let mut keys = vec![key.clone()];
-            let hash_key = format!("{}.sha256", key);
-            if storage.stat(&hash_key).await.is_some() {
-                keys.push(hash_key);
+            for sidecar in [".sha256", ".metadata", ".metadata.sha256"] {
+                let sidecar_key = format!("{}{}", key, sidecar);
+                if storage.stat(&sidecar_key).await.is_some() {
+                    keys.push(sidecar_key);
+                }
             }
             entries.push(VersionEntry {
                 name: filename.to_string(),
@@ -751,6 +757,25 @@ async fn aggregate_meta(storage: &Storage, keys: &[String]) -> (u64, u64) {
 // ============================================================================
 
 /// Result of a retention run.
+/// Collect apk package versions per repository — same sidecar contract as
+/// [`collect_rpm_versions`]. Each `{branch}/{component}/{arch}` directory has
+/// its own APKINDEX, so groups are `apk:{repo}/{branch}/{component}/{arch}/{package}`.
+async fn collect_apk_versions(storage: &Storage) -> Vec<(String, Vec<VersionEntry>)> {
+    collect_sidecar_versions(storage, "apk", |v| {
+        let s = |f: &str| v.get(f).and_then(|x| x.as_str()).unwrap_or("").to_string();
+        SidecarVersion {
+            package: s("name"),
+            version: format!("{}_{}", s("version"), s("arch")),
+            arch: s("index_arch"),
+            href: s("href"),
+            size: v.get("size").and_then(|x| x.as_u64()).unwrap_or(0),
+            modified: v.get("upload_time").and_then(|x| x.as_u64()),
+            placement: Some(format!("{}/{}", s("branch"), s("component"))),
+        }
+    })
+    .await
+}
+
 pub struct RetentionResult {
     pub planned: usize,
     pub deleted_keys: usize,
@@ -768,6 +793,7 @@ pub async fn run_retention(
     storage: &Storage,
     publish_locks: &PublishLocks,
     signer: Option<&crate::signing::RepoSigner>,
+    apk_signer: Option<&crate::signing::ApkSigner>,
     rules: &[RetentionRule],
     dry_run: bool,
 ) -> RetentionResult {
@@ -787,13 +813,14 @@ pub async fn run_retention(
     all_groups.extend(collect_go_versions(storage).await);
     all_groups.extend(collect_rpm_versions(storage).await);
     all_groups.extend(collect_deb_versions(storage).await);
+    all_groups.extend(collect_apk_versions(storage).await);
     all_groups.extend(collect_raw_versions(storage).await);
 
     let mut all_plans: Vec<(String, Vec<DeletionPlan>)> = Vec::new();
     let mut total_planned = 0usize;
     let mut total_deleted_keys = 0usize;
     let mut total_bytes = 0u64;
-    // rpm/deb repos whose packages were deleted — their indexes must be
+    // rpm/deb/apk repos whose packages were deleted — their indexes must be
     // rebuilt (and re-signed) afterwards or they keep advertising ghosts.
     let mut regen: std::collections::BTreeSet<(&'static str, String)> =
         std::collections::BTreeSet::new();
@@ -814,20 +841,11 @@ pub async fn run_retention(
         total_planned += plans.len();
 
         if !dry_run {
-            if let Some(repo) = group_name
-                .strip_prefix("rpm:")
-                .map(|n| ("rpm", n))
-                .or_else(|| group_name.strip_prefix("deb:").map(|n| ("deb", n)))
-                .and_then(|(fmt, n)| n.split('/').next().map(|r| (fmt, r.to_string())))
-            {
-                regen.insert((
-                    if group_name.starts_with("rpm:") {
-                        "rpm"
-                    } else {
-                        "deb"
-                    },
-                    repo.1,
-                ));
+            if let Some((fmt, repo)) = ["rpm", "deb", "apk"].into_iter().find_map(|fmt| {
+                let rest = group_name.strip_prefix(fmt)?.strip_prefix(':')?;
+                rest.split('/').next().map(|r| (fmt, r.to_string()))
+            }) {
+                regen.insert((fmt, repo));
             }
             for plan in &plans {
                 for key in &plan.keys {
@@ -863,19 +881,21 @@ pub async fn run_retention(
         all_plans.push((group_name, plans));
     }
 
-    // Rebuild + re-sign the indexes of every rpm/deb repo retention touched,
+    // Rebuild + re-sign the indexes of every rpm/deb/apk repo retention touched,
     // under the same per-repo publish lock the handlers use. Fail-open per
     // repo: a failed rebuild logs loudly and the next publish/reindex heals
     // it; the deletions themselves are already durable.
     for (fmt, repo) in &regen {
         let lock_key = match *fmt {
             "rpm" => format!("rpm/{repo}/repodata/repomd.xml"),
+            "apk" => format!("apk/{repo}/APKINDEX"),
             _ => format!("deb/{repo}/Release"),
         };
         let lock = crate::acquire_publish_lock(publish_locks, &lock_key);
         let _guard = lock.lock().await;
         let result = match *fmt {
             "rpm" => crate::registry::rpm::regenerate_repodata(storage, signer, repo).await,
+            "apk" => crate::registry::apk::regenerate_repo(storage, apk_signer, repo).await,
             _ => crate::registry::deb::regenerate_indexes(storage, signer, repo).await,
         };
         if let Err(e) = result {
@@ -1162,7 +1182,8 @@ mod tests {
             exclude_tags: vec![],
         }];
 
-        let result = run_retention(&storage, &test_publish_locks(), None, &rules, false).await;
+        let result =
+            run_retention(&storage, &test_publish_locks(), None, None, &rules, false).await;
         assert_eq!(result.planned, 2); // 1.0 and 2.0 deleted, 3.0 kept
         assert!(storage
             .get("maven/com/example/lib/3.0/lib-3.0.jar")
@@ -1192,7 +1213,7 @@ mod tests {
             exclude_tags: vec![],
         }];
 
-        let result = run_retention(&storage, &test_publish_locks(), None, &rules, true).await;
+        let result = run_retention(&storage, &test_publish_locks(), None, None, &rules, true).await;
         assert_eq!(result.planned, 1);
         assert_eq!(result.deleted_keys, 0); // dry run
                                             // Both still exist
@@ -1219,7 +1240,8 @@ mod tests {
             exclude_tags: vec![],
         }];
 
-        let result = run_retention(&storage, &test_publish_locks(), None, &rules, false).await;
+        let result =
+            run_retention(&storage, &test_publish_locks(), None, None, &rules, false).await;
         assert_eq!(result.planned, 0);
     }
 
@@ -1245,7 +1267,8 @@ mod tests {
             exclude_tags: vec![],
         }];
 
-        let result = run_retention(&storage, &test_publish_locks(), None, &rules, false).await;
+        let result =
+            run_retention(&storage, &test_publish_locks(), None, None, &rules, false).await;
         assert!(result.planned >= 1); // at least 1.0 deleted
     }
 
@@ -1284,7 +1307,8 @@ mod tests {
             exclude_tags: vec![],
         }];
 
-        let result = run_retention(&storage, &test_publish_locks(), None, &rules, false).await;
+        let result =
+            run_retention(&storage, &test_publish_locks(), None, None, &rules, false).await;
         assert_eq!(result.planned, 2); // v1.0.0 and v2.0.0 deleted
         assert_eq!(result.deleted_keys, 6); // 3 files per version * 2
                                             // v3.0.0 kept (newest by name tiebreaker)
@@ -1381,6 +1405,7 @@ mod format_retention_tests {
             &ctx.state.storage,
             &ctx.state.publish_locks,
             ctx.state.signer.as_deref(),
+            ctx.state.apk_signer.as_deref(),
             &rules,
             false,
         )
@@ -1458,6 +1483,7 @@ mod format_retention_tests {
             &ctx.state.storage,
             &ctx.state.publish_locks,
             ctx.state.signer.as_deref(),
+            ctx.state.apk_signer.as_deref(),
             &rules,
             true,
         )
@@ -1474,6 +1500,7 @@ mod format_retention_tests {
             &ctx.state.storage,
             &ctx.state.publish_locks,
             ctx.state.signer.as_deref(),
+            ctx.state.apk_signer.as_deref(),
             &rules,
             false,
         )
@@ -1505,6 +1532,7 @@ mod format_retention_tests {
             &ctx.state.storage,
             &ctx.state.publish_locks,
             ctx.state.signer.as_deref(),
+            ctx.state.apk_signer.as_deref(),
             &rules,
             false,
         )
@@ -1545,6 +1573,7 @@ mod format_retention_tests {
             &ctx.state.storage,
             &ctx.state.publish_locks,
             ctx.state.signer.as_deref(),
+            ctx.state.apk_signer.as_deref(),
             &rules,
             false,
         )
@@ -1594,6 +1623,7 @@ mod format_retention_tests {
             &ctx.state.storage,
             &ctx.state.publish_locks,
             ctx.state.signer.as_deref(),
+            ctx.state.apk_signer.as_deref(),
             &rules,
             false,
         )
@@ -1631,6 +1661,7 @@ mod format_retention_tests {
             &ctx.state.storage,
             &ctx.state.publish_locks,
             ctx.state.signer.as_deref(),
+            ctx.state.apk_signer.as_deref(),
             &rules,
             false,
         )
@@ -1672,6 +1703,65 @@ mod format_retention_tests {
         assert!(focal.contains("Version: 2.0\n"), "{focal}");
     }
 
+    /// apk keep_last is scoped per index directory: the v3.20 directory drops
+    /// its older versions while edge keeps its only one, and the touched
+    /// APKINDEX is rebuilt without the evicted packages.
+    #[tokio::test]
+    async fn test_apk_retention_per_directory_and_regenerates() {
+        let ctx = create_test_context();
+        for (v, branch) in [("1.0-r0", "v3.20"), ("2.0-r0", "v3.20"), ("3.0-r0", "edge")] {
+            let (apk, _) = crate::registry::apk::tests::build_test_apk("hello", v, "x86_64");
+            let r = send(
+                &ctx.app,
+                Method::PUT,
+                &format!("/apk/myrepo/{branch}/main/x86_64/hello-{v}.apk"),
+                apk,
+            )
+            .await;
+            assert_eq!(r.status(), StatusCode::CREATED);
+        }
+
+        let rules = vec![rule("apk", Some("myrepo/*"), Some(1), None)];
+        let result = run_retention(
+            &ctx.state.storage,
+            &ctx.state.publish_locks,
+            ctx.state.signer.as_deref(),
+            ctx.state.apk_signer.as_deref(),
+            &rules,
+            false,
+        )
+        .await;
+        assert_eq!(result.planned, 1, "only v3.20 exceeds keep_last");
+
+        let index = |branch: &'static str| {
+            let storage = ctx.state.storage.clone();
+            async move {
+                let gz = storage
+                    .get(&format!("apk/myrepo/{branch}/main/x86_64/APKINDEX.tar.gz"))
+                    .await
+                    .unwrap();
+                let mut tar = Vec::new();
+                std::io::Read::read_to_end(
+                    &mut flate2::read::MultiGzDecoder::new(&gz[..]),
+                    &mut tar,
+                )
+                .unwrap();
+                String::from_utf8_lossy(&tar).into_owned()
+            }
+        };
+        let stable = index("v3.20").await;
+        assert!(stable.contains("V:2.0-r0\n"), "{stable}");
+        assert!(!stable.contains("V:1.0-r0\n"), "{stable}");
+        assert!(stable.contains(".SIGN.RSA256."), "index re-signed");
+        assert!(index("edge").await.contains("V:3.0-r0\n"));
+        assert!(ctx
+            .state
+            .storage
+            .stat("apk/myrepo/v3.20/main/x86_64/hello-1.0-r0.apk")
+            .await
+            .is_none());
+    }
+
     /// Raw: a depth-2 prefix is the version unit — the whole CALVER-style
     /// directory ages out together; root-level files are never collected.
     #[tokio::test]
@@ -1699,6 +1789,7 @@ mod format_retention_tests {
             &ctx.state.storage,
             &ctx.state.publish_locks,
             None,
+            None,
             &rules,
             false,
         )
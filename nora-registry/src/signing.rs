@@ -3,10 +3,11 @@
 
 //! Repository index signing (#128).
 //!
-//! Holds one OpenPGP signing key per NORA instance and produces the three
+//! Holds one OpenPGP signing key per NORA instance and produces the
 //! signature shapes package managers verify: a clearsigned document (APT
 //! `InRelease`), a detached armored signature (APT `Release.gpg`, yum
-//! `repomd.xml.asc`), and the armored public key clients import.
+//! `repomd.xml.asc`), a detached binary signature (Terraform
+//! `SHA256SUMS.sig`), and the armored public key clients import.
 //!
 //! The key is a v4 Ed25519 ("Ed25519Legacy") primary with no passphrase —
 //! the variant every deployed verifier understands (gpgv on apt's side,
@@ -22,6 +23,11 @@
 //! Secret), or share a ReadWriteMany volume. Replicas that each generate
 //! their own key serve clients a public key that fails to verify indexes
 //! signed by their siblings.
+//!
+//! apk does not speak OpenPGP: `APKINDEX.tar.gz` carries a raw RSA
+//! PKCS#1 v1.5 signature, checked against `/etc/apk/keys/{name}`. That key
+//! is a separate [`ApkSigner`] with the same lifecycle and the same
+//! single-writer caveat (`signing.apk_key_path`).
 
 use pgp::composed::{
     ArmorOptions, CleartextSignedMessage, Deserializable, DetachedSignature, KeyType,
@@ -29,6 +35,9 @@ use pgp::composed::{
 };
 use pgp::crypto::hash::HashAlgorithm;
 use pgp::types::{KeyDetails, Password};
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::{DecodePrivateKey, EncodePrivateKey, EncodePublicKey, LineEnding};
+use sha2::Digest;
 use std::io::Cursor;
 use std::path::Path;
 use zeroize::Zeroizing;
@@ -60,7 +69,11 @@ impl RepoSigner {
             }
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
                 let key = generate_key()?;
-                match persist_key(&key, path)? {
+                let armored = Zeroizing::new(
+                    key.to_armored_string(ArmorOptions::default())
+                        .map_err(|e| format!("armor signing key: {e}"))?,
+                );
+                match persist_secret(&armored, path)? {
                     Persisted::Written => {
                         tracing::info!(path = %path.display(), "generated new repository signing key");
                         generated = true;
@@ -125,6 +138,21 @@ impl RepoSigner {
         .map_err(|e| format!("detached sign: {e}"))
     }
 
+    /// Detached binary (unarmored) signature over `data` — Terraform reads
+    /// `SHA256SUMS.sig` as raw OpenPGP packets.
+    pub fn sign_detached_binary(&self, data: &[u8]) -> Result<Vec<u8>, String> {
+        use pgp::ser::Serialize;
+        DetachedSignature::sign_binary_data(
+            rand::thread_rng(),
+            &*self.key,
+            &Password::empty(),
+            HashAlgorithm::Sha256,
+            data,
+        )
+        .and_then(|s| s.to_bytes())
+        .map_err(|e| format!("detached sign: {e}"))
+    }
+
     /// Armored public key for client import (`repomd.xml.key`, `pubkey.gpg`).
     pub fn public_key_armored(&self) -> &str {
         &self.public_armored
@@ -133,6 +161,13 @@ impl RepoSigner {
     pub fn fingerprint(&self) -> &str {
         &self.fingerprint
     }
+
+    /// Long (64-bit) key ID in uppercase hex — the low 16 digits of the v4
+    /// fingerprint, the form Terraform's `signing_keys` block carries.
+    pub fn key_id(&self) -> String {
+        let start = self.fingerprint.len().saturating_sub(16);
+        self.fingerprint[start..].to_ascii_uppercase()
+    }
 }
 
 fn generate_key() -> Result<SignedSecretKey, String> {
@@ -146,7 +181,107 @@ fn generate_key() -> Result<SignedSecretKey, String> {
         .map_err(|e| format!("generate signing key: {e}"))
 }
 
-/// Write the armored secret key with owner-only permissions, creating the
+/// abuild's key size (`abuild-keygen`).
+const APK_KEY_BITS: usize = 4096;
+
+/// DER `DigestInfo` prefix for SHA-256 (RFC 8017 §9.2 note 1) — what
+/// `openssl dgst -sha256 -sign` (abuild-sign) wraps the digest in.
+pub(crate) const SHA256_DIGEST_INFO: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05,
+    0x00, 0x04, 0x20,
+];
+
+/// The RSA key that signs `APKINDEX.tar.gz`.
+pub struct ApkSigner {
+    key: rsa::RsaPrivateKey,
+    public_pem: String,
+    key_name: String,
+    generated: bool,
+}
+
+impl ApkSigner {
+    /// Load the RSA key from `path` — PKCS#8 (`BEGIN PRIVATE KEY`) or
+    /// PKCS#1 (`BEGIN RSA PRIVATE KEY`), so an existing abuild key can be
+    /// mounted as-is — or generate and persist one on first boot. Same
+    /// fail-closed contract as [`RepoSigner::load_or_generate`].
+    ///
+    /// `key_name` is the file name clients install the public key under
+    /// (`/etc/apk/keys/{key_name}`); apk matches it against the name
+    /// embedded in each index signature.
+    pub fn load_or_generate(path: &Path, key_name: &str) -> Result<Self, String> {
+        let parse = |pem: &str| {
+            if pem.contains("BEGIN RSA PRIVATE KEY") {
+                rsa::RsaPrivateKey::from_pkcs1_pem(pem).map_err(|e| e.to_string())
+            } else {
+                rsa::RsaPrivateKey::from_pkcs8_pem(pem).map_err(|e| e.to_string())
+            }
+            .map_err(|e| format!("parse apk signing key {}: {e}", path.display()))
+        };
+        let mut generated = false;
+        let key = match std::fs::read_to_string(path).map(Zeroizing::new) {
+            Ok(pem) => parse(&pem)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let key = rsa::RsaPrivateKey::new(&mut rand::thread_rng(), APK_KEY_BITS)
+                    .map_err(|e| format!("generate apk signing key: {e}"))?;
+                let pem = key
+                    .to_pkcs8_pem(LineEnding::LF)
+                    .map_err(|e| format!("encode apk signing key: {e}"))?;
+                match persist_secret(&pem, path)? {
+                    Persisted::Written => {
+                        tracing::info!(path = %path.display(), "generated new apk signing key");
+                        generated = true;
+                        key
+                    }
+                    Persisted::LostRace => {
+                        let pem = Zeroizing::new(std::fs::read_to_string(path).map_err(|e| {
+                            format!("re-read apk signing key {}: {e}", path.display())
+                        })?);
+                        parse(&pem)?
+                    }
+                }
+            }
+            Err(e) => return Err(format!("read apk signing key {}: {e}", path.display())),
+        };
+        let public_pem = key
+            .to_public_key()
+            .to_public_key_pem(LineEnding::LF)
+            .map_err(|e| format!("encode apk public key: {e}"))?;
+        Ok(Self {
+            key,
+            public_pem,
+            key_name: key_name.to_string(),
+            generated,
+        })
+    }
+
+    /// True when the key was generated (not loaded) on this boot.
+    pub fn was_generated(&self) -> bool {
+        self.generated
+    }
+
+    /// RSA PKCS#1 v1.5 signature over the SHA-256 of `data` — the payload
+    /// of an index's `.SIGN.RSA256.{key_name}` entry.
+    pub fn sign_sha256(&self, data: &[u8]) -> Result<Vec<u8>, String> {
+        let scheme = rsa::Pkcs1v15Sign {
+            hash_len: Some(32),
+            prefix: Box::new(SHA256_DIGEST_INFO),
+        };
+        self.key
+            .sign(scheme, &sha2::Sha256::digest(data))
+            .map_err(|e| format!("apk sign: {e}"))
+    }
+
+    /// PEM (`BEGIN PUBLIC KEY`) public key, the `/etc/apk/keys` format.
+    pub fn public_key_pem(&self) -> &str {
+        &self.public_pem
+    }
+
+    pub fn key_name(&self) -> &str {
+        &self.key_name
+    }
+}
+
+/// Write a secret key with owner-only permissions, creating the
 /// parent directory. Staged to a per-process temp file, then claimed with a
 /// create-exclusive link: exactly one of N concurrent first boots wins;
 /// losers get [`Persisted::LostRace`] and must adopt the winner's key. A
@@ -157,11 +292,7 @@ enum Persisted {
     LostRace,
 }
 
-fn persist_key(key: &SignedSecretKey, path: &Path) -> Result<Persisted, String> {
-    let armored = Zeroizing::new(
-        key.to_armored_string(ArmorOptions::default())
-            .map_err(|e| format!("armor signing key: {e}"))?,
-    );
+fn persist_secret(secret: &str, path: &Path) -> Result<Persisted, String> {
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent).map_err(|e| format!("create {}: {e}", parent.display()))?;
     }
@@ -174,8 +305,7 @@ fn persist_key(key: &SignedSecretKey, path: &Path) -> Result<Persisted, String>
         std::process::id(),
         STAGE_SEQ.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
     ));
-    std::fs::write(&tmp, armored.as_bytes())
-        .map_err(|e| format!("write {}: {e}", tmp.display()))?;
+    std::fs::write(&tmp, secret.as_bytes()).map_err(|e| format!("write {}: {e}", tmp.display()))?;
     #[cfg(unix)]
     {
         use std::os::unix::fs::PermissionsExt;
@@ -293,6 +423,72 @@ mod tests {
         let public = signer.public_key_armored();
         assert!(public.starts_with("-----BEGIN PGP PUBLIC KEY BLOCK-----"));
     }
+
+    #[test]
+    fn binary_detached_signature_verifies() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let signer = RepoSigner::load_or_generate(&dir.path().join("k.key")).unwrap();
+
+        let data = b"abc123  terraform-provider-x_1.0.0_linux_amd64.zip\n";
+        let raw = signer.sign_detached_binary(data).unwrap();
+        assert!(!raw.starts_with(b"-----BEGIN"));
+        let sig = DetachedSignature::from_bytes(&raw[..]).unwrap();
+        sig.verify(&signer.key.to_public_key(), data).unwrap();
+
+        let key_id = signer.key_id();
+        assert_eq!(key_id.len(), 16);
+        assert!(signer.fingerprint().to_ascii_uppercase().ends_with(&key_id));
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod apk_tests {
+    use super::*;
+    use rsa::pkcs8::DecodePublicKey;
+
+    fn fixture(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata/apk")
+            .join(name)
+    }
+
+    #[test]
+    fn loads_pkcs8_and_pkcs1_keys_and_signs() {
+        let pkcs8 =
+            ApkSigner::load_or_generate(&fixture("test-signing.rsa"), "nora.rsa.pub").unwrap();
+        let pkcs1 = ApkSigner::load_or_generate(&fixture("test-signing-pkcs1.rsa"), "nora.rsa.pub")
+            .unwrap();
+        assert!(!pkcs8.was_generated());
+        assert_eq!(pkcs8.public_key_pem(), pkcs1.public_key_pem());
+        assert!(pkcs8
+            .public_key_pem()
+            .starts_with("-----BEGIN PUBLIC KEY-----"));
+        assert_eq!(pkcs8.key_name(), "nora.rsa.pub");
+
+        let data = b"APKINDEX gzip stream";
+        let sig = pkcs8.sign_sha256(data).unwrap();
+        let public = rsa::RsaPublicKey::from_public_key_pem(pkcs8.public_key_pem()).unwrap();
+        let scheme = rsa::Pkcs1v15Sign {
+            hash_len: Some(32),
+            prefix: Box::new(SHA256_DIGEST_INFO),
+        };
+        public
+            .verify(scheme, &sha2::Sha256::digest(data), &sig)
+            .unwrap();
+    }
+
+    #[test]
+    fn corrupt_apk_key_fails_closed() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("apk.rsa");
+        std::fs::write(&path, "not a key").unwrap();
+        let err = ApkSigner::load_or_generate(&path, "k.rsa.pub")
+            .err()
+            .unwrap();
+        assert!(err.contains("parse apk signing key"), "{err}");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "not a key");
+    }
 }
 
 #[cfg(test)]
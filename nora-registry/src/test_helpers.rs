@@ -55,6 +55,14 @@ pub fn create_test_context_with_auth(users: &[(&str, &str)]) -> TestContext {
     build_context(true, users, false, |_| {})
 }
 
+/// Build a test context with auth **enabled** plus custom config tweaks.
+pub fn create_test_context_with_auth_and_config(
+    users: &[(&str, &str)],
+    customize: impl FnOnce(&mut Config),
+) -> TestContext {
+    build_context(true, users, false, customize)
+}
+
 /// Build a test context with auth + anonymous_read.
 pub fn create_test_context_with_anonymous_read(users: &[(&str, &str)]) -> TestContext {
     build_context(true, users, true, |_| {})
@@ -143,6 +151,7 @@ fn build_context(
             proxy_timeout_zip: 30,
             max_zip_size: 10_485_760,
             metadata_ttl: 300,
+            sumdbs: Default::default(),
         },
         cargo: CargoConfig {
             enabled: true,
@@ -157,6 +166,7 @@ fn build_context(
             read_timeout: 60,
             metadata_ttl: -1,
             serve_stale: true,
+            catalog_ttl: 30,
             default_action: crate::config::DefaultAction::Allow,
             upstreams: vec![],
         },
@@ -179,6 +189,26 @@ fn build_context(
             enabled: true,
             ..crate::config::DebConfig::default()
         },
+        helm: crate::config::HelmConfig {
+            enabled: true,
+            ..crate::config::HelmConfig::default()
+        },
+        apk: crate::config::ApkConfig {
+            enabled: true,
+            ..crate::config::ApkConfig::default()
+        },
+        conda: crate::config::CondaConfig {
+            enabled: true,
+            ..crate::config::CondaConfig::default()
+        },
+        composer: crate::config::ComposerConfig {
+            enabled: true,
+            ..crate::config::ComposerConfig::default()
+        },
+        swift: crate::config::SwiftConfig {
+            enabled: true,
+            ..crate::config::SwiftConfig::default()
+        },
         auth: AuthConfig {
             enabled: auth_enabled,
             anonymous_read,
@@ -242,6 +272,23 @@ fn build_context(
             curation_engine.add_filter(Box::new(filter));
         }
     }
+    let vulndb = config
+        .curation
+        .vulnerabilities
+        .enabled
+        .then(|| crate::vulndb::VulnDb::load(&config.vulndb_path()).ok())
+        .flatten()
+        .map(Arc::new);
+    if let Some(db) = &vulndb {
+        let filter = crate::curation::VulnerabilityFilter::new(
+            Arc::clone(db),
+            &config.curation.vulnerabilities,
+        );
+        curation_engine.add_filter(Box::new(filter));
+    }
+    if config.curation.licenses.enabled {
+        curation_engine.add_filter(Box::new(crate::license_filter(&config.curation)));
+    }
     if let Some(ref path) = config.curation.allowlist_path {
         if let Ok(filter) =
             crate::curation::AllowlistFilter::from_file(path, config.curation.require_integrity)
@@ -249,6 +296,11 @@ fn build_context(
             curation_engine.add_filter(Box::new(filter));
         }
     }
+    if config.curation.typosquat.enabled {
+        if let Ok(filter) = crate::typosquat_filter(&config.curation) {
+            curation_engine.add_filter(Box::new(filter));
+        }
+    }
     if !config.curation.internal_namespaces.is_empty() {
         let ns_filter =
             crate::curation::NamespaceFilter::new(config.curation.internal_namespaces.clone());
@@ -262,6 +314,9 @@ fn build_context(
     let reloadable = Arc::new(arc_swap::ArcSwap::from_pointee(crate::ReloadableConfig {
         curation_engine,
         bypass_token,
+        vulndb,
+        cosign: crate::registry::docker_cosign::CosignTrust::load(&config.docker.upstreams)
+            .expect("cosign trust material"),
     }));
 
     let leak_finders = crate::metrics::LeakFinders::new(config.upstream_hostnames());
@@ -276,6 +331,20 @@ fn build_context(
     } else {
         None
     };
+    // A fixture key: generating a 4096-bit RSA key per test context would
+    // dominate the suite's runtime.
+    let apk_signer = if config.signing.enabled {
+        Some(Arc::new(
+            crate::signing::ApkSigner::load_or_generate(
+                &std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("testdata/apk/test-signing.rsa"),
+                &config.signing.apk_key_name,
+            )
+            .expect("test apk signing key"),
+        ))
+    } else {
+        None
+    };
     let state = AppState {
         storage,
         config: Arc::new(config),
@@ -300,7 +369,10 @@ fn build_context(
         )),
         proxy_coalesce: crate::proxy_coalesce::InflightMap::new(),
         digest_store: Arc::new(crate::digest_quarantine::DigestStore::empty(&storage_path)),
+        cosign_children: Arc::new(crate::registry::docker_cosign::VerifiedChildren::new()),
         signer,
+        terraform_signer: Arc::new(tokio::sync::OnceCell::new()),
+        apk_signer,
         leak_finders,
         cancel_token: tokio_util::sync::CancellationToken::new(),
     };
@@ -355,6 +427,21 @@ fn build_context(
             crate::registry_type::RegistryType::Deb => {
                 registry_routes = registry_routes.merge(registry::deb_routes());
             }
+            crate::registry_type::RegistryType::Helm => {
+                registry_routes = registry_routes.merge(registry::helm_routes());
+            }
+            crate::registry_type::RegistryType::Apk => {
+                registry_routes = registry_routes.merge(registry::apk_routes());
+            }
+            crate::registry_type::RegistryType::Conda => {
+                registry_routes = registry_routes.merge(registry::conda_routes());
+            }
+            crate::registry_type::RegistryType::Composer => {
+                registry_routes = registry_routes.merge(registry::composer_routes());
+            }
+            crate::registry_type::RegistryType::Swift => {
+                registry_routes = registry_routes.merge(registry::swift_routes());
+            }
         }
     }
 
@@ -0,0 +1,338 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+//! Lookalike-name detection for the typosquatting curation filter.
+//!
+//! A corpus lists names worth impersonating — popular upstream packages, the
+//! organization's own — one per line (`#` starts a comment). An entry ending in
+//! `/*` or `/**` (npm `@acme/*`, Go `github.com/acme/*`) or `:*` (Maven `com.acme:*`)
+//! names a namespace, and the requested name's namespace is compared with it instead.
+//!
+//! A requested name resembles an entry when it is not a corpus name itself but
+//! - has the same skeleton — lowercased, separators (`-`, `_`, `.`) dropped and
+//!   homoglyphs folded (`0`→`o`, `1`→`l`, `rn`→`m`, Cyrillic `а`→`a`, …) — so
+//!   `@acme-corp/x` resembles `@acmecorp/x`; or
+//! - is within `max_distance` edits (insert, delete, substitute, swap adjacent
+//!   characters), so `lodahs` resembles `lodash`. Names shorter than `min_length`
+//!   skip this test: at that length one edit turns most names into other real ones.
+
+use crate::registry_type::RegistryType;
+use std::collections::HashSet;
+
+/// One corpus entry, prepared for comparison.
+#[derive(Debug, Clone)]
+struct Entry {
+    /// As written in the corpus, for reasons.
+    display: String,
+    /// Canonical name, or the namespace prefix of a namespace entry.
+    name: String,
+    /// For a namespace entry: its separator and how many separator-delimited
+    /// segments the prefix has.
+    namespace: Option<(char, usize)>,
+    skeleton: String,
+}
+
+/// How a requested name resembles a corpus entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lookalike {
+    /// The entry, as written in the corpus.
+    pub entry: String,
+    /// Edit distance, or `None` for a skeleton (homoglyph/separator) match.
+    pub distance: Option<usize>,
+}
+
+/// The names one registry's requests are compared with.
+#[derive(Debug, Clone)]
+pub struct Corpus {
+    registry: RegistryType,
+    entries: Vec<Entry>,
+    /// Canonical names of the plain (non-namespace) entries.
+    exact: HashSet<String>,
+}
+
+impl Corpus {
+    pub fn new<'a>(registry: RegistryType, names: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut corpus = Self {
+            registry,
+            entries: Vec::new(),
+            exact: HashSet::new(),
+        };
+        for display in names {
+            let display = display.trim();
+            let (prefix, namespace) = if let Some(prefix) = display
+                .strip_suffix("/**")
+                .or_else(|| display.strip_suffix("/*"))
+            {
+                (prefix, Some(('/', prefix.matches('/').count() + 1)))
+            } else if let Some(prefix) = display.strip_suffix(":*") {
+                (prefix, Some((':', 1)))
+            } else {
+                (display, None)
+            };
+            if prefix.is_empty() {
+                continue;
+            }
+            let name = canonical(registry, prefix);
+            if namespace.is_none() {
+                corpus.exact.insert(name.clone());
+            }
+            corpus.entries.push(Entry {
+                display: display.to_string(),
+                skeleton: skeleton(&name),
+                name,
+                namespace,
+            });
+        }
+        corpus
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The first entry `name` resembles; `None` when it resembles none, or is a
+    /// corpus name itself.
+    pub fn lookalike(
+        &self,
+        name: &str,
+        max_distance: usize,
+        min_length: usize,
+    ) -> Option<Lookalike> {
+        let name = canonical(self.registry, name);
+        if self.exact.contains(&name) {
+            return None;
+        }
+        let name_skeleton = skeleton(&name);
+        for entry in &self.entries {
+            let subject = match entry.namespace {
+                Some((separator, segments)) => match namespace_of(&name, separator, segments) {
+                    Some(namespace) => namespace,
+                    None => continue,
+                },
+                None => name.as_str(),
+            };
+            if subject == entry.name {
+                continue;
+            }
+            let subject_skeleton = if entry.namespace.is_some() {
+                skeleton(subject)
+            } else {
+                name_skeleton.clone()
+            };
+            if subject_skeleton == entry.skeleton {
+                return Some(Lookalike {
+                    entry: entry.display.clone(),
+                    distance: None,
+                });
+            }
+            let (a, b): (Vec<char>, Vec<char>) =
+                (subject.chars().collect(), entry.name.chars().collect());
+            if a.len().min(b.len()) < min_length || a.len().abs_diff(b.len()) > max_distance {
+                continue;
+            }
+            let distance = edit_distance(&a, &b);
+            if distance <= max_distance {
+                return Some(Lookalike {
+                    entry: entry.display.clone(),
+                    distance: Some(distance),
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Read a corpus file: one name per line, blank lines and `#` comments skipped.
+pub fn read_names(path: &str) -> Result<Vec<String>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read typosquat corpus '{}': {}", path, e))?;
+    Ok(content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or("").trim())
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Name as the registry compares it: case-insensitive, and PyPI (PEP 503) and
+/// crates.io treat some separators as equal.
+fn canonical(registry: RegistryType, name: &str) -> String {
+    let lower = name.trim().to_lowercase();
+    match registry {
+        RegistryType::PyPI => {
+            let mut out = String::with_capacity(lower.len());
+            for c in lower.chars() {
+                if matches!(c, '-' | '_' | '.') {
+                    if !out.ends_with('-') {
+                        out.push('-');
+                    }
+                } else {
+                    out.push(c);
+                }
+            }
+            out
+        }
+        RegistryType::Cargo => lower.replace('_', "-"),
+        _ => lower,
+    }
+}
+
+/// The first `segments` `separator`-delimited segments of `name`, when it has more.
+fn namespace_of(name: &str, separator: char, segments: usize) -> Option<&str> {
+    let (end, _) = name.match_indices(separator).nth(segments - 1)?;
+    Some(&name[..end])
+}
+
+/// Canonical name with separators dropped and lookalike characters folded.
+fn skeleton(name: &str) -> String {
+    let folded: String = name
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_' | '.'))
+        .map(|c| match c {
+            '0' | 'о' | 'ο' => 'o',
+            '1' | 'i' | 'і' | 'ı' | '|' => 'l',
+            '3' => 'e',
+            '5' | 'ѕ' => 's',
+            'а' | 'α' => 'a',
+            'е' | 'ε' => 'e',
+            'р' | 'ρ' => 'p',
+            'с' | 'ϲ' => 'c',
+            'х' | 'χ' => 'x',
+            'у' | 'γ' => 'y',
+            'ј' => 'j',
+            'ν' => 'v',
+            'κ' | 'к' => 'k',
+            'м' => 'm',
+            'т' | 'τ' => 't',
+            'н' => 'h',
+            other => other,
+        })
+        .collect();
+    folded.replace("rn", "m").replace("vv", "w")
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent swaps.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    rows[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut d = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d = d.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = d;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn npm(names: &[&str]) -> Corpus {
+        Corpus::new(RegistryType::Npm, names.iter().copied())
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        let d = |a: &str, b: &str| {
+            edit_distance(
+                &a.chars().collect::<Vec<_>>(),
+                &b.chars().collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(d("lodahs", "lodash"), 1);
+        assert_eq!(d("lodas", "lodash"), 1);
+        assert_eq!(d("llodash", "lodash"), 1);
+        assert_eq!(d("lodesh", "lodash"), 1);
+        assert_eq!(d("express", "expres"), 1);
+        assert_eq!(d("react", "preact"), 1);
+        assert_eq!(d("kitten", "sitting"), 3);
+        assert_eq!(d("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_typos_resemble_corpus_names() {
+        let corpus = npm(&["lodash", "express", "react"]);
+        assert_eq!(
+            corpus.lookalike("lodahs", 1, 5),
+            Some(Lookalike {
+                entry: "lodash".to_string(),
+                distance: Some(1),
+            })
+        );
+        assert_eq!(corpus.lookalike("LODASH", 1, 5), None);
+        assert_eq!(corpus.lookalike("underscore", 1, 5), None);
+        // Too short for the distance test…
+        assert_eq!(corpus.lookalike("raect", 1, 6), None);
+        // …but still compared by skeleton.
+        assert_eq!(corpus.lookalike("re-act", 1, 6).unwrap().distance, None);
+        // A corpus name is never a lookalike of another corpus name.
+        let corpus = npm(&["react", "preact"]);
+        assert_eq!(corpus.lookalike("preact", 1, 5), None);
+    }
+
+    #[test]
+    fn test_homoglyphs_and_namespaces() {
+        let corpus = npm(&["@acmecorp/*", "lodash"]);
+        assert_eq!(
+            corpus.lookalike("@acme-corp/x", 1, 5),
+            Some(Lookalike {
+                entry: "@acmecorp/*".to_string(),
+                distance: None,
+            })
+        );
+        assert_eq!(
+            corpus.lookalike("@acrnecorp/x", 1, 5).unwrap().entry,
+            "@acmecorp/*"
+        );
+        assert_eq!(corpus.lookalike("@acmecorp/x", 1, 5), None);
+        assert_eq!(
+            corpus.lookalike("@acmecorq/x", 1, 5).unwrap().distance,
+            Some(1)
+        );
+        // Cyrillic "о" and a zero.
+        assert!(corpus.lookalike("lоdash", 1, 5).is_some());
+        assert!(corpus.lookalike("l0dash", 0, 5).is_some());
+
+        let go = Corpus::new(RegistryType::Go, ["github.com/acme/*"]);
+        assert!(go.lookalike("github.com/acrne/tool", 1, 5).is_some());
+        assert_eq!(go.lookalike("github.com/acme/tool", 1, 5), None);
+        assert_eq!(go.lookalike("github.com/other/tool", 1, 5), None);
+
+        let maven = Corpus::new(RegistryType::Maven, ["com.acme:*"]);
+        assert!(maven.lookalike("com.acme-corp:lib", 1, 5).is_none());
+        assert!(maven.lookalike("corn.acme:lib", 1, 5).is_some());
+    }
+
+    #[test]
+    fn test_registry_canonical_names() {
+        let pypi = Corpus::new(RegistryType::PyPI, ["Django", "zope.interface"]);
+        assert_eq!(pypi.lookalike("zope-interface", 1, 5), None);
+        assert_eq!(pypi.lookalike("django", 1, 5), None);
+        assert!(pypi.lookalike("djanga", 1, 5).is_some());
+        let cargo = Corpus::new(RegistryType::Cargo, ["serde_json"]);
+        assert_eq!(cargo.lookalike("serde-json", 1, 5), None);
+    }
+
+    #[test]
+    fn test_read_names() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("npm.txt");
+        std::fs::write(&path, "# popular\nlodash\n\n  express  # web\n@acme/*\n").unwrap();
+        assert_eq!(
+            read_names(path.to_str().unwrap()).unwrap(),
+            vec!["lodash", "express", "@acme/*"]
+        );
+        assert!(read_names("/nonexistent/names.txt").is_err());
+    }
+}
@@ -35,6 +35,11 @@ pub struct RegistryStats {
     pub conan: usize,
     pub rpm: usize,
     pub deb: usize,
+    pub helm: usize,
+    pub apk: usize,
+    pub conda: usize,
+    pub composer: usize,
+    pub swift: usize,
 }
 
 #[derive(Serialize)]
@@ -176,6 +181,11 @@ pub async fn api_stats(State(state): State<AppState>) -> Json<RegistryStats> {
         conan: get(RegistryType::Conan),
         rpm: get(RegistryType::Rpm),
         deb: get(RegistryType::Deb),
+        helm: get(RegistryType::Helm),
+        apk: get(RegistryType::Apk),
+        conda: get(RegistryType::Conda),
+        composer: get(RegistryType::Composer),
+        swift: get(RegistryType::Swift),
     })
 }
 
@@ -240,6 +250,29 @@ pub async fn api_dashboard(State(state): State<AppState>) -> Json<DashboardRespo
             RegistryType::Conan => state.config.conan.proxy.clone().into_iter().collect(),
             RegistryType::Rpm => vec![],
             RegistryType::Deb => vec![],
+            RegistryType::Helm => state
+                .config
+                .helm
+                .proxies
+                .values()
+                .map(|e| e.url().to_string())
+                .collect(),
+            RegistryType::Apk => state
+                .config
+                .apk
+                .proxies
+                .values()
+                .map(|e| e.url().to_string())
+                .collect(),
+            RegistryType::Conda => state
+                .config
+                .conda
+                .proxies
+                .values()
+                .map(|e| e.url().to_string())
+                .collect(),
+            RegistryType::Composer => state.config.composer.proxy.clone().into_iter().collect(),
+            RegistryType::Swift => vec![],
         };
 
         mount_points.push(MountPoint {
@@ -886,6 +919,10 @@ pub async fn get_go_dir_listing(storage: &Storage, path: &str) -> (Vec<RepoInfo>
             if child_name.starts_with('@') {
                 continue;
             }
+            // The checksum database cache (`go/sumdb/`) is not a module namespace
+            if path.is_empty() && child_name == "sumdb" {
+                continue;
+            }
             let entry = groups.entry(child_name).or_insert((0, 0, 0));
             entry.0 += 1;
             if let Some(meta) = storage.stat(key).await {
@@ -1074,6 +1111,11 @@ pub async fn get_generic_detail(
         "conan" => get_conan_detail(storage, &name_lower, show_prerelease, show_all).await,
         "rpm" => get_rpm_detail(storage, name, show_all).await,
         "deb" => get_deb_detail(storage, name, show_all).await,
+        "apk" => get_apk_detail(storage, name, show_all).await,
+        "conda" => get_conda_detail(storage, name, show_all).await,
+        "composer" => get_composer_detail(storage, &name_lower, show_prerelease, show_all).await,
+        "swift" => get_swift_detail(storage, &name_lower, show_prerelease, show_all).await,
+        "helm" => get_helm_detail(storage, name, show_prerelease, show_all).await,
         "gems" => get_gems_detail(storage, &name_lower, show_prerelease, show_all).await,
         "pub" => get_pub_detail(storage, &name_lower, show_prerelease, show_all).await,
         "ansible" => get_ansible_detail(storage, &name_lower, show_prerelease, show_all).await,
@@ -1096,75 +1138,116 @@ async fn get_nuget_detail(
     // Extract package-level metadata from the registration index
     let metadata = load_nuget_package_metadata(storage, &reg_key).await;
 
+    // Pushed versions have a record under nuget/hosted/{id}/ and may have no
+    // cached upstream version list at all. Unlisted ones are left out.
+    let mut hosted: Vec<(String, String)> = Vec::new();
+    let hosted_prefix = format!("nuget/hosted/{}/", name);
+    for record_key in storage.list(&hosted_prefix).await.unwrap_or_default() {
+        let Ok(data) = storage.get(&record_key).await else {
+            continue;
+        };
+        let Ok(record) = serde_json::from_slice::<serde_json::Value>(&data) else {
+            continue;
+        };
+        if let (Some(version), Some(published), Some(true)) = (
+            record["nuspec"]["version"].as_str(),
+            record["published"].as_str(),
+            record["listed"].as_bool(),
+        ) {
+            hosted.push((version.to_lowercase(), published.to_string()));
+        }
+    }
+
     let key = format!("nuget/flatcontainer/{}/index.json", name);
-    if let Ok(data) = storage.get(&key).await {
-        if let Ok(index) = serde_json::from_slice::<serde_json::Value>(&data) {
-            if let Some(versions) = index.get("versions").and_then(|v| v.as_array()) {
-                let fallback_ts = storage
-                    .stat(&key)
-                    .await
-                    .map(|m| format_timestamp(m.modified))
-                    .unwrap_or_else(|| "N/A".to_string());
-
-                let mut stable_versions = Vec::new();
-                let mut prerelease_count: usize = 0;
-
-                for v in versions.iter().rev().filter_map(|v| v.as_str()) {
-                    let is_prerelease = v.contains('-');
-
-                    // Get published date from registration metadata
-                    let (published, _upstream_size) = reg_meta
-                        .get(v)
-                        .map(|(p, s)| (p.clone(), *s))
-                        .unwrap_or_else(|| (fallback_ts.clone(), 0));
-
-                    // Skip unlisted (NuGet convention: published=1900-01-01)
-                    if published.starts_with("1900") {
-                        continue;
-                    }
+    let cached: Option<Vec<String>> = match storage.get(&key).await {
+        Ok(data) => serde_json::from_slice::<serde_json::Value>(&data)
+            .ok()
+            .and_then(|index| {
+                index.get("versions").and_then(|v| v.as_array()).map(|v| {
+                    v.iter()
+                        .filter_map(|v| v.as_str().map(str::to_string))
+                        .collect()
+                })
+            }),
+        Err(_) => None,
+    };
+    let versions = match cached {
+        Some(mut versions) => {
+            for (version, _) in &hosted {
+                if !versions.contains(version) {
+                    versions.push(version.clone());
+                }
+            }
+            Some(versions)
+        }
+        None if !hosted.is_empty() => Some(hosted.iter().map(|(v, _)| v.clone()).collect()),
+        None => None,
+    };
+    if let Some(versions) = versions {
+        let fallback_ts = storage
+            .stat(&key)
+            .await
+            .map(|m| format_timestamp(m.modified))
+            .unwrap_or_else(|| "N/A".to_string());
 
-                    // Count pre-release, skip unless toggled
-                    if is_prerelease {
-                        prerelease_count += 1;
-                        if !show_prerelease {
-                            continue;
-                        }
-                    }
+        let mut stable_versions = Vec::new();
+        let mut prerelease_count: usize = 0;
 
-                    // Check if .nupkg is cached locally
-                    let nupkg_key =
-                        format!("nuget/flatcontainer/{}/{}/{}.{}.nupkg", name, v, name, v);
-                    let (size, cached) = if let Some(meta) = storage.stat(&nupkg_key).await {
-                        (meta.size, true)
-                    } else {
-                        (0, false)
-                    };
+        for v in versions.iter().rev().map(String::as_str) {
+            let is_prerelease = v.contains('-');
 
-                    stable_versions.push(VersionInfo {
-                        version: v.to_string(),
-                        size,
-                        published,
-                        cached,
-                    });
-                }
+            // Get published date from the hosted record or registration metadata
+            let (published, _upstream_size) = hosted
+                .iter()
+                .find(|(version, _)| version == v)
+                .map(|(_, published)| (published.clone(), 0))
+                .or_else(|| reg_meta.get(v).map(|(p, s)| (p.clone(), *s)))
+                .unwrap_or_else(|| (fallback_ts.clone(), 0));
 
-                let total_stable = stable_versions
-                    .iter()
-                    .filter(|v| !v.version.contains('-'))
-                    .count();
-                // Limit default view to 20 versions when not showing all
-                if !show_prerelease && !show_all && stable_versions.len() > 20 {
-                    stable_versions.truncate(20);
-                }
+            // Skip unlisted (NuGet convention: published=1900-01-01)
+            if published.starts_with("1900") {
+                continue;
+            }
 
-                return PackageDetail {
-                    versions: stable_versions,
-                    prerelease_count,
-                    total_stable,
-                    metadata,
-                };
+            // Count pre-release, skip unless toggled
+            if is_prerelease {
+                prerelease_count += 1;
+                if !show_prerelease {
+                    continue;
+                }
             }
+
+            // Check if .nupkg is cached locally
+            let nupkg_key = format!("nuget/flatcontainer/{}/{}/{}.{}.nupkg", name, v, name, v);
+            let (size, cached) = if let Some(meta) = storage.stat(&nupkg_key).await {
+                (meta.size, true)
+            } else {
+                (0, false)
+            };
+
+            stable_versions.push(VersionInfo {
+                version: v.to_string(),
+                size,
+                published,
+                cached,
+            });
         }
+
+        let total_stable = stable_versions
+            .iter()
+            .filter(|v| !v.version.contains('-'))
+            .count();
+        // Limit default view to 20 versions when not showing all
+        if !show_prerelease && !show_all && stable_versions.len() > 20 {
+            stable_versions.truncate(20);
+        }
+
+        return PackageDetail {
+            versions: stable_versions,
+            prerelease_count,
+            total_stable,
+            metadata,
+        };
     }
     PackageDetail {
         versions: vec![],
@@ -1379,6 +1462,186 @@ async fn get_rpm_detail(storage: &Storage, repo: &str, show_all: bool) -> Packag
     }
 }
 
+/// Alpine: the UI "package" is a repository; each row is one stored package
+/// (`{branch}/{component}/{arch}/{name}-{version}`), read from the `.PKGINFO`
+/// sidecars written at upload.
+async fn get_apk_detail(storage: &Storage, repo: &str, show_all: bool) -> PackageDetail {
+    let prefix = format!("apk/{}/.nora-meta/", repo);
+    let keys = storage.list(&prefix).await.unwrap_or_default();
+    let mut versions = Vec::new();
+
+    for key in &keys {
+        let Ok(data) = storage.get(key).await else {
+            continue;
+        };
+        let Ok(rec) = serde_json::from_slice::<serde_json::Value>(&data) else {
+            continue;
+        };
+        let s = |f: &str| {
+            rec.get(f)
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string()
+        };
+        versions.push(VersionInfo {
+            version: s("href").trim_end_matches(".apk").to_string(),
+            size: rec.get("size").and_then(|v| v.as_u64()).unwrap_or(0),
+            published: format_timestamp(
+                rec.get("upload_time").and_then(|v| v.as_u64()).unwrap_or(0),
+            ),
+            cached: true,
+        });
+    }
+
+    versions.sort_by(|a, b| b.version.cmp(&a.version));
+    let total = versions.len();
+    if !show_all && versions.len() > 20 {
+        versions.truncate(20);
+    }
+    PackageDetail {
+        versions,
+        prerelease_count: 0,
+        total_stable: total,
+        metadata: PackageMetadata::default(),
+    }
+}
+
+/// Conda: the UI "package" is a channel; each row is one stored package
+/// (`{subdir}/{name}-{version}-{build}`). Lists package files rather than
+/// sidecars so pull-through channels show what has been cached.
+async fn get_conda_detail(storage: &Storage, channel: &str, show_all: bool) -> PackageDetail {
+    let prefix = format!("conda/{}/", channel);
+    let keys = storage.list(&prefix).await.unwrap_or_default();
+    let mut versions = Vec::new();
+
+    for key in &keys {
+        let Some(rest) = key.strip_prefix(&prefix) else {
+            continue;
+        };
+        if rest.starts_with('.') || !crate::registry::conda::is_package_file(rest) {
+            continue;
+        }
+        let Some(meta) = storage.stat(key).await else {
+            continue;
+        };
+        let version = rest
+            .strip_suffix(".conda")
+            .or_else(|| rest.strip_suffix(".tar.bz2"))
+            .unwrap_or(rest);
+        versions.push(VersionInfo {
+            version: version.to_string(),
+            size: meta.size,
+            published: format_timestamp(meta.modified),
+            cached: true,
+        });
+    }
+
+    versions.sort_by(|a, b| b.version.cmp(&a.version));
+    let total = versions.len();
+    if !show_all && versions.len() > 20 {
+        versions.truncate(20);
+    }
+    PackageDetail {
+        versions,
+        prerelease_count: 0,
+        total_stable: total,
+        metadata: PackageMetadata::default(),
+    }
+}
+
+/// Composer: one row per stored dist of `vendor/package`, hosted or proxied.
+/// Dists are keyed by reference; the version comes from the hosted records or
+/// the cached upstream metadata (a short reference when neither knows it).
+async fn get_composer_detail(
+    storage: &Storage,
+    name: &str,
+    show_prerelease: bool,
+    show_all: bool,
+) -> PackageDetail {
+    let prefix = format!("composer/dists/{}/", name);
+    let keys = storage.list(&prefix).await.unwrap_or_default();
+    let known = crate::registry::composer::dist_versions(storage, name).await;
+    let mut versions = Vec::new();
+
+    for key in &keys {
+        let Some(reference) = key
+            .strip_prefix(&prefix)
+            .and_then(|r| r.strip_suffix(".zip"))
+        else {
+            continue;
+        };
+        let Some(meta) = storage.stat(key).await else {
+            continue;
+        };
+        let version = match known.get(reference) {
+            Some(v) => v.clone(),
+            None => reference.chars().take(12).collect(),
+        };
+        versions.push(VersionInfo {
+            version,
+            size: meta.size,
+            published: format_timestamp(meta.modified),
+            cached: true,
+        });
+    }
+
+    versions.sort_by(|a, b| b.version.cmp(&a.version));
+    let (versions, prerelease_count, total_stable) =
+        apply_prerelease_filter(versions, show_prerelease, show_all);
+    PackageDetail {
+        versions,
+        prerelease_count,
+        total_stable,
+        metadata: PackageMetadata::default(),
+    }
+}
+
+/// Swift: one row per published release of `scope.name`, from the release
+/// records; description and repository come from the newest release's metadata.
+async fn get_swift_detail(
+    storage: &Storage,
+    name: &str,
+    show_prerelease: bool,
+    show_all: bool,
+) -> PackageDetail {
+    let releases = match name.split_once('.') {
+        Some((scope, package)) => {
+            crate::registry::swift::load_releases(storage, scope, package).await
+        }
+        None => Vec::new(),
+    };
+    let metadata = releases
+        .first()
+        .map(|latest| PackageMetadata {
+            description: latest.metadata["description"].as_str().map(str::to_string),
+            repository: latest.metadata["repositoryURLs"][0]
+                .as_str()
+                .map(str::to_string),
+            ..PackageMetadata::default()
+        })
+        .unwrap_or_default();
+    let versions = releases
+        .into_iter()
+        .map(|r| VersionInfo {
+            published: chrono::DateTime::parse_from_rfc3339(&r.published_at)
+                .map(|t| format_timestamp(t.timestamp().max(0) as u64))
+                .unwrap_or_else(|_| "N/A".to_string()),
+            version: r.version,
+            size: r.size,
+            cached: true,
+        })
+        .collect();
+
+    let (versions, prerelease_count, total_stable) =
+        apply_prerelease_filter(versions, show_prerelease, show_all);
+    PackageDetail {
+        versions,
+        prerelease_count,
+        total_stable,
+        metadata,
+    }
+}
+
 /// Debian: the UI "package" is a repository; each row is one stored package
 /// (package_version_arch). Reads the per-package control sidecars written at
 /// upload — never the .deb payloads. No prerelease filter: Debian versions
@@ -1427,6 +1690,49 @@ async fn get_deb_detail(storage: &Storage, repo: &str, show_all: bool) -> Packag
     }
 }
 
+/// Helm: every stored archive of the chart — hosted (`helm/charts/`) and
+/// cached from a proxied repo (`helm/{repo}/charts/`).
+async fn get_helm_detail(
+    storage: &Storage,
+    name: &str,
+    show_prerelease: bool,
+    show_all: bool,
+) -> PackageDetail {
+    let keys = storage.list("helm/").await.unwrap_or_default();
+    let mut versions = Vec::new();
+    for key in &keys {
+        let Some(file) = key.rsplit('/').next() else {
+            continue;
+        };
+        let Some((chart, version)) = crate::registry::helm::split_chart_filename(file) else {
+            continue;
+        };
+        if chart != name {
+            continue;
+        }
+        let (size, published) = match storage.stat(key).await {
+            Some(meta) => (meta.size, format_timestamp(meta.modified)),
+            None => (0, "N/A".to_string()),
+        };
+        versions.push(VersionInfo {
+            version: version.to_string(),
+            size,
+            published,
+            cached: true,
+        });
+    }
+    versions.sort_by(|a, b| b.version.cmp(&a.version));
+    versions.dedup_by(|a, b| a.version == b.version);
+    let (versions, prerelease_count, total_stable) =
+        apply_prerelease_filter(versions, show_prerelease, show_all);
+    PackageDetail {
+        versions,
+        prerelease_count,
+        total_stable,
+        metadata: PackageMetadata::default(),
+    }
+}
+
 async fn get_gems_detail(
     storage: &Storage,
     name: &str,
@@ -1474,6 +1780,30 @@ async fn get_gems_detail(
         }
     }
 
+    // Pushed gems have a record per version under gems/hosted/{name}/ and no
+    // cached upstream index of their own.
+    let hosted_prefix = format!("gems/hosted/{}/", name);
+    for key in storage.list(&hosted_prefix).await.unwrap_or_default() {
+        let Some(version) = key
+            .strip_prefix(&hosted_prefix)
+            .and_then(|rest| rest.strip_suffix(".json"))
+        else {
+            continue;
+        };
+        if versions.iter().any(|v: &VersionInfo| v.version == version) {
+            continue;
+        }
+        let gem_key = format!("gems/gems/{}-{}.gem", name, version);
+        if let Some(meta) = storage.stat(&gem_key).await {
+            versions.push(VersionInfo {
+                version: version.to_string(),
+                size: meta.size,
+                published: format_timestamp(meta.modified),
+                cached: true,
+            });
+        }
+    }
+
     // Reverse: newest first (compact index is chronological)
     versions.reverse();
     let (versions, prerelease_count, total_stable) =
@@ -274,6 +274,46 @@ pub fn sidebar_dark_with_registries(
             icons::DEB,
             false,
         ),
+        (
+            Some(RegistryType::Helm),
+            "helm",
+            "/ui/helm",
+            "Helm",
+            icons::HELM,
+            false,
+        ),
+        (
+            Some(RegistryType::Apk),
+            "apk",
+            "/ui/apk",
+            "Alpine",
+            icons::APK,
+            false,
+        ),
+        (
+            Some(RegistryType::Conda),
+            "conda",
+            "/ui/conda",
+            "Conda",
+            icons::CONDA,
+            false,
+        ),
+        (
+            Some(RegistryType::Composer),
+            "composer",
+            "/ui/composer",
+            "Composer",
+            icons::COMPOSER,
+            false,
+        ),
+        (
+            Some(RegistryType::Swift),
+            "swift",
+            "/ui/swift",
+            "Swift",
+            icons::SWIFT,
+            false,
+        ),
     ];
 
     // Filter to enabled registries (dashboard always shown)
@@ -758,6 +798,11 @@ pub mod icons {
     pub const CARGO: &str = r#"<path fill="currentColor" d="M6 2h12a1 1 0 011 1v8a1 1 0 01-1 1H6a1 1 0 01-1-1V3a1 1 0 011-1zm0 2v2h12V4H6zm0 3v2h12V7H6zM2 14h8a1 1 0 011 1v6a1 1 0 01-1 1H2a1 1 0 01-1-1v-6a1 1 0 011-1zm0 2v1.5h8V16H2zM14 14h8a1 1 0 011 1v6a1 1 0 01-1 1h-8a1 1 0 01-1-1v-6a1 1 0 011-1zm0 2v1.5h8V16h-8z"/>"#;
     pub const GO: &str = r#"<path fill="currentColor" d="M2.64 9.56s.24-.14.65-.38c.41-.24.97-.5 1.63-.7A7.85 7.85 0 017.53 8c.86 0 1.67.17 2.37.52.7.35 1.26.87 1.63 1.51.37.64.54 1.41.54 2.27v.2h-2.7v-.16c0-.47-.09-.86-.28-1.15a1.7 1.7 0 00-.77-.67 2.7 2.7 0 00-1.14-.22c-.56 0-1.06.13-1.46.4-.41.27-.72.66-.93 1.16-.21.5-.31 1.1-.31 1.8 0 .69.1 1.28.32 1.78.21.5.53.88.94 1.15.41.27.9.4 1.47.4.38 0 .73-.06 1.04-.17.31-.12.56-.29.74-.52.19-.23.29-.51.29-.84v-.14H7.15v-1.76h5.07v1.3c0 .8-.17 1.48-.52 2.04a3.46 3.46 0 01-1.5 1.3c-.66.3-1.44.45-2.35.45-.99 0-1.87-.18-2.63-.55a4.2 4.2 0 01-1.77-1.59C3.15 14.82 3 13.94 3 12.89v-.28c0-1.04.16-1.93.48-2.65a3.08 3.08 0 01-.84-.4zm12.1-1.34c.92 0 1.74.18 2.44.55a3.96 3.96 0 011.66 1.59c.4.7.6 1.54.6 2.53v.28c0 .99-.2 1.83-.6 2.53a3.96 3.96 0 01-1.66 1.59c-.7.37-1.52.55-2.44.55s-1.74-.18-2.44-.55a3.96 3.96 0 01-1.66-1.59c-.4-.7-.6-1.54-.6-2.53v-.28c0-.99.2-1.83.6-2.53a3.96 3.96 0 011.66-1.59c.7-.37 1.52-.55 2.44-.55zm0 2.12c-.44 0-.82.12-1.14.37-.32.24-.56.6-.73 1.06-.17.46-.26 1.01-.26 1.65v.28c0 .64.09 1.19.26 1.65.17.46.41.82.73 1.06.32.25.7.37 1.14.37.44 0 .82-.12 1.14-.37.32-.24.56-.6.73-1.06.17-.46.26-1.01.26-1.65v-.28c0-.64-.09-1.19-.26-1.65a2.17 2.17 0 00-.73-1.06 1.78 1.78 0 00-1.14-.37z"/>"#;
     pub const DEB: &str = r#"<path fill="currentColor" d="M13.88 12.685c-.4 0 .08.2.601.28.14-.1.27-.22.39-.33a3.001 3.001 0 01-.99.05m2.14-.53c.23-.33.4-.69.47-1.06-.06.27-.2.5-.33.73-.75.47-.07-.27 0-.56-.8 1.01-.11.6-.14.89m.781-2.05c.05-.721-.14-.501-.2-.221.07.04.13.5.2.22M12.38.31c.2.04.45.07.42.12.23-.05.28-.1-.43-.12m.43.12l-.15.03.14-.01V.43m6.633 9.944c.02.64-.2.95-.38 1.5l-.35.181c-.28.54.03.35-.17.78-.44.39-1.34 1.22-1.62 1.301-.201 0 .14-.25.19-.34-.591.4-.481.6-1.371.85l-.03-.06c-2.221 1.04-5.303-1.02-5.253-3.842-.03.17-.07.13-.12.2a3.551 3.552 0 012.001-3.501 3.361 3.362 0 013.732.48 3.341 3.342 0 00-2.721-1.3c-1.18.01-2.281.76-2.651 1.57-.6.38-.67 1.47-.93 1.661-.361 2.601.66 3.722 2.38 5.042.27.19.08.21.12.35a4.702 4.702 0 01-1.53-1.16c.23.33.47.66.8.91-.55-.18-1.27-1.3-1.48-1.35.93 1.66 3.78 2.921 5.261 2.3a6.203 6.203 0 01-2.33-.28c-.33-.16-.77-.51-.7-.57a5.802 5.803 0 005.902-.84c.44-.35.93-.94 1.07-.95-.2.32.04.16-.12.44.44-.72-.2-.3.46-1.24l.24.33c-.09-.6.74-1.321.66-2.262.19-.3.2.3 0 .97.29-.74.08-.85.15-1.46.08.2.18.42.23.63-.18-.7.2-1.2.28-1.6-.09-.05-.28.3-.32-.53 0-.37.1-.2.14-.28-.08-.05-.26-.32-.38-.861.08-.13.22.33.34.34-.08-.42-.2-.75-.2-1.08-.34-.68-.12.1-.4-.3-.34-1.091.3-.25.34-.74.54.77.84 1.96.981 2.46-.1-.6-.28-1.2-.49-1.76.16.07-.26-1.241.21-.37A7.823 7.824 0 0017.702 1.6c.18.17.42.39.33.42-.75-.45-.62-.48-.73-.67-.61-.25-.65.02-1.06 0C15.082.73 14.862.8 13.8.4l.05.23c-.77-.25-.9.1-1.73 0-.05-.04.27-.14.53-.18-.741.1-.701-.14-1.431.03.17-.13.36-.21.55-.32-.6.04-1.44.35-1.18.07C9.6.68 7.847 1.3 6.867 2.22L6.838 2c-.45.54-1.96 1.611-2.08 2.311l-.131.03c-.23.4-.38.85-.57 1.261-.3.52-.45.2-.4.28-.6 1.22-.9 2.251-1.16 3.102.18.27 0 1.65.07 2.76-.3 5.463 3.84 10.776 8.363 12.006.67.23 1.65.23 2.49.25-.99-.28-1.12-.15-2.08-.49-.7-.32-.85-.7-1.34-1.13l.2.35c-.971-.34-.57-.42-1.361-.67l.21-.27c-.31-.03-.83-.53-.97-.81l-.34.01c-.41-.501-.63-.871-.61-1.161l-.111.2c-.13-.21-1.52-1.901-.8-1.511-.13-.12-.31-.2-.5-.55l.14-.17c-.35-.44-.64-1.02-.62-1.2.2.24.32.3.45.33-.88-2.172-.93-.12-1.601-2.202l.15-.02c-.1-.16-.18-.34-.26-.51l.06-.6c-.63-.74-.18-3.102-.09-4.402.07-.54.53-1.1.88-1.981l-.21-.04c.4-.71 2.341-2.872 3.241-2.761.43-.55-.09 0-.18-.14.96-.991 1.26-.7 1.901-.88.7-.401-.6.16-.27-.151 1.2-.3.85-.7 2.421-.85.16.1-.39.14-.52.26 1-.49 3.151-.37 4.562.27 1.63.77 3.461 3.011 3.531 5.132l.08.02c-.04.85.13 1.821-.17 2.711l.2-.42M9.54 13.236l-.05.28c.26.35.47.73.8 1.01-.24-.47-.42-.66-.75-1.3m.62-.02c-.14-.15-.22-.34-.31-.52.08.32.26.6.43.88l-.12-.36m10.945-2.382l-.07.15c-.1.76-.34 1.511-.69 2.212.4-.73.65-1.541.75-2.362M12.45.12c.27-.1.66-.05.95-.12-.37.03-.74.05-1.1.1l.15.02M3.006 5.142c.07.57-.43.8.11.42.3-.66-.11-.18-.1-.42m-.64 2.661c.12-.39.15-.62.2-.84-.35.44-.17.53-.2.83"/>"#;
+    pub const HELM: &str = r#"<path fill="currentColor" fill-rule="evenodd" d="M12 2a1 1 0 011 1v1.07A8 8 0 0119.93 11H21a1 1 0 110 2h-1.07A8 8 0 0113 19.93V21a1 1 0 11-2 0v-1.07A8 8 0 014.07 13H3a1 1 0 110-2h1.07A8 8 0 0111 4.07V3a1 1 0 011-1zm0 4a6 6 0 100 12 6 6 0 000-12zm0 4a2 2 0 110 4 2 2 0 010-4z"/>"#;
+    pub const APK: &str = r#"<path fill="currentColor" fill-rule="evenodd" d="M12 2l9 5v10l-9 5-9-5V7l9-5zm-2.5 7L5 16h14l-3.5-5-2 2.5L9.5 9z"/>"#;
+    pub const CONDA: &str = r#"<path fill="currentColor" fill-rule="evenodd" d="M12 2a10 10 0 110 20 10 10 0 010-20zm0 3a7 7 0 100 14 7 7 0 000-14zm0 3a4 4 0 110 8 4 4 0 010-8z"/>"#;
+    pub const COMPOSER: &str = r#"<path fill="currentColor" fill-rule="evenodd" d="M4 6h16a2 2 0 012 2v8a2 2 0 01-2 2H4a2 2 0 01-2-2V8a2 2 0 012-2zm1.5 3v6h1.5v-2h1a2 2 0 000-4H5.5zm1.5 1.3h1a.7.7 0 010 1.4H7v-1.4zM10.5 9v6H12v-2.3h1.5V15H15V9h-1.5v2.4H12V9h-1.5zm5 0v6H17v-2h1a2 2 0 000-4h-2.5zm1.5 1.3h1a.7.7 0 010 1.4h-1v-1.4z"/>"#;
+    pub const SWIFT: &str = r#"<path fill="currentColor" d="M13.54 3.41c4.12 2.47 6.55 7.16 5.55 11.13l-.07.27c2.06 2.54 1.5 5.26 1.23 4.75-1.07-2.09-3.06-1.57-4.08-1.05l-.28.16c-2.12 1.13-4.96 1.21-7.82-.02a12.57 12.57 0 01-5.64-4.84c.65.48 1.35.9 2.1 1.25 3.02 1.42 6.05 1.31 8.2 0-3.06-2.35-5.61-5.41-7.56-7.89a10.63 10.63 0 01-1-1.38c2.34 2.14 6.04 4.83 7.36 5.58C8.69 8.41 6.21 4.74 6.32 4.86c4.44 4.47 8.53 7 8.53 7 .15.08.27.15.36.21.09-.21.16-.44.22-.67.71-2.59-.09-5.55-1.89-7.99z"/>"#;
     pub const RAW: &str = r#"<path fill="currentColor" d="M14 2H6a2 2 0 00-2 2v16a2 2 0 002 2h12a2 2 0 002-2V8l-6-6zm4 18H6V4h7v5h5v11z"/>"#;
     pub const GEMS: &str = r#"<path fill="currentColor" d="M7.81 7.9l-2.97 2.95 7.19 7.18 2.96-2.95 4.22-4.23-2.96-2.96v-.01H7.8zM12 0L1.53 6v12L12 24l10.47-6V6L12 0zm8.47 16.85L12 21.73l-8.47-4.88V7.12L12 2.24l8.47 4.88v9.73z"/>"#;
     pub const TERRAFORM: &str = r#"<path fill="currentColor" d="M1.5 0v7.69l6.56 3.85V3.85L1.5 0zm7.94 4.62v7.69l6.56-3.84V.77L9.44 4.62zm7.94 0v7.69l6.56-3.84V.77l-6.56 3.85zM9.44 13.46v7.69l6.56-3.85v-7.69l-6.56 3.85z"/>"#;
@@ -1075,6 +1120,11 @@ mod tests {
         assert!(!html.contains("/ui/conan"), "disabled conan absent");
         assert!(!html.contains("/ui/rpm"), "disabled rpm absent");
         assert!(!html.contains("/ui/deb"), "disabled deb absent");
+        assert!(!html.contains("/ui/helm"), "disabled helm absent");
+        assert!(!html.contains("/ui/apk"), "disabled apk absent");
+        assert!(!html.contains("/ui/conda"), "disabled conda absent");
+        assert!(!html.contains("/ui/composer"), "disabled composer absent");
+        assert!(!html.contains("/ui/swift"), "disabled swift absent");
         assert!(html.contains("\"/ui/\""), "dashboard nav always present");
     }
 
@@ -1088,6 +1138,11 @@ mod tests {
         assert!(html.contains("/ui/conan"));
         assert!(html.contains("/ui/rpm"));
         assert!(html.contains("/ui/deb"));
+        assert!(html.contains("/ui/helm"));
+        assert!(html.contains("/ui/apk"));
+        assert!(html.contains("/ui/conda"));
+        assert!(html.contains("/ui/composer"));
+        assert!(html.contains("/ui/swift"));
         assert!(html.contains("/ui/docker"));
     }
 
@@ -149,6 +149,16 @@ pub fn routes() -> Router<AppState> {
         .route("/ui/rpm/{name}", get(generic_registry_detail))
         .route("/ui/deb", get(generic_registry_list))
         .route("/ui/deb/{name}", get(generic_registry_detail))
+        .route("/ui/helm", get(generic_registry_list))
+        .route("/ui/helm/{name}", get(generic_registry_detail))
+        .route("/ui/apk", get(generic_registry_list))
+        .route("/ui/apk/{name}", get(generic_registry_detail))
+        .route("/ui/conda", get(generic_registry_list))
+        .route("/ui/conda/{name}", get(generic_registry_detail))
+        .route("/ui/composer", get(generic_registry_list))
+        .route("/ui/composer/{name}", get(generic_registry_detail))
+        .route("/ui/swift", get(generic_registry_list))
+        .route("/ui/swift/{name}", get(generic_registry_detail))
         .route("/ui/gems/{name}", get(generic_registry_detail))
         .route("/ui/terraform/{name}", get(generic_registry_detail))
         // Token management UI (protected by auth middleware)
@@ -689,6 +699,11 @@ async fn generic_registry_list(
         "conan" => "Conan (C/C++)",
         "rpm" => "RPM (yum/dnf)",
         "deb" => "Debian (APT)",
+        "helm" => "Helm Charts",
+        "apk" => "Alpine (apk)",
+        "conda" => "Conda",
+        "composer" => "Composer (PHP)",
+        "swift" => "Swift Packages",
         _ => registry_key,
     };
 
@@ -276,7 +276,7 @@ pub fn render_registry_list_paginated(
 
     let version_label = match registry_type {
         "docker" => t.tags,
-        "raw" | "rpm" | "deb" => t.items,
+        "raw" | "rpm" | "deb" | "apk" | "conda" => t.items,
         _ => t.versions,
     };
 
@@ -1191,6 +1191,27 @@ pub fn render_package_detail(
             "echo 'deb [trusted=yes] {}/deb/{} ./' | sudo tee /etc/apt/sources.list.d/nora-{}.list && sudo apt-get update",
             base_url, name, name
         ),
+        "helm" => format!(
+            "helm repo add nora {}/helm\nhelm install {} nora/{}",
+            base_url, name, name
+        ),
+        "apk" => format!(
+            "wget -O /etc/apk/keys/nora.rsa.pub {base}/apk/{name}/keys/nora.rsa.pub\necho '{base}/apk/{name}/v3.20/main' >> /etc/apk/repositories && apk update",
+            base = base_url,
+            name = name
+        ),
+        "conda" => format!(
+            "conda install -c {}/conda/{} <package>",
+            base_url, name
+        ),
+        "composer" => format!(
+            "composer config repositories.nora composer {}/composer && composer require {}",
+            base_url, name
+        ),
+        "swift" => format!(
+            "swift package-registry set {}/swift\n.package(id: \"{}\", from: \"<version>\")",
+            base_url, name
+        ),
         _ => String::new(),
     };
 
@@ -1353,7 +1374,7 @@ if (copyBtn) {{ copyBtn.addEventListener('click', function() {{
         metadata_panel = metadata_panel,
         versions_label = if registry_type == "raw" {
             _t.files
-        } else if registry_type == "rpm" || registry_type == "deb" {
+        } else if matches!(registry_type, "rpm" | "deb" | "apk" | "conda") {
             _t.items
         } else {
             _t.versions
@@ -1363,7 +1384,7 @@ if (copyBtn) {{ copyBtn.addEventListener('click', function() {{
         prerelease = prerelease_toggle,
         col_version = if registry_type == "raw" {
             _t.filename
-        } else if registry_type == "rpm" || registry_type == "deb" {
+        } else if matches!(registry_type, "rpm" | "deb" | "apk" | "conda") {
             _t.items
         } else {
             _t.versions
@@ -1748,6 +1769,11 @@ fn get_registry_icon(registry_type: &str) -> &'static str {
         "conan" => icons::CONAN,
         "rpm" => icons::RPM,
         "deb" => icons::DEB,
+        "helm" => icons::HELM,
+        "apk" => icons::APK,
+        "conda" => icons::CONDA,
+        "composer" => icons::COMPOSER,
+        "swift" => icons::SWIFT,
         _ => {
             r#"<path fill="currentColor" d="M10 4H4c-1.1 0-1.99.9-1.99 2L2 18c0 1.1.9 2 2 2h16c1.1 0 2-.9 2-2V8c0-1.1-.9-2-2-2h-8l-2-2z"/>"#
         }
@@ -1771,6 +1797,11 @@ fn get_registry_title(registry_type: &str) -> &'static str {
         "conan" => "Conan (C/C++)",
         "rpm" => "RPM (yum/dnf)",
         "deb" => "Debian (APT)",
+        "helm" => "Helm Charts",
+        "apk" => "Alpine (apk)",
+        "conda" => "Conda Channels",
+        "composer" => "Composer (PHP)",
+        "swift" => "Swift Packages",
         _ => "Registry",
     }
 }
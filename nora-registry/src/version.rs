@@ -0,0 +1,96 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+//! Semantic version validation and ordering for the registries whose clients
+//! require semver (Ansible Galaxy, pub, crates.io, Helm, Swift, Terraform).
+//!
+//! Ecosystems with their own rules keep their own comparators: Go
+//! (`registry::go`), NuGet (`registry::nuget`) and PEP 440 (`registry::pypi`).
+
+use std::cmp::Ordering;
+
+/// `MAJOR.MINOR.PATCH[-pre][+build]` with a numeric core. Character checks are
+/// left to each registry's own version validation.
+pub fn is_semver(version: &str) -> bool {
+    let core = version.split(['-', '+']).next().unwrap_or_default();
+    let parts: Vec<&str> = core.split('.').collect();
+    parts.len() == 3
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Semver precedence: numeric core, then a release above its pre-releases, with
+/// pre-release identifiers compared numerically where both are numbers. Build
+/// metadata is ignored, so `1.0.0+1` equals `1.0.0+2`.
+pub fn cmp_semver(a: &str, b: &str) -> Ordering {
+    fn split(v: &str) -> (Vec<u64>, Option<&str>) {
+        let v = v.split('+').next().unwrap_or(v);
+        let (core, pre) = match v.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (v, None),
+        };
+        (
+            core.split('.').map(|p| p.parse().unwrap_or(0)).collect(),
+            pre,
+        )
+    }
+    let ((core_a, pre_a), (core_b, pre_b)) = (split(a), split(b));
+    core_a.cmp(&core_b).then_with(|| match (pre_a, pre_b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(x), Some(y)) => {
+            let mut xs = x.split('.');
+            let mut ys = y.split('.');
+            loop {
+                match (xs.next(), ys.next()) {
+                    (None, None) => return Ordering::Equal,
+                    (None, Some(_)) => return Ordering::Less,
+                    (Some(_), None) => return Ordering::Greater,
+                    (Some(p), Some(q)) => {
+                        let ord = match (p.parse::<u64>(), q.parse::<u64>()) {
+                            (Ok(m), Ok(n)) => m.cmp(&n),
+                            (Ok(_), Err(_)) => Ordering::Less,
+                            (Err(_), Ok(_)) => Ordering::Greater,
+                            (Err(_), Err(_)) => p.cmp(q),
+                        };
+                        if ord != Ordering::Equal {
+                            return ord;
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_semver() {
+        assert!(is_semver("1.0.0"));
+        assert!(is_semver("1.0.0-rc.1"));
+        assert!(is_semver("1.0.0+build.5"));
+        assert!(is_semver("1.0.0-rc.1+build.5"));
+        assert!(!is_semver("1.0"));
+        assert!(!is_semver("1.0.x"));
+        assert!(!is_semver("1..0"));
+        assert!(!is_semver("v1.0.0"));
+    }
+
+    #[test]
+    fn test_cmp_semver() {
+        assert_eq!(cmp_semver("1.10.0", "1.9.9"), Ordering::Greater);
+        assert_eq!(cmp_semver("0.10.0", "0.9.1"), Ordering::Greater);
+        assert_eq!(cmp_semver("1.0.0", "1.0.0-rc.1"), Ordering::Greater);
+        assert_eq!(cmp_semver("2.0.0-beta", "2.0.0"), Ordering::Less);
+        assert_eq!(cmp_semver("1.0.0-rc.2", "1.0.0-rc.10"), Ordering::Less);
+        assert_eq!(cmp_semver("1.0.0-alpha", "1.0.0-alpha.1"), Ordering::Less);
+        assert_eq!(cmp_semver("1.0.0-1", "1.0.0-alpha"), Ordering::Less);
+        assert_eq!(cmp_semver("1.0.0+1", "1.0.0+2"), Ordering::Equal);
+        assert_eq!(cmp_semver("2.0.0", "2.0.0"), Ordering::Equal);
+    }
+}
@@ -0,0 +1,1112 @@
+// Copyright (c) 2026 The NORA Authors
+// SPDX-License-Identifier: MIT
+
+//! Offline vulnerability database built from OSV advisories.
+//!
+//! `nora vulndb import <dump.zip>` reads an [OSV](https://ossf.github.io/osv-schema/)
+//! export (`all.zip` from `osv-vulnerabilities`, per ecosystem or combined), keeps the
+//! advisories for the ecosystems NORA proxies, and writes a compact index to
+//! `curation.vulnerabilities.db_path`. The server loads that index at startup and on
+//! every SIGHUP reload, so a fresh import takes effect without a restart. With
+//! `[curation.vulnerabilities] enabled` it backs the vulnerability curation filter
+//! and the npm audit / pub advisories endpoints, which answer from it instead of the
+//! upstream for the ecosystems an import has covered.
+//!
+//! Matching follows the OSV schema: a version is affected when it is listed in
+//! `versions`, or falls inside a `SEMVER`/`ECOSYSTEM` range — at or after an
+//! `introduced` event and before the next `fixed` (or at/before `last_affected`).
+//! Versions compare with the ecosystem's own ordering. `GIT` ranges are dropped at
+//! import: they name commits, not the versions clients ask for.
+
+use crate::registry_type::RegistryType;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::io::Read;
+use std::path::Path;
+
+/// Schema version of the on-disk index.
+const DB_VERSION: u32 = 1;
+/// An OSV record larger than this is not an advisory (the largest real ones are ~1 MB).
+const MAX_RECORD_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Advisory severity, ordered low → critical.
+///
+/// Taken from the advisory's `database_specific.severity` (GitHub's
+/// LOW/MODERATE/HIGH/CRITICAL), else computed from a CVSS v3 vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Low,
+    #[serde(alias = "moderate")]
+    Medium,
+    High,
+    Critical,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+            Severity::Critical => "critical",
+        }
+    }
+
+    /// GitHub's name for the rating (`moderate` rather than `medium`), as npm audit
+    /// responses and GHSA records spell it.
+    pub fn ghsa_name(&self) -> &'static str {
+        match self {
+            Severity::Medium => "moderate",
+            other => other.as_str(),
+        }
+    }
+
+    /// CVSS qualitative rating of a base score; `None` for 0.0.
+    fn from_score(score: f64) -> Option<Self> {
+        match score {
+            s if s >= 9.0 => Some(Severity::Critical),
+            s if s >= 7.0 => Some(Severity::High),
+            s if s >= 4.0 => Some(Severity::Medium),
+            s if s > 0.0 => Some(Severity::Low),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "low" => Ok(Self::Low),
+            "medium" | "moderate" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            "critical" => Ok(Self::Critical),
+            other => Err(format!(
+                "unknown severity {:?} — valid values: low, medium, high, critical",
+                other
+            )),
+        }
+    }
+}
+
+/// One advisory as stored in the index (a subset of the OSV record).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Advisory {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub modified: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+    /// `None` = the advisory carries no usable severity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    pub affected: Vec<Affected>,
+}
+
+impl Advisory {
+    /// The advisory as an OSV record, limited to its entries for `affected`'s package.
+    ///
+    /// `known` versions inside a range are listed in `versions` too, as osv.dev does
+    /// for clients (`dart pub`) that only read the explicit list.
+    pub fn to_osv(&self, affected: &Affected, known: &[String]) -> serde_json::Value {
+        let mut versions = affected.versions.clone();
+        for version in known {
+            if affected.affects(version) && !versions.contains(version) {
+                versions.push(version.clone());
+            }
+        }
+        versions.sort_by(|a, b| cmp_versions(affected.registry, a, b));
+        let ranges: Vec<serde_json::Value> = affected
+            .ranges
+            .iter()
+            .map(|events| serde_json::json!({"type": "ECOSYSTEM", "events": events}))
+            .collect();
+        let mut record = serde_json::json!({
+            "schema_version": "1.6.0",
+            "id": self.id,
+            "modified": self.modified,
+            "aliases": self.aliases,
+            "summary": self.summary,
+            "affected": [{
+                "package": {
+                    "ecosystem": ecosystem_name(affected.registry),
+                    "name": affected.name,
+                },
+                "ranges": ranges,
+                "versions": versions,
+            }],
+            "references": [{
+                "type": "ADVISORY",
+                "url": format!("https://osv.dev/vulnerability/{}", self.id),
+            }],
+        });
+        if let Some(severity) = self.severity {
+            record["database_specific"] =
+                serde_json::json!({"severity": severity.ghsa_name().to_uppercase()});
+        }
+        record
+    }
+}
+
+/// The versions of one package an advisory applies to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Affected {
+    #[serde(with = "registry_name")]
+    pub registry: RegistryType,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ranges: Vec<Vec<Event>>,
+    /// Explicit versions, kept only when there is no range to evaluate.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub versions: Vec<String>,
+}
+
+/// An OSV range event; exactly one field is set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Event {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub introduced: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_affected: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<String>,
+}
+
+/// (De)serialize a [`RegistryType`] by its config name (`pypi`, `pub`, …).
+mod registry_name {
+    use crate::registry_type::RegistryType;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(registry: &RegistryType, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(registry.as_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<RegistryType, D::Error> {
+        let name = String::deserialize(d)?;
+        RegistryType::from_str_opt(&name)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown registry {:?}", name)))
+    }
+}
+
+impl Affected {
+    /// Whether `version` is affected.
+    pub fn affects(&self, version: &str) -> bool {
+        let registry = self.registry;
+        if self
+            .versions
+            .iter()
+            .any(|v| cmp_versions(registry, v, version) == Ordering::Equal)
+        {
+            return true;
+        }
+        self.ranges
+            .iter()
+            .any(|events| range_affects(registry, events, version))
+    }
+
+    /// The affected versions as an npm-style range (`>=1.0.0 <1.2.3 || 2.0.0`), the
+    /// `vulnerable_versions` shape of npm audit responses.
+    pub fn range_expr(&self) -> String {
+        let mut parts = self.versions.clone();
+        for events in &self.ranges {
+            let mut start: Option<&str> = None;
+            let interval = |start: Option<&str>, end: String| match start {
+                Some(s) if s != "0" => format!(">={} {}", s, end),
+                _ => end,
+            };
+            for event in sorted_events(self.registry, events) {
+                if let Some(introduced) = &event.introduced {
+                    start = Some(introduced);
+                } else if let (Some(fixed), true) = (&event.fixed, start.is_some()) {
+                    parts.push(interval(start.take(), format!("<{}", fixed)));
+                } else if let (Some(last), true) = (&event.last_affected, start.is_some()) {
+                    parts.push(interval(start.take(), format!("<={}", last)));
+                }
+            }
+            match start {
+                Some("0") => parts.push("*".to_string()),
+                Some(s) => parts.push(format!(">={}", s)),
+                None => {}
+            }
+        }
+        parts.join(" || ")
+    }
+}
+
+/// The events of an OSV range in version order (`0` sorts first).
+fn sorted_events(registry: RegistryType, events: &[Event]) -> Vec<&Event> {
+    let at = |e: &Event| -> String {
+        e.introduced
+            .as_ref()
+            .or(e.fixed.as_ref())
+            .or(e.last_affected.as_ref())
+            .or(e.limit.as_ref())
+            .cloned()
+            .unwrap_or_default()
+    };
+    let mut sorted: Vec<&Event> = events.iter().collect();
+    sorted.sort_by(|x, y| cmp_with_zero(registry, &at(x), &at(y)));
+    sorted
+}
+
+/// [`cmp_versions`] with OSV's `0` ("since the first version") below everything.
+fn cmp_with_zero(registry: RegistryType, a: &str, b: &str) -> Ordering {
+    match (a == "0", b == "0") {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => cmp_versions(registry, a, b),
+    }
+}
+
+/// OSV range evaluation: walk the events in version order; `introduced` opens an
+/// affected interval, `fixed` closes it (exclusive), `last_affected` closes it
+/// (inclusive). `limit` only bounds the search and changes nothing for a single version.
+fn range_affects(registry: RegistryType, events: &[Event], version: &str) -> bool {
+    let cmp = |a: &str, b: &str| cmp_with_zero(registry, a, b);
+    let mut affected = false;
+    for event in sorted_events(registry, events) {
+        if let Some(introduced) = &event.introduced {
+            if introduced == "0" || cmp(introduced, version) != Ordering::Greater {
+                affected = true;
+            }
+        } else if let Some(fixed) = &event.fixed {
+            if cmp(fixed, version) != Ordering::Greater {
+                affected = false;
+            }
+        } else if let Some(last) = &event.last_affected {
+            if cmp(last, version) == Ordering::Less {
+                affected = false;
+            }
+        }
+    }
+    affected
+}
+
+/// Compare two versions with the ordering of `registry`'s ecosystem: the
+/// registry's own comparator where it has one, semver otherwise.
+pub fn cmp_versions(registry: RegistryType, a: &str, b: &str) -> Ordering {
+    match registry {
+        RegistryType::PyPI => crate::registry::pypi::cmp_versions(a, b),
+        RegistryType::Maven | RegistryType::Gems => cmp_segmented(a, b),
+        // OSV lists Go versions without the `v` the module proxy uses.
+        RegistryType::Go => {
+            let canonical = |v: &str| format!("v{}", v.strip_prefix('v').unwrap_or(v));
+            crate::registry::go::cmp_go_versions(&canonical(a), &canonical(b))
+        }
+        RegistryType::Nuget => crate::registry::nuget::cmp_nuget_versions(a, b),
+        _ => crate::version::cmp_semver(a, b),
+    }
+}
+
+/// Maven / RubyGems ordering: digit runs compare numerically; a qualifier that marks a
+/// pre-release (`alpha`, `beta`, `rc`, `SNAPSHOT`, `pre`, …) sorts before the release,
+/// `final`/`ga`/`release` equal it, and any other qualifier (`sp1`) sorts after it.
+fn cmp_segmented(a: &str, b: &str) -> Ordering {
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    enum Token {
+        Pre(u8, String),
+        Release,
+        Number(u64),
+        Post(String),
+    }
+    fn tokens(v: &str) -> Vec<Token> {
+        let v = v.to_lowercase();
+        let mut out = Vec::new();
+        let mut rest = v.as_str();
+        while !rest.is_empty() {
+            rest = rest.trim_start_matches(['.', '-', '_']);
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            if digits > 0 {
+                out.push(Token::Number(rest[..digits].parse().unwrap_or(u64::MAX)));
+                rest = &rest[digits..];
+                continue;
+            }
+            let word_len = rest
+                .find(|c: char| c.is_ascii_digit() || matches!(c, '.' | '-' | '_'))
+                .unwrap_or(rest.len());
+            let word = &rest[..word_len];
+            rest = &rest[word_len..];
+            out.push(match word {
+                "" => continue,
+                "dev" | "snapshot" => Token::Pre(0, word.to_string()),
+                "alpha" | "a" => Token::Pre(1, word.to_string()),
+                "beta" | "b" => Token::Pre(2, word.to_string()),
+                "milestone" | "m" => Token::Pre(3, word.to_string()),
+                "rc" | "cr" | "pre" | "preview" => Token::Pre(4, word.to_string()),
+                "final" | "ga" | "release" => Token::Release,
+                _ => Token::Post(word.to_string()),
+            });
+        }
+        // Trailing zeros and release markers do not distinguish versions (1.0 == 1.0.0).
+        while matches!(out.last(), Some(Token::Number(0) | Token::Release)) {
+            out.pop();
+        }
+        out
+    }
+    // A missing segment is a zero against a number (1.0 vs 1.0.1), else the release.
+    fn filler(other: &Token) -> Token {
+        match other {
+            Token::Number(_) => Token::Number(0),
+            _ => Token::Release,
+        }
+    }
+    let (ta, tb) = (tokens(a), tokens(b));
+    for i in 0..ta.len().max(tb.len()) {
+        let ord = match (ta.get(i), tb.get(i)) {
+            (Some(x), Some(y)) => x.cmp(y),
+            (Some(x), None) => x.cmp(&filler(x)),
+            (None, Some(y)) => filler(y).cmp(y),
+            (None, None) => Ordering::Equal,
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    Ordering::Equal
+}
+
+/// The name an ecosystem compares package names by (PEP 503 for PyPI; crates and
+/// NuGet ids are case-insensitive).
+pub fn normalize_name(registry: RegistryType, name: &str) -> String {
+    match registry {
+        RegistryType::PyPI => {
+            let lower = name.to_lowercase();
+            let mut out = String::with_capacity(lower.len());
+            for c in lower.chars() {
+                let c = if matches!(c, '.' | '_') { '-' } else { c };
+                if !(c == '-' && out.ends_with('-')) {
+                    out.push(c);
+                }
+            }
+            out
+        }
+        RegistryType::Cargo | RegistryType::Nuget => name.to_lowercase(),
+        _ => name.to_string(),
+    }
+}
+
+/// The OSV ecosystem name of a NORA registry (inverse of [`registry_for_ecosystem`]).
+fn ecosystem_name(registry: RegistryType) -> &'static str {
+    match registry {
+        RegistryType::PyPI => "PyPI",
+        RegistryType::Cargo => "crates.io",
+        RegistryType::Go => "Go",
+        RegistryType::Maven => "Maven",
+        RegistryType::Nuget => "NuGet",
+        RegistryType::Gems => "RubyGems",
+        RegistryType::PubDart => "Pub",
+        _ => "npm",
+    }
+}
+
+/// The NORA registry an OSV ecosystem name maps to.
+fn registry_for_ecosystem(ecosystem: &str) -> Option<RegistryType> {
+    Some(match ecosystem {
+        "npm" => RegistryType::Npm,
+        "PyPI" => RegistryType::PyPI,
+        "crates.io" => RegistryType::Cargo,
+        "Go" => RegistryType::Go,
+        "Maven" => RegistryType::Maven,
+        "NuGet" => RegistryType::Nuget,
+        "RubyGems" => RegistryType::Gems,
+        "Pub" => RegistryType::PubDart,
+        _ => return None,
+    })
+}
+
+// ============================================================================
+// OSV parsing
+// ============================================================================
+
+#[derive(Deserialize)]
+struct OsvRecord {
+    id: String,
+    #[serde(default)]
+    modified: String,
+    #[serde(default)]
+    withdrawn: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    severity: Vec<OsvSeverity>,
+    #[serde(default)]
+    affected: Vec<OsvAffected>,
+    #[serde(default)]
+    database_specific: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct OsvSeverity {
+    #[serde(rename = "type")]
+    kind: String,
+    score: String,
+}
+
+#[derive(Deserialize)]
+struct OsvAffected {
+    package: Option<OsvPackage>,
+    #[serde(default)]
+    ranges: Vec<OsvRange>,
+    #[serde(default)]
+    versions: Vec<String>,
+    #[serde(default)]
+    database_specific: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct OsvPackage {
+    ecosystem: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct OsvRange {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    events: Vec<Event>,
+}
+
+/// What a parsed OSV record contributes to the database.
+enum Parsed {
+    Advisory(Advisory),
+    /// Withdrawn upstream: drop any stored copy.
+    Withdrawn(String),
+    /// No affected package in a supported ecosystem.
+    Irrelevant,
+}
+
+fn parse_osv(json: &[u8]) -> Result<Parsed, String> {
+    let record: OsvRecord =
+        serde_json::from_slice(json).map_err(|e| format!("invalid OSV record: {}", e))?;
+    if record.withdrawn.is_some() {
+        return Ok(Parsed::Withdrawn(record.id));
+    }
+
+    let mut affected = Vec::new();
+    for entry in &record.affected {
+        let Some(package) = &entry.package else {
+            continue;
+        };
+        // Ecosystems may carry a suffix (`Debian:12`); none of ours do.
+        let Some(registry) = registry_for_ecosystem(&package.ecosystem) else {
+            continue;
+        };
+        let ranges: Vec<Vec<Event>> = entry
+            .ranges
+            .iter()
+            .filter(|r| r.kind == "SEMVER" || r.kind == "ECOSYSTEM")
+            .map(|r| r.events.clone())
+            .collect();
+        let versions = if ranges.is_empty() {
+            entry.versions.clone()
+        } else {
+            Vec::new()
+        };
+        if ranges.is_empty() && versions.is_empty() {
+            continue;
+        }
+        affected.push(Affected {
+            registry,
+            name: normalize_name(registry, &package.name),
+            ranges,
+            versions,
+        });
+    }
+    if affected.is_empty() {
+        return Ok(Parsed::Irrelevant);
+    }
+
+    let severity = record
+        .database_specific
+        .as_ref()
+        .or_else(|| {
+            record
+                .affected
+                .iter()
+                .find_map(|a| a.database_specific.as_ref())
+        })
+        .and_then(|d| d.get("severity"))
+        .and_then(|s| s.as_str())
+        .and_then(|s| s.parse().ok())
+        .or_else(|| {
+            record
+                .severity
+                .iter()
+                .filter(|s| s.kind == "CVSS_V3")
+                .filter_map(|s| cvss3_base_score(&s.score))
+                .filter_map(Severity::from_score)
+                .max()
+        });
+
+    Ok(Parsed::Advisory(Advisory {
+        id: record.id,
+        aliases: record.aliases,
+        modified: record.modified,
+        summary: record.summary,
+        severity,
+        affected,
+    }))
+}
+
+/// CVSS v3.x base score from a vector string (`CVSS:3.1/AV:N/AC:L/...`).
+fn cvss3_base_score(vector: &str) -> Option<f64> {
+    let mut metrics = HashMap::new();
+    let mut parts = vector.split('/');
+    if !parts.next()?.starts_with("CVSS:3") {
+        return None;
+    }
+    for part in parts {
+        let (k, v) = part.split_once(':')?;
+        metrics.insert(k, v);
+    }
+    let changed = match *metrics.get("S")? {
+        "U" => false,
+        "C" => true,
+        _ => return None,
+    };
+    let av = match *metrics.get("AV")? {
+        "N" => 0.85,
+        "A" => 0.62,
+        "L" => 0.55,
+        "P" => 0.2,
+        _ => return None,
+    };
+    let ac = match *metrics.get("AC")? {
+        "L" => 0.77,
+        "H" => 0.44,
+        _ => return None,
+    };
+    let pr = match (*metrics.get("PR")?, changed) {
+        ("N", _) => 0.85,
+        ("L", false) => 0.62,
+        ("L", true) => 0.68,
+        ("H", false) => 0.27,
+        ("H", true) => 0.5,
+        _ => return None,
+    };
+    let ui = match *metrics.get("UI")? {
+        "N" => 0.85,
+        "R" => 0.62,
+        _ => return None,
+    };
+    let cia = |k: &str| -> Option<f64> {
+        match *metrics.get(k)? {
+            "H" => Some(0.56),
+            "L" => Some(0.22),
+            "N" => Some(0.0),
+            _ => None,
+        }
+    };
+    let iss = 1.0 - (1.0 - cia("C")?) * (1.0 - cia("I")?) * (1.0 - cia("A")?);
+    let impact = if changed {
+        7.52 * (iss - 0.029) - 3.25 * (iss - 0.02f64).powi(15)
+    } else {
+        6.42 * iss
+    };
+    if impact <= 0.0 {
+        return Some(0.0);
+    }
+    let exploitability = 8.22 * av * ac * pr * ui;
+    let raw = if changed {
+        1.08 * (impact + exploitability)
+    } else {
+        impact + exploitability
+    };
+    // CVSS 3.1 Roundup: smallest one-decimal number >= the input, float-safe.
+    let int = (raw.min(10.0) * 100_000.0).round() as i64;
+    Some(if int % 10_000 == 0 {
+        int as f64 / 100_000.0
+    } else {
+        ((int / 10_000) + 1) as f64 / 10.0
+    })
+}
+
+// ============================================================================
+// Database
+// ============================================================================
+
+#[derive(Serialize, Deserialize)]
+struct DbFile {
+    version: u32,
+    /// RFC 3339 time of the last import.
+    imported_at: String,
+    /// OSV ecosystems the imported dumps carried advisories for. Absent from
+    /// databases written before it was recorded; those derive it on load.
+    #[serde(default)]
+    ecosystems: BTreeSet<String>,
+    advisories: Vec<Advisory>,
+}
+
+/// The loaded vulnerability database, indexed by (registry, package name).
+pub struct VulnDb {
+    advisories: Vec<Advisory>,
+    /// (registry, normalized name) → (advisory index, affected index).
+    index: HashMap<(RegistryType, String), Vec<(usize, usize)>>,
+    imported_at: String,
+    ecosystems: HashSet<RegistryType>,
+}
+
+impl VulnDb {
+    fn from_advisories(
+        mut advisories: Vec<Advisory>,
+        imported_at: String,
+        ecosystems: &BTreeSet<String>,
+    ) -> Self {
+        // PyPI's own advisories (PYSEC-*) are usually unscored while their GHSA alias
+        // is: let an unscored advisory borrow the highest severity among its aliases.
+        let by_id: HashMap<&str, Severity> = advisories
+            .iter()
+            .filter_map(|a| a.severity.map(|s| (a.id.as_str(), s)))
+            .collect();
+        let borrowed: Vec<Option<Severity>> = advisories
+            .iter()
+            .map(|a| match a.severity {
+                Some(s) => Some(s),
+                None => a
+                    .aliases
+                    .iter()
+                    .filter_map(|alias| by_id.get(alias.as_str()).copied())
+                    .max(),
+            })
+            .collect();
+        for (advisory, severity) in advisories.iter_mut().zip(borrowed) {
+            advisory.severity = severity;
+        }
+
+        let mut index: HashMap<(RegistryType, String), Vec<(usize, usize)>> = HashMap::new();
+        for (i, advisory) in advisories.iter().enumerate() {
+            for (j, affected) in advisory.affected.iter().enumerate() {
+                index
+                    .entry((affected.registry, affected.name.clone()))
+                    .or_default()
+                    .push((i, j));
+            }
+        }
+        let ecosystems = if ecosystems.is_empty() {
+            index.keys().map(|(registry, _)| *registry).collect()
+        } else {
+            ecosystems
+                .iter()
+                .filter_map(|e| registry_for_ecosystem(e))
+                .collect()
+        };
+        Self {
+            advisories,
+            index,
+            imported_at,
+            ecosystems,
+        }
+    }
+
+    /// Load the index written by [`import`].
+    pub fn load(path: &str) -> Result<Self, String> {
+        let content = std::fs::read(path)
+            .map_err(|e| format!("failed to read vulnerability database '{}': {}", path, e))?;
+        let file: DbFile = serde_json::from_slice(&content)
+            .map_err(|e| format!("failed to parse vulnerability database '{}': {}", path, e))?;
+        if file.version != DB_VERSION {
+            return Err(format!(
+                "unsupported vulnerability database version {} (expected {}) — re-run `nora vulndb import`",
+                file.version, DB_VERSION
+            ));
+        }
+        Ok(Self::from_advisories(
+            file.advisories,
+            file.imported_at,
+            &file.ecosystems,
+        ))
+    }
+
+    /// Number of advisories loaded.
+    pub fn advisory_count(&self) -> usize {
+        self.advisories.len()
+    }
+
+    /// When the database was last imported (RFC 3339).
+    pub fn imported_at(&self) -> &str {
+        &self.imported_at
+    }
+
+    /// Whether an import carried advisories for `registry`'s ecosystem. A package
+    /// without advisories is only known clean in an ecosystem the database covers.
+    pub fn covers(&self, registry: RegistryType) -> bool {
+        self.ecosystems.contains(&registry)
+    }
+
+    /// OSV names of the covered ecosystems, sorted.
+    pub fn ecosystems(&self) -> Vec<&'static str> {
+        let mut names: Vec<_> = self.ecosystems.iter().map(|r| ecosystem_name(*r)).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Every advisory entry for a package, affected version or not.
+    pub fn advisories_for(
+        &self,
+        registry: RegistryType,
+        name: &str,
+    ) -> impl Iterator<Item = (&Advisory, &Affected)> {
+        self.index
+            .get(&(registry, normalize_name(registry, name)))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+            .iter()
+            .map(|&(i, j)| (&self.advisories[i], &self.advisories[i].affected[j]))
+    }
+
+    /// The advisories affecting `name@version`, most severe first.
+    pub fn affecting(&self, registry: RegistryType, name: &str, version: &str) -> Vec<&Advisory> {
+        let mut hits: Vec<&Advisory> = self
+            .advisories_for(registry, name)
+            .filter(|(_, affected)| affected.affects(version))
+            .map(|(advisory, _)| advisory)
+            .collect();
+        hits.sort_by(|a, b| b.severity.cmp(&a.severity).then_with(|| a.id.cmp(&b.id)));
+        hits.dedup_by(|a, b| a.id == b.id);
+        hits
+    }
+}
+
+/// Counts reported by [`import`].
+#[derive(Debug, Default, PartialEq)]
+pub struct ImportStats {
+    /// Advisories added or updated from the dump.
+    pub imported: usize,
+    /// Records skipped: other ecosystems, or older than the stored copy.
+    pub skipped: usize,
+    /// Stored advisories removed because the dump marks them withdrawn.
+    pub withdrawn: usize,
+    /// Records that failed to parse.
+    pub invalid: usize,
+    /// Advisories in the database after the import.
+    pub total: usize,
+}
+
+/// Import an OSV zip dump into the database at `db_path`.
+///
+/// Advisories merge by id into the existing database (the newer `modified` wins), so
+/// per-ecosystem dumps can be imported one after another; `replace` starts empty. The
+/// database is written to a temp file and renamed into place — a running server picks
+/// it up on its next SIGHUP reload.
+pub fn import(dump: &Path, db_path: &str, replace: bool) -> Result<ImportStats, String> {
+    let file = std::fs::File::open(dump)
+        .map_err(|e| format!("cannot open '{}': {}", dump.display(), e))?;
+    let mut archive = zip::ZipArchive::new(std::io::BufReader::new(file))
+        .map_err(|e| format!("'{}' is not a zip archive: {}", dump.display(), e))?;
+
+    let mut advisories: BTreeMap<String, Advisory> = BTreeMap::new();
+    let mut ecosystems: BTreeSet<String> = BTreeSet::new();
+    if !replace && Path::new(db_path).exists() {
+        let existing = VulnDb::load(db_path)?;
+        ecosystems.extend(existing.ecosystems().into_iter().map(str::to_string));
+        for advisory in existing.advisories {
+            advisories.insert(advisory.id.clone(), advisory);
+        }
+    }
+
+    let mut stats = ImportStats::default();
+    for i in 0..archive.len() {
+        let mut entry = archive
+            .by_index(i)
+            .map_err(|e| format!("corrupt zip entry {}: {}", i, e))?;
+        if !entry.is_file() || !entry.name().ends_with(".json") {
+            continue;
+        }
+        if entry.size() > MAX_RECORD_SIZE {
+            stats.invalid += 1;
+            continue;
+        }
+        let mut json = Vec::with_capacity(entry.size() as usize);
+        entry
+            .read_to_end(&mut json)
+            .map_err(|e| format!("cannot read {}: {}", entry.name(), e))?;
+        match parse_osv(&json) {
+            Ok(Parsed::Advisory(advisory)) => {
+                for affected in &advisory.affected {
+                    ecosystems.insert(ecosystem_name(affected.registry).to_string());
+                }
+                match advisories.get(&advisory.id) {
+                    Some(stored) if stored.modified > advisory.modified => stats.skipped += 1,
+                    _ => {
+                        advisories.insert(advisory.id.clone(), advisory);
+                        stats.imported += 1;
+                    }
+                }
+            }
+            Ok(Parsed::Withdrawn(id)) => {
+                if advisories.remove(&id).is_some() {
+                    stats.withdrawn += 1;
+                }
+            }
+            Ok(Parsed::Irrelevant) => stats.skipped += 1,
+            Err(e) => {
+                tracing::warn!(entry = %entry.name(), error = %e, "Skipping OSV record");
+                stats.invalid += 1;
+            }
+        }
+    }
+    stats.total = advisories.len();
+
+    let db = DbFile {
+        version: DB_VERSION,
+        imported_at: chrono::Utc::now().to_rfc3339(),
+        ecosystems,
+        advisories: advisories.into_values().collect(),
+    };
+    let path = Path::new(db_path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("cannot create '{}': {}", parent.display(), e))?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    let out = std::fs::File::create(&tmp)
+        .map_err(|e| format!("cannot write '{}': {}", tmp.display(), e))?;
+    serde_json::to_writer(std::io::BufWriter::new(out), &db)
+        .map_err(|e| format!("cannot write '{}': {}", tmp.display(), e))?;
+    std::fs::rename(&tmp, path).map_err(|e| format!("cannot write '{}': {}", db_path, e))?;
+    Ok(stats)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// Build an OSV zip dump from `(file name, record)` pairs.
+    pub(crate) fn osv_zip(dir: &Path, records: &[serde_json::Value]) -> std::path::PathBuf {
+        let path = dir.join("all.zip");
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+        for record in records {
+            let name = format!("{}.json", record["id"].as_str().unwrap());
+            zip.start_file(name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zip.write_all(record.to_string().as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+        path
+    }
+
+    impl VulnDb {
+        /// A database straight from OSV records, without the import round trip.
+        pub(crate) fn from_osv(records: &[serde_json::Value]) -> Self {
+            let advisories = records
+                .iter()
+                .filter_map(|r| match parse_osv(r.to_string().as_bytes()).unwrap() {
+                    Parsed::Advisory(a) => Some(a),
+                    _ => None,
+                })
+                .collect();
+            Self::from_advisories(advisories, String::new(), &BTreeSet::new())
+        }
+    }
+
+    /// A GHSA-style npm advisory for `lodash` fixed in 4.17.21.
+    /// Import `records` into a fresh database under `dir`; returns its path.
+    pub(crate) fn db_with(dir: &Path, records: &[serde_json::Value]) -> String {
+        let dump = osv_zip(dir, records);
+        let db_path = dir.join("osv.json").to_str().unwrap().to_string();
+        import(&dump, &db_path, true).unwrap();
+        db_path
+    }
+
+    pub(crate) fn lodash_advisory() -> serde_json::Value {
+        serde_json::json!({
+            "id": "GHSA-35jh-r3h4-6jhm",
+            "modified": "2024-01-01T00:00:00Z",
+            "aliases": ["CVE-2021-23337"],
+            "summary": "Command Injection in lodash",
+            "affected": [{
+                "package": {"ecosystem": "npm", "name": "lodash"},
+                "ranges": [{"type": "SEMVER", "events": [{"introduced": "0"}, {"fixed": "4.17.21"}]}],
+                "database_specific": {"source": "https://github.com/advisories"}
+            }],
+            "database_specific": {"severity": "HIGH"}
+        })
+    }
+
+    fn affected(registry: RegistryType, events: serde_json::Value) -> Affected {
+        Affected {
+            registry,
+            name: "pkg".into(),
+            ranges: vec![serde_json::from_value(events).unwrap()],
+            versions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_range_semantics() {
+        let a = affected(
+            RegistryType::Npm,
+            // Out of order on purpose: OSV does not require sorted events.
+            serde_json::json!([{"introduced": "2.0.0"}, {"last_affected": "2.1.0"}, {"introduced": "1.0.0"}, {"fixed": "1.2.0"}]),
+        );
+        assert!(!a.affects("0.9.0"));
+        assert!(a.affects("1.0.0"));
+        assert!(a.affects("1.1.9"));
+        assert!(!a.affects("1.2.0"));
+        assert!(!a.affects("1.5.0"));
+        assert!(a.affects("2.1.0"));
+        assert!(!a.affects("2.1.1"));
+        // Pre-releases of the fixed version are still affected.
+        assert!(a.affects("1.2.0-rc.1"));
+        assert_eq!(a.range_expr(), ">=1.0.0 <1.2.0 || >=2.0.0 <=2.1.0");
+        let open = affected(RegistryType::Npm, serde_json::json!([{"introduced": "0"}]));
+        assert_eq!(open.range_expr(), "*");
+        let fixed = affected(
+            RegistryType::Npm,
+            serde_json::json!([{"introduced": "0"}, {"fixed": "4.17.21"}]),
+        );
+        assert_eq!(fixed.range_expr(), "<4.17.21");
+    }
+
+    #[test]
+    fn test_ecosystem_orderings() {
+        // PEP 440: 2.0rc1 < 2.0 < 2.0.post1.
+        let py = affected(
+            RegistryType::PyPI,
+            serde_json::json!([{"introduced": "0"}, {"fixed": "2.0"}]),
+        );
+        assert!(py.affects("2.0rc1"));
+        assert!(!py.affects("2.0"));
+        assert!(!py.affects("2.0.post1"));
+        // Maven: 2.0-beta-1 < 2.0 == 2.0.0 == 2.0.Final < 2.0.1.
+        let mvn = affected(
+            RegistryType::Maven,
+            serde_json::json!([{"introduced": "0"}, {"fixed": "2.0"}]),
+        );
+        assert!(mvn.affects("2.0-beta-1"));
+        assert!(mvn.affects("1.10"));
+        assert!(!mvn.affects("2.0.0"));
+        assert!(!mvn.affects("2.0.Final"));
+        // Go: request versions carry the `v` prefix, OSV's do not.
+        let go = affected(
+            RegistryType::Go,
+            serde_json::json!([{"introduced": "0"}, {"fixed": "0.3.8"}]),
+        );
+        assert!(go.affects("v0.3.7"));
+        assert!(!go.affects("v0.3.8"));
+        assert!(go.affects("v0.3.8-rc.1"));
+        // NuGet: `1.0` == `1.0.0.0`, pre-release labels case-insensitive.
+        let nuget = affected(
+            RegistryType::Nuget,
+            serde_json::json!([{"introduced": "0"}, {"fixed": "1.0"}]),
+        );
+        assert!(!nuget.affects("1.0.0.0"));
+        assert!(nuget.affects("1.0.0-RC.1"));
+        assert!(nuget.affects("0.9.9.9"));
+        assert_eq!(cmp_segmented("1.2.3.pre", "1.2.3"), Ordering::Less);
+        assert_eq!(cmp_segmented("1.0-sp1", "1.0"), Ordering::Greater);
+        assert_eq!(cmp_segmented("1.0-beta", "1.0"), Ordering::Less);
+        assert_eq!(cmp_segmented("1.0.1", "1.0-beta"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_cvss3_base_score() {
+        assert_eq!(
+            cvss3_base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"),
+            Some(9.8)
+        );
+        assert_eq!(
+            cvss3_base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N"),
+            Some(6.1)
+        );
+        assert_eq!(
+            cvss3_base_score("CVSS:3.0/AV:L/AC:H/PR:H/UI:R/S:U/C:N/I:N/A:N"),
+            Some(0.0)
+        );
+        assert_eq!(cvss3_base_score("CVSS:4.0/AV:N"), None);
+    }
+
+    #[test]
+    fn test_import_merges_and_withdraws() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db_path = dir.path().join("vulndb/osv.json");
+        let db_path = db_path.to_str().unwrap();
+        let pysec = serde_json::json!({
+            "id": "PYSEC-2021-1",
+            "modified": "2024-01-01T00:00:00Z",
+            "aliases": ["GHSA-aaaa-bbbb-cccc"],
+            "affected": [{
+                "package": {"ecosystem": "PyPI", "name": "Django_Utils"},
+                "ranges": [{"type": "ECOSYSTEM", "events": [{"introduced": "0"}, {"fixed": "1.1"}]}]
+            }]
+        });
+        let ghsa = serde_json::json!({
+            "id": "GHSA-aaaa-bbbb-cccc",
+            "modified": "2024-01-01T00:00:00Z",
+            "severity": [{"type": "CVSS_V3", "score": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"}],
+            "affected": [{
+                "package": {"ecosystem": "PyPI", "name": "django-utils"},
+                "ranges": [{"type": "GIT", "repo": "x", "events": [{"introduced": "0"}]}],
+                "versions": ["1.0"]
+            }]
+        });
+        let debian = serde_json::json!({
+            "id": "DSA-1",
+            "affected": [{"package": {"ecosystem": "Debian:12", "name": "openssl"}, "versions": ["1"]}]
+        });
+        let dump = osv_zip(
+            dir.path(),
+            &[pysec.clone(), ghsa, debian, lodash_advisory()],
+        );
+        let stats = import(&dump, db_path, false).unwrap();
+        assert_eq!((stats.imported, stats.skipped, stats.total), (3, 1, 3));
+
+        let db = VulnDb::load(db_path).unwrap();
+        let hits = db.affecting(RegistryType::PyPI, "django.utils", "1.0");
+        assert_eq!(hits.len(), 2);
+        // PYSEC borrowed the critical rating of its GHSA alias.
+        assert!(hits.iter().all(|a| a.severity == Some(Severity::Critical)));
+        assert!(db
+            .affecting(RegistryType::PyPI, "django-utils", "1.1")
+            .is_empty());
+        assert_eq!(
+            db.affecting(RegistryType::Npm, "lodash", "4.17.20")[0].severity,
+            Some(Severity::High)
+        );
+        assert_eq!(db.ecosystems(), ["PyPI", "npm"]);
+        assert!(!db.covers(RegistryType::Cargo));
+
+        // A later per-ecosystem dump merges; a withdrawn record removes the stored copy.
+        let withdrawn = serde_json::json!({
+            "id": "GHSA-35jh-r3h4-6jhm",
+            "modified": "2025-01-01T00:00:00Z",
+            "withdrawn": "2025-01-01T00:00:00Z"
+        });
+        let dir2 = tempfile::TempDir::new().unwrap();
+        let stats = import(&osv_zip(dir2.path(), &[withdrawn]), db_path, false).unwrap();
+        assert_eq!((stats.withdrawn, stats.total), (1, 2));
+        let db = VulnDb::load(db_path).unwrap();
+        assert!(db
+            .affecting(RegistryType::Npm, "lodash", "4.17.20")
+            .is_empty());
+        assert_eq!(db.advisory_count(), 2);
+        // npm stays covered: the import said lodash is no longer affected.
+        assert!(db.covers(RegistryType::Npm));
+
+        // `--replace` covers only what the new dump carries.
+        let dir3 = tempfile::TempDir::new().unwrap();
+        import(&osv_zip(dir3.path(), &[pysec]), db_path, true).unwrap();
+        let db = VulnDb::load(db_path).unwrap();
+        assert_eq!(db.ecosystems(), ["PyPI"]);
+    }
+}